@@ -0,0 +1,11 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use sysinfo::parsing::{container_id_from_cgroup, parse_cgroup};
+
+fuzz_target!(|data: &str| {
+    let _ = parse_cgroup(data);
+    let _ = container_id_from_cgroup(data);
+});
@@ -0,0 +1,13 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use sysinfo::parsing::{parse_kmsg_record, parse_redox_log_line};
+
+fuzz_target!(|data: &[u8]| {
+    let _ = parse_kmsg_record(data);
+    for line in String::from_utf8_lossy(data).lines() {
+        let _ = parse_redox_log_line(line);
+    }
+});
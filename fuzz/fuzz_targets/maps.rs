@@ -0,0 +1,14 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use sysinfo::parsing::{
+    parse_maps_deleted_executables, parse_maps_paths, parse_maps_stack_and_heap,
+};
+
+fuzz_target!(|data: &str| {
+    let _ = parse_maps_stack_and_heap(data);
+    let _ = parse_maps_paths(data.as_bytes()).count();
+    let _ = parse_maps_deleted_executables(data.as_bytes()).count();
+});
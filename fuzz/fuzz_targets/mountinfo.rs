@@ -0,0 +1,12 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use sysinfo::parsing::parse_mountinfo_line;
+
+fuzz_target!(|data: &str| {
+    for line in data.lines() {
+        let _ = parse_mountinfo_line(line);
+    }
+});
@@ -0,0 +1,10 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use sysinfo::parsing::parse_mountstats;
+
+fuzz_target!(|data: &str| {
+    let _ = parse_mountstats(data);
+});
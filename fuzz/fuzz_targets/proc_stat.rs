@@ -0,0 +1,13 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use sysinfo::parsing::{ProcIndex, parse_stat_file};
+
+fuzz_target!(|data: &[u8]| {
+    if let Some(parts) = parse_stat_file(data) {
+        // All the fields we use must be accessible.
+        let _ = parts.str_parts[ProcIndex::ResidentSetSize as usize];
+    }
+});
@@ -0,0 +1,10 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use sysinfo::parsing::parse_rpi_throttled;
+
+fuzz_target!(|data: &str| {
+    let _ = parse_rpi_throttled(data);
+});
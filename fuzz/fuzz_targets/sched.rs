@@ -0,0 +1,14 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use sysinfo::parsing::{
+    parse_process_schedstat, parse_sched_debug_nr_running, parse_schedstat_run_delays,
+};
+
+fuzz_target!(|data: &str| {
+    let _ = parse_schedstat_run_delays(data);
+    let _ = parse_sched_debug_nr_running(data);
+    let _ = parse_process_schedstat(data);
+});
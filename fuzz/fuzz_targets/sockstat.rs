@@ -0,0 +1,12 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use sysinfo::parsing::{parse_snmp, parse_snmp6, parse_sockstat};
+
+fuzz_target!(|data: &str| {
+    let _ = parse_sockstat(data);
+    let _ = parse_snmp(data);
+    let _ = parse_snmp6(data);
+});
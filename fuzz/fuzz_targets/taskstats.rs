@@ -0,0 +1,12 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use sysinfo::parsing::{parse_genl_family_id, parse_netlink_ack, parse_taskstats_exits};
+
+fuzz_target!(|data: &[u8]| {
+    let _ = parse_taskstats_exits(data);
+    let _ = parse_genl_family_id(data);
+    let _ = parse_netlink_ack(data);
+});
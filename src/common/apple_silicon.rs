@@ -0,0 +1,244 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::AppleSiliconMetricsInner;
+
+use std::io;
+
+/// Retrieves the metrics of Apple Silicon chips which aren't available through [`Cpu`]: the
+/// frequency and the usage of each CPU cluster (efficiency and performance cores) and the
+/// power used by the chip.
+///
+/// [`Cpu::frequency`] always returns the nominal frequency on Apple Silicon, since the
+/// frequency of each cluster changes all the time depending on the load. The values returned
+/// here are averaged between the two last refreshes, so [`AppleSiliconMetrics::refresh`] must
+/// be called twice before they're available.
+///
+/// ⚠️ They're retrieved with the private `IOReport` API, so they might stop working on a future
+/// macOS version. It is only supported on macOS (and not with the `apple-sandbox` feature). On
+/// other systems, [`AppleSiliconMetrics::start`] returns an error of kind
+/// [`io::ErrorKind::Unsupported`].
+///
+/// ```no_run
+/// use sysinfo::AppleSiliconMetrics;
+///
+/// let mut metrics = AppleSiliconMetrics::start().expect("IOReport isn't available");
+/// metrics.refresh();
+/// std::thread::sleep(std::time::Duration::from_millis(500));
+/// metrics.refresh();
+/// for cluster in metrics.clusters() {
+///     println!(
+///         "{}: {} MHz, {:.1}%",
+///         cluster.name(),
+///         cluster.frequency(),
+///         cluster.usage(),
+///     );
+/// }
+/// println!("CPU power: {:?} W", metrics.cpu_power());
+/// ```
+///
+/// [`Cpu`]: crate::Cpu
+/// [`Cpu::frequency`]: crate::Cpu::frequency
+pub struct AppleSiliconMetrics {
+    inner: AppleSiliconMetricsInner,
+    clusters: Vec<CpuCluster>,
+    power: PowerUsage,
+}
+
+/// Power (in watts) used by the different parts of the chip between the two last refreshes.
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct PowerUsage {
+    pub(crate) cpu: Option<f32>,
+    pub(crate) gpu: Option<f32>,
+    pub(crate) ane: Option<f32>,
+}
+
+impl AppleSiliconMetrics {
+    /// Subscribes to the `IOReport` channels. The metrics are only available after
+    /// [`AppleSiliconMetrics::refresh`] is called twice.
+    ///
+    /// ```no_run
+    /// use sysinfo::AppleSiliconMetrics;
+    ///
+    /// match AppleSiliconMetrics::start() {
+    ///     Ok(_metrics) => println!("subscribed"),
+    ///     Err(error) => eprintln!("cannot subscribe to IOReport: {error}"),
+    /// }
+    /// ```
+    pub fn start() -> io::Result<Self> {
+        Ok(Self {
+            inner: AppleSiliconMetricsInner::start()?,
+            clusters: Vec::new(),
+            power: PowerUsage::default(),
+        })
+    }
+
+    /// Takes a new sample and computes the metrics since the previous one.
+    ///
+    /// ```no_run
+    /// use sysinfo::AppleSiliconMetrics;
+    ///
+    /// let mut metrics = AppleSiliconMetrics::start().expect("IOReport isn't available");
+    /// metrics.refresh();
+    /// std::thread::sleep(std::time::Duration::from_millis(500));
+    /// metrics.refresh();
+    /// ```
+    pub fn refresh(&mut self) {
+        self.inner.refresh(&mut self.clusters, &mut self.power);
+    }
+
+    /// Returns the CPU clusters.
+    ///
+    /// ```no_run
+    /// use sysinfo::AppleSiliconMetrics;
+    ///
+    /// let mut metrics = AppleSiliconMetrics::start().expect("IOReport isn't available");
+    /// metrics.refresh();
+    /// std::thread::sleep(std::time::Duration::from_millis(500));
+    /// metrics.refresh();
+    /// for cluster in metrics.clusters() {
+    ///     println!("{} ({:?}): {} MHz", cluster.name(), cluster.kind(), cluster.frequency());
+    /// }
+    /// ```
+    pub fn clusters(&self) -> &[CpuCluster] {
+        &self.clusters
+    }
+
+    /// Returns the power (in watts) used by the CPU clusters.
+    ///
+    /// ```no_run
+    /// use sysinfo::AppleSiliconMetrics;
+    ///
+    /// let mut metrics = AppleSiliconMetrics::start().expect("IOReport isn't available");
+    /// metrics.refresh();
+    /// std::thread::sleep(std::time::Duration::from_millis(500));
+    /// metrics.refresh();
+    /// println!("{:?} W", metrics.cpu_power());
+    /// ```
+    pub fn cpu_power(&self) -> Option<f32> {
+        self.power.cpu
+    }
+
+    /// Returns the power (in watts) used by the GPU.
+    ///
+    /// ```no_run
+    /// use sysinfo::AppleSiliconMetrics;
+    ///
+    /// let mut metrics = AppleSiliconMetrics::start().expect("IOReport isn't available");
+    /// metrics.refresh();
+    /// std::thread::sleep(std::time::Duration::from_millis(500));
+    /// metrics.refresh();
+    /// println!("{:?} W", metrics.gpu_power());
+    /// ```
+    pub fn gpu_power(&self) -> Option<f32> {
+        self.power.gpu
+    }
+
+    /// Returns the power (in watts) used by the whole package, which is the sum of the power
+    /// used by the CPU clusters, the GPU and the Neural Engine.
+    ///
+    /// ```no_run
+    /// use sysinfo::AppleSiliconMetrics;
+    ///
+    /// let mut metrics = AppleSiliconMetrics::start().expect("IOReport isn't available");
+    /// metrics.refresh();
+    /// std::thread::sleep(std::time::Duration::from_millis(500));
+    /// metrics.refresh();
+    /// println!("{:?} W", metrics.package_power());
+    /// ```
+    pub fn package_power(&self) -> Option<f32> {
+        let PowerUsage { cpu, gpu, ane } = self.power;
+        if cpu.is_none() && gpu.is_none() && ane.is_none() {
+            return None;
+        }
+        Some(cpu.unwrap_or(0.) + gpu.unwrap_or(0.) + ane.unwrap_or(0.))
+    }
+}
+
+/// Kind of the cores of a [`CpuCluster`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub enum CpuClusterKind {
+    /// Efficiency cores ("E-cores").
+    Efficiency,
+    /// Performance cores ("P-cores").
+    Performance,
+    /// Unknown kind of cores.
+    Unknown,
+}
+
+/// A cluster of CPU cores sharing the same frequency. It is returned by
+/// [`AppleSiliconMetrics::clusters`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct CpuCluster {
+    pub(crate) name: String,
+    pub(crate) kind: CpuClusterKind,
+    pub(crate) frequency: u64,
+    pub(crate) usage: f32,
+}
+
+impl CpuCluster {
+    /// Returns the name of the cluster, like `ECPU` or `PCPU1`.
+    ///
+    /// ```no_run
+    /// use sysinfo::AppleSiliconMetrics;
+    ///
+    /// let mut metrics = AppleSiliconMetrics::start().expect("IOReport isn't available");
+    /// metrics.refresh();
+    /// for cluster in metrics.clusters() {
+    ///     println!("{}", cluster.name());
+    /// }
+    /// ```
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the kind of the cores of the cluster.
+    ///
+    /// ```no_run
+    /// use sysinfo::AppleSiliconMetrics;
+    ///
+    /// let mut metrics = AppleSiliconMetrics::start().expect("IOReport isn't available");
+    /// metrics.refresh();
+    /// for cluster in metrics.clusters() {
+    ///     println!("{:?}", cluster.kind());
+    /// }
+    /// ```
+    pub fn kind(&self) -> CpuClusterKind {
+        self.kind
+    }
+
+    /// Returns the average frequency (in MHz) of the cluster while it was active, or `0` if it
+    /// was never active.
+    ///
+    /// ```no_run
+    /// use sysinfo::AppleSiliconMetrics;
+    ///
+    /// let mut metrics = AppleSiliconMetrics::start().expect("IOReport isn't available");
+    /// metrics.refresh();
+    /// std::thread::sleep(std::time::Duration::from_millis(500));
+    /// metrics.refresh();
+    /// for cluster in metrics.clusters() {
+    ///     println!("{} MHz", cluster.frequency());
+    /// }
+    /// ```
+    pub fn frequency(&self) -> u64 {
+        self.frequency
+    }
+
+    /// Returns the percentage (between `0` and `100`) of the time the cluster was active.
+    ///
+    /// ```no_run
+    /// use sysinfo::AppleSiliconMetrics;
+    ///
+    /// let mut metrics = AppleSiliconMetrics::start().expect("IOReport isn't available");
+    /// metrics.refresh();
+    /// std::thread::sleep(std::time::Duration::from_millis(500));
+    /// metrics.refresh();
+    /// for cluster in metrics.clusters() {
+    ///     println!("{}%", cluster.usage());
+    /// }
+    /// ```
+    pub fn usage(&self) -> f32 {
+        self.usage
+    }
+}
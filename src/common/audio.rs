@@ -0,0 +1,282 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+/// Interacting with audio devices.
+///
+/// ⚠️ The list is always empty on iOS and with the `apple-sandbox` feature.
+///
+/// ```no_run
+/// use sysinfo::AudioDevices;
+///
+/// let audio_devices = AudioDevices::new_with_refreshed_list();
+/// for device in audio_devices.list() {
+///     println!("{device:?}");
+/// }
+/// ```
+pub struct AudioDevices {
+    devices: Vec<AudioDevice>,
+}
+
+impl Default for AudioDevices {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<AudioDevices> for Vec<AudioDevice> {
+    fn from(devices: AudioDevices) -> Self {
+        devices.devices
+    }
+}
+
+impl From<Vec<AudioDevice>> for AudioDevices {
+    fn from(devices: Vec<AudioDevice>) -> Self {
+        Self { devices }
+    }
+}
+
+impl std::ops::Deref for AudioDevices {
+    type Target = [AudioDevice];
+
+    fn deref(&self) -> &Self::Target {
+        self.list()
+    }
+}
+
+impl std::ops::DerefMut for AudioDevices {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.list_mut()
+    }
+}
+
+impl<'a> IntoIterator for &'a AudioDevices {
+    type Item = &'a AudioDevice;
+    type IntoIter = std::slice::Iter<'a, AudioDevice>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.list().iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a mut AudioDevices {
+    type Item = &'a mut AudioDevice;
+    type IntoIter = std::slice::IterMut<'a, AudioDevice>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.list_mut().iter_mut()
+    }
+}
+
+impl AudioDevices {
+    /// Creates a new empty [`AudioDevices`][crate::AudioDevices] type.
+    ///
+    /// If you want it to be filled directly, take a look at
+    /// [`AudioDevices::new_with_refreshed_list`].
+    ///
+    /// ```no_run
+    /// use sysinfo::AudioDevices;
+    ///
+    /// let mut audio_devices = AudioDevices::new();
+    /// audio_devices.refresh();
+    /// for device in audio_devices.list() {
+    ///     println!("{device:?}");
+    /// }
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            devices: Vec::new(),
+        }
+    }
+
+    /// Creates a new [`AudioDevices`][crate::AudioDevices] type with the audio devices list loaded.
+    ///
+    /// ```no_run
+    /// use sysinfo::AudioDevices;
+    ///
+    /// let audio_devices = AudioDevices::new_with_refreshed_list();
+    /// for device in audio_devices.list() {
+    ///     println!("{device:?}");
+    /// }
+    /// ```
+    pub fn new_with_refreshed_list() -> Self {
+        let mut devices = Self::new();
+        devices.refresh();
+        devices
+    }
+
+    /// Returns the audio devices list.
+    ///
+    /// ```no_run
+    /// use sysinfo::AudioDevices;
+    ///
+    /// let audio_devices = AudioDevices::new_with_refreshed_list();
+    /// for device in audio_devices.list() {
+    ///     println!("{device:?}");
+    /// }
+    /// ```
+    pub fn list(&self) -> &[AudioDevice] {
+        &self.devices
+    }
+
+    /// Returns the audio devices list.
+    ///
+    /// ```no_run
+    /// use sysinfo::AudioDevices;
+    ///
+    /// let mut audio_devices = AudioDevices::new_with_refreshed_list();
+    /// audio_devices
+    ///     .list_mut()
+    ///     .sort_by(|device1, device2| device1.name().cmp(device2.name()));
+    /// ```
+    pub fn list_mut(&mut self) -> &mut [AudioDevice] {
+        &mut self.devices
+    }
+
+    /// The audio devices list will be emptied then completely recomputed.
+    ///
+    /// ```no_run
+    /// use sysinfo::AudioDevices;
+    ///
+    /// let mut audio_devices = AudioDevices::new();
+    /// audio_devices.refresh();
+    /// ```
+    pub fn refresh(&mut self) {
+        crate::sys::get_audio_devices(&mut self.devices);
+    }
+}
+
+/// Type containing audio device information.
+///
+/// A device able to both play and capture audio is listed twice: once as
+/// [`AudioDeviceKind::Output`] and once as [`AudioDeviceKind::Input`].
+///
+/// ```no_run
+/// use sysinfo::AudioDevices;
+///
+/// let audio_devices = AudioDevices::new_with_refreshed_list();
+/// for device in audio_devices.list() {
+///     println!(
+///         "{} ({:?}){}",
+///         device.name(),
+///         device.kind(),
+///         if device.is_default() { " [default]" } else { "" },
+///     );
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AudioDevice {
+    pub(crate) id: String,
+    pub(crate) name: String,
+    pub(crate) kind: AudioDeviceKind,
+    pub(crate) is_default: bool,
+}
+
+impl AudioDevice {
+    /// Returns the platform identifier of the device.
+    ///
+    /// ## Linux
+    ///
+    /// The ALSA PCM device (like `hw:0,0`).
+    ///
+    /// ## FreeBSD
+    ///
+    /// The `pcm` device (like `pcm0`).
+    ///
+    /// ## macOS
+    ///
+    /// The CoreAudio device UID.
+    ///
+    /// ## Windows
+    ///
+    /// The endpoint ID.
+    ///
+    /// ```no_run
+    /// use sysinfo::AudioDevices;
+    ///
+    /// let audio_devices = AudioDevices::new_with_refreshed_list();
+    /// for device in audio_devices.list() {
+    ///     println!("{}", device.id());
+    /// }
+    /// ```
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Returns the human readable name of the device.
+    ///
+    /// ```no_run
+    /// use sysinfo::AudioDevices;
+    ///
+    /// let audio_devices = AudioDevices::new_with_refreshed_list();
+    /// for device in audio_devices.list() {
+    ///     println!("{}", device.name());
+    /// }
+    /// ```
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns whether this device is used to play or to capture audio.
+    ///
+    /// ```no_run
+    /// use sysinfo::AudioDevices;
+    ///
+    /// let audio_devices = AudioDevices::new_with_refreshed_list();
+    /// for device in audio_devices.list() {
+    ///     println!("{:?}", device.kind());
+    /// }
+    /// ```
+    pub fn kind(&self) -> AudioDeviceKind {
+        self.kind
+    }
+
+    /// Returns `true` if this device is the default one for its [`AudioDeviceKind`].
+    ///
+    /// ## Linux
+    ///
+    /// Sound servers like PulseAudio or PipeWire have their own default device which cannot be
+    /// retrieved, so the ALSA default (the first device of the first card) is used.
+    ///
+    /// ```no_run
+    /// use sysinfo::AudioDevices;
+    ///
+    /// let audio_devices = AudioDevices::new_with_refreshed_list();
+    /// for device in audio_devices.list() {
+    ///     println!("{}", device.is_default());
+    /// }
+    /// ```
+    pub fn is_default(&self) -> bool {
+        self.is_default
+    }
+}
+
+/// Enum describing the direction of an audio device. It is returned by [`AudioDevice::kind`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub enum AudioDeviceKind {
+    /// Device capturing audio, like a microphone.
+    Input,
+    /// Device playing audio, like speakers.
+    Output,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn check_list() {
+        let mut devices = AudioDevices::new();
+        assert!(devices.list().is_empty());
+        devices.refresh();
+        for kind in [AudioDeviceKind::Input, AudioDeviceKind::Output] {
+            // There can only be one default device for each kind.
+            assert!(
+                devices
+                    .iter()
+                    .filter(|d| d.kind() == kind && d.is_default())
+                    .count()
+                    <= 1
+            );
+        }
+    }
+}
@@ -2,6 +2,8 @@
 
 use crate::{ComponentInner, ComponentsInner};
 
+use std::collections::HashMap;
+
 /// Interacting with components.
 ///
 /// ```no_run
@@ -14,6 +16,7 @@ use crate::{ComponentInner, ComponentsInner};
 /// ```
 pub struct Components {
     pub(crate) inner: ComponentsInner,
+    threshold_watches: Vec<ThresholdWatch>,
 }
 
 impl Default for Components {
@@ -32,6 +35,7 @@ impl From<Vec<Component>> for Components {
     fn from(components: Vec<Component>) -> Self {
         Self {
             inner: ComponentsInner::from_vec(components),
+            threshold_watches: Vec::new(),
         }
     }
 }
@@ -86,6 +90,7 @@ impl Components {
     pub fn new() -> Self {
         Self {
             inner: ComponentsInner::new(),
+            threshold_watches: Vec::new(),
         }
     }
 
@@ -156,6 +161,267 @@ impl Components {
                 true
             });
         }
+        for component in self.inner.list_mut() {
+            component.update_max_observed();
+        }
+        for watch in &mut self.threshold_watches {
+            watch.check(self.inner.list());
+        }
+    }
+
+    /// Registers `callback` to be called by [`Components::refresh`] when the temperature of the
+    /// components whose [`label`][Component::label] or [`id`][Component::id] is `component`
+    /// crosses one of the `thresholds`. It is called with the new [`ThresholdLevel`] of the
+    /// component, including when it goes back to [`ThresholdLevel::Normal`].
+    ///
+    /// ```no_run
+    /// use sysinfo::{Components, TemperatureThresholds, ThresholdLevel};
+    ///
+    /// let mut components = Components::new_with_refreshed_list();
+    /// components.watch_thresholds(
+    ///     "coretemp Package id 0",
+    ///     TemperatureThresholds::new().with_high(80.).with_hysteresis(5.),
+    ///     |component, level| {
+    ///         if level == ThresholdLevel::Critical {
+    ///             eprintln!("{} is overheating!", component.label());
+    ///         }
+    ///     },
+    /// );
+    /// loop {
+    ///     std::thread::sleep(std::time::Duration::from_secs(1));
+    ///     components.refresh(false);
+    /// }
+    /// ```
+    pub fn watch_thresholds<F>(
+        &mut self,
+        component: impl Into<String>,
+        thresholds: TemperatureThresholds,
+        callback: F,
+    ) where
+        F: FnMut(&Component, ThresholdLevel) + Send + Sync + 'static,
+    {
+        self.threshold_watches.push(ThresholdWatch {
+            component: component.into(),
+            thresholds,
+            callback: Box::new(callback),
+            levels: HashMap::new(),
+        });
+    }
+}
+
+/// Temperature thresholds watched by [`Components::watch_thresholds`] (in celsius degree).
+///
+/// ```no_run
+/// use sysinfo::TemperatureThresholds;
+///
+/// let thresholds = TemperatureThresholds::new()
+///     .with_high(80.)
+///     .with_critical(95.)
+///     .with_hysteresis(5.);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TemperatureThresholds {
+    high: Option<f32>,
+    critical: Option<f32>,
+    hysteresis: f32,
+}
+
+impl Default for TemperatureThresholds {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TemperatureThresholds {
+    /// Creates thresholds without a high temperature, using the
+    /// [critical temperature][Component::critical] of the components and an hysteresis of 2°C.
+    ///
+    /// ```no_run
+    /// use sysinfo::TemperatureThresholds;
+    ///
+    /// let thresholds = TemperatureThresholds::new();
+    /// assert_eq!(thresholds.high(), None);
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            high: None,
+            critical: None,
+            hysteresis: 2.,
+        }
+    }
+
+    /// Sets the temperature above which a component is at the [`ThresholdLevel::High`] level.
+    ///
+    /// ```no_run
+    /// use sysinfo::TemperatureThresholds;
+    ///
+    /// let thresholds = TemperatureThresholds::new().with_high(80.);
+    /// assert_eq!(thresholds.high(), Some(80.));
+    /// ```
+    pub fn with_high(mut self, high: f32) -> Self {
+        self.high = Some(high);
+        self
+    }
+
+    /// Sets the temperature above which a component is at the [`ThresholdLevel::Critical`]
+    /// level, instead of its [critical temperature][Component::critical].
+    ///
+    /// ```no_run
+    /// use sysinfo::TemperatureThresholds;
+    ///
+    /// let thresholds = TemperatureThresholds::new().with_critical(95.);
+    /// assert_eq!(thresholds.critical(), Some(95.));
+    /// ```
+    pub fn with_critical(mut self, critical: f32) -> Self {
+        self.critical = Some(critical);
+        self
+    }
+
+    /// Sets how many degrees the temperature needs to go below a threshold to leave its level,
+    /// so a temperature oscillating around a threshold doesn't trigger the callback at every
+    /// refresh.
+    ///
+    /// ```no_run
+    /// use sysinfo::TemperatureThresholds;
+    ///
+    /// let thresholds = TemperatureThresholds::new().with_hysteresis(5.);
+    /// assert_eq!(thresholds.hysteresis(), 5.);
+    /// ```
+    pub fn with_hysteresis(mut self, hysteresis: f32) -> Self {
+        self.hysteresis = hysteresis.max(0.);
+        self
+    }
+
+    /// Returns the high temperature threshold.
+    ///
+    /// ```no_run
+    /// use sysinfo::TemperatureThresholds;
+    ///
+    /// assert_eq!(TemperatureThresholds::new().high(), None);
+    /// ```
+    pub fn high(&self) -> Option<f32> {
+        self.high
+    }
+
+    /// Returns the critical temperature threshold. If `None`, the
+    /// [critical temperature][Component::critical] of the components is used.
+    ///
+    /// ```no_run
+    /// use sysinfo::TemperatureThresholds;
+    ///
+    /// assert_eq!(TemperatureThresholds::new().critical(), None);
+    /// ```
+    pub fn critical(&self) -> Option<f32> {
+        self.critical
+    }
+
+    /// Returns the hysteresis (in celsius degree).
+    ///
+    /// ```no_run
+    /// use sysinfo::TemperatureThresholds;
+    ///
+    /// assert_eq!(TemperatureThresholds::new().hysteresis(), 2.);
+    /// ```
+    pub fn hysteresis(&self) -> f32 {
+        self.hysteresis
+    }
+
+    fn level(
+        &self,
+        current: ThresholdLevel,
+        temperature: f32,
+        critical: Option<f32>,
+    ) -> ThresholdLevel {
+        // Once a level is reached, it is only left when the temperature goes below its
+        // threshold minus the hysteresis.
+        let is_above = |threshold: Option<f32>, level: ThresholdLevel| {
+            threshold.is_some_and(|threshold| {
+                if current >= level {
+                    temperature >= threshold - self.hysteresis
+                } else {
+                    temperature >= threshold
+                }
+            })
+        };
+        if is_above(self.critical.or(critical), ThresholdLevel::Critical) {
+            ThresholdLevel::Critical
+        } else if is_above(self.high, ThresholdLevel::High) {
+            ThresholdLevel::High
+        } else {
+            ThresholdLevel::Normal
+        }
+    }
+}
+
+/// Level of the temperature of a component, compared to its [`TemperatureThresholds`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub enum ThresholdLevel {
+    /// The temperature is below all thresholds.
+    Normal,
+    /// The temperature is above the high threshold.
+    High,
+    /// The temperature is above the critical threshold.
+    Critical,
+}
+
+/// Where a value of a [`Component`] comes from.
+///
+/// ```no_run
+/// use sysinfo::{Components, ValueSource};
+///
+/// let components = Components::new_with_refreshed_list();
+/// for component in &components {
+///     if component.max_source() == Some(ValueSource::Hardware) {
+///         println!("{}: {:?}°C", component.label(), component.max());
+///     }
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub enum ValueSource {
+    /// The value is provided by the hardware (or its driver).
+    Hardware,
+    /// The value is computed by `sysinfo` from the temperatures it retrieved.
+    Inferred,
+}
+
+type ThresholdCallback = Box<dyn FnMut(&Component, ThresholdLevel) + Send + Sync>;
+
+struct ThresholdWatch {
+    component: String,
+    thresholds: TemperatureThresholds,
+    callback: ThresholdCallback,
+    /// Current level of each watched component, identified by its label and ID.
+    levels: HashMap<(String, Option<String>), ThresholdLevel>,
+}
+
+impl ThresholdWatch {
+    fn check(&mut self, components: &[Component]) {
+        for component in components {
+            if component.label() != self.component && component.id() != Some(&self.component) {
+                continue;
+            }
+            let Some(temperature) = component.temperature().filter(|t| !t.is_nan()) else {
+                continue;
+            };
+            let level = self
+                .levels
+                .entry((
+                    component.label().to_owned(),
+                    component.id().map(str::to_owned),
+                ))
+                .or_insert(ThresholdLevel::Normal);
+            let new_level = self.thresholds.level(
+                *level,
+                temperature,
+                component.critical().filter(|c| !c.is_nan()),
+            );
+            if new_level != *level {
+                *level = new_level;
+                (self.callback)(component, new_level);
+            }
+        }
     }
 }
 
@@ -175,6 +441,7 @@ impl Components {
 /// ```
 pub struct Component {
     pub(crate) inner: ComponentInner,
+    pub(crate) max_observed: Option<f32>,
 }
 
 impl Component {
@@ -242,6 +509,41 @@ impl Component {
         self.inner.critical()
     }
 
+    /// Returns where the value returned by [`Component::max`] comes from, or `None` if there is
+    /// no maximum temperature.
+    ///
+    /// ```no_run
+    /// use sysinfo::Components;
+    ///
+    /// let components = Components::new_with_refreshed_list();
+    /// for component in &components {
+    ///     println!("{}: {:?} ({:?})", component.label(), component.max(), component.max_source());
+    /// }
+    /// ```
+    pub fn max_source(&self) -> Option<ValueSource> {
+        self.inner.max_source()
+    }
+
+    /// Returns where the value returned by [`Component::critical`] comes from, or `None` if
+    /// there is no critical temperature.
+    ///
+    /// ```no_run
+    /// use sysinfo::Components;
+    ///
+    /// let components = Components::new_with_refreshed_list();
+    /// for component in &components {
+    ///     println!(
+    ///         "{}: {:?} ({:?})",
+    ///         component.label(),
+    ///         component.critical(),
+    ///         component.critical_source(),
+    ///     );
+    /// }
+    /// ```
+    pub fn critical_source(&self) -> Option<ValueSource> {
+        self.inner.critical_source()
+    }
+
     /// Returns the label of the component.
     ///
     /// ## Linux
@@ -269,14 +571,58 @@ impl Component {
         self.inner.label()
     }
 
+    /// Returns the label of the sensor as provided by the hardware or its driver, unlike
+    /// [`Component::label`] which may be generated by `sysinfo`.
+    ///
+    /// On Linux, it is the content of the `tempN_label` file of the `hwmon` device. Several
+    /// sensors can have the same raw label (like `Package id 0` for each CPU package), use it
+    /// along with [`Component::chip`] and [`Component::id`] to tell them apart.
+    ///
+    /// ⚠️ It always returns `None` on other platforms than Linux and macOS (arm).
+    ///
+    /// ```no_run
+    /// use sysinfo::Components;
+    ///
+    /// let components = Components::new_with_refreshed_list();
+    /// for component in &components {
+    ///     println!("{:?} {:?}", component.chip(), component.raw_label());
+    /// }
+    /// ```
+    pub fn raw_label(&self) -> Option<&str> {
+        self.inner.raw_label()
+    }
+
+    /// Returns the name of the chip (or driver) providing the sensor.
+    ///
+    /// On Linux, it is the `name` of the `hwmon` device (like `coretemp` or `k10temp`) or the
+    /// type of the thermal zone.
+    ///
+    /// ⚠️ It always returns `None` on other platforms than Linux.
+    ///
+    /// ```no_run
+    /// use sysinfo::Components;
+    ///
+    /// let components = Components::new_with_refreshed_list();
+    /// for component in &components {
+    ///     if let Some(chip) = component.chip() {
+    ///         println!("{}: {chip}", component.label());
+    ///     }
+    /// }
+    /// ```
+    pub fn chip(&self) -> Option<&str> {
+        self.inner.chip()
+    }
+
     /// Returns the identifier of the component.
     ///
-    /// Note: The identifier should be reasonably unique but is provided by the kernel.
-    /// It could change if the hardware changes or after a reboot.
+    /// Unlike the [label][Component::label], it is unique and doesn't change across refreshes
+    /// and reboots (unless the hardware changes), so it can be used to store the history of the
+    /// temperature of the components.
     ///
     /// | OS | Computed ID by `sysinfo` | Example |
     /// |----|--------------------------|----------|
-    /// | Linux/hwmon | hwmon file concatenated with the temp index. | ` hwmon0_1` if the temperature data comes from the `hwmon0/temp1_input` file. |
+    /// | Linux/hwmon | Path of the hwmon device in `/sys/devices` concatenated with the temp index. | `pci0000:00/0000:00:18.3/temp1` if the temperature data comes from the `temp1_input` file of the `0000:00:18.3` PCI device. |
+    /// | Linux/hwmon (without device) | hwmon file concatenated with the temp index. | `hwmon0_1` if the temperature data comes from the `hwmon0/temp1_input` file. |
     /// | Linux/thermal | thermal file name | `thermal_zone0` |
     /// | FreeBSD | `cpu_` concatenated with the core index. | `cpu_1` for the first core. |
     /// | macOS/arm | Serial ID reported by the HID driver. | |
@@ -316,7 +662,54 @@ impl Component {
     /// }
     /// ```
     pub fn refresh(&mut self) {
-        self.inner.refresh()
+        self.inner.refresh();
+        self.update_max_observed();
+    }
+
+    /// Returns the highest temperature of the component (in celsius degree) retrieved by
+    /// `sysinfo` since the component was listed or since the last call to
+    /// [`Component::reset_max_observed`].
+    ///
+    /// Unlike [`Component::max`], which may come from the hardware and include temperatures
+    /// reached before the program started, it is only computed from the values retrieved by
+    /// [`Component::refresh`] and [`Components::refresh`].
+    ///
+    /// ```no_run
+    /// use sysinfo::Components;
+    ///
+    /// let components = Components::new_with_refreshed_list();
+    /// for component in &components {
+    ///     if let Some(max) = component.max_observed() {
+    ///         println!("{}: {max}°C", component.label());
+    ///     }
+    /// }
+    /// ```
+    pub fn max_observed(&self) -> Option<f32> {
+        self.max_observed
+    }
+
+    /// Resets the value returned by [`Component::max_observed`] to the current temperature.
+    ///
+    /// ```no_run
+    /// use sysinfo::Components;
+    ///
+    /// let mut components = Components::new_with_refreshed_list();
+    /// for component in components.iter_mut() {
+    ///     component.reset_max_observed();
+    /// }
+    /// ```
+    pub fn reset_max_observed(&mut self) {
+        self.max_observed = None;
+        self.update_max_observed();
+    }
+
+    fn update_max_observed(&mut self) {
+        if let Some(temperature) = self.temperature().filter(|t| !t.is_nan()) {
+            self.max_observed = Some(
+                self.max_observed
+                    .map_or(temperature, |max| max.max(temperature)),
+            );
+        }
     }
 }
 
@@ -324,6 +717,38 @@ impl Component {
 mod tests {
     use crate::*;
 
+    #[test]
+    fn check_threshold_level() {
+        let thresholds = TemperatureThresholds::new()
+            .with_high(80.)
+            .with_hysteresis(5.);
+        let mut level = ThresholdLevel::Normal;
+        let mut levels = Vec::new();
+        for temperature in [70., 80., 76., 74., 90., 99., 97., 60.] {
+            level = thresholds.level(level, temperature, Some(98.));
+            levels.push(level);
+        }
+        assert_eq!(
+            levels,
+            [
+                ThresholdLevel::Normal,
+                ThresholdLevel::High,
+                // Above `high - hysteresis`.
+                ThresholdLevel::High,
+                ThresholdLevel::Normal,
+                ThresholdLevel::High,
+                ThresholdLevel::Critical,
+                ThresholdLevel::Critical,
+                ThresholdLevel::Normal,
+            ]
+        );
+        // Without critical temperature.
+        assert_eq!(
+            thresholds.level(ThresholdLevel::Normal, 200., None),
+            ThresholdLevel::High
+        );
+    }
+
     #[test]
     fn test_components_mac_m1() {
         let mut components = Components::new();
@@ -2,10 +2,11 @@
 
 use std::ffi::OsStr;
 use std::fmt;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 
-use crate::DiskUsage;
 use crate::common::impl_get_set::impl_get_set;
+use crate::{DiskUsage, RefreshError, RefreshTimestamp};
 
 /// Struct containing a disk information.
 ///
@@ -177,6 +178,53 @@ impl Disk {
     pub fn usage(&self) -> DiskUsage {
         self.inner.usage()
     }
+
+    /// Returns the statistics of the network file system (like the number of operations and
+    /// retransmissions or the round-trip time) since it was mounted, or `None` if it is not a
+    /// network file system.
+    ///
+    /// It is updated with [`Disk::usage`] (see [`DiskRefreshKind::io_usage`]).
+    ///
+    /// ⚠️ It is only available for NFS mounts on Linux (from `/proc/self/mountstats`), which
+    /// are only listed if the `linux-netdevs` feature is enabled. It always returns `None` on
+    /// other systems.
+    ///
+    /// ```no_run
+    /// use sysinfo::Disks;
+    ///
+    /// let disks = Disks::new_with_refreshed_list();
+    /// for disk in disks.list() {
+    ///     if let Some(stats) = disk.network_fs_stats() {
+    ///         println!("{:?}: {:?}", disk.mount_point(), stats.average_rtt());
+    ///     }
+    /// }
+    /// ```
+    pub fn network_fs_stats(&self) -> Option<NetworkFsStats> {
+        self.inner.network_fs_stats()
+    }
+
+    /// Returns the latency, queue depth and utilization of the disk between the last two
+    /// refreshes of [`Disk::usage`] (see [`DiskRefreshKind::io_usage`]), or `None` if it wasn't
+    /// refreshed twice yet.
+    ///
+    /// ⚠️ It is only available on Linux (from `/proc/diskstats`). It always returns `None` on
+    /// other systems.
+    ///
+    /// ```no_run
+    /// use sysinfo::{DiskRefreshKind, Disks};
+    ///
+    /// let mut disks = Disks::new_with_refreshed_list();
+    /// std::thread::sleep(std::time::Duration::from_secs(1));
+    /// disks.refresh_specifics(true, DiskRefreshKind::nothing().with_io_usage());
+    /// for disk in disks.list() {
+    ///     if let Some(stats) = disk.io_stats() {
+    ///         println!("{:?}: {:.1}% busy", disk.name(), stats.utilization());
+    ///     }
+    /// }
+    /// ```
+    pub fn io_stats(&self) -> Option<DiskIoStats> {
+        self.inner.io_stats()
+    }
 }
 
 /// Disks interface.
@@ -199,7 +247,8 @@ impl Disk {
 /// circumstances. For example, if a CIFS or NFS share has been mounted with
 /// the _hard_ option, but the connection has an error, such as the share server has stopped.
 pub struct Disks {
-    inner: crate::DisksInner,
+    pub(crate) inner: crate::DisksInner,
+    pub(crate) last_refreshed_at: Option<RefreshTimestamp>,
 }
 
 impl Default for Disks {
@@ -218,6 +267,7 @@ impl From<Vec<Disk>> for Disks {
     fn from(disks: Vec<Disk>) -> Self {
         Self {
             inner: crate::DisksInner::from_vec(disks),
+            last_refreshed_at: None,
         }
     }
 }
@@ -257,6 +307,7 @@ impl Disks {
     pub fn new() -> Self {
         Self {
             inner: crate::DisksInner::new(),
+            last_refreshed_at: None,
         }
     }
 
@@ -326,8 +377,7 @@ impl Disks {
     ///
     /// Equivalent to <code>[Disks::refresh_specifics]\([DiskRefreshKind::everything]\())</code>.
     pub fn refresh(&mut self, remove_not_listed_disks: bool) {
-        self.inner
-            .refresh_specifics(remove_not_listed_disks, DiskRefreshKind::everything());
+        self.refresh_specifics(remove_not_listed_disks, DiskRefreshKind::everything());
     }
 
     /// Refreshes the disks' information according to the given [`DiskRefreshKind`].
@@ -342,6 +392,78 @@ impl Disks {
     pub fn refresh_specifics(&mut self, remove_not_listed_disks: bool, refreshes: DiskRefreshKind) {
         self.inner
             .refresh_specifics(remove_not_listed_disks, refreshes);
+        self.last_refreshed_at = Some(RefreshTimestamp::now());
+    }
+
+    /// Same as [`Disks::refresh_specifics`] but returns an error if the disks cannot be listed
+    /// at all instead of considering that there are none.
+    ///
+    /// If an error is returned, the disks list is left untouched, even if
+    /// `remove_not_listed_disks` is `true`.
+    ///
+    /// ⚠️ Errors are only detected on Linux and Redox, where they come from reading and parsing
+    /// the source of the information. It never fails on the other platforms (the failures of
+    /// their system calls aren't reported) and always returns [`RefreshError::Unsupported`] on
+    /// unsupported platforms.
+    ///
+    /// ```no_run
+    /// use sysinfo::{DiskRefreshKind, Disks};
+    ///
+    /// let mut disks = Disks::new();
+    /// if let Err(error) = disks.try_refresh_specifics(true, DiskRefreshKind::everything()) {
+    ///     eprintln!("failed to list disks: {error}");
+    /// }
+    /// ```
+    pub fn try_refresh_specifics(
+        &mut self,
+        remove_not_listed_disks: bool,
+        refreshes: DiskRefreshKind,
+    ) -> Result<(), RefreshError> {
+        self.inner
+            .try_refresh_specifics(remove_not_listed_disks, refreshes)?;
+        self.last_refreshed_at = Some(RefreshTimestamp::now());
+        Ok(())
+    }
+
+    /// Returns when the disks list was last refreshed, or `None` if it was never refreshed.
+    ///
+    /// ```no_run
+    /// use sysinfo::Disks;
+    ///
+    /// let disks = Disks::new_with_refreshed_list();
+    /// if let Some(at) = disks.last_refreshed_at() {
+    ///     println!("Disks refreshed at {:?}", at.system_time());
+    /// }
+    /// ```
+    pub fn last_refreshed_at(&self) -> Option<RefreshTimestamp> {
+        self.last_refreshed_at
+    }
+
+    /// Returns the space of the file system containing `path`, along with the mount point it
+    /// belongs to, or `None` if it couldn't be retrieved (if `path` doesn't exist for example).
+    ///
+    /// The mount point is resolved by the system, so it works with bind mounts and with file
+    /// systems which aren't listed by [`Disks`] (like `tmpfs` on Linux).
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ⚠️ It always returns `None` on unsupported platforms.
+    ///
+    /// ```no_run
+    /// use std::path::Path;
+    /// use sysinfo::Disks;
+    ///
+    /// if let Some(space) = Disks::usage_for_path(Path::new("/home")) {
+    ///     println!(
+    ///         "{:?}: {} bytes available out of {}",
+    ///         space.mount_point(),
+    ///         space.available_space(),
+    ///         space.total_space(),
+    ///     );
+    /// }
+    /// ```
+    pub fn usage_for_path(path: &Path) -> Option<DiskSpace> {
+        crate::DisksInner::usage_for_path(path)
     }
 }
 
@@ -359,6 +481,252 @@ impl std::ops::DerefMut for Disks {
     }
 }
 
+/// Space of the file system containing a path. It is returned by [`Disks::usage_for_path`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DiskSpace {
+    pub(crate) mount_point: PathBuf,
+    pub(crate) total_space: u64,
+    pub(crate) available_space: u64,
+    pub(crate) used_space: u64,
+}
+
+impl DiskSpace {
+    /// Returns the mount point of the file system.
+    ///
+    /// ```no_run
+    /// use std::path::Path;
+    /// use sysinfo::Disks;
+    ///
+    /// if let Some(space) = Disks::usage_for_path(Path::new("/home")) {
+    ///     println!("{:?}", space.mount_point());
+    /// }
+    /// ```
+    pub fn mount_point(&self) -> &Path {
+        &self.mount_point
+    }
+
+    /// Returns the total space of the file system, in bytes.
+    ///
+    /// ```no_run
+    /// use std::path::Path;
+    /// use sysinfo::Disks;
+    ///
+    /// if let Some(space) = Disks::usage_for_path(Path::new("/home")) {
+    ///     println!("{}", space.total_space());
+    /// }
+    /// ```
+    pub fn total_space(&self) -> u64 {
+        self.total_space
+    }
+
+    /// Returns the space available to the current user, in bytes.
+    ///
+    /// ```no_run
+    /// use std::path::Path;
+    /// use sysinfo::Disks;
+    ///
+    /// if let Some(space) = Disks::usage_for_path(Path::new("/home")) {
+    ///     println!("{}", space.available_space());
+    /// }
+    /// ```
+    pub fn available_space(&self) -> u64 {
+        self.available_space
+    }
+
+    /// Returns the used space, in bytes.
+    ///
+    /// It can be lower than `total_space - available_space` because some space can be reserved
+    /// (for the root user for example).
+    ///
+    /// ```no_run
+    /// use std::path::Path;
+    /// use sysinfo::Disks;
+    ///
+    /// if let Some(space) = Disks::usage_for_path(Path::new("/home")) {
+    ///     println!("{}", space.used_space());
+    /// }
+    /// ```
+    pub fn used_space(&self) -> u64 {
+        self.used_space
+    }
+}
+
+/// I/O statistics of a disk between two refreshes. It is returned by [`Disk::io_stats`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct DiskIoStats {
+    pub(crate) average_latency: Duration,
+    pub(crate) in_flight: u64,
+    pub(crate) utilization: f32,
+    pub(crate) average_queue_depth: f32,
+}
+
+impl DiskIoStats {
+    /// Returns the average time the read and write requests completed between the two refreshes
+    /// took, including the time spent waiting in the queue, or [`Duration::ZERO`] if there was
+    /// no request.
+    ///
+    /// ```no_run
+    /// use sysinfo::Disks;
+    ///
+    /// for disk in Disks::new_with_refreshed_list().list() {
+    ///     if let Some(stats) = disk.io_stats() {
+    ///         println!("{:?}: {:?}", disk.name(), stats.average_latency());
+    ///     }
+    /// }
+    /// ```
+    pub fn average_latency(&self) -> Duration {
+        self.average_latency
+    }
+
+    /// Returns the number of requests which were being processed by the disk during the last
+    /// refresh.
+    ///
+    /// ```no_run
+    /// use sysinfo::Disks;
+    ///
+    /// for disk in Disks::new_with_refreshed_list().list() {
+    ///     if let Some(stats) = disk.io_stats() {
+    ///         println!("{:?}: {} requests in flight", disk.name(), stats.in_flight());
+    ///     }
+    /// }
+    /// ```
+    pub fn in_flight(&self) -> u64 {
+        self.in_flight
+    }
+
+    /// Returns the percentage of time (between `0.` and `100.`) the disk was busy processing
+    /// requests between the two refreshes. A disk close to 100% is saturated, unless it can
+    /// process multiple requests in parallel (like SSDs).
+    ///
+    /// ```no_run
+    /// use sysinfo::Disks;
+    ///
+    /// for disk in Disks::new_with_refreshed_list().list() {
+    ///     if let Some(stats) = disk.io_stats() {
+    ///         println!("{:?}: {:.1}%", disk.name(), stats.utilization());
+    ///     }
+    /// }
+    /// ```
+    pub fn utilization(&self) -> f32 {
+        self.utilization
+    }
+
+    /// Returns the average number of requests which were waiting or being processed between
+    /// the two refreshes.
+    ///
+    /// ```no_run
+    /// use sysinfo::Disks;
+    ///
+    /// for disk in Disks::new_with_refreshed_list().list() {
+    ///     if let Some(stats) = disk.io_stats() {
+    ///         println!("{:?}: {:.2}", disk.name(), stats.average_queue_depth());
+    ///     }
+    /// }
+    /// ```
+    pub fn average_queue_depth(&self) -> f32 {
+        self.average_queue_depth
+    }
+}
+
+/// Statistics of a network file system. It is returned by [`Disk::network_fs_stats`].
+///
+/// All the values are accumulated since the file system was mounted.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct NetworkFsStats {
+    pub(crate) operations: u64,
+    pub(crate) transmissions: u64,
+    pub(crate) timeouts: u64,
+    pub(crate) rtt_ms: u64,
+    pub(crate) execute_ms: u64,
+}
+
+impl NetworkFsStats {
+    /// Returns the number of operations (like reads, writes or lookups) sent to the server.
+    ///
+    /// ```no_run
+    /// use sysinfo::Disks;
+    ///
+    /// for disk in Disks::new_with_refreshed_list().list() {
+    ///     if let Some(stats) = disk.network_fs_stats() {
+    ///         println!("{:?}: {} operations", disk.mount_point(), stats.operations());
+    ///     }
+    /// }
+    /// ```
+    pub fn operations(&self) -> u64 {
+        self.operations
+    }
+
+    /// Returns the number of times operations had to be sent again because the server didn't
+    /// reply in time.
+    ///
+    /// ```no_run
+    /// use sysinfo::Disks;
+    ///
+    /// for disk in Disks::new_with_refreshed_list().list() {
+    ///     if let Some(stats) = disk.network_fs_stats() {
+    ///         println!("{:?}: {} retransmissions", disk.mount_point(), stats.retransmissions());
+    ///     }
+    /// }
+    /// ```
+    pub fn retransmissions(&self) -> u64 {
+        self.transmissions.saturating_sub(self.operations)
+    }
+
+    /// Returns the number of major timeouts.
+    ///
+    /// ```no_run
+    /// use sysinfo::Disks;
+    ///
+    /// for disk in Disks::new_with_refreshed_list().list() {
+    ///     if let Some(stats) = disk.network_fs_stats() {
+    ///         println!("{:?}: {} timeouts", disk.mount_point(), stats.timeouts());
+    ///     }
+    /// }
+    /// ```
+    pub fn timeouts(&self) -> u64 {
+        self.timeouts
+    }
+
+    /// Returns the average time between sending an operation and receiving its reply, or
+    /// [`Duration::ZERO`] if no operation was sent.
+    ///
+    /// ```no_run
+    /// use sysinfo::Disks;
+    ///
+    /// for disk in Disks::new_with_refreshed_list().list() {
+    ///     if let Some(stats) = disk.network_fs_stats() {
+    ///         println!("{:?}: {:?}", disk.mount_point(), stats.average_rtt());
+    ///     }
+    /// }
+    /// ```
+    pub fn average_rtt(&self) -> Duration {
+        average_ms(self.rtt_ms, self.operations)
+    }
+
+    /// Returns the average duration of an operation, including the time spent waiting to be
+    /// sent, or [`Duration::ZERO`] if no operation was sent.
+    ///
+    /// ```no_run
+    /// use sysinfo::Disks;
+    ///
+    /// for disk in Disks::new_with_refreshed_list().list() {
+    ///     if let Some(stats) = disk.network_fs_stats() {
+    ///         println!("{:?}: {:?}", disk.mount_point(), stats.average_execution_time());
+    ///     }
+    /// }
+    /// ```
+    pub fn average_execution_time(&self) -> Duration {
+        average_ms(self.execute_ms, self.operations)
+    }
+}
+
+fn average_ms(total_ms: u64, count: u64) -> Duration {
+    if count == 0 {
+        return Duration::ZERO;
+    }
+    Duration::from_secs_f64(total_ms as f64 / count as f64 / 1_000.)
+}
+
 /// Enum containing the different supported kinds of disks.
 ///
 /// This type is returned by [`Disk::kind`](`crate::Disk::kind`).
@@ -396,7 +764,8 @@ impl fmt::Display for DiskKind {
 ///
 /// * `kind` is about refreshing the [`Disk::kind`] information.
 /// * `storage` is about refreshing the [`Disk::available_space`] and [`Disk::total_space`] information.
-/// * `io_usage` is about refreshing the [`Disk::usage`] information.
+/// * `io_usage` is about refreshing the [`Disk::usage`], [`Disk::io_stats`] and
+///   [`Disk::network_fs_stats`] information.
 ///
 /// ```no_run
 /// use sysinfo::{Disks, DiskRefreshKind};
@@ -0,0 +1,368 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::DiskWatcherInner;
+use crate::common::watcher::EventReceiver;
+
+use std::ffi::{OsStr, OsString};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// Watches the file systems being mounted, unmounted or remounted (with different options, like
+/// read-only), so applications don't need to call [`Disks::refresh`][crate::Disks::refresh] in a
+/// loop to detect removable media.
+///
+/// The watch starts when it is created and runs in a background thread until it is dropped.
+///
+/// On Linux, the system notifies the changes of the mount table, so they're reported right
+/// away and, unlike [`Disks`][crate::Disks], all mounted file systems are reported, including
+/// virtual ones (like `tmpfs`).
+///
+/// On macOS and FreeBSD, the changes are notified through a `kqueue` too, then the disks are
+/// listed (like [`Disks`][crate::Disks] does, so virtual file systems are not reported) and
+/// compared with the previous list.
+///
+/// If the mount table cannot be read after a change, no event is reported for it (rather than
+/// reporting all file systems as unmounted).
+///
+/// ⚠️ On Windows and Redox, there is no such notification: the background thread lists the
+/// disks once per second and compares the list with the previous one. It means that:
+///
+///  * A change is reported up to one second after it happened.
+///  * A disk mounted and unmounted within the same second might not be reported at all.
+///  * Listing the disks has a (small) cost, paid every second as long as the watcher is alive.
+///
+/// On unsupported systems, [`DiskWatcher::start`] returns an error of kind
+/// [`io::ErrorKind::Unsupported`].
+///
+/// ```no_run
+/// use sysinfo::DiskWatcher;
+/// use std::time::Duration;
+///
+/// let mut watcher = DiskWatcher::start().expect("failed to watch disks");
+/// loop {
+///     watcher.wait(Duration::from_secs(60));
+///     for event in watcher.events() {
+///         println!("{:?}: {:?}", event.kind(), event.mount_point());
+///     }
+/// }
+/// ```
+pub struct DiskWatcher {
+    receiver: EventReceiver<DiskEvent>,
+    events: Vec<DiskEvent>,
+}
+
+impl DiskWatcher {
+    /// Starts watching the mount table. The events are only available after
+    /// [`DiskWatcher::refresh`] or [`DiskWatcher::wait`] is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::DiskWatcher;
+    ///
+    /// match DiskWatcher::start() {
+    ///     Ok(_watcher) => println!("watching disks"),
+    ///     Err(error) => eprintln!("cannot watch disks: {error}"),
+    /// }
+    /// ```
+    pub fn start() -> io::Result<Self> {
+        let mut inner = DiskWatcherInner::open()?;
+        let mut mounts = inner.mounts()?;
+        let receiver = EventReceiver::spawn("sysinfo-disk-watcher", move |timeout| {
+            if !inner.wait(timeout) {
+                return Vec::new();
+            }
+            // If the mount table cannot be read, comparing an empty list with the previous one
+            // would report all file systems as unmounted.
+            let new_mounts = match inner.mounts() {
+                Ok(new_mounts) => new_mounts,
+                Err(_error) => {
+                    sysinfo_debug!("failed to list the mounted file systems: {_error:?}");
+                    return Vec::new();
+                }
+            };
+            let events = diff_mounts(&mounts, &new_mounts);
+            mounts = new_mounts;
+            events
+        })?;
+        Ok(Self {
+            receiver,
+            events: Vec::new(),
+        })
+    }
+
+    /// Replaces the list of events with the ones which happened since the previous call to
+    /// this method (or to [`DiskWatcher::wait`]), without blocking.
+    ///
+    /// ```no_run
+    /// use sysinfo::DiskWatcher;
+    ///
+    /// let mut watcher = DiskWatcher::start().expect("failed to watch disks");
+    /// std::thread::sleep(std::time::Duration::from_secs(1));
+    /// watcher.refresh();
+    /// println!("{} events", watcher.events().len());
+    /// ```
+    pub fn refresh(&mut self) {
+        self.events = self.receiver.take();
+    }
+
+    /// Same as [`DiskWatcher::refresh`] but blocks until at least one event happens or until
+    /// `timeout` elapses.
+    ///
+    /// ```no_run
+    /// use sysinfo::DiskWatcher;
+    /// use std::time::Duration;
+    ///
+    /// let mut watcher = DiskWatcher::start().expect("failed to watch disks");
+    /// watcher.wait(Duration::from_secs(10));
+    /// if watcher.events().is_empty() {
+    ///     println!("nothing was mounted or unmounted");
+    /// }
+    /// ```
+    pub fn wait(&mut self, timeout: Duration) {
+        self.events = self.receiver.wait(timeout);
+    }
+
+    /// Returns the events retrieved by the last call to [`DiskWatcher::refresh`] or
+    /// [`DiskWatcher::wait`], in the order they happened.
+    ///
+    /// ```no_run
+    /// use sysinfo::DiskWatcher;
+    ///
+    /// let mut watcher = DiskWatcher::start().expect("failed to watch disks");
+    /// watcher.refresh();
+    /// for event in watcher.events() {
+    ///     println!("{event:?}");
+    /// }
+    /// ```
+    pub fn events(&self) -> &[DiskEvent] {
+        &self.events
+    }
+}
+
+/// Kind of a [`DiskEvent`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub enum DiskEventKind {
+    /// A file system was mounted.
+    Mounted,
+    /// A file system was unmounted.
+    Unmounted,
+    /// A file system was remounted with different options (like read-only).
+    Remounted,
+}
+
+/// A change of the mount table, retrieved by [`DiskWatcher`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DiskEvent {
+    pub(crate) kind: DiskEventKind,
+    pub(crate) name: OsString,
+    pub(crate) mount_point: PathBuf,
+    pub(crate) file_system: OsString,
+    pub(crate) is_read_only: bool,
+}
+
+impl DiskEvent {
+    /// Returns what happened to the file system.
+    pub fn kind(&self) -> DiskEventKind {
+        self.kind
+    }
+
+    /// Returns the name of the mounted device (like [`Disk::name`][crate::Disk::name]).
+    pub fn name(&self) -> &OsStr {
+        &self.name
+    }
+
+    /// Returns the mount point of the file system.
+    pub fn mount_point(&self) -> &Path {
+        &self.mount_point
+    }
+
+    /// Returns the file system (like `ext4` or `NTFS`).
+    pub fn file_system(&self) -> &OsStr {
+        &self.file_system
+    }
+
+    /// Returns `true` if the file system is mounted read-only. For
+    /// [`DiskEventKind::Unmounted`], it is the value before it was unmounted.
+    pub fn is_read_only(&self) -> bool {
+        self.is_read_only
+    }
+}
+
+/// A mounted file system, as listed by a `DiskWatcherInner`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct MountEntry {
+    /// Identifier of the mount if the system provides one.
+    pub(crate) id: Option<u64>,
+    pub(crate) name: OsString,
+    pub(crate) mount_point: PathBuf,
+    pub(crate) file_system: OsString,
+    pub(crate) is_read_only: bool,
+    /// Mount options, used to detect remounts.
+    pub(crate) options: String,
+}
+
+impl MountEntry {
+    fn is_same_mount(&self, other: &Self) -> bool {
+        match (self.id, other.id) {
+            (Some(id), Some(other_id)) => id == other_id,
+            _ => {
+                self.mount_point == other.mount_point
+                    && self.name == other.name
+                    && self.file_system == other.file_system
+            }
+        }
+    }
+
+    fn to_event(&self, kind: DiskEventKind) -> DiskEvent {
+        DiskEvent {
+            kind,
+            name: self.name.clone(),
+            mount_point: self.mount_point.clone(),
+            file_system: self.file_system.clone(),
+            is_read_only: self.is_read_only,
+        }
+    }
+}
+
+fn diff_mounts(old: &[MountEntry], new: &[MountEntry]) -> Vec<DiskEvent> {
+    let mut events = Vec::new();
+    for entry in old {
+        if !new.iter().any(|new_entry| new_entry.is_same_mount(entry)) {
+            events.push(entry.to_event(DiskEventKind::Unmounted));
+        }
+    }
+    for entry in new {
+        match old.iter().find(|old_entry| old_entry.is_same_mount(entry)) {
+            None => events.push(entry.to_event(DiskEventKind::Mounted)),
+            Some(old_entry)
+                if old_entry.options != entry.options
+                    || old_entry.is_read_only != entry.is_read_only =>
+            {
+                events.push(entry.to_event(DiskEventKind::Remounted))
+            }
+            Some(_) => {}
+        }
+    }
+    events
+}
+
+/// Watches the mount table by listing the disks every second, for the systems which don't
+/// notify its changes.
+#[cfg(all(
+    any(target_os = "redox", windows),
+    not(feature = "unknown-ci"),
+    not(sysinfo_test_backend)
+))]
+pub(crate) struct PollingDiskWatcherInner {
+    last_listing: Option<std::time::Instant>,
+}
+
+#[cfg(all(
+    any(target_os = "redox", windows),
+    not(feature = "unknown-ci"),
+    not(sysinfo_test_backend)
+))]
+impl PollingDiskWatcherInner {
+    const INTERVAL: Duration = Duration::from_secs(1);
+
+    pub(crate) fn open() -> io::Result<Self> {
+        Ok(Self { last_listing: None })
+    }
+
+    pub(crate) fn wait(&mut self, timeout: Duration) -> bool {
+        std::thread::sleep(timeout);
+        self.last_listing
+            .is_none_or(|last_listing| last_listing.elapsed() >= Self::INTERVAL)
+    }
+
+    pub(crate) fn mounts(&mut self) -> io::Result<Vec<MountEntry>> {
+        self.last_listing = Some(std::time::Instant::now());
+        list_disks_mounts()
+    }
+}
+
+/// Returns the file systems listed by [`Disks`][crate::Disks], for the systems which don't
+/// provide the mount table in a file.
+#[cfg(all(
+    any(
+        target_os = "macos",
+        target_os = "ios",
+        target_os = "freebsd",
+        target_os = "redox",
+        windows
+    ),
+    not(feature = "unknown-ci"),
+    not(sysinfo_test_backend)
+))]
+pub(crate) fn list_disks_mounts() -> io::Result<Vec<MountEntry>> {
+    let mut disks = crate::Disks::new();
+    disks
+        .try_refresh_specifics(false, crate::DiskRefreshKind::nothing())
+        .map_err(io::Error::other)?;
+    Ok(disks
+        .list()
+        .iter()
+        .map(|disk| MountEntry {
+            id: None,
+            name: disk.name().to_owned(),
+            mount_point: disk.mount_point().to_owned(),
+            file_system: disk.file_system().to_owned(),
+            is_read_only: disk.is_read_only(),
+            options: String::new(),
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(id: Option<u64>, mount_point: &str, options: &str) -> MountEntry {
+        MountEntry {
+            id,
+            name: "/dev/sda1".into(),
+            mount_point: mount_point.into(),
+            file_system: "ext4".into(),
+            is_read_only: options.starts_with("ro"),
+            options: options.to_owned(),
+        }
+    }
+
+    #[test]
+    fn check_diff_mounts() {
+        let old = [
+            entry(Some(1), "/", "rw"),
+            entry(Some(2), "/media/usb", "rw"),
+            entry(Some(3), "/boot", "rw"),
+        ];
+        let new = [
+            entry(Some(1), "/", "rw"),
+            entry(Some(3), "/boot", "ro"),
+            entry(Some(4), "/media/usb", "rw"),
+        ];
+        let events = diff_mounts(&old, &new);
+        assert_eq!(
+            events
+                .iter()
+                .map(|event| (event.kind(), event.mount_point().to_str().unwrap()))
+                .collect::<Vec<_>>(),
+            [
+                // The same mount point, but a different mount.
+                (DiskEventKind::Unmounted, "/media/usb"),
+                (DiskEventKind::Remounted, "/boot"),
+                (DiskEventKind::Mounted, "/media/usb"),
+            ]
+        );
+        assert!(events[1].is_read_only());
+
+        // Without identifiers, the mounts are compared with their mount point and device.
+        let old = [entry(None, "/", "rw"), entry(None, "/media/usb", "")];
+        let new = [entry(None, "/", "rw")];
+        assert_eq!(
+            diff_mounts(&old, &new),
+            [old[1].to_event(DiskEventKind::Unmounted)]
+        );
+        assert!(diff_mounts(&new, &new).is_empty());
+    }
+}
@@ -0,0 +1,323 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+/// Interacting with displays (monitors).
+///
+/// ⚠️ The list is always empty on FreeBSD, iOS and with the `apple-sandbox` feature.
+///
+/// ```no_run
+/// use sysinfo::Displays;
+///
+/// let displays = Displays::new_with_refreshed_list();
+/// for display in displays.list() {
+///     println!("{display:?}");
+/// }
+/// ```
+pub struct Displays {
+    displays: Vec<Display>,
+}
+
+impl Default for Displays {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<Displays> for Vec<Display> {
+    fn from(displays: Displays) -> Self {
+        displays.displays
+    }
+}
+
+impl From<Vec<Display>> for Displays {
+    fn from(displays: Vec<Display>) -> Self {
+        Self { displays }
+    }
+}
+
+impl std::ops::Deref for Displays {
+    type Target = [Display];
+
+    fn deref(&self) -> &Self::Target {
+        self.list()
+    }
+}
+
+impl std::ops::DerefMut for Displays {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.list_mut()
+    }
+}
+
+impl<'a> IntoIterator for &'a Displays {
+    type Item = &'a Display;
+    type IntoIter = std::slice::Iter<'a, Display>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.list().iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a mut Displays {
+    type Item = &'a mut Display;
+    type IntoIter = std::slice::IterMut<'a, Display>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.list_mut().iter_mut()
+    }
+}
+
+impl Displays {
+    /// Creates a new empty [`Displays`][crate::Displays] type.
+    ///
+    /// If you want it to be filled directly, take a look at
+    /// [`Displays::new_with_refreshed_list`].
+    ///
+    /// ```no_run
+    /// use sysinfo::Displays;
+    ///
+    /// let mut displays = Displays::new();
+    /// displays.refresh();
+    /// for display in displays.list() {
+    ///     println!("{display:?}");
+    /// }
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            displays: Vec::new(),
+        }
+    }
+
+    /// Creates a new [`Displays`][crate::Displays] type with the displays list loaded.
+    ///
+    /// ```no_run
+    /// use sysinfo::Displays;
+    ///
+    /// let displays = Displays::new_with_refreshed_list();
+    /// for display in displays.list() {
+    ///     println!("{display:?}");
+    /// }
+    /// ```
+    pub fn new_with_refreshed_list() -> Self {
+        let mut displays = Self::new();
+        displays.refresh();
+        displays
+    }
+
+    /// Returns the displays list.
+    ///
+    /// ```no_run
+    /// use sysinfo::Displays;
+    ///
+    /// let displays = Displays::new_with_refreshed_list();
+    /// for display in displays.list() {
+    ///     println!("{display:?}");
+    /// }
+    /// ```
+    pub fn list(&self) -> &[Display] {
+        &self.displays
+    }
+
+    /// Returns the displays list.
+    ///
+    /// ```no_run
+    /// use sysinfo::Displays;
+    ///
+    /// let mut displays = Displays::new_with_refreshed_list();
+    /// displays
+    ///     .list_mut()
+    ///     .sort_by_key(|display| (display.width(), display.height()));
+    /// ```
+    pub fn list_mut(&mut self) -> &mut [Display] {
+        &mut self.displays
+    }
+
+    /// The displays list will be emptied then completely recomputed.
+    ///
+    /// ```no_run
+    /// use sysinfo::Displays;
+    ///
+    /// let mut displays = Displays::new();
+    /// displays.refresh();
+    /// ```
+    pub fn refresh(&mut self) {
+        crate::sys::get_displays(&mut self.displays);
+    }
+}
+
+/// Type containing display information.
+///
+/// It is returned by [`Displays`][crate::Displays].
+///
+/// ```no_run
+/// use sysinfo::Displays;
+///
+/// let displays = Displays::new_with_refreshed_list();
+/// for display in displays.list() {
+///     println!(
+///         "{}: {}x{} @ {:?} Hz",
+///         display.id(),
+///         display.width(),
+///         display.height(),
+///         display.refresh_rate(),
+///     );
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Display {
+    pub(crate) id: String,
+    pub(crate) name: Option<String>,
+    pub(crate) width: u32,
+    pub(crate) height: u32,
+    pub(crate) refresh_rate: Option<f64>,
+    pub(crate) gpu: Option<String>,
+    pub(crate) is_primary: bool,
+}
+
+impl Display {
+    /// Returns the platform identifier of the display.
+    ///
+    /// ## Linux
+    ///
+    /// The DRM connector (like `HDMI-A-1`).
+    ///
+    /// ## macOS
+    ///
+    /// The CoreGraphics display ID.
+    ///
+    /// ## Windows
+    ///
+    /// The GDI device name (like `\\.\DISPLAY1`).
+    ///
+    /// ## Redox
+    ///
+    /// The display scheme path.
+    ///
+    /// ```no_run
+    /// use sysinfo::Displays;
+    ///
+    /// let displays = Displays::new_with_refreshed_list();
+    /// for display in displays.list() {
+    ///     println!("{}", display.id());
+    /// }
+    /// ```
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Returns the name of the monitor.
+    ///
+    /// ⚠️ It always returns `None` on macOS and Redox.
+    ///
+    /// ```no_run
+    /// use sysinfo::Displays;
+    ///
+    /// let displays = Displays::new_with_refreshed_list();
+    /// for display in displays.list() {
+    ///     println!("{:?}", display.name());
+    /// }
+    /// ```
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Returns the horizontal resolution of the display in pixels.
+    ///
+    /// On Linux, it is the preferred resolution of the monitor, which is usually the one in use.
+    ///
+    /// ```no_run
+    /// use sysinfo::Displays;
+    ///
+    /// let displays = Displays::new_with_refreshed_list();
+    /// for display in displays.list() {
+    ///     println!("{}", display.width());
+    /// }
+    /// ```
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Returns the vertical resolution of the display in pixels.
+    ///
+    /// On Linux, it is the preferred resolution of the monitor, which is usually the one in use.
+    ///
+    /// ```no_run
+    /// use sysinfo::Displays;
+    ///
+    /// let displays = Displays::new_with_refreshed_list();
+    /// for display in displays.list() {
+    ///     println!("{}", display.height());
+    /// }
+    /// ```
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Returns the refresh rate of the display in Hz.
+    ///
+    /// On Linux, it is computed from the preferred timing advertised in the monitor EDID.
+    ///
+    /// ⚠️ It always returns `None` on Redox.
+    ///
+    /// ```no_run
+    /// use sysinfo::Displays;
+    ///
+    /// let displays = Displays::new_with_refreshed_list();
+    /// for display in displays.list() {
+    ///     println!("{:?}", display.refresh_rate());
+    /// }
+    /// ```
+    pub fn refresh_rate(&self) -> Option<f64> {
+        self.refresh_rate
+    }
+
+    /// Returns the GPU driving the display.
+    ///
+    /// On Linux, it is the name of the kernel driver (like `amdgpu` or `i915`).
+    ///
+    /// ⚠️ It always returns `None` on macOS and Redox.
+    ///
+    /// ```no_run
+    /// use sysinfo::Displays;
+    ///
+    /// let displays = Displays::new_with_refreshed_list();
+    /// for display in displays.list() {
+    ///     println!("{:?}", display.gpu());
+    /// }
+    /// ```
+    pub fn gpu(&self) -> Option<&str> {
+        self.gpu.as_deref()
+    }
+
+    /// Returns `true` if this is the primary display.
+    ///
+    /// ⚠️ It always returns `false` on Linux since the primary display is a property of the
+    /// display server.
+    ///
+    /// ```no_run
+    /// use sysinfo::Displays;
+    ///
+    /// let displays = Displays::new_with_refreshed_list();
+    /// for display in displays.list() {
+    ///     println!("{}", display.is_primary());
+    /// }
+    /// ```
+    pub fn is_primary(&self) -> bool {
+        self.is_primary
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn check_list() {
+        let mut displays = Displays::new();
+        assert!(displays.list().is_empty());
+        displays.refresh();
+        assert!(displays.iter().filter(|d| d.is_primary()).count() <= 1);
+        for display in displays.list() {
+            assert!(display.refresh_rate().is_none_or(|rate| rate > 0.));
+        }
+    }
+}
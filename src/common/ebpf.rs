@@ -0,0 +1,168 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::{EbpfCollectorsInner, Pid};
+
+use std::collections::HashMap;
+use std::io;
+use std::time::Duration;
+
+/// Collects per-process metrics which cannot be retrieved by reading `/proc`, using eBPF
+/// programs attached to kernel tracepoints:
+///
+/// * the latency of the block I/O requests of each process;
+/// * the number of TCP retransmits of the sockets of each process;
+/// * the time the threads of each process spent waiting in a run queue before running.
+///
+/// The collection starts when it is created and stops when it is dropped. The values are
+/// accumulated since the collection started.
+///
+/// ⚠️ It is only supported on Linux (5.11 and later) and requires the `CAP_BPF` and
+/// `CAP_PERFMON` capabilities (or `CAP_SYS_ADMIN`). On other systems,
+/// [`EbpfCollectors::start`] returns an error of kind [`io::ErrorKind::Unsupported`].
+///
+/// ```no_run
+/// use sysinfo::{EbpfCollectors, Pid};
+///
+/// let mut collectors = EbpfCollectors::start().expect("failed to load eBPF programs");
+/// std::thread::sleep(std::time::Duration::from_secs(1));
+/// collectors.refresh();
+/// if let Some(latency) = collectors.run_queue_latency(Pid::from(1337)) {
+///     println!("average run queue latency: {:?}", latency.average());
+/// }
+/// ```
+pub struct EbpfCollectors {
+    inner: EbpfCollectorsInner,
+    io_latency: HashMap<Pid, LatencyStats>,
+    tcp_retransmits: HashMap<Pid, u64>,
+    run_queue_latency: HashMap<Pid, LatencyStats>,
+}
+
+impl EbpfCollectors {
+    /// Loads the eBPF programs and starts collecting. The collected values are only available
+    /// after [`EbpfCollectors::refresh`] is called.
+    ///
+    /// Collectors which aren't supported by the running kernel are skipped and their
+    /// accessors always return `None`.
+    ///
+    /// ```no_run
+    /// use sysinfo::EbpfCollectors;
+    ///
+    /// match EbpfCollectors::start() {
+    ///     Ok(_collectors) => println!("collecting"),
+    ///     Err(error) => eprintln!("cannot load eBPF programs: {error}"),
+    /// }
+    /// ```
+    pub fn start() -> io::Result<Self> {
+        Ok(Self {
+            inner: EbpfCollectorsInner::start()?,
+            io_latency: HashMap::new(),
+            tcp_retransmits: HashMap::new(),
+            run_queue_latency: HashMap::new(),
+        })
+    }
+
+    /// Retrieves the values collected by the eBPF programs.
+    ///
+    /// ```no_run
+    /// use sysinfo::EbpfCollectors;
+    ///
+    /// let mut collectors = EbpfCollectors::start().expect("failed to load eBPF programs");
+    /// std::thread::sleep(std::time::Duration::from_secs(1));
+    /// collectors.refresh();
+    /// ```
+    pub fn refresh(&mut self) {
+        self.inner.refresh(
+            &mut self.io_latency,
+            &mut self.tcp_retransmits,
+            &mut self.run_queue_latency,
+        );
+    }
+
+    /// Returns the latency of the block I/O requests started by the process with the given
+    /// `pid`, from the moment they're started until they're completed.
+    ///
+    /// ```no_run
+    /// use sysinfo::{EbpfCollectors, Pid};
+    ///
+    /// let mut collectors = EbpfCollectors::start().expect("failed to load eBPF programs");
+    /// std::thread::sleep(std::time::Duration::from_secs(1));
+    /// collectors.refresh();
+    /// if let Some(latency) = collectors.io_latency(Pid::from(1337)) {
+    ///     println!("{} requests, max latency: {:?}", latency.count(), latency.max());
+    /// }
+    /// ```
+    pub fn io_latency(&self, pid: Pid) -> Option<LatencyStats> {
+        self.io_latency.get(&pid).copied()
+    }
+
+    /// Returns the number of TCP segments retransmitted by the sockets of the process with
+    /// the given `pid`.
+    ///
+    /// ⚠️ Sockets are attributed to the process which connected them or, starting with Linux
+    /// 6.3, which sent data on them.
+    ///
+    /// ```no_run
+    /// use sysinfo::{EbpfCollectors, Pid};
+    ///
+    /// let mut collectors = EbpfCollectors::start().expect("failed to load eBPF programs");
+    /// std::thread::sleep(std::time::Duration::from_secs(1));
+    /// collectors.refresh();
+    /// if let Some(retransmits) = collectors.tcp_retransmits(Pid::from(1337)) {
+    ///     println!("{retransmits} TCP retransmits");
+    /// }
+    /// ```
+    pub fn tcp_retransmits(&self, pid: Pid) -> Option<u64> {
+        self.tcp_retransmits.get(&pid).copied()
+    }
+
+    /// Returns the time the threads of the process with the given `pid` spent in a run queue,
+    /// from the moment they were woken up (or preempted) until they ran.
+    ///
+    /// ```no_run
+    /// use sysinfo::{EbpfCollectors, Pid};
+    ///
+    /// let mut collectors = EbpfCollectors::start().expect("failed to load eBPF programs");
+    /// std::thread::sleep(std::time::Duration::from_secs(1));
+    /// collectors.refresh();
+    /// if let Some(latency) = collectors.run_queue_latency(Pid::from(1337)) {
+    ///     println!("average run queue latency: {:?}", latency.average());
+    /// }
+    /// ```
+    pub fn run_queue_latency(&self, pid: Pid) -> Option<LatencyStats> {
+        self.run_queue_latency.get(&pid).copied()
+    }
+}
+
+/// Latency measured by [`EbpfCollectors`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LatencyStats {
+    pub(crate) count: u64,
+    pub(crate) total: Duration,
+    pub(crate) max: Duration,
+}
+
+impl LatencyStats {
+    /// Returns the number of measured events.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Returns the sum of the latencies of all measured events.
+    pub fn total(&self) -> Duration {
+        self.total
+    }
+
+    /// Returns the highest latency measured.
+    pub fn max(&self) -> Duration {
+        self.max
+    }
+
+    /// Returns the average latency, or [`Duration::ZERO`] if no event was measured.
+    pub fn average(&self) -> Duration {
+        match u32::try_from(self.count) {
+            Ok(0) => Duration::ZERO,
+            Ok(count) => self.total / count,
+            Err(_) => Duration::from_nanos((self.total.as_nanos() / self.count as u128) as u64),
+        }
+    }
+}
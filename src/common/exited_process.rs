@@ -0,0 +1,173 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::{ExitedProcessesInner, Pid};
+
+use std::ffi::{OsStr, OsString};
+use std::io;
+use std::process::ExitStatus;
+
+/// Captures the processes as they exit, including the ones which started and exited between
+/// two refreshes of a [`System`][crate::System] and which are therefore never listed in
+/// [`System::processes`][crate::System::processes] (crashing processes, fork bombs, etc).
+///
+/// The capture starts when it is created and runs in a background thread until it is dropped.
+///
+/// ⚠️ It is only supported on Linux, where it uses the process events connector and requires
+/// the `CAP_NET_ADMIN` capability. The information of the exited processes comes from their
+/// `/proc/[pid]/stat` file if it can be read before the process is reaped by its parent, and
+/// from the taskstats interface otherwise (if the kernel supports it). Taskstats only provides
+/// the CPU time of the main thread of the process. On other systems (including Windows),
+/// [`ExitedProcesses::start`] returns an error of kind [`io::ErrorKind::Unsupported`].
+///
+/// ```no_run
+/// use sysinfo::ExitedProcesses;
+///
+/// let mut exited = ExitedProcesses::start().expect("failed to start capture");
+/// loop {
+///     std::thread::sleep(std::time::Duration::from_secs(1));
+///     exited.refresh();
+///     for process in exited.list() {
+///         println!(
+///             "[{}] {:?} exited with {:?} after using {}ms of CPU",
+///             process.pid(),
+///             process.name(),
+///             process.exit_status(),
+///             process.accumulated_cpu_time(),
+///         );
+///     }
+/// }
+/// ```
+pub struct ExitedProcesses {
+    inner: ExitedProcessesInner,
+    processes: Vec<ExitedProcess>,
+    missed_exits: bool,
+}
+
+impl ExitedProcesses {
+    /// Starts capturing the processes which exit. The list is empty until
+    /// [`ExitedProcesses::refresh`] is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::ExitedProcesses;
+    ///
+    /// match ExitedProcesses::start() {
+    ///     Ok(_exited) => println!("capture started"),
+    ///     Err(error) => eprintln!("cannot capture exited processes: {error}"),
+    /// }
+    /// ```
+    pub fn start() -> io::Result<Self> {
+        Ok(Self {
+            inner: ExitedProcessesInner::start()?,
+            processes: Vec::new(),
+            missed_exits: false,
+        })
+    }
+
+    /// Replaces the list with the processes which exited since the previous call to this
+    /// method (or since [`ExitedProcesses::start`]).
+    ///
+    /// ```no_run
+    /// use sysinfo::ExitedProcesses;
+    ///
+    /// let mut exited = ExitedProcesses::start().expect("failed to start capture");
+    /// std::thread::sleep(std::time::Duration::from_secs(1));
+    /// exited.refresh();
+    /// println!("{} processes exited", exited.list().len());
+    /// ```
+    pub fn refresh(&mut self) {
+        self.missed_exits = self.inner.take(&mut self.processes);
+    }
+
+    /// Returns the processes which exited, in the order they exited.
+    ///
+    /// ```no_run
+    /// use sysinfo::ExitedProcesses;
+    ///
+    /// let mut exited = ExitedProcesses::start().expect("failed to start capture");
+    /// exited.refresh();
+    /// for process in exited.list() {
+    ///     println!("{:?}", process.name());
+    /// }
+    /// ```
+    pub fn list(&self) -> &[ExitedProcess] {
+        &self.processes
+    }
+
+    /// Returns `true` if some exits couldn't be captured before the last
+    /// [`ExitedProcesses::refresh`], either because the system dropped events or because too
+    /// many processes exited since the previous refresh.
+    ///
+    /// ```no_run
+    /// use sysinfo::ExitedProcesses;
+    ///
+    /// let mut exited = ExitedProcesses::start().expect("failed to start capture");
+    /// exited.refresh();
+    /// if exited.has_missed_exits() {
+    ///     println!("the list is incomplete, refresh more often");
+    /// }
+    /// ```
+    pub fn has_missed_exits(&self) -> bool {
+        self.missed_exits
+    }
+}
+
+/// Information about a process which exited, captured by [`ExitedProcesses`].
+#[derive(Clone, Debug)]
+pub struct ExitedProcess {
+    pub(crate) pid: Pid,
+    pub(crate) parent: Option<Pid>,
+    pub(crate) name: OsString,
+    pub(crate) exit_status: Option<ExitStatus>,
+    pub(crate) start_time: u64,
+    pub(crate) run_time: u64,
+    pub(crate) accumulated_cpu_time: u64,
+}
+
+impl ExitedProcess {
+    /// Returns the PID of the process.
+    pub fn pid(&self) -> Pid {
+        self.pid
+    }
+
+    /// Returns the PID of the parent of the process when it exited.
+    pub fn parent(&self) -> Option<Pid> {
+        self.parent
+    }
+
+    /// Returns the name of the process when it exited.
+    ///
+    /// ⚠️ It is empty if the process information couldn't be retrieved (take a look at
+    /// [`ExitedProcesses`] for more information).
+    pub fn name(&self) -> &OsStr {
+        &self.name
+    }
+
+    /// Returns the exit status of the process, or `None` if it isn't known.
+    pub fn exit_status(&self) -> Option<ExitStatus> {
+        self.exit_status
+    }
+
+    /// Returns the time where the process was started (in seconds) from epoch.
+    ///
+    /// ⚠️ It is `0` if the process information couldn't be retrieved (take a look at
+    /// [`ExitedProcesses`] for more information).
+    pub fn start_time(&self) -> u64 {
+        self.start_time
+    }
+
+    /// Returns for how much time the process ran (in seconds).
+    ///
+    /// ⚠️ It is `0` if the process information couldn't be retrieved (take a look at
+    /// [`ExitedProcesses`] for more information).
+    pub fn run_time(&self) -> u64 {
+        self.run_time
+    }
+
+    /// Returns the total CPU time used by the process (in CPU-milliseconds).
+    ///
+    /// ⚠️ It is `0` if the process information couldn't be retrieved (take a look at
+    /// [`ExitedProcesses`] for more information).
+    pub fn accumulated_cpu_time(&self) -> u64 {
+        self.accumulated_cpu_time
+    }
+}
@@ -0,0 +1,621 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+//! A fake backend allowing to write deterministic tests for code using `sysinfo`.
+//!
+//! When `sysinfo` is built with `--cfg sysinfo_test_backend` (for example with
+//! `RUSTFLAGS="--cfg sysinfo_test_backend" cargo test`), it doesn't retrieve any information from
+//! the system anymore. Instead, the information returned by a [`System`] or a [`Disks`] created
+//! with a [`FakeSystem`] is the one provided to this [`FakeSystem`]. Changes made on the
+//! [`FakeSystem`] are visible on the next refresh, allowing to script how the system evolves
+//! between refreshes.
+//!
+//! ⚠️ This is not a feature so that a dependency cannot enable it for the whole crate graph. It
+//! is meant to be set when running tests only: once set, [`System::new`] and [`Disks::new`] return
+//! empty information everywhere `sysinfo` is used. It requires the `disk` and `system` features.
+//!
+//! Information not covered by [`FakeSystem`] (like [`System::uptime`] or the components) is
+//! returned as if the target was not supported.
+//!
+//! ```
+//! use sysinfo::fake::{FakeCpu, FakeProcess, FakeSystem};
+//! use sysinfo::Pid;
+//!
+//! let fake = FakeSystem::new();
+//! fake.set_memory(8 * 1024 * 1024 * 1024, 1024 * 1024 * 1024);
+//! fake.set_cpus(vec![FakeCpu::new("cpu0").with_cpu_usage(10.)]);
+//! fake.add_process(FakeProcess::new(Pid::from(1), "init").with_memory(4096));
+//!
+//! let mut s = fake.system();
+//! assert_eq!(s.used_memory(), 1024 * 1024 * 1024);
+//! assert_eq!(s.process(Pid::from(1)).unwrap().memory(), 4096);
+//!
+//! fake.add_process(FakeProcess::new(Pid::from(2), "worker").with_parent(Pid::from(1)));
+//! fake.update_process(Pid::from(1), |p| p.with_memory(8192));
+//! s.refresh_all();
+//! assert_eq!(s.processes().len(), 2);
+//! assert_eq!(s.process(Pid::from(1)).unwrap().memory(), 8192);
+//! ```
+//!
+//! [`System`]: crate::System
+//! [`System::new`]: crate::System::new
+//! [`System::uptime`]: crate::System::uptime
+//! [`Disks`]: crate::Disks
+//! [`Disks::new`]: crate::Disks::new
+
+use crate::{
+    DiskKind, DiskUsage, Disks, DisksInner, Gid, Pid, ProcessStatus, System, SystemInner, Uid,
+};
+
+use std::collections::HashMap;
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+#[derive(Default)]
+pub(crate) struct FakeState {
+    pub(crate) processes: HashMap<Pid, FakeProcess>,
+    pub(crate) cpus: Vec<FakeCpu>,
+    pub(crate) disks: Vec<FakeDisk>,
+    pub(crate) total_memory: u64,
+    pub(crate) used_memory: u64,
+    pub(crate) total_swap: u64,
+    pub(crate) used_swap: u64,
+    /// Overrides the average of the CPUs usage. Only used by `Replay`.
+    pub(crate) global_cpu_usage: Option<f32>,
+}
+
+pub(crate) type SharedState = Arc<Mutex<FakeState>>;
+
+/// Holds the information returned by the [`System`] and [`Disks`] it creates.
+///
+/// Cloning it returns a handle to the same information.
+///
+/// ```
+/// use sysinfo::fake::{FakeProcess, FakeSystem};
+/// use sysinfo::Pid;
+///
+/// let fake = FakeSystem::new();
+/// fake.add_process(FakeProcess::new(Pid::from(1), "init").with_cpu_usage(2.));
+/// let mut s = fake.system();
+///
+/// fake.update_process(Pid::from(1), |p| p.with_cpu_usage(50.));
+/// s.refresh_all();
+/// assert_eq!(s.process(Pid::from(1)).unwrap().cpu_usage(), 50.);
+///
+/// fake.remove_process(Pid::from(1));
+/// s.refresh_all();
+/// assert!(s.process(Pid::from(1)).is_none());
+/// ```
+#[derive(Clone, Default)]
+pub struct FakeSystem {
+    pub(crate) state: SharedState,
+}
+
+impl FakeSystem {
+    /// Creates a new [`FakeSystem`] without any process, CPU or disk.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new [`System`] with all information loaded from this [`FakeSystem`].
+    ///
+    /// Its refresh methods load the information of this [`FakeSystem`] at the time they're
+    /// called.
+    pub fn system(&self) -> System {
+        let mut s = System {
+            inner: SystemInner::with_state(self.state.clone()),
+            last_refreshed_at: None,
+            refresh_observers: Vec::new(),
+            last_refresh_durations: Default::default(),
+        };
+        s.refresh_all();
+        s
+    }
+
+    /// Creates a new [`Disks`] with the disks list loaded from this [`FakeSystem`].
+    ///
+    /// Its refresh methods load the information of this [`FakeSystem`] at the time they're
+    /// called.
+    pub fn disks(&self) -> Disks {
+        let mut disks = Disks {
+            inner: DisksInner::with_state(self.state.clone()),
+            last_refreshed_at: None,
+        };
+        disks.refresh(false);
+        disks
+    }
+
+    /// Sets the total and used RAM (in bytes). The free and available RAM are the difference
+    /// between the two.
+    pub fn set_memory(&self, total: u64, used: u64) {
+        let mut state = self.state.lock().unwrap();
+        state.total_memory = total;
+        state.used_memory = used.min(total);
+    }
+
+    /// Sets the total and used swap (in bytes).
+    pub fn set_swap(&self, total: u64, used: u64) {
+        let mut state = self.state.lock().unwrap();
+        state.total_swap = total;
+        state.used_swap = used.min(total);
+    }
+
+    /// Adds a process. If a process with the same PID already exists, it is replaced.
+    pub fn add_process(&self, process: FakeProcess) {
+        self.state
+            .lock()
+            .unwrap()
+            .processes
+            .insert(process.pid, process);
+    }
+
+    /// Removes the process with the given `pid`. Returns `false` if there is no such process.
+    pub fn remove_process(&self, pid: Pid) -> bool {
+        self.state.lock().unwrap().processes.remove(&pid).is_some()
+    }
+
+    /// Replaces the process with the given `pid` by the value returned by `f`. Returns `false`
+    /// if there is no such process.
+    ///
+    /// ```
+    /// use sysinfo::fake::{FakeProcess, FakeSystem};
+    /// use sysinfo::{Pid, ProcessStatus};
+    ///
+    /// let fake = FakeSystem::new();
+    /// fake.add_process(FakeProcess::new(Pid::from(1), "init"));
+    /// assert!(fake.update_process(Pid::from(1), |p| p.with_status(ProcessStatus::Zombie)));
+    /// ```
+    pub fn update_process<F: FnOnce(FakeProcess) -> FakeProcess>(&self, pid: Pid, f: F) -> bool {
+        let mut state = self.state.lock().unwrap();
+        let Some(process) = state.processes.remove(&pid) else {
+            return false;
+        };
+        let process = f(process);
+        state.processes.insert(process.pid, process);
+        true
+    }
+
+    /// Sets the CPUs list.
+    pub fn set_cpus(&self, cpus: Vec<FakeCpu>) {
+        self.state.lock().unwrap().cpus = cpus;
+    }
+
+    /// Replaces the CPU at `index` by the value returned by `f`. Returns `false` if there is no
+    /// such CPU.
+    pub fn update_cpu<F: FnOnce(FakeCpu) -> FakeCpu>(&self, index: usize, f: F) -> bool {
+        let mut state = self.state.lock().unwrap();
+        let Some(cpu) = state.cpus.get_mut(index) else {
+            return false;
+        };
+        *cpu = f(std::mem::take(cpu));
+        true
+    }
+
+    /// Adds a disk. If a disk with the same mount point already exists, it is replaced.
+    pub fn add_disk(&self, disk: FakeDisk) {
+        let mut state = self.state.lock().unwrap();
+        state.disks.retain(|d| d.mount_point != disk.mount_point);
+        state.disks.push(disk);
+    }
+
+    /// Removes the disk mounted on `mount_point`. Returns `false` if there is no such disk.
+    pub fn remove_disk(&self, mount_point: impl AsRef<Path>) -> bool {
+        let mut state = self.state.lock().unwrap();
+        let len = state.disks.len();
+        state
+            .disks
+            .retain(|d| d.mount_point != mount_point.as_ref());
+        state.disks.len() != len
+    }
+
+    /// Replaces the disk mounted on `mount_point` by the value returned by `f`. Returns `false`
+    /// if there is no such disk.
+    pub fn update_disk<F: FnOnce(FakeDisk) -> FakeDisk>(
+        &self,
+        mount_point: impl AsRef<Path>,
+        f: F,
+    ) -> bool {
+        let mut state = self.state.lock().unwrap();
+        let Some(pos) = state
+            .disks
+            .iter()
+            .position(|d| d.mount_point == mount_point.as_ref())
+        else {
+            return false;
+        };
+        let disk = state.disks.remove(pos);
+        state.disks.insert(pos, f(disk));
+        true
+    }
+}
+
+/// A fake process, used with [`FakeSystem::add_process`][crate::fake::FakeSystem::add_process].
+///
+/// ```
+/// use sysinfo::fake::FakeProcess;
+/// use sysinfo::{Pid, ProcessStatus};
+///
+/// let process = FakeProcess::new(Pid::from(12), "server")
+///     .with_parent(Pid::from(1))
+///     .with_cmd(["server", "--port", "8080"])
+///     .with_status(ProcessStatus::Sleep)
+///     .with_memory(64 * 1024 * 1024)
+///     .with_cpu_usage(12.5);
+/// ```
+#[derive(Clone, Debug)]
+pub struct FakeProcess {
+    pub(crate) pid: Pid,
+    pub(crate) parent: Option<Pid>,
+    pub(crate) name: OsString,
+    pub(crate) cmd: Vec<OsString>,
+    pub(crate) exe: Option<PathBuf>,
+    pub(crate) environ: Vec<OsString>,
+    pub(crate) cwd: Option<PathBuf>,
+    pub(crate) root: Option<PathBuf>,
+    pub(crate) memory: u64,
+    pub(crate) virtual_memory: u64,
+    pub(crate) thread_count: Option<usize>,
+    pub(crate) status: ProcessStatus,
+    pub(crate) start_time: u64,
+    pub(crate) run_time: u64,
+    pub(crate) cpu_usage: f32,
+    pub(crate) accumulated_cpu_time: u64,
+    pub(crate) children_cpu_time: u64,
+    pub(crate) disk_usage: DiskUsage,
+    pub(crate) user_id: Option<Uid>,
+    pub(crate) group_id: Option<Gid>,
+}
+
+impl FakeProcess {
+    /// Creates a new running [`FakeProcess`] with the given `pid` and `name`.
+    pub fn new(pid: Pid, name: impl Into<OsString>) -> Self {
+        Self {
+            pid,
+            parent: None,
+            name: name.into(),
+            cmd: Vec::new(),
+            exe: None,
+            environ: Vec::new(),
+            cwd: None,
+            root: None,
+            memory: 0,
+            virtual_memory: 0,
+            thread_count: None,
+            status: ProcessStatus::Run,
+            start_time: 0,
+            run_time: 0,
+            cpu_usage: 0.,
+            accumulated_cpu_time: 0,
+            children_cpu_time: 0,
+            disk_usage: DiskUsage::default(),
+            user_id: None,
+            group_id: None,
+        }
+    }
+
+    /// Sets the value returned by [`Process::parent`][crate::Process::parent].
+    pub fn with_parent(mut self, parent: Pid) -> Self {
+        self.parent = Some(parent);
+        self
+    }
+
+    /// Sets the value returned by [`Process::name`][crate::Process::name].
+    pub fn with_name(mut self, name: impl Into<OsString>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    /// Sets the value returned by [`Process::cmd`][crate::Process::cmd].
+    pub fn with_cmd<I: IntoIterator<Item = S>, S: Into<OsString>>(mut self, cmd: I) -> Self {
+        self.cmd = cmd.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets the value returned by [`Process::exe`][crate::Process::exe].
+    pub fn with_exe(mut self, exe: impl Into<PathBuf>) -> Self {
+        self.exe = Some(exe.into());
+        self
+    }
+
+    /// Sets the value returned by [`Process::environ`][crate::Process::environ].
+    pub fn with_environ<I: IntoIterator<Item = S>, S: Into<OsString>>(
+        mut self,
+        environ: I,
+    ) -> Self {
+        self.environ = environ.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets the value returned by [`Process::cwd`][crate::Process::cwd].
+    pub fn with_cwd(mut self, cwd: impl Into<PathBuf>) -> Self {
+        self.cwd = Some(cwd.into());
+        self
+    }
+
+    /// Sets the value returned by [`Process::root`][crate::Process::root].
+    pub fn with_root(mut self, root: impl Into<PathBuf>) -> Self {
+        self.root = Some(root.into());
+        self
+    }
+
+    /// Sets the value returned by [`Process::memory`][crate::Process::memory] (in bytes).
+    pub fn with_memory(mut self, memory: u64) -> Self {
+        self.memory = memory;
+        self
+    }
+
+    /// Sets the value returned by [`Process::virtual_memory`][crate::Process::virtual_memory]
+    /// (in bytes).
+    pub fn with_virtual_memory(mut self, virtual_memory: u64) -> Self {
+        self.virtual_memory = virtual_memory;
+        self
+    }
+
+    /// Sets the value returned by [`Process::thread_count`][crate::Process::thread_count].
+    pub fn with_thread_count(mut self, thread_count: usize) -> Self {
+        self.thread_count = Some(thread_count);
+        self
+    }
+
+    /// Sets the value returned by [`Process::status`][crate::Process::status].
+    pub fn with_status(mut self, status: ProcessStatus) -> Self {
+        self.status = status;
+        self
+    }
+
+    /// Sets the value returned by [`Process::start_time`][crate::Process::start_time] (in
+    /// seconds since UNIX epoch).
+    pub fn with_start_time(mut self, start_time: u64) -> Self {
+        self.start_time = start_time;
+        self
+    }
+
+    /// Sets the value returned by [`Process::run_time`][crate::Process::run_time] (in seconds).
+    pub fn with_run_time(mut self, run_time: u64) -> Self {
+        self.run_time = run_time;
+        self
+    }
+
+    /// Sets the value returned by [`Process::cpu_usage`][crate::Process::cpu_usage].
+    pub fn with_cpu_usage(mut self, cpu_usage: f32) -> Self {
+        self.cpu_usage = cpu_usage;
+        self
+    }
+
+    /// Sets the value returned by
+    /// [`Process::accumulated_cpu_time`][crate::Process::accumulated_cpu_time] (in
+    /// milliseconds).
+    pub fn with_accumulated_cpu_time(mut self, accumulated_cpu_time: u64) -> Self {
+        self.accumulated_cpu_time = accumulated_cpu_time;
+        self
+    }
+
+    /// Sets the value returned by
+    /// [`Process::children_cpu_time`][crate::Process::children_cpu_time] (in milliseconds).
+    pub fn with_children_cpu_time(mut self, children_cpu_time: u64) -> Self {
+        self.children_cpu_time = children_cpu_time;
+        self
+    }
+
+    /// Sets the value returned by [`Process::disk_usage`][crate::Process::disk_usage].
+    pub fn with_disk_usage(mut self, disk_usage: DiskUsage) -> Self {
+        self.disk_usage = disk_usage;
+        self
+    }
+
+    /// Sets the value returned by [`Process::user_id`][crate::Process::user_id].
+    pub fn with_user_id(mut self, user_id: Uid) -> Self {
+        self.user_id = Some(user_id);
+        self
+    }
+
+    /// Sets the value returned by [`Process::group_id`][crate::Process::group_id].
+    pub fn with_group_id(mut self, group_id: Gid) -> Self {
+        self.group_id = Some(group_id);
+        self
+    }
+}
+
+/// A fake CPU, used with [`FakeSystem::set_cpus`][crate::fake::FakeSystem::set_cpus].
+///
+/// ```
+/// use sysinfo::fake::FakeCpu;
+///
+/// let cpu = FakeCpu::new("cpu0")
+///     .with_brand("Fake CPU")
+///     .with_frequency(3_200)
+///     .with_cpu_usage(42.);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct FakeCpu {
+    pub(crate) name: String,
+    pub(crate) vendor_id: String,
+    pub(crate) brand: String,
+    pub(crate) frequency: u64,
+    pub(crate) cpu_usage: f32,
+}
+
+impl FakeCpu {
+    /// Creates a new idle [`FakeCpu`] named `name`.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the value returned by [`Cpu::vendor_id`][crate::Cpu::vendor_id].
+    pub fn with_vendor_id(mut self, vendor_id: impl Into<String>) -> Self {
+        self.vendor_id = vendor_id.into();
+        self
+    }
+
+    /// Sets the value returned by [`Cpu::brand`][crate::Cpu::brand].
+    pub fn with_brand(mut self, brand: impl Into<String>) -> Self {
+        self.brand = brand.into();
+        self
+    }
+
+    /// Sets the value returned by [`Cpu::frequency`][crate::Cpu::frequency] (in MHz).
+    pub fn with_frequency(mut self, frequency: u64) -> Self {
+        self.frequency = frequency;
+        self
+    }
+
+    /// Sets the value returned by [`Cpu::cpu_usage`][crate::Cpu::cpu_usage].
+    pub fn with_cpu_usage(mut self, cpu_usage: f32) -> Self {
+        self.cpu_usage = cpu_usage;
+        self
+    }
+}
+
+/// A fake disk, used with [`FakeSystem::add_disk`][crate::fake::FakeSystem::add_disk].
+///
+/// Disks are identified by their mount point.
+///
+/// ```
+/// use sysinfo::fake::FakeDisk;
+/// use sysinfo::DiskKind;
+///
+/// let disk = FakeDisk::new("/dev/sda1", "/")
+///     .with_kind(DiskKind::SSD)
+///     .with_space(512 * 1024 * 1024 * 1024, 128 * 1024 * 1024 * 1024);
+/// ```
+#[derive(Clone, Debug)]
+pub struct FakeDisk {
+    pub(crate) name: OsString,
+    pub(crate) mount_point: PathBuf,
+    pub(crate) file_system: OsString,
+    pub(crate) kind: DiskKind,
+    pub(crate) total_space: u64,
+    pub(crate) available_space: u64,
+    pub(crate) is_removable: bool,
+    pub(crate) is_read_only: bool,
+    pub(crate) usage: DiskUsage,
+}
+
+impl FakeDisk {
+    /// Creates a new empty [`FakeDisk`] named `name` and mounted on `mount_point`.
+    pub fn new(name: impl Into<OsString>, mount_point: impl Into<PathBuf>) -> Self {
+        Self {
+            name: name.into(),
+            mount_point: mount_point.into(),
+            file_system: OsString::new(),
+            kind: DiskKind::Unknown(-1),
+            total_space: 0,
+            available_space: 0,
+            is_removable: false,
+            is_read_only: false,
+            usage: DiskUsage::default(),
+        }
+    }
+
+    /// Sets the value returned by [`Disk::file_system`][crate::Disk::file_system].
+    pub fn with_file_system(mut self, file_system: impl Into<OsString>) -> Self {
+        self.file_system = file_system.into();
+        self
+    }
+
+    /// Sets the value returned by [`Disk::kind`][crate::Disk::kind].
+    pub fn with_kind(mut self, kind: DiskKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// Sets the values returned by [`Disk::total_space`][crate::Disk::total_space] and
+    /// [`Disk::available_space`][crate::Disk::available_space] (in bytes).
+    pub fn with_space(mut self, total_space: u64, available_space: u64) -> Self {
+        self.total_space = total_space;
+        self.available_space = available_space;
+        self
+    }
+
+    /// Sets the value returned by [`Disk::is_removable`][crate::Disk::is_removable].
+    pub fn with_removable(mut self, is_removable: bool) -> Self {
+        self.is_removable = is_removable;
+        self
+    }
+
+    /// Sets the value returned by [`Disk::is_read_only`][crate::Disk::is_read_only].
+    pub fn with_read_only(mut self, is_read_only: bool) -> Self {
+        self.is_read_only = is_read_only;
+        self
+    }
+
+    /// Sets the value returned by [`Disk::usage`][crate::Disk::usage].
+    pub fn with_usage(mut self, usage: DiskUsage) -> Self {
+        self.usage = usage;
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{FakeCpu, FakeDisk, FakeProcess, FakeSystem};
+    use crate::{DiskKind, Pid, ProcessRefreshKind, ProcessesToUpdate};
+
+    #[test]
+    fn check_fake_system() {
+        let fake = FakeSystem::new();
+        fake.set_memory(1000, 400);
+        fake.set_swap(100, 10);
+        fake.set_cpus(vec![
+            FakeCpu::new("cpu0").with_cpu_usage(10.),
+            FakeCpu::new("cpu1").with_cpu_usage(30.),
+        ]);
+        fake.add_process(FakeProcess::new(Pid::from(1), "init"));
+        fake.add_process(
+            FakeProcess::new(Pid::from(2), "child")
+                .with_parent(Pid::from(1))
+                .with_cmd(["child", "--flag"])
+                .with_memory(12),
+        );
+
+        let mut s = fake.system();
+        assert_eq!(s.total_memory(), 1000);
+        assert_eq!(s.available_memory(), 600);
+        assert_eq!(s.free_swap(), 90);
+        assert_eq!(s.cpus().len(), 2);
+        assert_eq!(s.global_cpu_usage(), 20.);
+        assert_eq!(s.processes().len(), 2);
+        let child = s.process(Pid::from(2)).unwrap();
+        assert_eq!(child.parent(), Some(Pid::from(1)));
+        assert_eq!(child.cmd(), ["child", "--flag"]);
+        assert_eq!(child.memory(), 12);
+
+        // Changes are only visible after a refresh.
+        fake.update_process(Pid::from(2), |p| p.with_memory(24));
+        fake.remove_process(Pid::from(1));
+        fake.update_cpu(1, |cpu| cpu.with_cpu_usage(50.));
+        assert_eq!(s.process(Pid::from(2)).unwrap().memory(), 12);
+
+        // Only the requested information is refreshed.
+        s.refresh_processes_specifics(ProcessesToUpdate::All, true, ProcessRefreshKind::nothing());
+        assert!(s.process(Pid::from(1)).is_none());
+        assert_eq!(s.process(Pid::from(2)).unwrap().memory(), 12);
+
+        s.refresh_all();
+        assert_eq!(s.process(Pid::from(2)).unwrap().memory(), 24);
+        assert_eq!(s.global_cpu_usage(), 30.);
+    }
+
+    #[test]
+    fn check_fake_disks() {
+        let fake = FakeSystem::new();
+        fake.add_disk(FakeDisk::new("sda1", "/").with_space(100, 50));
+        fake.add_disk(FakeDisk::new("sdb1", "/data").with_kind(DiskKind::HDD));
+
+        let mut disks = fake.disks();
+        assert_eq!(disks.list().len(), 2);
+        assert_eq!(disks.list()[0].available_space(), 50);
+
+        fake.update_disk("/", |disk| disk.with_space(100, 20));
+        fake.remove_disk("/data");
+        assert!(disks.list_mut()[0].refresh());
+        assert_eq!(disks.list()[0].available_space(), 20);
+        assert!(!disks.list_mut()[1].refresh());
+
+        disks.refresh(true);
+        assert_eq!(disks.list().len(), 1);
+    }
+}
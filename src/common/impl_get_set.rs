@@ -43,7 +43,7 @@ assert_eq!(r.", stringify!($name), "(), true);
 ```
 use sysinfo::", stringify!($ty_name), ";
 
-let r = ", stringify!($ty_name), "::everything();
+let r = ", stringify!($ty_name), "::nothing().with_", stringify!($name), "();
 assert_eq!(r.", stringify!($name), "(), true);
 
 let r = r.without_", stringify!($name), "();
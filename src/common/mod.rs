@@ -1,22 +1,72 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
+#[cfg(feature = "apple-silicon")]
+pub(crate) mod apple_silicon;
+#[cfg(feature = "audio")]
+pub(crate) mod audio;
 #[cfg(feature = "component")]
 pub(crate) mod component;
 #[cfg(feature = "disk")]
 pub(crate) mod disk;
+#[cfg(feature = "disk")]
+pub(crate) mod disk_watcher;
+#[cfg(feature = "display")]
+pub(crate) mod display;
+#[cfg(feature = "ebpf")]
+pub(crate) mod ebpf;
+#[cfg(feature = "system")]
+pub(crate) mod exited_process;
+#[cfg(all(sysinfo_test_backend, not(feature = "unknown-ci")))]
+pub mod fake;
 #[cfg(any(feature = "system", feature = "disk"))]
 pub(crate) mod impl_get_set;
 #[cfg(feature = "network")]
 pub(crate) mod network;
+#[cfg(feature = "network")]
+pub(crate) mod network_watcher;
+#[cfg(feature = "system")]
+pub(crate) mod pid_set_tracker;
+#[cfg(feature = "record")]
+pub mod record;
+#[cfg(feature = "remote")]
+pub mod remote;
+#[cfg(feature = "system")]
+pub(crate) mod shared_system;
+#[cfg(feature = "disk")]
+pub(crate) mod storage;
 #[cfg(feature = "system")]
 pub(crate) mod system;
+#[cfg(feature = "usb")]
+pub(crate) mod usb;
 #[cfg(feature = "user")]
 pub(crate) mod user;
+#[cfg(any(feature = "disk", feature = "network"))]
+pub(crate) mod watcher;
+#[cfg(feature = "zfs")]
+pub(crate) mod zfs;
 
 /// Type containing read and written bytes.
 ///
 /// It is returned by [`Process::disk_usage`][crate::Process::disk_usage] and [`Disk::usage`][crate::Disk::usage].
 ///
+/// What is counted by [`Process::disk_usage`][crate::Process::disk_usage] depends on the
+/// platform:
+///
+///  * On Linux, the bytes the process caused to be fetched from or sent to the storage layer
+///    (`read_bytes` and `write_bytes` of `/proc/<pid>/io`). Reads served by the page cache are
+///    not counted.
+///  * On macOS, the bytes of the disk I/O operations done by the process
+///    (`ri_diskio_bytesread` and `ri_diskio_byteswritten` returned by `proc_pid_rusage`).
+///  * On Windows, **ALL** the I/O bytes of the process, including network and device I/O and
+///    reads served by the file system cache.
+///  * On FreeBSD, the number of block input and output **operations** (`ru_inblock` and
+///    `ru_oublock`), not bytes.
+///  * On other platforms (including iOS and with the `apple-sandbox` feature), all values are
+///    always `0`.
+///
+/// [`Disk::usage`][crate::Disk::usage] always counts the bytes read from and written to the
+/// disk device.
+///
 #[cfg_attr(not(all(feature = "system", feature = "disk")), doc = "```ignore")]
 /// ```no_run
 /// use sysinfo::{Disks, System};
@@ -54,6 +104,135 @@ pub struct DiskUsage {
     pub read_bytes: u64,
 }
 
+/// Error returned by the `try_refresh*` methods (like `System::try_refresh_memory_specifics`)
+/// explaining why the requested information couldn't be retrieved.
+///
+/// The methods without the `try_` prefix silently keep the previous (or default) values instead.
+///
+#[cfg_attr(not(feature = "system"), doc = "```ignore")]
+/// ```no_run
+/// use sysinfo::{MemoryRefreshKind, RefreshError, System};
+///
+/// let mut s = System::new();
+/// match s.try_refresh_memory_specifics(MemoryRefreshKind::everything()) {
+///     Ok(()) => println!("{} bytes", s.total_memory()),
+///     Err(RefreshError::PermissionDenied(path)) => eprintln!("cannot read {path:?}"),
+///     Err(error) => eprintln!("failed to refresh memory: {error}"),
+/// }
+/// ```
+#[cfg(any(feature = "system", feature = "disk", feature = "network"))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RefreshError {
+    /// The source of the information (a file or a Redox scheme) doesn't exist.
+    NotFound(std::path::PathBuf),
+    /// Not allowed to read the source of the information.
+    PermissionDenied(std::path::PathBuf),
+    /// The source of the information was read but its content couldn't be parsed.
+    Parse(std::path::PathBuf),
+    /// Another I/O error occurred while reading the source of the information.
+    Io(std::path::PathBuf, std::io::ErrorKind),
+    /// This information cannot be retrieved on this platform.
+    Unsupported,
+}
+
+#[cfg(any(feature = "system", feature = "disk", feature = "network"))]
+impl RefreshError {
+    #[allow(dead_code)]
+    pub(crate) fn from_io(path: impl Into<std::path::PathBuf>, error: std::io::Error) -> Self {
+        let path = path.into();
+        match error.kind() {
+            std::io::ErrorKind::NotFound => Self::NotFound(path),
+            std::io::ErrorKind::PermissionDenied => Self::PermissionDenied(path),
+            std::io::ErrorKind::InvalidData => Self::Parse(path),
+            kind => Self::Io(path, kind),
+        }
+    }
+}
+
+#[cfg(any(feature = "system", feature = "disk", feature = "network"))]
+impl std::fmt::Display for RefreshError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotFound(path) => write!(f, "`{}` not found", path.display()),
+            Self::PermissionDenied(path) => {
+                write!(f, "not allowed to read `{}`", path.display())
+            }
+            Self::Parse(path) => write!(f, "failed to parse `{}`", path.display()),
+            Self::Io(path, kind) => write!(f, "failed to read `{}`: {kind}", path.display()),
+            Self::Unsupported => f.write_str("not supported on this platform"),
+        }
+    }
+}
+
+#[cfg(any(feature = "system", feature = "disk", feature = "network"))]
+impl std::error::Error for RefreshError {}
+
+/// Time of a refresh, returned by the `last_refreshed_at` methods (like
+/// [`System::last_refreshed_at`]).
+///
+/// It contains both a monotonic timestamp, to compute the exact interval between two
+/// refreshes, and the wall-clock time, to label the values.
+///
+/// ```no_run
+/// use sysinfo::System;
+///
+/// let mut s = System::new();
+/// s.refresh_memory();
+/// let before = s.last_refreshed_at().unwrap();
+/// // Wait some time...
+/// s.refresh_memory();
+/// let after = s.last_refreshed_at().unwrap();
+/// println!(
+///     "{:?} between the two refreshes",
+///     after.instant().duration_since(before.instant()),
+/// );
+/// ```
+///
+/// [`System::last_refreshed_at`]: crate::System::last_refreshed_at
+#[cfg(any(feature = "system", feature = "disk", feature = "network"))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RefreshTimestamp {
+    instant: std::time::Instant,
+    system_time: std::time::SystemTime,
+}
+
+#[cfg(any(feature = "system", feature = "disk", feature = "network"))]
+impl RefreshTimestamp {
+    pub(crate) fn now() -> Self {
+        Self {
+            instant: std::time::Instant::now(),
+            system_time: std::time::SystemTime::now(),
+        }
+    }
+
+    /// Returns the monotonic time of the refresh. Use it to compute intervals between
+    /// refreshes, as it isn't affected by changes of the system clock.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let mut s = System::new();
+    /// s.refresh_memory();
+    /// println!("{:?} ago", s.last_refreshed_at().unwrap().instant().elapsed());
+    /// ```
+    pub fn instant(&self) -> std::time::Instant {
+        self.instant
+    }
+
+    /// Returns the wall-clock time of the refresh.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let mut s = System::new();
+    /// s.refresh_memory();
+    /// println!("{:?}", s.last_refreshed_at().unwrap().system_time());
+    /// ```
+    pub fn system_time(&self) -> std::time::SystemTime {
+        self.system_time
+    }
+}
+
 macro_rules! xid {
     ($(#[$outer:meta])+ $name:ident, $type:ty $(, $trait:ty)?) => {
         #[cfg(any(feature = "system", feature = "user"))]
@@ -129,7 +308,7 @@ cfg_if! {
     ))] {
         uid!(libc::uid_t, std::str::FromStr);
         gid!(libc::gid_t);
-    } else if #[cfg(windows)] {
+    } else if #[cfg(all(windows, not(feature = "unknown-ci"), not(sysinfo_test_backend)))] {
         uid!(crate::windows::Sid);
         gid!(u32);
         // Manual implementation outside of the macro...
@@ -6,7 +6,7 @@ use std::net::{AddrParseError, IpAddr};
 use std::num::ParseIntError;
 use std::str::FromStr;
 
-use crate::{NetworkDataInner, NetworksInner};
+use crate::{NetworkDataInner, NetworksInner, RefreshError, RefreshTimestamp};
 
 /// Interacting with network interfaces.
 ///
@@ -20,6 +20,7 @@ use crate::{NetworkDataInner, NetworksInner};
 /// ```
 pub struct Networks {
     pub(crate) inner: NetworksInner,
+    pub(crate) last_refreshed_at: Option<RefreshTimestamp>,
 }
 
 impl<'a> IntoIterator for &'a Networks {
@@ -54,6 +55,7 @@ impl Networks {
     pub fn new() -> Self {
         Self {
             inner: NetworksInner::new(),
+            last_refreshed_at: None,
         }
     }
 
@@ -98,7 +100,138 @@ impl Networks {
     /// networks.refresh(true);
     /// ```
     pub fn refresh(&mut self, remove_not_listed_interfaces: bool) {
-        self.inner.refresh(remove_not_listed_interfaces)
+        self.inner.refresh(remove_not_listed_interfaces);
+        self.last_refreshed_at = Some(RefreshTimestamp::now());
+    }
+
+    /// Same as [`Networks::refresh`] but returns an error if the network interfaces cannot be
+    /// listed at all instead of considering that there are none.
+    ///
+    /// If an error is returned, the interfaces list is left untouched, even if
+    /// `remove_not_listed_interfaces` is `true`.
+    ///
+    /// ⚠️ Errors are only detected on Linux and Redox, where they come from reading and parsing
+    /// the source of the information. It never fails on the other platforms (the failures of
+    /// their system calls aren't reported) and always returns [`RefreshError::Unsupported`] on
+    /// unsupported platforms.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let mut networks = Networks::new();
+    /// if let Err(error) = networks.try_refresh(true) {
+    ///     eprintln!("failed to list network interfaces: {error}");
+    /// }
+    /// ```
+    pub fn try_refresh(&mut self, remove_not_listed_interfaces: bool) -> Result<(), RefreshError> {
+        self.inner.try_refresh(remove_not_listed_interfaces)?;
+        self.last_refreshed_at = Some(RefreshTimestamp::now());
+        Ok(())
+    }
+
+    /// Returns when the network interfaces were last refreshed, or `None` if they were never
+    /// refreshed.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let networks = Networks::new_with_refreshed_list();
+    /// if let Some(at) = networks.last_refreshed_at() {
+    ///     println!("Networks refreshed {:?} ago", at.instant().elapsed());
+    /// }
+    /// ```
+    pub fn last_refreshed_at(&self) -> Option<RefreshTimestamp> {
+        self.last_refreshed_at
+    }
+
+    /// Returns the DNS resolver configuration of the system.
+    ///
+    /// On Linux and FreeBSD, it comes from `/etc/resolv.conf` (or from the upstream
+    /// configuration of `systemd-resolved` if `/etc/resolv.conf` only points to its local stub).
+    /// On macOS, it comes from the `SystemConfiguration` framework and on Windows from the
+    /// configuration of the network adapters which are up.
+    ///
+    /// ⚠️ It always returns an empty configuration on iOS.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let dns = Networks::dns_configuration();
+    /// println!("nameservers: {:?}", dns.nameservers());
+    /// println!("search domains: {:?}", dns.search_domains());
+    /// ```
+    pub fn dns_configuration() -> DnsConfiguration {
+        NetworksInner::dns_configuration()
+    }
+
+    /// Returns the gateway of the default route, preferring IPv4 over IPv6.
+    ///
+    /// It comes from the routing table of the system (`/proc/net/route` on Linux, routing
+    /// sockets on macOS and FreeBSD, `GetIpForwardTable2` on Windows). If there are multiple
+    /// default routes, the one used by the system is returned.
+    ///
+    /// ⚠️ It always returns `None` on iOS.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// println!("default gateway: {:?}", Networks::default_gateway());
+    /// ```
+    pub fn default_gateway() -> Option<IpAddr> {
+        NetworksInner::default_gateway()
+    }
+
+    /// Returns the name of the network interface of the default route, preferring IPv4 over
+    /// IPv6. It can be used as key in the [`Networks`] list.
+    ///
+    /// ⚠️ It always returns `None` on iOS and Redox.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let networks = Networks::new_with_refreshed_list();
+    /// if let Some(name) = Networks::primary_interface() {
+    ///     println!("[{name}]: {:?}", networks.get(&name));
+    /// }
+    /// ```
+    pub fn primary_interface() -> Option<String> {
+        NetworksInner::primary_interface()
+    }
+
+    /// Returns the counters of the IP, ICMP, TCP and UDP protocols (IPv4 and IPv6 combined)
+    /// since the system booted, or `None` if they aren't available.
+    ///
+    /// Unlike the counters of [`NetworkData`], they tell about problems happening above the
+    /// network interfaces, like retransmitted TCP segments or UDP datagrams dropped because of
+    /// a full buffer, which helps to tell whether an application issue is actually a network
+    /// issue. Compare two values to know how much they increased in between.
+    ///
+    /// On Linux, they come from `/proc/net/snmp` and `/proc/net/snmp6`. On Windows, they come
+    /// from the `Get*StatisticsEx` functions of the IP Helper API.
+    ///
+    /// ⚠️ It always returns `None` on other systems.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// if let Some(stats) = Networks::protocol_stats() {
+    ///     println!(
+    ///         "{} TCP segments retransmitted out of {}",
+    ///         stats.tcp_retransmitted_segments(),
+    ///         stats.tcp_segments_sent(),
+    ///     );
+    /// }
+    /// ```
+    pub fn protocol_stats() -> Option<ProtocolStats> {
+        NetworksInner::protocol_stats()
     }
 }
 
@@ -365,6 +498,316 @@ impl NetworkData {
         self.inner.total_errors_on_transmitted()
     }
 
+    /// Returns the number of incoming packets dropped since the last refresh.
+    ///
+    /// Packets are usually dropped because of a lack of resources (like a full buffer).
+    ///
+    /// If you want the total number of dropped incoming packets, take a look at the
+    /// [`total_drops_on_received`](NetworkData::total_drops_on_received) method.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    /// use std::{thread, time};
+    ///
+    /// let mut networks = Networks::new_with_refreshed_list();
+    /// // Waiting a bit to get data from network...
+    /// thread::sleep(time::Duration::from_millis(10));
+    /// // Refreshing again to generate diff.
+    /// networks.refresh(true);
+    ///
+    /// for (interface_name, network) in &networks {
+    ///     println!("in: {}", network.drops_on_received());
+    /// }
+    /// ```
+    pub fn drops_on_received(&self) -> u64 {
+        self.inner.drops_on_received()
+    }
+
+    /// Returns the total number of dropped incoming packets.
+    ///
+    /// If you want the number of dropped incoming packets since the last refresh, take a look at
+    /// the [`drops_on_received`](NetworkData::drops_on_received) method.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let networks = Networks::new_with_refreshed_list();
+    /// for (interface_name, network) in &networks {
+    ///     println!("in: {}", network.total_drops_on_received());
+    /// }
+    /// ```
+    pub fn total_drops_on_received(&self) -> u64 {
+        self.inner.total_drops_on_received()
+    }
+
+    /// Returns the number of outcoming packets dropped since the last refresh.
+    ///
+    /// If you want the total number of dropped outcoming packets, take a look at the
+    /// [`total_drops_on_transmitted`](NetworkData::total_drops_on_transmitted) method.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    /// use std::{thread, time};
+    ///
+    /// let mut networks = Networks::new_with_refreshed_list();
+    /// // Waiting a bit to get data from network...
+    /// thread::sleep(time::Duration::from_millis(10));
+    /// // Refreshing again to generate diff.
+    /// networks.refresh(true);
+    ///
+    /// for (interface_name, network) in &networks {
+    ///     println!("out: {}", network.drops_on_transmitted());
+    /// }
+    /// ```
+    pub fn drops_on_transmitted(&self) -> u64 {
+        self.inner.drops_on_transmitted()
+    }
+
+    /// Returns the total number of dropped outcoming packets.
+    ///
+    /// If you want the number of dropped outcoming packets since the last refresh, take a look at
+    /// the [`drops_on_transmitted`](NetworkData::drops_on_transmitted) method.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let networks = Networks::new_with_refreshed_list();
+    /// for (interface_name, network) in &networks {
+    ///     println!("out: {}", network.total_drops_on_transmitted());
+    /// }
+    /// ```
+    pub fn total_drops_on_transmitted(&self) -> u64 {
+        self.inner.total_drops_on_transmitted()
+    }
+
+    /// Returns the number of FIFO buffer overrun errors on incoming packets since the last refresh.
+    ///
+    /// If you want the total number of FIFO buffer overrun errors on incoming packets, take a look
+    /// at the [`total_fifo_errors_on_received`](NetworkData::total_fifo_errors_on_received) method.
+    ///
+    /// ⚠️ It always returns `0` on systems other than Linux.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    /// use std::{thread, time};
+    ///
+    /// let mut networks = Networks::new_with_refreshed_list();
+    /// // Waiting a bit to get data from network...
+    /// thread::sleep(time::Duration::from_millis(10));
+    /// // Refreshing again to generate diff.
+    /// networks.refresh(true);
+    ///
+    /// for (interface_name, network) in &networks {
+    ///     println!("in: {}", network.fifo_errors_on_received());
+    /// }
+    /// ```
+    pub fn fifo_errors_on_received(&self) -> u64 {
+        self.inner.fifo_errors_on_received()
+    }
+
+    /// Returns the total number of FIFO buffer overrun errors on incoming packets.
+    ///
+    /// If you want the number of FIFO buffer overrun errors on incoming packets since the last
+    /// refresh, take a look at the
+    /// [`fifo_errors_on_received`](NetworkData::fifo_errors_on_received) method.
+    ///
+    /// ⚠️ It always returns `0` on systems other than Linux.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let networks = Networks::new_with_refreshed_list();
+    /// for (interface_name, network) in &networks {
+    ///     println!("in: {}", network.total_fifo_errors_on_received());
+    /// }
+    /// ```
+    pub fn total_fifo_errors_on_received(&self) -> u64 {
+        self.inner.total_fifo_errors_on_received()
+    }
+
+    /// Returns the number of FIFO buffer underrun errors on outcoming packets since the last
+    /// refresh.
+    ///
+    /// If you want the total number of FIFO buffer underrun errors on outcoming packets, take a
+    /// look at the
+    /// [`total_fifo_errors_on_transmitted`](NetworkData::total_fifo_errors_on_transmitted) method.
+    ///
+    /// ⚠️ It always returns `0` on systems other than Linux.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    /// use std::{thread, time};
+    ///
+    /// let mut networks = Networks::new_with_refreshed_list();
+    /// // Waiting a bit to get data from network...
+    /// thread::sleep(time::Duration::from_millis(10));
+    /// // Refreshing again to generate diff.
+    /// networks.refresh(true);
+    ///
+    /// for (interface_name, network) in &networks {
+    ///     println!("out: {}", network.fifo_errors_on_transmitted());
+    /// }
+    /// ```
+    pub fn fifo_errors_on_transmitted(&self) -> u64 {
+        self.inner.fifo_errors_on_transmitted()
+    }
+
+    /// Returns the total number of FIFO buffer underrun errors on outcoming packets.
+    ///
+    /// If you want the number of FIFO buffer underrun errors on outcoming packets since the last
+    /// refresh, take a look at the
+    /// [`fifo_errors_on_transmitted`](NetworkData::fifo_errors_on_transmitted) method.
+    ///
+    /// ⚠️ It always returns `0` on systems other than Linux.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let networks = Networks::new_with_refreshed_list();
+    /// for (interface_name, network) in &networks {
+    ///     println!("out: {}", network.total_fifo_errors_on_transmitted());
+    /// }
+    /// ```
+    pub fn total_fifo_errors_on_transmitted(&self) -> u64 {
+        self.inner.total_fifo_errors_on_transmitted()
+    }
+
+    /// Returns the number of collisions since the last refresh.
+    ///
+    /// Collisions only happen on half-duplex links.
+    ///
+    /// If you want the total number of collisions, take a look at the
+    /// [`total_collisions`](NetworkData::total_collisions) method.
+    ///
+    /// ⚠️ It always returns `0` on Windows.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    /// use std::{thread, time};
+    ///
+    /// let mut networks = Networks::new_with_refreshed_list();
+    /// // Waiting a bit to get data from network...
+    /// thread::sleep(time::Duration::from_millis(10));
+    /// // Refreshing again to generate diff.
+    /// networks.refresh(true);
+    ///
+    /// for (interface_name, network) in &networks {
+    ///     println!("collisions: {}", network.collisions());
+    /// }
+    /// ```
+    pub fn collisions(&self) -> u64 {
+        self.inner.collisions()
+    }
+
+    /// Returns the total number of collisions.
+    ///
+    /// If you want the number of collisions since the last refresh, take a look at the
+    /// [`collisions`](NetworkData::collisions) method.
+    ///
+    /// ⚠️ It always returns `0` on Windows.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let networks = Networks::new_with_refreshed_list();
+    /// for (interface_name, network) in &networks {
+    ///     println!("collisions: {}", network.total_collisions());
+    /// }
+    /// ```
+    pub fn total_collisions(&self) -> u64 {
+        self.inner.total_collisions()
+    }
+
+    /// Returns the number of carrier errors on outcoming packets since the last refresh.
+    ///
+    /// Carrier errors usually mean that the link is unstable (like a bad cable).
+    ///
+    /// If you want the total number of carrier errors on outcoming packets, take a look at the
+    /// [`total_carrier_errors`](NetworkData::total_carrier_errors) method.
+    ///
+    /// ⚠️ It always returns `0` on systems other than Linux.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    /// use std::{thread, time};
+    ///
+    /// let mut networks = Networks::new_with_refreshed_list();
+    /// // Waiting a bit to get data from network...
+    /// thread::sleep(time::Duration::from_millis(10));
+    /// // Refreshing again to generate diff.
+    /// networks.refresh(true);
+    ///
+    /// for (interface_name, network) in &networks {
+    ///     println!("out: {}", network.carrier_errors());
+    /// }
+    /// ```
+    pub fn carrier_errors(&self) -> u64 {
+        self.inner.carrier_errors()
+    }
+
+    /// Returns the total number of carrier errors on outcoming packets.
+    ///
+    /// If you want the number of carrier errors on outcoming packets since the last refresh, take a
+    /// look at the [`carrier_errors`](NetworkData::carrier_errors) method.
+    ///
+    /// ⚠️ It always returns `0` on systems other than Linux.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let networks = Networks::new_with_refreshed_list();
+    /// for (interface_name, network) in &networks {
+    ///     println!("out: {}", network.total_carrier_errors());
+    /// }
+    /// ```
+    pub fn total_carrier_errors(&self) -> u64 {
+        self.inner.total_carrier_errors()
+    }
+
+    /// Returns the number of incoming multicast packets since the last refresh.
+    ///
+    /// If you want the total number of incoming multicast packets, take a look at the
+    /// [`total_multicast_packets_received`](NetworkData::total_multicast_packets_received) method.
+    ///
+    /// ⚠️ It always returns `0` on Windows.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    /// use std::{thread, time};
+    ///
+    /// let mut networks = Networks::new_with_refreshed_list();
+    /// // Waiting a bit to get data from network...
+    /// thread::sleep(time::Duration::from_millis(10));
+    /// // Refreshing again to generate diff.
+    /// networks.refresh(true);
+    ///
+    /// for (interface_name, network) in &networks {
+    ///     println!("in: {}", network.multicast_packets_received());
+    /// }
+    /// ```
+    pub fn multicast_packets_received(&self) -> u64 {
+        self.inner.multicast_packets_received()
+    }
+
+    /// Returns the total number of incoming multicast packets.
+    ///
+    /// If you want the number of incoming multicast packets since the last refresh, take a look at
+    /// the [`multicast_packets_received`](NetworkData::multicast_packets_received) method.
+    ///
+    /// ⚠️ It always returns `0` on Windows.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let networks = Networks::new_with_refreshed_list();
+    /// for (interface_name, network) in &networks {
+    ///     println!("in: {}", network.total_multicast_packets_received());
+    /// }
+    /// ```
+    pub fn total_multicast_packets_received(&self) -> u64 {
+        self.inner.total_multicast_packets_received()
+    }
+
     /// Returns the MAC address associated to current interface.
     ///
     /// ```no_run
@@ -480,6 +923,283 @@ impl FromStr for MacAddr {
     }
 }
 
+/// DNS resolver configuration.
+///
+/// It is returned by [`Networks::dns_configuration`][crate::Networks::dns_configuration].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DnsConfiguration {
+    pub(crate) nameservers: Vec<IpAddr>,
+    pub(crate) search_domains: Vec<String>,
+}
+
+impl DnsConfiguration {
+    /// Returns the IP addresses of the configured nameservers, in order of preference.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// for nameserver in Networks::dns_configuration().nameservers() {
+    ///     println!("{nameserver}");
+    /// }
+    /// ```
+    pub fn nameservers(&self) -> &[IpAddr] {
+        &self.nameservers
+    }
+
+    /// Returns the domains appended to the names which are not fully qualified.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// for domain in Networks::dns_configuration().search_domains() {
+    ///     println!("{domain}");
+    /// }
+    /// ```
+    pub fn search_domains(&self) -> &[String] {
+        &self.search_domains
+    }
+}
+
+/// Counters of the IP, ICMP, TCP and UDP protocols since the system booted.
+///
+/// It is returned by [`Networks::protocol_stats`][crate::Networks::protocol_stats].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ProtocolStats {
+    pub(crate) ip_header_errors: u64,
+    pub(crate) ip_address_errors: u64,
+    pub(crate) ip_discards: u64,
+    pub(crate) ip_reassembly_failures: u64,
+    pub(crate) ip_fragment_failures: u64,
+    pub(crate) icmp_errors_received: u64,
+    pub(crate) icmp_errors_sent: u64,
+    pub(crate) tcp_segments_received: u64,
+    pub(crate) tcp_segments_sent: u64,
+    pub(crate) tcp_retransmitted_segments: u64,
+    pub(crate) tcp_errors_received: u64,
+    pub(crate) tcp_resets_sent: u64,
+    pub(crate) tcp_connection_resets: u64,
+    pub(crate) tcp_failed_connection_attempts: u64,
+    pub(crate) udp_receive_errors: u64,
+    pub(crate) udp_no_ports: u64,
+}
+
+impl ProtocolStats {
+    /// Returns the number of IP packets received and discarded because of an error in their
+    /// header (like a wrong checksum or TTL exceeded).
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// if let Some(stats) = Networks::protocol_stats() {
+    ///     println!("{}", stats.ip_header_errors());
+    /// }
+    /// ```
+    pub fn ip_header_errors(&self) -> u64 {
+        self.ip_header_errors
+    }
+
+    /// Returns the number of IP packets received and discarded because their destination
+    /// address isn't valid for this host.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// if let Some(stats) = Networks::protocol_stats() {
+    ///     println!("{}", stats.ip_address_errors());
+    /// }
+    /// ```
+    pub fn ip_address_errors(&self) -> u64 {
+        self.ip_address_errors
+    }
+
+    /// Returns the number of IP packets received without errors but discarded anyway (usually
+    /// because of a lack of buffer space).
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// if let Some(stats) = Networks::protocol_stats() {
+    ///     println!("{}", stats.ip_discards());
+    /// }
+    /// ```
+    pub fn ip_discards(&self) -> u64 {
+        self.ip_discards
+    }
+
+    /// Returns the number of failures while reassembling fragmented IP packets.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// if let Some(stats) = Networks::protocol_stats() {
+    ///     println!("{}", stats.ip_reassembly_failures());
+    /// }
+    /// ```
+    pub fn ip_reassembly_failures(&self) -> u64 {
+        self.ip_reassembly_failures
+    }
+
+    /// Returns the number of IP packets which needed to be fragmented but couldn't be (usually
+    /// because of the "don't fragment" flag).
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// if let Some(stats) = Networks::protocol_stats() {
+    ///     println!("{}", stats.ip_fragment_failures());
+    /// }
+    /// ```
+    pub fn ip_fragment_failures(&self) -> u64 {
+        self.ip_fragment_failures
+    }
+
+    /// Returns the number of ICMP messages received with an error (like a wrong checksum).
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// if let Some(stats) = Networks::protocol_stats() {
+    ///     println!("{}", stats.icmp_errors_received());
+    /// }
+    /// ```
+    pub fn icmp_errors_received(&self) -> u64 {
+        self.icmp_errors_received
+    }
+
+    /// Returns the number of ICMP messages which couldn't be sent.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// if let Some(stats) = Networks::protocol_stats() {
+    ///     println!("{}", stats.icmp_errors_sent());
+    /// }
+    /// ```
+    pub fn icmp_errors_sent(&self) -> u64 {
+        self.icmp_errors_sent
+    }
+
+    /// Returns the number of TCP segments received.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// if let Some(stats) = Networks::protocol_stats() {
+    ///     println!("{}", stats.tcp_segments_received());
+    /// }
+    /// ```
+    pub fn tcp_segments_received(&self) -> u64 {
+        self.tcp_segments_received
+    }
+
+    /// Returns the number of TCP segments sent, retransmitted ones excluded.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// if let Some(stats) = Networks::protocol_stats() {
+    ///     println!("{}", stats.tcp_segments_sent());
+    /// }
+    /// ```
+    pub fn tcp_segments_sent(&self) -> u64 {
+        self.tcp_segments_sent
+    }
+
+    /// Returns the number of TCP segments retransmitted. A high ratio of retransmitted segments
+    /// compared to [`ProtocolStats::tcp_segments_sent`] points to packet losses.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// if let Some(stats) = Networks::protocol_stats() {
+    ///     println!("{}", stats.tcp_retransmitted_segments());
+    /// }
+    /// ```
+    pub fn tcp_retransmitted_segments(&self) -> u64 {
+        self.tcp_retransmitted_segments
+    }
+
+    /// Returns the number of TCP segments received with an error (like a wrong checksum).
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// if let Some(stats) = Networks::protocol_stats() {
+    ///     println!("{}", stats.tcp_errors_received());
+    /// }
+    /// ```
+    pub fn tcp_errors_received(&self) -> u64 {
+        self.tcp_errors_received
+    }
+
+    /// Returns the number of TCP segments sent with the RST flag.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// if let Some(stats) = Networks::protocol_stats() {
+    ///     println!("{}", stats.tcp_resets_sent());
+    /// }
+    /// ```
+    pub fn tcp_resets_sent(&self) -> u64 {
+        self.tcp_resets_sent
+    }
+
+    /// Returns the number of established TCP connections which were reset, usually because an
+    /// RST was received.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// if let Some(stats) = Networks::protocol_stats() {
+    ///     println!("{}", stats.tcp_connection_resets());
+    /// }
+    /// ```
+    pub fn tcp_connection_resets(&self) -> u64 {
+        self.tcp_connection_resets
+    }
+
+    /// Returns the number of TCP connection attempts which failed.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// if let Some(stats) = Networks::protocol_stats() {
+    ///     println!("{}", stats.tcp_failed_connection_attempts());
+    /// }
+    /// ```
+    pub fn tcp_failed_connection_attempts(&self) -> u64 {
+        self.tcp_failed_connection_attempts
+    }
+
+    /// Returns the number of UDP datagrams received which couldn't be delivered for other
+    /// reasons than a closed port (like a full receive buffer).
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// if let Some(stats) = Networks::protocol_stats() {
+    ///     println!("{}", stats.udp_receive_errors());
+    /// }
+    /// ```
+    pub fn udp_receive_errors(&self) -> u64 {
+        self.udp_receive_errors
+    }
+
+    /// Returns the number of UDP datagrams received for a port on which nothing listens.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// if let Some(stats) = Networks::protocol_stats() {
+    ///     println!("{}", stats.udp_no_ports());
+    /// }
+    /// ```
+    pub fn udp_no_ports(&self) -> u64 {
+        self.udp_no_ports
+    }
+}
+
 /// IP networks address for network interface.
 ///
 /// It is returned by [`NetworkData::ip_networks`][crate::NetworkData::ip_networks].
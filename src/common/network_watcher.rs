@@ -0,0 +1,239 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::NetworkWatcherInner;
+use crate::common::watcher::EventReceiver;
+
+use std::io;
+use std::time::Duration;
+
+/// Watches the network interfaces being added, removed, or having their link going up or down,
+/// as a companion to [`Networks`][crate::Networks] which needs to be refreshed to notice them.
+///
+/// The watch starts when it is created and runs in a background thread until it is dropped.
+///
+/// Unlike [`Networks`][crate::Networks], all interfaces are reported, including the virtual
+/// ones.
+///
+/// ⚠️ On Linux, the changes are notified through a `rtnetlink` socket, on macOS and FreeBSD
+/// through a routing socket and on Windows with `NotifyIpInterfaceChange`. On unsupported
+/// systems, [`NetworkWatcher::start`] returns an error of kind [`io::ErrorKind::Unsupported`].
+///
+/// ```no_run
+/// use sysinfo::NetworkWatcher;
+/// use std::time::Duration;
+///
+/// let mut watcher = NetworkWatcher::start().expect("failed to watch network interfaces");
+/// loop {
+///     watcher.wait(Duration::from_secs(60));
+///     for event in watcher.events() {
+///         println!("{:?}: {}", event.kind(), event.interface_name());
+///     }
+/// }
+/// ```
+pub struct NetworkWatcher {
+    receiver: EventReceiver<NetworkEvent>,
+    events: Vec<NetworkEvent>,
+}
+
+impl NetworkWatcher {
+    /// Starts watching the network interfaces. The events are only available after
+    /// [`NetworkWatcher::refresh`] or [`NetworkWatcher::wait`] is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::NetworkWatcher;
+    ///
+    /// match NetworkWatcher::start() {
+    ///     Ok(_watcher) => println!("watching network interfaces"),
+    ///     Err(error) => eprintln!("cannot watch network interfaces: {error}"),
+    /// }
+    /// ```
+    pub fn start() -> io::Result<Self> {
+        let mut inner = NetworkWatcherInner::open()?;
+        let mut interfaces = inner.interfaces()?;
+        let receiver = EventReceiver::spawn("sysinfo-network-watcher", move |timeout| {
+            if !inner.wait(timeout) {
+                return Vec::new();
+            }
+            // If the interfaces cannot be listed, comparing an empty list with the previous one
+            // would report all interfaces as removed.
+            let new_interfaces = match inner.interfaces() {
+                Ok(new_interfaces) => new_interfaces,
+                Err(_error) => {
+                    sysinfo_debug!("failed to list the network interfaces: {_error:?}");
+                    return Vec::new();
+                }
+            };
+            let events = diff_interfaces(&interfaces, &new_interfaces);
+            interfaces = new_interfaces;
+            events
+        })?;
+        Ok(Self {
+            receiver,
+            events: Vec::new(),
+        })
+    }
+
+    /// Replaces the list of events with the ones which happened since the previous call to
+    /// this method (or to [`NetworkWatcher::wait`]), without blocking.
+    ///
+    /// ```no_run
+    /// use sysinfo::NetworkWatcher;
+    ///
+    /// let mut watcher = NetworkWatcher::start().expect("failed to watch network interfaces");
+    /// std::thread::sleep(std::time::Duration::from_secs(1));
+    /// watcher.refresh();
+    /// println!("{} events", watcher.events().len());
+    /// ```
+    pub fn refresh(&mut self) {
+        self.events = self.receiver.take();
+    }
+
+    /// Same as [`NetworkWatcher::refresh`] but blocks until at least one event happens or until
+    /// `timeout` elapses.
+    ///
+    /// ```no_run
+    /// use sysinfo::NetworkWatcher;
+    /// use std::time::Duration;
+    ///
+    /// let mut watcher = NetworkWatcher::start().expect("failed to watch network interfaces");
+    /// watcher.wait(Duration::from_secs(10));
+    /// if watcher.events().is_empty() {
+    ///     println!("no network interface changed");
+    /// }
+    /// ```
+    pub fn wait(&mut self, timeout: Duration) {
+        self.events = self.receiver.wait(timeout);
+    }
+
+    /// Returns the events retrieved by the last call to [`NetworkWatcher::refresh`] or
+    /// [`NetworkWatcher::wait`], in the order they happened.
+    ///
+    /// ```no_run
+    /// use sysinfo::NetworkWatcher;
+    ///
+    /// let mut watcher = NetworkWatcher::start().expect("failed to watch network interfaces");
+    /// watcher.refresh();
+    /// for event in watcher.events() {
+    ///     println!("{event:?}");
+    /// }
+    /// ```
+    pub fn events(&self) -> &[NetworkEvent] {
+        &self.events
+    }
+}
+
+/// Kind of a [`NetworkEvent`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub enum NetworkEventKind {
+    /// A network interface was added.
+    Added,
+    /// A network interface was removed.
+    Removed,
+    /// The link of a network interface went up or down.
+    LinkStateChanged,
+}
+
+/// A change of the network interfaces, retrieved by [`NetworkWatcher`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NetworkEvent {
+    pub(crate) kind: NetworkEventKind,
+    pub(crate) interface_name: String,
+    pub(crate) is_up: bool,
+}
+
+impl NetworkEvent {
+    /// Returns what happened to the network interface.
+    pub fn kind(&self) -> NetworkEventKind {
+        self.kind
+    }
+
+    /// Returns the name of the network interface (like the keys of
+    /// [`Networks::list`][crate::Networks::list]).
+    pub fn interface_name(&self) -> &str {
+        &self.interface_name
+    }
+
+    /// Returns `true` if the link of the network interface is up and running. For
+    /// [`NetworkEventKind::Removed`], it is the value before it was removed.
+    pub fn is_up(&self) -> bool {
+        self.is_up
+    }
+}
+
+/// A network interface, as listed by a `NetworkWatcherInner`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct InterfaceState {
+    pub(crate) name: String,
+    pub(crate) is_up: bool,
+}
+
+impl InterfaceState {
+    fn to_event(&self, kind: NetworkEventKind) -> NetworkEvent {
+        NetworkEvent {
+            kind,
+            interface_name: self.name.clone(),
+            is_up: self.is_up,
+        }
+    }
+}
+
+fn diff_interfaces(old: &[InterfaceState], new: &[InterfaceState]) -> Vec<NetworkEvent> {
+    let mut events = Vec::new();
+    for interface in old {
+        if !new
+            .iter()
+            .any(|new_interface| new_interface.name == interface.name)
+        {
+            events.push(interface.to_event(NetworkEventKind::Removed));
+        }
+    }
+    for interface in new {
+        match old
+            .iter()
+            .find(|old_interface| old_interface.name == interface.name)
+        {
+            None => events.push(interface.to_event(NetworkEventKind::Added)),
+            Some(old_interface) if old_interface.is_up != interface.is_up => {
+                events.push(interface.to_event(NetworkEventKind::LinkStateChanged))
+            }
+            Some(_) => {}
+        }
+    }
+    events
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn interface(name: &str, is_up: bool) -> InterfaceState {
+        InterfaceState {
+            name: name.to_owned(),
+            is_up,
+        }
+    }
+
+    #[test]
+    fn check_diff_interfaces() {
+        let old = [
+            interface("lo", true),
+            interface("eth0", true),
+            interface("wlan0", false),
+        ];
+        let new = [
+            interface("lo", true),
+            interface("wlan0", true),
+            interface("usb0", false),
+        ];
+        assert_eq!(
+            diff_interfaces(&old, &new),
+            [
+                old[1].to_event(NetworkEventKind::Removed),
+                new[1].to_event(NetworkEventKind::LinkStateChanged),
+                new[2].to_event(NetworkEventKind::Added),
+            ]
+        );
+        assert!(diff_interfaces(&new, &new).is_empty());
+    }
+}
@@ -0,0 +1,277 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::{Pid, Process, ProcessHandle, ProcessRefreshKind, ProcessesToUpdate, System};
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// Supervises a known set of processes (like the workers of a service) at a high frequency.
+///
+/// Only the tracked processes are refreshed and the resources used to retrieve their
+/// information are kept between refreshes (the `stat` files and the pidfds on Linux), so a
+/// refresh is much cheaper than refreshing all processes of a [`System`].
+///
+/// After each refresh, the [`PidDelta`] of each tracked process gives how much CPU, memory and
+/// disk I/O it used since the previous refresh. If a PID was reused by another process (detected
+/// by comparing their [`ProcessHandle`]), the new process is tracked from scratch.
+///
+/// ```no_run
+/// use sysinfo::{Pid, PidSetTracker};
+///
+/// let mut tracker = PidSetTracker::new([Pid::from(1337), Pid::from(1338)]);
+/// loop {
+///     std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+///     tracker.refresh();
+///     for (pid, delta) in tracker.deltas() {
+///         println!(
+///             "[{pid}] {}% CPU, {} bytes of memory, {} bytes read",
+///             delta.cpu_usage, delta.memory, delta.read_bytes,
+///         );
+///     }
+/// }
+/// ```
+pub struct PidSetTracker {
+    system: System,
+    pids: Vec<Pid>,
+    refresh_kind: ProcessRefreshKind,
+    states: HashMap<Pid, PidState>,
+    last_refresh: Option<Instant>,
+}
+
+struct PidState {
+    handle: ProcessHandle,
+    accumulated_cpu_time: u64,
+    memory: u64,
+    delta: PidDelta,
+}
+
+/// Resources used by a process tracked by a [`PidSetTracker`] between its two last refreshes.
+/// It is returned by [`PidSetTracker::delta`].
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct PidDelta {
+    /// Time elapsed between the two last refreshes, or `None` if the process was refreshed only
+    /// once since it was tracked (or since its PID was reused).
+    pub interval: Option<Duration>,
+    /// CPU usage (in %) of the process, as returned by [`Process::cpu_usage`].
+    pub cpu_usage: f32,
+    /// CPU time (in milliseconds) used by the process.
+    pub cpu_time: u64,
+    /// Change of the memory (in bytes) used by the process.
+    pub memory: i64,
+    /// Number of bytes read by the process.
+    pub read_bytes: u64,
+    /// Number of bytes written by the process.
+    pub written_bytes: u64,
+    /// Whether the PID was reused by another process since the previous refresh. In this case,
+    /// the other values only concern the new process.
+    pub pid_reused: bool,
+}
+
+impl PidSetTracker {
+    /// Creates a new `PidSetTracker` tracking the given processes. Nothing is retrieved until
+    /// [`PidSetTracker::refresh`] is called.
+    ///
+    /// By default, the memory, the CPU usage and the disk usage of the processes are refreshed.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, PidSetTracker};
+    ///
+    /// let tracker = PidSetTracker::new([Pid::from(1337)]);
+    /// ```
+    pub fn new<I: IntoIterator<Item = Pid>>(pids: I) -> Self {
+        let mut system = System::new();
+        system.set_use_pidfds(true);
+        let mut tracker = Self {
+            system,
+            pids: Vec::new(),
+            refresh_kind: ProcessRefreshKind::nothing()
+                .with_memory()
+                .with_cpu()
+                .with_disk_usage(),
+            states: HashMap::new(),
+            last_refresh: None,
+        };
+        for pid in pids {
+            tracker.add(pid);
+        }
+        tracker
+    }
+
+    /// Sets what is refreshed for each tracked process. The memory, the CPU usage and the disk
+    /// usage are needed to compute the corresponding values of [`PidDelta`].
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, PidSetTracker, ProcessRefreshKind};
+    ///
+    /// let tracker = PidSetTracker::new([Pid::from(1337)])
+    ///     .with_refresh_kind(ProcessRefreshKind::nothing().with_cpu());
+    /// ```
+    pub fn with_refresh_kind(mut self, refresh_kind: ProcessRefreshKind) -> Self {
+        self.refresh_kind = refresh_kind;
+        self
+    }
+
+    /// Starts tracking `pid`. Returns `false` if it was already tracked.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, PidSetTracker};
+    ///
+    /// let mut tracker = PidSetTracker::new([]);
+    /// tracker.add(Pid::from(1337));
+    /// ```
+    pub fn add(&mut self, pid: Pid) -> bool {
+        if self.pids.contains(&pid) {
+            return false;
+        }
+        self.pids.push(pid);
+        true
+    }
+
+    /// Stops tracking `pid`. Returns `false` if it wasn't tracked.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, PidSetTracker};
+    ///
+    /// let mut tracker = PidSetTracker::new([Pid::from(1337)]);
+    /// assert!(tracker.remove(Pid::from(1337)));
+    /// ```
+    pub fn remove(&mut self, pid: Pid) -> bool {
+        let Some(pos) = self.pids.iter().position(|p| *p == pid) else {
+            return false;
+        };
+        self.pids.swap_remove(pos);
+        self.states.remove(&pid);
+        // Refreshing only the tracked processes doesn't remove the other ones from `system`, so
+        // we need to do it here to release the resources kept for this process.
+        self.system.inner.processes_mut().remove(&pid);
+        true
+    }
+
+    /// Returns the tracked PIDs.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, PidSetTracker};
+    ///
+    /// let tracker = PidSetTracker::new([Pid::from(1337)]);
+    /// assert_eq!(tracker.pids(), &[Pid::from(1337)]);
+    /// ```
+    pub fn pids(&self) -> &[Pid] {
+        &self.pids
+    }
+
+    /// Refreshes the tracked processes and computes their [`PidDelta`].
+    ///
+    /// The processes which don't exist anymore have no [`Process`] nor [`PidDelta`] until their
+    /// PID is used again, but they stay tracked.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, PidSetTracker};
+    ///
+    /// let mut tracker = PidSetTracker::new([Pid::from(1337)]);
+    /// tracker.refresh();
+    /// ```
+    pub fn refresh(&mut self) {
+        self.system.refresh_processes_specifics(
+            ProcessesToUpdate::Some(&self.pids),
+            true,
+            self.refresh_kind,
+        );
+        let now = Instant::now();
+        let interval = self.last_refresh.map(|last| now.duration_since(last));
+        self.last_refresh = Some(now);
+
+        for pid in &self.pids {
+            let Some(process) = self.system.process(*pid) else {
+                self.states.remove(pid);
+                continue;
+            };
+            let disk_usage = process.disk_usage();
+            let accumulated_cpu_time = process.accumulated_cpu_time();
+            let memory = process.memory();
+            let handle = process.handle();
+            let state = match self.states.get_mut(pid) {
+                Some(state) if state.handle == handle => state,
+                state => {
+                    let pid_reused = state.is_some();
+                    self.states.insert(
+                        *pid,
+                        PidState {
+                            handle,
+                            accumulated_cpu_time,
+                            memory,
+                            delta: PidDelta {
+                                cpu_usage: process.cpu_usage(),
+                                pid_reused,
+                                ..PidDelta::default()
+                            },
+                        },
+                    );
+                    continue;
+                }
+            };
+            state.delta = PidDelta {
+                interval,
+                cpu_usage: process.cpu_usage(),
+                cpu_time: accumulated_cpu_time.saturating_sub(state.accumulated_cpu_time),
+                memory: memory as i64 - state.memory as i64,
+                read_bytes: disk_usage.read_bytes,
+                written_bytes: disk_usage.written_bytes,
+                pid_reused: false,
+            };
+            state.accumulated_cpu_time = accumulated_cpu_time;
+            state.memory = memory;
+        }
+    }
+
+    /// Returns the information of the tracked process `pid` retrieved by the last refresh, or
+    /// `None` if it isn't tracked or doesn't exist.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, PidSetTracker};
+    ///
+    /// let mut tracker = PidSetTracker::new([Pid::from(1337)]);
+    /// tracker.refresh();
+    /// if let Some(process) = tracker.process(Pid::from(1337)) {
+    ///     println!("{:?}", process.name());
+    /// }
+    /// ```
+    pub fn process(&self, pid: Pid) -> Option<&Process> {
+        if !self.pids.contains(&pid) {
+            return None;
+        }
+        self.system.process(pid)
+    }
+
+    /// Returns the resources used by the tracked process `pid` between the two last refreshes,
+    /// or `None` if it isn't tracked or doesn't exist.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, PidSetTracker};
+    ///
+    /// let mut tracker = PidSetTracker::new([Pid::from(1337)]);
+    /// tracker.refresh();
+    /// std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+    /// tracker.refresh();
+    /// if let Some(delta) = tracker.delta(Pid::from(1337)) {
+    ///     println!("{}ms of CPU time in {:?}", delta.cpu_time, delta.interval);
+    /// }
+    /// ```
+    pub fn delta(&self, pid: Pid) -> Option<&PidDelta> {
+        self.states.get(&pid).map(|state| &state.delta)
+    }
+
+    /// Returns an iterator over the [`PidDelta`] of the tracked processes which exist.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, PidSetTracker};
+    ///
+    /// let mut tracker = PidSetTracker::new([Pid::from(1337), Pid::from(1338)]);
+    /// tracker.refresh();
+    /// for (pid, delta) in tracker.deltas() {
+    ///     println!("[{pid}] {:?}", delta);
+    /// }
+    /// ```
+    pub fn deltas(&self) -> impl Iterator<Item = (Pid, &PidDelta)> + '_ {
+        self.states.iter().map(|(pid, state)| (*pid, &state.delta))
+    }
+}
@@ -0,0 +1,738 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+//! Recording of the information retrieved by `sysinfo`, to reproduce issues offline.
+//!
+//! A [`Recorder`] writes a [`Snapshot`] of a [`System`] (and optionally of a [`Disks`]) after
+//! each refresh in a file, one JSON object per line. [`read_snapshots`] reads this file back and
+//! the information of each snapshot can then be read through its getters. With
+//! `--cfg sysinfo_test_backend`, a `Replay` also allows to feed each snapshot, with its original
+//! timestamp, to a [`System`] through a `FakeSystem`.
+//!
+//! ```no_run
+//! use sysinfo::record::Recorder;
+//! use sysinfo::{Disks, System};
+//!
+//! let mut recorder = Recorder::create("sysinfo-recording.jsonl").expect("failed to create file");
+//! let mut s = System::new_all();
+//! let mut disks = Disks::new_with_refreshed_list();
+//! for _ in 0..10 {
+//!     std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+//!     s.refresh_all();
+//!     disks.refresh(true);
+//!     recorder.record(&s, Some(&disks)).expect("failed to write snapshot");
+//! }
+//! ```
+//!
+//! [`System`]: crate::System
+//! [`Disks`]: crate::Disks
+
+use crate::{DiskKind, DiskUsage, Disks, Pid, ProcessStatus, System};
+
+use std::ffi::OsStr;
+use std::fs::File;
+use std::io::{self, BufRead, BufWriter, Write};
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// The information of a [`System`] (and optionally of a [`Disks`]) at a given time.
+///
+/// It is created with [`Snapshot::capture`], written by a [`Recorder`] and read back by
+/// [`read_snapshots`].
+///
+/// ```no_run
+/// use std::fs::File;
+/// use std::io::BufReader;
+///
+/// let file = File::open("sysinfo-recording.jsonl").expect("failed to open file");
+/// let snapshots = sysinfo::record::read_snapshots(BufReader::new(file))
+///     .expect("invalid recording");
+/// for snapshot in &snapshots {
+///     println!("[{}] used memory: {}", snapshot.timestamp(), snapshot.used_memory());
+///     for process in snapshot.processes() {
+///         println!("  [{}] {:?}: {}%", process.pid(), process.name(), process.cpu_usage());
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct Snapshot {
+    pub(crate) timestamp: u64,
+    pub(crate) total_memory: u64,
+    pub(crate) used_memory: u64,
+    pub(crate) total_swap: u64,
+    pub(crate) used_swap: u64,
+    pub(crate) global_cpu_usage: f32,
+    pub(crate) cpus: Vec<CpuSnapshot>,
+    pub(crate) processes: Vec<ProcessSnapshot>,
+    pub(crate) disks: Option<Vec<DiskSnapshot>>,
+}
+
+/// The information of a [`Cpu`][crate::Cpu] stored in a [`Snapshot`].
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct CpuSnapshot {
+    pub(crate) name: String,
+    pub(crate) vendor_id: String,
+    pub(crate) brand: String,
+    pub(crate) frequency: u64,
+    pub(crate) cpu_usage: f32,
+}
+
+/// The information of a [`Process`][crate::Process] stored in a [`Snapshot`].
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct ProcessSnapshot {
+    pub(crate) pid: u32,
+    pub(crate) parent: Option<u32>,
+    pub(crate) name: String,
+    pub(crate) cmd: Vec<String>,
+    pub(crate) exe: Option<String>,
+    pub(crate) status: ProcessStatus,
+    pub(crate) memory: u64,
+    pub(crate) virtual_memory: u64,
+    pub(crate) thread_count: Option<usize>,
+    pub(crate) start_time: u64,
+    pub(crate) run_time: u64,
+    pub(crate) cpu_usage: f32,
+    pub(crate) accumulated_cpu_time: u64,
+    pub(crate) read_bytes: u64,
+    pub(crate) total_read_bytes: u64,
+    pub(crate) written_bytes: u64,
+    pub(crate) total_written_bytes: u64,
+}
+
+/// The information of a [`Disk`][crate::Disk] stored in a [`Snapshot`].
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct DiskSnapshot {
+    pub(crate) name: String,
+    pub(crate) mount_point: String,
+    pub(crate) file_system: String,
+    pub(crate) kind: DiskKind,
+    pub(crate) total_space: u64,
+    pub(crate) available_space: u64,
+    pub(crate) is_removable: bool,
+    pub(crate) is_read_only: bool,
+    pub(crate) read_bytes: u64,
+    pub(crate) total_read_bytes: u64,
+    pub(crate) written_bytes: u64,
+    pub(crate) total_written_bytes: u64,
+}
+
+impl Snapshot {
+    /// Captures the information currently stored in `system` and `disks`. Nothing is refreshed.
+    ///
+    /// ```no_run
+    /// use sysinfo::record::Snapshot;
+    /// use sysinfo::System;
+    ///
+    /// let s = System::new_all();
+    /// let snapshot = Snapshot::capture(&s, None);
+    /// println!("captured at {}", snapshot.timestamp());
+    /// ```
+    pub fn capture(system: &System, disks: Option<&Disks>) -> Self {
+        let mut processes = system
+            .processes()
+            .values()
+            .map(|p| {
+                let usage = p.disk_usage();
+                ProcessSnapshot {
+                    pid: p.pid().as_u32(),
+                    parent: p.parent().map(Pid::as_u32),
+                    name: p.name().to_string_lossy().into_owned(),
+                    cmd: p
+                        .cmd()
+                        .iter()
+                        .map(|arg| arg.to_string_lossy().into_owned())
+                        .collect(),
+                    exe: p.exe().map(|exe| exe.to_string_lossy().into_owned()),
+                    status: p.status(),
+                    memory: p.memory(),
+                    virtual_memory: p.virtual_memory(),
+                    thread_count: p.thread_count(),
+                    start_time: p.start_time(),
+                    run_time: p.run_time(),
+                    cpu_usage: p.cpu_usage(),
+                    accumulated_cpu_time: p.accumulated_cpu_time(),
+                    read_bytes: usage.read_bytes,
+                    total_read_bytes: usage.total_read_bytes,
+                    written_bytes: usage.written_bytes,
+                    total_written_bytes: usage.total_written_bytes,
+                }
+            })
+            .collect::<Vec<_>>();
+        // To make recordings easier to compare.
+        processes.sort_unstable_by_key(|p| p.pid);
+
+        Self {
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_millis() as u64)
+                .unwrap_or(0),
+            total_memory: system.total_memory(),
+            used_memory: system.used_memory(),
+            total_swap: system.total_swap(),
+            used_swap: system.used_swap(),
+            global_cpu_usage: system.global_cpu_usage(),
+            cpus: system
+                .cpus()
+                .iter()
+                .map(|cpu| CpuSnapshot {
+                    name: cpu.name().to_owned(),
+                    vendor_id: cpu.vendor_id().to_owned(),
+                    brand: cpu.brand().to_owned(),
+                    frequency: cpu.frequency(),
+                    cpu_usage: cpu.cpu_usage(),
+                })
+                .collect(),
+            processes,
+            disks: disks.map(|disks| {
+                disks
+                    .list()
+                    .iter()
+                    .map(|disk| {
+                        let usage = disk.usage();
+                        DiskSnapshot {
+                            name: disk.name().to_string_lossy().into_owned(),
+                            mount_point: disk.mount_point().to_string_lossy().into_owned(),
+                            file_system: disk.file_system().to_string_lossy().into_owned(),
+                            kind: disk.kind(),
+                            total_space: disk.total_space(),
+                            available_space: disk.available_space(),
+                            is_removable: disk.is_removable(),
+                            is_read_only: disk.is_read_only(),
+                            read_bytes: usage.read_bytes,
+                            total_read_bytes: usage.total_read_bytes,
+                            written_bytes: usage.written_bytes,
+                            total_written_bytes: usage.total_written_bytes,
+                        }
+                    })
+                    .collect()
+            }),
+        }
+    }
+
+    /// Returns the time (in milliseconds since UNIX epoch) when this snapshot was captured.
+    pub fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+
+    /// Returns the value of [`System::total_memory`] when this snapshot was captured.
+    pub fn total_memory(&self) -> u64 {
+        self.total_memory
+    }
+
+    /// Returns the value of [`System::used_memory`] when this snapshot was captured.
+    pub fn used_memory(&self) -> u64 {
+        self.used_memory
+    }
+
+    /// Returns the value of [`System::total_swap`] when this snapshot was captured.
+    pub fn total_swap(&self) -> u64 {
+        self.total_swap
+    }
+
+    /// Returns the value of [`System::used_swap`] when this snapshot was captured.
+    pub fn used_swap(&self) -> u64 {
+        self.used_swap
+    }
+
+    /// Returns the value of [`System::global_cpu_usage`] when this snapshot was captured.
+    pub fn global_cpu_usage(&self) -> f32 {
+        self.global_cpu_usage
+    }
+
+    /// Returns the CPUs.
+    pub fn cpus(&self) -> &[CpuSnapshot] {
+        &self.cpus
+    }
+
+    /// Returns the processes, sorted by PID.
+    pub fn processes(&self) -> &[ProcessSnapshot] {
+        &self.processes
+    }
+
+    /// Returns the process with the given `pid`, if any.
+    pub fn process(&self, pid: Pid) -> Option<&ProcessSnapshot> {
+        self.processes
+            .binary_search_by_key(&pid.as_u32(), |p| p.pid)
+            .ok()
+            .map(|pos| &self.processes[pos])
+    }
+
+    /// Returns the disks, or `None` if no [`Disks`] was given to [`Snapshot::capture`].
+    pub fn disks(&self) -> Option<&[DiskSnapshot]> {
+        self.disks.as_deref()
+    }
+}
+
+impl CpuSnapshot {
+    /// Returns the value of [`Cpu::name`][crate::Cpu::name].
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the value of [`Cpu::vendor_id`][crate::Cpu::vendor_id].
+    pub fn vendor_id(&self) -> &str {
+        &self.vendor_id
+    }
+
+    /// Returns the value of [`Cpu::brand`][crate::Cpu::brand].
+    pub fn brand(&self) -> &str {
+        &self.brand
+    }
+
+    /// Returns the value of [`Cpu::frequency`][crate::Cpu::frequency].
+    pub fn frequency(&self) -> u64 {
+        self.frequency
+    }
+
+    /// Returns the value of [`Cpu::cpu_usage`][crate::Cpu::cpu_usage].
+    pub fn cpu_usage(&self) -> f32 {
+        self.cpu_usage
+    }
+}
+
+impl ProcessSnapshot {
+    /// Returns the value of [`Process::pid`][crate::Process::pid].
+    pub fn pid(&self) -> Pid {
+        Pid::from_u32(self.pid)
+    }
+
+    /// Returns the value of [`Process::parent`][crate::Process::parent].
+    pub fn parent(&self) -> Option<Pid> {
+        self.parent.map(Pid::from_u32)
+    }
+
+    /// Returns the value of [`Process::name`][crate::Process::name].
+    pub fn name(&self) -> &OsStr {
+        OsStr::new(&self.name)
+    }
+
+    /// Returns the value of [`Process::cmd`][crate::Process::cmd]. Arguments which weren't
+    /// valid UTF-8 were converted lossily.
+    pub fn cmd(&self) -> &[String] {
+        &self.cmd
+    }
+
+    /// Returns the value of [`Process::exe`][crate::Process::exe].
+    pub fn exe(&self) -> Option<&Path> {
+        self.exe.as_deref().map(Path::new)
+    }
+
+    /// Returns the value of [`Process::status`][crate::Process::status].
+    pub fn status(&self) -> ProcessStatus {
+        self.status
+    }
+
+    /// Returns the value of [`Process::memory`][crate::Process::memory].
+    pub fn memory(&self) -> u64 {
+        self.memory
+    }
+
+    /// Returns the value of [`Process::virtual_memory`][crate::Process::virtual_memory].
+    pub fn virtual_memory(&self) -> u64 {
+        self.virtual_memory
+    }
+
+    /// Returns the value of [`Process::thread_count`][crate::Process::thread_count].
+    pub fn thread_count(&self) -> Option<usize> {
+        self.thread_count
+    }
+
+    /// Returns the value of [`Process::start_time`][crate::Process::start_time].
+    pub fn start_time(&self) -> u64 {
+        self.start_time
+    }
+
+    /// Returns the value of [`Process::run_time`][crate::Process::run_time].
+    pub fn run_time(&self) -> u64 {
+        self.run_time
+    }
+
+    /// Returns the value of [`Process::cpu_usage`][crate::Process::cpu_usage].
+    pub fn cpu_usage(&self) -> f32 {
+        self.cpu_usage
+    }
+
+    /// Returns the value of
+    /// [`Process::accumulated_cpu_time`][crate::Process::accumulated_cpu_time].
+    pub fn accumulated_cpu_time(&self) -> u64 {
+        self.accumulated_cpu_time
+    }
+
+    /// Returns the value of [`Process::disk_usage`][crate::Process::disk_usage].
+    pub fn disk_usage(&self) -> DiskUsage {
+        DiskUsage {
+            read_bytes: self.read_bytes,
+            total_read_bytes: self.total_read_bytes,
+            written_bytes: self.written_bytes,
+            total_written_bytes: self.total_written_bytes,
+        }
+    }
+}
+
+impl DiskSnapshot {
+    /// Returns the value of [`Disk::name`][crate::Disk::name].
+    pub fn name(&self) -> &OsStr {
+        OsStr::new(&self.name)
+    }
+
+    /// Returns the value of [`Disk::mount_point`][crate::Disk::mount_point].
+    pub fn mount_point(&self) -> &Path {
+        Path::new(&self.mount_point)
+    }
+
+    /// Returns the value of [`Disk::file_system`][crate::Disk::file_system].
+    pub fn file_system(&self) -> &OsStr {
+        OsStr::new(&self.file_system)
+    }
+
+    /// Returns the value of [`Disk::kind`][crate::Disk::kind].
+    pub fn kind(&self) -> DiskKind {
+        self.kind
+    }
+
+    /// Returns the value of [`Disk::total_space`][crate::Disk::total_space].
+    pub fn total_space(&self) -> u64 {
+        self.total_space
+    }
+
+    /// Returns the value of [`Disk::available_space`][crate::Disk::available_space].
+    pub fn available_space(&self) -> u64 {
+        self.available_space
+    }
+
+    /// Returns the value of [`Disk::is_removable`][crate::Disk::is_removable].
+    pub fn is_removable(&self) -> bool {
+        self.is_removable
+    }
+
+    /// Returns the value of [`Disk::is_read_only`][crate::Disk::is_read_only].
+    pub fn is_read_only(&self) -> bool {
+        self.is_read_only
+    }
+
+    /// Returns the value of [`Disk::usage`][crate::Disk::usage].
+    pub fn usage(&self) -> DiskUsage {
+        DiskUsage {
+            read_bytes: self.read_bytes,
+            total_read_bytes: self.total_read_bytes,
+            written_bytes: self.written_bytes,
+            total_written_bytes: self.total_written_bytes,
+        }
+    }
+}
+
+/// Writes [`Snapshot`]s into a file (or any [`Write`] implementor), one JSON object per line.
+///
+/// ```no_run
+/// use sysinfo::record::Recorder;
+/// use sysinfo::System;
+///
+/// let mut recorder = Recorder::new(Vec::new());
+/// let s = System::new_all();
+/// recorder.record(&s, None).expect("failed to write snapshot");
+/// let recording = recorder.into_inner().expect("failed to flush");
+/// ```
+pub struct Recorder<W: Write> {
+    writer: W,
+}
+
+impl Recorder<BufWriter<File>> {
+    /// Creates (or truncates) the file at `path` and returns a [`Recorder`] writing into it.
+    pub fn create(path: impl AsRef<Path>) -> io::Result<Self> {
+        Ok(Self::new(BufWriter::new(File::create(path)?)))
+    }
+}
+
+impl<W: Write> Recorder<W> {
+    /// Creates a new [`Recorder`] writing into `writer`.
+    pub fn new(writer: W) -> Self {
+        Self { writer }
+    }
+
+    /// Captures a [`Snapshot`] of `system` and `disks` and writes it. Nothing is refreshed, so
+    /// it should be called after the refresh methods.
+    pub fn record(&mut self, system: &System, disks: Option<&Disks>) -> io::Result<()> {
+        self.write_snapshot(&Snapshot::capture(system, disks))
+    }
+
+    /// Writes `snapshot`.
+    pub fn write_snapshot(&mut self, snapshot: &Snapshot) -> io::Result<()> {
+        serde_json::to_writer(&mut self.writer, snapshot)?;
+        self.writer.write_all(b"\n")?;
+        self.writer.flush()
+    }
+
+    /// Flushes and returns the underlying writer.
+    pub fn into_inner(mut self) -> io::Result<W> {
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+}
+
+/// Reads all the [`Snapshot`]s written by a [`Recorder`]. Empty lines are ignored.
+///
+/// ```no_run
+/// use std::fs::File;
+/// use std::io::BufReader;
+///
+/// let file = File::open("sysinfo-recording.jsonl").expect("failed to open file");
+/// let snapshots = sysinfo::record::read_snapshots(BufReader::new(file))
+///     .expect("invalid recording");
+/// println!("{} snapshots", snapshots.len());
+/// ```
+pub fn read_snapshots(reader: impl BufRead) -> io::Result<Vec<Snapshot>> {
+    let mut snapshots = Vec::new();
+    for line in reader.lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        snapshots.push(serde_json::from_str(&line)?);
+    }
+    Ok(snapshots)
+}
+
+cfg_if! {
+    if #[cfg(all(sysinfo_test_backend, not(feature = "unknown-ci")))] {
+        use crate::fake::{FakeCpu, FakeDisk, FakeProcess, FakeSystem};
+
+        use std::io::BufReader;
+
+        /// Feeds the [`Snapshot`]s written by a [`Recorder`] to a [`FakeSystem`], one by one.
+        ///
+        /// The [`System`] and [`Disks`] created by [`Replay::system`] and [`Replay::disks`]
+        /// return the information of the current snapshot after they're refreshed.
+        ///
+        /// ```
+        /// use sysinfo::record::{Recorder, Replay};
+        ///
+        /// // A recording made on another computer.
+        /// # let fake = sysinfo::fake::FakeSystem::new();
+        /// # fake.add_process(sysinfo::fake::FakeProcess::new(sysinfo::Pid::from(1), "init"));
+        /// # let mut recorder = Recorder::new(Vec::new());
+        /// # recorder.record(&fake.system(), None).unwrap();
+        /// # recorder.record(&fake.system(), None).unwrap();
+        /// # let recording = recorder.into_inner().unwrap();
+        /// let mut replay = Replay::from_reader(&recording[..]).expect("invalid recording");
+        /// let mut s = replay.system();
+        /// loop {
+        ///     println!("[{}] CPU usage: {}%", replay.timestamp(), s.global_cpu_usage());
+        ///     if !replay.advance() {
+        ///         break;
+        ///     }
+        ///     s.refresh_all();
+        /// }
+        /// ```
+        pub struct Replay {
+            snapshots: Vec<Snapshot>,
+            position: usize,
+            fake: FakeSystem,
+        }
+
+        impl Replay {
+            /// Reads the recording stored in the file at `path`.
+            pub fn open(path: impl AsRef<Path>) -> io::Result<Self> {
+                Self::from_reader(BufReader::new(File::open(path)?))
+            }
+
+            /// Reads the recording from `reader`.
+            pub fn from_reader(reader: impl BufRead) -> io::Result<Self> {
+                Self::from_snapshots(read_snapshots(reader)?)
+            }
+
+            /// Creates a new [`Replay`] from `snapshots`. Returns an error if it is empty.
+            pub fn from_snapshots(snapshots: Vec<Snapshot>) -> io::Result<Self> {
+                if snapshots.is_empty() {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        "recording doesn't contain any snapshot",
+                    ));
+                }
+                let replay = Self {
+                    snapshots,
+                    position: 0,
+                    fake: FakeSystem::new(),
+                };
+                replay.load_current();
+                Ok(replay)
+            }
+
+            /// Returns the [`FakeSystem`] fed with the snapshots.
+            pub fn fake_system(&self) -> &FakeSystem {
+                &self.fake
+            }
+
+            /// Creates a new [`System`] with the information of the current snapshot loaded.
+            pub fn system(&self) -> System {
+                self.fake.system()
+            }
+
+            /// Creates a new [`Disks`] with the disks of the current snapshot loaded.
+            pub fn disks(&self) -> Disks {
+                self.fake.disks()
+            }
+
+            /// Returns the current snapshot.
+            pub fn snapshot(&self) -> &Snapshot {
+                &self.snapshots[self.position]
+            }
+
+            /// Returns the time (in milliseconds since UNIX epoch) when the current snapshot
+            /// was captured.
+            pub fn timestamp(&self) -> u64 {
+                self.snapshot().timestamp
+            }
+
+            /// Returns the number of snapshots.
+            pub fn len(&self) -> usize {
+                self.snapshots.len()
+            }
+
+            /// Always returns `false` as a [`Replay`] cannot be empty.
+            pub fn is_empty(&self) -> bool {
+                false
+            }
+
+            /// Moves to the next snapshot. Returns `false` if the current snapshot is the last
+            /// one.
+            ///
+            /// The [`System`] and [`Disks`] need to be refreshed to get its information.
+            pub fn advance(&mut self) -> bool {
+                if self.position + 1 >= self.snapshots.len() {
+                    return false;
+                }
+                self.position += 1;
+                self.load_current();
+                true
+            }
+
+            fn load_current(&self) {
+                let snapshot = &self.snapshots[self.position];
+                let mut state = self.fake.state.lock().unwrap();
+                state.total_memory = snapshot.total_memory;
+                state.used_memory = snapshot.used_memory;
+                state.total_swap = snapshot.total_swap;
+                state.used_swap = snapshot.used_swap;
+                state.global_cpu_usage = Some(snapshot.global_cpu_usage);
+                state.cpus = snapshot
+                    .cpus
+                    .iter()
+                    .map(|cpu| {
+                        FakeCpu::new(cpu.name.clone())
+                            .with_vendor_id(cpu.vendor_id.clone())
+                            .with_brand(cpu.brand.clone())
+                            .with_frequency(cpu.frequency)
+                            .with_cpu_usage(cpu.cpu_usage)
+                    })
+                    .collect();
+                state.processes = snapshot
+                    .processes
+                    .iter()
+                    .map(|p| {
+                        let mut process = FakeProcess::new(Pid::from_u32(p.pid), p.name.clone())
+                            .with_cmd(p.cmd.iter().cloned())
+                            .with_status(p.status)
+                            .with_memory(p.memory)
+                            .with_virtual_memory(p.virtual_memory)
+                            .with_start_time(p.start_time)
+                            .with_run_time(p.run_time)
+                            .with_cpu_usage(p.cpu_usage)
+                            .with_accumulated_cpu_time(p.accumulated_cpu_time)
+                            .with_disk_usage(p.disk_usage());
+                        process.parent = p.parent.map(Pid::from_u32);
+                        process.exe = p.exe.as_ref().map(Into::into);
+                        process.thread_count = p.thread_count;
+                        (process.pid, process)
+                    })
+                    .collect();
+                state.disks = snapshot
+                    .disks
+                    .iter()
+                    .flatten()
+                    .map(|disk| {
+                        FakeDisk::new(disk.name.clone(), disk.mount_point.clone())
+                            .with_file_system(disk.file_system.clone())
+                            .with_kind(disk.kind)
+                            .with_space(disk.total_space, disk.available_space)
+                            .with_removable(disk.is_removable)
+                            .with_read_only(disk.is_read_only)
+                            .with_usage(disk.usage())
+                    })
+                    .collect();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn check_recorder() {
+        let mut s = System::new();
+        s.refresh_memory();
+        s.refresh_cpu_list(crate::CpuRefreshKind::everything());
+        let pid = crate::get_current_pid().unwrap();
+        s.refresh_processes(crate::ProcessesToUpdate::Some(&[pid]), false);
+        let mut recorder = Recorder::new(Vec::new());
+        recorder.record(&s, None).unwrap();
+        recorder.record(&s, Some(&Disks::new())).unwrap();
+        let recording = recorder.into_inner().unwrap();
+
+        let snapshots = read_snapshots(&recording[..]).unwrap();
+        assert_eq!(snapshots.len(), 2);
+        assert_eq!(snapshots[0].total_memory(), s.total_memory());
+        assert_eq!(snapshots[0].cpus().len(), s.cpus().len());
+        assert!(snapshots[0].disks().is_none());
+        assert!(snapshots[1].disks().is_some());
+        assert_eq!(
+            snapshots[0].process(pid).map(|p| p.name()),
+            s.process(pid).map(|p| p.name()),
+        );
+        assert!(snapshots[0].timestamp() <= snapshots[1].timestamp());
+        assert!(read_snapshots(&b"{"[..]).is_err());
+    }
+
+    #[cfg(all(sysinfo_test_backend, not(feature = "unknown-ci")))]
+    #[test]
+    fn check_replay() {
+        use crate::fake::{FakeCpu, FakeDisk, FakeProcess, FakeSystem};
+
+        let fake = FakeSystem::new();
+        fake.set_memory(100, 40);
+        fake.set_cpus(vec![FakeCpu::new("cpu0").with_cpu_usage(25.)]);
+        fake.add_process(FakeProcess::new(Pid::from(1), "init").with_cpu_usage(3.));
+        fake.add_disk(FakeDisk::new("sda1", "/").with_space(10, 5));
+        let mut recorder = Recorder::new(Vec::new());
+        recorder
+            .record(&fake.system(), Some(&fake.disks()))
+            .unwrap();
+        fake.set_memory(100, 60);
+        fake.add_process(FakeProcess::new(Pid::from(2), "sh").with_parent(Pid::from(1)));
+        recorder
+            .record(&fake.system(), Some(&fake.disks()))
+            .unwrap();
+        let recording = recorder.into_inner().unwrap();
+
+        let mut replay = Replay::from_reader(&recording[..]).unwrap();
+        assert_eq!(replay.len(), 2);
+        let mut s = replay.system();
+        let disks = replay.disks();
+        assert_eq!(s.used_memory(), 40);
+        assert_eq!(s.global_cpu_usage(), 25.);
+        assert_eq!(s.processes().len(), 1);
+        assert_eq!(s.process(Pid::from(1)).unwrap().cpu_usage(), 3.);
+        assert_eq!(disks.list()[0].available_space(), 5);
+
+        assert!(replay.advance());
+        s.refresh_all();
+        assert_eq!(s.used_memory(), 60);
+        assert_eq!(
+            s.process(Pid::from(2)).unwrap().parent(),
+            Some(Pid::from(1))
+        );
+        assert!(!replay.advance());
+
+        assert!(Replay::from_reader(&b""[..]).is_err());
+    }
+}
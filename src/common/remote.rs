@@ -0,0 +1,474 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+//! Monitoring of remote computers.
+//!
+//! An agent exposes the information of the computer it runs on with [`serve`] (or
+//! [`serve_unix`] on unix systems), and a [`RemoteSystem`] retrieves it.
+//!
+//! The protocol is very simple: the client sends a single byte request ([`REQUEST_SNAPSHOT`]),
+//! the agent answers with its last [`Snapshot`] serialized in JSON, prefixed by its length (as
+//! a big-endian `u32`). The connection is kept open so the client can send the next request.
+//!
+//! To keep the snapshots small (and to not leak secrets), the agent doesn't retrieve the tasks,
+//! the command line nor the environment of the processes.
+//!
+//! ⚠️ There is no authentication nor encryption: anyone who can connect to the agent can read
+//! the information of the computer it runs on, including the name and the executable path of
+//! every process. Only listen on a loopback address or on a unix domain socket, and use an SSH
+//! tunnel (or a VPN) to reach the agent from another computer.
+//!
+//! On the agent:
+//!
+//! ```no_run
+//! sysinfo::remote::serve("127.0.0.1:5555").expect("failed to start agent");
+//! ```
+//!
+//! On the dashboard:
+//!
+//! ```no_run
+//! use sysinfo::remote::RemoteSystem;
+//!
+//! // For example, with `ssh -L 5555:127.0.0.1:5555 agent-host` running.
+//! let mut remote = RemoteSystem::connect("127.0.0.1:5555").expect("failed to connect");
+//! loop {
+//!     println!("used memory: {} bytes", remote.used_memory());
+//!     std::thread::sleep(std::time::Duration::from_secs(1));
+//!     remote.refresh().expect("failed to refresh");
+//! }
+//! ```
+
+use crate::record::{CpuSnapshot, DiskSnapshot, ProcessSnapshot, Snapshot};
+use crate::{Disks, Pid, ProcessRefreshKind, RefreshKind, System, UpdateKind};
+
+use std::collections::HashMap;
+use std::io::{self, Read, Write};
+use std::net::{TcpListener, TcpStream, ToSocketAddrs};
+#[cfg(unix)]
+use std::os::unix::net::{UnixListener, UnixStream};
+#[cfg(unix)]
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Duration;
+
+/// Request sent by a [`RemoteSystem`] to get a new [`Snapshot`].
+pub const REQUEST_SNAPSHOT: u8 = 1;
+
+/// Maximum size of a frame. Bigger frames are considered invalid.
+const MAX_FRAME_SIZE: usize = 8 * 1024 * 1024;
+
+/// Maximum number of clients handled at the same time by an agent. The connections above this
+/// limit are closed right away.
+const MAX_CLIENTS: usize = 16;
+
+/// Interval between two refreshes of the information sent by an agent.
+const REFRESH_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Maximum time an agent waits for a client to send a request or to read an answer. Idle
+/// connections are closed after it so they don't prevent other clients from connecting.
+const CLIENT_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Writes `payload` prefixed by its length.
+fn write_frame(writer: &mut impl Write, payload: &[u8]) -> io::Result<()> {
+    let len = u32::try_from(payload.len())
+        .ok()
+        .filter(|&len| len as usize <= MAX_FRAME_SIZE)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "frame too big"))?;
+    writer.write_all(&len.to_be_bytes())?;
+    writer.write_all(payload)?;
+    writer.flush()
+}
+
+/// Reads a payload written by [`write_frame`].
+fn read_frame(reader: &mut impl Read) -> io::Result<Vec<u8>> {
+    let mut len = [0; 4];
+    reader.read_exact(&mut len)?;
+    let len = u32::from_be_bytes(len) as usize;
+    if len > MAX_FRAME_SIZE {
+        return Err(io::Error::new(io::ErrorKind::InvalidData, "frame too big"));
+    }
+    let mut payload = vec![0; len];
+    reader.read_exact(&mut payload)?;
+    Ok(payload)
+}
+
+/// Information sent to the clients. It is refreshed in its own thread every
+/// [`REFRESH_INTERVAL`], so requests only have to send the last serialized [`Snapshot`].
+struct Agent {
+    payload: Mutex<Arc<Vec<u8>>>,
+    clients: AtomicUsize,
+}
+
+impl Agent {
+    fn refresh_kind() -> RefreshKind {
+        RefreshKind::everything().with_processes(
+            ProcessRefreshKind::nothing()
+                .with_memory()
+                .with_cpu()
+                .with_disk_usage()
+                .with_exe(UpdateKind::OnlyIfNotSet)
+                .without_tasks(),
+        )
+    }
+
+    fn start() -> io::Result<Arc<Self>> {
+        let mut system = System::new_with_specifics(Self::refresh_kind());
+        let mut disks = Disks::new_with_refreshed_list();
+        let agent = Arc::new(Self {
+            payload: Mutex::new(Arc::new(Self::serialize(&system, &disks)?)),
+            clients: AtomicUsize::new(0),
+        });
+        let weak = Arc::downgrade(&agent);
+        std::thread::spawn(move || {
+            loop {
+                std::thread::sleep(REFRESH_INTERVAL);
+                // The agent isn't used anymore (the listener failed).
+                let Some(agent) = weak.upgrade() else { break };
+                system.refresh_specifics(Self::refresh_kind());
+                disks.refresh(true);
+                match Self::serialize(&system, &disks) {
+                    Ok(payload) => *agent.payload() = Arc::new(payload),
+                    // The previous snapshot is kept, its timestamp tells the clients how old
+                    // it is.
+                    Err(_error) => sysinfo_debug!("failed to serialize snapshot: {_error:?}"),
+                }
+            }
+        });
+        Ok(agent)
+    }
+
+    fn serialize(system: &System, disks: &Disks) -> io::Result<Vec<u8>> {
+        let payload = serde_json::to_vec(&Snapshot::capture(system, Some(disks)))?;
+        if payload.len() > MAX_FRAME_SIZE {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "snapshot too big: {} bytes (maximum is {MAX_FRAME_SIZE})",
+                    payload.len(),
+                ),
+            ));
+        }
+        Ok(payload)
+    }
+
+    fn payload(&self) -> MutexGuard<'_, Arc<Vec<u8>>> {
+        // Replacing an `Arc` cannot leave it in an invalid state if a panic occurs.
+        self.payload
+            .lock()
+            .unwrap_or_else(|error| error.into_inner())
+    }
+}
+
+fn handle_client(agent: &Agent, mut stream: impl Read + Write) -> io::Result<()> {
+    let mut request = [0];
+    loop {
+        if stream.read(&mut request)? == 0 {
+            // The client closed the connection.
+            return Ok(());
+        }
+        if request[0] != REQUEST_SNAPSHOT {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("unknown request {}", request[0]),
+            ));
+        }
+        let payload = Arc::clone(&agent.payload());
+        write_frame(&mut stream, &payload)?;
+    }
+}
+
+/// Stream accepted by an agent.
+trait ClientStream: Read + Write + Send + 'static {
+    fn set_timeout(&self, timeout: Duration) -> io::Result<()>;
+}
+
+impl ClientStream for TcpStream {
+    fn set_timeout(&self, timeout: Duration) -> io::Result<()> {
+        self.set_read_timeout(Some(timeout))?;
+        self.set_write_timeout(Some(timeout))
+    }
+}
+
+#[cfg(unix)]
+impl ClientStream for UnixStream {
+    fn set_timeout(&self, timeout: Duration) -> io::Result<()> {
+        self.set_read_timeout(Some(timeout))?;
+        self.set_write_timeout(Some(timeout))
+    }
+}
+
+fn serve_incoming<S: ClientStream>(
+    incoming: impl Iterator<Item = io::Result<S>>,
+) -> io::Result<()> {
+    let agent = Agent::start()?;
+    for stream in incoming {
+        match stream {
+            Ok(stream) => {
+                if let Err(_error) = stream.set_timeout(CLIENT_TIMEOUT) {
+                    sysinfo_debug!("failed to set remote client timeout: {_error:?}");
+                    continue;
+                }
+                if agent.clients.fetch_add(1, Ordering::AcqRel) >= MAX_CLIENTS {
+                    agent.clients.fetch_sub(1, Ordering::AcqRel);
+                    sysinfo_debug!("too many remote clients, closing the connection");
+                    continue;
+                }
+                let agent = Arc::clone(&agent);
+                std::thread::spawn(move || {
+                    if let Err(_error) = handle_client(&agent, stream) {
+                        sysinfo_debug!("remote client error: {_error:?}");
+                    }
+                    agent.clients.fetch_sub(1, Ordering::AcqRel);
+                });
+            }
+            Err(_error) => {
+                sysinfo_debug!("failed to accept remote client: {_error:?}");
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Listens on `addr` and answers the requests of [`RemoteSystem`]s. It only returns if the
+/// listener couldn't be created.
+///
+/// ⚠️ Anyone who can connect to `addr` can read the information of this computer, including
+/// the name and the executable path of every process: there is no authentication nor
+/// encryption. Don't listen on a public address, take a look at the
+/// [module documentation](self) for more information.
+///
+/// The information is refreshed every second in a separate thread and each request gets the
+/// last refreshed one, so clients cannot make the agent refresh more often. Up to 16 clients
+/// are handled at the same time (each in its own thread), the other connections are closed
+/// right away. Connections without any request for 60 seconds are closed as well.
+///
+/// It returns an error if the serialized information is bigger than the maximum frame size
+/// (8 MiB).
+///
+/// ```no_run
+/// sysinfo::remote::serve("127.0.0.1:5555").expect("failed to start agent");
+/// ```
+pub fn serve(addr: impl ToSocketAddrs) -> io::Result<()> {
+    serve_listener(TcpListener::bind(addr)?)
+}
+
+/// Same as [`serve`] but with an already created `listener`.
+///
+/// ```no_run
+/// use std::net::TcpListener;
+///
+/// let listener = TcpListener::bind("127.0.0.1:0").expect("failed to bind");
+/// println!("listening on {:?}", listener.local_addr());
+/// sysinfo::remote::serve_listener(listener).expect("agent stopped");
+/// ```
+pub fn serve_listener(listener: TcpListener) -> io::Result<()> {
+    serve_incoming(listener.incoming())
+}
+
+/// Same as [`serve`] but listens on the unix domain socket at `path`. Access can be restricted
+/// with the permissions of the socket file.
+///
+/// ```no_run
+/// sysinfo::remote::serve_unix("/run/sysinfo.sock").expect("failed to start agent");
+/// ```
+#[cfg(unix)]
+pub fn serve_unix(path: impl AsRef<Path>) -> io::Result<()> {
+    serve_incoming(UnixListener::bind(path)?.incoming())
+}
+
+trait Stream: Read + Write + Send {}
+
+impl<S: Read + Write + Send> Stream for S {}
+
+/// Information of a computer retrieved from an agent started with [`serve`].
+///
+/// It provides the same getters as [`System`] (and as [`Disks`] with [`RemoteSystem::disks`]).
+/// The information is retrieved when it is created and every time [`RemoteSystem::refresh`]
+/// is called. The command line of the processes is not retrieved by the agent, so
+/// [`ProcessSnapshot::cmd`] is always empty.
+///
+/// ⚠️ The agent closes the connections without any request for 60 seconds, so
+/// [`RemoteSystem::refresh`] needs to be called more often than that to keep the connection
+/// open.
+///
+/// ```no_run
+/// use sysinfo::remote::RemoteSystem;
+///
+/// let remote = RemoteSystem::connect("127.0.0.1:5555").expect("failed to connect");
+/// for (pid, process) in remote.processes() {
+///     println!("[{pid}] {:?}", process.name());
+/// }
+/// ```
+pub struct RemoteSystem {
+    stream: Box<dyn Stream>,
+    snapshot: Snapshot,
+    processes: HashMap<Pid, ProcessSnapshot>,
+}
+
+impl RemoteSystem {
+    /// Connects to the agent listening on `addr` and retrieves its information.
+    pub fn connect(addr: impl ToSocketAddrs) -> io::Result<Self> {
+        Self::from_stream(Box::new(TcpStream::connect(addr)?))
+    }
+
+    /// Connects to the agent listening on the unix domain socket at `path` and retrieves its
+    /// information.
+    #[cfg(unix)]
+    pub fn connect_unix(path: impl AsRef<Path>) -> io::Result<Self> {
+        Self::from_stream(Box::new(UnixStream::connect(path)?))
+    }
+
+    fn from_stream(mut stream: Box<dyn Stream>) -> io::Result<Self> {
+        let snapshot = Self::request(&mut stream)?;
+        let mut remote = Self {
+            stream,
+            snapshot,
+            processes: HashMap::new(),
+        };
+        remote.update_processes();
+        Ok(remote)
+    }
+
+    fn request(stream: &mut Box<dyn Stream>) -> io::Result<Snapshot> {
+        stream.write_all(&[REQUEST_SNAPSHOT])?;
+        stream.flush()?;
+        Ok(serde_json::from_slice(&read_frame(stream)?)?)
+    }
+
+    fn update_processes(&mut self) {
+        self.processes = self
+            .snapshot
+            .processes()
+            .iter()
+            .map(|p| (p.pid(), p.clone()))
+            .collect();
+    }
+
+    /// Retrieves the current information of the agent.
+    ///
+    /// If it fails, the previous information is kept.
+    pub fn refresh(&mut self) -> io::Result<()> {
+        self.snapshot = Self::request(&mut self.stream)?;
+        self.update_processes();
+        Ok(())
+    }
+
+    /// Returns the last [`Snapshot`] retrieved from the agent.
+    pub fn snapshot(&self) -> &Snapshot {
+        &self.snapshot
+    }
+
+    /// Returns the time (in milliseconds since UNIX epoch) when the agent captured the
+    /// current information.
+    pub fn timestamp(&self) -> u64 {
+        self.snapshot.timestamp()
+    }
+
+    /// Returns the value of [`System::total_memory`] on the agent.
+    pub fn total_memory(&self) -> u64 {
+        self.snapshot.total_memory()
+    }
+
+    /// Returns the value of [`System::used_memory`] on the agent.
+    pub fn used_memory(&self) -> u64 {
+        self.snapshot.used_memory()
+    }
+
+    /// Returns the value of [`System::total_swap`] on the agent.
+    pub fn total_swap(&self) -> u64 {
+        self.snapshot.total_swap()
+    }
+
+    /// Returns the value of [`System::used_swap`] on the agent.
+    pub fn used_swap(&self) -> u64 {
+        self.snapshot.used_swap()
+    }
+
+    /// Returns the value of [`System::free_swap`] on the agent.
+    pub fn free_swap(&self) -> u64 {
+        self.total_swap().saturating_sub(self.used_swap())
+    }
+
+    /// Returns the value of [`System::global_cpu_usage`] on the agent.
+    pub fn global_cpu_usage(&self) -> f32 {
+        self.snapshot.global_cpu_usage()
+    }
+
+    /// Returns the CPUs of the agent.
+    pub fn cpus(&self) -> &[CpuSnapshot] {
+        self.snapshot.cpus()
+    }
+
+    /// Returns the processes of the agent.
+    pub fn processes(&self) -> &HashMap<Pid, ProcessSnapshot> {
+        &self.processes
+    }
+
+    /// Returns the process of the agent corresponding to the given `pid` or `None` if no such
+    /// process exists.
+    pub fn process(&self, pid: Pid) -> Option<&ProcessSnapshot> {
+        self.processes.get(&pid)
+    }
+
+    /// Returns the disks of the agent.
+    pub fn disks(&self) -> &[DiskSnapshot] {
+        self.snapshot.disks().unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn check_frames() {
+        let mut buf = Vec::new();
+        write_frame(&mut buf, b"hello").unwrap();
+        assert_eq!(&buf[..4], &5u32.to_be_bytes());
+        assert_eq!(read_frame(&mut &buf[..]).unwrap(), b"hello");
+        // Truncated frame.
+        assert!(read_frame(&mut &buf[..6]).is_err());
+        // Too big frame.
+        assert!(read_frame(&mut &u32::MAX.to_be_bytes()[..]).is_err());
+    }
+
+    #[test]
+    fn check_remote_system() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        std::thread::spawn(move || serve_listener(listener));
+
+        let mut remote = RemoteSystem::connect(addr).unwrap();
+        let s = System::new_all();
+        assert_eq!(remote.total_memory(), s.total_memory());
+        assert_eq!(remote.cpus().len(), s.cpus().len());
+        let timestamp = remote.timestamp();
+        remote.refresh().unwrap();
+        assert!(remote.timestamp() >= timestamp);
+        if crate::IS_SUPPORTED_SYSTEM {
+            let pid = crate::get_current_pid().unwrap();
+            assert_eq!(remote.process(pid).unwrap().pid(), pid);
+            // The command lines are not sent.
+            assert!(remote.process(pid).unwrap().cmd().is_empty());
+        }
+    }
+
+    #[test]
+    fn check_max_clients() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        std::thread::spawn(move || serve_listener(listener));
+
+        let clients = (0..MAX_CLIENTS)
+            .map(|_| RemoteSystem::connect(addr).unwrap())
+            .collect::<Vec<_>>();
+        assert!(RemoteSystem::connect(addr).is_err());
+        drop(clients);
+        // The clients threads need a bit of time to notice that the connections were closed.
+        let start = std::time::Instant::now();
+        while RemoteSystem::connect(addr).is_err() {
+            assert!(start.elapsed() < Duration::from_secs(5));
+            std::thread::sleep(Duration::from_millis(10));
+        }
+    }
+}
@@ -0,0 +1,222 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+#[cfg(feature = "record")]
+use crate::record::Snapshot;
+use crate::{RefreshKind, System};
+
+use std::sync::{Arc, Mutex, MutexGuard, RwLock};
+
+/// A [`System`] which can be shared between threads: some threads read its information while
+/// another one refreshes it in the background.
+///
+/// Cloning a `SharedSystem` is cheap and returns a handle to the same [`System`].
+///
+/// ## Double-buffering
+///
+/// A `SharedSystem` contains two [`System`]s:
+///
+///  * The front one is returned to the readers by [`SharedSystem::snapshot_ref`] and is never
+///    modified, so readers can go through it for as long as needed and all the information
+///    they get (processes, CPUs and memory) comes from the same refresh.
+///  * The back one is refreshed by [`SharedSystem::refresh_specifics`]. Once the refresh is
+///    done, it becomes the front one and the previous front one becomes the back one.
+///
+/// So readers never wait for a refresh, and a refresh never waits for the readers. As each
+/// [`System`] is only refreshed every other time, the refresh also updates what the back one
+/// missed during the previous refresh, and the CPU usages are computed over the last two
+/// refreshes.
+///
+/// If a reader still holds the previous front [`System`] when the next refresh starts, it
+/// cannot be reused, so a new [`System`] is created and entirely refreshed instead (and its
+/// CPU usages are `0` until its next refresh). Don't keep a snapshot around longer than needed.
+///
+/// Since the processes keep the resources used to retrieve their information (like the open
+/// `stat` files on Linux), these resources are used twice.
+///
+/// ```no_run
+/// use sysinfo::{SharedSystem, System};
+///
+/// let shared = SharedSystem::new(System::new_all());
+///
+/// let refresher = shared.clone();
+/// std::thread::spawn(move || loop {
+///     std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+///     refresher.refresh_all();
+/// });
+///
+/// // For example in the handler of a `/metrics` endpoint:
+/// let s = shared.snapshot_ref();
+/// println!("{} processes, {}% CPU", s.processes().len(), s.global_cpu_usage());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct SharedSystem {
+    inner: Arc<Buffers>,
+}
+
+#[derive(Debug, Default)]
+struct Buffers {
+    /// [`System`] returned to the readers. The lock is only held to clone or replace the `Arc`,
+    /// never during a refresh.
+    front: RwLock<Arc<System>>,
+    /// Also serializes the refreshes.
+    back: Mutex<BackBuffer>,
+}
+
+#[derive(Debug, Default)]
+struct BackBuffer {
+    /// Previous front [`System`], `None` before the first refresh.
+    system: Option<Arc<System>>,
+    /// What was refreshed in the front [`System`] since `system` was replaced by it.
+    missed: RefreshKind,
+}
+
+impl SharedSystem {
+    /// Creates a new `SharedSystem` from `system`, which is returned to the readers until the
+    /// first refresh.
+    ///
+    /// ```no_run
+    /// use sysinfo::{SharedSystem, System};
+    ///
+    /// let shared = SharedSystem::new(System::new_all());
+    /// ```
+    pub fn new(system: System) -> Self {
+        Self {
+            inner: Arc::new(Buffers {
+                front: RwLock::new(Arc::new(system)),
+                back: Default::default(),
+            }),
+        }
+    }
+
+    /// Returns the [`System`] refreshed last, without waiting for the refresh in progress (if
+    /// any). Nothing is refreshed and nothing is copied.
+    ///
+    /// The returned [`System`] is never modified, the next refreshes are done in another one.
+    ///
+    /// ```no_run
+    /// use sysinfo::{SharedSystem, System};
+    ///
+    /// let shared = SharedSystem::new(System::new_all());
+    ///
+    /// let refresher = shared.clone();
+    /// std::thread::spawn(move || loop {
+    ///     std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+    ///     refresher.refresh_all();
+    /// });
+    ///
+    /// // Refreshes keep happening while we go through the processes.
+    /// let s = shared.snapshot_ref();
+    /// for (pid, process) in s.processes() {
+    ///     println!("[{pid}] {:?}", process.name());
+    /// }
+    /// ```
+    pub fn snapshot_ref(&self) -> Arc<System> {
+        // Replacing an `Arc` cannot leave it in an invalid state if a panic occurs.
+        Arc::clone(
+            &self
+                .inner
+                .front
+                .read()
+                .unwrap_or_else(|error| error.into_inner()),
+        )
+    }
+
+    /// Returns a copy of the information of [`SharedSystem::snapshot_ref`] which can be
+    /// serialized or recorded. Nothing is refreshed.
+    ///
+    /// ```no_run
+    /// use sysinfo::{SharedSystem, System};
+    ///
+    /// let shared = SharedSystem::new(System::new_all());
+    /// let snapshot = shared.snapshot();
+    /// for process in snapshot.processes() {
+    ///     println!("[{}] {:?}", process.pid(), process.name());
+    /// }
+    /// ```
+    #[cfg(feature = "record")]
+    pub fn snapshot(&self) -> Snapshot {
+        Snapshot::capture(&self.snapshot_ref(), None)
+    }
+
+    /// Refreshes the back [`System`] according to the given [`RefreshKind`], like
+    /// [`System::refresh_specifics`], then makes it the one returned by
+    /// [`SharedSystem::snapshot_ref`].
+    ///
+    /// If another thread is refreshing, it waits for it to be done.
+    ///
+    /// ```no_run
+    /// use sysinfo::{ProcessRefreshKind, RefreshKind, SharedSystem, System};
+    ///
+    /// let shared = SharedSystem::new(System::new());
+    /// shared.refresh_specifics(
+    ///     RefreshKind::nothing().with_processes(ProcessRefreshKind::everything()),
+    /// );
+    /// ```
+    pub fn refresh_specifics(&self, refreshes: RefreshKind) {
+        let mut back = self.back();
+        let (mut system, missed) = match back.system.take() {
+            Some(system) if Arc::strong_count(&system) == 1 => (system, back.missed),
+            // Before the first refresh, or when a reader still holds the previous front
+            // `System`, a new one is needed and everything needs to be retrieved.
+            _ => (Arc::new(System::new()), RefreshKind::everything()),
+        };
+        // Nobody else can get a reference to the back `System`, so it cannot be shared.
+        if let Some(system) = Arc::get_mut(&mut system) {
+            system.refresh_specifics(catch_up(missed, refreshes));
+        }
+
+        let previous = std::mem::replace(
+            &mut *self
+                .inner
+                .front
+                .write()
+                .unwrap_or_else(|error| error.into_inner()),
+            system,
+        );
+        back.system = Some(previous);
+        back.missed = refreshes;
+    }
+
+    /// Refreshes all system and processes information, like [`System::refresh_all`].
+    ///
+    /// Take a look at [`SharedSystem::refresh_specifics`] for more information.
+    ///
+    /// ```no_run
+    /// use sysinfo::{SharedSystem, System};
+    ///
+    /// let shared = SharedSystem::new(System::new());
+    /// shared.refresh_all();
+    /// ```
+    pub fn refresh_all(&self) {
+        self.refresh_specifics(RefreshKind::everything());
+    }
+
+    fn back(&self) -> MutexGuard<'_, BackBuffer> {
+        // A panic during a refresh only loses the back `System`, which is recreated.
+        self.inner
+            .back
+            .lock()
+            .unwrap_or_else(|error| error.into_inner())
+    }
+}
+
+/// Returns `refreshes` with the parts of `missed` it doesn't refresh.
+fn catch_up(missed: RefreshKind, refreshes: RefreshKind) -> RefreshKind {
+    let mut kind = refreshes;
+    if let (None, Some(memory)) = (refreshes.memory(), missed.memory()) {
+        kind = kind.with_memory(memory);
+    }
+    if let (None, Some(cpu)) = (refreshes.cpu(), missed.cpu()) {
+        kind = kind.with_cpu(cpu);
+    }
+    if let (None, Some(processes)) = (refreshes.processes(), missed.processes()) {
+        kind = kind.with_processes(processes);
+    }
+    kind
+}
+
+impl From<System> for SharedSystem {
+    fn from(system: System) -> Self {
+        Self::new(system)
+    }
+}
@@ -0,0 +1,213 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::StorageInner;
+
+/// This type allows to retrieve information about the storage volumes built on top of multiple
+/// disks (software RAID arrays, LVM volume groups and btrfs file systems) and about their health.
+///
+/// ```no_run
+/// use sysinfo::{Storage, VolumeState};
+///
+/// for volume in Storage::volumes() {
+///     if volume.state() != VolumeState::Healthy {
+///         println!("{}: {:?}", volume.name(), volume.state());
+///     }
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Storage;
+
+impl Storage {
+    /// Returns the software RAID arrays, LVM volume groups and btrfs file systems of the system.
+    ///
+    /// On Linux, RAID arrays (`md` devices) and btrfs file systems come from `/sys`. LVM volume
+    /// groups are retrieved by running `pvs`, which usually requires root privileges.
+    ///
+    /// ⚠️ It always returns an empty list on systems other than Linux.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::Storage;
+    ///
+    /// for volume in Storage::volumes() {
+    ///     println!("{} ({:?}): {:?}", volume.name(), volume.kind(), volume.state());
+    /// }
+    /// ```
+    pub fn volumes() -> Vec<Volume> {
+        StorageInner::volumes()
+    }
+}
+
+/// Kind of a [`Volume`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub enum VolumeKind {
+    /// A software RAID array (like a Linux `md` device).
+    Raid,
+    /// An LVM volume group.
+    LvmVolumeGroup,
+    /// A btrfs file system, which can span multiple devices.
+    Btrfs,
+}
+
+/// Health of a [`Volume`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub enum VolumeState {
+    /// All the devices of the volume are working.
+    Healthy,
+    /// Some devices are missing or faulty but the volume still works.
+    Degraded,
+    /// The volume is degraded and its redundancy is being rebuilt.
+    Rebuilding,
+    /// The volume cannot be used.
+    Failed,
+    /// Unknown state.
+    Unknown,
+}
+
+/// A storage volume. It is returned by [`Storage::volumes`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Volume {
+    pub(crate) name: String,
+    pub(crate) kind: VolumeKind,
+    pub(crate) level: Option<String>,
+    pub(crate) state: VolumeState,
+    pub(crate) devices: Vec<VolumeDevice>,
+}
+
+impl Volume {
+    /// Returns the name of the volume: the name of the RAID device (like `md0`), of the volume
+    /// group or the label of the btrfs file system (or its UUID if it has no label).
+    ///
+    /// ```no_run
+    /// use sysinfo::Storage;
+    ///
+    /// for volume in Storage::volumes() {
+    ///     println!("{}", volume.name());
+    /// }
+    /// ```
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the kind of the volume.
+    ///
+    /// ```no_run
+    /// use sysinfo::Storage;
+    ///
+    /// for volume in Storage::volumes() {
+    ///     println!("{}: {:?}", volume.name(), volume.kind());
+    /// }
+    /// ```
+    pub fn kind(&self) -> VolumeKind {
+        self.kind
+    }
+
+    /// Returns the RAID level of the volume (like `raid1`).
+    ///
+    /// ⚠️ It is only available for [`VolumeKind::Raid`].
+    ///
+    /// ```no_run
+    /// use sysinfo::Storage;
+    ///
+    /// for volume in Storage::volumes() {
+    ///     println!("{}: {:?}", volume.name(), volume.level());
+    /// }
+    /// ```
+    pub fn level(&self) -> Option<&str> {
+        self.level.as_deref()
+    }
+
+    /// Returns the health of the volume.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Storage, VolumeState};
+    ///
+    /// for volume in Storage::volumes() {
+    ///     if volume.state() == VolumeState::Degraded {
+    ///         println!("{} is degraded", volume.name());
+    ///     }
+    /// }
+    /// ```
+    pub fn state(&self) -> VolumeState {
+        self.state
+    }
+
+    /// Returns the devices the volume is made of.
+    ///
+    /// ```no_run
+    /// use sysinfo::Storage;
+    ///
+    /// for volume in Storage::volumes() {
+    ///     for device in volume.devices() {
+    ///         println!("{}: {}", volume.name(), device.name());
+    ///     }
+    /// }
+    /// ```
+    pub fn devices(&self) -> &[VolumeDevice] {
+        &self.devices
+    }
+}
+
+/// A device of a [`Volume`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VolumeDevice {
+    pub(crate) name: String,
+    pub(crate) is_faulty: bool,
+    pub(crate) error_count: Option<u64>,
+}
+
+impl VolumeDevice {
+    /// Returns the name of the device (like `sda1` or `/dev/sda1`).
+    ///
+    /// ⚠️ For btrfs, it is the ID of the device in the file system, as `/sys` doesn't provide
+    /// its name.
+    ///
+    /// ```no_run
+    /// use sysinfo::Storage;
+    ///
+    /// for volume in Storage::volumes() {
+    ///     for device in volume.devices() {
+    ///         println!("{}", device.name());
+    ///     }
+    /// }
+    /// ```
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns `true` if the device is faulty or missing.
+    ///
+    /// ```no_run
+    /// use sysinfo::Storage;
+    ///
+    /// for volume in Storage::volumes() {
+    ///     for device in volume.devices().iter().filter(|device| device.is_faulty()) {
+    ///         println!("{}: {} is faulty", volume.name(), device.name());
+    ///     }
+    /// }
+    /// ```
+    pub fn is_faulty(&self) -> bool {
+        self.is_faulty
+    }
+
+    /// Returns the number of I/O errors recorded for the device: the read errors corrected by
+    /// RAID arrays or the sum of the error counters of btrfs.
+    ///
+    /// ⚠️ It always returns `None` for LVM volume groups.
+    ///
+    /// ```no_run
+    /// use sysinfo::Storage;
+    ///
+    /// for volume in Storage::volumes() {
+    ///     for device in volume.devices() {
+    ///         println!("{}: {:?} errors", device.name(), device.error_count());
+    ///     }
+    /// }
+    /// ```
+    pub fn error_count(&self) -> Option<u64> {
+        self.error_count
+    }
+}
@@ -1,15 +1,20 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::ffi::{OsStr, OsString};
 use std::fmt;
-use std::path::Path;
-use std::process::ExitStatus;
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
+use std::process::{Child, ExitStatus};
 use std::str::FromStr;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
-use crate::common::DiskUsage;
 use crate::common::impl_get_set::impl_get_set;
-use crate::{CpuInner, Gid, MotherboardInner, ProcessInner, ProductInner, SystemInner, Uid};
+use crate::common::{DiskUsage, RefreshError, RefreshTimestamp};
+use crate::{
+    CpuInner, Gid, MotherboardInner, ProcessInner, ProductInner, SecurityInfoInner, SystemInner,
+    Uid,
+};
 
 /// Type containing system's information such as processes, memory and CPU.
 ///
@@ -27,8 +32,13 @@ use crate::{CpuInner, Gid, MotherboardInner, ProcessInner, ProductInner, SystemI
 /// ```
 pub struct System {
     pub(crate) inner: SystemInner,
+    pub(crate) last_refreshed_at: Option<RefreshTimestamp>,
+    pub(crate) refresh_observers: Vec<RefreshObserver>,
+    pub(crate) last_refresh_durations: RefreshDurations,
 }
 
+pub(crate) type RefreshObserver = Box<dyn FnMut(&RefreshEvent) + Send + Sync>;
+
 impl Default for System {
     fn default() -> System {
         System::new()
@@ -87,6 +97,9 @@ impl System {
     pub fn new_with_specifics(refreshes: RefreshKind) -> Self {
         let mut s = Self {
             inner: SystemInner::new(),
+            last_refreshed_at: None,
+            refresh_observers: Vec::new(),
+            last_refresh_durations: RefreshDurations::default(),
         };
         s.refresh_specifics(refreshes);
         s
@@ -166,7 +179,35 @@ impl System {
     /// s.refresh_memory_specifics(MemoryRefreshKind::nothing().with_ram());
     /// ```
     pub fn refresh_memory_specifics(&mut self, refresh_kind: MemoryRefreshKind) {
-        self.inner.refresh_memory_specifics(refresh_kind)
+        let start = Instant::now();
+        self.inner.refresh_memory_specifics(refresh_kind);
+        self.refreshed(RefreshSubsystem::Memory, start);
+    }
+
+    /// Same as [`System::refresh_memory_specifics`] but returns an error explaining why the
+    /// memory information couldn't be retrieved instead of keeping the previous values.
+    ///
+    /// ⚠️ Errors are only detected on Linux and Redox, where they come from reading and parsing
+    /// the source of the information. It never fails on the other platforms (the failures of
+    /// their system calls aren't reported) and always returns [`RefreshError::Unsupported`] on
+    /// unsupported platforms.
+    ///
+    /// ```no_run
+    /// use sysinfo::{MemoryRefreshKind, System};
+    ///
+    /// let mut s = System::new();
+    /// if let Err(error) = s.try_refresh_memory_specifics(MemoryRefreshKind::everything()) {
+    ///     eprintln!("failed to refresh memory: {error}");
+    /// }
+    /// ```
+    pub fn try_refresh_memory_specifics(
+        &mut self,
+        refresh_kind: MemoryRefreshKind,
+    ) -> Result<(), RefreshError> {
+        let start = Instant::now();
+        self.inner.try_refresh_memory_specifics(refresh_kind)?;
+        self.refreshed(RefreshSubsystem::Memory, start);
+        Ok(())
     }
 
     /// Refreshes CPUs usage.
@@ -176,6 +217,12 @@ impl System {
     /// 200 ms, take a look at [`MINIMUM_CPU_UPDATE_INTERVAL`] for more information)
     /// to get accurate value as it uses previous results to compute the next value.
     ///
+    /// Returns `true` if the CPU usage was computed from a new sample taken at least
+    /// [`MINIMUM_CPU_UPDATE_INTERVAL`] after the previous one. If `false` is returned, the CPU
+    /// usage is either not computed yet (first call) or wasn't updated because this method was
+    /// called too soon, so a `0%` usage doesn't mean the CPUs are idle. The interval which was
+    /// actually used is available with [`Cpu::measurement_interval`].
+    ///
     /// Calling this method is the same as calling
     /// `system.refresh_cpu_specifics(CpuRefreshKind::nothing().with_cpu_usage())`.
     ///
@@ -186,12 +233,14 @@ impl System {
     /// // Wait a bit because CPU usage is based on diff.
     /// std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
     /// // Refresh CPUs again.
-    /// s.refresh_cpu_usage();
+    /// if s.refresh_cpu_usage() {
+    ///     println!("{}%", s.global_cpu_usage());
+    /// }
     /// ```
     ///
     /// [`MINIMUM_CPU_UPDATE_INTERVAL`]: crate::MINIMUM_CPU_UPDATE_INTERVAL
-    pub fn refresh_cpu_usage(&mut self) {
-        self.refresh_cpu_specifics(CpuRefreshKind::nothing().with_cpu_usage())
+    pub fn refresh_cpu_usage(&mut self) -> bool {
+        self.refresh_cpus(CpuRefreshKind::nothing().with_cpu_usage())
     }
 
     /// Refreshes CPUs frequency information.
@@ -227,7 +276,11 @@ impl System {
     /// s.refresh_cpu_list(CpuRefreshKind::everything());
     /// ```
     pub fn refresh_cpu_list(&mut self, refresh_kind: CpuRefreshKind) {
+        let start = Instant::now();
+        let previous_update = self.inner.last_cpu_usage_update();
         self.inner.refresh_cpu_list(refresh_kind);
+        self.refreshed(RefreshSubsystem::CpuList, start);
+        self.update_cpu_peak_usage(previous_update);
     }
 
     /// Refreshes all information related to CPUs information. It does not refresh the CPU list.
@@ -265,7 +318,98 @@ impl System {
     /// s.refresh_cpu_specifics(CpuRefreshKind::everything());
     /// ```
     pub fn refresh_cpu_specifics(&mut self, refresh_kind: CpuRefreshKind) {
-        self.inner.refresh_cpu_specifics(refresh_kind)
+        self.refresh_cpus(refresh_kind);
+    }
+
+    /// Refreshes the CPUs and returns `true` if their usage was updated from a new sample
+    /// taken at least [`MINIMUM_CPU_UPDATE_INTERVAL`][crate::MINIMUM_CPU_UPDATE_INTERVAL]
+    /// after the previous one.
+    fn refresh_cpus(&mut self, refresh_kind: CpuRefreshKind) -> bool {
+        let start = Instant::now();
+        let previous_update = self.inner.last_cpu_usage_update();
+        self.inner.refresh_cpu_specifics(refresh_kind);
+        self.refreshed(RefreshSubsystem::Cpu, start);
+        self.update_cpu_peak_usage(previous_update);
+        self.update_cpu_measurement_interval(previous_update)
+    }
+
+    /// Same as [`System::refresh_cpu_specifics`] but returns an error explaining why the CPUs
+    /// information couldn't be retrieved instead of keeping the previous values.
+    ///
+    /// ⚠️ Errors are only detected on Linux and Redox, where they come from reading and parsing
+    /// the source of the information. It never fails on the other platforms (the failures of
+    /// their system calls aren't reported) and always returns [`RefreshError::Unsupported`] on
+    /// unsupported platforms.
+    ///
+    /// ```no_run
+    /// use sysinfo::{CpuRefreshKind, System};
+    ///
+    /// let mut s = System::new();
+    /// if let Err(error) = s.try_refresh_cpu_specifics(CpuRefreshKind::everything()) {
+    ///     eprintln!("failed to refresh CPUs: {error}");
+    /// }
+    /// ```
+    pub fn try_refresh_cpu_specifics(
+        &mut self,
+        refresh_kind: CpuRefreshKind,
+    ) -> Result<(), RefreshError> {
+        let start = Instant::now();
+        let previous_update = self.inner.last_cpu_usage_update();
+        self.inner.try_refresh_cpu_specifics(refresh_kind)?;
+        self.refreshed(RefreshSubsystem::Cpu, start);
+        self.update_cpu_peak_usage(previous_update);
+        self.update_cpu_measurement_interval(previous_update);
+        Ok(())
+    }
+
+    /// Resets the value returned by [`Cpu::peak_usage`] of all CPUs to their current usage.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let mut s = System::new_all();
+    /// std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+    /// s.refresh_cpu_usage();
+    /// s.reset_cpu_peak_usage();
+    /// ```
+    pub fn reset_cpu_peak_usage(&mut self) {
+        for cpu in self.inner.cpus_mut() {
+            cpu.peak_usage = cpu.cpu_usage();
+        }
+    }
+
+    /// Updates the value returned by [`Cpu::peak_usage`] if a new CPU usage sample was taken
+    /// since `previous_update`. The first sample is skipped since there is no previous one to
+    /// compute the usage from, making its value meaningless.
+    fn update_cpu_peak_usage(&mut self, previous_update: Option<Instant>) {
+        let Some(previous_update) = previous_update else {
+            return;
+        };
+        if self.inner.last_cpu_usage_update() == Some(previous_update) {
+            return;
+        }
+        for cpu in self.inner.cpus_mut() {
+            cpu.peak_usage = cpu.peak_usage.max(cpu.cpu_usage());
+        }
+    }
+
+    /// Updates the value returned by [`Cpu::measurement_interval`] if a new CPU usage sample
+    /// was taken since `previous_update`. Returns `true` if the CPU usage was computed over at
+    /// least [`MINIMUM_CPU_UPDATE_INTERVAL`][crate::MINIMUM_CPU_UPDATE_INTERVAL].
+    fn update_cpu_measurement_interval(&mut self, previous_update: Option<Instant>) -> bool {
+        let (Some(previous_update), Some(last_update)) =
+            (previous_update, self.inner.last_cpu_usage_update())
+        else {
+            return false;
+        };
+        if last_update == previous_update {
+            return false;
+        }
+        let interval = last_update.duration_since(previous_update);
+        for cpu in self.inner.cpus_mut() {
+            cpu.measurement_interval = Some(interval);
+        }
+        interval >= crate::MINIMUM_CPU_UPDATE_INTERVAL
     }
 
     /// Gets all processes and updates their information, along with all the tasks each process has.
@@ -291,7 +435,8 @@ impl System {
     /// since 7 is not part of the update.
     ///
     /// ⚠️ On Linux, `sysinfo` keeps the `stat` files open by default. You can change this behaviour
-    /// by using [`set_open_files_limit`][crate::set_open_files_limit].
+    /// by using [`set_open_files_limit`][crate::set_open_files_limit] or
+    /// [`System::set_max_kept_file_handles`].
     ///
     /// ⚠️ On Linux, if you dont need the tasks of each process, you can use
     /// `refresh_processes_specifics` with `ProcessRefreshKind::everything().without_tasks()`.
@@ -323,6 +468,42 @@ impl System {
         )
     }
 
+    /// Same as [`System::refresh_processes_specifics`] but returns an error if the processes
+    /// cannot be listed at all instead of considering that there are none.
+    ///
+    /// If an error is returned, the processes list is left untouched, even if
+    /// `remove_dead_processes` is `true`.
+    ///
+    /// ⚠️ Errors are only detected on Linux and Redox for now. It always returns
+    /// [`RefreshError::Unsupported`] on unsupported platforms.
+    ///
+    /// ```no_run
+    /// use sysinfo::{ProcessesToUpdate, ProcessRefreshKind, System};
+    ///
+    /// let mut s = System::new();
+    /// match s.try_refresh_processes_specifics(
+    ///     ProcessesToUpdate::All,
+    ///     true,
+    ///     ProcessRefreshKind::everything(),
+    /// ) {
+    ///     Ok(nb) => println!("{nb} processes updated"),
+    ///     Err(error) => eprintln!("failed to list processes: {error}"),
+    /// }
+    /// ```
+    pub fn try_refresh_processes_specifics(
+        &mut self,
+        processes_to_update: ProcessesToUpdate<'_>,
+        remove_dead_processes: bool,
+        refresh_kind: ProcessRefreshKind,
+    ) -> Result<usize, RefreshError> {
+        SystemInner::check_processes_source()?;
+        Ok(self.refresh_processes_specifics(
+            processes_to_update,
+            remove_dead_processes,
+            refresh_kind,
+        ))
+    }
+
     /// Gets all processes and updates the specified information.
     ///
     /// Returns the number of updated processes.
@@ -332,7 +513,8 @@ impl System {
     /// since 7 is not part of the update.
     ///
     /// ⚠️ On Linux, `sysinfo` keeps the `stat` files open by default. You can change this behaviour
-    /// by using [`set_open_files_limit`][crate::set_open_files_limit].
+    /// by using [`set_open_files_limit`][crate::set_open_files_limit] or
+    /// [`System::set_max_kept_file_handles`].
     ///
     /// ```no_run
     /// use sysinfo::{ProcessesToUpdate, ProcessRefreshKind, System};
@@ -350,9 +532,13 @@ impl System {
         remove_dead_processes: bool,
         refresh_kind: ProcessRefreshKind,
     ) -> usize {
-        fn update_and_remove(pid: &Pid, processes: &mut HashMap<Pid, Process>) {
+        fn update_and_remove(
+            pid: &Pid,
+            processes: &mut HashMap<Pid, Process>,
+            now: RefreshTimestamp,
+        ) {
             let updated = if let Some(proc) = processes.get_mut(pid) {
-                proc.inner.switch_updated()
+                proc.switch_updated(now)
             } else {
                 return;
             };
@@ -360,25 +546,31 @@ impl System {
                 processes.remove(pid);
             }
         }
-        fn update(pid: &Pid, processes: &mut HashMap<Pid, Process>) {
+        fn update(pid: &Pid, processes: &mut HashMap<Pid, Process>, now: RefreshTimestamp) {
             if let Some(proc) = processes.get_mut(pid)
-                && !proc.inner.switch_updated()
+                && !proc.switch_updated(now)
             {
                 proc.inner.set_nonexistent();
             }
         }
 
+        let start = Instant::now();
+        let previous_cpu_update = self.inner.last_cpu_usage_update();
         let nb_updated = self
             .inner
             .refresh_processes_specifics(processes_to_update, refresh_kind);
+        // Computing the processes CPU usage might refresh the CPUs usage as well.
+        self.update_cpu_peak_usage(previous_cpu_update);
+        let now = RefreshTimestamp::now();
+        self.last_refreshed_at = Some(now);
         let processes = self.inner.processes_mut();
         match processes_to_update {
             ProcessesToUpdate::All => {
                 if remove_dead_processes {
-                    processes.retain(|_, v| v.inner.switch_updated());
+                    processes.retain(|_, v| v.switch_updated(now));
                 } else {
                     for proc in processes.values_mut() {
-                        proc.inner.switch_updated();
+                        proc.switch_updated(now);
                     }
                 }
             }
@@ -389,15 +581,89 @@ impl System {
                     update
                 };
                 for pid in pids {
-                    call(pid, processes);
+                    call(pid, processes, now);
                 }
             }
         }
+        self.record_refresh(RefreshSubsystem::Processes, start, now);
         nb_updated
     }
 
+    /// Registers `observer` to be called after each memory, CPU and process refresh of this
+    /// [`System`] with a [`RefreshEvent`] telling what was refreshed and how long it took.
+    ///
+    /// Observers are called in the order they were registered. It allows to find out which
+    /// refreshes are slow on a given machine without having to time each call yourself.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let mut s = System::new();
+    /// s.add_refresh_observer(|event| {
+    ///     println!("{:?} refresh took {:?}", event.subsystem, event.duration);
+    /// });
+    /// s.refresh_all();
+    /// ```
+    pub fn add_refresh_observer<F>(&mut self, observer: F)
+    where
+        F: FnMut(&RefreshEvent) + Send + Sync + 'static,
+    {
+        self.refresh_observers.push(Box::new(observer));
+    }
+
+    /// Returns how long the last refresh of each part of the [`System`] information took, so
+    /// you can find out which [`RefreshKind`] are expensive on a given machine.
+    ///
+    /// A duration is `None` if the corresponding information was never refreshed.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let s = System::new_all();
+    /// let durations = s.last_refresh_durations();
+    /// println!("processes refresh took {:?}", durations.processes);
+    /// ```
+    pub fn last_refresh_durations(&self) -> RefreshDurations {
+        self.last_refresh_durations
+    }
+
+    /// Updates the time of the last refresh and notifies the refresh observers.
+    fn refreshed(&mut self, subsystem: RefreshSubsystem, start: Instant) {
+        let now = RefreshTimestamp::now();
+        self.last_refreshed_at = Some(now);
+        self.record_refresh(subsystem, start, now);
+    }
+
+    /// Stores the duration of the refresh and notifies the refresh observers.
+    fn record_refresh(
+        &mut self,
+        subsystem: RefreshSubsystem,
+        start: Instant,
+        refreshed_at: RefreshTimestamp,
+    ) {
+        let duration = refreshed_at.instant().saturating_duration_since(start);
+        let durations = &mut self.last_refresh_durations;
+        match subsystem {
+            RefreshSubsystem::Memory => durations.memory = Some(duration),
+            RefreshSubsystem::Cpu => durations.cpu = Some(duration),
+            RefreshSubsystem::CpuList => durations.cpu_list = Some(duration),
+            RefreshSubsystem::Processes => durations.processes = Some(duration),
+        }
+        let event = RefreshEvent {
+            subsystem,
+            duration,
+            refreshed_at,
+        };
+        for observer in &mut self.refresh_observers {
+            observer(&event);
+        }
+    }
+
     /// Returns the process list.
     ///
+    /// ⚠️ The iteration order is unspecified and can change between two refreshes (or two
+    /// [`System`] instances). Use [`System::processes_sorted`] if you need a deterministic order.
+    ///
     /// ```no_run
     /// use sysinfo::System;
     ///
@@ -410,6 +676,62 @@ impl System {
         self.inner.processes()
     }
 
+    /// Returns when the last memory, CPU or process refresh of this `System` happened, or `None`
+    /// if nothing was refreshed yet.
+    ///
+    /// Each process also keeps its own refresh time, available with
+    /// [`Process::last_refreshed_at`].
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let mut s = System::new();
+    /// s.refresh_memory();
+    /// if let Some(at) = s.last_refreshed_at() {
+    ///     println!("Memory refreshed at {:?}", at.system_time());
+    /// }
+    /// ```
+    pub fn last_refreshed_at(&self) -> Option<RefreshTimestamp> {
+        self.last_refreshed_at
+    }
+
+    /// Returns the process list sorted by PID.
+    ///
+    /// Contrary to [`System::processes`], the order is always the same for the same processes.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let s = System::new_all();
+    /// for process in s.processes_sorted() {
+    ///     println!("{} {:?}", process.pid(), process.name());
+    /// }
+    /// ```
+    pub fn processes_sorted(&self) -> Vec<&Process> {
+        let mut processes = self.processes().values().collect::<Vec<_>>();
+        processes.sort_unstable_by_key(|process| process.pid());
+        processes
+    }
+
+    /// Returns the processes grouped by name, sorted by name. The processes of each name are
+    /// sorted by PID.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let s = System::new_all();
+    /// for (name, processes) in s.processes_by_name_index() {
+    ///     println!("{name:?}: {} processes", processes.len());
+    /// }
+    /// ```
+    pub fn processes_by_name_index(&self) -> BTreeMap<&OsStr, Vec<&Process>> {
+        let mut index: BTreeMap<&OsStr, Vec<&Process>> = BTreeMap::new();
+        for process in self.processes_sorted() {
+            index.entry(process.name()).or_default().push(process);
+        }
+        index
+    }
+
     /// Returns the process corresponding to the given `pid` or `None` if no such process exists.
     ///
     /// ```no_run
@@ -424,6 +746,174 @@ impl System {
         self.inner.process(pid)
     }
 
+    /// Returns the process corresponding to the given `handle` or `None` if no such process
+    /// exists.
+    ///
+    /// Unlike [`System::process`], it returns `None` if the PID of the process which was used to
+    /// create `handle` has since been reused by another process.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, ProcessesToUpdate, System};
+    ///
+    /// let mut s = System::new_all();
+    /// let handle = s.process(Pid::from(1337)).map(|process| process.handle());
+    /// s.refresh_processes(ProcessesToUpdate::All, true);
+    /// if let Some(process) = handle.and_then(|handle| s.process_by_handle(handle)) {
+    ///     println!("still the same process: {:?}", process.name());
+    /// }
+    /// ```
+    pub fn process_by_handle(&self, handle: ProcessHandle) -> Option<&Process> {
+        self.process(handle.pid)
+            .filter(|process| process.inner.start_time_raw() == handle.start_time_raw)
+    }
+
+    /// Refreshes the process corresponding to `child` and returns it, or `None` if it doesn't
+    /// exist anymore.
+    ///
+    /// Only this process is refreshed, with the same information as
+    /// [`System::refresh_processes`].
+    ///
+    /// ⚠️ On Unix systems, as long as `child` hasn't been waited on (with [`Child::wait`] or
+    /// [`Child::try_wait`]), the process is still listed as a [`ProcessStatus::Zombie`] once it
+    /// exited. Once it has been waited on, it is removed from the process list by this method and
+    /// its PID can be reused by another process.
+    ///
+    /// ```no_run
+    /// use std::process::Command;
+    /// use sysinfo::System;
+    ///
+    /// let mut child = Command::new("sleep").arg("1").spawn().expect("failed to spawn");
+    /// let mut s = System::new();
+    ///
+    /// if let Some(process) = s.track_child(&child) {
+    ///     println!("{:?} uses {} bytes", process.name(), process.memory());
+    /// }
+    /// child.wait().expect("failed to wait");
+    /// assert!(s.track_child(&child).is_none());
+    /// ```
+    pub fn track_child(&mut self, child: &Child) -> Option<&Process> {
+        let pid = Pid::from_u32(child.id());
+        self.refresh_processes(ProcessesToUpdate::Some(&[pid]), true);
+        self.process(pid)
+    }
+
+    /// Refreshes the memory and CPU usage of the current process and returns it, or `None` if
+    /// it couldn't be retrieved.
+    ///
+    /// Only the current process is refreshed. If you don't need a [`Process`], take a look at
+    /// [`current_process`] which is even cheaper.
+    ///
+    /// ⚠️ On Windows, a snapshot of all running processes is still taken.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let mut s = System::new();
+    /// if let Some(process) = s.refresh_self() {
+    ///     println!("{} bytes, {}%", process.memory(), process.cpu_usage());
+    /// }
+    /// ```
+    pub fn refresh_self(&mut self) -> Option<&Process> {
+        let pid = get_current_pid().ok()?;
+        self.refresh_processes_specifics(
+            ProcessesToUpdate::Some(&[pid]),
+            false,
+            ProcessRefreshKind::nothing().with_memory().with_cpu(),
+        );
+        self.process(pid)
+    }
+
+    /// Returns statistics about the process strings (name, executable path, command line
+    /// arguments and environment variables) handled by the process refreshes of this `System`.
+    ///
+    /// When refreshing a process, the command line arguments and environment variables which
+    /// didn't change are kept (with their allocations) instead of being allocated again, and the
+    /// buffers used to read them are reused across refreshes. The names and executable paths are
+    /// interned: processes with the same name or executable path share a single allocation.
+    ///
+    /// ⚠️ It always returns `0` for both values on other platforms than Linux.
+    ///
+    /// ```no_run
+    /// use sysinfo::{ProcessRefreshKind, ProcessesToUpdate, System, UpdateKind};
+    ///
+    /// let mut s = System::new();
+    /// let refresh_kind = ProcessRefreshKind::nothing().with_cmd(UpdateKind::Always);
+    /// s.refresh_processes_specifics(ProcessesToUpdate::All, true, refresh_kind);
+    /// s.refresh_processes_specifics(ProcessesToUpdate::All, true, refresh_kind);
+    ///
+    /// let stats = s.process_string_stats();
+    /// println!("{} strings reused, {} allocated", stats.reused, stats.allocated);
+    /// ```
+    pub fn process_string_stats(&self) -> ProcessStringStats {
+        self.inner.process_string_stats()
+    }
+
+    /// Sets the maximum number of `stat` files this `System` keeps open to speed up the process
+    /// refreshes.
+    ///
+    /// If more files than `max` are currently kept open, the extra ones are closed. By default,
+    /// there is no limit per `System`, only the global one which can be changed with
+    /// [`set_open_files_limit`][crate::set_open_files_limit]. Both limits apply: a file is only
+    /// kept open if neither is reached.
+    ///
+    /// ⚠️ It does nothing on other platforms than Linux.
+    ///
+    /// ```no_run
+    /// use sysinfo::{ProcessesToUpdate, System};
+    ///
+    /// let mut s = System::new();
+    /// // We don't want to keep more than 100 file descriptors open.
+    /// s.set_max_kept_file_handles(100);
+    /// s.refresh_processes(ProcessesToUpdate::All, true);
+    /// assert!(s.kept_file_handles() <= 100);
+    /// ```
+    pub fn set_max_kept_file_handles(&mut self, max: usize) {
+        self.inner.set_max_kept_file_handles(max);
+    }
+
+    /// Returns the number of `stat` files currently kept open by this `System`.
+    ///
+    /// See [`System::set_max_kept_file_handles`] for more information.
+    ///
+    /// ⚠️ It always returns `0` on other platforms than Linux.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let s = System::new_all();
+    /// println!("{} files kept open", s.kept_file_handles());
+    /// ```
+    pub fn kept_file_handles(&self) -> usize {
+        self.inner.kept_file_handles()
+    }
+
+    /// Sets whether this `System` should keep a pidfd for each process it refreshes.
+    ///
+    /// Without a pidfd, a process is identified by its PID, which can be reused by another
+    /// process once the original one is gone. With a pidfd:
+    ///
+    /// * [`Process::kill_with`] uses `pidfd_send_signal`, so it can't send a signal to another
+    ///   process which would have reused the PID.
+    /// * [`Process::wait`] waits for the process to exit without polling.
+    /// * The refreshes detect for sure if a PID has been reused instead of comparing start times.
+    ///
+    /// The pidfds are opened during the next process refreshes and closed when disabling this
+    /// mode. Keep in mind that each of them uses a file descriptor. It is disabled by default.
+    ///
+    /// ⚠️ It does nothing on other platforms than Linux. It requires Linux 5.3 or newer, on older
+    /// kernels the PIDs are used as usual.
+    ///
+    /// ```no_run
+    /// use sysinfo::{ProcessesToUpdate, System};
+    ///
+    /// let mut s = System::new();
+    /// s.set_use_pidfds(true);
+    /// s.refresh_processes(ProcessesToUpdate::All, true);
+    /// ```
+    pub fn set_use_pidfds(&mut self, use_pidfds: bool) {
+        self.inner.set_use_pidfds(use_pidfds);
+    }
+
     /// Returns an iterator of process containing the given `name`.
     ///
     /// If you want only the processes with exactly the given `name`, take a look at
@@ -481,61 +971,363 @@ impl System {
             .filter(move |val: &&Process| val.name() == name)
     }
 
-    /// Returns "global" CPUs usage (aka the addition of all the CPUs).
+    /// Returns an iterator of the processes started at or after `since`, ordered by start time.
     ///
-    /// To have up-to-date information, you need to call [`System::refresh_cpu_specifics`] or
-    /// [`System::refresh_specifics`] with `cpu` enabled.
+    /// It is based on [`Process::start_time`], so it has a precision of one second.
     ///
     /// ```no_run
-    /// use sysinfo::{CpuRefreshKind, RefreshKind, System};
+    /// use std::time::{Duration, SystemTime};
+    /// use sysinfo::System;
     ///
-    /// let mut s = System::new_with_specifics(
-    ///     RefreshKind::nothing().with_cpu(CpuRefreshKind::everything()),
-    /// );
-    /// // Wait a bit because CPU usage is based on diff.
-    /// std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
-    /// // Refresh CPUs again to get actual value.
-    /// s.refresh_cpu_usage();
-    /// println!("{}%", s.global_cpu_usage());
+    /// let s = System::new_all();
+    /// let since = SystemTime::now() - Duration::from_secs(60);
+    /// println!("{} processes started in the last minute", s.processes_started_since(since).count());
     /// ```
-    pub fn global_cpu_usage(&self) -> f32 {
-        self.inner.global_cpu_usage()
+    pub fn processes_started_since(&self, since: SystemTime) -> impl Iterator<Item = &Process> {
+        let since = since
+            .duration_since(UNIX_EPOCH)
+            .map(|since| since.as_secs())
+            .unwrap_or(0);
+        let mut processes = self
+            .processes()
+            .values()
+            .filter(|process| process.start_time() >= since)
+            .collect::<Vec<_>>();
+        processes.sort_unstable_by_key(|process| (process.start_time(), process.pid()));
+        processes.into_iter()
     }
 
-    /// Returns the list of the CPUs.
+    /// Returns the total number of threads of the refreshed processes.
     ///
-    /// By default, the list of CPUs is empty until you call [`System::refresh_cpu_specifics`] or
-    /// [`System::refresh_specifics`] with `cpu` enabled.
+    /// It is the sum of [`Process::thread_count`] of the processes which aren't threads
+    /// themselves (see [`Process::thread_kind`]).
     ///
     /// ```no_run
-    /// use sysinfo::{CpuRefreshKind, RefreshKind, System};
+    /// use sysinfo::System;
     ///
-    /// let mut s = System::new_with_specifics(
-    ///     RefreshKind::nothing().with_cpu(CpuRefreshKind::everything()),
-    /// );
-    /// // Wait a bit because CPU usage is based on diff.
-    /// std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
-    /// // Refresh CPUs again to get actual value.
-    /// s.refresh_cpu_usage();
-    /// for cpu in s.cpus() {
-    ///     println!("{}%", cpu.cpu_usage());
-    /// }
+    /// let s = System::new_all();
+    /// println!("{} threads", s.total_threads());
     /// ```
-    pub fn cpus(&self) -> &[Cpu] {
-        self.inner.cpus()
+    pub fn total_threads(&self) -> usize {
+        self.processes()
+            .values()
+            .filter(|process| process.thread_kind() != Some(ThreadKind::Userland))
+            .filter_map(|process| process.thread_count())
+            .sum()
     }
 
-    /// Returns the RAM size in bytes.
+    /// Returns the resources used by the refreshed processes of each user, computed in one pass
+    /// over the process list.
+    ///
+    /// The processes without [user ID][Process::user_id] and the userland threads (see
+    /// [`Process::thread_kind`]) are ignored.
     ///
     /// ```no_run
     /// use sysinfo::System;
     ///
     /// let s = System::new_all();
-    /// println!("{} bytes", s.total_memory());
+    /// for (uid, usage) in s.usage_by_user() {
+    ///     println!(
+    ///         "{uid:?}: {} processes, {}% CPU, {} bytes",
+    ///         usage.process_count, usage.cpu_usage, usage.memory,
+    ///     );
+    /// }
     /// ```
-    ///
-    /// On Linux, if you want to see this information with the limit of your cgroup, take a look
-    /// at [`cgroup_limits`](System::cgroup_limits).
+    pub fn usage_by_user(&self) -> HashMap<Uid, UserUsage> {
+        let mut usage: HashMap<Uid, UserUsage> = HashMap::new();
+        for process in self
+            .processes()
+            .values()
+            .filter(|process| process.thread_kind() != Some(ThreadKind::Userland))
+        {
+            let Some(uid) = process.user_id() else {
+                continue;
+            };
+            let user = usage.entry(uid.clone()).or_default();
+            user.process_count += 1;
+            user.cpu_usage += process.cpu_usage();
+            user.memory += process.memory();
+        }
+        usage
+    }
+
+    /// Returns the resources used by the processes of each cgroup, indexed by the cgroup path (as
+    /// returned by [`Process::cgroup`]). The [`CGroupUsage::container_id`] field tells in which
+    /// container the processes of the cgroup run, if any.
+    ///
+    /// Only the processes which were refreshed are taken into account. Userland threads are
+    /// skipped so their resources aren't counted twice.
+    ///
+    /// ⚠️ It always returns an empty map on other platforms than Linux.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let s = System::new_all();
+    /// for (cgroup, usage) in s.usage_by_cgroup() {
+    ///     println!(
+    ///         "{cgroup} ({:?}): {} processes, {}% CPU, {} bytes",
+    ///         usage.container_id, usage.process_count, usage.cpu_usage, usage.memory,
+    ///     );
+    /// }
+    /// ```
+    pub fn usage_by_cgroup(&self) -> HashMap<String, CGroupUsage> {
+        let mut usage: HashMap<String, CGroupUsage> = HashMap::new();
+        for process in self
+            .processes()
+            .values()
+            .filter(|process| process.thread_kind() != Some(ThreadKind::Userland))
+        {
+            let Some(cgroup) = process.cgroup() else {
+                continue;
+            };
+            let group = usage.entry(cgroup).or_insert_with(|| CGroupUsage {
+                container_id: process.container_id(),
+                ..CGroupUsage::default()
+            });
+            group.process_count += 1;
+            group.cpu_usage += process.cpu_usage();
+            group.memory += process.memory();
+        }
+        usage
+    }
+
+    /// Returns the processes using `path` or, if it's a directory, anything inside it, like the
+    /// `fuser` command. It tells for example what's keeping a mount point busy.
+    ///
+    /// The [`PathUsage`] of each process tells how it's used. Userland threads are skipped since
+    /// they share the files of their process.
+    ///
+    /// The information used depends on the platform:
+    ///
+    ///  * On Linux, the current working directory, root directory, executable, open files and
+    ///    mapped files are read from `/proc`. Only the processes owned by the current user can be
+    ///    inspected without extra privileges.
+    ///  * On macOS, the open files are listed with `proc_pidfdinfo`. Mapped files aren't listed,
+    ///    so [`PathUsage::mapped`] is always `false`.
+    ///  * On other platforms (FreeBSD, Redox, Windows...), neither open nor mapped files are
+    ///    listed, so [`PathUsage::open_file`] and [`PathUsage::mapped`] are always `false`.
+    ///
+    /// Except on Linux, the already retrieved [current working directory][Process::cwd],
+    /// [root directory][Process::root] and [executable][Process::exe] are used, so the
+    /// processes need to be refreshed with them first (take a look at [`ProcessRefreshKind`]).
+    ///
+    /// **Important**: it goes through all the open files of all processes, so it is expensive.
+    /// This information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    /// use std::path::Path;
+    ///
+    /// let s = System::new_all();
+    /// for (pid, usage) in s.processes_using_path(Path::new("/mnt/usb")) {
+    ///     println!("{pid}: {usage:?}");
+    /// }
+    /// ```
+    pub fn processes_using_path(&self, path: &Path) -> HashMap<Pid, PathUsage> {
+        // The paths of the processes are canonical, so `path` needs to be as well.
+        let path = std::fs::canonicalize(path).unwrap_or_else(|_| path.to_owned());
+        self.processes()
+            .values()
+            .filter(|process| process.thread_kind() != Some(ThreadKind::Userland))
+            .filter_map(|process| {
+                let usage = process.inner.path_usage(&path);
+                usage.is_used().then_some((process.pid(), usage))
+            })
+            .collect()
+    }
+
+    /// Returns the processes which are still running code from a file which was deleted or
+    /// replaced since they loaded it (their executable or a shared library), with the paths of
+    /// these files, sorted.
+    ///
+    /// It is the usual check done after a security update to know which processes need to be
+    /// restarted to use the fixed files, like the `needrestart` command. Userland threads are
+    /// skipped since they share the mappings of their process.
+    ///
+    /// ⚠️ It is only supported on Linux, where the executable mappings marked as `(deleted)`
+    /// are read from `/proc/[pid]/maps`. Only the processes owned by the current user can be
+    /// inspected without extra privileges. On the other platforms, it always returns an empty
+    /// `HashMap`.
+    ///
+    /// **Important**: it reads the mappings of all processes, so it is expensive. This
+    /// information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let s = System::new_all();
+    /// for (pid, paths) in s.processes_with_deleted_mappings() {
+    ///     println!("{pid} needs to be restarted, it uses {paths:?}");
+    /// }
+    /// ```
+    pub fn processes_with_deleted_mappings(&self) -> HashMap<Pid, Vec<PathBuf>> {
+        self.processes()
+            .values()
+            .filter(|process| process.thread_kind() != Some(ThreadKind::Userland))
+            .filter_map(|process| {
+                let paths = process.inner.deleted_mappings();
+                (!paths.is_empty()).then_some((process.pid(), paths))
+            })
+            .collect()
+    }
+
+    /// Returns the processes running each executable, indexed by the canonical path of the
+    /// executable, with the resources they use. It answers "is anything still running this
+    /// binary?", for example before replacing or removing it.
+    ///
+    /// Only the processes whose [executable][Process::exe] was retrieved are taken into account
+    /// (take a look at [`ProcessRefreshKind::with_exe`]). Userland threads are skipped so their
+    /// resources aren't counted twice.
+    ///
+    /// If the executable doesn't exist anymore (it was removed or replaced since the process
+    /// started), its path can't be canonicalized and is used as is.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    /// use std::path::Path;
+    ///
+    /// let s = System::new_all();
+    /// if let Some(usage) = s.processes_by_exe().get(Path::new("/usr/bin/python3")) {
+    ///     println!("python3 is still running in {:?}", usage.pids);
+    /// }
+    /// ```
+    pub fn processes_by_exe(&self) -> HashMap<PathBuf, ExeUsage> {
+        let mut canonical_paths: HashMap<&Path, PathBuf> = HashMap::new();
+        let mut usage: HashMap<PathBuf, ExeUsage> = HashMap::new();
+        for process in self
+            .processes()
+            .values()
+            .filter(|process| process.thread_kind() != Some(ThreadKind::Userland))
+        {
+            let Some(exe) = process.exe() else {
+                continue;
+            };
+            let canonical_path = canonical_paths
+                .entry(exe)
+                .or_insert_with(|| std::fs::canonicalize(exe).unwrap_or_else(|_| exe.to_owned()));
+            let group = usage.entry(canonical_path.clone()).or_default();
+            group.pids.push(process.pid());
+            group.cpu_usage += process.cpu_usage();
+            group.memory += process.memory();
+        }
+        for group in usage.values_mut() {
+            group.pids.sort_unstable();
+        }
+        usage
+    }
+
+    /// Returns an iterator of the zombie processes, which exited but haven't been reaped by their
+    /// parent yet.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let s = System::new_all();
+    /// for process in s.zombie_processes() {
+    ///     println!("{} (parent: {:?})", process.pid(), process.parent());
+    /// }
+    /// ```
+    pub fn zombie_processes(&self) -> impl Iterator<Item = &Process> + '_ {
+        self.processes()
+            .values()
+            .filter(|process| process.status() == ProcessStatus::Zombie)
+    }
+
+    /// Returns an iterator of the orphaned processes, whose parent exited.
+    ///
+    /// A process is considered orphaned if it was [reparented to init], if its parent isn't
+    /// in the list of processes anymore or if its parent started after it (which means its parent
+    /// PID was reused).
+    ///
+    /// ⚠️ Only the processes which were refreshed are taken into account, so you should refresh
+    /// all processes to prevent false positives.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let s = System::new_all();
+    /// for process in s.orphaned_processes() {
+    ///     println!("{} {:?}", process.pid(), process.name());
+    /// }
+    /// ```
+    ///
+    /// [reparented to init]: Process::reparented_to_init
+    pub fn orphaned_processes(&self) -> impl Iterator<Item = &Process> + '_ {
+        let processes = self.processes();
+        processes.values().filter(move |process| {
+            if process.reparented_to_init() {
+                return true;
+            }
+            let Some(parent) = process.parent() else {
+                return false;
+            };
+            match processes.get(&parent) {
+                Some(parent) => parent.start_time() > process.start_time(),
+                None => true,
+            }
+        })
+    }
+
+    /// Returns "global" CPUs usage (aka the addition of all the CPUs).
+    ///
+    /// To have up-to-date information, you need to call [`System::refresh_cpu_specifics`] or
+    /// [`System::refresh_specifics`] with `cpu` enabled.
+    ///
+    /// ```no_run
+    /// use sysinfo::{CpuRefreshKind, RefreshKind, System};
+    ///
+    /// let mut s = System::new_with_specifics(
+    ///     RefreshKind::nothing().with_cpu(CpuRefreshKind::everything()),
+    /// );
+    /// // Wait a bit because CPU usage is based on diff.
+    /// std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+    /// // Refresh CPUs again to get actual value.
+    /// s.refresh_cpu_usage();
+    /// println!("{}%", s.global_cpu_usage());
+    /// ```
+    pub fn global_cpu_usage(&self) -> f32 {
+        self.inner.global_cpu_usage()
+    }
+
+    /// Returns the list of the CPUs.
+    ///
+    /// By default, the list of CPUs is empty until you call [`System::refresh_cpu_specifics`] or
+    /// [`System::refresh_specifics`] with `cpu` enabled.
+    ///
+    /// ```no_run
+    /// use sysinfo::{CpuRefreshKind, RefreshKind, System};
+    ///
+    /// let mut s = System::new_with_specifics(
+    ///     RefreshKind::nothing().with_cpu(CpuRefreshKind::everything()),
+    /// );
+    /// // Wait a bit because CPU usage is based on diff.
+    /// std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+    /// // Refresh CPUs again to get actual value.
+    /// s.refresh_cpu_usage();
+    /// for cpu in s.cpus() {
+    ///     println!("{}%", cpu.cpu_usage());
+    /// }
+    /// ```
+    pub fn cpus(&self) -> &[Cpu] {
+        self.inner.cpus()
+    }
+
+    /// Returns the RAM size in bytes.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let s = System::new_all();
+    /// println!("{} bytes", s.total_memory());
+    /// ```
+    ///
+    /// On Linux, if you want to see this information with the limit of your cgroup, take a look
+    /// at [`cgroup_limits`](System::cgroup_limits).
     pub fn total_memory(&self) -> u64 {
         self.inner.total_memory()
     }
@@ -624,6 +1416,76 @@ impl System {
         self.inner.used_swap()
     }
 
+    /// Returns the swap areas (partitions, files or page files) used by the system, with their
+    /// own size and usage.
+    ///
+    /// On Linux, it comes from `/proc/swaps` and includes `zram` devices. On Windows, it lists
+    /// the page files.
+    ///
+    /// ⚠️ It always returns an empty list on macOS (swap files are managed dynamically by the
+    /// system), iOS and Redox.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let s = System::new();
+    /// for device in s.swap_devices() {
+    ///     println!("{:?}: {}/{} bytes", device.path(), device.used(), device.total());
+    /// }
+    /// ```
+    pub fn swap_devices(&self) -> Vec<SwapDevice> {
+        self.inner.swap_devices()
+    }
+
+    /// Returns how much memory is stored compressed in RAM, or `None` if the system doesn't
+    /// compress memory.
+    ///
+    /// On Linux, it adds up the `zram` devices and `zswap`. On macOS, it comes from the memory
+    /// compressor.
+    ///
+    /// ⚠️ It always returns `None` on systems other than Linux and macOS.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let s = System::new();
+    /// if let Some(compressed) = s.compressed_memory() {
+    ///     println!(
+    ///         "{} bytes compressed into {} bytes",
+    ///         compressed.original_size(),
+    ///         compressed.compressed_size(),
+    ///     );
+    /// }
+    /// ```
+    pub fn compressed_memory(&self) -> Option<CompressedMemory> {
+        self.inner.compressed_memory()
+    }
+
+    /// Returns the number of inotify instances and watches used by the processes of the current
+    /// user, along with their limits.
+    ///
+    /// When `max_user_watches` is reached, file watchers (used by editors, build tools or
+    /// development servers) fail, often with a misleading "No space left on device" error.
+    ///
+    /// ⚠️ It always returns `None` on systems other than Linux.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// if let Some(usage) = System::new().inotify_usage() {
+    ///     println!("{}/{} watches", usage.watches(), usage.max_watches());
+    /// }
+    /// ```
+    pub fn inotify_usage(&self) -> Option<InotifyUsage> {
+        self.inner.inotify_usage()
+    }
+
     /// Retrieves the limits for the current cgroup (if any), otherwise it returns `None`.
     ///
     /// This information is computed every time the method is called.
@@ -691,6 +1553,26 @@ impl System {
         SystemInner::load_average()
     }
 
+    /// Returns the number of tasks which are currently runnable, either running or waiting in a
+    /// run queue.
+    ///
+    /// Unlike the CPU usage, it shows how saturated the CPUs are: a value higher than the number
+    /// of CPUs means that some tasks are waiting to run.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ⚠️ This method is only implemented for Linux. It always returns `None` for all other
+    /// systems.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// println!("runnable tasks: {:?}", System::run_queue_length());
+    /// ```
+    pub fn run_queue_length() -> Option<usize> {
+        SystemInner::run_queue_length()
+    }
+
     /// Returns the system name.
     ///
     /// | example platform | value of `System::name()` |
@@ -874,6 +1756,9 @@ impl System {
 
     /// Returns the CPU architecture (eg. x86, amd64, aarch64, ...).
     ///
+    /// It is the architecture of the running kernel, which might differ from the one the current
+    /// binary was compiled for (see [`System::is_compat_mode`]).
+    ///
     /// **Important**: this information is computed every time this function is called.
     ///
     /// ```no_run
@@ -885,6 +1770,28 @@ impl System {
         SystemInner::cpu_arch().unwrap_or_else(|| std::env::consts::ARCH.to_owned())
     }
 
+    /// Returns `true` if the current process runs in a compatibility mode, meaning that it was
+    /// built for a different architecture than the one returned by [`System::cpu_arch`].
+    ///
+    /// It is the case for a 32-bit process running on a 64-bit kernel (WOW64 on Windows,
+    /// `linux32` personality on Linux) or for a x86_64 binary translated by Rosetta on Apple
+    /// Silicon.
+    ///
+    /// ⚠️ It always returns `None` on systems other than Linux, Android, Windows and macOS.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// if System::is_compat_mode() == Some(true) {
+    ///     println!("running a {} binary on {}", std::env::consts::ARCH, System::cpu_arch());
+    /// }
+    /// ```
+    pub fn is_compat_mode() -> Option<bool> {
+        SystemInner::is_compat_mode()
+    }
+
     /// Returns the number of physical cores on the CPU or `None` if it couldn't get it.
     ///
     /// In case there are multiple CPUs, it will combine the physical core count of all the CPUs.
@@ -901,6 +1808,81 @@ impl System {
         SystemInner::physical_core_count()
     }
 
+    /// Returns the most commonly displayed information about the host (host name, OS, kernel,
+    /// CPU, total memory, boot time and virtualization) in one call.
+    ///
+    /// It is meant for tools printing a "host header" at startup: only the RAM and the CPU list
+    /// are retrieved, so it is much cheaper than creating a [`System`] with everything refreshed.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let facts = System::facts();
+    /// println!(
+    ///     "{} ({} {}) - {} x {:?}",
+    ///     facts.host_name().unwrap_or("<unknown>"),
+    ///     facts.os_name().unwrap_or("<unknown>"),
+    ///     facts.cpu_arch(),
+    ///     facts.cpu_count(),
+    ///     facts.cpu_brand(),
+    /// );
+    /// ```
+    pub fn facts() -> SystemFacts {
+        let mut inner = SystemInner::new();
+        inner.refresh_memory_specifics(MemoryRefreshKind::nothing().with_ram());
+        inner.refresh_cpu_list(CpuRefreshKind::nothing());
+        let cpus = inner.cpus();
+
+        SystemFacts {
+            host_name: Self::host_name(),
+            os_name: Self::name(),
+            os_version: Self::long_os_version(),
+            kernel_version: Self::kernel_version(),
+            cpu_arch: Self::cpu_arch(),
+            cpu_brand: cpus
+                .first()
+                .map(|cpu| cpu.brand().to_owned())
+                .filter(|brand| !brand.is_empty()),
+            cpu_count: cpus.len(),
+            physical_core_count: Self::physical_core_count(),
+            total_memory: inner.total_memory(),
+            boot_time: Self::boot_time(),
+            virtualization: SystemInner::virtualization(),
+        }
+    }
+
+    /// Returns the number of clock ticks per second, which is the unit of some of the times
+    /// provided by the system (like the CPU times in `/proc/[pid]/stat` on Linux).
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ⚠️ It always returns `None` on Windows, where times aren't expressed in clock ticks.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// println!("Clock ticks per second: {:?}", System::clock_ticks_per_second());
+    /// ```
+    pub fn clock_ticks_per_second() -> Option<u64> {
+        SystemInner::clock_ticks_per_second()
+    }
+
+    /// Returns the size (in bytes) of a memory page, which is the unit of some of the memory
+    /// amounts provided by the system (like the ones in `/proc/[pid]/statm` on Linux).
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// println!("Page size: {:?} bytes", System::page_size());
+    /// ```
+    pub fn page_size() -> Option<u64> {
+        SystemInner::page_size()
+    }
+
     /// Returns the (default) maximum number of open files for a process.
     ///
     /// Returns `None` if it failed retrieving the information or if the current system is not
@@ -916,12 +1898,311 @@ impl System {
     pub fn open_files_limit() -> Option<usize> {
         SystemInner::open_files_limit()
     }
-}
 
-/// This type allows to retrieve motherboard-related information.
-///
-/// ```
-/// use sysinfo::Motherboard;
+    /// Returns the name of the local time zone.
+    ///
+    /// On Unix systems, it is the IANA name (like `Europe/Paris`) retrieved from the `TZ`
+    /// environment variable or the `/etc/localtime` symlink. On Windows, it is the Windows time
+    /// zone name (like `Romance Standard Time`).
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// println!("Time zone: {:?}", System::timezone());
+    /// ```
+    pub fn timezone() -> Option<String> {
+        SystemInner::timezone()
+    }
+
+    /// Returns the locale of the current user.
+    ///
+    /// On Unix systems (except Apple ones), it comes from the `LC_ALL`, `LC_MESSAGES` and `LANG`
+    /// environment variables (like `en_US.UTF-8`). On Apple systems, it is the identifier of the
+    /// current locale (like `en_US`). On Windows, it is the user default locale name (like
+    /// `en-US`).
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// println!("Locale: {:?}", System::locale());
+    /// ```
+    pub fn locale() -> Option<String> {
+        SystemInner::locale()
+    }
+
+    /// Returns how much (in seconds) the wall clock drifted from the monotonic uptime since the
+    /// first call to this function.
+    ///
+    /// The first call stores the current wall clock time and [`System::uptime`] as a reference
+    /// and returns `Some(0)`. The next calls compare the time elapsed since this reference on
+    /// both clocks: a positive value means the wall clock moved forward more than the uptime (for
+    /// example because it was adjusted by NTP or by hand), a negative value means it moved
+    /// backward. Because both values are in seconds, a difference of `1` can come from rounding.
+    ///
+    /// Returns `None` if the uptime or the current time couldn't be retrieved.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// System::time_skew();
+    /// std::thread::sleep(std::time::Duration::from_secs(60));
+    /// println!("Time skew: {:?} seconds", System::time_skew());
+    /// ```
+    pub fn time_skew() -> Option<i64> {
+        static REFERENCE: std::sync::OnceLock<(i64, i64)> = std::sync::OnceLock::new();
+
+        let uptime = Self::uptime();
+        if uptime == 0 {
+            return None;
+        }
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).ok()?.as_secs();
+        let (reference_now, reference_uptime) =
+            *REFERENCE.get_or_init(|| (now as i64, uptime as i64));
+        Some((now as i64 - reference_now) - (uptime as i64 - reference_uptime))
+    }
+
+    /// Returns the status of the mitigations against the CPU vulnerabilities (like Meltdown or
+    /// Spectre) known by the kernel.
+    ///
+    /// On Linux, it comes from `/sys/devices/system/cpu/vulnerabilities`.
+    ///
+    /// ⚠️ It always returns an empty list on other systems.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::{CpuVulnerabilityStatus, System};
+    ///
+    /// for vulnerability in System::cpu_vulnerabilities() {
+    ///     if vulnerability.status() == CpuVulnerabilityStatus::Vulnerable {
+    ///         println!("{}: {}", vulnerability.name(), vulnerability.details());
+    ///     }
+    /// }
+    /// ```
+    pub fn cpu_vulnerabilities() -> Vec<CpuVulnerability> {
+        SystemInner::cpu_vulnerabilities()
+    }
+
+    /// Returns whether the CPUs are allowed to run above their base frequency (Intel Turbo
+    /// Boost or AMD Core Performance Boost), or `None` if it cannot be determined.
+    ///
+    /// Benchmarks are usually run with the boost disabled since it makes the frequency depend
+    /// on the temperature and on the number of busy cores. [`Cpu::boost_active`] gives the
+    /// same information for each CPU.
+    ///
+    /// On Linux, it comes from the cpufreq driver (`intel_pstate/no_turbo` or `cpufreq/boost`)
+    /// or, if the driver doesn't report it, from the model-specific registers of the first CPU
+    /// (which requires the `msr` kernel module and root privileges). On Windows, it comes from
+    /// the "processor performance boost mode" of the active power scheme.
+    ///
+    /// ⚠️ It always returns `None` on other systems.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// if System::turbo_enabled() == Some(true) {
+    ///     eprintln!("turbo is enabled, results might not be reproducible");
+    /// }
+    /// ```
+    pub fn turbo_enabled() -> Option<bool> {
+        SystemInner::turbo_enabled()
+    }
+
+    /// Returns whether the firewalls (or firewall profiles) found on the system are enabled.
+    ///
+    /// On Linux, it reports `ufw` (from its configuration file), `firewalld` and `nftables` (from
+    /// the state of their systemd service). On Windows, it reports the `Domain`, `Private` and
+    /// `Public` profiles of Windows Firewall, taking group policies into account. On macOS, it
+    /// reports the application firewall. On FreeBSD, it reports `ipfw`.
+    ///
+    /// ⚠️ It always returns an empty list on Redox, iOS and with the `apple-sandbox` feature.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// for firewall in System::firewall_status() {
+    ///     println!("{}: {}", firewall.name(), firewall.is_enabled());
+    /// }
+    /// ```
+    pub fn firewall_status() -> Vec<FirewallProfile> {
+        SecurityInfoInner::firewall_status()
+    }
+
+    /// Returns where the kernel writes core dumps of crashing processes and the core dump size
+    /// limits of the current process (which processes started from it inherit).
+    ///
+    /// It is useful to check that crashes will actually produce a core dump: the size limit
+    /// must not be `0` and, on Linux, the dumps might be handed to a crash handler like
+    /// `systemd-coredump` or `apport` instead of being written in a file.
+    ///
+    /// ⚠️ It always returns `None` on Windows, Redox, iOS and with the `apple-sandbox` feature.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// if let Some(config) = System::core_dump_config() {
+    ///     match config.handler() {
+    ///         Some(handler) => println!("core dumps are piped to {handler}"),
+    ///         None => println!("core dumps are written to {}", config.pattern()),
+    ///     }
+    ///     if config.soft_limit() == Some(0) {
+    ///         println!("core dumps are disabled");
+    ///     }
+    /// }
+    /// ```
+    pub fn core_dump_config() -> Option<CoreDumpConfig> {
+        SystemInner::core_dump_config()
+    }
+
+    /// Returns information about the memory modules installed in the system and about their
+    /// error correction, or `None` if it isn't available.
+    ///
+    /// It comes from the SMBIOS tables provided by the firmware. On Linux, reading them requires
+    /// root privileges (`/sys/firmware/dmi/tables/DMI`).
+    ///
+    /// ⚠️ It always returns `None` on systems other than Linux and Windows.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// if let Some(info) = System::memory_hardware_info() {
+    ///     println!("ECC enabled: {:?}", info.ecc_enabled());
+    ///     for module in info.modules() {
+    ///         println!("{:?}: {} bytes", module.locator(), module.size());
+    ///     }
+    /// }
+    /// ```
+    pub fn memory_hardware_info() -> Option<MemoryHardwareInfo> {
+        SystemInner::memory_hardware_info()
+    }
+
+    /// Returns the model of the board as provided by the device tree, like
+    /// `Raspberry Pi 4 Model B Rev 1.4`.
+    ///
+    /// It is mostly available on ARM and RISC-V single-board computers, which usually don't
+    /// have the DMI information used by [`Product::name`].
+    ///
+    /// ⚠️ It always returns `None` on other platforms than Linux.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// println!("board model: {:?}", System::board_model());
+    /// ```
+    pub fn board_model() -> Option<String> {
+        SystemInner::board_model()
+    }
+
+    /// Returns the throttling status reported by the firmware of the Raspberry Pi boards, or
+    /// `None` if it isn't available (which is the case on any other board).
+    ///
+    /// It tells whether the board is (or was since boot) under-voltage or throttled because of
+    /// its temperature, which is the usual explanation of a Pi being slower than expected. It
+    /// is the same information as `vcgencmd get_throttled`.
+    ///
+    /// ⚠️ It always returns `None` on other platforms than Linux.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// if let Some(status) = System::throttling_status() {
+    ///     if status.has_been_under_voltage() {
+    ///         println!("the power supply isn't good enough");
+    ///     }
+    /// }
+    /// ```
+    pub fn throttling_status() -> Option<ThrottlingStatus> {
+        SystemInner::throttling_status()
+    }
+
+    /// Returns a summary of the sockets of the system, like the number of TCP connections in
+    /// some states, or `None` if it isn't available.
+    ///
+    /// It is much cheaper than listing all connections, so it can be used to detect a SYN flood
+    /// or an abnormal number of connections in the `TIME_WAIT` state.
+    ///
+    /// On Linux, it comes from `/proc/net/sockstat`, `/proc/net/sockstat6`, `/proc/net/snmp`
+    /// and `/proc/net/netstat`. On FreeBSD, it comes from the `net.inet.tcp.states` sysctl. On
+    /// Windows, it comes from `GetTcpStatisticsEx` and `GetUdpStatisticsEx`.
+    ///
+    /// ⚠️ It always returns `None` on other systems.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// if let Some(summary) = System::socket_summary() {
+    ///     println!("TCP connections in TIME_WAIT: {:?}", summary.tcp_time_wait());
+    /// }
+    /// ```
+    pub fn socket_summary() -> Option<SocketSummary> {
+        SystemInner::socket_summary()
+    }
+
+    /// Returns the messages of the kernel log (what the `dmesg` command shows) logged since
+    /// `since`, or since boot if `None`, from the oldest to the newest. It allows to monitor
+    /// hardware errors (like machine check exceptions) or processes killed because the system
+    /// ran out of memory.
+    ///
+    /// The information used depends on the platform:
+    ///
+    ///  * On Linux, it is read from `/dev/kmsg`. Only the messages still in the kernel ring
+    ///    buffer are returned. Reading it requires the `CAP_SYSLOG` capability when the
+    ///    `kernel.dmesg_restrict` sysctl is set (the default on most distributions), otherwise
+    ///    an error of kind [`PermissionDenied`][std::io::ErrorKind::PermissionDenied] is
+    ///    returned.
+    ///  * On Redox, it is read from the `/scheme/sys/log` scheme. The messages don't have a
+    ///    timestamp, so `since` is ignored and all of them are returned.
+    ///  * On Windows, the events logged since boot in the `System` channel of the event log are
+    ///    returned.
+    ///
+    /// ⚠️ On other platforms, it always returns an error of kind
+    /// [`Unsupported`][std::io::ErrorKind::Unsupported].
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::{KernelLogLevel, System};
+    /// use std::time::{Duration, Instant};
+    ///
+    /// let since = Instant::now() - Duration::from_secs(60);
+    /// match System::kernel_log(Some(since)) {
+    ///     Ok(entries) => {
+    ///         for entry in entries.iter().filter(|e| e.level() <= KernelLogLevel::Error) {
+    ///             println!("{:?}: {}", entry.time_since_boot(), entry.message());
+    ///         }
+    ///     }
+    ///     Err(error) => eprintln!("cannot read the kernel log: {error}"),
+    /// }
+    /// ```
+    pub fn kernel_log(since: Option<Instant>) -> std::io::Result<Vec<KernelLogEntry>> {
+        SystemInner::kernel_log(since)
+    }
+}
+
+/// This type allows to retrieve motherboard-related information.
+///
+/// ```
+/// use sysinfo::Motherboard;
 ///
 /// if let Some(m) = Motherboard::new() {
 ///     println!("{m:?}");
@@ -1170,6 +2451,73 @@ impl Product {
     }
 }
 
+/// This type allows to retrieve security-related information about the host.
+///
+/// ```
+/// use sysinfo::SecurityInfo;
+///
+/// println!("{:?}", SecurityInfo);
+/// ```
+pub struct SecurityInfo;
+
+impl SecurityInfo {
+    /// Returns `true` if the system was booted with UEFI Secure Boot enabled.
+    ///
+    /// On Linux, it is read from the `SecureBoot` EFI variable, and systems not booted with UEFI
+    /// return `Some(false)`. On Windows, it is read from the registry.
+    ///
+    /// ⚠️ It always returns `None` on FreeBSD, Redox and Apple systems.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::SecurityInfo;
+    ///
+    /// println!("Secure boot: {:?}", SecurityInfo::secure_boot_enabled());
+    /// ```
+    pub fn secure_boot_enabled() -> Option<bool> {
+        SecurityInfoInner::secure_boot_enabled()
+    }
+
+    /// Returns `true` if a TPM (Trusted Platform Module) is available.
+    ///
+    /// Apple systems don't have a TPM (they have a Secure Enclave instead), so it always returns
+    /// `Some(false)` on them.
+    ///
+    /// ⚠️ It always returns `None` on Redox.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::SecurityInfo;
+    ///
+    /// println!("TPM present: {:?}", SecurityInfo::tpm_present());
+    /// ```
+    pub fn tpm_present() -> Option<bool> {
+        SecurityInfoInner::tpm_present()
+    }
+
+    /// Returns `true` if the disk containing the root file system is encrypted.
+    ///
+    /// On Linux, it checks if the root file system is on top of a LUKS (`dm-crypt`) device. On
+    /// FreeBSD, it checks if it is on top of a GELI provider. On macOS, it returns the FileVault
+    /// status. On Windows, it returns the BitLocker protection status of the system drive, which
+    /// requires administrator privileges (it returns `None` otherwise).
+    ///
+    /// ⚠️ It always returns `None` on Redox, iOS and with the `apple-sandbox` feature.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::SecurityInfo;
+    ///
+    /// println!("Root disk encrypted: {:?}", SecurityInfo::root_disk_encrypted());
+    /// ```
+    pub fn root_disk_encrypted() -> Option<bool> {
+        SecurityInfoInner::root_disk_encrypted()
+    }
+}
+
 /// A struct representing system load average value.
 ///
 /// It is returned by [`System::load_average`][crate::System::load_average].
@@ -1465,154 +2813,2390 @@ pub enum KillError {
     FailedToSendSignal,
 }
 
-/// Struct containing information of a process.
-///
-/// ## iOS
-///
-/// This information cannot be retrieved on iOS due to sandboxing.
-///
-/// ## Apple app store
-///
-/// If you are building a macOS Apple app store, it won't be able
-/// to retrieve this information.
-///
-/// ```no_run
-/// use sysinfo::{Pid, System};
-///
-/// let s = System::new_all();
-/// if let Some(process) = s.process(Pid::from(1337)) {
-///     println!("{:?}", process.name());
-/// }
-/// ```
-pub struct Process {
-    pub(crate) inner: ProcessInner,
+/// Enum describing how the executable of a process is identified. It is returned by
+/// [`Process::exe_signature`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub enum ExeSignature {
+    /// Name of the signer of the Authenticode signature of the executable (Windows only).
+    Authenticode(String),
+    /// Team identifier of the code signature of the executable (macOS only).
+    TeamId(String),
+    /// SHA-256 hash of the executable file.
+    Sha256([u8; 32]),
 }
 
-impl Process {
-    /// Sends [`Signal::Kill`] to the process (which is the only signal supported on all supported
-    /// platforms by this crate).
+/// Information read from the headers of the executable of a process. It is returned by
+/// [`Process::exe_metadata`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ExeMetadata {
+    pub(crate) build_id: Option<Vec<u8>>,
+    pub(crate) interpreter: Option<PathBuf>,
+    pub(crate) link_time: Option<u64>,
+    pub(crate) stripped: Option<bool>,
+}
+
+impl ExeMetadata {
+    /// Returns the identifier used by symbol servers to find the debug symbols of the
+    /// executable:
     ///
-    /// Returns `true` if the signal was sent successfully. If you want to wait for this process
-    /// to end, you can use [`Process::wait`] or directly [`Process::kill_and_wait`].
+    ///  * For ELF files, the GNU build ID (`NT_GNU_BUILD_ID` note).
+    ///  * For PE files, the GUID followed by the age (little-endian) of the PDB file.
+    ///  * For Mach-O files, the UUID (`LC_UUID` load command).
     ///
-    /// ⚠️ Even if this function returns `true`, it doesn't necessarily mean that the process will
-    /// be killed. It just means that the signal was sent successfully.
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
     ///
-    /// ⚠️ Please note that some processes might not be "killable", like if they run with higher
-    /// levels than the current process for example.
+    /// let s = System::new_all();
     ///
-    /// If you want to use another signal, take a look at [`Process::kill_with`].
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{:?}", process.exe_metadata().and_then(|m| m.build_id()));
+    /// }
+    /// ```
+    pub fn build_id(&self) -> Option<&[u8]> {
+        self.build_id.as_deref()
+    }
+
+    /// Returns the program interpreter (dynamic loader) of the executable, like
+    /// `/lib64/ld-linux-x86-64.so.2` or `/usr/lib/dyld`.
     ///
-    /// To get the list of the supported signals on this system, use
-    /// [`SUPPORTED_SIGNALS`][crate::SUPPORTED_SIGNALS].
+    /// It is `None` for statically linked executables and for PE files.
     ///
     /// ```no_run
     /// use sysinfo::{Pid, System};
     ///
     /// let s = System::new_all();
+    ///
     /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     process.kill();
+    ///     println!("{:?}", process.exe_metadata().and_then(|m| m.interpreter()));
     /// }
     /// ```
-    pub fn kill(&self) -> bool {
-        self.kill_with(Signal::Kill).unwrap_or(false)
+    pub fn interpreter(&self) -> Option<&Path> {
+        self.interpreter.as_deref()
     }
 
-    /// Sends the given `signal` to the process. If the signal doesn't exist on this platform,
-    /// it'll do nothing and will return `None`. Otherwise it'll return `Some(bool)`. The boolean
-    /// value will depend on whether or not the signal was sent successfully.
-    ///
-    /// If you just want to kill the process, use [`Process::kill`] directly. If you want to wait
-    /// for this process to end, you can use [`Process::wait`] or [`Process::kill_with_and_wait`].
-    ///
-    /// ⚠️ Please note that some processes might not be "killable", like if they run with higher
-    /// levels than the current process for example.
+    /// Returns the link time (in seconds since UNIX epoch) written in the header of the
+    /// executable.
     ///
-    /// To get the list of the supported signals on this system, use
-    /// [`SUPPORTED_SIGNALS`][crate::SUPPORTED_SIGNALS].
+    /// ⚠️ It is only available for PE files. For reproducible builds, it is a hash and not a
+    /// time.
     ///
     /// ```no_run
-    /// use sysinfo::{Pid, Signal, System};
+    /// use sysinfo::{Pid, System};
     ///
     /// let s = System::new_all();
+    ///
     /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     if process.kill_with(Signal::Kill).is_none() {
-    ///         println!("This signal isn't supported on this platform");
-    ///     }
+    ///     println!("{:?}", process.exe_metadata().and_then(|m| m.link_time()));
     /// }
     /// ```
-    pub fn kill_with(&self, signal: Signal) -> Option<bool> {
-        self.inner.kill_with(signal)
+    pub fn link_time(&self) -> Option<u64> {
+        self.link_time
     }
 
-    /// Sends [`Signal::Kill`] to the process then waits for its termination.
+    /// Returns `true` if the symbol table (`.symtab` section) was removed from the executable.
+    ///
+    /// ⚠️ It is only available for ELF files.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    ///
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{:?}", process.exe_metadata().and_then(|m| m.is_stripped()));
+    /// }
+    /// ```
+    pub fn is_stripped(&self) -> Option<bool> {
+        self.stripped
+    }
+}
+
+/// Enum describing the quality of service (QoS) class of a process. It is returned by
+/// [`EnergyImpact::qos_class`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub enum QosClass {
+    /// Work interacting with the user, like refreshing the user interface.
+    UserInteractive,
+    /// Work initiated by the user and for which they are waiting for the result.
+    UserInitiated,
+    /// Default QoS class.
+    Default,
+    /// Long-running work whose progress the user doesn't follow actively.
+    Utility,
+    /// Work which isn't visible to the user, like indexing or backups.
+    Background,
+    /// Maintenance work run by the system.
+    Maintenance,
+    /// Work which doesn't specify a QoS class.
+    Legacy,
+}
+
+/// Mitigation status of a CPU vulnerability. It is returned by
+/// [`System::cpu_vulnerabilities`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CpuVulnerability {
+    pub(crate) name: String,
+    pub(crate) status: CpuVulnerabilityStatus,
+    pub(crate) details: String,
+}
+
+impl CpuVulnerability {
+    /// Returns the name of the vulnerability as reported by the system (like `spectre_v2`).
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// for vulnerability in System::cpu_vulnerabilities() {
+    ///     println!("{}", vulnerability.name());
+    /// }
+    /// ```
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns whether the CPU is affected by the vulnerability and if it is mitigated.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// for vulnerability in System::cpu_vulnerabilities() {
+    ///     println!("{}: {:?}", vulnerability.name(), vulnerability.status());
+    /// }
+    /// ```
+    pub fn status(&self) -> CpuVulnerabilityStatus {
+        self.status
+    }
+
+    /// Returns the status as reported by the system (like `Mitigation: PTI`).
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// for vulnerability in System::cpu_vulnerabilities() {
+    ///     println!("{}: {}", vulnerability.name(), vulnerability.details());
+    /// }
+    /// ```
+    pub fn details(&self) -> &str {
+        &self.details
+    }
+}
+
+/// Idle state (also known as C-state) of a CPU. It is returned by [`Cpu::idle_states`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CpuIdleState {
+    pub(crate) name: String,
+    pub(crate) description: String,
+    pub(crate) exit_latency: u64,
+    pub(crate) target_residency: u64,
+    pub(crate) entry_count: u64,
+    pub(crate) residency: u64,
+    pub(crate) is_disabled: bool,
+}
+
+impl CpuIdleState {
+    /// Returns the name of the state (like `C1` or `C6`).
+    ///
+    /// ```no_run
+    /// use sysinfo::{System, RefreshKind, CpuRefreshKind};
+    ///
+    /// let s = System::new_with_specifics(
+    ///     RefreshKind::nothing().with_cpu(CpuRefreshKind::nothing().with_idle_states()),
+    /// );
+    /// for cpu in s.cpus() {
+    ///     for state in cpu.idle_states() {
+    ///         println!("{}", state.name());
+    ///     }
+    /// }
+    /// ```
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the description of the state (like `MWAIT 0x20`).
+    ///
+    /// ```no_run
+    /// use sysinfo::{System, RefreshKind, CpuRefreshKind};
+    ///
+    /// let s = System::new_with_specifics(
+    ///     RefreshKind::nothing().with_cpu(CpuRefreshKind::nothing().with_idle_states()),
+    /// );
+    /// for cpu in s.cpus() {
+    ///     for state in cpu.idle_states() {
+    ///         println!("{}: {}", state.name(), state.description());
+    ///     }
+    /// }
+    /// ```
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    /// Returns the time (in microseconds) needed by the CPU to leave this state.
+    ///
+    /// ```no_run
+    /// use sysinfo::{System, RefreshKind, CpuRefreshKind};
+    ///
+    /// let s = System::new_with_specifics(
+    ///     RefreshKind::nothing().with_cpu(CpuRefreshKind::nothing().with_idle_states()),
+    /// );
+    /// for cpu in s.cpus() {
+    ///     for state in cpu.idle_states() {
+    ///         println!("{}: {}us", state.name(), state.exit_latency());
+    ///     }
+    /// }
+    /// ```
+    pub fn exit_latency(&self) -> u64 {
+        self.exit_latency
+    }
+
+    /// Returns the minimum time (in microseconds) the CPU should stay in this state for it to
+    /// save more power than a shallower state.
+    ///
+    /// ```no_run
+    /// use sysinfo::{System, RefreshKind, CpuRefreshKind};
+    ///
+    /// let s = System::new_with_specifics(
+    ///     RefreshKind::nothing().with_cpu(CpuRefreshKind::nothing().with_idle_states()),
+    /// );
+    /// for cpu in s.cpus() {
+    ///     for state in cpu.idle_states() {
+    ///         println!("{}: {}us", state.name(), state.target_residency());
+    ///     }
+    /// }
+    /// ```
+    pub fn target_residency(&self) -> u64 {
+        self.target_residency
+    }
+
+    /// Returns how many times the CPU entered this state since the system booted.
+    ///
+    /// ```no_run
+    /// use sysinfo::{System, RefreshKind, CpuRefreshKind};
+    ///
+    /// let s = System::new_with_specifics(
+    ///     RefreshKind::nothing().with_cpu(CpuRefreshKind::nothing().with_idle_states()),
+    /// );
+    /// for cpu in s.cpus() {
+    ///     for state in cpu.idle_states() {
+    ///         println!("{}: {}", state.name(), state.entry_count());
+    ///     }
+    /// }
+    /// ```
+    pub fn entry_count(&self) -> u64 {
+        self.entry_count
+    }
+
+    /// Returns the total time (in microseconds) the CPU spent in this state since the system
+    /// booted.
+    ///
+    /// ```no_run
+    /// use sysinfo::{System, RefreshKind, CpuRefreshKind};
+    ///
+    /// let s = System::new_with_specifics(
+    ///     RefreshKind::nothing().with_cpu(CpuRefreshKind::nothing().with_idle_states()),
+    /// );
+    /// for cpu in s.cpus() {
+    ///     for state in cpu.idle_states() {
+    ///         println!("{}: {}us", state.name(), state.residency());
+    ///     }
+    /// }
+    /// ```
+    pub fn residency(&self) -> u64 {
+        self.residency
+    }
+
+    /// Returns `true` if the state was disabled, in which case the CPU doesn't enter it
+    /// anymore.
+    ///
+    /// ```no_run
+    /// use sysinfo::{System, RefreshKind, CpuRefreshKind};
+    ///
+    /// let s = System::new_with_specifics(
+    ///     RefreshKind::nothing().with_cpu(CpuRefreshKind::nothing().with_idle_states()),
+    /// );
+    /// for cpu in s.cpus() {
+    ///     for state in cpu.idle_states() {
+    ///         println!("{}: disabled: {}", state.name(), state.is_disabled());
+    ///     }
+    /// }
+    /// ```
+    pub fn is_disabled(&self) -> bool {
+        self.is_disabled
+    }
+}
+
+/// Enum describing the status of a CPU vulnerability. It is returned by
+/// [`CpuVulnerability::status`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub enum CpuVulnerabilityStatus {
+    /// The CPU is not affected by the vulnerability.
+    NotAffected,
+    /// The CPU is affected but a mitigation is enabled. It may be partial, check
+    /// [`CpuVulnerability::details`] for more information.
+    Mitigated,
+    /// The CPU is affected and no mitigation is enabled.
+    Vulnerable,
+    /// The status couldn't be determined.
+    Unknown,
+}
+
+/// State of a firewall or of a firewall profile. It is returned by [`System::firewall_status`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FirewallProfile {
+    pub(crate) name: String,
+    pub(crate) enabled: bool,
+}
+
+impl FirewallProfile {
+    /// Returns the name of the firewall (like `ufw`) or of the firewall profile (like `Public`).
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// for firewall in System::firewall_status() {
+    ///     println!("{}", firewall.name());
+    /// }
+    /// ```
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns `true` if the firewall (or the firewall profile) is enabled.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// for firewall in System::firewall_status() {
+    ///     println!("{}: {}", firewall.name(), firewall.is_enabled());
+    /// }
+    /// ```
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+}
+
+/// Configuration of core dumps. It is returned by [`System::core_dump_config`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CoreDumpConfig {
+    pub(crate) pattern: String,
+    pub(crate) handler: Option<String>,
+    pub(crate) soft_limit: Option<u64>,
+    pub(crate) hard_limit: Option<u64>,
+}
+
+impl CoreDumpConfig {
+    /// Returns the pattern used to name core dump files (`kernel.core_pattern` on Linux,
+    /// `kern.corefile` on macOS and FreeBSD), like `core` or `/cores/core.%P`.
+    ///
+    /// On Linux, it starts with `|` when core dumps are piped to a crash handler.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// if let Some(config) = System::core_dump_config() {
+    ///     println!("{}", config.pattern());
+    /// }
+    /// ```
+    pub fn pattern(&self) -> &str {
+        &self.pattern
+    }
+
+    /// Returns the program core dumps are piped to, like
+    /// `/usr/lib/systemd/systemd-coredump` or `/usr/share/apport/apport`.
+    ///
+    /// ⚠️ It always returns `None` on other platforms than Linux.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// if let Some(handler) = System::core_dump_config().as_ref().and_then(|c| c.handler()) {
+    ///     println!("crash handler: {handler}");
+    /// }
+    /// ```
+    pub fn handler(&self) -> Option<&str> {
+        self.handler.as_deref()
+    }
+
+    /// Returns the soft limit (in bytes) of the size of core dumps (`RLIMIT_CORE`), or `None`
+    /// if it is unlimited. A limit of `0` disables core dumps.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// if let Some(config) = System::core_dump_config() {
+    ///     println!("{:?}", config.soft_limit());
+    /// }
+    /// ```
+    pub fn soft_limit(&self) -> Option<u64> {
+        self.soft_limit
+    }
+
+    /// Returns the hard limit (in bytes) of the size of core dumps (`RLIMIT_CORE`), or `None`
+    /// if it is unlimited. It is the maximum value the soft limit can be raised to.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// if let Some(config) = System::core_dump_config() {
+    ///     println!("{:?}", config.hard_limit());
+    /// }
+    /// ```
+    pub fn hard_limit(&self) -> Option<u64> {
+        self.hard_limit
+    }
+}
+
+/// Throttling status reported by the firmware of the Raspberry Pi boards. It is returned by
+/// [`System::throttling_status`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ThrottlingStatus {
+    pub(crate) flags: u32,
+}
+
+impl ThrottlingStatus {
+    /// Returns `true` if the board is currently under-voltage.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// if let Some(status) = System::throttling_status() {
+    ///     println!("{}", status.is_under_voltage());
+    /// }
+    /// ```
+    pub fn is_under_voltage(&self) -> bool {
+        self.flags & (1 << 0) != 0
+    }
+
+    /// Returns `true` if the ARM frequency is currently capped.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// if let Some(status) = System::throttling_status() {
+    ///     println!("{}", status.is_frequency_capped());
+    /// }
+    /// ```
+    pub fn is_frequency_capped(&self) -> bool {
+        self.flags & (1 << 1) != 0
+    }
+
+    /// Returns `true` if the board is currently throttled.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// if let Some(status) = System::throttling_status() {
+    ///     println!("{}", status.is_throttled());
+    /// }
+    /// ```
+    pub fn is_throttled(&self) -> bool {
+        self.flags & (1 << 2) != 0
+    }
+
+    /// Returns `true` if the soft temperature limit is currently active, which lowers the
+    /// frequency of the CPU.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// if let Some(status) = System::throttling_status() {
+    ///     println!("{}", status.is_soft_temperature_limit_active());
+    /// }
+    /// ```
+    pub fn is_soft_temperature_limit_active(&self) -> bool {
+        self.flags & (1 << 3) != 0
+    }
+
+    /// Returns `true` if the board was under-voltage since boot.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// if let Some(status) = System::throttling_status() {
+    ///     println!("{}", status.has_been_under_voltage());
+    /// }
+    /// ```
+    pub fn has_been_under_voltage(&self) -> bool {
+        self.flags & (1 << 16) != 0
+    }
+
+    /// Returns `true` if the ARM frequency was capped since boot.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// if let Some(status) = System::throttling_status() {
+    ///     println!("{}", status.has_been_frequency_capped());
+    /// }
+    /// ```
+    pub fn has_been_frequency_capped(&self) -> bool {
+        self.flags & (1 << 17) != 0
+    }
+
+    /// Returns `true` if the board was throttled since boot.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// if let Some(status) = System::throttling_status() {
+    ///     println!("{}", status.has_been_throttled());
+    /// }
+    /// ```
+    pub fn has_been_throttled(&self) -> bool {
+        self.flags & (1 << 18) != 0
+    }
+
+    /// Returns `true` if the soft temperature limit was reached since boot.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// if let Some(status) = System::throttling_status() {
+    ///     println!("{}", status.has_reached_soft_temperature_limit());
+    /// }
+    /// ```
+    pub fn has_reached_soft_temperature_limit(&self) -> bool {
+        self.flags & (1 << 19) != 0
+    }
+
+    /// Returns the raw flags, as returned by `vcgencmd get_throttled`.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// if let Some(status) = System::throttling_status() {
+    ///     println!("throttled={:#x}", status.raw());
+    /// }
+    /// ```
+    pub fn raw(&self) -> u32 {
+        self.flags
+    }
+}
+
+/// Summary of the sockets of the system. It is returned by [`System::socket_summary`].
+///
+/// All the values are `None` when the system doesn't provide them (check the documentation
+/// of each method).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SocketSummary {
+    pub(crate) tcp_sockets: Option<u64>,
+    pub(crate) tcp_established: Option<u64>,
+    pub(crate) tcp_syn_received: Option<u64>,
+    pub(crate) tcp_time_wait: Option<u64>,
+    pub(crate) tcp_orphaned: Option<u64>,
+    pub(crate) tcp_listen_overflows: Option<u64>,
+    pub(crate) tcp_syn_cookies_sent: Option<u64>,
+    pub(crate) udp_sockets: Option<u64>,
+}
+
+impl SocketSummary {
+    /// Returns the number of TCP sockets.
+    ///
+    /// On Linux, sockets in the `TIME_WAIT` state aren't counted (take a look at
+    /// [`SocketSummary::tcp_time_wait`]).
+    ///
+    /// ⚠️ It is only available on Linux, FreeBSD and Windows.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// if let Some(summary) = System::socket_summary() {
+    ///     println!("{:?}", summary.tcp_sockets());
+    /// }
+    /// ```
+    pub fn tcp_sockets(&self) -> Option<u64> {
+        self.tcp_sockets
+    }
+
+    /// Returns the number of TCP connections in the `ESTABLISHED` state.
+    ///
+    /// On Linux, connections in the `CLOSE_WAIT` state are counted as well.
+    ///
+    /// ⚠️ It is only available on Linux, FreeBSD and Windows.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// if let Some(summary) = System::socket_summary() {
+    ///     println!("{:?}", summary.tcp_established());
+    /// }
+    /// ```
+    pub fn tcp_established(&self) -> Option<u64> {
+        self.tcp_established
+    }
+
+    /// Returns the number of TCP connections in the `SYN_RECEIVED` state, which grows a lot
+    /// during a SYN flood.
+    ///
+    /// ⚠️ It is only available on FreeBSD.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// if let Some(summary) = System::socket_summary() {
+    ///     println!("{:?}", summary.tcp_syn_received());
+    /// }
+    /// ```
+    pub fn tcp_syn_received(&self) -> Option<u64> {
+        self.tcp_syn_received
+    }
+
+    /// Returns the number of TCP connections in the `TIME_WAIT` state.
+    ///
+    /// ⚠️ It is only available on Linux and FreeBSD.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// if let Some(summary) = System::socket_summary() {
+    ///     println!("{:?}", summary.tcp_time_wait());
+    /// }
+    /// ```
+    pub fn tcp_time_wait(&self) -> Option<u64> {
+        self.tcp_time_wait
+    }
+
+    /// Returns the number of TCP sockets which aren't attached to any file descriptor anymore
+    /// but are still closing.
+    ///
+    /// ⚠️ It is only available on Linux.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// if let Some(summary) = System::socket_summary() {
+    ///     println!("{:?}", summary.tcp_orphaned());
+    /// }
+    /// ```
+    pub fn tcp_orphaned(&self) -> Option<u64> {
+        self.tcp_orphaned
+    }
+
+    /// Returns how many times a connection was dropped since the system booted because the
+    /// accept queue of the listening socket was full.
+    ///
+    /// ⚠️ It is only available on Linux.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// if let Some(summary) = System::socket_summary() {
+    ///     println!("{:?}", summary.tcp_listen_overflows());
+    /// }
+    /// ```
+    pub fn tcp_listen_overflows(&self) -> Option<u64> {
+        self.tcp_listen_overflows
+    }
+
+    /// Returns how many SYN cookies were sent since the system booted, which happens when the
+    /// SYN queue of a listening socket is full (usually because of a SYN flood).
+    ///
+    /// ⚠️ It is only available on Linux.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// if let Some(summary) = System::socket_summary() {
+    ///     println!("{:?}", summary.tcp_syn_cookies_sent());
+    /// }
+    /// ```
+    pub fn tcp_syn_cookies_sent(&self) -> Option<u64> {
+        self.tcp_syn_cookies_sent
+    }
+
+    /// Returns the number of UDP sockets.
+    ///
+    /// ⚠️ It is only available on Linux and Windows.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// if let Some(summary) = System::socket_summary() {
+    ///     println!("{:?}", summary.udp_sockets());
+    /// }
+    /// ```
+    pub fn udp_sockets(&self) -> Option<u64> {
+        self.udp_sockets
+    }
+}
+
+/// Severity of a [`KernelLogEntry`], from the most to the least severe (the syslog levels).
+///
+/// The levels can be compared: for example `level <= KernelLogLevel::Error` matches errors
+/// and everything more severe.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub enum KernelLogLevel {
+    /// The system is unusable.
+    Emergency,
+    /// Action must be taken immediately.
+    Alert,
+    /// Critical condition.
+    Critical,
+    /// Error condition.
+    Error,
+    /// Warning condition.
+    Warning,
+    /// Normal but significant condition.
+    Notice,
+    /// Informational message.
+    Info,
+    /// Debug-level message.
+    Debug,
+}
+
+impl KernelLogLevel {
+    /// Converts a syslog level (`0` for emergency to `7` for debug).
+    #[allow(dead_code)] // Not used on all targets.
+    pub(crate) fn from_syslog(level: u8) -> Self {
+        match level {
+            0 => Self::Emergency,
+            1 => Self::Alert,
+            2 => Self::Critical,
+            3 => Self::Error,
+            4 => Self::Warning,
+            5 => Self::Notice,
+            6 => Self::Info,
+            _ => Self::Debug,
+        }
+    }
+}
+
+/// A message of the kernel log. It is returned by [`System::kernel_log`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KernelLogEntry {
+    pub(crate) level: KernelLogLevel,
+    pub(crate) sequence: u64,
+    pub(crate) time_since_boot: Option<Duration>,
+    pub(crate) message: String,
+}
+
+impl KernelLogEntry {
+    /// Returns the severity of the message.
+    ///
+    /// On Redox, it is only known if the message has the `target:LEVEL -- message` format of
+    /// the kernel logger, otherwise it is [`KernelLogLevel::Info`].
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// for entry in System::kernel_log(None).unwrap_or_default() {
+    ///     println!("{:?}", entry.level());
+    /// }
+    /// ```
+    pub fn level(&self) -> KernelLogLevel {
+        self.level
+    }
+
+    /// Returns the sequence number of the message, which increases with each message. It is
+    /// the sequence number of the ring buffer on Linux, the record ID of the event on Windows
+    /// and the index of the line on Redox.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// for entry in System::kernel_log(None).unwrap_or_default() {
+    ///     println!("{}", entry.sequence());
+    /// }
+    /// ```
+    pub fn sequence(&self) -> u64 {
+        self.sequence
+    }
+
+    /// Returns when the message was logged, as the time elapsed since boot.
+    ///
+    /// ⚠️ It always returns `None` on Redox.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// for entry in System::kernel_log(None).unwrap_or_default() {
+    ///     println!("{:?}", entry.time_since_boot());
+    /// }
+    /// ```
+    pub fn time_since_boot(&self) -> Option<Duration> {
+        self.time_since_boot
+    }
+
+    /// Returns the message.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// for entry in System::kernel_log(None).unwrap_or_default() {
+    ///     println!("{}", entry.message());
+    /// }
+    /// ```
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+/// A swap area. It is returned by [`System::swap_devices`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SwapDevice {
+    pub(crate) path: PathBuf,
+    pub(crate) total: u64,
+    pub(crate) used: u64,
+    pub(crate) priority: Option<i32>,
+}
+
+impl SwapDevice {
+    /// Returns the path of the swap partition or of the swap file (like `/dev/zram0` or
+    /// `C:\pagefile.sys`).
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// for device in System::new().swap_devices() {
+    ///     println!("{:?}", device.path());
+    /// }
+    /// ```
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the size of the swap area in bytes.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// for device in System::new().swap_devices() {
+    ///     println!("{:?}: {} bytes", device.path(), device.total());
+    /// }
+    /// ```
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+
+    /// Returns the used size of the swap area in bytes.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// for device in System::new().swap_devices() {
+    ///     println!("{:?}: {} bytes", device.path(), device.used());
+    /// }
+    /// ```
+    pub fn used(&self) -> u64 {
+        self.used
+    }
+
+    /// Returns the priority of the swap area. Areas with a higher priority are used first.
+    ///
+    /// ⚠️ It always returns `None` on systems other than Linux.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// for device in System::new().swap_devices() {
+    ///     println!("{:?}: {:?}", device.path(), device.priority());
+    /// }
+    /// ```
+    pub fn priority(&self) -> Option<i32> {
+        self.priority
+    }
+}
+
+/// Memory stored compressed in RAM. It is returned by [`System::compressed_memory`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CompressedMemory {
+    pub(crate) original_size: u64,
+    pub(crate) compressed_size: u64,
+}
+
+impl CompressedMemory {
+    /// Returns the size in bytes of the compressed memory before it was compressed.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// if let Some(compressed) = System::new().compressed_memory() {
+    ///     println!("{} bytes", compressed.original_size());
+    /// }
+    /// ```
+    pub fn original_size(&self) -> u64 {
+        self.original_size
+    }
+
+    /// Returns the size in bytes of the compressed memory once compressed.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// if let Some(compressed) = System::new().compressed_memory() {
+    ///     println!("{} bytes", compressed.compressed_size());
+    /// }
+    /// ```
+    pub fn compressed_size(&self) -> u64 {
+        self.compressed_size
+    }
+
+    /// Returns [`original_size`](Self::original_size) divided by
+    /// [`compressed_size`](Self::compressed_size), or `0.` if nothing is compressed.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// if let Some(compressed) = System::new().compressed_memory() {
+    ///     println!("compression ratio: {:.2}", compressed.compression_ratio());
+    /// }
+    /// ```
+    pub fn compression_ratio(&self) -> f32 {
+        if self.compressed_size == 0 {
+            return 0.;
+        }
+        self.original_size as f32 / self.compressed_size as f32
+    }
+}
+
+/// Summary of the host information. It is returned by [`System::facts`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SystemFacts {
+    pub(crate) host_name: Option<String>,
+    pub(crate) os_name: Option<String>,
+    pub(crate) os_version: Option<String>,
+    pub(crate) kernel_version: Option<String>,
+    pub(crate) cpu_arch: String,
+    pub(crate) cpu_brand: Option<String>,
+    pub(crate) cpu_count: usize,
+    pub(crate) physical_core_count: Option<usize>,
+    pub(crate) total_memory: u64,
+    pub(crate) boot_time: u64,
+    pub(crate) virtualization: Option<String>,
+}
+
+impl SystemFacts {
+    /// Returns the host name, like [`System::host_name`].
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// println!("{:?}", System::facts().host_name());
+    /// ```
+    pub fn host_name(&self) -> Option<&str> {
+        self.host_name.as_deref()
+    }
+
+    /// Returns the OS name, like [`System::name`].
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// println!("{:?}", System::facts().os_name());
+    /// ```
+    pub fn os_name(&self) -> Option<&str> {
+        self.os_name.as_deref()
+    }
+
+    /// Returns the long OS version, like [`System::long_os_version`].
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// println!("{:?}", System::facts().os_version());
+    /// ```
+    pub fn os_version(&self) -> Option<&str> {
+        self.os_version.as_deref()
+    }
+
+    /// Returns the kernel version, like [`System::kernel_version`].
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// println!("{:?}", System::facts().kernel_version());
+    /// ```
+    pub fn kernel_version(&self) -> Option<&str> {
+        self.kernel_version.as_deref()
+    }
+
+    /// Returns the CPU architecture, like [`System::cpu_arch`].
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// println!("{}", System::facts().cpu_arch());
+    /// ```
+    pub fn cpu_arch(&self) -> &str {
+        &self.cpu_arch
+    }
+
+    /// Returns the CPU model, like [`Cpu::brand`].
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// println!("{:?}", System::facts().cpu_brand());
+    /// ```
+    pub fn cpu_brand(&self) -> Option<&str> {
+        self.cpu_brand.as_deref()
+    }
+
+    /// Returns the number of logical CPUs.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// println!("{}", System::facts().cpu_count());
+    /// ```
+    pub fn cpu_count(&self) -> usize {
+        self.cpu_count
+    }
+
+    /// Returns the number of physical cores, like [`System::physical_core_count`].
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// println!("{:?}", System::facts().physical_core_count());
+    /// ```
+    pub fn physical_core_count(&self) -> Option<usize> {
+        self.physical_core_count
+    }
+
+    /// Returns the RAM size in bytes, like [`System::total_memory`].
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// println!("{} bytes", System::facts().total_memory());
+    /// ```
+    pub fn total_memory(&self) -> u64 {
+        self.total_memory
+    }
+
+    /// Returns the system boot time (in seconds since UNIX epoch), like [`System::boot_time`].
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// println!("{}", System::facts().boot_time());
+    /// ```
+    pub fn boot_time(&self) -> u64 {
+        self.boot_time
+    }
+
+    /// Returns the name of the hypervisor (eg. "KVM", "VMware", "Hyper-V", ...) if the system
+    /// is running in a virtual machine.
+    ///
+    /// It is detected from the firmware information (see [`Product`]), so it returns `None` if
+    /// this information isn't accessible.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// if let Some(hypervisor) = System::facts().virtualization() {
+    ///     println!("running on {hypervisor}");
+    /// }
+    /// ```
+    pub fn virtualization(&self) -> Option<&str> {
+        self.virtualization.as_deref()
+    }
+}
+
+/// Guesses the hypervisor from the product information, for the backends which don't have a
+/// more reliable way to know it.
+pub(crate) fn virtualization_from_product() -> Option<String> {
+    let vendor = ProductInner::vendor_name().unwrap_or_default();
+    let name = ProductInner::name().unwrap_or_default();
+    hypervisor_from_product(vendor.trim(), name.trim()).map(str::to_owned)
+}
+
+fn hypervisor_from_product(vendor: &str, name: &str) -> Option<&'static str> {
+    Some(match (vendor, name) {
+        (_, name) if name.contains("KVM") => "KVM",
+        ("QEMU", _) => "QEMU",
+        ("VMware, Inc.", _) => "VMware",
+        (_, "VirtualBox") | ("innotek GmbH", _) => "VirtualBox",
+        ("Xen", _) => "Xen",
+        ("Microsoft Corporation", "Virtual Machine") => "Hyper-V",
+        ("Amazon EC2", _) => "Amazon EC2",
+        ("Google", "Google Compute Engine") => "Google Compute Engine",
+        (_, name) if name.starts_with("Parallels") => "Parallels",
+        ("Apple Inc.", name) if name.starts_with("VirtualMac") => "Apple Virtualization",
+        ("Bochs", _) => "Bochs",
+        ("OpenStack Foundation", _) => "OpenStack",
+        ("DigitalOcean", _) => "DigitalOcean",
+        _ => return None,
+    })
+}
+
+/// inotify usage of the current user. It is returned by [`System::inotify_usage`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct InotifyUsage {
+    pub(crate) instances: u64,
+    pub(crate) max_instances: u64,
+    pub(crate) watches: u64,
+    pub(crate) max_watches: u64,
+}
+
+impl InotifyUsage {
+    /// Returns the number of inotify instances opened by the processes of the current user.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// if let Some(usage) = System::new().inotify_usage() {
+    ///     println!("{} instances", usage.instances());
+    /// }
+    /// ```
+    pub fn instances(&self) -> u64 {
+        self.instances
+    }
+
+    /// Returns the maximum number of inotify instances a user can open (`max_user_instances`).
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// if let Some(usage) = System::new().inotify_usage() {
+    ///     println!("{} instances at most", usage.max_instances());
+    /// }
+    /// ```
+    pub fn max_instances(&self) -> u64 {
+        self.max_instances
+    }
+
+    /// Returns the number of inotify watches added by the processes of the current user.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// if let Some(usage) = System::new().inotify_usage() {
+    ///     println!("{} watches", usage.watches());
+    /// }
+    /// ```
+    pub fn watches(&self) -> u64 {
+        self.watches
+    }
+
+    /// Returns the maximum number of inotify watches a user can add (`max_user_watches`).
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// if let Some(usage) = System::new().inotify_usage() {
+    ///     println!("{} watches at most", usage.max_watches());
+    /// }
+    /// ```
+    pub fn max_watches(&self) -> u64 {
+        self.max_watches
+    }
+}
+
+/// Information about the memory hardware. It is returned by [`System::memory_hardware_info`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MemoryHardwareInfo {
+    pub(crate) ecc_supported: Option<bool>,
+    pub(crate) ecc_enabled: Option<bool>,
+    pub(crate) slot_count: usize,
+    pub(crate) modules: Vec<MemoryModule>,
+}
+
+impl MemoryHardwareInfo {
+    #[cfg(all(
+        any(target_os = "linux", target_os = "android", windows),
+        not(feature = "unknown-ci"),
+        not(sysinfo_test_backend)
+    ))]
+    pub(crate) fn from_smbios(table: &[u8]) -> Option<Self> {
+        let memory = crate::parsing::parse_smbios_memory(table);
+        if memory.arrays.is_empty() && memory.devices.is_empty() {
+            return None;
+        }
+        let arrays = memory
+            .arrays
+            .iter()
+            .filter(|array| array.is_system_memory)
+            .collect::<Vec<_>>();
+        let ecc_supported = if arrays.iter().any(|array| array.ecc == Some(true)) {
+            Some(true)
+        } else if !arrays.is_empty() && arrays.iter().all(|array| array.ecc == Some(false)) {
+            Some(false)
+        } else {
+            None
+        };
+        let slot_count = arrays
+            .iter()
+            .map(|array| array.slot_count.unwrap_or(0) as usize)
+            .sum::<usize>()
+            .max(memory.devices.len());
+
+        let installed = memory
+            .devices
+            .into_iter()
+            .filter(|device| device.size.is_some_and(|size| size > 0))
+            .collect::<Vec<_>>();
+        // Modules with error correction have more bits than the data they store.
+        let widths = installed
+            .iter()
+            .filter_map(|device| Some((device.total_width?, device.data_width?)))
+            .filter(|&(total, data)| total != 0 && data != 0)
+            .collect::<Vec<_>>();
+        let ecc_enabled =
+            (!widths.is_empty()).then(|| widths.iter().all(|&(total, data)| total > data));
+
+        let to_string = |s: Option<&str>| s.map(str::to_owned);
+        let modules = installed
+            .into_iter()
+            .map(|device| MemoryModule {
+                locator: to_string(device.locator),
+                size: device.size.unwrap_or(0),
+                speed: device.speed,
+                manufacturer: to_string(device.manufacturer),
+                part_number: to_string(device.part_number),
+            })
+            .collect();
+        Some(Self {
+            ecc_supported,
+            ecc_enabled,
+            slot_count,
+            modules,
+        })
+    }
+
+    /// Returns `true` if the memory controller supports error correcting code (ECC) memory,
+    /// or `None` if it is unknown.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// if let Some(info) = System::memory_hardware_info() {
+    ///     println!("ECC supported: {:?}", info.ecc_supported());
+    /// }
+    /// ```
+    pub fn ecc_supported(&self) -> Option<bool> {
+        self.ecc_supported
+    }
+
+    /// Returns `true` if all the installed memory modules have error correction bits, or
+    /// `None` if it is unknown.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// if let Some(info) = System::memory_hardware_info() {
+    ///     println!("ECC enabled: {:?}", info.ecc_enabled());
+    /// }
+    /// ```
+    pub fn ecc_enabled(&self) -> Option<bool> {
+        self.ecc_enabled
+    }
+
+    /// Returns the number of memory slots, including the empty ones.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// if let Some(info) = System::memory_hardware_info() {
+    ///     println!("{}/{} slots used", info.modules().len(), info.slot_count());
+    /// }
+    /// ```
+    pub fn slot_count(&self) -> usize {
+        self.slot_count
+    }
+
+    /// Returns the installed memory modules.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// if let Some(info) = System::memory_hardware_info() {
+    ///     for module in info.modules() {
+    ///         println!("{:?}: {} bytes", module.locator(), module.size());
+    ///     }
+    /// }
+    /// ```
+    pub fn modules(&self) -> &[MemoryModule] {
+        &self.modules
+    }
+}
+
+/// An installed memory module. It is returned by [`MemoryHardwareInfo::modules`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MemoryModule {
+    pub(crate) locator: Option<String>,
+    pub(crate) size: u64,
+    pub(crate) speed: Option<u32>,
+    pub(crate) manufacturer: Option<String>,
+    pub(crate) part_number: Option<String>,
+}
+
+impl MemoryModule {
+    /// Returns the name of the slot of the module, as written on the motherboard (like
+    /// `DIMM_A1`).
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// if let Some(info) = System::memory_hardware_info() {
+    ///     for module in info.modules() {
+    ///         println!("{:?}", module.locator());
+    ///     }
+    /// }
+    /// ```
+    pub fn locator(&self) -> Option<&str> {
+        self.locator.as_deref()
+    }
+
+    /// Returns the size of the module in bytes.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// if let Some(info) = System::memory_hardware_info() {
+    ///     for module in info.modules() {
+    ///         println!("{} bytes", module.size());
+    ///     }
+    /// }
+    /// ```
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    /// Returns the speed of the module in megatransfers per second (MT/s). It is the speed
+    /// configured by the firmware if it is known, otherwise the maximum speed of the module.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// if let Some(info) = System::memory_hardware_info() {
+    ///     for module in info.modules() {
+    ///         println!("{:?} MT/s", module.speed());
+    ///     }
+    /// }
+    /// ```
+    pub fn speed(&self) -> Option<u32> {
+        self.speed
+    }
+
+    /// Returns the manufacturer of the module.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// if let Some(info) = System::memory_hardware_info() {
+    ///     for module in info.modules() {
+    ///         println!("{:?}", module.manufacturer());
+    ///     }
+    /// }
+    /// ```
+    pub fn manufacturer(&self) -> Option<&str> {
+        self.manufacturer.as_deref()
+    }
+
+    /// Returns the part number of the module.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// if let Some(info) = System::memory_hardware_info() {
+    ///     for module in info.modules() {
+    ///         println!("{:?}", module.part_number());
+    ///     }
+    /// }
+    /// ```
+    pub fn part_number(&self) -> Option<&str> {
+        self.part_number.as_deref()
+    }
+}
+
+/// Energy information of a process. It is returned by [`Process::energy_impact`].
+///
+/// All values are computed between the last two refreshes of the process.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct EnergyImpact {
+    /// Average power drawn by the process, in watts.
+    pub power: f64,
+    /// Number of wakeups caused by interrupts.
+    pub interrupt_wakeups: u64,
+    /// Number of times the process woke up the CPU package from idle.
+    pub idle_wakeups: u64,
+    /// QoS class in which the process spent the most CPU time, `None` if it didn't run.
+    pub qos_class: Option<QosClass>,
+}
+
+/// Privacy sensitive resources held by a process. It is returned by [`Process::media_usage`].
+///
+/// `None` means that this information cannot be retrieved on the current platform.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct MediaUsage {
+    /// Whether the process is currently capturing audio (from a microphone for example).
+    pub audio_capture: Option<bool>,
+    /// Whether the process is currently using a camera.
+    pub camera: Option<bool>,
+    /// Whether the process is currently recording the screen.
+    pub screen_recording: Option<bool>,
+}
+
+/// Number of sockets of a process. It is returned by [`Process::connection_count`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ConnectionCount {
+    /// Number of TCP sockets (IPv4 and IPv6).
+    pub tcp: usize,
+    /// Number of UDP sockets (IPv4 and IPv6).
+    pub udp: usize,
+}
+
+/// Scheduler statistics of a process. It is returned by [`Process::sched_stats`].
+///
+/// All values are accumulated since the process started.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct SchedStats {
+    /// Time spent running on a CPU.
+    pub run_time: Duration,
+    /// Time spent waiting in a run queue to be run.
+    pub wait_time: Duration,
+    /// Number of timeslices run on a CPU.
+    pub timeslices: u64,
+}
+
+/// I/O counters of a process which aren't part of [`DiskUsage`]. It is returned by
+/// [`Process::io_counters`].
+///
+/// All values are accumulated since the process started.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct IoCounters {
+    /// Number of read system calls (like `read` or `pread`).
+    pub read_syscalls: u64,
+    /// Number of write system calls (like `write` or `pwrite`).
+    pub write_syscalls: u64,
+    /// Number of written bytes which were never sent to the storage device, because the
+    /// file was truncated or deleted before the page cache was flushed.
+    pub cancelled_write_bytes: u64,
+}
+
+/// Resources used by the processes of a user. It is returned by [`System::usage_by_user`].
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct UserUsage {
+    /// Number of processes.
+    pub process_count: usize,
+    /// Sum of the [CPU usage][Process::cpu_usage] of the processes (in %), so it might be bigger
+    /// than 100 on a multi-core machine.
+    pub cpu_usage: f32,
+    /// Sum of the [memory][Process::memory] used by the processes (in bytes).
+    pub memory: u64,
+}
+
+/// Resources used by the processes of a cgroup. It is returned by [`System::usage_by_cgroup`].
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct CGroupUsage {
+    /// Id of the container in which the processes run, as returned by
+    /// [`Process::container_id`].
+    pub container_id: Option<String>,
+    /// Number of processes.
+    pub process_count: usize,
+    /// Sum of the [CPU usage][Process::cpu_usage] of the processes (in %), so it might be bigger
+    /// than 100 on a multi-core machine.
+    pub cpu_usage: f32,
+    /// Sum of the [memory][Process::memory] used by the processes (in bytes).
+    pub memory: u64,
+}
+
+/// Processes running the same executable. It is returned by [`System::processes_by_exe`].
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ExeUsage {
+    /// PIDs of the processes, sorted.
+    pub pids: Vec<Pid>,
+    /// Sum of the [CPU usage][Process::cpu_usage] of the processes (in %), so it might be bigger
+    /// than 100 on a multi-core machine.
+    pub cpu_usage: f32,
+    /// Sum of the [memory][Process::memory] used by the processes (in bytes).
+    pub memory: u64,
+}
+
+/// How a process uses a file or a directory. It is returned by [`System::processes_using_path`].
+///
+/// Each field is `true` if the corresponding path is the file or directory or is inside the
+/// directory.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct PathUsage {
+    /// The [current working directory][Process::cwd] of the process.
+    pub cwd: bool,
+    /// The [root directory][Process::root] of the process.
+    pub root: bool,
+    /// The [executable][Process::exe] of the process.
+    pub exe: bool,
+    /// A file (or directory) opened by the process.
+    ///
+    /// ⚠️ Open files are only listed on Linux and macOS, it's always `false` on other platforms.
+    pub open_file: bool,
+    /// A file mapped in the memory of the process, like a shared library.
+    ///
+    /// ⚠️ Mapped files are only listed on Linux, it's always `false` on other platforms.
+    pub mapped: bool,
+}
+
+impl PathUsage {
+    /// Returns `true` if the process uses the path in any way.
+    ///
+    /// ```
+    /// use sysinfo::PathUsage;
+    ///
+    /// assert!(!PathUsage::default().is_used());
+    /// ```
+    pub fn is_used(&self) -> bool {
+        self.cwd || self.root || self.exe || self.open_file || self.mapped
+    }
+
+    /// Computes the usage of `path` from the already retrieved paths of a process, for the
+    /// platforms where nothing else is available.
+    #[allow(dead_code)] // Not used on all targets.
+    pub(crate) fn from_process_paths(
+        path: &Path,
+        cwd: Option<&Path>,
+        root: Option<&Path>,
+        exe: Option<&Path>,
+    ) -> Self {
+        let is_used = |used: Option<&Path>| used.is_some_and(|used| used.starts_with(path));
+        Self {
+            cwd: is_used(cwd),
+            root: is_used(root),
+            exe: is_used(exe),
+            ..Self::default()
+        }
+    }
+}
+
+/// Statistics about the process strings (name, executable path, command line arguments and
+/// environment variables) handled while refreshing processes. It is returned by
+/// [`System::process_string_stats`].
+///
+/// ⚠️ It is only computed on Linux, both values are always `0` on other platforms.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ProcessStringStats {
+    /// Number of strings which didn't change since the previous refresh and were kept as is,
+    /// or which were shared with another process.
+    pub reused: u64,
+    /// Number of strings which were new or changed and had to be (re)written.
+    pub allocated: u64,
+}
+
+/// Identifies a process across refreshes. It is returned by [`Process::handle`].
+///
+/// Contrary to a [`Pid`], which can be reused by another process once the original one is gone,
+/// it also contains the start time of the process so [`System::process_by_handle`] can detect it.
+///
+/// ```no_run
+/// use sysinfo::{Pid, System};
+///
+/// let s = System::new_all();
+/// if let Some(process) = s.process(Pid::from(1337)) {
+///     let handle = process.handle();
+///     assert_eq!(handle.pid(), process.pid());
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ProcessHandle {
+    pub(crate) pid: Pid,
+    /// Start time of the process in the most precise unit available on the current platform
+    /// (clock ticks since boot on Linux, seconds since epoch on most other platforms).
+    pub(crate) start_time_raw: u64,
+}
+
+impl ProcessHandle {
+    /// Returns the PID of the process.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{}", process.handle().pid());
+    /// }
+    /// ```
+    pub fn pid(&self) -> Pid {
+        self.pid
+    }
+}
+
+/// Resource usage of the current process. It is returned by [`current_process`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct CurrentProcessUsage {
+    /// Memory usage (in bytes), like [`Process::memory`].
+    pub memory: u64,
+    /// Virtual memory usage (in bytes), like [`Process::virtual_memory`].
+    pub virtual_memory: u64,
+    /// Accumulated CPU time (in milliseconds), like [`Process::accumulated_cpu_time`].
+    pub accumulated_cpu_time: u64,
+}
+
+/// Resources used by the exited children of the current process. It is returned by
+/// [`self_children_usage`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ChildrenUsage {
+    /// Accumulated CPU time (in milliseconds) of the children, spent in user and in kernel
+    /// mode.
+    pub accumulated_cpu_time: u64,
+    /// Largest resident set size (in bytes) reached by one of the children. It is not the sum
+    /// over all children.
+    pub max_memory: u64,
+}
+
+/// Struct containing information of a process.
+///
+/// ## iOS
+///
+/// This information cannot be retrieved on iOS due to sandboxing.
+///
+/// ## Apple app store
+///
+/// If you are building a macOS Apple app store, it won't be able
+/// to retrieve this information.
+///
+/// ```no_run
+/// use sysinfo::{Pid, System};
+///
+/// let s = System::new_all();
+/// if let Some(process) = s.process(Pid::from(1337)) {
+///     println!("{:?}", process.name());
+/// }
+/// ```
+pub struct Process {
+    pub(crate) inner: ProcessInner,
+    pub(crate) last_refreshed_at: Option<RefreshTimestamp>,
+}
+
+impl Process {
+    /// Resets the "updated" state of the process and returns it. If the process was updated by
+    /// the refresh, its refresh time is set to `now`.
+    fn switch_updated(&mut self, now: RefreshTimestamp) -> bool {
+        let updated = self.inner.switch_updated();
+        if updated {
+            self.last_refreshed_at = Some(now);
+        }
+        updated
+    }
+
+    /// Refreshes the process corresponding to `child` in `system` and returns it.
+    ///
+    /// It is the same as [`System::track_child`].
+    ///
+    /// ```no_run
+    /// use std::process::Command;
+    /// use sysinfo::{Process, System};
+    ///
+    /// let child = Command::new("sleep").arg("1").spawn().expect("failed to spawn");
+    /// let mut s = System::new();
+    ///
+    /// if let Some(process) = Process::from_child(&child, &mut s) {
+    ///     println!("{:?}", process.status());
+    /// }
+    /// ```
+    pub fn from_child<'a>(child: &Child, system: &'a mut System) -> Option<&'a Process> {
+        system.track_child(child)
+    }
+
+    /// Sends [`Signal::Kill`] to the process (which is the only signal supported on all supported
+    /// platforms by this crate).
+    ///
+    /// Returns `true` if the signal was sent successfully. If you want to wait for this process
+    /// to end, you can use [`Process::wait`] or directly [`Process::kill_and_wait`].
+    ///
+    /// ⚠️ Even if this function returns `true`, it doesn't necessarily mean that the process will
+    /// be killed. It just means that the signal was sent successfully.
+    ///
+    /// ⚠️ Please note that some processes might not be "killable", like if they run with higher
+    /// levels than the current process for example.
+    ///
+    /// If you want to use another signal, take a look at [`Process::kill_with`].
+    ///
+    /// To get the list of the supported signals on this system, use
+    /// [`SUPPORTED_SIGNALS`][crate::SUPPORTED_SIGNALS].
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     process.kill();
+    /// }
+    /// ```
+    pub fn kill(&self) -> bool {
+        self.kill_with(Signal::Kill).unwrap_or(false)
+    }
+
+    /// Sends the given `signal` to the process. If the signal doesn't exist on this platform,
+    /// it'll do nothing and will return `None`. Otherwise it'll return `Some(bool)`. The boolean
+    /// value will depend on whether or not the signal was sent successfully.
+    ///
+    /// If you just want to kill the process, use [`Process::kill`] directly. If you want to wait
+    /// for this process to end, you can use [`Process::wait`] or [`Process::kill_with_and_wait`].
+    ///
+    /// ⚠️ Please note that some processes might not be "killable", like if they run with higher
+    /// levels than the current process for example.
+    ///
+    /// To get the list of the supported signals on this system, use
+    /// [`SUPPORTED_SIGNALS`][crate::SUPPORTED_SIGNALS].
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, Signal, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     if process.kill_with(Signal::Kill).is_none() {
+    ///         println!("This signal isn't supported on this platform");
+    ///     }
+    /// }
+    /// ```
+    pub fn kill_with(&self, signal: Signal) -> Option<bool> {
+        self.inner.kill_with(signal)
+    }
+
+    /// Sends [`Signal::Kill`] to the process then waits for its termination.
     ///
     /// Internally, this method is calling [`Process::kill`] then [`Process::wait`].
     ///
-    /// ⚠️ Please note that some processes might not be "killable", like if they run with higher
-    /// levels than the current process for example. In this case, this method could enter an
-    /// infinite loop.
+    /// ⚠️ Please note that some processes might not be "killable", like if they run with higher
+    /// levels than the current process for example. In this case, this method could enter an
+    /// infinite loop.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     if let Err(error) = process.kill_and_wait() {
+    ///         println!("`kill_and_wait` failed: {error:?}");
+    ///     }
+    /// }
+    /// ```
+    pub fn kill_and_wait(&self) -> Result<Option<ExitStatus>, KillError> {
+        self.kill_with_and_wait(Signal::Kill)
+    }
+
+    /// Sends the given `signal` to the process.then waits for its termination.
+    ///
+    /// Internally, this method is calling [`Process::kill_with`] then [`Process::wait`].
+    ///
+    /// ⚠️ Please note that some processes might not be "killable", like if they run with higher
+    /// levels than the current process for example. In this case, this method could enter an
+    /// infinite loop.
+    ///
+    /// To get the list of the supported signals on this system, use
+    /// [`SUPPORTED_SIGNALS`][crate::SUPPORTED_SIGNALS].
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     if let Err(error) = process.kill_and_wait() {
+    ///         println!("`kill_and_wait` failed: {error:?}");
+    ///     }
+    /// }
+    /// ```
+    pub fn kill_with_and_wait(&self, signal: Signal) -> Result<Option<ExitStatus>, KillError> {
+        match self.inner.kill_with(signal) {
+            Some(sent) => {
+                if !sent {
+                    return Err(KillError::FailedToSendSignal);
+                }
+            }
+            None => return Err(KillError::SignalDoesNotExist),
+        }
+
+        Ok(self.inner.wait())
+    }
+
+    /// Waits for process termination and returns its [`ExitStatus`] if it could be retrieved,
+    /// returns `None` otherwise. It means that as long as the process is alive, this method will
+    /// not return.
+    ///
+    /// ⚠️ On **macOS** and **FreeBSD**, if the process died and a new one took its PID, unless
+    /// you refreshed, it will wait for the new process to end.
+    ///
+    /// On **Windows**, as long as we have a (internal) handle, we can always retrieve the exit
+    /// status.
+    ///
+    /// On **Linux**/**Android**, we check that the start time of the PID we're waiting is the same
+    /// as the current process'. If not it means the process died and a new one got its PID.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let mut s = System::new_all();
+    ///
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("Waiting for pid 1337");
+    ///     let exit_status = process.wait();
+    ///     println!("Pid 1337 exited with: {exit_status:?}");
+    /// }
+    /// ```
+    pub fn wait(&self) -> Option<ExitStatus> {
+        self.inner.wait()
+    }
+
+    /// Returns the name of the process.
+    ///
+    /// **⚠️ Important ⚠️**
+    ///
+    /// On **Linux**, there are two things to know about processes' name:
+    ///  1. It is limited to 15 characters.
+    ///  2. It is not always the exe name.
+    ///
+    /// If you are looking for a specific process, unless you know what you are
+    /// doing, in most cases it's better to use [`Process::exe`] instead (which
+    /// can be empty sometimes!).
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{:?}", process.name());
+    /// }
+    /// ```
+    pub fn name(&self) -> &OsStr {
+        self.inner.name()
+    }
+
+    /// Returns the command line.
+    ///
+    ///  **⚠️ Important ⚠️**
+    ///
+    /// On **Windows**, you might need to use `administrator` privileges when running your program
+    /// to have access to this information.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{:?}", process.cmd());
+    /// }
+    /// ```
+    pub fn cmd(&self) -> &[OsString] {
+        self.inner.cmd()
+    }
+
+    /// Returns the path to the process.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{:?}", process.exe());
+    /// }
+    /// ```
+    ///
+    /// ### Implementation notes
+    ///
+    /// On Linux, this method will return an empty path if there
+    /// was an error trying to read `/proc/<pid>/exe`. This can
+    /// happen, for example, if the permission levels or UID namespaces
+    /// between the caller and target processes are different.
+    ///
+    /// It is also the case that `cmd[0]` is _not_ usually a correct
+    /// replacement for this.
+    /// A process [may change its `cmd[0]` value](https://man7.org/linux/man-pages/man5/proc.5.html)
+    /// freely, making this an untrustworthy source of information.
+    pub fn exe(&self) -> Option<&Path> {
+        self.inner.exe()
+    }
+
+    /// Returns the PID of the process.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{}", process.pid());
+    /// }
+    /// ```
+    pub fn pid(&self) -> Pid {
+        self.inner.pid()
+    }
+
+    /// Returns the environment variables of the process.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{:?}", process.environ());
+    /// }
+    /// ```
+    pub fn environ(&self) -> &[OsString] {
+        self.inner.environ()
+    }
+
+    /// Returns the current working directory.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{:?}", process.cwd());
+    /// }
+    /// ```
+    pub fn cwd(&self) -> Option<&Path> {
+        self.inner.cwd()
+    }
+
+    /// Returns the path of the root directory.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{:?}", process.root());
+    /// }
+    /// ```
+    pub fn root(&self) -> Option<&Path> {
+        self.inner.root()
+    }
+
+    /// Returns the memory usage (in bytes).
+    ///
+    /// This method returns the [size of the resident set], that is, the amount of memory that the
+    /// process allocated and which is currently mapped in physical RAM. It does not include memory
+    /// that is swapped out, or, in some operating systems, that has been allocated but never used.
+    ///
+    /// Thus, it represents exactly the amount of physical RAM that the process is using at the
+    /// present time, but it might not be a good indicator of the total memory that the process will
+    /// be using over its lifetime. For that purpose, you can try and use
+    /// [`virtual_memory`](Process::virtual_memory).
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{} bytes", process.memory());
+    /// }
+    /// ```
+    ///
+    /// [size of the resident set]: https://en.wikipedia.org/wiki/Resident_set_size
+    pub fn memory(&self) -> u64 {
+        self.inner.memory()
+    }
+
+    /// Returns the virtual memory usage (in bytes).
+    ///
+    /// This method returns the [size of virtual memory], that is, the amount of memory that the
+    /// process can access, whether it is currently mapped in physical RAM or not. It includes
+    /// physical RAM, allocated but not used regions, swapped-out regions, and even memory
+    /// associated with [memory-mapped files](https://en.wikipedia.org/wiki/Memory-mapped_file).
+    ///
+    /// This value has limitations though. Depending on the operating system and type of process,
+    /// this value might be a good indicator of the total memory that the process will be using over
+    /// its lifetime. However, for example, in the version 14 of macOS this value is in the order of
+    /// the hundreds of gigabytes for every process, and thus not very informative. Moreover, if a
+    /// process maps into memory a very large file, this value will increase accordingly, even if
+    /// the process is not actively using the memory.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{} bytes", process.virtual_memory());
+    /// }
+    /// ```
+    ///
+    /// [size of virtual memory]: https://en.wikipedia.org/wiki/Virtual_memory
+    pub fn virtual_memory(&self) -> u64 {
+        self.inner.virtual_memory()
+    }
+
+    /// Returns the size (in bytes) of the stack regions of this process.
+    ///
+    /// It is the virtual size of the regions, so it tells how far the stacks grew and not how
+    /// much of them is currently in RAM. On Linux, it is computed from the `[stack]` regions of
+    /// `/proc/[pid]/maps`, which only covers the stack of the main thread on recent kernels.
+    ///
+    /// Returns `None` if it failed retrieving the information (for example if the process belongs
+    /// to another user).
+    ///
+    /// ⚠️ It always returns `None` on other platforms than Linux.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{:?} bytes", process.stack_size());
+    /// }
+    /// ```
+    pub fn stack_size(&self) -> Option<u64> {
+        self.inner.stack_size()
+    }
+
+    /// Returns the size (in bytes) of the heap of this process.
+    ///
+    /// It is the virtual size of the region extended with `brk`. On Linux, it is computed from
+    /// the `[heap]` region of `/proc/[pid]/maps`. Allocators also get memory with anonymous
+    /// `mmap` calls (for large allocations or for the arenas of other threads), which isn't
+    /// included: a heap growing steadily is still a good hint of a leak.
+    ///
+    /// Returns `None` if it failed retrieving the information (for example if the process belongs
+    /// to another user).
+    ///
+    /// ⚠️ It always returns `None` on other platforms than Linux.
+    ///
+    /// **Important**: this information is computed every time this function is called.
     ///
     /// ```no_run
     /// use sysinfo::{Pid, System};
     ///
     /// let s = System::new_all();
     /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     if let Err(error) = process.kill_and_wait() {
-    ///         println!("`kill_and_wait` failed: {error:?}");
+    ///     println!("{:?} bytes", process.heap_size());
+    /// }
+    /// ```
+    pub fn heap_size(&self) -> Option<u64> {
+        self.inner.heap_size()
+    }
+
+    /// Returns the path of the cgroup of this process, relative to the root of the cgroup
+    /// hierarchy (like `/system.slice/docker-<id>.scope`).
+    ///
+    /// With cgroup v1, the path of the systemd hierarchy is returned if any, otherwise the path
+    /// of the first hierarchy listed in `/proc/[pid]/cgroup`.
+    ///
+    /// ⚠️ It always returns `None` on other platforms than Linux.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{:?}", process.cgroup());
+    /// }
+    /// ```
+    pub fn cgroup(&self) -> Option<String> {
+        self.inner.cgroup()
+    }
+
+    /// Returns the id of the container (Docker, Podman, containerd, CRI-O...) in which this
+    /// process runs, or `None` if it doesn't run in a container.
+    ///
+    /// It is retrieved from the [cgroup][Process::cgroup] of the process, so it returns `None`
+    /// for the processes of a container which doesn't have its own cgroup.
+    ///
+    /// ⚠️ It always returns `None` on other platforms than Linux.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{:?}", process.container_id());
+    /// }
+    /// ```
+    pub fn container_id(&self) -> Option<String> {
+        self.inner.container_id()
+    }
+
+    /// Returns the parent PID.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{:?}", process.parent());
+    /// }
+    /// ```
+    pub fn parent(&self) -> Option<Pid> {
+        self.inner.parent()
+    }
+
+    /// Returns `true` if the parent of this process exited and it was reparented to the init
+    /// process (PID `1`) since it was first retrieved.
+    ///
+    /// ⚠️ It always returns `false` on Windows (where processes aren't reparented), Redox and iOS.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{}", process.reparented_to_init());
+    /// }
+    /// ```
+    pub fn reparented_to_init(&self) -> bool {
+        self.inner.reparented_to_init()
+    }
+
+    /// Returns how many times this process was seen replacing its executable image with
+    /// `exec` since it was first retrieved. It allows to notice when a shell wrapper executes
+    /// the real program without spawning a new process (the PID stays the same).
+    ///
+    /// `exec` is detected during the processes refresh when the name of the process changed,
+    /// so:
+    ///
+    ///  * Several `exec` calls between two refreshes are counted once.
+    ///  * An `exec` of an executable with the same name isn't counted.
+    ///
+    /// On Linux, a process can change its own name (with `prctl(PR_SET_NAME)`), so the name
+    /// change is only counted if the executable file changed too. It means that an `exec` of
+    /// the same executable file (like a `busybox` applet running another one) isn't counted.
+    /// If the executable cannot be checked (usually for processes of other users when not
+    /// running as root), any name change is counted.
+    ///
+    /// ⚠️ It always returns `0` on other platforms than Linux and macOS.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     if process.exec_count() > 0 {
+    ///         println!("{:?} was executed by another program", process.name());
     ///     }
     /// }
     /// ```
-    pub fn kill_and_wait(&self) -> Result<Option<ExitStatus>, KillError> {
-        self.kill_with_and_wait(Signal::Kill)
+    pub fn exec_count(&self) -> u64 {
+        self.inner.exec_count()
     }
 
-    /// Sends the given `signal` to the process.then waits for its termination.
+    /// Returns the status of the process.
     ///
-    /// Internally, this method is calling [`Process::kill_with`] then [`Process::wait`].
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
     ///
-    /// ⚠️ Please note that some processes might not be "killable", like if they run with higher
-    /// levels than the current process for example. In this case, this method could enter an
-    /// infinite loop.
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{:?}", process.status());
+    /// }
+    /// ```
+    pub fn status(&self) -> ProcessStatus {
+        self.inner.status()
+    }
+
+    /// Returns the time where the process was started (in seconds) from epoch.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("Started at {} seconds", process.start_time());
+    /// }
+    /// ```
+    pub fn start_time(&self) -> u64 {
+        self.inner.start_time()
+    }
+
+    /// Returns a handle identifying this process which can be kept across refreshes and given
+    /// back to [`System::process_by_handle`].
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{:?}", process.handle());
+    /// }
+    /// ```
+    pub fn handle(&self) -> ProcessHandle {
+        ProcessHandle {
+            pid: self.pid(),
+            start_time_raw: self.inner.start_time_raw(),
+        }
+    }
+
+    /// Returns `true` if this process was started after `other`.
+    ///
+    /// It is based on [`Process::start_time`], so processes started during the same second are
+    /// not considered as started after one another.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let (Some(a), Some(b)) = (s.process(Pid::from(1337)), s.process(Pid::from(1338))) {
+    ///     println!("{}", a.started_after(b));
+    /// }
+    /// ```
+    pub fn started_after(&self, other: &Process) -> bool {
+        self.start_time() > other.start_time()
+    }
+
+    /// Returns for how much time the process has been running (in seconds).
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("Running since {} seconds", process.run_time());
+    /// }
+    /// ```
+    pub fn run_time(&self) -> u64 {
+        self.inner.run_time()
+    }
+
+    /// Returns when this process was last updated by a refresh, or `None` if it was never
+    /// refreshed since it was added to the process list.
+    ///
+    /// A process which couldn't be found anymore during a refresh (and wasn't removed) keeps
+    /// its previous value.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337))
+    ///     && let Some(at) = process.last_refreshed_at()
+    /// {
+    ///     println!("Refreshed {:?} ago", at.instant().elapsed());
+    /// }
+    /// ```
+    pub fn last_refreshed_at(&self) -> Option<RefreshTimestamp> {
+        self.last_refreshed_at
+    }
+
+    /// Returns the total CPU usage (in %). Notice that it might be bigger than
+    /// 100 if run on a multi-core machine.
+    ///
+    /// If you want a value between 0% and 100%, divide the returned value by
+    /// the number of CPUs.
+    ///
+    /// ⚠️ To start to have accurate CPU usage, a process needs to be refreshed
+    /// **twice** because CPU usage computation is based on time diff (process
+    /// time on a given time period divided by total system time on the same
+    /// time period).
+    ///
+    /// ⚠️ If you want accurate CPU usage number, better leave a bit of time
+    /// between two calls of this method (take a look at
+    /// [`MINIMUM_CPU_UPDATE_INTERVAL`][crate::MINIMUM_CPU_UPDATE_INTERVAL] for
+    /// more information).
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, ProcessesToUpdate, ProcessRefreshKind, System};
+    ///
+    /// let mut s = System::new_all();
+    /// // Wait a bit because CPU usage is based on diff.
+    /// std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+    /// // Refresh CPU usage to get actual value.
+    /// s.refresh_processes_specifics(
+    ///     ProcessesToUpdate::All,
+    ///     true,
+    ///     ProcessRefreshKind::nothing().with_cpu()
+    /// );
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{}%", process.cpu_usage());
+    /// }
+    /// ```
+    pub fn cpu_usage(&self) -> f32 {
+        self.inner.cpu_usage()
+    }
+
+    /// Returns the total accumulated CPU usage (in CPU-milliseconds). Note
+    /// that it might be bigger than the total clock run time of a process if
+    /// run on a multi-core machine.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{}", process.accumulated_cpu_time());
+    /// }
+    /// ```
+    pub fn accumulated_cpu_time(&self) -> u64 {
+        self.inner.accumulated_cpu_time()
+    }
+
+    /// Returns the total CPU time (in CPU-milliseconds) used by the children of this process
+    /// which terminated and were waited for. It allows to attribute the cost of short-lived
+    /// children (like compilers spawned by a build system) to their parent.
+    ///
+    /// It is updated with the CPU information
+    /// ([`ProcessRefreshKind::with_cpu`][crate::ProcessRefreshKind::with_cpu]).
+    ///
+    /// ⚠️ It always returns `0` on Windows and Redox.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{}", process.children_cpu_time());
+    /// }
+    /// ```
+    pub fn children_cpu_time(&self) -> u64 {
+        self.inner.children_cpu_time()
+    }
+
+    /// Returns number of bytes read and written to disk.
+    ///
+    /// ⚠️ On Windows, this method actually returns **ALL** I/O read and
+    /// written bytes. Take a look at [`DiskUsage`] for what is counted on each platform.
+    ///
+    /// ⚠️ Files might be cached in memory by your OS, meaning that reading/writing them might not
+    /// increase the `read_bytes`/`written_bytes` values. You can find more information about it
+    /// in the `proc_pid_io` manual (`man proc_pid_io` on unix platforms).
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     let disk_usage = process.disk_usage();
+    ///     println!("read bytes   : new/total => {}/{}",
+    ///         disk_usage.read_bytes,
+    ///         disk_usage.total_read_bytes,
+    ///     );
+    ///     println!("written bytes: new/total => {}/{}",
+    ///         disk_usage.written_bytes,
+    ///         disk_usage.total_written_bytes,
+    ///     );
+    /// }
+    /// ```
+    pub fn disk_usage(&self) -> DiskUsage {
+        self.inner.disk_usage()
+    }
+
+    /// Returns the ID of the owner user of this process or `None` if this
+    /// information couldn't be retrieved. If you want to get the [`User`] from
+    /// it, take a look at [`Users::get_user_by_id`].
     ///
-    /// To get the list of the supported signals on this system, use
-    /// [`SUPPORTED_SIGNALS`][crate::SUPPORTED_SIGNALS].
+    /// [`User`]: crate::User
+    /// [`Users::get_user_by_id`]: crate::Users::get_user_by_id
     ///
     /// ```no_run
     /// use sysinfo::{Pid, System};
     ///
-    /// let s = System::new_all();
+    /// let mut s = System::new_all();
+    ///
     /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     if let Err(error) = process.kill_and_wait() {
-    ///         println!("`kill_and_wait` failed: {error:?}");
-    ///     }
+    ///     println!("User id for process 1337: {:?}", process.user_id());
     /// }
     /// ```
-    pub fn kill_with_and_wait(&self, signal: Signal) -> Result<Option<ExitStatus>, KillError> {
-        match self.inner.kill_with(signal) {
-            Some(sent) => {
-                if !sent {
-                    return Err(KillError::FailedToSendSignal);
-                }
-            }
-            None => return Err(KillError::SignalDoesNotExist),
-        }
-
-        Ok(self.inner.wait())
+    pub fn user_id(&self) -> Option<&Uid> {
+        self.inner.user_id()
     }
 
-    /// Waits for process termination and returns its [`ExitStatus`] if it could be retrieved,
-    /// returns `None` otherwise. It means that as long as the process is alive, this method will
-    /// not return.
+    /// Returns the user ID of the effective owner of this process or `None` if
+    /// this information couldn't be retrieved. If you want to get the [`User`]
+    /// from it, take a look at [`Users::get_user_by_id`].
     ///
-    /// ⚠️ On **macOS** and **FreeBSD**, if the process died and a new one took its PID, unless
-    /// you refreshed, it will wait for the new process to end.
+    /// If you run something with `sudo`, the real user ID of the launched
+    /// process will be the ID of the user you are logged in as but effective
+    /// user ID will be `0` (i-e root).
     ///
-    /// On **Windows**, as long as we have a (internal) handle, we can always retrieve the exit
-    /// status.
+    /// ⚠️ It always returns `None` on Windows.
     ///
-    /// On **Linux**/**Android**, we check that the start time of the PID we're waiting is the same
-    /// as the current process'. If not it means the process died and a new one got its PID.
+    /// [`User`]: crate::User
+    /// [`Users::get_user_by_id`]: crate::Users::get_user_by_id
     ///
     /// ```no_run
     /// use sysinfo::{Pid, System};
@@ -1620,563 +5204,697 @@ impl Process {
     /// let mut s = System::new_all();
     ///
     /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("Waiting for pid 1337");
-    ///     let exit_status = process.wait();
-    ///     println!("Pid 1337 exited with: {exit_status:?}");
+    ///     println!("User id for process 1337: {:?}", process.effective_user_id());
     /// }
     /// ```
-    pub fn wait(&self) -> Option<ExitStatus> {
-        self.inner.wait()
+    pub fn effective_user_id(&self) -> Option<&Uid> {
+        self.inner.effective_user_id()
     }
 
-    /// Returns the name of the process.
-    ///
-    /// **⚠️ Important ⚠️**
-    ///
-    /// On **Linux**, there are two things to know about processes' name:
-    ///  1. It is limited to 15 characters.
-    ///  2. It is not always the exe name.
+    /// Returns the process group ID of the process.
     ///
-    /// If you are looking for a specific process, unless you know what you are
-    /// doing, in most cases it's better to use [`Process::exe`] instead (which
-    /// can be empty sometimes!).
+    /// ⚠️ It always returns `None` on Windows.
     ///
     /// ```no_run
     /// use sysinfo::{Pid, System};
     ///
-    /// let s = System::new_all();
+    /// let mut s = System::new_all();
+    ///
     /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("{:?}", process.name());
+    ///     println!("Group ID for process 1337: {:?}", process.group_id());
     /// }
     /// ```
-    pub fn name(&self) -> &OsStr {
-        self.inner.name()
+    pub fn group_id(&self) -> Option<Gid> {
+        self.inner.group_id()
     }
 
-    /// Returns the command line.
+    /// Returns the effective group ID of the process.
     ///
-    ///  **⚠️ Important ⚠️**
+    /// If you run something with `sudo`, the real group ID of the launched
+    /// process will be the primary group ID you are logged in as but effective
+    /// group ID will be `0` (i-e root).
     ///
-    /// On **Windows**, you might need to use `administrator` privileges when running your program
-    /// to have access to this information.
+    /// ⚠️ It always returns `None` on Windows.
     ///
     /// ```no_run
     /// use sysinfo::{Pid, System};
     ///
-    /// let s = System::new_all();
+    /// let mut s = System::new_all();
+    ///
     /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("{:?}", process.cmd());
+    ///     println!("User id for process 1337: {:?}", process.effective_group_id());
     /// }
     /// ```
-    pub fn cmd(&self) -> &[OsString] {
-        self.inner.cmd()
+    pub fn effective_group_id(&self) -> Option<Gid> {
+        self.inner.effective_group_id()
     }
 
-    /// Returns the path to the process.
+    /// Returns the session ID for the current process or `None` if it couldn't
+    /// be retrieved.
+    ///
+    /// ⚠️ On macOS and Windows, this information is computed every time this method is called.
+    /// On other platforms, it is retrieved when the process is refreshed.
     ///
     /// ```no_run
     /// use sysinfo::{Pid, System};
     ///
-    /// let s = System::new_all();
+    /// let mut s = System::new_all();
+    ///
     /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("{:?}", process.exe());
+    ///     println!("Session ID for process 1337: {:?}", process.session_id());
     /// }
     /// ```
-    ///
-    /// ### Implementation notes
-    ///
-    /// On Linux, this method will return an empty path if there
-    /// was an error trying to read `/proc/<pid>/exe`. This can
-    /// happen, for example, if the permission levels or UID namespaces
-    /// between the caller and target processes are different.
-    ///
-    /// It is also the case that `cmd[0]` is _not_ usually a correct
-    /// replacement for this.
-    /// A process [may change its `cmd[0]` value](https://man7.org/linux/man-pages/man5/proc.5.html)
-    /// freely, making this an untrustworthy source of information.
-    pub fn exe(&self) -> Option<&Path> {
-        self.inner.exe()
+    pub fn session_id(&self) -> Option<Pid> {
+        self.inner.session_id()
     }
 
-    /// Returns the PID of the process.
+    /// Returns the process group ID of the process or `None` if it couldn't be retrieved.
+    ///
+    /// ⚠️ It always returns `None` on Windows and iOS.
     ///
     /// ```no_run
     /// use sysinfo::{Pid, System};
     ///
-    /// let s = System::new_all();
+    /// let mut s = System::new_all();
+    ///
     /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("{}", process.pid());
+    ///     println!("Process group ID for process 1337: {:?}", process.process_group_id());
     /// }
     /// ```
-    pub fn pid(&self) -> Pid {
-        self.inner.pid()
+    pub fn process_group_id(&self) -> Option<Pid> {
+        self.inner.process_group_id()
     }
 
-    /// Returns the environment variables of the process.
+    /// Returns the number of threads of this process.
+    ///
+    /// Unlike [`Process::tasks`], it is available on all supported systems. On Linux, a thread
+    /// listed as a process (see [`Process::thread_kind`]) returns the number of threads of the
+    /// process it belongs to.
+    ///
+    /// On macOS, it is only updated when refreshing the CPU or memory usage of the process.
+    ///
+    /// ⚠️ It always returns `None` on iOS.
     ///
     /// ```no_run
     /// use sysinfo::{Pid, System};
     ///
     /// let s = System::new_all();
     /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("{:?}", process.environ());
+    ///     println!("{:?} threads", process.thread_count());
     /// }
     /// ```
-    pub fn environ(&self) -> &[OsString] {
-        self.inner.environ()
+    pub fn thread_count(&self) -> Option<usize> {
+        self.inner.thread_count()
     }
 
-    /// Returns the current working directory.
+    /// Returns the name of the controlling terminal of this process (like `pts/0`), without the
+    /// `/dev/` prefix. Returns `None` if the process doesn't have a controlling terminal.
+    ///
+    /// ⚠️ It always returns `None` on Windows, Redox and iOS.
     ///
     /// ```no_run
     /// use sysinfo::{Pid, System};
     ///
     /// let s = System::new_all();
     /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("{:?}", process.cwd());
+    ///     println!("TTY: {}", process.tty().as_deref().unwrap_or("?"));
     /// }
     /// ```
-    pub fn cwd(&self) -> Option<&Path> {
-        self.inner.cwd()
+    pub fn tty(&self) -> Option<String> {
+        self.inner.tty()
     }
 
-    /// Returns the path of the root directory.
+    /// Tasks run by this process. If there are none, returns `None`.
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than Linux.
     ///
     /// ```no_run
     /// use sysinfo::{Pid, System};
     ///
-    /// let s = System::new_all();
+    /// let mut s = System::new_all();
+    ///
     /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("{:?}", process.root());
+    ///     if let Some(tasks) = process.tasks() {
+    ///         println!("Listing tasks for process {:?}", process.pid());
+    ///         for task_pid in tasks {
+    ///             if let Some(task) = s.process(*task_pid) {
+    ///                 println!("Task {:?}: {:?}", task.pid(), task.name());
+    ///             }
+    ///         }
+    ///     }
     /// }
     /// ```
-    pub fn root(&self) -> Option<&Path> {
-        self.inner.root()
+    pub fn tasks(&self) -> Option<&HashSet<Pid>> {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.tasks.as_ref()
+            } else {
+                None
+            }
+        }
     }
 
-    /// Returns the memory usage (in bytes).
-    ///
-    /// This method returns the [size of the resident set], that is, the amount of memory that the
-    /// process allocated and which is currently mapped in physical RAM. It does not include memory
-    /// that is swapped out, or, in some operating systems, that has been allocated but never used.
+    /// If the process is a thread, it'll return `Some` with the kind of thread it is. Returns
+    /// `None` otherwise.
     ///
-    /// Thus, it represents exactly the amount of physical RAM that the process is using at the
-    /// present time, but it might not be a good indicator of the total memory that the process will
-    /// be using over its lifetime. For that purpose, you can try and use
-    /// [`virtual_memory`](Process::virtual_memory).
+    /// ⚠️ This method always returns `None` on other platforms than Linux.
     ///
     /// ```no_run
-    /// use sysinfo::{Pid, System};
+    /// use sysinfo::System;
     ///
     /// let s = System::new_all();
-    /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("{} bytes", process.memory());
+    ///
+    /// for (_, process) in s.processes() {
+    ///     if let Some(thread_kind) = process.thread_kind() {
+    ///         println!("Process {:?} is a {thread_kind:?} thread", process.pid());
+    ///     }
     /// }
     /// ```
-    ///
-    /// [size of the resident set]: https://en.wikipedia.org/wiki/Resident_set_size
-    pub fn memory(&self) -> u64 {
-        self.inner.memory()
+    pub fn thread_kind(&self) -> Option<ThreadKind> {
+        cfg_if! {
+            if #[cfg(all(
+                any(target_os = "linux", target_os = "android"),
+                not(feature = "unknown-ci")
+            ))] {
+                self.inner.thread_kind()
+            } else {
+                None
+            }
+        }
     }
 
-    /// Returns the virtual memory usage (in bytes).
-    ///
-    /// This method returns the [size of virtual memory], that is, the amount of memory that the
-    /// process can access, whether it is currently mapped in physical RAM or not. It includes
-    /// physical RAM, allocated but not used regions, swapped-out regions, and even memory
-    /// associated with [memory-mapped files](https://en.wikipedia.org/wiki/Memory-mapped_file).
-    ///
-    /// This value has limitations though. Depending on the operating system and type of process,
-    /// this value might be a good indicator of the total memory that the process will be using over
-    /// its lifetime. However, for example, in the version 14 of macOS this value is in the order of
-    /// the hundreds of gigabytes for every process, and thus not very informative. Moreover, if a
-    /// process maps into memory a very large file, this value will increase accordingly, even if
-    /// the process is not actively using the memory.
+    /// Returns `true` if the process doesn't exist anymore but was not yet removed from
+    /// the processes list because the `remove_dead_processes` argument was set to `false`
+    /// in methods like [`System::refresh_processes`].
     ///
     /// ```no_run
-    /// use sysinfo::{Pid, System};
+    /// use sysinfo::{ProcessesToUpdate, System};
     ///
-    /// let s = System::new_all();
-    /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("{} bytes", process.virtual_memory());
+    /// let mut s = System::new_all();
+    /// // We set the `remove_dead_processes` to `false`.
+    /// s.refresh_processes(ProcessesToUpdate::All, false);
+    ///
+    /// for (_, process) in s.processes() {
+    ///     println!(
+    ///         "Process {:?} {}",
+    ///         process.pid(),
+    ///         if process.exists() { "exists" } else { "doesn't exist" },
+    ///     );
     /// }
     /// ```
-    ///
-    /// [size of virtual memory]: https://en.wikipedia.org/wiki/Virtual_memory
-    pub fn virtual_memory(&self) -> u64 {
-        self.inner.virtual_memory()
+    pub fn exists(&self) -> bool {
+        self.inner.exists()
     }
 
-    /// Returns the parent PID.
+    /// Returns the number of open files in the current process.
+    ///
+    /// Returns `None` if it failed retrieving the information or if the current system is not
+    /// supported.
+    ///
+    /// **Important**: this information is computed every time this function is called.
     ///
     /// ```no_run
-    /// use sysinfo::{Pid, System};
+    /// use sysinfo::System;
     ///
     /// let s = System::new_all();
-    /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("{:?}", process.parent());
+    ///
+    /// for (_, process) in s.processes() {
+    ///     println!(
+    ///         "Process {:?} {:?}",
+    ///         process.pid(),
+    ///         process.open_files(),
+    ///     );
     /// }
     /// ```
-    pub fn parent(&self) -> Option<Pid> {
-        self.inner.parent()
+    pub fn open_files(&self) -> Option<usize> {
+        self.inner.open_files()
     }
 
-    /// Returns the status of the process.
+    /// Returns the maximum number of open files for the current process.
+    ///
+    /// Returns `None` if it failed retrieving the information or if the current system is not
+    /// supported.
+    ///
+    /// **Important**: this information is computed every time this function is called.
     ///
     /// ```no_run
-    /// use sysinfo::{Pid, System};
+    /// use sysinfo::System;
     ///
     /// let s = System::new_all();
-    /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("{:?}", process.status());
+    ///
+    /// for (_, process) in s.processes() {
+    ///     println!(
+    ///         "Process {:?} {:?}",
+    ///         process.pid(),
+    ///         process.open_files_limit(),
+    ///     );
     /// }
     /// ```
-    pub fn status(&self) -> ProcessStatus {
-        self.inner.status()
+    pub fn open_files_limit(&self) -> Option<usize> {
+        self.inner.open_files_limit()
     }
 
-    /// Returns the time where the process was started (in seconds) from epoch.
+    /// Returns the local addresses of the TCP sockets of this process which are listening for
+    /// incoming connections.
+    ///
+    /// On Linux, sockets are matched using the network namespace of the process.
+    ///
+    /// Returns `None` if it failed retrieving the information (for example if the process belongs
+    /// to another user).
+    ///
+    /// ⚠️ It always returns `None` on FreeBSD, Redox and Apple systems.
+    ///
+    /// **Important**: this information is computed every time this function is called.
     ///
     /// ```no_run
-    /// use sysinfo::{Pid, System};
+    /// use sysinfo::System;
     ///
     /// let s = System::new_all();
-    /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("Started at {} seconds", process.start_time());
+    ///
+    /// for (pid, process) in s.processes() {
+    ///     if let Some(ports) = process.listening_ports()
+    ///         && !ports.is_empty()
+    ///     {
+    ///         println!("{pid}: {ports:?}");
+    ///     }
     /// }
     /// ```
-    pub fn start_time(&self) -> u64 {
-        self.inner.start_time()
+    pub fn listening_ports(&self) -> Option<Vec<SocketAddr>> {
+        self.inner.listening_ports()
     }
 
-    /// Returns for how much time the process has been running (in seconds).
+    /// Returns the number of TCP and UDP sockets of this process (in all states), without
+    /// retrieving their addresses. It is cheaper than listing the connections when only their
+    /// number is needed.
+    ///
+    /// Returns `None` if it failed retrieving the information (for example if the process belongs
+    /// to another user).
+    ///
+    /// ⚠️ It always returns `None` on FreeBSD, Redox and Apple systems.
+    ///
+    /// **Important**: this information is computed every time this function is called.
     ///
     /// ```no_run
-    /// use sysinfo::{Pid, System};
+    /// use sysinfo::System;
     ///
     /// let s = System::new_all();
-    /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("Running since {} seconds", process.run_time());
+    ///
+    /// for (pid, process) in s.processes() {
+    ///     if let Some(count) = process.connection_count() {
+    ///         println!("{pid}: {} TCP, {} UDP", count.tcp, count.udp);
+    ///     }
     /// }
     /// ```
-    pub fn run_time(&self) -> u64 {
-        self.inner.run_time()
+    pub fn connection_count(&self) -> Option<ConnectionCount> {
+        self.inner.connection_count()
     }
 
-    /// Returns the total CPU usage (in %). Notice that it might be bigger than
-    /// 100 if run on a multi-core machine.
+    /// Returns `true` if this process runs 64-bit code.
     ///
-    /// If you want a value between 0% and 100%, divide the returned value by
-    /// the number of CPUs.
+    /// A 32-bit process can run on a 64-bit kernel, so it can differ from
+    /// [`System::cpu_arch`]. See [`Process::abi`] for more details.
     ///
-    /// ⚠️ To start to have accurate CPU usage, a process needs to be refreshed
-    /// **twice** because CPU usage computation is based on time diff (process
-    /// time on a given time period divided by total system time on the same
-    /// time period).
+    /// Returns `None` if it failed retrieving the information (for example if the process belongs
+    /// to another user).
     ///
-    /// ⚠️ If you want accurate CPU usage number, better leave a bit of time
-    /// between two calls of this method (take a look at
-    /// [`MINIMUM_CPU_UPDATE_INTERVAL`][crate::MINIMUM_CPU_UPDATE_INTERVAL] for
-    /// more information).
+    /// ⚠️ It always returns `None` on FreeBSD, Redox and Apple systems.
+    ///
+    /// **Important**: this information is computed every time this function is called.
     ///
     /// ```no_run
-    /// use sysinfo::{Pid, ProcessesToUpdate, ProcessRefreshKind, System};
+    /// use sysinfo::System;
     ///
-    /// let mut s = System::new_all();
-    /// // Wait a bit because CPU usage is based on diff.
-    /// std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
-    /// // Refresh CPU usage to get actual value.
-    /// s.refresh_processes_specifics(
-    ///     ProcessesToUpdate::All,
-    ///     true,
-    ///     ProcessRefreshKind::nothing().with_cpu()
-    /// );
-    /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("{}%", process.cpu_usage());
+    /// let s = System::new_all();
+    ///
+    /// for (pid, process) in s.processes() {
+    ///     if process.is_64_bit() == Some(false) {
+    ///         println!("{pid} is a 32-bit process");
+    ///     }
     /// }
     /// ```
-    pub fn cpu_usage(&self) -> f32 {
-        self.inner.cpu_usage()
+    pub fn is_64_bit(&self) -> Option<bool> {
+        self.inner.is_64_bit()
     }
 
-    /// Returns the total accumulated CPU usage (in CPU-milliseconds). Note
-    /// that it might be bigger than the total clock run time of a process if
-    /// run on a multi-core machine.
+    /// Returns the architecture the code of this process was built for, using the same names
+    /// as [`System::cpu_arch`] (eg. "x86", "x86_64", "arm", "aarch64", ...).
+    ///
+    /// On Linux, it is read from the ELF header of the executable, so 64-bit executables using
+    /// 32-bit pointers are reported as "x32". On Windows, it is the architecture of the process
+    /// as seen by WOW64.
+    ///
+    /// Returns `None` if it failed retrieving the information (for example if the process belongs
+    /// to another user) or if the architecture isn't known.
+    ///
+    /// ⚠️ It always returns `None` on FreeBSD, Redox and Apple systems.
+    ///
+    /// **Important**: this information is computed every time this function is called.
     ///
     /// ```no_run
-    /// use sysinfo::{Pid, System};
+    /// use sysinfo::System;
     ///
     /// let s = System::new_all();
-    /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("{}", process.accumulated_cpu_time());
+    /// let arch = System::cpu_arch();
+    ///
+    /// for (pid, process) in s.processes() {
+    ///     if let Some(abi) = process.abi().filter(|abi| *abi != arch) {
+    ///         println!("{pid} runs {abi} code on {arch}");
+    ///     }
     /// }
     /// ```
-    pub fn accumulated_cpu_time(&self) -> u64 {
-        self.inner.accumulated_cpu_time()
+    pub fn abi(&self) -> Option<&'static str> {
+        self.inner.abi()
     }
 
-    /// Returns number of bytes read and written to disk.
+    /// Returns the scheduler statistics of this process: how long it ran, how long it waited
+    /// in a run queue before running and the number of timeslices it got.
     ///
-    /// ⚠️ On Windows, this method actually returns **ALL** I/O read and
-    /// written bytes.
+    /// The waiting time is the scheduling delay: a high value compared to the running time
+    /// means the process is slowed down by other processes competing for the CPUs.
     ///
-    /// ⚠️ Files might be cached in memory by your OS, meaning that reading/writing them might not
-    /// increase the `read_bytes`/`written_bytes` values. You can find more information about it
-    /// in the `proc_pid_io` manual (`man proc_pid_io` on unix platforms).
+    /// ⚠️ It always returns `None` on other platforms than Linux. On Linux, it requires the
+    /// kernel to be built with `CONFIG_SCHED_INFO`.
+    ///
+    /// **Important**: this information is computed every time this function is called.
     ///
     /// ```no_run
-    /// use sysinfo::{Pid, System};
+    /// use sysinfo::System;
     ///
     /// let s = System::new_all();
-    /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     let disk_usage = process.disk_usage();
-    ///     println!("read bytes   : new/total => {}/{}",
-    ///         disk_usage.read_bytes,
-    ///         disk_usage.total_read_bytes,
-    ///     );
-    ///     println!("written bytes: new/total => {}/{}",
-    ///         disk_usage.written_bytes,
-    ///         disk_usage.total_written_bytes,
-    ///     );
+    ///
+    /// for (pid, process) in s.processes() {
+    ///     if let Some(stats) = process.sched_stats() {
+    ///         println!("{pid}: waited {:?}", stats.wait_time);
+    ///     }
     /// }
     /// ```
-    pub fn disk_usage(&self) -> DiskUsage {
-        self.inner.disk_usage()
+    pub fn sched_stats(&self) -> Option<SchedStats> {
+        self.inner.sched_stats()
     }
 
-    /// Returns the ID of the owner user of this process or `None` if this
-    /// information couldn't be retrieved. If you want to get the [`User`] from
-    /// it, take a look at [`Users::get_user_by_id`].
+    /// Returns the number of read and write system calls done by this process and the number
+    /// of written bytes which were cancelled before reaching the storage device.
     ///
-    /// [`User`]: crate::User
-    /// [`Users::get_user_by_id`]: crate::Users::get_user_by_id
+    /// Combined with [`Process::disk_usage`], it allows to distinguish a process doing many
+    /// small I/O operations from one doing a few large ones.
+    ///
+    /// ⚠️ It always returns `None` on other platforms than Linux. On Linux, it requires the
+    /// kernel to be built with `CONFIG_TASK_IO_ACCOUNTING` and reading the information of
+    /// processes owned by other users requires extra privileges.
+    ///
+    /// **Important**: this information is computed every time this function is called.
     ///
     /// ```no_run
-    /// use sysinfo::{Pid, System};
+    /// use sysinfo::System;
     ///
-    /// let mut s = System::new_all();
+    /// let s = System::new_all();
     ///
-    /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("User id for process 1337: {:?}", process.user_id());
+    /// for (pid, process) in s.processes() {
+    ///     if let Some(counters) = process.io_counters() {
+    ///         println!("{pid}: {} write calls", counters.write_syscalls);
+    ///     }
     /// }
     /// ```
-    pub fn user_id(&self) -> Option<&Uid> {
-        self.inner.user_id()
+    pub fn io_counters(&self) -> Option<IoCounters> {
+        self.inner.io_counters()
     }
 
-    /// Returns the user ID of the effective owner of this process or `None` if
-    /// this information couldn't be retrieved. If you want to get the [`User`]
-    /// from it, take a look at [`Users::get_user_by_id`].
+    /// Returns the name of the kernel function this process is sleeping in (its "wait
+    /// channel"), or `None` if it isn't sleeping.
     ///
-    /// If you run something with `sudo`, the real user ID of the launched
-    /// process will be the ID of the user you are logged in as but effective
-    /// user ID will be `0` (i-e root).
+    /// It helps understanding what a process in [`ProcessStatus::UninterruptibleDiskSleep`]
+    /// (`D` state) is waiting for.
     ///
-    /// ⚠️ It always returns `None` on Windows.
+    /// ⚠️ It always returns `None` on other platforms than Linux. On Linux, the kernel only
+    /// reports it to the users allowed to read the kernel stack of the process (usually root).
     ///
-    /// [`User`]: crate::User
-    /// [`Users::get_user_by_id`]: crate::Users::get_user_by_id
+    /// **Important**: this information is computed every time this function is called.
     ///
     /// ```no_run
-    /// use sysinfo::{Pid, System};
+    /// use sysinfo::{ProcessStatus, System};
     ///
-    /// let mut s = System::new_all();
+    /// let s = System::new_all();
     ///
-    /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("User id for process 1337: {:?}", process.effective_user_id());
+    /// for (pid, process) in s.processes() {
+    ///     if process.status() == ProcessStatus::UninterruptibleDiskSleep {
+    ///         println!("{pid}: blocked in {:?}", process.wait_channel());
+    ///     }
     /// }
     /// ```
-    pub fn effective_user_id(&self) -> Option<&Uid> {
-        self.inner.effective_user_id()
+    pub fn wait_channel(&self) -> Option<String> {
+        self.inner.wait_channel()
     }
 
-    /// Returns the process group ID of the process.
+    /// Returns the kernel stack of this process (the kernel functions it is currently in,
+    /// innermost first), to find where a hung task is stuck.
     ///
-    /// ⚠️ It always returns `None` on Windows.
+    /// On Linux, it reads `/proc/<pid>/stack`, which only root can read:
+    /// [`RefreshError::PermissionDenied`] is returned otherwise. The stack is empty if the process
+    /// is running in user space.
+    ///
+    /// ⚠️ It always returns [`RefreshError::Unsupported`] on other platforms than Linux.
+    ///
+    /// **Important**: this information is computed every time this function is called.
     ///
     /// ```no_run
-    /// use sysinfo::{Pid, System};
+    /// use sysinfo::{Pid, RefreshError, System};
     ///
-    /// let mut s = System::new_all();
+    /// let s = System::new_all();
     ///
     /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("Group ID for process 1337: {:?}", process.group_id());
+    ///     match process.kernel_stack() {
+    ///         Ok(stack) => {
+    ///             for frame in stack {
+    ///                 println!("{frame}");
+    ///             }
+    ///         }
+    ///         Err(RefreshError::PermissionDenied(_)) => eprintln!("must be run as root"),
+    ///         Err(error) => eprintln!("failed to get the kernel stack: {error}"),
+    ///     }
     /// }
     /// ```
-    pub fn group_id(&self) -> Option<Gid> {
-        self.inner.group_id()
+    pub fn kernel_stack(&self) -> Result<Vec<String>, RefreshError> {
+        self.inner.kernel_stack()
     }
 
-    /// Returns the effective group ID of the process.
+    /// Returns information identifying the executable of this process:
     ///
-    /// If you run something with `sudo`, the real group ID of the launched
-    /// process will be the primary group ID you are logged in as but effective
-    /// group ID will be `0` (i-e root).
+    ///  * On Windows, the name of the Authenticode signer if the executable has a valid signature.
+    ///  * On macOS, the Team ID of the code signature if the executable is signed by a team.
+    ///  * Otherwise, the SHA-256 hash of the executable file.
     ///
-    /// ⚠️ It always returns `None` on Windows.
+    /// Returns `None` if [`Process::exe`] is not set (take a look at
+    /// [`ProcessRefreshKind::with_exe`]) or if the executable couldn't be read.
+    ///
+    /// **Important**: checking the signature or hashing the executable is expensive, so this
+    /// information is only computed the first time this method is called and then kept for as
+    /// long as this [`Process`] is the same process.
+    ///
+    /// ⚠️ It always returns `None` on iOS and with the `apple-sandbox` feature.
     ///
     /// ```no_run
     /// use sysinfo::{Pid, System};
     ///
-    /// let mut s = System::new_all();
+    /// let s = System::new_all();
     ///
     /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("User id for process 1337: {:?}", process.effective_group_id());
+    ///     println!("{:?}", process.exe_signature());
     /// }
     /// ```
-    pub fn effective_group_id(&self) -> Option<Gid> {
-        self.inner.effective_group_id()
+    pub fn exe_signature(&self) -> Option<&ExeSignature> {
+        self.inner.exe_signature()
     }
 
-    /// Returns the session ID for the current process or `None` if it couldn't
-    /// be retrieved.
+    /// Returns information read from the headers (ELF, PE or Mach-O) of the executable of this
+    /// process, like its build ID, which allows to match it with its debug symbols.
     ///
-    /// ⚠️ This information is computed every time this method is called.
+    /// Returns `None` if [`Process::exe`] is not set (take a look at
+    /// [`ProcessRefreshKind::with_exe`]) or if the executable couldn't be read or parsed.
+    ///
+    /// **Important**: reading the executable is expensive, so this information is only computed
+    /// the first time this method is called and then kept for as long as this [`Process`] is the
+    /// same process.
+    ///
+    /// ⚠️ It always returns `None` on iOS and with the `apple-sandbox` feature.
     ///
     /// ```no_run
     /// use sysinfo::{Pid, System};
     ///
-    /// let mut s = System::new_all();
+    /// let s = System::new_all();
     ///
     /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     println!("Session ID for process 1337: {:?}", process.session_id());
+    ///     if let Some(build_id) = process.exe_metadata().and_then(|m| m.build_id()) {
+    ///         let build_id: String = build_id.iter().map(|b| format!("{b:02x}")).collect();
+    ///         println!("build ID: {build_id}");
+    ///     }
     /// }
     /// ```
-    pub fn session_id(&self) -> Option<Pid> {
-        self.inner.session_id()
+    pub fn exe_metadata(&self) -> Option<&ExeMetadata> {
+        self.inner.exe_metadata()
     }
 
-    /// Tasks run by this process. If there are none, returns `None`.
+    /// Returns the bundle identifier (`CFBundleIdentifier`) of the application bundle
+    /// containing the executable of this process (for example `com.microsoft.VSCode`).
     ///
-    /// ⚠️ This method always returns `None` on other platforms than Linux.
+    /// This is useful to identify GUI applications whose executable has a generic name like
+    /// `Electron`. Returns `None` if [`Process::exe`] is not set (take a look at
+    /// [`ProcessRefreshKind::with_exe`]) or if the executable isn't part of a `.app` bundle.
+    ///
+    /// ⚠️ It always returns `None` on other platforms than macOS and with the `apple-sandbox`
+    /// feature.
     ///
     /// ```no_run
     /// use sysinfo::{Pid, System};
     ///
-    /// let mut s = System::new_all();
+    /// let s = System::new_all();
     ///
     /// if let Some(process) = s.process(Pid::from(1337)) {
-    ///     if let Some(tasks) = process.tasks() {
-    ///         println!("Listing tasks for process {:?}", process.pid());
-    ///         for task_pid in tasks {
-    ///             if let Some(task) = s.process(*task_pid) {
-    ///                 println!("Task {:?}: {:?}", task.pid(), task.name());
-    ///             }
-    ///         }
-    ///     }
+    ///     println!("{:?}", process.bundle_identifier());
     /// }
     /// ```
-    pub fn tasks(&self) -> Option<&HashSet<Pid>> {
-        cfg_if! {
-            if #[cfg(all(
-                any(target_os = "linux", target_os = "android"),
-                not(feature = "unknown-ci")
-            ))] {
-                self.inner.tasks.as_ref()
-            } else {
-                None
-            }
-        }
+    pub fn bundle_identifier(&self) -> Option<&str> {
+        self.inner.bundle_identifier()
     }
 
-    /// If the process is a thread, it'll return `Some` with the kind of thread it is. Returns
-    /// `None` otherwise.
+    /// Returns the bundle name (`CFBundleName`) of the application bundle containing the
+    /// executable of this process (for example `Code`).
     ///
-    /// ⚠️ This method always returns `None` on other platforms than Linux.
+    /// Returns `None` if [`Process::exe`] is not set (take a look at
+    /// [`ProcessRefreshKind::with_exe`]) or if the executable isn't part of a `.app` bundle.
+    ///
+    /// ⚠️ It always returns `None` on other platforms than macOS and with the `apple-sandbox`
+    /// feature.
     ///
     /// ```no_run
-    /// use sysinfo::System;
+    /// use sysinfo::{Pid, System};
     ///
     /// let s = System::new_all();
     ///
-    /// for (_, process) in s.processes() {
-    ///     if let Some(thread_kind) = process.thread_kind() {
-    ///         println!("Process {:?} is a {thread_kind:?} thread", process.pid());
-    ///     }
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{:?}", process.bundle_name());
     /// }
     /// ```
-    pub fn thread_kind(&self) -> Option<ThreadKind> {
-        cfg_if! {
-            if #[cfg(all(
-                any(target_os = "linux", target_os = "android"),
-                not(feature = "unknown-ci")
-            ))] {
-                self.inner.thread_kind()
-            } else {
-                None
-            }
-        }
+    pub fn bundle_name(&self) -> Option<&str> {
+        self.inner.bundle_name()
     }
 
-    /// Returns `true` if the process doesn't exist anymore but was not yet removed from
-    /// the processes list because the `remove_dead_processes` argument was set to `false`
-    /// in methods like [`System::refresh_processes`].
+    /// Returns the energy information of this process (power drawn, wakeups and QoS class)
+    /// between the last two refreshes, similar to what is displayed in the "Energy" tab of
+    /// Activity Monitor.
+    ///
+    /// This information is refreshed alongside the CPU usage (take a look at
+    /// [`ProcessRefreshKind::with_cpu`]) so, like the CPU usage, it needs the process to be
+    /// refreshed at least twice to be computed.
+    ///
+    /// ⚠️ It always returns `None` on other platforms than macOS and with the `apple-sandbox`
+    /// feature.
     ///
     /// ```no_run
-    /// use sysinfo::{ProcessesToUpdate, System};
+    /// use sysinfo::{Pid, System};
     ///
-    /// let mut s = System::new_all();
-    /// // We set the `remove_dead_processes` to `false`.
-    /// s.refresh_processes(ProcessesToUpdate::All, false);
+    /// let s = System::new_all();
     ///
-    /// for (_, process) in s.processes() {
-    ///     println!(
-    ///         "Process {:?} {}",
-    ///         process.pid(),
-    ///         if process.exists() { "exists" } else { "doesn't exist" },
-    ///     );
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     if let Some(energy) = process.energy_impact() {
+    ///         println!("{}W ({:?})", energy.power, energy.qos_class);
+    ///     }
     /// }
     /// ```
-    pub fn exists(&self) -> bool {
-        self.inner.exists()
+    pub fn energy_impact(&self) -> Option<EnergyImpact> {
+        self.inner.energy_impact()
     }
 
-    /// Returns the number of open files in the current process.
+    /// Returns the GPU usage of this process (in %) between the last two refreshes.
     ///
-    /// Returns `None` if it failed retrieving the information or if the current system is not
-    /// supported.
+    /// On Linux, it is computed from the DRM usage statistics exposed by the GPU driver in
+    /// `/proc/[pid]/fdinfo`. If the process uses multiple GPU engines (render, copy, video...),
+    /// the usage of the busiest one is returned.
     ///
-    /// **Important**: this information is computed every time this function is called.
+    /// It needs [`ProcessRefreshKind::with_gpu_usage`] to be refreshed (it isn't part of
+    /// [`ProcessRefreshKind::everything`]) and, like the CPU usage, the first refresh always
+    /// returns `0`. Returns `None` if the process doesn't use the GPU or if the driver doesn't
+    /// provide this information.
+    ///
+    /// ⚠️ It is only implemented on Linux and always returns `None` on other platforms. On
+    /// Windows (D3DKMT statistics) and macOS (IOKit `PerformanceStatistics`), the per-process
+    /// GPU usage is out of scope for now.
     ///
     /// ```no_run
-    /// use sysinfo::System;
+    /// use sysinfo::{Pid, ProcessRefreshKind, ProcessesToUpdate, System};
     ///
-    /// let s = System::new_all();
+    /// let mut s = System::new();
+    /// s.refresh_processes_specifics(
+    ///     ProcessesToUpdate::All,
+    ///     true,
+    ///     ProcessRefreshKind::nothing().with_gpu_usage(),
+    /// );
+    /// // Wait a bit so the usage can be computed.
+    /// std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+    /// s.refresh_processes_specifics(
+    ///     ProcessesToUpdate::All,
+    ///     true,
+    ///     ProcessRefreshKind::nothing().with_gpu_usage(),
+    /// );
     ///
-    /// for (_, process) in s.processes() {
-    ///     println!(
-    ///         "Process {:?} {:?}",
-    ///         process.pid(),
-    ///         process.open_files(),
-    ///     );
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{:?}", process.gpu_usage());
     /// }
     /// ```
-    pub fn open_files(&self) -> Option<usize> {
-        self.inner.open_files()
+    pub fn gpu_usage(&self) -> Option<f32> {
+        self.inner.gpu_usage()
     }
 
-    /// Returns the maximum number of open files for the current process.
+    /// Returns whether this process currently holds audio capture, camera or screen recording
+    /// resources.
     ///
-    /// Returns `None` if it failed retrieving the information or if the current system is not
-    /// supported.
+    /// It needs [`ProcessRefreshKind::with_media_usage`] to be refreshed (it isn't part of
+    /// [`ProcessRefreshKind::everything`]).
     ///
-    /// **Important**: this information is computed every time this function is called.
+    /// ## Linux
+    ///
+    /// It checks if the process has opened an ALSA capture device (`/dev/snd/pcm*c`) or a
+    /// video device (`/dev/video*`). Applications going through a sound server like PulseAudio
+    /// or PipeWire don't open the capture device themselves, so the recording streams of the
+    /// sound server of the current user are also retrieved with `pactl` (which works with
+    /// PipeWire through its PulseAudio compatibility server). Screen recording cannot be
+    /// detected.
+    ///
+    /// ## macOS
+    ///
+    /// Only the audio capture is detected, using the process objects of `CoreAudio` which are
+    /// available since macOS 14.0 (it returns `None` on older versions).
+    ///
+    /// ## Windows
+    ///
+    /// It uses the microphone, webcam and screen capture usage tracked by Windows for the
+    /// current user (the information displayed in the privacy settings), so processes started
+    /// by other users are not detected.
+    ///
+    /// ⚠️ It always returns `None` on other platforms than Linux, macOS and Windows.
     ///
     /// ```no_run
-    /// use sysinfo::System;
+    /// use sysinfo::{ProcessRefreshKind, ProcessesToUpdate, System};
     ///
-    /// let s = System::new_all();
+    /// let mut s = System::new();
+    /// s.refresh_processes_specifics(
+    ///     ProcessesToUpdate::All,
+    ///     true,
+    ///     ProcessRefreshKind::nothing().with_media_usage(),
+    /// );
     ///
-    /// for (_, process) in s.processes() {
-    ///     println!(
-    ///         "Process {:?} {:?}",
-    ///         process.pid(),
-    ///         process.open_files_limit(),
-    ///     );
+    /// for process in s.processes().values() {
+    ///     if let Some(usage) = process.media_usage()
+    ///         && usage.camera == Some(true)
+    ///     {
+    ///         println!("{:?} is using the camera", process.name());
+    ///     }
     /// }
     /// ```
-    pub fn open_files_limit(&self) -> Option<usize> {
-        self.inner.open_files_limit()
+    pub fn media_usage(&self) -> Option<MediaUsage> {
+        self.inner.media_usage()
     }
 }
 
@@ -2197,6 +5915,19 @@ macro_rules! pid_decl {
                 v.0 as _
             }
         }
+        impl TryFrom<u32> for Pid {
+            type Error = std::num::TryFromIntError;
+            fn try_from(v: u32) -> Result<Self, Self::Error> {
+                Ok(Self(<$typ>::try_from(v)?))
+            }
+        }
+        impl TryFrom<i64> for Pid {
+            type Error = std::num::TryFromIntError;
+            fn try_from(v: i64) -> Result<Self, Self::Error> {
+                // Negative values are never valid process IDs.
+                Self::try_from(u32::try_from(v)?)
+            }
+        }
         impl FromStr for Pid {
             type Err = <$typ as FromStr>::Err;
             fn from_str(s: &str) -> Result<Self, Self::Err> {
@@ -2254,6 +5985,77 @@ cfg_if! {
     }
 }
 
+impl Pid {
+    /// Returns the [`Pid`] of the current process.
+    ///
+    /// It is the same as [`get_current_pid`].
+    ///
+    /// ```no_run
+    /// use sysinfo::Pid;
+    ///
+    /// let pid = Pid::current().expect("failed to get current pid");
+    /// assert_eq!(pid.as_u32(), std::process::id());
+    /// ```
+    pub fn current() -> Result<Self, &'static str> {
+        get_current_pid()
+    }
+
+    /// Returns the [`Pid`] of the parent of the current process.
+    ///
+    /// `Err` is returned in case the platform isn't supported or if it couldn't be retrieved.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::Pid;
+    ///
+    /// if let Ok(parent) = Pid::parent_of_current() {
+    ///     println!("parent pid: {parent}");
+    /// }
+    /// ```
+    #[allow(clippy::unnecessary_wraps)]
+    pub fn parent_of_current() -> Result<Self, &'static str> {
+        cfg_if! {
+            if #[cfg(feature = "unknown-ci")] {
+                Err("Unknown platform (CI)")
+            } else if #[cfg(any(
+                target_os = "freebsd",
+                target_os = "linux",
+                target_os = "android",
+                target_os = "macos",
+                target_os = "ios",
+                target_os = "redox",
+            ))] {
+                unsafe { Ok(Pid(libc::getppid())) }
+            } else if #[cfg(windows)] {
+                use std::ptr::null_mut;
+                use windows::Wdk::System::Threading::{
+                    NtQueryInformationProcess, ProcessBasicInformation,
+                };
+                use windows::Win32::System::Threading::{
+                    GetCurrentProcess, PROCESS_BASIC_INFORMATION,
+                };
+
+                let mut info = PROCESS_BASIC_INFORMATION::default();
+                unsafe {
+                    NtQueryInformationProcess(
+                        GetCurrentProcess(),
+                        ProcessBasicInformation,
+                        (&mut info as *mut PROCESS_BASIC_INFORMATION).cast(),
+                        std::mem::size_of::<PROCESS_BASIC_INFORMATION>() as _,
+                        null_mut(),
+                    )
+                    .ok()
+                    .map_err(|_| "NtQueryInformationProcess failed")?;
+                }
+                Ok(Pid(info.InheritedFromUniqueProcessId as _))
+            } else {
+                Err("Unknown platform")
+            }
+        }
+    }
+}
+
 /// This enum allows you to specify when you want the related information to be updated.
 ///
 /// For example if you only want the [`Process::exe()`] information to be refreshed only if it's not
@@ -2278,16 +6080,50 @@ pub enum UpdateKind {
     Always,
     /// Only update the related information if it was not already set at least once.
     OnlyIfNotSet,
+    /// Only update the related information if it was not already set at least once or if the
+    /// process may have changed it since the last refresh.
+    ///
+    /// It is meant for the information which is only changed by `exec` (like the executable,
+    /// the command line, the environment or the user). `exec` is detected when the name of the
+    /// process changes, so an `exec` of an executable with the same name isn't detected.
+    ///
+    /// ⚠️ `exec` is only detected on Linux and macOS (Windows has no `exec`). On other platforms,
+    /// it behaves like [`UpdateKind::OnlyIfNotSet`].
+    ///
+    /// ```no_run
+    /// use sysinfo::{ProcessesToUpdate, ProcessRefreshKind, System, UpdateKind};
+    ///
+    /// let mut system = System::new();
+    /// system.refresh_processes_specifics(
+    ///     ProcessesToUpdate::All,
+    ///     true,
+    ///     ProcessRefreshKind::nothing()
+    ///         .with_exe(UpdateKind::OnlyIfChanged)
+    ///         .with_cmd(UpdateKind::OnlyIfChanged),
+    /// );
+    /// ```
+    OnlyIfChanged,
 }
 
 impl UpdateKind {
-    /// If `self` is `OnlyIfNotSet`, `f` is called and its returned value is returned.
+    /// If `self` is `OnlyIfNotSet` or `OnlyIfChanged`, `f` is called and its returned value is
+    /// returned.
     #[allow(dead_code)] // Needed for unsupported targets.
     pub(crate) fn needs_update(self, f: impl Fn() -> bool) -> bool {
         match self {
             Self::Never => false,
             Self::Always => true,
-            Self::OnlyIfNotSet => f(),
+            Self::OnlyIfNotSet | Self::OnlyIfChanged => f(),
+        }
+    }
+
+    /// Same as [`UpdateKind::needs_update`] except that `OnlyIfChanged` also returns `true` if
+    /// `changed` is `true`.
+    #[allow(dead_code)] // Needed for unsupported targets.
+    pub(crate) fn needs_update_if_changed(self, changed: bool, f: impl Fn() -> bool) -> bool {
+        match self {
+            Self::OnlyIfChanged if changed => true,
+            _ => self.needs_update(f),
         }
     }
 }
@@ -2364,6 +6200,8 @@ pub enum ProcessesToUpdate<'a> {
 pub struct ProcessRefreshKind {
     cpu: bool,
     disk_usage: bool,
+    gpu_usage: bool,
+    media_usage: bool,
     memory: bool,
     user: UpdateKind,
     cwd: UpdateKind,
@@ -2383,6 +6221,8 @@ impl Default for ProcessRefreshKind {
         Self {
             cpu: false,
             disk_usage: false,
+            gpu_usage: false,
+            media_usage: false,
             memory: false,
             user: UpdateKind::default(),
             cwd: UpdateKind::default(),
@@ -2413,7 +6253,9 @@ impl ProcessRefreshKind {
     }
 
     /// Creates a new `ProcessRefreshKind` with every refresh set to `true` or
-    /// [`UpdateKind::OnlyIfNotSet`].
+    /// [`UpdateKind::OnlyIfNotSet`], except for the GPU and media usages: they require to go
+    /// through the opened files of every process, so they need to be enabled explicitly with
+    /// [`ProcessRefreshKind::with_gpu_usage`] and [`ProcessRefreshKind::with_media_usage`].
     ///
     /// ```
     /// use sysinfo::{ProcessRefreshKind, UpdateKind};
@@ -2422,11 +6264,15 @@ impl ProcessRefreshKind {
     ///
     /// assert_eq!(r.cpu(), true);
     /// assert_eq!(r.user(), UpdateKind::OnlyIfNotSet);
+    /// assert_eq!(r.gpu_usage(), false);
+    /// assert_eq!(r.media_usage(), false);
     /// ```
     pub fn everything() -> Self {
         Self {
             cpu: true,
             disk_usage: true,
+            gpu_usage: false,
+            media_usage: false,
             memory: true,
             user: UpdateKind::OnlyIfNotSet,
             cwd: UpdateKind::OnlyIfNotSet,
@@ -2438,6 +6284,27 @@ impl ProcessRefreshKind {
         }
     }
 
+    /// Creates a new `ProcessRefreshKind` which only retrieves the CPU and memory usage (the PID,
+    /// parent and status are always retrieved). Tasks are not listed either.
+    ///
+    /// It is meant for high-frequency samplers: the process strings (like the command line or
+    /// the environment) are not retrieved, which makes the refreshes cheaper. It doesn't mean that
+    /// refreshing processes doesn't allocate though (listing the processes does for example).
+    ///
+    /// ```
+    /// use sysinfo::{ProcessRefreshKind, UpdateKind};
+    ///
+    /// let r = ProcessRefreshKind::minimal();
+    ///
+    /// assert_eq!(r.cpu(), true);
+    /// assert_eq!(r.memory(), true);
+    /// assert_eq!(r.tasks(), false);
+    /// assert_eq!(r.cmd(), UpdateKind::Never);
+    /// ```
+    pub fn minimal() -> Self {
+        Self::nothing().with_cpu().with_memory().without_tasks()
+    }
+
     impl_get_set!(
         ProcessRefreshKind,
         cpu,
@@ -2452,6 +6319,18 @@ It will retrieve both CPU usage and CPU accumulated time,"
         with_disk_usage,
         without_disk_usage
     );
+    impl_get_set!(
+        ProcessRefreshKind,
+        gpu_usage,
+        with_gpu_usage,
+        without_gpu_usage
+    );
+    impl_get_set!(
+        ProcessRefreshKind,
+        media_usage,
+        with_media_usage,
+        without_media_usage
+    );
     impl_get_set!(
         ProcessRefreshKind,
         user,
@@ -2511,6 +6390,8 @@ It will retrieve the following information:
 pub struct CpuRefreshKind {
     cpu_usage: bool,
     frequency: bool,
+    run_queue: bool,
+    idle_states: bool,
 }
 
 impl CpuRefreshKind {
@@ -2523,12 +6404,16 @@ impl CpuRefreshKind {
     ///
     /// assert_eq!(r.frequency(), false);
     /// assert_eq!(r.cpu_usage(), false);
+    /// assert_eq!(r.run_queue(), false);
+    /// assert_eq!(r.idle_states(), false);
     /// ```
     pub fn nothing() -> Self {
         Self::default()
     }
 
-    /// Creates a new `CpuRefreshKind` with every refresh set to `true`.
+    /// Creates a new `CpuRefreshKind` with every refresh set to `true`, except for the run
+    /// queue: it requires to parse the (large) scheduler debug information, so it needs to be
+    /// enabled explicitly with [`CpuRefreshKind::with_run_queue`].
     ///
     /// ```
     /// use sysinfo::CpuRefreshKind;
@@ -2537,16 +6422,27 @@ impl CpuRefreshKind {
     ///
     /// assert_eq!(r.frequency(), true);
     /// assert_eq!(r.cpu_usage(), true);
+    /// assert_eq!(r.run_queue(), false);
+    /// assert_eq!(r.idle_states(), true);
     /// ```
     pub fn everything() -> Self {
         Self {
             cpu_usage: true,
             frequency: true,
+            run_queue: false,
+            idle_states: true,
         }
     }
 
     impl_get_set!(CpuRefreshKind, cpu_usage, with_cpu_usage, without_cpu_usage);
     impl_get_set!(CpuRefreshKind, frequency, with_frequency, without_frequency);
+    impl_get_set!(CpuRefreshKind, run_queue, with_run_queue, without_run_queue);
+    impl_get_set!(
+        CpuRefreshKind,
+        idle_states,
+        with_idle_states,
+        without_idle_states
+    );
 }
 
 /// Used to determine which memory you want to refresh specifically.
@@ -2684,6 +6580,48 @@ impl RefreshKind {
     impl_get_set!(RefreshKind, cpu, with_cpu, without_cpu, CpuRefreshKind);
 }
 
+/// Part of the [`System`] information which was refreshed. It is used by [`RefreshEvent`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RefreshSubsystem {
+    /// RAM and swap, refreshed by [`System::refresh_memory_specifics`] and the methods calling
+    /// it.
+    Memory,
+    /// CPUs information, refreshed by [`System::refresh_cpu_specifics`] and the methods calling
+    /// it.
+    Cpu,
+    /// CPUs list, refreshed by [`System::refresh_cpu_list`].
+    CpuList,
+    /// Processes, refreshed by [`System::refresh_processes_specifics`] and the methods calling
+    /// it.
+    Processes,
+}
+
+/// Information about a refresh, given to the observers registered with
+/// [`System::add_refresh_observer`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RefreshEvent {
+    /// What was refreshed.
+    pub subsystem: RefreshSubsystem,
+    /// How long the refresh took.
+    pub duration: Duration,
+    /// When the refresh ended.
+    pub refreshed_at: RefreshTimestamp,
+}
+
+/// Durations of the last refreshes of the [`System`] information. It is returned by
+/// [`System::last_refresh_durations`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RefreshDurations {
+    /// Duration of the last memory refresh.
+    pub memory: Option<Duration>,
+    /// Duration of the last CPUs refresh.
+    pub cpu: Option<Duration>,
+    /// Duration of the last CPUs list refresh.
+    pub cpu_list: Option<Duration>,
+    /// Duration of the last processes refresh.
+    pub processes: Option<Duration>,
+}
+
 /// Returns the pid for the current process.
 ///
 /// `Err` is returned in case the platform isn't supported.
@@ -2733,6 +6671,57 @@ pub fn get_current_pid() -> Result<Pid, &'static str> {
     inner()
 }
 
+/// Returns the memory and CPU usage of the current process, or `None` if it couldn't be
+/// retrieved.
+///
+/// Unlike [`System::refresh_self`], it doesn't need a [`System`] and uses the cheapest API
+/// available on each platform (`getrusage` and `/proc/self/statm` on Linux, `task_info` on
+/// macOS, `GetProcessMemoryInfo` on Windows, etc) instead of listing processes.
+///
+/// ⚠️ It always returns `None` on iOS.
+///
+/// **Important**: this information is computed every time this function is called.
+///
+/// ```no_run
+/// let usage = sysinfo::current_process().expect("failed to get current process usage");
+/// println!(
+///     "memory: {} bytes, CPU time: {} ms",
+///     usage.memory,
+///     usage.accumulated_cpu_time,
+/// );
+/// ```
+pub fn current_process() -> Option<CurrentProcessUsage> {
+    ProcessInner::current_usage()
+}
+
+/// Returns the resources used by the children of the current process which exited and were
+/// waited for (with [`Child::wait`] for example), or `None` if it couldn't be retrieved.
+///
+/// It allows build tools or test runners to measure the subprocesses they spawn without
+/// having to refresh them while they run. The children still running (or not waited for yet)
+/// aren't taken into account. Neither are the children of children which weren't waited for by
+/// their parent.
+///
+/// It uses `getrusage` with `RUSAGE_CHILDREN`.
+///
+/// ⚠️ It always returns `None` on Windows, Redox, iOS and with the `apple-sandbox` feature.
+///
+/// **Important**: this information is computed every time this function is called.
+///
+/// ```no_run
+/// let before = sysinfo::self_children_usage().unwrap_or_default();
+/// std::process::Command::new("cargo").arg("build").status().expect("failed to run cargo");
+/// if let Some(after) = sysinfo::self_children_usage() {
+///     println!(
+///         "cargo used {} ms of CPU time",
+///         after.accumulated_cpu_time - before.accumulated_cpu_time,
+///     );
+/// }
+/// ```
+pub fn self_children_usage() -> Option<ChildrenUsage> {
+    ProcessInner::children_usage()
+}
+
 /// Contains all the methods of the [`Cpu`][crate::Cpu] struct.
 ///
 /// ```no_run
@@ -2753,6 +6742,8 @@ pub fn get_current_pid() -> Result<Pid, &'static str> {
 /// ```
 pub struct Cpu {
     pub(crate) inner: CpuInner,
+    pub(crate) peak_usage: f32,
+    pub(crate) measurement_interval: Option<Duration>,
 }
 
 impl Cpu {
@@ -2799,6 +6790,9 @@ impl Cpu {
 
     /// Returns the CPU's vendor id.
     ///
+    /// On Linux, if `/proc/cpuinfo` doesn't provide it (on RISC-V and on a lot of ARM boards), it
+    /// comes from the devicetree `compatible` property of the CPU (like `sifive`).
+    ///
     /// ```no_run
     /// use sysinfo::{System, RefreshKind, CpuRefreshKind};
     ///
@@ -2815,6 +6809,10 @@ impl Cpu {
 
     /// Returns the CPU's brand.
     ///
+    /// On Linux, if `/proc/cpuinfo` doesn't provide it (on RISC-V and on a lot of ARM boards), it
+    /// comes from the devicetree `compatible` property of the CPU (like `u74-mc`) or, as a last
+    /// resort, from the ISA string of the CPU (like `rv64imafdc`).
+    ///
     /// ```no_run
     /// use sysinfo::{System, RefreshKind, CpuRefreshKind};
     ///
@@ -2844,6 +6842,152 @@ impl Cpu {
     pub fn frequency(&self) -> u64 {
         self.inner.frequency()
     }
+
+    /// Returns whether this CPU is allowed to run above its base frequency (Intel Turbo Boost
+    /// or AMD Core Performance Boost), or `None` if it cannot be determined. It is updated
+    /// with the [frequency][Cpu::frequency].
+    ///
+    /// On Linux, some cpufreq drivers (like `amd-pstate`) allow to enable the boost for each
+    /// CPU. On Windows, all CPUs have the same value as [`System::turbo_enabled`].
+    ///
+    /// ⚠️ This method is only implemented for Linux and Windows. It always returns `None`
+    /// otherwise.
+    ///
+    /// ```no_run
+    /// use sysinfo::{System, RefreshKind, CpuRefreshKind};
+    ///
+    /// let s = System::new_with_specifics(
+    ///     RefreshKind::nothing().with_cpu(CpuRefreshKind::nothing().with_frequency()),
+    /// );
+    /// for cpu in s.cpus() {
+    ///     println!("{}: {:?}", cpu.name(), cpu.boost_active());
+    /// }
+    /// ```
+    pub fn boost_active(&self) -> Option<bool> {
+        self.inner.boost_active()
+    }
+
+    /// Returns the number of tasks which are runnable on this CPU, either running or waiting in
+    /// its run queue.
+    ///
+    /// ⚠️ This method is only implemented for Linux, where it requires access to the scheduler
+    /// debug information (`/sys/kernel/debug/sched/debug`, usually only readable by root). It
+    /// always returns `None` otherwise.
+    ///
+    /// It needs [`CpuRefreshKind::with_run_queue`] to be refreshed (it isn't part of
+    /// [`CpuRefreshKind::everything`]).
+    ///
+    /// ```no_run
+    /// use sysinfo::{System, RefreshKind, CpuRefreshKind};
+    ///
+    /// let s = System::new_with_specifics(
+    ///     RefreshKind::nothing().with_cpu(CpuRefreshKind::nothing().with_run_queue()),
+    /// );
+    /// for cpu in s.cpus() {
+    ///     println!("{}: {:?}", cpu.name(), cpu.run_queue_length());
+    /// }
+    /// ```
+    pub fn run_queue_length(&self) -> Option<usize> {
+        self.inner.run_queue_length()
+    }
+
+    /// Returns the total time (in milliseconds) tasks spent waiting in the run queue of this
+    /// CPU before running, since the system booted.
+    ///
+    /// Comparing two values gives how much the tasks were delayed because the CPU was busy.
+    ///
+    /// ⚠️ This method is only implemented for Linux, where it requires a kernel built with
+    /// `CONFIG_SCHEDSTATS`. It always returns `None` otherwise.
+    ///
+    /// It needs [`CpuRefreshKind::with_run_queue`] to be refreshed (it isn't part of
+    /// [`CpuRefreshKind::everything`]).
+    ///
+    /// ```no_run
+    /// use sysinfo::{System, RefreshKind, CpuRefreshKind};
+    ///
+    /// let s = System::new_with_specifics(
+    ///     RefreshKind::nothing().with_cpu(CpuRefreshKind::nothing().with_run_queue()),
+    /// );
+    /// for cpu in s.cpus() {
+    ///     println!("{}: {:?}", cpu.name(), cpu.run_queue_wait_time());
+    /// }
+    /// ```
+    pub fn run_queue_wait_time(&self) -> Option<u64> {
+        self.inner.run_queue_wait_time()
+    }
+
+    /// Returns the idle states (also known as C-states) of this CPU and how much time it spent
+    /// in each of them, from the shallowest to the deepest.
+    ///
+    /// Comparing two values of [`CpuIdleState::residency`] gives how much time the CPU spent in
+    /// each state in between, which tells whether it actually reaches the deep (and power
+    /// efficient) states.
+    ///
+    /// ⚠️ This method is only implemented for Linux, where it comes from cpuidle
+    /// (`/sys/devices/system/cpu/cpu*/cpuidle`). It always returns an empty list otherwise.
+    ///
+    /// ```no_run
+    /// use sysinfo::{System, RefreshKind, CpuRefreshKind};
+    ///
+    /// let s = System::new_with_specifics(
+    ///     RefreshKind::nothing().with_cpu(CpuRefreshKind::nothing().with_idle_states()),
+    /// );
+    /// for cpu in s.cpus() {
+    ///     for state in cpu.idle_states() {
+    ///         println!("{} {}: {}us", cpu.name(), state.name(), state.residency());
+    ///     }
+    /// }
+    /// ```
+    pub fn idle_states(&self) -> &[CpuIdleState] {
+        self.inner.idle_states()
+    }
+
+    /// Returns the highest [usage][Cpu::cpu_usage] of this CPU retrieved by `sysinfo` since
+    /// the CPU was listed or since the last call to [`System::reset_cpu_peak_usage`].
+    ///
+    /// It is updated every time the CPU usage is, including when it's updated while computing
+    /// the processes CPU usage. The first CPU usage sample is ignored as it is inaccurate, so it
+    /// returns `0.` until the CPU usage has been refreshed at least twice.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let mut s = System::new_all();
+    /// for _ in 0..10 {
+    ///     std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+    ///     s.refresh_cpu_usage();
+    /// }
+    /// for cpu in s.cpus() {
+    ///     println!("{}: {}% (peak: {}%)", cpu.name(), cpu.cpu_usage(), cpu.peak_usage());
+    /// }
+    /// ```
+    pub fn peak_usage(&self) -> f32 {
+        self.peak_usage
+    }
+
+    /// Returns the time elapsed between the two samples used to compute the current
+    /// [usage][Cpu::cpu_usage] of this CPU, or `None` if the usage wasn't computed yet.
+    ///
+    /// The CPU usage isn't updated if it is refreshed again before
+    /// [`MINIMUM_CPU_UPDATE_INTERVAL`][crate::MINIMUM_CPU_UPDATE_INTERVAL] elapsed, so this
+    /// interval can be longer than the time between the two last calls to
+    /// [`System::refresh_cpu_usage`].
+    ///
+    /// ⚠️ It always returns `None` on unsupported platforms.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let mut s = System::new_all();
+    /// std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+    /// s.refresh_cpu_usage();
+    /// for cpu in s.cpus() {
+    ///     println!("{}: {}% over {:?}", cpu.name(), cpu.cpu_usage(), cpu.measurement_interval());
+    /// }
+    /// ```
+    pub fn measurement_interval(&self) -> Option<Duration> {
+        self.measurement_interval
+    }
 }
 
 #[cfg(test)]
@@ -2870,6 +7014,36 @@ mod test {
         );
     }
 
+    #[test]
+    fn check_pid_conversions() {
+        assert_eq!(Pid::try_from(12u32), Ok(Pid::from_u32(12)));
+        assert_eq!(Pid::try_from(12i64), Ok(Pid::from_u32(12)));
+        assert!(Pid::try_from(-1i64).is_err());
+        assert!(Pid::try_from(i64::MAX).is_err());
+        assert_eq!(Pid::from_u32(42).to_string(), "42");
+    }
+
+    #[test]
+    fn check_process_handle() {
+        if !IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+            return;
+        }
+        let pid = get_current_pid().expect("failed to get current pid");
+        let mut s = System::new();
+        s.refresh_processes(ProcessesToUpdate::Some(&[pid]), false);
+        let handle = s.process(pid).expect("current process not found").handle();
+        assert_eq!(handle.pid(), pid);
+
+        s.refresh_processes(ProcessesToUpdate::Some(&[pid]), false);
+        assert!(s.process_by_handle(handle).is_some());
+        // Same PID but a different start time, like if the PID had been reused.
+        let reused = ProcessHandle {
+            start_time_raw: handle.start_time_raw.wrapping_add(1),
+            ..handle
+        };
+        assert!(s.process_by_handle(reused).is_none());
+    }
+
     #[test]
     fn check_cpu_arch() {
         assert!(!System::cpu_arch().is_empty());
@@ -0,0 +1,354 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+/// Interacting with USB devices.
+///
+/// ⚠️ The list is always empty on FreeBSD, iOS and with the `apple-sandbox` feature. On Redox,
+/// only the devices connected to an xHCI controller are listed.
+///
+/// ```no_run
+/// use sysinfo::UsbDevices;
+///
+/// let usb_devices = UsbDevices::new_with_refreshed_list();
+/// for device in usb_devices.list() {
+///     println!("{device:?}");
+/// }
+/// ```
+pub struct UsbDevices {
+    devices: Vec<UsbDevice>,
+}
+
+impl Default for UsbDevices {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<UsbDevices> for Vec<UsbDevice> {
+    fn from(devices: UsbDevices) -> Self {
+        devices.devices
+    }
+}
+
+impl From<Vec<UsbDevice>> for UsbDevices {
+    fn from(devices: Vec<UsbDevice>) -> Self {
+        Self { devices }
+    }
+}
+
+impl std::ops::Deref for UsbDevices {
+    type Target = [UsbDevice];
+
+    fn deref(&self) -> &Self::Target {
+        self.list()
+    }
+}
+
+impl std::ops::DerefMut for UsbDevices {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.list_mut()
+    }
+}
+
+impl<'a> IntoIterator for &'a UsbDevices {
+    type Item = &'a UsbDevice;
+    type IntoIter = std::slice::Iter<'a, UsbDevice>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.list().iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a mut UsbDevices {
+    type Item = &'a mut UsbDevice;
+    type IntoIter = std::slice::IterMut<'a, UsbDevice>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.list_mut().iter_mut()
+    }
+}
+
+impl UsbDevices {
+    /// Creates a new empty [`UsbDevices`][crate::UsbDevices] type.
+    ///
+    /// If you want it to be filled directly, take a look at
+    /// [`UsbDevices::new_with_refreshed_list`].
+    ///
+    /// ```no_run
+    /// use sysinfo::UsbDevices;
+    ///
+    /// let mut usb_devices = UsbDevices::new();
+    /// usb_devices.refresh();
+    /// for device in usb_devices.list() {
+    ///     println!("{device:?}");
+    /// }
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            devices: Vec::new(),
+        }
+    }
+
+    /// Creates a new [`UsbDevices`][crate::UsbDevices] type with the USB devices list loaded.
+    ///
+    /// ```no_run
+    /// use sysinfo::UsbDevices;
+    ///
+    /// let usb_devices = UsbDevices::new_with_refreshed_list();
+    /// for device in usb_devices.list() {
+    ///     println!("{device:?}");
+    /// }
+    /// ```
+    pub fn new_with_refreshed_list() -> Self {
+        let mut devices = Self::new();
+        devices.refresh();
+        devices
+    }
+
+    /// Returns the USB devices list.
+    ///
+    /// ```no_run
+    /// use sysinfo::UsbDevices;
+    ///
+    /// let usb_devices = UsbDevices::new_with_refreshed_list();
+    /// for device in usb_devices.list() {
+    ///     println!("{device:?}");
+    /// }
+    /// ```
+    pub fn list(&self) -> &[UsbDevice] {
+        &self.devices
+    }
+
+    /// Returns the USB devices list.
+    ///
+    /// ```no_run
+    /// use sysinfo::UsbDevices;
+    ///
+    /// let mut usb_devices = UsbDevices::new_with_refreshed_list();
+    /// usb_devices
+    ///     .list_mut()
+    ///     .sort_by_key(|device| (device.vendor_id(), device.product_id()));
+    /// ```
+    pub fn list_mut(&mut self) -> &mut [UsbDevice] {
+        &mut self.devices
+    }
+
+    /// The USB devices list will be emptied then completely recomputed.
+    ///
+    /// ```no_run
+    /// use sysinfo::UsbDevices;
+    ///
+    /// let mut usb_devices = UsbDevices::new();
+    /// usb_devices.refresh();
+    /// ```
+    pub fn refresh(&mut self) {
+        crate::sys::get_usb_devices(&mut self.devices);
+    }
+}
+
+/// Type containing USB device information.
+///
+/// It is returned by [`UsbDevices`][crate::UsbDevices].
+///
+/// ```no_run
+/// use sysinfo::UsbDevices;
+///
+/// let usb_devices = UsbDevices::new_with_refreshed_list();
+/// for device in usb_devices.list() {
+///     println!(
+///         "{:04x}:{:04x} {:?}",
+///         device.vendor_id(),
+///         device.product_id(),
+///         device.product(),
+///     );
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UsbDevice {
+    pub(crate) vendor_id: u16,
+    pub(crate) product_id: u16,
+    pub(crate) manufacturer: Option<String>,
+    pub(crate) product: Option<String>,
+    pub(crate) serial_number: Option<String>,
+    pub(crate) bus_number: Option<u8>,
+    pub(crate) port_numbers: Vec<u8>,
+    pub(crate) speed: UsbSpeed,
+}
+
+impl UsbDevice {
+    /// Returns the vendor ID of the device.
+    ///
+    /// ```no_run
+    /// use sysinfo::UsbDevices;
+    ///
+    /// let usb_devices = UsbDevices::new_with_refreshed_list();
+    /// for device in usb_devices.list() {
+    ///     println!("{:04x}", device.vendor_id());
+    /// }
+    /// ```
+    pub fn vendor_id(&self) -> u16 {
+        self.vendor_id
+    }
+
+    /// Returns the product ID of the device.
+    ///
+    /// ```no_run
+    /// use sysinfo::UsbDevices;
+    ///
+    /// let usb_devices = UsbDevices::new_with_refreshed_list();
+    /// for device in usb_devices.list() {
+    ///     println!("{:04x}", device.product_id());
+    /// }
+    /// ```
+    pub fn product_id(&self) -> u16 {
+        self.product_id
+    }
+
+    /// Returns the manufacturer string of the device.
+    ///
+    /// ```no_run
+    /// use sysinfo::UsbDevices;
+    ///
+    /// let usb_devices = UsbDevices::new_with_refreshed_list();
+    /// for device in usb_devices.list() {
+    ///     println!("{:?}", device.manufacturer());
+    /// }
+    /// ```
+    pub fn manufacturer(&self) -> Option<&str> {
+        self.manufacturer.as_deref()
+    }
+
+    /// Returns the product string of the device.
+    ///
+    /// ```no_run
+    /// use sysinfo::UsbDevices;
+    ///
+    /// let usb_devices = UsbDevices::new_with_refreshed_list();
+    /// for device in usb_devices.list() {
+    ///     println!("{:?}", device.product());
+    /// }
+    /// ```
+    pub fn product(&self) -> Option<&str> {
+        self.product.as_deref()
+    }
+
+    /// Returns the serial number of the device.
+    ///
+    /// ```no_run
+    /// use sysinfo::UsbDevices;
+    ///
+    /// let usb_devices = UsbDevices::new_with_refreshed_list();
+    /// for device in usb_devices.list() {
+    ///     println!("{:?}", device.serial_number());
+    /// }
+    /// ```
+    pub fn serial_number(&self) -> Option<&str> {
+        self.serial_number.as_deref()
+    }
+
+    /// Returns the number of the bus the device is connected to.
+    ///
+    /// On Redox, each xHCI controller is a bus, numbered from `1`.
+    ///
+    /// ⚠️ It always returns `None` on Windows.
+    ///
+    /// ```no_run
+    /// use sysinfo::UsbDevices;
+    ///
+    /// let usb_devices = UsbDevices::new_with_refreshed_list();
+    /// for device in usb_devices.list() {
+    ///     println!("{:?}", device.bus_number());
+    /// }
+    /// ```
+    pub fn bus_number(&self) -> Option<u8> {
+        self.bus_number
+    }
+
+    /// Returns the list of ports from the root hub to the device. It is empty for root hubs.
+    ///
+    /// ```no_run
+    /// use sysinfo::UsbDevices;
+    ///
+    /// let usb_devices = UsbDevices::new_with_refreshed_list();
+    /// for device in usb_devices.list() {
+    ///     println!("{:?}", device.port_numbers());
+    /// }
+    /// ```
+    pub fn port_numbers(&self) -> &[u8] {
+        &self.port_numbers
+    }
+
+    /// Returns the speed the device is operating at.
+    ///
+    /// ⚠️ It always returns [`UsbSpeed::Unknown`] on Windows and Redox.
+    ///
+    /// ```no_run
+    /// use sysinfo::UsbDevices;
+    ///
+    /// let usb_devices = UsbDevices::new_with_refreshed_list();
+    /// for device in usb_devices.list() {
+    ///     println!("{:?}", device.speed());
+    /// }
+    /// ```
+    pub fn speed(&self) -> UsbSpeed {
+        self.speed
+    }
+}
+
+/// Enum describing the speed of a USB device. It is returned by [`UsbDevice::speed`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub enum UsbSpeed {
+    /// Low speed (1.5 Mbit/s).
+    Low,
+    /// Full speed (12 Mbit/s).
+    Full,
+    /// High speed (480 Mbit/s).
+    High,
+    /// SuperSpeed (5 Gbit/s).
+    Super,
+    /// SuperSpeed+ (10 Gbit/s or more).
+    SuperPlus,
+    /// Unknown speed.
+    Unknown,
+}
+
+impl UsbSpeed {
+    /// Converts a speed in Mbit/s into a `UsbSpeed`.
+    #[allow(dead_code)] // Not used on all platforms.
+    pub(crate) fn from_mbps(mbps: f32) -> Self {
+        match mbps {
+            x if x <= 0. => Self::Unknown,
+            x if x < 12. => Self::Low,
+            x if x < 480. => Self::Full,
+            x if x < 5_000. => Self::High,
+            x if x < 10_000. => Self::Super,
+            _ => Self::SuperPlus,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn check_list() {
+        let mut devices = UsbDevices::new();
+        assert!(devices.list().is_empty());
+        devices.refresh();
+        for device in devices.list() {
+            // There cannot be more than 7 tiers of hubs.
+            assert!(device.port_numbers().len() <= 7);
+        }
+    }
+
+    #[test]
+    fn check_usb_speed_from_mbps() {
+        assert_eq!(UsbSpeed::from_mbps(0.), UsbSpeed::Unknown);
+        assert_eq!(UsbSpeed::from_mbps(1.5), UsbSpeed::Low);
+        assert_eq!(UsbSpeed::from_mbps(12.), UsbSpeed::Full);
+        assert_eq!(UsbSpeed::from_mbps(480.), UsbSpeed::High);
+        assert_eq!(UsbSpeed::from_mbps(5_000.), UsbSpeed::Super);
+        assert_eq!(UsbSpeed::from_mbps(20_000.), UsbSpeed::SuperPlus);
+    }
+}
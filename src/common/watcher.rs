@@ -0,0 +1,98 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+//! Background thread shared by the watchers ([`DiskWatcher`][crate::DiskWatcher] and
+//! [`NetworkWatcher`][crate::NetworkWatcher]).
+
+use std::io;
+use std::mem;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Condvar, Mutex};
+use std::time::{Duration, Instant};
+
+/// How often the watching thread checks if it needs to stop.
+const STOP_CHECK_INTERVAL: Duration = Duration::from_millis(200);
+
+struct Shared<T> {
+    pending: Mutex<Vec<T>>,
+    condvar: Condvar,
+    stop: AtomicBool,
+}
+
+/// Receives the events of a watching thread, which is stopped when it is dropped.
+pub(crate) struct EventReceiver<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T: Send + 'static> EventReceiver<T> {
+    /// Spawns a thread named `name` which calls `poll` in a loop. `poll` is expected to wait up
+    /// to the given duration for something to happen and to return the new events.
+    pub(crate) fn spawn<F>(name: &str, mut poll: F) -> io::Result<Self>
+    where
+        F: FnMut(Duration) -> Vec<T> + Send + 'static,
+    {
+        let shared = Arc::new(Shared {
+            pending: Mutex::new(Vec::new()),
+            condvar: Condvar::new(),
+            stop: AtomicBool::new(false),
+        });
+        let thread_shared = Arc::clone(&shared);
+        std::thread::Builder::new()
+            .name(name.to_owned())
+            .spawn(move || {
+                while !thread_shared.stop.load(Ordering::Relaxed) {
+                    let events = poll(STOP_CHECK_INTERVAL);
+                    if events.is_empty() {
+                        continue;
+                    }
+                    let Ok(mut pending) = thread_shared.pending.lock() else {
+                        return;
+                    };
+                    pending.extend(events);
+                    thread_shared.condvar.notify_all();
+                }
+            })?;
+        Ok(Self { shared })
+    }
+}
+
+impl<T> EventReceiver<T> {
+    /// Returns the pending events without blocking.
+    pub(crate) fn take(&self) -> Vec<T> {
+        let mut pending = self
+            .shared
+            .pending
+            .lock()
+            .unwrap_or_else(|error| error.into_inner());
+        mem::take(&mut *pending)
+    }
+
+    /// Returns the pending events, waiting up to `timeout` for at least one to happen.
+    pub(crate) fn wait(&self, timeout: Duration) -> Vec<T> {
+        let deadline = Instant::now().checked_add(timeout);
+        let mut pending = self
+            .shared
+            .pending
+            .lock()
+            .unwrap_or_else(|error| error.into_inner());
+        while pending.is_empty() {
+            let remaining = match deadline {
+                Some(deadline) => deadline.saturating_duration_since(Instant::now()),
+                None => Duration::MAX,
+            };
+            if remaining.is_zero() {
+                break;
+            }
+            pending = match self.shared.condvar.wait_timeout(pending, remaining) {
+                Ok((pending, _)) => pending,
+                Err(error) => error.into_inner().0,
+            };
+        }
+        mem::take(&mut *pending)
+    }
+}
+
+impl<T> Drop for EventReceiver<T> {
+    fn drop(&mut self) {
+        self.shared.stop.store(true, Ordering::Relaxed);
+    }
+}
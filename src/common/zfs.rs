@@ -0,0 +1,344 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+/// Interacting with ZFS pools and with the ZFS ARC (Adaptive Replacement Cache).
+///
+/// The ARC is counted as used memory by the kernel, so its size is needed to interpret
+/// [`System::used_memory`][crate::System::used_memory] on systems using ZFS.
+///
+/// ⚠️ The pools list is retrieved by running `zpool list`, so it is empty if the `zpool` command
+/// isn't available. On systems other than Linux and FreeBSD, the pools list is always empty and
+/// [`Zfs::arc`] always returns `None`.
+///
+/// ```no_run
+/// use sysinfo::Zfs;
+///
+/// let zfs = Zfs::new_with_refreshed_list();
+/// for pool in zfs.pools() {
+///     println!("{pool:?}");
+/// }
+/// if let Some(arc) = zfs.arc() {
+///     println!("ARC: {} bytes (hit rate: {:.2})", arc.size(), arc.hit_rate());
+/// }
+/// ```
+pub struct Zfs {
+    pools: Vec<ZfsPool>,
+    arc: Option<ZfsArc>,
+}
+
+impl Default for Zfs {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Zfs {
+    /// Creates a new empty [`Zfs`][crate::Zfs] type.
+    ///
+    /// If you want it to be filled directly, take a look at [`Zfs::new_with_refreshed_list`].
+    ///
+    /// ```no_run
+    /// use sysinfo::Zfs;
+    ///
+    /// let mut zfs = Zfs::new();
+    /// zfs.refresh();
+    /// for pool in zfs.pools() {
+    ///     println!("{pool:?}");
+    /// }
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            pools: Vec::new(),
+            arc: None,
+        }
+    }
+
+    /// Creates a new [`Zfs`][crate::Zfs] type with the pools list and the ARC statistics loaded.
+    ///
+    /// ```no_run
+    /// use sysinfo::Zfs;
+    ///
+    /// let zfs = Zfs::new_with_refreshed_list();
+    /// for pool in zfs.pools() {
+    ///     println!("{pool:?}");
+    /// }
+    /// ```
+    pub fn new_with_refreshed_list() -> Self {
+        let mut zfs = Self::new();
+        zfs.refresh();
+        zfs
+    }
+
+    /// Returns the ZFS pools list.
+    ///
+    /// ```no_run
+    /// use sysinfo::Zfs;
+    ///
+    /// let zfs = Zfs::new_with_refreshed_list();
+    /// for pool in zfs.pools() {
+    ///     println!("{}: {:?}", pool.name(), pool.health());
+    /// }
+    /// ```
+    pub fn pools(&self) -> &[ZfsPool] {
+        &self.pools
+    }
+
+    /// Returns the ARC statistics, or `None` if ZFS isn't loaded.
+    ///
+    /// ```no_run
+    /// use sysinfo::Zfs;
+    ///
+    /// let zfs = Zfs::new_with_refreshed_list();
+    /// if let Some(arc) = zfs.arc() {
+    ///     println!("ARC: {} bytes", arc.size());
+    /// }
+    /// ```
+    pub fn arc(&self) -> Option<&ZfsArc> {
+        self.arc.as_ref()
+    }
+
+    /// The pools list will be emptied then completely recomputed, and the ARC statistics are
+    /// updated.
+    ///
+    /// ```no_run
+    /// use sysinfo::Zfs;
+    ///
+    /// let mut zfs = Zfs::new();
+    /// zfs.refresh();
+    /// ```
+    pub fn refresh(&mut self) {
+        crate::sys::get_zfs_pools(&mut self.pools);
+        self.arc = crate::sys::get_zfs_arc();
+    }
+}
+
+/// Health of a [`ZfsPool`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub enum ZfsPoolHealth {
+    /// The pool is working normally.
+    Online,
+    /// A device failed but the pool is still working thanks to its redundancy.
+    Degraded,
+    /// The pool cannot be accessed anymore.
+    Faulted,
+    /// The pool was taken offline by an administrator.
+    Offline,
+    /// The devices of the pool cannot be opened.
+    Unavailable,
+    /// A device of the pool was physically removed.
+    Removed,
+    /// The pool is waiting for its devices to come back after an I/O failure.
+    Suspended,
+    /// Unknown health.
+    Unknown,
+}
+
+impl ZfsPoolHealth {
+    #[cfg(all(
+        any(target_os = "linux", target_os = "android", target_os = "freebsd"),
+        not(feature = "unknown-ci"),
+        not(sysinfo_test_backend)
+    ))]
+    pub(crate) fn from_zpool(health: &str) -> Self {
+        match health {
+            "ONLINE" => Self::Online,
+            "DEGRADED" => Self::Degraded,
+            "FAULTED" => Self::Faulted,
+            "OFFLINE" => Self::Offline,
+            "UNAVAIL" => Self::Unavailable,
+            "REMOVED" => Self::Removed,
+            "SUSPENDED" => Self::Suspended,
+            _ => Self::Unknown,
+        }
+    }
+}
+
+/// A ZFS pool. It is returned by [`Zfs::pools`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ZfsPool {
+    pub(crate) name: String,
+    pub(crate) health: ZfsPoolHealth,
+    pub(crate) size: u64,
+    pub(crate) allocated: u64,
+    pub(crate) free: u64,
+}
+
+impl ZfsPool {
+    /// Returns the name of the pool.
+    ///
+    /// ```no_run
+    /// use sysinfo::Zfs;
+    ///
+    /// for pool in Zfs::new_with_refreshed_list().pools() {
+    ///     println!("{}", pool.name());
+    /// }
+    /// ```
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the health of the pool.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Zfs, ZfsPoolHealth};
+    ///
+    /// for pool in Zfs::new_with_refreshed_list().pools() {
+    ///     if pool.health() != ZfsPoolHealth::Online {
+    ///         println!("{}: {:?}", pool.name(), pool.health());
+    ///     }
+    /// }
+    /// ```
+    pub fn health(&self) -> ZfsPoolHealth {
+        self.health
+    }
+
+    /// Returns the size of the pool in bytes.
+    ///
+    /// ```no_run
+    /// use sysinfo::Zfs;
+    ///
+    /// for pool in Zfs::new_with_refreshed_list().pools() {
+    ///     println!("{}: {} bytes", pool.name(), pool.size());
+    /// }
+    /// ```
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    /// Returns the space allocated in the pool in bytes.
+    ///
+    /// ```no_run
+    /// use sysinfo::Zfs;
+    ///
+    /// for pool in Zfs::new_with_refreshed_list().pools() {
+    ///     println!("{}: {} bytes", pool.name(), pool.allocated());
+    /// }
+    /// ```
+    pub fn allocated(&self) -> u64 {
+        self.allocated
+    }
+
+    /// Returns the space which isn't allocated in the pool in bytes.
+    ///
+    /// ```no_run
+    /// use sysinfo::Zfs;
+    ///
+    /// for pool in Zfs::new_with_refreshed_list().pools() {
+    ///     println!("{}: {} bytes", pool.name(), pool.free());
+    /// }
+    /// ```
+    pub fn free(&self) -> u64 {
+        self.free
+    }
+}
+
+/// Statistics of the ZFS ARC (Adaptive Replacement Cache). It is returned by [`Zfs::arc`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ZfsArc {
+    pub(crate) size: u64,
+    pub(crate) target_size: u64,
+    pub(crate) min_size: u64,
+    pub(crate) max_size: u64,
+    pub(crate) hits: u64,
+    pub(crate) misses: u64,
+}
+
+impl ZfsArc {
+    /// Returns the current size of the ARC in bytes. This memory is reported as used by
+    /// [`System::used_memory`][crate::System::used_memory] but it is given back when
+    /// applications need it.
+    ///
+    /// ```no_run
+    /// use sysinfo::Zfs;
+    ///
+    /// if let Some(arc) = Zfs::new_with_refreshed_list().arc() {
+    ///     println!("{} bytes", arc.size());
+    /// }
+    /// ```
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    /// Returns the size in bytes the ARC is currently trying to reach.
+    ///
+    /// ```no_run
+    /// use sysinfo::Zfs;
+    ///
+    /// if let Some(arc) = Zfs::new_with_refreshed_list().arc() {
+    ///     println!("{} bytes", arc.target_size());
+    /// }
+    /// ```
+    pub fn target_size(&self) -> u64 {
+        self.target_size
+    }
+
+    /// Returns the minimum size of the ARC in bytes.
+    ///
+    /// ```no_run
+    /// use sysinfo::Zfs;
+    ///
+    /// if let Some(arc) = Zfs::new_with_refreshed_list().arc() {
+    ///     println!("{} bytes", arc.min_size());
+    /// }
+    /// ```
+    pub fn min_size(&self) -> u64 {
+        self.min_size
+    }
+
+    /// Returns the maximum size of the ARC in bytes.
+    ///
+    /// ```no_run
+    /// use sysinfo::Zfs;
+    ///
+    /// if let Some(arc) = Zfs::new_with_refreshed_list().arc() {
+    ///     println!("{} bytes", arc.max_size());
+    /// }
+    /// ```
+    pub fn max_size(&self) -> u64 {
+        self.max_size
+    }
+
+    /// Returns the number of requests served by the ARC since ZFS was loaded.
+    ///
+    /// ```no_run
+    /// use sysinfo::Zfs;
+    ///
+    /// if let Some(arc) = Zfs::new_with_refreshed_list().arc() {
+    ///     println!("{} hits", arc.hits());
+    /// }
+    /// ```
+    pub fn hits(&self) -> u64 {
+        self.hits
+    }
+
+    /// Returns the number of requests which couldn't be served by the ARC since ZFS was loaded.
+    ///
+    /// ```no_run
+    /// use sysinfo::Zfs;
+    ///
+    /// if let Some(arc) = Zfs::new_with_refreshed_list().arc() {
+    ///     println!("{} misses", arc.misses());
+    /// }
+    /// ```
+    pub fn misses(&self) -> u64 {
+        self.misses
+    }
+
+    /// Returns the proportion (between `0.` and `1.`) of requests served by the ARC since ZFS
+    /// was loaded, or `0.` if there was no request.
+    ///
+    /// ```no_run
+    /// use sysinfo::Zfs;
+    ///
+    /// if let Some(arc) = Zfs::new_with_refreshed_list().arc() {
+    ///     println!("hit rate: {:.2}", arc.hit_rate());
+    /// }
+    /// ```
+    pub fn hit_rate(&self) -> f32 {
+        let total = self.hits.saturating_add(self.misses);
+        if total == 0 {
+            return 0.;
+        }
+        self.hits as f32 / total as f32
+    }
+}
@@ -9,6 +9,9 @@ impl std::fmt::Debug for crate::Cpu {
             .field("frequency", &self.frequency())
             .field("vendor ID", &self.vendor_id())
             .field("brand", &self.brand())
+            .field("run queue length", &self.run_queue_length())
+            .field("run queue wait time", &self.run_queue_wait_time())
+            .field("peak usage", &self.peak_usage())
             .finish()
     }
 }
@@ -57,6 +60,17 @@ impl std::fmt::Debug for crate::Product {
     }
 }
 
+#[cfg(feature = "system")]
+impl std::fmt::Debug for crate::SecurityInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SecurityInfo")
+            .field("secure_boot_enabled", &Self::secure_boot_enabled())
+            .field("tpm_present", &Self::tpm_present())
+            .field("root_disk_encrypted", &Self::root_disk_encrypted())
+            .finish()
+    }
+}
+
 #[cfg(feature = "system")]
 impl std::fmt::Debug for crate::Process {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -72,6 +86,7 @@ impl std::fmt::Debug for crate::Process {
             .field("virtual memory usage", &self.virtual_memory())
             .field("CPU usage", &self.cpu_usage())
             .field("accumulated CPU time", &self.accumulated_cpu_time())
+            .field("children CPU time", &self.children_cpu_time())
             .field("status", &self.status())
             .field("root", &self.root())
             .field("disk_usage", &self.disk_usage())
@@ -158,11 +173,58 @@ impl std::fmt::Debug for crate::NetworkData {
             .field("total errors income", &self.total_errors_on_received())
             .field("errors outcome", &self.errors_on_transmitted())
             .field("total errors outcome", &self.total_errors_on_transmitted())
+            .field("drops income", &self.drops_on_received())
+            .field("total drops income", &self.total_drops_on_received())
+            .field("drops outcome", &self.drops_on_transmitted())
+            .field("total drops outcome", &self.total_drops_on_transmitted())
+            .field("fifo errors income", &self.fifo_errors_on_received())
+            .field(
+                "total fifo errors income",
+                &self.total_fifo_errors_on_received(),
+            )
+            .field("fifo errors outcome", &self.fifo_errors_on_transmitted())
+            .field(
+                "total fifo errors outcome",
+                &self.total_fifo_errors_on_transmitted(),
+            )
+            .field("collisions", &self.collisions())
+            .field("total collisions", &self.total_collisions())
+            .field("carrier errors", &self.carrier_errors())
+            .field("total carrier errors", &self.total_carrier_errors())
+            .field(
+                "multicast packets income",
+                &self.multicast_packets_received(),
+            )
+            .field(
+                "total multicast packets income",
+                &self.total_multicast_packets_received(),
+            )
             .field("maximum transfer unit", &self.mtu())
             .finish()
     }
 }
 
+#[cfg(feature = "audio")]
+impl std::fmt::Debug for crate::AudioDevices {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(feature = "display")]
+impl std::fmt::Debug for crate::Displays {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(feature = "usb")]
+impl std::fmt::Debug for crate::UsbDevices {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
 #[cfg(feature = "user")]
 impl std::fmt::Debug for crate::Users {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -0,0 +1,104 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, RwLock};
+
+type Handler = Arc<dyn Fn(fmt::Arguments<'_>) + Send + Sync>;
+
+static HANDLER: RwLock<Option<Handler>> = RwLock::new(None);
+// Allows to skip the lock when no handler is set, which is the most common case.
+static HAS_HANDLER: AtomicBool = AtomicBool::new(false);
+
+/// Sets the function called with a message every time `sysinfo` fails to read or parse some
+/// information and falls back to a default value.
+///
+/// These failures are otherwise silently ignored (or printed on the standard error output if
+/// the `debug` feature is enabled). It replaces the previously set handler, if any.
+///
+/// The handler can be called from any thread, including the `rayon` ones used to refresh
+/// processes. It must not call [`set_diagnostics_handler`] or [`remove_diagnostics_handler`].
+///
+/// ```no_run
+/// // The messages can be forwarded to `log`, `tracing` or any other logging system.
+/// sysinfo::set_diagnostics_handler(|message| eprintln!("[sysinfo] {message}"));
+/// ```
+pub fn set_diagnostics_handler<F>(handler: F)
+where
+    F: Fn(fmt::Arguments<'_>) + Send + Sync + 'static,
+{
+    let mut current = HANDLER.write().unwrap_or_else(|error| error.into_inner());
+    *current = Some(Arc::new(handler));
+    HAS_HANDLER.store(true, Ordering::Release);
+}
+
+/// Removes the handler set with [`set_diagnostics_handler`].
+///
+/// ```no_run
+/// sysinfo::set_diagnostics_handler(|message| eprintln!("[sysinfo] {message}"));
+/// // We don't want the messages anymore.
+/// sysinfo::remove_diagnostics_handler();
+/// ```
+pub fn remove_diagnostics_handler() {
+    let mut current = HANDLER.write().unwrap_or_else(|error| error.into_inner());
+    HAS_HANDLER.store(false, Ordering::Release);
+    *current = None;
+}
+
+/// Called by the `sysinfo_debug!` macro before evaluating its arguments.
+// It is unused if no feature using `sysinfo_debug!` is enabled.
+#[allow(dead_code)]
+#[inline]
+pub(crate) fn is_enabled() -> bool {
+    cfg!(feature = "debug") || HAS_HANDLER.load(Ordering::Acquire)
+}
+
+/// Called by the `sysinfo_debug!` macro.
+// It is unused if no feature using `sysinfo_debug!` is enabled.
+#[allow(dead_code)]
+pub(crate) fn emit(args: fmt::Arguments<'_>) {
+    #[cfg(feature = "debug")]
+    eprintln!("{args}");
+
+    if !HAS_HANDLER.load(Ordering::Acquire) {
+        return;
+    }
+    // We don't keep the lock while calling the handler in case it's slow.
+    let handler = HANDLER
+        .read()
+        .unwrap_or_else(|error| error.into_inner())
+        .clone();
+    if let Some(handler) = handler {
+        handler(args);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    #[test]
+    fn check_diagnostics_handler() {
+        let messages = Arc::new(Mutex::new(Vec::new()));
+        let handler_messages = Arc::clone(&messages);
+        super::set_diagnostics_handler(move |message| {
+            handler_messages.lock().unwrap().push(message.to_string());
+        });
+        sysinfo_debug!("failed to read {}", "/proc/check_diagnostics_handler");
+        super::remove_diagnostics_handler();
+        sysinfo_debug!("failed to read {}", "/proc/after_remove");
+
+        // Other tests might emit messages at the same time so we only look for ours.
+        let messages = messages.lock().unwrap();
+        assert!(
+            messages
+                .iter()
+                .any(|message| message == "failed to read /proc/check_diagnostics_handler")
+        );
+        assert!(
+            !messages
+                .iter()
+                .any(|message| message.contains("after_remove"))
+        );
+    }
+}
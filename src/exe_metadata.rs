@@ -0,0 +1,442 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+//! Minimal parsers of the headers of executable files (ELF, PE and Mach-O) used to retrieve the
+//! information matching an executable with its debug symbols, without pulling an extra
+//! dependency.
+
+use std::fs::File;
+use std::io::{BufReader, Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+
+use crate::ExeMetadata;
+
+/// Maximum number of bytes read at once, so a malformed header cannot make us allocate a huge
+/// buffer.
+const MAX_READ_SIZE: u64 = 1024 * 1024;
+
+const PT_INTERP: u32 = 3;
+const PT_NOTE: u32 = 4;
+const SHT_SYMTAB: u32 = 2;
+const NT_GNU_BUILD_ID: u32 = 3;
+
+const IMAGE_DIRECTORY_ENTRY_DEBUG: u32 = 6;
+const IMAGE_DEBUG_TYPE_CODEVIEW: u32 = 2;
+
+const FAT_MAGIC: u32 = 0xcafe_babe;
+const LC_LOAD_DYLINKER: u32 = 0xe;
+const LC_UUID: u32 = 0x1b;
+const CPU_TYPE_X86_64: u32 = 0x0100_0007;
+const CPU_TYPE_ARM64: u32 = 0x0100_000c;
+
+/// Reads the headers of the executable at `path`.
+#[allow(dead_code)] // Not used on unsupported targets.
+pub(crate) fn read_exe_metadata(path: &Path) -> Option<ExeMetadata> {
+    let file = match File::open(path) {
+        Ok(f) => f,
+        Err(_e) => {
+            sysinfo_debug!("failed to open {path:?} to read its headers: {_e:?}");
+            return None;
+        }
+    };
+    parse_exe_metadata(&mut BufReader::new(file))
+}
+
+fn parse_exe_metadata<R: Read + Seek>(reader: &mut R) -> Option<ExeMetadata> {
+    match read_at(reader, 0, 4)?[..] {
+        [0x7f, b'E', b'L', b'F'] => parse_elf(reader),
+        [b'M', b'Z', ..] => parse_pe(reader),
+        _ => parse_mach_o(reader, 0),
+    }
+}
+
+fn read_at<R: Read + Seek>(reader: &mut R, offset: u64, len: u64) -> Option<Vec<u8>> {
+    if len > MAX_READ_SIZE {
+        return None;
+    }
+    reader.seek(SeekFrom::Start(offset)).ok()?;
+    let mut buf = vec![0; len as usize];
+    reader.read_exact(&mut buf).ok()?;
+    Some(buf)
+}
+
+fn path_from_c_str(data: &[u8]) -> PathBuf {
+    let len = data.iter().position(|&c| c == 0).unwrap_or(data.len());
+    PathBuf::from(String::from_utf8_lossy(&data[..len]).into_owned())
+}
+
+#[derive(Clone, Copy)]
+struct Endian {
+    big: bool,
+}
+
+impl Endian {
+    const LITTLE: Self = Self { big: false };
+    const BIG: Self = Self { big: true };
+
+    fn u16(self, data: &[u8], offset: usize) -> Option<u16> {
+        let bytes = data.get(offset..offset.checked_add(2)?)?.try_into().ok()?;
+        Some(if self.big {
+            u16::from_be_bytes(bytes)
+        } else {
+            u16::from_le_bytes(bytes)
+        })
+    }
+
+    fn u32(self, data: &[u8], offset: usize) -> Option<u32> {
+        let bytes = data.get(offset..offset.checked_add(4)?)?.try_into().ok()?;
+        Some(if self.big {
+            u32::from_be_bytes(bytes)
+        } else {
+            u32::from_le_bytes(bytes)
+        })
+    }
+
+    fn u64(self, data: &[u8], offset: usize) -> Option<u64> {
+        let bytes = data.get(offset..offset.checked_add(8)?)?.try_into().ok()?;
+        Some(if self.big {
+            u64::from_be_bytes(bytes)
+        } else {
+            u64::from_le_bytes(bytes)
+        })
+    }
+
+    /// Reads an ELF address or offset, whose size depends on the ELF class.
+    fn word(self, is_64_bit: bool, data: &[u8], offset: usize) -> Option<u64> {
+        if is_64_bit {
+            self.u64(data, offset)
+        } else {
+            self.u32(data, offset).map(u64::from)
+        }
+    }
+}
+
+fn parse_elf<R: Read + Seek>(reader: &mut R) -> Option<ExeMetadata> {
+    let header = read_at(reader, 0, 64)?;
+    let is_64_bit = match header[4] {
+        1 => false,
+        2 => true,
+        _ => return None,
+    };
+    let e = match header[5] {
+        1 => Endian::LITTLE,
+        2 => Endian::BIG,
+        _ => return None,
+    };
+    // Offsets of `e_phoff`, `e_shoff` and `e_phentsize` (followed by the other sizes).
+    let (phoff, shoff, sizes) = if is_64_bit {
+        (0x20, 0x28, 0x36)
+    } else {
+        (0x1c, 0x20, 0x2a)
+    };
+    let phoff = e.word(is_64_bit, &header, phoff)?;
+    let shoff = e.word(is_64_bit, &header, shoff)?;
+    let phentsize = e.u16(&header, sizes)?;
+    let phnum = e.u16(&header, sizes + 2)?;
+    let shentsize = e.u16(&header, sizes + 4)?;
+    let shnum = e.u16(&header, sizes + 6)?;
+
+    let mut metadata = ExeMetadata::default();
+
+    // Offsets of `p_offset` and `p_filesz`.
+    let (p_offset, p_filesz) = if is_64_bit { (0x8, 0x20) } else { (0x4, 0x10) };
+    if usize::from(phentsize) > p_filesz {
+        let program_headers = read_at(reader, phoff, u64::from(phentsize) * u64::from(phnum))?;
+        for program_header in program_headers.chunks_exact(phentsize.into()) {
+            let offset = e.word(is_64_bit, program_header, p_offset)?;
+            let size = e.word(is_64_bit, program_header, p_filesz)?;
+            match e.u32(program_header, 0)? {
+                PT_INTERP => {
+                    metadata.interpreter = read_at(reader, offset, size)
+                        .map(|interpreter| path_from_c_str(&interpreter));
+                }
+                PT_NOTE if metadata.build_id.is_none() => {
+                    metadata.build_id = read_at(reader, offset, size)
+                        .and_then(|notes| find_gnu_build_id(&notes, e));
+                }
+                _ => {}
+            }
+        }
+    }
+
+    // Tools like `strip` remove the `.symtab` section but keep the section headers.
+    metadata.stripped = if shoff == 0 {
+        Some(true)
+    } else if shentsize >= 8 {
+        read_at(reader, shoff, u64::from(shentsize) * u64::from(shnum)).map(|sections| {
+            !sections
+                .chunks_exact(shentsize.into())
+                .any(|section| e.u32(section, 4) == Some(SHT_SYMTAB))
+        })
+    } else {
+        None
+    };
+    Some(metadata)
+}
+
+fn find_gnu_build_id(mut notes: &[u8], e: Endian) -> Option<Vec<u8>> {
+    let align = |size: u32| (size as usize).checked_add(3).map(|size| size & !3);
+
+    while notes.len() >= 12 {
+        let name_size = e.u32(notes, 0)?;
+        let desc_size = e.u32(notes, 4)?;
+        let desc_start = align(name_size)?.checked_add(12)?;
+        if e.u32(notes, 8)? == NT_GNU_BUILD_ID
+            && notes.get(12..12 + name_size as usize) == Some(b"GNU\0")
+        {
+            let desc_end = desc_start.checked_add(desc_size as usize)?;
+            return notes.get(desc_start..desc_end).map(<[u8]>::to_vec);
+        }
+        notes = notes.get(desc_start.checked_add(align(desc_size)?)?..)?;
+    }
+    None
+}
+
+fn parse_pe<R: Read + Seek>(reader: &mut R) -> Option<ExeMetadata> {
+    let e = Endian::LITTLE;
+    let dos_header = read_at(reader, 0, 0x40)?;
+    let pe_offset = u64::from(e.u32(&dos_header, 0x3c)?);
+    // The signature is followed by the COFF header.
+    let header = read_at(reader, pe_offset, 24)?;
+    if header[..4] != *b"PE\0\0" {
+        return None;
+    }
+    let section_count = e.u16(&header, 6)?;
+    let timestamp = e.u32(&header, 8)?;
+    let optional_header_size = e.u16(&header, 20)?;
+
+    let mut metadata = ExeMetadata {
+        link_time: (timestamp != 0).then_some(timestamp.into()),
+        ..ExeMetadata::default()
+    };
+
+    let optional_header_offset = pe_offset + 24;
+    let optional_header = read_at(reader, optional_header_offset, optional_header_size.into())?;
+    let data_directories = match e.u16(&optional_header, 0)? {
+        // PE32
+        0x10b => 96,
+        // PE32+
+        0x20b => 112,
+        _ => return Some(metadata),
+    };
+    if e.u32(&optional_header, data_directories - 4)? <= IMAGE_DIRECTORY_ENTRY_DEBUG {
+        return Some(metadata);
+    }
+    let debug_directory = data_directories + 8 * IMAGE_DIRECTORY_ENTRY_DEBUG as usize;
+    let debug_rva = e.u32(&optional_header, debug_directory)?;
+    let debug_size = e.u32(&optional_header, debug_directory + 4)?;
+
+    let sections = read_at(
+        reader,
+        optional_header_offset + u64::from(optional_header_size),
+        40 * u64::from(section_count),
+    )?;
+    // The debug directory is given as a virtual address, which needs to be converted into an
+    // offset in the file using the section which contains it.
+    let debug_offset = sections.chunks_exact(40).find_map(|section| {
+        let size = e.u32(section, 8)?.max(e.u32(section, 16)?);
+        let address = e.u32(section, 12)?;
+        let offset = e.u32(section, 20)?;
+        let delta = debug_rva
+            .checked_sub(address)
+            .filter(|&delta| delta < size)?;
+        Some(u64::from(offset) + u64::from(delta))
+    });
+    let Some(entries) = debug_offset.and_then(|offset| read_at(reader, offset, debug_size.into()))
+    else {
+        return Some(metadata);
+    };
+    metadata.build_id = entries.chunks_exact(28).find_map(|entry| {
+        if e.u32(entry, 12)? != IMAGE_DEBUG_TYPE_CODEVIEW {
+            return None;
+        }
+        // "RSDS" signature followed by the GUID and the age of the PDB file.
+        let data = read_at(reader, e.u32(entry, 24)?.into(), 24)?;
+        (data[..4] == *b"RSDS").then(|| data[4..].to_vec())
+    });
+    Some(metadata)
+}
+
+fn parse_mach_o<R: Read + Seek>(reader: &mut R, offset: u64) -> Option<ExeMetadata> {
+    let header = read_at(reader, offset, 28)?;
+    let (e, header_size) = match u32::from_be_bytes(header[..4].try_into().ok()?) {
+        0xfeed_face => (Endian::BIG, 28),
+        0xfeed_facf => (Endian::BIG, 32),
+        0xcefa_edfe => (Endian::LITTLE, 28),
+        0xcffa_edfe => (Endian::LITTLE, 32),
+        FAT_MAGIC if offset == 0 => return parse_fat_mach_o(reader),
+        _ => return None,
+    };
+    let command_count = e.u32(&header, 16)?;
+    let commands_size = e.u32(&header, 20)?;
+    let commands = read_at(reader, offset + header_size, commands_size.into())?;
+
+    let mut metadata = ExeMetadata::default();
+    let mut data = &commands[..];
+    for _ in 0..command_count {
+        let size = e.u32(data, 4)? as usize;
+        if size < 8 {
+            break;
+        }
+        let command = data.get(..size)?;
+        match e.u32(command, 0)? {
+            LC_UUID => metadata.build_id = command.get(8..24).map(<[u8]>::to_vec),
+            LC_LOAD_DYLINKER => {
+                metadata.interpreter = command
+                    .get(e.u32(command, 8)? as usize..)
+                    .map(path_from_c_str);
+            }
+            _ => {}
+        }
+        data = &data[size..];
+    }
+    Some(metadata)
+}
+
+/// Universal binaries contain one Mach-O file per architecture: the one matching the current
+/// architecture is used if there is one.
+fn parse_fat_mach_o<R: Read + Seek>(reader: &mut R) -> Option<ExeMetadata> {
+    let e = Endian::BIG;
+    let count = e.u32(&read_at(reader, 4, 4)?, 0)?;
+    let archs = read_at(reader, 8, 20 * u64::from(count))?;
+    let native = if cfg!(target_arch = "aarch64") {
+        CPU_TYPE_ARM64
+    } else {
+        CPU_TYPE_X86_64
+    };
+    let arch = archs
+        .chunks_exact(20)
+        .find(|arch| e.u32(arch, 0) == Some(native))
+        .or_else(|| archs.chunks_exact(20).next())?;
+    parse_mach_o(reader, e.u32(arch, 8)?.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_exe_metadata;
+    use std::io::Cursor;
+    use std::path::Path;
+
+    fn put(data: &mut Vec<u8>, offset: usize, bytes: &[u8]) {
+        if data.len() < offset + bytes.len() {
+            data.resize(offset + bytes.len(), 0);
+        }
+        data[offset..offset + bytes.len()].copy_from_slice(bytes);
+    }
+
+    #[test]
+    fn check_elf() {
+        let mut elf = Vec::new();
+        put(&mut elf, 0, &[0x7f, b'E', b'L', b'F', 2, 1]);
+        // Two program headers at 0x40 and three section headers at 0x200.
+        put(&mut elf, 0x20, &0x40u64.to_le_bytes());
+        put(&mut elf, 0x28, &0x200u64.to_le_bytes());
+        put(&mut elf, 0x36, &56u16.to_le_bytes());
+        put(&mut elf, 0x38, &2u16.to_le_bytes());
+        put(&mut elf, 0x3a, &64u16.to_le_bytes());
+        put(&mut elf, 0x3c, &3u16.to_le_bytes());
+        // PT_INTERP
+        put(&mut elf, 0x40, &3u32.to_le_bytes());
+        put(&mut elf, 0x48, &0x100u64.to_le_bytes());
+        put(&mut elf, 0x60, &13u64.to_le_bytes());
+        put(&mut elf, 0x100, b"/lib/ld.so.1\0");
+        // PT_NOTE, with an ABI tag note before the build ID.
+        put(&mut elf, 0x78, &4u32.to_le_bytes());
+        put(&mut elf, 0x80, &0x120u64.to_le_bytes());
+        put(&mut elf, 0x98, &52u64.to_le_bytes());
+        put(&mut elf, 0x120, &4u32.to_le_bytes());
+        put(&mut elf, 0x124, &16u32.to_le_bytes());
+        put(&mut elf, 0x128, &1u32.to_le_bytes());
+        put(&mut elf, 0x12c, b"GNU\0");
+        put(&mut elf, 0x140, &4u32.to_le_bytes());
+        put(&mut elf, 0x144, &4u32.to_le_bytes());
+        put(&mut elf, 0x148, &3u32.to_le_bytes());
+        put(&mut elf, 0x14c, b"GNU\0");
+        put(&mut elf, 0x150, &[0xde, 0xad, 0xbe, 0xef]);
+        // No SHT_SYMTAB section.
+        put(&mut elf, 0x200 + 64 + 4, &3u32.to_le_bytes());
+        put(&mut elf, 0x200 + 128 + 4, &1u32.to_le_bytes());
+        put(&mut elf, 0x200 + 191, &[0]);
+
+        let metadata = parse_exe_metadata(&mut Cursor::new(&elf)).unwrap();
+        assert_eq!(metadata.build_id(), Some(&[0xde, 0xad, 0xbe, 0xef][..]));
+        assert_eq!(metadata.interpreter(), Some(Path::new("/lib/ld.so.1")));
+        assert_eq!(metadata.link_time(), None);
+        assert_eq!(metadata.is_stripped(), Some(true));
+
+        put(&mut elf, 0x200 + 64 + 4, &2u32.to_le_bytes());
+        let metadata = parse_exe_metadata(&mut Cursor::new(&elf)).unwrap();
+        assert_eq!(metadata.is_stripped(), Some(false));
+    }
+
+    #[test]
+    fn check_pe() {
+        let mut pe = Vec::new();
+        put(&mut pe, 0, b"MZ");
+        put(&mut pe, 0x3c, &0x80u32.to_le_bytes());
+        put(&mut pe, 0x80, b"PE\0\0");
+        put(&mut pe, 0x86, &1u16.to_le_bytes());
+        put(&mut pe, 0x88, &1_700_000_000u32.to_le_bytes());
+        put(&mut pe, 0x94, &240u16.to_le_bytes());
+        // PE32+ optional header with 16 data directories, the debug one pointing to 0x2010.
+        put(&mut pe, 0x98, &0x20bu16.to_le_bytes());
+        put(&mut pe, 0x98 + 108, &16u32.to_le_bytes());
+        put(&mut pe, 0x98 + 112 + 48, &0x2010u32.to_le_bytes());
+        put(&mut pe, 0x98 + 112 + 52, &28u32.to_le_bytes());
+        // A section mapping 0x2000 to 0x400.
+        put(&mut pe, 0x188 + 8, &0x100u32.to_le_bytes());
+        put(&mut pe, 0x188 + 12, &0x2000u32.to_le_bytes());
+        put(&mut pe, 0x188 + 16, &0x100u32.to_le_bytes());
+        put(&mut pe, 0x188 + 20, &0x400u32.to_le_bytes());
+        // CodeView debug entry.
+        put(&mut pe, 0x410 + 12, &2u32.to_le_bytes());
+        put(&mut pe, 0x410 + 24, &0x440u32.to_le_bytes());
+        put(&mut pe, 0x440, b"RSDS");
+        put(&mut pe, 0x444, &[7; 16]);
+        put(&mut pe, 0x454, &1u32.to_le_bytes());
+
+        let metadata = parse_exe_metadata(&mut Cursor::new(&pe)).unwrap();
+        let mut build_id = vec![7; 16];
+        build_id.extend_from_slice(&1u32.to_le_bytes());
+        assert_eq!(metadata.build_id(), Some(&build_id[..]));
+        assert_eq!(metadata.interpreter(), None);
+        assert_eq!(metadata.link_time(), Some(1_700_000_000));
+        assert_eq!(metadata.is_stripped(), None);
+    }
+
+    #[test]
+    fn check_mach_o() {
+        let mut mach_o = Vec::new();
+        put(&mut mach_o, 0, &0xfeed_facfu32.to_le_bytes());
+        put(&mut mach_o, 16, &2u32.to_le_bytes());
+        put(&mut mach_o, 20, &56u32.to_le_bytes());
+        // LC_LOAD_DYLINKER
+        put(&mut mach_o, 32, &0xeu32.to_le_bytes());
+        put(&mut mach_o, 36, &32u32.to_le_bytes());
+        put(&mut mach_o, 40, &12u32.to_le_bytes());
+        put(&mut mach_o, 44, b"/usr/lib/dyld\0");
+        // LC_UUID
+        put(&mut mach_o, 64, &0x1bu32.to_le_bytes());
+        put(&mut mach_o, 68, &24u32.to_le_bytes());
+        put(&mut mach_o, 72, &[3; 16]);
+
+        let metadata = parse_exe_metadata(&mut Cursor::new(&mach_o)).unwrap();
+        assert_eq!(metadata.build_id(), Some(&[3; 16][..]));
+        assert_eq!(metadata.interpreter(), Some(Path::new("/usr/lib/dyld")));
+
+        // The same file in a universal binary.
+        let mut fat = Vec::new();
+        put(&mut fat, 0, &0xcafe_babeu32.to_be_bytes());
+        put(&mut fat, 4, &1u32.to_be_bytes());
+        put(&mut fat, 8, &0x0100_000cu32.to_be_bytes());
+        put(&mut fat, 16, &0x1000u32.to_be_bytes());
+        put(&mut fat, 0x1000, &mach_o);
+        assert_eq!(parse_exe_metadata(&mut Cursor::new(&fat)), Some(metadata));
+    }
+
+    #[test]
+    fn check_invalid() {
+        assert!(parse_exe_metadata(&mut Cursor::new(b"#!/bin/sh\n")).is_none());
+        assert!(parse_exe_metadata(&mut Cursor::new(b"\x7fELF")).is_none());
+        assert!(parse_exe_metadata(&mut Cursor::new(b"MZ")).is_none());
+    }
+}
@@ -0,0 +1,141 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+//! Helpers to format the values returned by `sysinfo` in a human readable way.
+//!
+//! ```
+//! use sysinfo::format;
+//!
+//! assert_eq!(format::bytes(1_536), "1.50 KiB");
+//! assert_eq!(format::duration(93_784), "1d 2h 3m 4s");
+//! assert_eq!(format::frequency(3_200), "3.20 GHz");
+//! ```
+
+const BYTE_UNITS: &[&str] = &["KiB", "MiB", "GiB", "TiB", "PiB", "EiB"];
+
+/// Formats a number of bytes using binary units (`KiB`, `MiB`, `GiB`, etc).
+///
+/// ```
+/// use sysinfo::format;
+///
+/// assert_eq!(format::bytes(512), "512 B");
+/// assert_eq!(format::bytes(8 * 1024 * 1024 * 1024), "8.00 GiB");
+/// ```
+///
+/// It can be used to display the memory usage:
+///
+#[cfg_attr(not(feature = "system"), doc = "```ignore")]
+/// ```no_run
+/// use sysinfo::{System, format};
+///
+/// let s = System::new_all();
+/// println!("{} / {}", format::bytes(s.used_memory()), format::bytes(s.total_memory()));
+/// ```
+pub fn bytes(bytes: u64) -> String {
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+    let mut value = bytes as f64 / 1024.;
+    let mut unit = BYTE_UNITS[0];
+    for next_unit in &BYTE_UNITS[1..] {
+        if value < 1024. {
+            break;
+        }
+        value /= 1024.;
+        unit = next_unit;
+    }
+    format!("{value:.2} {unit}")
+}
+
+/// Formats a number of seconds as days, hours, minutes and seconds. Leading units equal to `0`
+/// are omitted.
+///
+/// ```
+/// use sysinfo::format;
+///
+/// assert_eq!(format::duration(0), "0s");
+/// assert_eq!(format::duration(3_605), "1h 0m 5s");
+/// ```
+///
+/// It can be used to display the system uptime:
+///
+#[cfg_attr(not(feature = "system"), doc = "```ignore")]
+/// ```no_run
+/// use sysinfo::{System, format};
+///
+/// println!("up {}", format::duration(System::uptime()));
+/// ```
+pub fn duration(secs: u64) -> String {
+    let days = secs / 86_400;
+    let hours = secs / 3_600 % 24;
+    let minutes = secs / 60 % 60;
+    let seconds = secs % 60;
+
+    if days > 0 {
+        format!("{days}d {hours}h {minutes}m {seconds}s")
+    } else if hours > 0 {
+        format!("{hours}h {minutes}m {seconds}s")
+    } else if minutes > 0 {
+        format!("{minutes}m {seconds}s")
+    } else {
+        format!("{seconds}s")
+    }
+}
+
+/// Formats a frequency in MHz, switching to GHz from 1000 MHz.
+///
+/// ```
+/// use sysinfo::format;
+///
+/// assert_eq!(format::frequency(800), "800 MHz");
+/// assert_eq!(format::frequency(2_400), "2.40 GHz");
+/// ```
+///
+/// It can be used to display the CPU frequency:
+///
+#[cfg_attr(not(feature = "system"), doc = "```ignore")]
+/// ```no_run
+/// use sysinfo::{System, format};
+///
+/// let s = System::new_all();
+/// for cpu in s.cpus() {
+///     println!("{}: {}", cpu.name(), format::frequency(cpu.frequency()));
+/// }
+/// ```
+pub fn frequency(mhz: u64) -> String {
+    if mhz < 1_000 {
+        format!("{mhz} MHz")
+    } else {
+        format!("{:.2} GHz", mhz as f64 / 1_000.)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_bytes() {
+        assert_eq!(bytes(0), "0 B");
+        assert_eq!(bytes(1_023), "1023 B");
+        assert_eq!(bytes(1_024), "1.00 KiB");
+        assert_eq!(bytes(1_048_576), "1.00 MiB");
+        assert_eq!(bytes(5 * 1024 * 1024 * 1024 * 1024), "5.00 TiB");
+        assert_eq!(bytes(u64::MAX), "16.00 EiB");
+    }
+
+    #[test]
+    fn check_duration() {
+        assert_eq!(duration(59), "59s");
+        assert_eq!(duration(60), "1m 0s");
+        assert_eq!(duration(86_400), "1d 0h 0m 0s");
+        assert_eq!(duration(200_000), "2d 7h 33m 20s");
+    }
+
+    #[test]
+    fn check_frequency() {
+        assert_eq!(frequency(0), "0 MHz");
+        assert_eq!(frequency(999), "999 MHz");
+        assert_eq!(frequency(1_000), "1.00 GHz");
+        assert_eq!(frequency(4_950), "4.95 GHz");
+    }
+}
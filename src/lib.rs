@@ -31,6 +31,21 @@ cfg_if! {
         mod unknown;
         use crate::unknown as sys;
 
+        #[cfg(test)]
+        pub(crate) const MIN_USERS: usize = 0;
+    } else if #[cfg(sysinfo_test_backend)] {
+        #[cfg(not(all(feature = "disk", feature = "system")))]
+        compile_error!("`--cfg sysinfo_test_backend` requires the `disk` and `system` features");
+
+        // The fake backend only implements the system and disk information, the rest comes
+        // from the unknown backend.
+        #[allow(dead_code, unused_imports)]
+        mod unknown;
+        mod test_backend;
+        use crate::test_backend as sys;
+
+        pub use crate::common::fake;
+
         #[cfg(test)]
         pub(crate) const MIN_USERS: usize = 0;
     } else if #[cfg(any(
@@ -68,22 +83,57 @@ cfg_if! {
     }
 }
 
+#[cfg(feature = "apple-silicon")]
+pub use crate::common::apple_silicon::{AppleSiliconMetrics, CpuCluster, CpuClusterKind};
+#[cfg(feature = "audio")]
+pub use crate::common::audio::{AudioDevice, AudioDeviceKind, AudioDevices};
 #[cfg(feature = "component")]
-pub use crate::common::component::{Component, Components};
+pub use crate::common::component::{
+    Component, Components, TemperatureThresholds, ThresholdLevel, ValueSource,
+};
 #[cfg(feature = "disk")]
-pub use crate::common::disk::{Disk, DiskKind, DiskRefreshKind, Disks};
+pub use crate::common::disk::{
+    Disk, DiskIoStats, DiskKind, DiskRefreshKind, DiskSpace, Disks, NetworkFsStats,
+};
+#[cfg(feature = "disk")]
+pub use crate::common::disk_watcher::{DiskEvent, DiskEventKind, DiskWatcher};
+#[cfg(feature = "display")]
+pub use crate::common::display::{Display, Displays};
+#[cfg(feature = "ebpf")]
+pub use crate::common::ebpf::{EbpfCollectors, LatencyStats};
+#[cfg(feature = "system")]
+pub use crate::common::exited_process::{ExitedProcess, ExitedProcesses};
 #[cfg(feature = "network")]
 pub use crate::common::network::{
-    IpNetwork, IpNetworkFromStrError, MacAddr, MacAddrFromStrError, NetworkData, Networks,
+    DnsConfiguration, IpNetwork, IpNetworkFromStrError, MacAddr, MacAddrFromStrError, NetworkData,
+    Networks, ProtocolStats,
 };
+#[cfg(feature = "network")]
+pub use crate::common::network_watcher::{NetworkEvent, NetworkEventKind, NetworkWatcher};
+#[cfg(feature = "system")]
+pub use crate::common::pid_set_tracker::{PidDelta, PidSetTracker};
+#[cfg(feature = "system")]
+pub use crate::common::shared_system::SharedSystem;
+#[cfg(feature = "disk")]
+pub use crate::common::storage::{Storage, Volume, VolumeDevice, VolumeKind, VolumeState};
 #[cfg(feature = "system")]
 pub use crate::common::system::{
-    CGroupLimits, Cpu, CpuRefreshKind, KillError, LoadAvg, MemoryRefreshKind, Motherboard, Pid,
-    Process, ProcessRefreshKind, ProcessStatus, ProcessesToUpdate, Product, RefreshKind, Signal,
-    System, ThreadKind, UpdateKind, get_current_pid,
+    CGroupLimits, CGroupUsage, ChildrenUsage, CompressedMemory, ConnectionCount, CoreDumpConfig,
+    Cpu, CpuIdleState, CpuRefreshKind, CpuVulnerability, CpuVulnerabilityStatus,
+    CurrentProcessUsage, EnergyImpact, ExeMetadata, ExeSignature, ExeUsage, FirewallProfile,
+    InotifyUsage, IoCounters, KernelLogEntry, KernelLogLevel, KillError, LoadAvg, MediaUsage,
+    MemoryHardwareInfo, MemoryModule, MemoryRefreshKind, Motherboard, PathUsage, Pid, Process,
+    ProcessHandle, ProcessRefreshKind, ProcessStatus, ProcessStringStats, ProcessesToUpdate,
+    Product, QosClass, RefreshDurations, RefreshEvent, RefreshKind, RefreshSubsystem, SchedStats,
+    SecurityInfo, Signal, SocketSummary, SwapDevice, System, SystemFacts, ThreadKind,
+    ThrottlingStatus, UpdateKind, UserUsage, current_process, get_current_pid, self_children_usage,
 };
+#[cfg(feature = "usb")]
+pub use crate::common::usb::{UsbDevice, UsbDevices, UsbSpeed};
 #[cfg(feature = "user")]
 pub use crate::common::user::{Group, Groups, User, Users};
+#[cfg(feature = "zfs")]
+pub use crate::common::zfs::{Zfs, ZfsArc, ZfsPool, ZfsPoolHealth};
 #[cfg(any(feature = "user", feature = "system"))]
 pub use crate::common::{Gid, Uid};
 #[cfg(feature = "system")]
@@ -91,20 +141,30 @@ pub use crate::sys::{MINIMUM_CPU_UPDATE_INTERVAL, SUPPORTED_SIGNALS};
 
 #[cfg(any(feature = "system", feature = "disk"))]
 pub use crate::common::DiskUsage;
+#[cfg(any(feature = "system", feature = "disk", feature = "network"))]
+pub use crate::common::{RefreshError, RefreshTimestamp};
 
 #[cfg(feature = "user")]
 pub(crate) use crate::common::user::GroupInner;
+#[cfg(feature = "apple-silicon")]
+pub(crate) use crate::sys::AppleSiliconMetricsInner;
+#[cfg(feature = "ebpf")]
+pub(crate) use crate::sys::EbpfCollectorsInner;
 #[cfg(feature = "user")]
 pub(crate) use crate::sys::UserInner;
 #[cfg(feature = "component")]
 pub(crate) use crate::sys::{ComponentInner, ComponentsInner};
 #[cfg(feature = "system")]
-pub(crate) use crate::sys::{CpuInner, MotherboardInner, ProcessInner, ProductInner, SystemInner};
+pub(crate) use crate::sys::{
+    CpuInner, ExitedProcessesInner, MotherboardInner, ProcessInner, ProductInner,
+    SecurityInfoInner, SystemInner,
+};
 #[cfg(feature = "disk")]
-pub(crate) use crate::sys::{DiskInner, DisksInner};
+pub(crate) use crate::sys::{DiskInner, DiskWatcherInner, DisksInner, StorageInner};
 #[cfg(feature = "network")]
-pub(crate) use crate::sys::{NetworkDataInner, NetworksInner};
+pub(crate) use crate::sys::{NetworkDataInner, NetworkWatcherInner, NetworksInner};
 
+pub use crate::diagnostics::{remove_diagnostics_handler, set_diagnostics_handler};
 pub use crate::sys::IS_SUPPORTED_SYSTEM;
 
 #[cfg(feature = "c-interface")]
@@ -114,14 +174,48 @@ pub use crate::c_interface::*;
 mod c_interface;
 mod common;
 mod debug;
+mod diagnostics;
+#[cfg(feature = "format")]
+pub mod format;
+#[cfg(all(
+    any(
+        feature = "system",
+        feature = "disk",
+        feature = "network",
+        feature = "zfs"
+    ),
+    not(feature = "fuzzing"),
+    any(
+        target_os = "linux",
+        target_os = "android",
+        target_os = "redox",
+        windows
+    )
+))]
+mod parsing;
+#[cfg(all(feature = "system", feature = "fuzzing"))]
+#[doc(hidden)]
+pub mod parsing;
+#[cfg(feature = "system")]
+pub mod quick;
+#[cfg(feature = "record")]
+pub use crate::common::record;
+#[cfg(feature = "remote")]
+pub use crate::common::remote;
+#[cfg(feature = "system")]
+pub(crate) mod exe_metadata;
 #[cfg(feature = "serde")]
 mod serde;
+#[cfg(feature = "system")]
+pub(crate) mod sha256;
 pub(crate) mod utils;
 
 // Make formattable by rustfmt.
 #[cfg(any())]
 mod network;
 #[cfg(any())]
+mod test_backend;
+#[cfg(any())]
 mod unix;
 #[cfg(any())]
 mod unknown;
@@ -154,7 +248,12 @@ mod windows;
 /// ```
 pub fn set_open_files_limit(mut _new_limit: usize) -> bool {
     cfg_if! {
-        if #[cfg(all(feature = "system", not(feature = "unknown-ci"), any(target_os = "linux", target_os = "android")))]
+        if #[cfg(all(
+            feature = "system",
+            not(feature = "unknown-ci"),
+            not(sysinfo_test_backend),
+            any(target_os = "linux", target_os = "android"),
+        ))]
         {
             use crate::sys::system::remaining_files;
             use std::sync::atomic::Ordering;
@@ -195,26 +294,68 @@ use sysinfo::", stringify!($imports), r";
     #[cfg(not(feature = "system"))]
     compile_fail_import!(
         no_system_feature =>
+        current_process,
         get_current_pid,
+        self_children_usage,
         CGroupLimits,
+        CGroupUsage,
+        ChildrenUsage,
+        CompressedMemory,
+        ConnectionCount,
+        CoreDumpConfig,
         Cpu,
+        CpuIdleState,
         CpuRefreshKind,
+        CpuVulnerability,
+        CpuVulnerabilityStatus,
+        CurrentProcessUsage,
         DiskUsage,
+        EnergyImpact,
+        ExitedProcess,
+        ExitedProcesses,
+        ExeMetadata,
+        ExeSignature,
+        ExeUsage,
+        FirewallProfile,
+        InotifyUsage,
+        IoCounters,
+        KernelLogEntry,
+        KernelLogLevel,
         KillError,
         LoadAvg,
+        MediaUsage,
+        MemoryHardwareInfo,
+        MemoryModule,
         MemoryRefreshKind,
         Motherboard,
+        PathUsage,
         Pid,
+        PidDelta,
+        PidSetTracker,
         Process,
         ProcessesToUpdate,
+        ProcessHandle,
         ProcessRefreshKind,
         ProcessStatus,
+        ProcessStringStats,
         Product,
+        QosClass,
+        RefreshDurations,
+        RefreshEvent,
         RefreshKind,
+        RefreshSubsystem,
+        SchedStats,
+        SecurityInfo,
+        SharedSystem,
         Signal,
+        SocketSummary,
+        SwapDevice,
         System,
+        SystemFacts,
         ThreadKind,
+        ThrottlingStatus,
         UpdateKind,
+        UserUsage,
     );
 
     #[cfg(not(feature = "disk"))]
@@ -222,7 +363,26 @@ use sysinfo::", stringify!($imports), r";
         no_disk_feature =>
         Disk,
         Disks,
+        DiskEvent,
+        DiskEventKind,
+        DiskIoStats,
         DiskKind,
+        DiskSpace,
+        DiskWatcher,
+        NetworkFsStats,
+        Storage,
+        Volume,
+        VolumeDevice,
+        VolumeKind,
+        VolumeState,
+    );
+
+    #[cfg(not(feature = "audio"))]
+    compile_fail_import!(
+        no_audio_feature =>
+        AudioDevice,
+        AudioDeviceKind,
+        AudioDevices,
     );
 
     #[cfg(not(feature = "component"))]
@@ -230,15 +390,30 @@ use sysinfo::", stringify!($imports), r";
         no_component_feature =>
         Component,
         Components,
+        TemperatureThresholds,
+        ThresholdLevel,
+        ValueSource,
+    );
+
+    #[cfg(not(feature = "display"))]
+    compile_fail_import!(
+        no_display_feature =>
+        Display,
+        Displays,
     );
 
     #[cfg(not(feature = "network"))]
     compile_fail_import!(
         no_network_feature =>
+        DnsConfiguration,
         IpNetwork,
         MacAddr,
         NetworkData,
+        NetworkEvent,
+        NetworkEventKind,
+        NetworkWatcher,
         Networks,
+        ProtocolStats,
     );
 
     #[cfg(not(feature = "user"))]
@@ -249,6 +424,14 @@ use sysinfo::", stringify!($imports), r";
         User,
         Users,
     );
+
+    #[cfg(not(feature = "usb"))]
+    compile_fail_import!(
+        no_usb_feature =>
+        UsbDevice,
+        UsbDevices,
+        UsbSpeed,
+    );
 }
 
 #[cfg(test)]
@@ -280,6 +463,8 @@ mod test {
         check_minimum_cpu_update_interval(MINIMUM_CPU_UPDATE_INTERVAL);
     }
 
+    // `MIN_USERS` is `0` on unsupported systems.
+    #[allow(clippy::absurd_extreme_comparisons)]
     #[cfg(feature = "user")]
     #[test]
     fn check_uid_gid() {
@@ -314,8 +499,8 @@ mod test {
                     ));
                 assert!(
                     s.processes()
-                        .iter()
-                        .filter_map(|(_, p)| p.user_id())
+                        .values()
+                        .filter_map(|p| p.user_id())
                         .any(|uid| users.get_user_by_id(uid).is_some())
                 );
             }
@@ -344,6 +529,8 @@ mod test {
         }
     }
 
+    // `MIN_USERS` is `0` on unsupported systems.
+    #[allow(clippy::absurd_extreme_comparisons)]
     #[test]
     fn ensure_is_supported_is_set_correctly() {
         if MIN_USERS > 0 {
@@ -367,43 +554,120 @@ mod test {
         trait HasSendAndSync: Send + Sync {}
 
         // Structs
+        #[cfg(feature = "audio")]
+        impl HasSendAndSync for AudioDevice {}
+        #[cfg(feature = "audio")]
+        impl HasSendAndSync for AudioDevices {}
         impl HasSendAndSync for CGroupLimits {}
+        impl HasSendAndSync for CGroupUsage {}
+        impl HasSendAndSync for ChildrenUsage {}
         impl HasSendAndSync for Component {}
         impl HasSendAndSync for Components {}
+        impl HasSendAndSync for CompressedMemory {}
+        impl HasSendAndSync for ConnectionCount {}
+        impl HasSendAndSync for CoreDumpConfig {}
+        impl HasSendAndSync for TemperatureThresholds {}
         impl HasSendAndSync for Cpu {}
+        impl HasSendAndSync for CpuIdleState {}
         impl HasSendAndSync for CpuRefreshKind {}
+        impl HasSendAndSync for CpuVulnerability {}
+        impl HasSendAndSync for CurrentProcessUsage {}
         impl HasSendAndSync for Disk {}
+        impl HasSendAndSync for DiskEvent {}
+        impl HasSendAndSync for DiskIoStats {}
         impl HasSendAndSync for Disks {}
         impl HasSendAndSync for DiskRefreshKind {}
+        impl HasSendAndSync for DiskSpace {}
         impl HasSendAndSync for DiskUsage {}
+        impl HasSendAndSync for DiskWatcher {}
+        #[cfg(feature = "display")]
+        impl HasSendAndSync for Display {}
+        #[cfg(feature = "display")]
+        impl HasSendAndSync for Displays {}
+        impl HasSendAndSync for DnsConfiguration {}
+        impl HasSendAndSync for EnergyImpact {}
+        impl HasSendAndSync for ExeMetadata {}
+        impl HasSendAndSync for ExitedProcess {}
+        impl HasSendAndSync for ExitedProcesses {}
+        impl HasSendAndSync for FirewallProfile {}
         impl HasSendAndSync for Gid {}
         impl HasSendAndSync for Group {}
         impl HasSendAndSync for Groups {}
+        impl HasSendAndSync for InotifyUsage {}
+        impl HasSendAndSync for IoCounters {}
         impl HasSendAndSync for IpNetwork {}
+        impl HasSendAndSync for KernelLogEntry {}
         impl HasSendAndSync for LoadAvg {}
         impl HasSendAndSync for MacAddr {}
+        impl HasSendAndSync for ProtocolStats {}
+        impl HasSendAndSync for MediaUsage {}
+        impl HasSendAndSync for MemoryHardwareInfo {}
+        impl HasSendAndSync for MemoryModule {}
         impl HasSendAndSync for MemoryRefreshKind {}
         impl HasSendAndSync for NetworkData {}
+        impl HasSendAndSync for NetworkEvent {}
+        impl HasSendAndSync for NetworkFsStats {}
+        impl HasSendAndSync for NetworkWatcher {}
         impl HasSendAndSync for Networks {}
+        impl HasSendAndSync for PathUsage {}
         impl HasSendAndSync for Pid {}
+        impl HasSendAndSync for PidDelta {}
+        impl HasSendAndSync for PidSetTracker {}
         impl HasSendAndSync for Process {}
+        impl HasSendAndSync for ProcessHandle {}
         impl HasSendAndSync for ProcessRefreshKind {}
+        impl HasSendAndSync for ProcessStringStats {}
         impl HasSendAndSync for Product {}
+        impl HasSendAndSync for RefreshDurations {}
+        impl HasSendAndSync for RefreshEvent {}
         impl HasSendAndSync for RefreshKind {}
+        impl HasSendAndSync for RefreshSubsystem {}
+        impl HasSendAndSync for RefreshTimestamp {}
+        impl HasSendAndSync for SchedStats {}
+        impl HasSendAndSync for SecurityInfo {}
+        impl HasSendAndSync for SharedSystem {}
+        impl HasSendAndSync for Storage {}
+        impl HasSendAndSync for SwapDevice {}
         impl HasSendAndSync for System {}
+        impl HasSendAndSync for SystemFacts {}
+        impl HasSendAndSync for SocketSummary {}
+        impl HasSendAndSync for ThrottlingStatus {}
         impl HasSendAndSync for Uid {}
+        #[cfg(feature = "usb")]
+        impl HasSendAndSync for UsbDevice {}
+        #[cfg(feature = "usb")]
+        impl HasSendAndSync for UsbDevices {}
         impl HasSendAndSync for User {}
+        impl HasSendAndSync for UserUsage {}
         impl HasSendAndSync for Users {}
+        impl HasSendAndSync for Volume {}
+        impl HasSendAndSync for VolumeDevice {}
 
         // Enums
+        #[cfg(feature = "audio")]
+        impl HasSendAndSync for AudioDeviceKind {}
+        impl HasSendAndSync for CpuVulnerabilityStatus {}
+        impl HasSendAndSync for DiskEventKind {}
         impl HasSendAndSync for DiskKind {}
+        impl HasSendAndSync for ExeSignature {}
+        impl HasSendAndSync for ExeUsage {}
         impl HasSendAndSync for IpNetworkFromStrError {}
+        impl HasSendAndSync for KernelLogLevel {}
         impl HasSendAndSync for KillError {}
         impl HasSendAndSync for MacAddrFromStrError {}
+        impl HasSendAndSync for NetworkEventKind {}
         impl HasSendAndSync for ProcessStatus {}
+        impl HasSendAndSync for ThresholdLevel {}
         impl HasSendAndSync for ProcessesToUpdate<'_> {}
+        impl HasSendAndSync for QosClass {}
+        impl HasSendAndSync for RefreshError {}
         impl HasSendAndSync for Signal {}
         impl HasSendAndSync for ThreadKind {}
         impl HasSendAndSync for UpdateKind {}
+        #[cfg(feature = "usb")]
+        impl HasSendAndSync for UsbSpeed {}
+        impl HasSendAndSync for ValueSource {}
+        impl HasSendAndSync for VolumeKind {}
+        impl HasSendAndSync for VolumeState {}
     }
 }
@@ -1,21 +1,18 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
-#[cfg(feature = "debug")]
+// The message is forwarded to the handler set with `set_diagnostics_handler` and printed on
+// the standard error output if the `debug` feature is enabled. The arguments are only evaluated
+// if the message is going to be used.
 #[doc(hidden)]
 #[allow(unused)]
 macro_rules! sysinfo_debug {
     ($($x:tt)*) => {{
-        eprintln!($($x)*);
+        if crate::diagnostics::is_enabled() {
+            crate::diagnostics::emit(format_args!($($x)*));
+        }
     }}
 }
 
-#[cfg(not(feature = "debug"))]
-#[doc(hidden)]
-#[allow(unused)]
-macro_rules! sysinfo_debug {
-    ($($x:tt)*) => {{}};
-}
-
 #[cfg(feature = "system")]
 macro_rules! declare_signals {
     ($kind:ty, _ => None,) => (
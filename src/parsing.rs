@@ -0,0 +1,1735 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+//! Parsers of the files and messages provided by the kernel (`/proc` and netlink on Linux,
+//! `/scheme` on Redox) and by the firmware (SMBIOS tables).
+//!
+//! They only work on the data they're given, without any I/O, and must never panic whatever the
+//! input is, so they can be fuzzed (take a look at the `fuzz` folder) on any Linux host.
+
+// All parsers are not used on all targets, but they're all fuzzed.
+#![cfg_attr(not(feature = "fuzzing"), allow(dead_code))]
+
+use std::str;
+
+/// Indexes of the fields of a `/proc/[pid]/stat` file, once the PID and the command name have
+/// been removed (the PID is kept as first field).
+#[allow(dead_code)]
+#[repr(usize)]
+pub enum ProcIndex {
+    Pid = 0,
+    State,
+    ParentPid,
+    GroupId,
+    SessionId,
+    Tty,
+    ForegroundProcessGroupId,
+    Flags,
+    MinorFaults,
+    ChildrenMinorFaults,
+    MajorFaults,
+    ChildrenMajorFaults,
+    UserTime,
+    SystemTime,
+    ChildrenUserTime,
+    ChildrenKernelTime,
+    Priority,
+    Nice,
+    NumberOfThreads,
+    IntervalTimerSigalarm,
+    StartTime,
+    VirtualSize,
+    ResidentSetSize,
+    // More exist but we only use the listed ones. For more, take a look at `man proc`.
+}
+
+/// Fields of a `/proc/[pid]/stat` file.
+pub struct StatParts<'a> {
+    /// It contains at least all the fields listed in [`ProcIndex`], so they can be accessed by
+    /// index directly.
+    pub str_parts: Vec<&'a str>,
+    pub short_exe: &'a [u8],
+}
+
+/// Parses the content of a `/proc/[pid]/stat` file. Returns `None` if it is malformed or if it
+/// doesn't contain all the fields listed in [`ProcIndex`].
+pub fn parse_stat_file(data: &[u8]) -> Option<StatParts<'_>> {
+    // The stat file is "interesting" to parse, because spaces cannot
+    // be used as delimiters. The second field stores the command name
+    // surrounded by parentheses. Unfortunately, whitespace and
+    // parentheses are legal parts of the command, so parsing has to
+    // proceed like this: The first field is delimited by the first
+    // whitespace, the second field is everything until the last ')'
+    // in the entire string. All other fields are delimited by
+    // whitespace.
+
+    let mut str_parts = Vec::with_capacity(51);
+    let mut data_it = data.splitn(2, |&b| b == b' ');
+    str_parts.push(str::from_utf8(data_it.next()?).ok()?);
+    let mut data_it = data_it.next()?.rsplitn(2, |&b| b == b')');
+    let data = str::from_utf8(data_it.next()?).ok()?;
+    let short_exe = data_it.next()?;
+    str_parts.extend(data.split_whitespace());
+    if str_parts.len() <= ProcIndex::ResidentSetSize as usize {
+        return None;
+    }
+    Some(StatParts {
+        str_parts,
+        short_exe: short_exe.strip_prefix(b"(").unwrap_or(short_exe),
+    })
+}
+
+/// Returns the trimmed content of the `start..end` column of `line`, or an empty string if the
+/// line is too short.
+fn column(line: &str, start: usize, end: usize) -> &str {
+    line.get(start..end.min(line.len()))
+        .map(str::trim)
+        .unwrap_or_default()
+}
+
+/// Fields of a line of `/scheme/proc/ps` on Redox.
+#[derive(Debug, PartialEq, Eq)]
+pub struct RedoxPsLine<'a> {
+    pub pid: usize,
+    pub pgid: Option<usize>,
+    pub ppid: Option<usize>,
+    pub sid: Option<usize>,
+    pub ruid: Option<u32>,
+    pub rgid: Option<u32>,
+    pub euid: Option<u32>,
+    pub egid: Option<u32>,
+    pub nthrd: Option<usize>,
+    pub status: char,
+    pub name: &'a str,
+}
+
+/// Parses a line (other than the header) of `/scheme/proc/ps` on Redox. Returns `None` if it
+/// doesn't start with a PID.
+pub fn parse_redox_ps_line(line: &str) -> Option<RedoxPsLine<'_>> {
+    /* Example data from /scheme/proc/ps:
+    PID   PGID  PPID  SID   RUID  RGID  RNS   EUID  EGID  ENS   NTHRD STATUS  NAME
+    1     1     1     1     0     0     1     0     0     1     1     R       /scheme/initfs/bin/init
+    4     1     1     1     0     0     0     0     0     0     1     R       /bin/nulld
+    0     6     12    18    24    30    36    42    48    54    60    66      74
+    Indexes listed above
+    */
+    Some(RedoxPsLine {
+        pid: column(line, 0, 6).parse().ok()?,
+        pgid: column(line, 6, 12).parse().ok(),
+        ppid: column(line, 12, 18).parse().ok(),
+        sid: column(line, 18, 24).parse().ok(),
+        ruid: column(line, 24, 30).parse().ok(),
+        rgid: column(line, 30, 36).parse().ok(),
+        euid: column(line, 42, 48).parse().ok(),
+        egid: column(line, 48, 54).parse().ok(),
+        nthrd: column(line, 60, 66).parse().ok(),
+        status: column(line, 66, 74).chars().next().unwrap_or_default(),
+        name: line.get(74..).unwrap_or_default(),
+    })
+}
+
+/// Fields of a line of `/scheme/sys/context` on Redox.
+#[derive(Debug, PartialEq, Eq)]
+pub struct RedoxContextLine<'a> {
+    pub pid: usize,
+    pub euid: Option<u32>,
+    pub egid: Option<u32>,
+    /// `U` for userland contexts.
+    pub kind: char,
+    pub status: char,
+    pub cpu: usize,
+    /// CPU time (in milliseconds).
+    pub time: u64,
+    /// Memory (in bytes).
+    pub memory: u64,
+    pub name: &'a str,
+}
+
+/// Parses a line (other than the header) of `/scheme/sys/context` on Redox. Returns `None` if it
+/// doesn't start with a PID.
+pub fn parse_redox_context_line(line: &str) -> Option<RedoxContextLine<'_>> {
+    /* Example data from /scheme/sys/context:
+    PID   EUID  EGID  ENS   STAT  CPU   AFFINITY   TIME        MEM     NAME
+    0     0     0     0     RR+   #3               00:00:01.36 1 KB    [kmain]
+    0     0     0     1     UB    #3               00:00:00.00 23 MB   [init]
+    1     0     0     1     UB    #3               00:00:00.01 1 MB    /scheme/initfs/bin/init
+    0     6     12    18    24    30    36         47 50 53 56 59      67
+    Indexes listed above
+    */
+    let pid = column(line, 0, 6).parse().ok()?;
+    let mut stat = column(line, 24, 30).chars();
+    Some(RedoxContextLine {
+        pid,
+        euid: column(line, 6, 12).parse().ok(),
+        egid: column(line, 12, 18).parse().ok(),
+        kind: stat.next().unwrap_or_default(),
+        status: stat.next().unwrap_or_default(),
+        //TODO: this ID may not map to the CPUs detected from /scheme/sys/cpu
+        cpu: column(line, 31, 36).parse().unwrap_or_default(),
+        time: parse_redox_context_time(line),
+        memory: parse_redox_context_memory(line),
+        name: line.get(67..).unwrap_or_default(),
+    })
+}
+
+/// Returns the CPU time (in milliseconds) of a line of `/scheme/sys/context`.
+pub fn parse_redox_context_time(line: &str) -> u64 {
+    let field = |start, end| column(line, start, end).parse::<u64>().unwrap_or_default();
+    // Hours
+    field(47, 49)
+        .saturating_mul(3600 * 1000)
+        // Minutes
+        .saturating_add(field(50, 52).saturating_mul(60 * 1000))
+        // Seconds
+        .saturating_add(field(53, 55).saturating_mul(1000))
+        // Centiseconds
+        .saturating_add(field(56, 58).saturating_mul(10))
+}
+
+/// Returns the memory (in bytes) of a line of `/scheme/sys/context`.
+pub fn parse_redox_context_memory(line: &str) -> u64 {
+    let mut parts = column(line, 59, 67).split(' ');
+    let mem = parts
+        .next()
+        .unwrap_or_default()
+        .parse::<u64>()
+        .unwrap_or_default();
+    let unit: u64 = match parts.next().unwrap_or_default() {
+        "B" => 1,
+        "KB" => 1024,
+        "MB" => 1024 * 1024,
+        "GB" => 1024 * 1024 * 1024,
+        suffix => {
+            sysinfo_debug!("unknown memory suffix {:?}", suffix);
+            1
+        }
+    };
+    mem.saturating_mul(unit)
+}
+
+/// Parses a line of `/scheme/sys/log` on Redox and returns its syslog level, from `0`
+/// (emergency) to `7` (debug), and its message. The level is read from the
+/// `target:LEVEL -- message` format of the kernel logger and is `6` (info) for other lines.
+pub fn parse_redox_log_line(line: &str) -> (u8, &str) {
+    let level =
+        line.split_once(" -- ")
+            .and_then(|(prefix, _)| match prefix.rsplit(':').next()?.trim() {
+                "ERROR" => Some(3),
+                "WARN" => Some(4),
+                "INFO" => Some(6),
+                "DEBUG" | "TRACE" => Some(7),
+                _ => None,
+            });
+    (level.unwrap_or(6), line.trim_end())
+}
+
+/// An exit event sent by the Linux process events connector (`linux/cn_proc.h`).
+#[derive(Debug, PartialEq, Eq)]
+pub struct ProcExitEvent {
+    pub pid: u32,
+    /// Thread group ID, equal to `pid` if it is the exit of a process and not of a thread.
+    pub tgid: u32,
+    /// Wait status, like the ones returned by `waitpid`.
+    pub exit_code: u32,
+    pub parent_tgid: u32,
+}
+
+/// Parses the netlink messages received from the Linux process events connector and returns
+/// the exit events they contain. Other events and malformed messages are ignored.
+pub fn parse_proc_connector_exits(data: &[u8]) -> Vec<ProcExitEvent> {
+    // Sizes of `struct cn_msg` and of the header of `struct proc_event`.
+    const CN_MSG_LEN: usize = 20;
+    const PROC_EVENT_HDR_LEN: usize = 16;
+    const NLMSG_NOOP: u16 = 1;
+    const NLMSG_ERROR: u16 = 2;
+    const PROC_EVENT_EXIT: u32 = 0x8000_0000;
+
+    let u32_at = |data: &[u8], offset: usize| -> Option<u32> {
+        Some(u32::from_ne_bytes(
+            data.get(offset..offset + 4)?.try_into().ok()?,
+        ))
+    };
+
+    let mut events = Vec::new();
+    for (kind, event) in netlink_messages(data) {
+        if kind == NLMSG_NOOP || kind == NLMSG_ERROR {
+            continue;
+        }
+        let field = |index: usize| u32_at(event, CN_MSG_LEN + PROC_EVENT_HDR_LEN + index * 4);
+        if u32_at(event, CN_MSG_LEN) == Some(PROC_EVENT_EXIT)
+            && let (Some(pid), Some(tgid), Some(exit_code), Some(parent_tgid)) =
+                (field(0), field(1), field(2), field(5))
+        {
+            events.push(ProcExitEvent {
+                pid,
+                tgid,
+                exit_code,
+                parent_tgid,
+            });
+        }
+    }
+    events
+}
+
+/// Iterates over the netlink messages contained in `data` and returns their type and payload.
+/// Malformed messages stop the iteration.
+fn netlink_messages(mut data: &[u8]) -> impl Iterator<Item = (u16, &[u8])> {
+    const NLMSG_HDR_LEN: usize = 16;
+
+    std::iter::from_fn(move || {
+        let len = u32::from_ne_bytes(data.get(..4)?.try_into().ok()?) as usize;
+        if len < NLMSG_HDR_LEN || len > data.len() {
+            return None;
+        }
+        let kind = u16::from_ne_bytes([data[4], data[5]]);
+        let payload = &data[NLMSG_HDR_LEN..len];
+        // Messages are aligned on 4 bytes.
+        data = data.get(len.next_multiple_of(4)..).unwrap_or_default();
+        Some((kind, payload))
+    })
+}
+
+/// Iterates over the netlink attributes (`struct nlattr`) contained in `data` and returns their
+/// type and value. Malformed attributes stop the iteration.
+fn netlink_attributes(mut data: &[u8]) -> impl Iterator<Item = (u16, &[u8])> {
+    const NLA_HDR_LEN: usize = 4;
+    // The two upper bits are flags.
+    const NLA_TYPE_MASK: u16 = 0x3fff;
+
+    std::iter::from_fn(move || {
+        let len = u16::from_ne_bytes(data.get(..2)?.try_into().ok()?) as usize;
+        if len < NLA_HDR_LEN || len > data.len() {
+            return None;
+        }
+        let kind = u16::from_ne_bytes([data[2], data[3]]) & NLA_TYPE_MASK;
+        let value = &data[NLA_HDR_LEN..len];
+        // Attributes are aligned on 4 bytes.
+        data = data.get(len.next_multiple_of(4)..).unwrap_or_default();
+        Some((kind, value))
+    })
+}
+
+/// Parses the answer of the Linux generic netlink controller to a `CTRL_CMD_GETFAMILY` request
+/// and returns the ID of the family.
+pub fn parse_genl_family_id(data: &[u8]) -> Option<u16> {
+    // Size of `struct genlmsghdr`.
+    const GENL_HDR_LEN: usize = 4;
+    const GENL_ID_CTRL: u16 = 0x10;
+    const CTRL_ATTR_FAMILY_ID: u16 = 1;
+
+    let (_, payload) = netlink_messages(data).find(|(kind, _)| *kind == GENL_ID_CTRL)?;
+    let (_, value) = netlink_attributes(payload.get(GENL_HDR_LEN..)?)
+        .find(|(kind, _)| *kind == CTRL_ATTR_FAMILY_ID)?;
+    Some(u16::from_ne_bytes(value.get(..2)?.try_into().ok()?))
+}
+
+/// Parses the acknowledgment of a netlink request and returns the error code it contains (`0`
+/// if the request succeeded, a negative `errno` otherwise).
+pub fn parse_netlink_ack(data: &[u8]) -> Option<i32> {
+    const NLMSG_ERROR: u16 = 2;
+
+    let (_, payload) = netlink_messages(data).find(|(kind, _)| *kind == NLMSG_ERROR)?;
+    Some(i32::from_ne_bytes(payload.get(..4)?.try_into().ok()?))
+}
+
+/// The statistics of a task sent by the Linux taskstats interface (`linux/taskstats.h`) when it
+/// exits.
+#[derive(Debug, PartialEq, Eq)]
+pub struct TaskExitStats {
+    pub pid: u32,
+    pub name: Vec<u8>,
+    /// In seconds since epoch.
+    pub start_time: u64,
+    /// In microseconds.
+    pub run_time: u64,
+    /// User and system CPU time, in microseconds.
+    pub cpu_time: u64,
+}
+
+/// Parses the netlink messages received from the Linux taskstats interface and returns the
+/// statistics of the exited tasks they contain. Messages about thread groups and malformed
+/// messages are ignored.
+pub fn parse_taskstats_exits(data: &[u8]) -> Vec<TaskExitStats> {
+    // Size of `struct genlmsghdr`.
+    const GENL_HDR_LEN: usize = 4;
+    const NLMSG_NOOP: u16 = 1;
+    const NLMSG_ERROR: u16 = 2;
+    const NLMSG_DONE: u16 = 3;
+    const TASKSTATS_TYPE_PID: u16 = 1;
+    const TASKSTATS_TYPE_STATS: u16 = 3;
+    const TASKSTATS_TYPE_AGGR_PID: u16 = 4;
+    // Offsets of the fields of `struct taskstats`, which are stable since its first version.
+    const AC_COMM: usize = 80;
+    const TS_COMM_LEN: usize = 32;
+    const AC_BTIME: usize = 136;
+    const AC_ETIME: usize = 144;
+    const AC_UTIME: usize = 152;
+    const AC_STIME: usize = 160;
+
+    let u64_at = |data: &[u8], offset: usize| -> Option<u64> {
+        Some(u64::from_ne_bytes(
+            data.get(offset..offset + 8)?.try_into().ok()?,
+        ))
+    };
+
+    let mut exits = Vec::new();
+    for (kind, payload) in netlink_messages(data) {
+        if matches!(kind, NLMSG_NOOP | NLMSG_ERROR | NLMSG_DONE) {
+            continue;
+        }
+        let Some(attributes) = payload.get(GENL_HDR_LEN..) else {
+            continue;
+        };
+        for (_, aggregate) in
+            netlink_attributes(attributes).filter(|(kind, _)| *kind == TASKSTATS_TYPE_AGGR_PID)
+        {
+            let mut pid = None;
+            let mut stats = None;
+            for (kind, value) in netlink_attributes(aggregate) {
+                match kind {
+                    TASKSTATS_TYPE_PID => {
+                        pid = value
+                            .get(..4)
+                            .and_then(|v| Some(u32::from_ne_bytes(v.try_into().ok()?)))
+                    }
+                    TASKSTATS_TYPE_STATS => stats = Some(value),
+                    _ => {}
+                }
+            }
+            let (Some(pid), Some(stats)) = (pid, stats) else {
+                continue;
+            };
+            let (Some(name), Some(start_time), Some(run_time), Some(utime), Some(stime)) = (
+                stats.get(AC_COMM..AC_COMM + TS_COMM_LEN),
+                stats
+                    .get(AC_BTIME..AC_BTIME + 4)
+                    .and_then(|v| Some(u32::from_ne_bytes(v.try_into().ok()?))),
+                u64_at(stats, AC_ETIME),
+                u64_at(stats, AC_UTIME),
+                u64_at(stats, AC_STIME),
+            ) else {
+                continue;
+            };
+            let name_len = name.iter().position(|c| *c == 0).unwrap_or(name.len());
+            exits.push(TaskExitStats {
+                pid,
+                name: name[..name_len].to_vec(),
+                start_time: start_time.into(),
+                run_time,
+                cpu_time: utime.saturating_add(stime),
+            });
+        }
+    }
+    exits
+}
+
+/// A record read from `/dev/kmsg` on Linux.
+#[derive(Debug, PartialEq, Eq)]
+pub struct KmsgRecord {
+    /// Syslog level, from `0` (emergency) to `7` (debug).
+    pub level: u8,
+    pub sequence: u64,
+    /// Time since boot, in microseconds.
+    pub timestamp: u64,
+    /// The message, with its `\xNN` escaped bytes decoded.
+    pub message: String,
+}
+
+/// Parses a record read from `/dev/kmsg` (like `6,1234,5678901,-;message`), each read returning
+/// one record. The continuation lines of the record (its `KEY=value` dictionary) are ignored.
+pub fn parse_kmsg_record(data: &[u8]) -> Option<KmsgRecord> {
+    let separator = data.iter().position(|c| *c == b';')?;
+    let mut fields = data[..separator].split(|c| *c == b',');
+    let mut next_number = || {
+        std::str::from_utf8(fields.next()?)
+            .ok()?
+            .parse::<u64>()
+            .ok()
+    };
+    let priority = next_number()?;
+    let sequence = next_number()?;
+    let timestamp = next_number()?;
+
+    let message = data[separator + 1..]
+        .split(|c| *c == b'\n')
+        .next()
+        .unwrap_or_default();
+    let mut decoded = Vec::with_capacity(message.len());
+    let mut pos = 0;
+    while pos < message.len() {
+        if let Some(escaped) = message.get(pos..pos + 4)
+            && escaped.starts_with(b"\\x")
+            && let Ok(hex) = std::str::from_utf8(&escaped[2..])
+            && let Ok(byte) = u8::from_str_radix(hex, 16)
+        {
+            decoded.push(byte);
+            pos += 4;
+        } else {
+            decoded.push(message[pos]);
+            pos += 1;
+        }
+    }
+    Some(KmsgRecord {
+        level: (priority & 7) as u8,
+        sequence,
+        timestamp,
+        message: String::from_utf8_lossy(&decoded).into_owned(),
+    })
+}
+
+/// Parses the content of `/proc/schedstat` and returns, for each CPU number, the time (in
+/// nanoseconds) tasks spent waiting in its run queue before running.
+pub fn parse_schedstat_run_delays(data: &str) -> Vec<(usize, u64)> {
+    data.lines()
+        .filter_map(|line| {
+            let mut parts = line.split_ascii_whitespace();
+            let cpu = parts.next()?.strip_prefix("cpu")?.parse().ok()?;
+            // `run_delay` is the 8th field after the CPU name.
+            let run_delay = parts.nth(7)?.parse().ok()?;
+            Some((cpu, run_delay))
+        })
+        .collect()
+}
+
+/// Parses a line of `/proc/[pid]/stack` (like `[<0>] do_select+0x5b0/0x7e0`) and returns the
+/// function and its offset, without the address.
+pub fn parse_kernel_stack_line(line: &str) -> Option<&str> {
+    let (_address, frame) = line.strip_prefix("[<")?.split_once(">] ")?;
+    let frame = frame.trim();
+    (!frame.is_empty()).then_some(frame)
+}
+
+/// Parses the beginning of an ELF file (like `/proc/[pid]/exe`) and returns whether it is a
+/// 64-bit ELF (`ELFCLASS64`) and its target machine (`e_machine`).
+pub fn parse_elf_header(data: &[u8]) -> Option<(bool, u16)> {
+    let [0x7f, b'E', b'L', b'F', class, endianness, ..] = *data else {
+        return None;
+    };
+    let is_64_bit = match class {
+        1 => false,
+        2 => true,
+        _ => return None,
+    };
+    let machine = [*data.get(18)?, *data.get(19)?];
+    let machine = match endianness {
+        1 => u16::from_le_bytes(machine),
+        2 => u16::from_be_bytes(machine),
+        _ => return None,
+    };
+    Some((is_64_bit, machine))
+}
+
+/// Parses the content of `/proc/[pid]/maps` and returns the total size (in bytes) of the stack
+/// regions (`[stack]`, or `[stack:<tid>]` on older kernels) and of the `[heap]` regions.
+pub fn parse_maps_stack_and_heap(data: &str) -> (u64, u64) {
+    let mut stack = 0;
+    let mut heap = 0;
+    for line in data.lines() {
+        let mut parts = line.split_ascii_whitespace();
+        let Some((start, end)) = parts.next().and_then(|range| range.split_once('-')) else {
+            continue;
+        };
+        let total = match parts.nth(4) {
+            Some("[heap]") => &mut heap,
+            Some(name) if name == "[stack]" || name.starts_with("[stack:") => &mut stack,
+            _ => continue,
+        };
+        if let (Ok(start), Ok(end)) = (u64::from_str_radix(start, 16), u64::from_str_radix(end, 16))
+        {
+            *total += end.saturating_sub(start);
+        }
+    }
+    (stack, heap)
+}
+
+/// Returns the paths of the files mapped in memory listed in a `/proc/[pid]/maps` file, without
+/// the ` (deleted)` suffix of the deleted (or replaced) files. A file mapped several times is
+/// returned several times.
+pub fn parse_maps_paths(data: &[u8]) -> impl Iterator<Item = &[u8]> {
+    // The fields before the path (addresses, permissions, offset, device and inode) cannot
+    // contain a `/`, contrary to the pseudo-paths like `[heap]`.
+    data.split(|c| *c == b'\n').filter_map(|line| {
+        let path = &line[line.iter().position(|c| *c == b'/')?..];
+        Some(path.strip_suffix(b" (deleted)").unwrap_or(path))
+    })
+}
+
+/// Returns the paths of the deleted (or replaced) files mapped as executable in a
+/// `/proc/[pid]/maps` file, without their ` (deleted)` suffix. Memory file descriptors
+/// (`/memfd:...`), which are always marked as deleted, are skipped. A file mapped several times
+/// is returned several times.
+pub fn parse_maps_deleted_executables(data: &[u8]) -> impl Iterator<Item = &[u8]> {
+    data.split(|c| *c == b'\n').filter_map(|line| {
+        let mut fields = line.split(|c| *c == b' ').filter(|field| !field.is_empty());
+        let permissions = fields.nth(1)?;
+        if permissions.get(2) != Some(&b'x') {
+            return None;
+        }
+        let path = &line[line.iter().position(|c| *c == b'/')?..];
+        let path = path.strip_suffix(b" (deleted)")?;
+        (!path.starts_with(b"/memfd:")).then_some(path)
+    })
+}
+
+/// Parses the content of `/proc/[pid]/cgroup` and returns the cgroup path of the process.
+///
+/// The path of the unified hierarchy (cgroup v2, `0::<path>`) is preferred, then the path of the
+/// systemd hierarchy and finally the path of the first hierarchy (cgroup v1).
+pub fn parse_cgroup(data: &str) -> Option<&str> {
+    let mut systemd = None;
+    let mut first = None;
+    for line in data.lines() {
+        let mut parts = line.splitn(3, ':');
+        let (Some(id), Some(controllers), Some(path)) = (parts.next(), parts.next(), parts.next())
+        else {
+            continue;
+        };
+        if path.is_empty() {
+            continue;
+        }
+        if id == "0" && controllers.is_empty() {
+            return Some(path);
+        } else if controllers == "name=systemd" {
+            systemd.get_or_insert(path);
+        } else {
+            first.get_or_insert(path);
+        }
+    }
+    systemd.or(first)
+}
+
+/// Returns the id of the container in which the processes of the cgroup `path` (as returned by
+/// [`parse_cgroup`]) run, if any.
+///
+/// The id is the last 64 hexadecimal characters component of the path, once the prefixes and
+/// suffixes added by the container runtimes (like `docker-<id>.scope`) are removed.
+pub fn container_id_from_cgroup(path: &str) -> Option<&str> {
+    path.rsplit('/').find_map(|component| {
+        let component = component.strip_suffix(".scope").unwrap_or(component);
+        let id = ["docker-", "cri-containerd-", "crio-", "libpod-"]
+            .iter()
+            .find_map(|prefix| component.strip_prefix(prefix))
+            .unwrap_or(component);
+        (id.len() == 64 && id.bytes().all(|b| b.is_ascii_hexdigit())).then_some(id)
+    })
+}
+
+/// Parses the first entry of a devicetree `compatible` property (like the content of
+/// `/proc/device-tree/compatible`) and returns its manufacturer and its model.
+///
+/// The property contains several `\0` separated strings, from the most specific to the most
+/// generic, and each of them has the `<manufacturer>,<model>` format.
+pub fn parse_devicetree_compatible(data: &[u8]) -> Option<(&str, &str)> {
+    let first = data.split(|&b| b == 0).next()?;
+    let (manufacturer, model) = str::from_utf8(first).ok()?.split_once(',')?;
+    Some((manufacturer.trim(), model.trim()))
+}
+
+/// Parses the throttling flags reported by the firmware of the Raspberry Pi boards, either from
+/// the `get_throttled` sysfs file (like `50005`) or from the output of `vcgencmd get_throttled`
+/// (like `throttled=0x50005`).
+pub fn parse_rpi_throttled(data: &str) -> Option<u32> {
+    let data = data.trim();
+    let data = data.strip_prefix("throttled=").unwrap_or(data);
+    let data = data
+        .strip_prefix("0x")
+        .or_else(|| data.strip_prefix("0X"))
+        .unwrap_or(data);
+    u32::from_str_radix(data, 16).ok()
+}
+
+/// Parses `/proc/net/sockstat` (or `/proc/net/sockstat6`) and returns the protocol, the name
+/// and the value of its counters (like `("TCP", "inuse", 4)`).
+pub fn parse_sockstat(data: &str) -> Vec<(&str, &str, u64)> {
+    let mut counters = Vec::new();
+    for line in data.lines() {
+        let Some((protocol, values)) = line.split_once(':') else {
+            continue;
+        };
+        let mut parts = values.split_ascii_whitespace();
+        while let (Some(name), Some(value)) = (parts.next(), parts.next()) {
+            if let Ok(value) = value.parse() {
+                counters.push((protocol, name, value));
+            }
+        }
+    }
+    counters
+}
+
+/// Parses `/proc/net/snmp` (or `/proc/net/netstat`), where the names of the counters of each
+/// protocol are on a line followed by a line with their values, and returns the protocol, the
+/// name and the value of its counters (like `("Tcp", "CurrEstab", 4)`). Negative values (like
+/// the `-1` of `Tcp: MaxConn`) are skipped.
+pub fn parse_snmp(data: &str) -> Vec<(&str, &str, u64)> {
+    let mut counters = Vec::new();
+    let mut lines = data.lines();
+    while let (Some(names), Some(values)) = (lines.next(), lines.next()) {
+        let (Some((protocol, names)), Some((values_protocol, values))) =
+            (names.split_once(':'), values.split_once(':'))
+        else {
+            break;
+        };
+        if protocol != values_protocol {
+            break;
+        }
+        counters.extend(
+            names
+                .split_ascii_whitespace()
+                .zip(values.split_ascii_whitespace())
+                .filter_map(|(name, value)| Some((protocol, name, value.parse().ok()?))),
+        );
+    }
+    counters
+}
+
+/// Parses `/proc/net/snmp6`, which has one counter per line (like `Ip6InReceives 42`), and
+/// returns the name and the value of its counters.
+pub fn parse_snmp6(data: &str) -> Vec<(&str, u64)> {
+    data.lines()
+        .filter_map(|line| {
+            let mut parts = line.split_ascii_whitespace();
+            let name = parts.next()?;
+            let value = parts.next()?.parse().ok()?;
+            parts.next().is_none().then_some((name, value))
+        })
+        .collect()
+}
+
+/// Parses the content of `/proc/[pid]/schedstat` and returns the time (in nanoseconds) spent
+/// running, the time (in nanoseconds) spent waiting in a run queue and the number of timeslices
+/// run on a CPU.
+pub fn parse_process_schedstat(data: &str) -> Option<(u64, u64, u64)> {
+    let mut parts = data.split_ascii_whitespace().map(|part| part.parse().ok());
+    Some((parts.next()??, parts.next()??, parts.next()??))
+}
+
+/// Parses the content of `/proc/[pid]/io` and returns the number of read system calls, the
+/// number of write system calls and the number of cancelled written bytes.
+pub fn parse_process_io_counters(data: &str) -> Option<(u64, u64, u64)> {
+    let (mut syscr, mut syscw, mut cancelled) = (None, None, None);
+    for line in data.lines() {
+        let Some((name, value)) = line.split_once(':') else {
+            continue;
+        };
+        let field = match name {
+            "syscr" => &mut syscr,
+            "syscw" => &mut syscw,
+            "cancelled_write_bytes" => &mut cancelled,
+            _ => continue,
+        };
+        *field = value.trim().parse().ok();
+    }
+    Some((syscr?, syscw?, cancelled?))
+}
+
+/// Parses the content of the scheduler debug file (`/sys/kernel/debug/sched/debug` or
+/// `/proc/sched_debug`) and returns, for each CPU number, the number of runnable tasks of its
+/// run queue.
+pub fn parse_sched_debug_nr_running(data: &str) -> Vec<(usize, usize)> {
+    let mut cpus = Vec::new();
+    let mut current = None;
+    for line in data.lines() {
+        if let Some(rest) = line.strip_prefix("cpu#") {
+            current = rest
+                .split(|c: char| !c.is_ascii_digit())
+                .next()
+                .and_then(|cpu| cpu.parse().ok());
+            continue;
+        }
+        // Run queues of the scheduling classes come after the one of the CPU and also have a
+        // `.nr_running` field, so only the first one after `cpu#` is kept.
+        if let Some(cpu) = current
+            && let Some((key, value)) = line.split_once(':')
+            && key.trim() == ".nr_running"
+        {
+            if let Ok(value) = value.trim().parse() {
+                cpus.push((cpu, value));
+            }
+            current = None;
+        }
+    }
+    cpus
+}
+
+/// A line of a `/proc/[pid]/mountinfo` file.
+#[derive(Debug, PartialEq, Eq)]
+pub struct MountInfoLine<'a> {
+    pub mount_id: u64,
+    /// Mount point, with its escaped characters (like spaces) restored.
+    pub mount_point: String,
+    /// Per-mount options (like `rw` or `noatime`).
+    pub options: &'a str,
+    pub file_system: &'a str,
+    /// Mounted device (or any other string for virtual file systems), with its escaped
+    /// characters restored.
+    pub source: String,
+    /// Per-superblock options.
+    pub super_options: &'a str,
+}
+
+/// Restores the characters escaped by the kernel in the paths of `/proc` files.
+fn unescape(field: &str) -> String {
+    field
+        .replace("\\134", "\\")
+        .replace("\\040", " ")
+        .replace("\\011", "\t")
+        .replace("\\012", "\n")
+}
+
+/// Parses a line of a `/proc/[pid]/mountinfo` file. Returns `None` if it is malformed.
+pub fn parse_mountinfo_line(line: &str) -> Option<MountInfoLine<'_>> {
+    let mut parts = line.split(' ');
+    let mount_id = parts.next()?.parse().ok()?;
+    // Parent ID, `major:minor` and root of the mount in its file system.
+    let mut parts = parts.skip(3);
+    let mount_point = unescape(parts.next()?);
+    let options = parts.next()?;
+    // Optional fields (like `shared:1`) are ended by a `-`.
+    let mut parts = parts.skip_while(|part| *part != "-").skip(1);
+    let file_system = parts.next()?;
+    let source = unescape(parts.next()?);
+    let super_options = parts.next()?;
+    Some(MountInfoLine {
+        mount_id,
+        mount_point,
+        options,
+        file_system,
+        source,
+        super_options,
+    })
+}
+
+/// The statistics of an NFS mount in `/proc/[pid]/mountstats`, summed over all its operations.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct MountStats<'a> {
+    /// Mount point, with its escaped characters (like spaces) restored.
+    pub mount_point: String,
+    pub file_system: &'a str,
+    pub operations: u64,
+    pub transmissions: u64,
+    pub timeouts: u64,
+    /// Time spent waiting for the replies in milliseconds.
+    pub rtt_ms: u64,
+    /// Time spent from the start of the operations to their end (including the time spent in
+    /// the queue) in milliseconds.
+    pub execute_ms: u64,
+}
+
+/// Parses the content of a `/proc/[pid]/mountstats` file. Only the mounts which have per-operation
+/// statistics (NFS) are returned.
+pub fn parse_mountstats(data: &str) -> Vec<MountStats<'_>> {
+    let mut mounts = Vec::new();
+    let mut current: Option<MountStats<'_>> = None;
+    let mut has_ops = false;
+    for line in data.lines() {
+        if let Some(rest) = line.strip_prefix("device ") {
+            if let Some(mount) = current.take().filter(|_| has_ops) {
+                mounts.push(mount);
+            }
+            has_ops = false;
+            // `device <source> mounted on <mount point> with fstype <file system> ...`
+            let mut parts = rest
+                .split(' ')
+                .skip_while(|part| *part != "mounted")
+                .skip(2);
+            let (Some(mount_point), Some("with"), Some("fstype"), Some(file_system)) =
+                (parts.next(), parts.next(), parts.next(), parts.next())
+            else {
+                continue;
+            };
+            current = Some(MountStats {
+                mount_point: unescape(mount_point),
+                file_system,
+                ..Default::default()
+            });
+            continue;
+        }
+        let Some(mount) = current.as_mut() else {
+            continue;
+        };
+        // Per-operation lines look like `READ: 100 100 0 14000 409600 30 500 600 0`.
+        let Some((name, values)) = line.trim_start().split_once(':') else {
+            continue;
+        };
+        if name.is_empty() || !name.bytes().all(|c| c.is_ascii_uppercase() || c == b'_') {
+            continue;
+        }
+        let values = values
+            .split_ascii_whitespace()
+            .map(|value| value.parse::<u64>().ok())
+            .collect::<Option<Vec<_>>>();
+        let Some(
+            &[
+                operations,
+                transmissions,
+                timeouts,
+                _,
+                _,
+                _,
+                rtt_ms,
+                execute_ms,
+                ..,
+            ],
+        ) = values.as_deref()
+        else {
+            continue;
+        };
+        has_ops = true;
+        mount.operations = mount.operations.saturating_add(operations);
+        mount.transmissions = mount.transmissions.saturating_add(transmissions);
+        mount.timeouts = mount.timeouts.saturating_add(timeouts);
+        mount.rtt_ms = mount.rtt_ms.saturating_add(rtt_ms);
+        mount.execute_ms = mount.execute_ms.saturating_add(execute_ms);
+    }
+    if let Some(mount) = current.filter(|_| has_ops) {
+        mounts.push(mount);
+    }
+    mounts
+}
+
+/// A line of `/proc/swaps`.
+#[derive(Debug, PartialEq, Eq)]
+pub struct SwapsLine {
+    /// Path of the swap partition or file, with its escaped characters restored.
+    pub path: String,
+    /// Size in KiB.
+    pub size: u64,
+    /// Used size in KiB.
+    pub used: u64,
+    pub priority: i32,
+}
+
+/// Parses a line of `/proc/swaps`. Returns `None` if it is malformed (or for the header line).
+pub fn parse_swaps_line(line: &str) -> Option<SwapsLine> {
+    // Whitespaces are escaped in the path so it can be split like the other fields.
+    let mut parts = line.split_ascii_whitespace();
+    let path = unescape(parts.next()?);
+    // The type (`partition` or `file`).
+    parts.next()?;
+    let size = parts.next()?.parse().ok()?;
+    let used = parts.next()?.parse().ok()?;
+    let priority = parts.next()?.parse().ok()?;
+    Some(SwapsLine {
+        path,
+        size,
+        used,
+        priority,
+    })
+}
+
+/// Parses a named kstat file of the ZFS on Linux SPL (like `/proc/spl/kstat/zfs/arcstats`) and
+/// returns the name and the value of its integer statistics.
+pub fn parse_kstat_named(data: &str) -> Vec<(&str, u64)> {
+    data.lines()
+        .filter_map(|line| {
+            let mut parts = line.split_ascii_whitespace();
+            let name = parts.next()?;
+            // The type of the statistic.
+            parts.next()?.parse::<u32>().ok()?;
+            let value = parts.next()?.parse().ok()?;
+            parts.next().is_none().then_some((name, value))
+        })
+        .collect()
+}
+
+/// A Physical Memory Array (type 16) structure of an SMBIOS table.
+#[derive(Debug, PartialEq, Eq)]
+pub struct SmbiosMemoryArray {
+    /// `true` if the array is used as system memory (and not as cache or video memory).
+    pub is_system_memory: bool,
+    /// `Some(true)` if the array uses an error correcting code, `None` if it is unknown.
+    pub ecc: Option<bool>,
+    /// Number of memory slots of the array.
+    pub slot_count: Option<u16>,
+}
+
+/// A Memory Device (type 17) structure of an SMBIOS table.
+#[derive(Debug, PartialEq, Eq)]
+pub struct SmbiosMemoryDevice<'a> {
+    pub locator: Option<&'a str>,
+    /// Size in bytes, `Some(0)` if no module is installed.
+    pub size: Option<u64>,
+    /// Width (in bits) of the module, including the error correction bits.
+    pub total_width: Option<u16>,
+    /// Width (in bits) of the module, without the error correction bits.
+    pub data_width: Option<u16>,
+    /// Speed in megatransfers per second. The configured speed is used if it is available.
+    pub speed: Option<u32>,
+    pub manufacturer: Option<&'a str>,
+    pub part_number: Option<&'a str>,
+}
+
+/// Memory structures of an SMBIOS table.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct SmbiosMemory<'a> {
+    pub arrays: Vec<SmbiosMemoryArray>,
+    pub devices: Vec<SmbiosMemoryDevice<'a>>,
+}
+
+/// Parses an SMBIOS structure table (like `/sys/firmware/dmi/tables/DMI` on Linux) and returns
+/// its memory structures. Parsing stops at the first malformed structure.
+///
+/// The format is described in the [SMBIOS specification][spec].
+///
+/// [spec]: https://www.dmtf.org/sites/default/files/standards/documents/DSP0134_3.6.0.pdf
+pub fn parse_smbios_memory(table: &[u8]) -> SmbiosMemory<'_> {
+    const END_OF_TABLE: u8 = 127;
+
+    let mut memory = SmbiosMemory::default();
+    let mut data = table;
+    while let [kind, len, ..] = *data {
+        let len = len as usize;
+        if kind == END_OF_TABLE || len < 4 || len > data.len() {
+            break;
+        }
+        let formatted = &data[..len];
+        // The strings section follows the formatted section and ends with two `\0`.
+        let Some(strings_len) = data[len..].windows(2).position(|w| w == [0, 0]) else {
+            break;
+        };
+        let strings = data[len..len + strings_len]
+            .split(|&b| b == 0)
+            .map(|s| str::from_utf8(s).map(str::trim).unwrap_or_default())
+            .collect::<Vec<_>>();
+        data = &data[len + strings_len + 2..];
+
+        let u8_at = |offset: usize| formatted.get(offset).copied();
+        let u16_at = |offset: usize| {
+            Some(u16::from_le_bytes(
+                formatted.get(offset..offset + 2)?.try_into().ok()?,
+            ))
+        };
+        let u32_at = |offset: usize| {
+            Some(u32::from_le_bytes(
+                formatted.get(offset..offset + 4)?.try_into().ok()?,
+            ))
+        };
+        // Strings are referenced by their index, starting at 1. `0` means there is no string.
+        let string_at = |offset: usize| {
+            let index = u8_at(offset)? as usize;
+            strings
+                .get(index.checked_sub(1)?)
+                .copied()
+                .filter(|s| !s.is_empty())
+        };
+
+        match kind {
+            16 => memory.arrays.push(SmbiosMemoryArray {
+                is_system_memory: u8_at(0x05) == Some(3),
+                ecc: match u8_at(0x06) {
+                    // Single-bit ECC, multi-bit ECC and CRC.
+                    Some(5..=7) => Some(true),
+                    // None and parity.
+                    Some(3 | 4) => Some(false),
+                    _ => None,
+                },
+                slot_count: u16_at(0x0D),
+            }),
+            17 => {
+                let width = |offset: usize| u16_at(offset).filter(|&width| width != 0xFFFF);
+                let size = match u16_at(0x0C) {
+                    None | Some(0xFFFF) => None,
+                    // The size is in the extended size field, in MiB.
+                    Some(0x7FFF) => {
+                        u32_at(0x1C).map(|size| u64::from(size & 0x7FFF_FFFF) * 1024 * 1024)
+                    }
+                    // The granularity is KiB if the highest bit is set, MiB otherwise.
+                    Some(size) if size & 0x8000 != 0 => Some(u64::from(size & 0x7FFF) * 1024),
+                    Some(size) => Some(u64::from(size) * 1024 * 1024),
+                };
+                // `0xFFFF` means that the speed is in the extended speed field.
+                let speed = |offset: usize, extended_offset: usize| match u16_at(offset)? {
+                    0 => None,
+                    0xFFFF => u32_at(extended_offset).filter(|&speed| speed != 0),
+                    speed => Some(u32::from(speed)),
+                };
+                memory.devices.push(SmbiosMemoryDevice {
+                    locator: string_at(0x10),
+                    size,
+                    total_width: width(0x08),
+                    data_width: width(0x0A),
+                    speed: speed(0x20, 0x58).or_else(|| speed(0x15, 0x54)),
+                    manufacturer: string_at(0x17),
+                    part_number: string_at(0x1A),
+                });
+            }
+            _ => {}
+        }
+    }
+    memory
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_parse_stat_file() {
+        let data = b"1234 (my (weird) exe) S 1 1234 1234 0 -1 4194560 100 0 0 0 12 34 0 0 20 0 \
+            3 0 5678 1000000 200 18446744073709551615";
+        let parts = parse_stat_file(data).unwrap();
+        assert_eq!(parts.short_exe, b"my (weird) exe");
+        assert_eq!(parts.str_parts[ProcIndex::Pid as usize], "1234");
+        assert_eq!(parts.str_parts[ProcIndex::State as usize], "S");
+        assert_eq!(parts.str_parts[ProcIndex::UserTime as usize], "12");
+        assert_eq!(parts.str_parts[ProcIndex::StartTime as usize], "5678");
+        assert_eq!(parts.str_parts[ProcIndex::ResidentSetSize as usize], "200");
+
+        // Truncated or malformed files.
+        assert!(parse_stat_file(b"").is_none());
+        assert!(parse_stat_file(b"1234").is_none());
+        assert!(parse_stat_file(b"1234 (exe").is_none());
+        assert!(parse_stat_file(b"1234 (exe) S 1 1234").is_none());
+        assert!(parse_stat_file(b"\xff (exe) S").is_none());
+    }
+
+    #[test]
+    fn check_parse_redox_ps_line() {
+        let line =
+            "4     1     1     1     0     0     0     0     0     0     1     R       /bin/nulld";
+        assert_eq!(
+            parse_redox_ps_line(line),
+            Some(RedoxPsLine {
+                pid: 4,
+                pgid: Some(1),
+                ppid: Some(1),
+                sid: Some(1),
+                ruid: Some(0),
+                rgid: Some(0),
+                euid: Some(0),
+                egid: Some(0),
+                nthrd: Some(1),
+                status: 'R',
+                name: "/bin/nulld",
+            })
+        );
+        // Lines which are too short or which cut a multi-byte character must not panic.
+        assert_eq!(
+            parse_redox_ps_line("4     1").map(|p| p.pgid),
+            Some(Some(1))
+        );
+        assert_eq!(
+            parse_redox_ps_line("4     1    é").map(|p| p.pgid),
+            Some(None)
+        );
+        assert!(parse_redox_ps_line("").is_none());
+        assert!(parse_redox_ps_line("PID   PGID").is_none());
+    }
+
+    #[test]
+    fn check_parse_redox_context_line() {
+        let line = "1     0     0     1     UB    #3               00:00:00.01 1 MB    /scheme/initfs/bin/init";
+        assert_eq!(
+            parse_redox_context_line(line),
+            Some(RedoxContextLine {
+                pid: 1,
+                euid: Some(0),
+                egid: Some(0),
+                kind: 'U',
+                status: 'B',
+                cpu: 3,
+                time: 10,
+                memory: 1024 * 1024,
+                name: "/scheme/initfs/bin/init",
+            })
+        );
+        let line = "0     0     0     0     RR+   #3               01:02:03.04 23 KB   [kmain]";
+        let parsed = parse_redox_context_line(line).unwrap();
+        assert_eq!(parsed.time, 3_723_040);
+        assert_eq!(parsed.memory, 23 * 1024);
+        assert!(parse_redox_context_line("1     0     0     1     UB").is_some());
+        assert!(parse_redox_context_line("").is_none());
+    }
+
+    #[test]
+    fn check_parse_proc_connector_exits() {
+        fn message(what: u32, fields: [u32; 6]) -> Vec<u8> {
+            let mut payload = Vec::new();
+            // `cn_msg`: idx, val, seq, ack, len + flags.
+            for value in [1u32, 1, 0, 0, 40] {
+                payload.extend(value.to_ne_bytes());
+            }
+            // `proc_event` header: what, cpu, timestamp.
+            payload.extend(what.to_ne_bytes());
+            payload.extend(0u32.to_ne_bytes());
+            payload.extend(0u64.to_ne_bytes());
+            for field in fields {
+                payload.extend(field.to_ne_bytes());
+            }
+            let mut message = Vec::new();
+            message.extend((16 + payload.len() as u32).to_ne_bytes());
+            // Type (`NLMSG_DONE`), flags, seq and pid.
+            message.extend(3u16.to_ne_bytes());
+            message.extend(0u16.to_ne_bytes());
+            message.extend(0u64.to_ne_bytes());
+            message.extend(payload);
+            message
+        }
+
+        let mut data = message(0x8000_0000, [12, 12, 256, 17, 1, 1]);
+        // A fork event, which must be ignored.
+        data.extend(message(1, [1, 1, 13, 13, 0, 0]));
+        data.extend(message(0x8000_0000, [14, 12, 9, 17, 1, 1]));
+        assert_eq!(
+            parse_proc_connector_exits(&data),
+            [
+                ProcExitEvent {
+                    pid: 12,
+                    tgid: 12,
+                    exit_code: 256,
+                    parent_tgid: 1,
+                },
+                ProcExitEvent {
+                    pid: 14,
+                    tgid: 12,
+                    exit_code: 9,
+                    parent_tgid: 1,
+                },
+            ]
+        );
+        // Truncated messages must not panic.
+        for len in 0..data.len() {
+            parse_proc_connector_exits(&data[..len]);
+        }
+    }
+
+    #[test]
+    fn check_parse_taskstats_exits() {
+        fn attribute(kind: u16, value: &[u8]) -> Vec<u8> {
+            let mut attribute = Vec::new();
+            attribute.extend((4 + value.len() as u16).to_ne_bytes());
+            attribute.extend(kind.to_ne_bytes());
+            attribute.extend(value);
+            attribute.resize(attribute.len().next_multiple_of(4), 0);
+            attribute
+        }
+        fn message(kind: u16, payload: &[u8]) -> Vec<u8> {
+            let mut message = Vec::new();
+            message.extend((16 + payload.len() as u32).to_ne_bytes());
+            message.extend(kind.to_ne_bytes());
+            message.extend(0u16.to_ne_bytes());
+            message.extend(0u64.to_ne_bytes());
+            message.extend(payload);
+            message
+        }
+
+        let mut stats = vec![0u8; 328];
+        stats[80..82].copy_from_slice(b"sh");
+        stats[136..140].copy_from_slice(&1_700_000_000u32.to_ne_bytes());
+        stats[144..152].copy_from_slice(&3_000_000u64.to_ne_bytes());
+        stats[152..160].copy_from_slice(&1_500u64.to_ne_bytes());
+        stats[160..168].copy_from_slice(&500u64.to_ne_bytes());
+        let mut aggregate = attribute(1, &42u32.to_ne_bytes());
+        aggregate.extend(attribute(3, &stats));
+        // `genlmsghdr` followed by the `TASKSTATS_TYPE_AGGR_PID` attribute.
+        let mut payload = vec![2, 1, 0, 0];
+        payload.extend(attribute(4, &aggregate));
+        let mut data = message(0x1f, &payload);
+        // An acknowledgment, which must be ignored.
+        data.extend(message(2, &[0; 20]));
+        assert_eq!(
+            parse_taskstats_exits(&data),
+            [TaskExitStats {
+                pid: 42,
+                name: b"sh".to_vec(),
+                start_time: 1_700_000_000,
+                run_time: 3_000_000,
+                cpu_time: 2_000,
+            }]
+        );
+        assert_eq!(parse_netlink_ack(&data), Some(0));
+        // Truncated messages must not panic.
+        for len in 0..data.len() {
+            parse_taskstats_exits(&data[..len]);
+        }
+
+        let mut payload = vec![1, 2, 0, 0];
+        payload.extend(attribute(2, b"TASKSTATS\0"));
+        payload.extend(attribute(1, &0x1fu16.to_ne_bytes()));
+        assert_eq!(parse_genl_family_id(&message(0x10, &payload)), Some(0x1f));
+        assert_eq!(parse_genl_family_id(&message(2, &[0; 20])), None);
+    }
+
+    #[test]
+    fn check_parse_kernel_stack_line() {
+        assert_eq!(
+            parse_kernel_stack_line("[<0>] do_select+0x5b0/0x7e0"),
+            Some("do_select+0x5b0/0x7e0")
+        );
+        assert_eq!(
+            parse_kernel_stack_line("[<ffffffff8110c2a5>] futex_wait_queue_me+0xc5/0x120\n"),
+            Some("futex_wait_queue_me+0xc5/0x120")
+        );
+        assert!(parse_kernel_stack_line("[<0>] ").is_none());
+        assert!(parse_kernel_stack_line("do_select+0x5b0/0x7e0").is_none());
+    }
+
+    #[test]
+    fn check_parse_elf_header() {
+        let mut header = [0; 20];
+        header[..6].copy_from_slice(&[0x7f, b'E', b'L', b'F', 2, 1]);
+        header[18..].copy_from_slice(&62u16.to_le_bytes());
+        assert_eq!(parse_elf_header(&header), Some((true, 62)));
+
+        header[4] = 1;
+        header[5] = 2;
+        header[18..].copy_from_slice(&8u16.to_be_bytes());
+        assert_eq!(parse_elf_header(&header), Some((false, 8)));
+
+        assert!(parse_elf_header(&header[..19]).is_none());
+        header[4] = 3;
+        assert!(parse_elf_header(&header).is_none());
+        assert!(parse_elf_header(b"#!/bin/sh\n").is_none());
+    }
+
+    #[test]
+    fn check_parse_maps_stack_and_heap() {
+        let maps = "\
+55d4c2a00000-55d4c2a2e000 r--p 00000000 fd:01 1234                       /usr/bin/cat
+55d4c2a4e000-55d4c2a6f000 rw-p 00000000 00:00 0                          [heap]
+7f2b1c000000-7f2b1c021000 rw-p 00000000 00:00 0
+7f2b1d5fe000-7f2b1ddfe000 rw-p 00000000 00:00 0                          [stack:4321]
+7ffc4b0e1000-7ffc4b102000 rw-p 00000000 00:00 0                          [stack]
+7ffc4b1d5000-7ffc4b1d9000 r--p 00000000 00:00 0                          [vvar]
+invalid-line [heap]
+";
+        assert_eq!(
+            parse_maps_stack_and_heap(maps),
+            (0x80_0000 + 0x2_1000, 0x2_1000)
+        );
+        assert_eq!(parse_maps_stack_and_heap(""), (0, 0));
+    }
+
+    #[test]
+    fn check_parse_maps_paths() {
+        let maps = b"\
+55d4c2a00000-55d4c2a2e000 r--p 00000000 fd:01 1234                       /usr/bin/cat
+55d4c2a4e000-55d4c2a6f000 rw-p 00000000 00:00 0                          [heap]
+7f2b1c000000-7f2b1c021000 rw-p 00000000 00:00 0
+7f2b1d5fe000-7f2b1ddfe000 r--p 00000000 fd:01 5678                       /tmp/some file (deleted)
+";
+        assert_eq!(
+            parse_maps_paths(maps).collect::<Vec<_>>(),
+            [b"/usr/bin/cat".as_slice(), b"/tmp/some file"],
+        );
+        assert_eq!(parse_maps_paths(b"").count(), 0);
+    }
+
+    #[test]
+    fn check_parse_maps_deleted_executables() {
+        let maps = b"\
+55d4c2a00000-55d4c2a2e000 r-xp 00000000 fd:01 1234                       /usr/bin/cat
+7f2b1c000000-7f2b1c021000 r-xp 00000000 fd:01 5678                       /usr/lib/libc.so.6 (deleted)
+7f2b1c021000-7f2b1c030000 r--p 00021000 fd:01 5678                       /usr/lib/libc.so.6 (deleted)
+7f2b1d5fe000-7f2b1ddfe000 r-xp 00000000 00:01 42                         /memfd:jit (deleted)
+7f2b1e000000-7f2b1e001000 r-xp 00000000 00:00 0                          [vdso]
+7f2b1e001000-7f2b1e002000 r-x
+";
+        assert_eq!(
+            parse_maps_deleted_executables(maps).collect::<Vec<_>>(),
+            [b"/usr/lib/libc.so.6".as_slice()],
+        );
+        assert_eq!(parse_maps_deleted_executables(b"").count(), 0);
+    }
+
+    #[test]
+    fn check_parse_kmsg_record() {
+        assert_eq!(
+            parse_kmsg_record(b"3,1234,5678901,-;Out of memory: Killed process 42\n"),
+            Some(KmsgRecord {
+                level: 3,
+                sequence: 1234,
+                timestamp: 5678901,
+                message: "Out of memory: Killed process 42".to_owned(),
+            }),
+        );
+        // The facility is removed from the priority, the escaped bytes are decoded and the
+        // dictionary is ignored.
+        assert_eq!(
+            parse_kmsg_record(
+                b"12,7,100,c,caller=T1;tab\\x09here \\xZZ\n SUBSYSTEM=pci\n DEVICE=+pci:0000:00:01.0\n"
+            ),
+            Some(KmsgRecord {
+                level: 4,
+                sequence: 7,
+                timestamp: 100,
+                message: "tab\there \\xZZ".to_owned(),
+            }),
+        );
+        assert_eq!(parse_kmsg_record(b"6,1;no timestamp"), None);
+        assert_eq!(parse_kmsg_record(b"6,1,2,-"), None);
+        assert_eq!(parse_kmsg_record(b""), None);
+    }
+
+    #[test]
+    fn check_parse_redox_log_line() {
+        assert_eq!(
+            parse_redox_log_line("kernel::acpi:WARN -- no HPET\n"),
+            (4, "kernel::acpi:WARN -- no HPET"),
+        );
+        assert_eq!(
+            parse_redox_log_line("kernel:ERROR -- failed"),
+            (3, "kernel:ERROR -- failed")
+        );
+        assert_eq!(parse_redox_log_line("plain message"), (6, "plain message"));
+        assert_eq!(parse_redox_log_line(""), (6, ""));
+    }
+
+    #[test]
+    fn check_parse_cgroup() {
+        assert_eq!(
+            parse_cgroup("0::/user.slice/user-1000.slice/session-2.scope\n"),
+            Some("/user.slice/user-1000.slice/session-2.scope")
+        );
+        let v1 = "\
+12:memory:/docker/abc
+11:cpu,cpuacct:/docker/abc
+1:name=systemd:/system.slice/docker.service
+";
+        assert_eq!(parse_cgroup(v1), Some("/system.slice/docker.service"));
+        assert_eq!(
+            parse_cgroup("12:memory:/docker/abc\n0::/init.scope\n"),
+            Some("/init.scope")
+        );
+        assert_eq!(parse_cgroup("12:memory:/docker/abc\n"), Some("/docker/abc"));
+        assert!(parse_cgroup("").is_none());
+        assert!(parse_cgroup("invalid\n0::\n").is_none());
+    }
+
+    #[test]
+    fn check_container_id_from_cgroup() {
+        let id = "4e3b1c2d5f6a7b8c9d0e1f2a3b4c5d6e7f8091a2b3c4d5e6f708192a3b4c5d6e";
+        assert_eq!(container_id_from_cgroup(&format!("/docker/{id}")), Some(id));
+        assert_eq!(
+            container_id_from_cgroup(&format!("/system.slice/docker-{id}.scope")),
+            Some(id)
+        );
+        assert_eq!(
+            container_id_from_cgroup(&format!(
+                "/kubepods.slice/kubepods-pod1.slice/cri-containerd-{id}.scope"
+            )),
+            Some(id)
+        );
+        assert_eq!(
+            container_id_from_cgroup(&format!("/machine.slice/libpod-{id}.scope/container")),
+            Some(id)
+        );
+        assert!(container_id_from_cgroup("/user.slice/user-1000.slice/session-2.scope").is_none());
+        assert!(container_id_from_cgroup(&format!("/docker/{}", &id[1..])).is_none());
+        assert!(container_id_from_cgroup("").is_none());
+    }
+
+    #[test]
+    fn check_parse_devicetree_compatible() {
+        assert_eq!(
+            parse_devicetree_compatible(b"raspberrypi,4-model-b\0brcm,bcm2711\0"),
+            Some(("raspberrypi", "4-model-b"))
+        );
+        assert_eq!(
+            parse_devicetree_compatible(b"sifive,u74-mc\0riscv\0"),
+            Some(("sifive", "u74-mc"))
+        );
+        assert!(parse_devicetree_compatible(b"riscv\0").is_none());
+        assert!(parse_devicetree_compatible(b"").is_none());
+        assert!(parse_devicetree_compatible(b"\xff,\xfe").is_none());
+    }
+
+    #[test]
+    fn check_parse_rpi_throttled() {
+        assert_eq!(parse_rpi_throttled("50005\n"), Some(0x50005));
+        assert_eq!(parse_rpi_throttled("throttled=0x50005\n"), Some(0x50005));
+        assert_eq!(parse_rpi_throttled("0"), Some(0));
+        assert!(parse_rpi_throttled("").is_none());
+        assert!(parse_rpi_throttled("throttled=").is_none());
+        assert!(parse_rpi_throttled("throttled=0xzz").is_none());
+    }
+
+    #[test]
+    fn check_parse_sockstat() {
+        const DATA: &str = "\
+sockets: used 16
+TCP: inuse 4 orphan 1 tw 2 alloc 5 mem 0
+UDP: inuse 3 mem 0
+FRAG: inuse 0 memory 0
+broken
+";
+        let counters = parse_sockstat(DATA);
+        assert_eq!(counters[0], ("sockets", "used", 16));
+        assert!(counters.contains(&("TCP", "orphan", 1)));
+        assert!(counters.contains(&("TCP", "tw", 2)));
+        assert!(counters.contains(&("UDP", "inuse", 3)));
+        assert!(counters.contains(&("FRAG", "memory", 0)));
+        assert_eq!(counters.len(), 10);
+        assert!(parse_sockstat("TCP: inuse").is_empty());
+    }
+
+    #[test]
+    fn check_parse_snmp() {
+        const DATA: &str = "\
+Tcp: RtoAlgorithm RtoMin RtoMax MaxConn ActiveOpens CurrEstab
+Tcp: 1 200 120000 -1 17 4
+Udp: InDatagrams NoPorts
+Udp: 12 3
+";
+        let counters = parse_snmp(DATA);
+        assert_eq!(
+            counters,
+            [
+                ("Tcp", "RtoAlgorithm", 1),
+                ("Tcp", "RtoMin", 200),
+                ("Tcp", "RtoMax", 120000),
+                ("Tcp", "ActiveOpens", 17),
+                ("Tcp", "CurrEstab", 4),
+                ("Udp", "InDatagrams", 12),
+                ("Udp", "NoPorts", 3),
+            ]
+        );
+        // The values of a protocol must follow its names.
+        assert!(parse_snmp("Tcp: CurrEstab\nUdp: 4\n").is_empty());
+        assert!(parse_snmp("Tcp: CurrEstab\n").is_empty());
+    }
+
+    #[test]
+    fn check_parse_snmp6() {
+        const DATA: &str = "\
+Ip6InReceives                   \t42
+Ip6InHdrErrors                  \t1
+Icmp6InErrors                   0
+broken
+Udp6NoPorts 3 4
+";
+        assert_eq!(
+            parse_snmp6(DATA),
+            [
+                ("Ip6InReceives", 42),
+                ("Ip6InHdrErrors", 1),
+                ("Icmp6InErrors", 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn check_parse_process_schedstat() {
+        assert_eq!(
+            parse_process_schedstat("1234567890 98765 42\n"),
+            Some((1_234_567_890, 98_765, 42))
+        );
+        assert!(parse_process_schedstat("1234567890 98765\n").is_none());
+        assert!(parse_process_schedstat("a b c").is_none());
+    }
+
+    #[test]
+    fn check_parse_process_io_counters() {
+        let data = "rchar: 323934931\nwchar: 323929600\nsyscr: 632687\nsyscw: 632675\n\
+                    read_bytes: 0\nwrite_bytes: 323932160\ncancelled_write_bytes: 4096\n";
+        assert_eq!(
+            parse_process_io_counters(data),
+            Some((632_687, 632_675, 4096))
+        );
+        assert!(parse_process_io_counters("syscr: 1\nsyscw: 2\n").is_none());
+        assert!(
+            parse_process_io_counters("syscr: a\nsyscw: 2\ncancelled_write_bytes: 0").is_none()
+        );
+    }
+
+    #[test]
+    fn check_parse_schedstat_run_delays() {
+        let data = "version 15\ntimestamp 4295628624\n\
+            cpu0 0 0 0 0 0 0 1234567 89012 345\n\
+            domain0 00000003 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0\n\
+            cpu1 0 0 0 0 0 0 7654321 21098 543\n\
+            cpu2 0 0 0\n";
+        assert_eq!(parse_schedstat_run_delays(data), [(0, 89012), (1, 21098)]);
+        assert!(parse_schedstat_run_delays("").is_empty());
+    }
+
+    #[test]
+    fn check_parse_sched_debug_nr_running() {
+        let data = "Sched Debug Version: v0.11, 6.8.0 #1\n\
+            \n\
+            cpu#0, 2893.202 MHz\n\
+            \x20 .nr_running                    : 2\n\
+            \x20 .nr_switches                   : 1234\n\
+            \n\
+            cfs_rq[0]:/\n\
+            \x20 .nr_running                    : 1\n\
+            \n\
+            cpu#1\n\
+            \x20 .nr_running                    : 0\n";
+        assert_eq!(parse_sched_debug_nr_running(data), [(0, 2), (1, 0)]);
+        assert!(parse_sched_debug_nr_running("cpu#").is_empty());
+        assert!(parse_sched_debug_nr_running(".nr_running : 3").is_empty());
+    }
+
+    #[test]
+    fn check_parse_mountinfo_line() {
+        assert_eq!(
+            parse_mountinfo_line(
+                "36 35 98:0 / /media/my\\040usb rw,noatime master:1 shared:2 - vfat /dev/sdb1 \
+                 rw,errors=remount-ro"
+            ),
+            Some(MountInfoLine {
+                mount_id: 36,
+                mount_point: "/media/my usb".to_owned(),
+                options: "rw,noatime",
+                file_system: "vfat",
+                source: "/dev/sdb1".to_owned(),
+                super_options: "rw,errors=remount-ro",
+            })
+        );
+        assert_eq!(
+            parse_mountinfo_line("22 1 0:21 / /proc ro - proc proc rw").map(|line| line.options),
+            Some("ro")
+        );
+        assert!(parse_mountinfo_line("").is_none());
+        assert!(parse_mountinfo_line("36 35 98:0 / /mnt rw shared:2 vfat /dev/sdb1 rw").is_none());
+    }
+
+    #[test]
+    fn check_parse_mountstats() {
+        let data = "device proc mounted on /proc with fstype proc\n\
+            device server:/export mounted on /mnt/my\\040nfs with fstype nfs4 statvers=1.1\n\
+            \topts:\trw,vers=4.2,rsize=1048576,wsize=1048576,hard,proto=tcp\n\
+            \tage:\t3600\n\
+            \tRPC iostats version: 1.1  p/v: 100003/4 (nfs)\n\
+            \txprt:\ttcp 0 1 0 0 0 1234 1234 0 5678 0 2 0 0\n\
+            \tper-op statistics\n\
+            \t        NULL: 1 1 0 44 24 0 0 0 0\n\
+            \t        READ: 100 103 2 14000 409600 30 500 600 0\n\
+            \t       WRITE: 50 50 0 204800 7200 10 250 300 1\n\
+            \n\
+            device //server/share mounted on /mnt/smb with fstype cifs\n";
+        assert_eq!(
+            parse_mountstats(data),
+            [MountStats {
+                mount_point: "/mnt/my nfs".to_owned(),
+                file_system: "nfs4",
+                operations: 151,
+                transmissions: 154,
+                timeouts: 2,
+                rtt_ms: 750,
+                execute_ms: 900,
+            }]
+        );
+        assert!(parse_mountstats("\tREAD: 1 1 0 0 0 0 0 0 0").is_empty());
+        assert!(parse_mountstats("device a mounted on").is_empty());
+    }
+
+    #[test]
+    fn check_parse_swaps_line() {
+        assert_eq!(
+            parse_swaps_line(
+                "/swap\\040file                             file\t\t8388604\t\t0\t\t-2"
+            ),
+            Some(SwapsLine {
+                path: "/swap file".to_owned(),
+                size: 8_388_604,
+                used: 0,
+                priority: -2,
+            })
+        );
+        assert_eq!(
+            parse_swaps_line("/dev/zram0 partition\t4194300\t4194300\t100").map(|line| line.used),
+            Some(4_194_300)
+        );
+        assert!(parse_swaps_line("Filename\t\t\t\tType\t\tSize\t\tUsed\t\tPriority").is_none());
+        assert!(parse_swaps_line("").is_none());
+    }
+
+    #[test]
+    fn check_parse_kstat_named() {
+        let data = "13 1 0x01 147 39984 5208014519 2184093524811\n\
+            name                            type data\n\
+            hits                            4    3452810\n\
+            misses                          4    51344\n\
+            size                            4    4189634048\n";
+        assert_eq!(
+            parse_kstat_named(data),
+            [
+                ("hits", 3_452_810),
+                ("misses", 51_344),
+                ("size", 4_189_634_048)
+            ]
+        );
+        assert!(parse_kstat_named("hits 4").is_empty());
+        assert!(parse_kstat_named("hits 4 -1").is_empty());
+    }
+
+    #[test]
+    fn check_parse_smbios_memory() {
+        fn structure(kind: u8, formatted: &[u8], strings: &[&str]) -> Vec<u8> {
+            let mut data = vec![kind, formatted.len() as u8 + 4, 0, 0];
+            data.extend(formatted);
+            for string in strings {
+                data.extend(string.as_bytes());
+                data.push(0);
+            }
+            if strings.is_empty() {
+                data.push(0);
+            }
+            data.push(0);
+            data
+        }
+
+        // A physical memory array used as system memory, with multi-bit ECC and 4 slots.
+        let mut table = structure(16, &[3, 3, 6, 0, 0, 0, 0, 0xFE, 0xFF, 4, 0], &[]);
+        // An installed 16 GiB module at 3200 MT/s, with ECC bits.
+        let mut device = vec![0; 0x28 - 4];
+        device[0x08 - 4..0x0A - 4].copy_from_slice(&72u16.to_le_bytes());
+        device[0x0A - 4..0x0C - 4].copy_from_slice(&64u16.to_le_bytes());
+        device[0x0C - 4..0x0E - 4].copy_from_slice(&16384u16.to_le_bytes());
+        device[0x10 - 4] = 1;
+        device[0x15 - 4..0x17 - 4].copy_from_slice(&3200u16.to_le_bytes());
+        device[0x17 - 4] = 2;
+        device[0x1A - 4] = 3;
+        table.extend(structure(
+            17,
+            &device,
+            &["DIMM_A1", "Samsung", " M393A2K43DB3-CWE "],
+        ));
+        // An empty slot, described with an older (and shorter) structure.
+        let mut device = vec![0; 0x15 - 4];
+        device[0x10 - 4] = 1;
+        table.extend(structure(17, &device, &["DIMM_A2"]));
+        // An unrelated structure and the end of the table.
+        table.extend(structure(1, &[1, 0, 0, 0], &["vendor"]));
+        table.extend(structure(127, &[], &[]));
+        table.extend(structure(17, &device, &["ignored"]));
+
+        let memory = parse_smbios_memory(&table);
+        assert_eq!(
+            memory.arrays,
+            [SmbiosMemoryArray {
+                is_system_memory: true,
+                ecc: Some(true),
+                slot_count: Some(4),
+            }]
+        );
+        assert_eq!(
+            memory.devices,
+            [
+                SmbiosMemoryDevice {
+                    locator: Some("DIMM_A1"),
+                    size: Some(16 * 1024 * 1024 * 1024),
+                    total_width: Some(72),
+                    data_width: Some(64),
+                    speed: Some(3200),
+                    manufacturer: Some("Samsung"),
+                    part_number: Some("M393A2K43DB3-CWE"),
+                },
+                SmbiosMemoryDevice {
+                    locator: Some("DIMM_A2"),
+                    size: Some(0),
+                    total_width: Some(0),
+                    data_width: Some(0),
+                    speed: None,
+                    manufacturer: None,
+                    part_number: None,
+                },
+            ]
+        );
+        // Truncated tables must not panic.
+        for len in 0..table.len() {
+            parse_smbios_memory(&table[..len]);
+        }
+    }
+}
@@ -0,0 +1,119 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+//! Functions to retrieve a single value without creating and refreshing a [`System`].
+//!
+//! They are meant for tools which only need one or two values at startup. If you need to
+//! retrieve the same information more than once, it is cheaper to keep a [`System`] around.
+//!
+//! ```no_run
+//! use sysinfo::quick;
+//!
+//! println!("total memory: {} bytes", quick::total_memory());
+//! println!("CPUs:         {}", quick::cpu_count());
+//! println!("boot time:    {}", quick::boot_time());
+//! ```
+//!
+//! [`System`]: crate::System
+
+use crate::{CpuRefreshKind, MemoryRefreshKind, SystemInner};
+
+/// Returns the RAM size in bytes.
+///
+/// It is the same as [`System::total_memory`][crate::System::total_memory].
+///
+/// **Important**: this information is computed every time this function is called.
+///
+/// ```no_run
+/// println!("{} bytes", sysinfo::quick::total_memory());
+/// ```
+pub fn total_memory() -> u64 {
+    let mut inner = SystemInner::new();
+    inner.refresh_memory_specifics(MemoryRefreshKind::nothing().with_ram());
+    inner.total_memory()
+}
+
+/// Returns the amount of available RAM in bytes.
+///
+/// It is the same as [`System::available_memory`][crate::System::available_memory].
+///
+/// **Important**: this information is computed every time this function is called.
+///
+/// ```no_run
+/// println!("{} bytes", sysinfo::quick::available_memory());
+/// ```
+pub fn available_memory() -> u64 {
+    let mut inner = SystemInner::new();
+    inner.refresh_memory_specifics(MemoryRefreshKind::nothing().with_ram());
+    inner.available_memory()
+}
+
+/// Returns the number of logical CPUs.
+///
+/// It is the same as the length of [`System::cpus`][crate::System::cpus] after a call to
+/// [`System::refresh_cpu_list`][crate::System::refresh_cpu_list].
+///
+/// **Important**: this information is computed every time this function is called.
+///
+/// ```no_run
+/// println!("{} CPUs", sysinfo::quick::cpu_count());
+/// ```
+pub fn cpu_count() -> usize {
+    let mut inner = SystemInner::new();
+    inner.refresh_cpu_list(CpuRefreshKind::nothing());
+    inner.cpus().len()
+}
+
+/// Returns the number of physical cores on the CPU or `None` if it couldn't get it.
+///
+/// It is the same as [`System::physical_core_count`][crate::System::physical_core_count].
+///
+/// **Important**: this information is computed every time this function is called.
+///
+/// ```no_run
+/// println!("{:?} physical cores", sysinfo::quick::physical_core_count());
+/// ```
+pub fn physical_core_count() -> Option<usize> {
+    SystemInner::physical_core_count()
+}
+
+/// Returns the time (in seconds) when the system booted since UNIX epoch.
+///
+/// It is the same as [`System::boot_time`][crate::System::boot_time].
+///
+/// ```no_run
+/// println!("booted at {}", sysinfo::quick::boot_time());
+/// ```
+pub fn boot_time() -> u64 {
+    SystemInner::boot_time()
+}
+
+/// Returns system uptime (in seconds).
+///
+/// It is the same as [`System::uptime`][crate::System::uptime].
+///
+/// ```no_run
+/// println!("up for {} seconds", sysinfo::quick::uptime());
+/// ```
+pub fn uptime() -> u64 {
+    SystemInner::uptime()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CpuRefreshKind, MemoryRefreshKind, System};
+
+    #[test]
+    fn check_quick_values() {
+        if !crate::IS_SUPPORTED_SYSTEM {
+            return;
+        }
+        let mut s = System::new();
+        s.refresh_memory_specifics(MemoryRefreshKind::nothing().with_ram());
+        s.refresh_cpu_list(CpuRefreshKind::nothing());
+
+        assert_eq!(super::total_memory(), s.total_memory());
+        assert_eq!(super::cpu_count(), s.cpus().len());
+        assert!(super::cpu_count() > 0);
+        assert!(super::boot_time().abs_diff(System::boot_time()) <= 1);
+    }
+}
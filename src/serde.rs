@@ -1,10 +1,13 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
 #[cfg(any(
+    feature = "audio",
     feature = "component",
     feature = "disk",
+    feature = "display",
     feature = "network",
     feature = "system",
+    feature = "usb",
     feature = "user"
 ))]
 use serde::{Serialize, Serializer, ser::SerializeStruct};
@@ -32,6 +35,61 @@ impl Serialize for crate::Disk {
     }
 }
 
+#[cfg(feature = "disk")]
+impl Serialize for crate::DiskIoStats {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `4` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("DiskIoStats", 4)?;
+
+        state.serialize_field("average_latency", &self.average_latency())?;
+        state.serialize_field("in_flight", &self.in_flight())?;
+        state.serialize_field("utilization", &self.utilization())?;
+        state.serialize_field("average_queue_depth", &self.average_queue_depth())?;
+
+        state.end()
+    }
+}
+
+#[cfg(feature = "disk")]
+impl Serialize for crate::NetworkFsStats {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `5` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("NetworkFsStats", 5)?;
+
+        state.serialize_field("operations", &self.operations())?;
+        state.serialize_field("retransmissions", &self.retransmissions())?;
+        state.serialize_field("timeouts", &self.timeouts())?;
+        state.serialize_field("average_rtt", &self.average_rtt())?;
+        state.serialize_field("average_execution_time", &self.average_execution_time())?;
+
+        state.end()
+    }
+}
+
+#[cfg(feature = "disk")]
+impl Serialize for crate::DiskSpace {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `4` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("DiskSpace", 4)?;
+
+        state.serialize_field("mount_point", &self.mount_point())?;
+        state.serialize_field("total_space", &self.total_space())?;
+        state.serialize_field("available_space", &self.available_space())?;
+        state.serialize_field("used_space", &self.used_space())?;
+
+        state.end()
+    }
+}
+
 #[cfg(feature = "disk")]
 impl Serialize for crate::Disks {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -62,6 +120,43 @@ impl Serialize for crate::DiskKind {
     }
 }
 
+#[cfg(feature = "disk")]
+impl Serialize for crate::DiskEvent {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `5` corresponds to the (maximum) number of fields.
+        let mut state = serializer.serialize_struct("DiskEvent", 5)?;
+
+        state.serialize_field("kind", &self.kind())?;
+        if let Some(s) = self.name().to_str() {
+            state.serialize_field("name", s)?;
+        }
+        state.serialize_field("mount_point", &self.mount_point())?;
+        state.serialize_field("file_system", &self.file_system())?;
+        state.serialize_field("is_read_only", &self.is_read_only())?;
+
+        state.end()
+    }
+}
+
+#[cfg(feature = "disk")]
+impl Serialize for crate::DiskEventKind {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (index, variant) = match *self {
+            Self::Mounted => (0, "Mounted"),
+            Self::Unmounted => (1, "Unmounted"),
+            Self::Remounted => (2, "Remounted"),
+        };
+
+        serializer.serialize_unit_variant("DiskEventKind", index, variant)
+    }
+}
+
 #[cfg(feature = "system")]
 impl Serialize for crate::Pid {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -78,8 +173,8 @@ impl Serialize for crate::Process {
     where
         S: Serializer,
     {
-        // `19` corresponds to the (maximum) number of fields.
-        let mut state = serializer.serialize_struct("Process", 19)?;
+        // `20` corresponds to the (maximum) number of fields.
+        let mut state = serializer.serialize_struct("Process", 20)?;
 
         state.serialize_field("name", &self.name().to_string_lossy())?;
         state.serialize_field("cmd", &self.cmd())?;
@@ -96,6 +191,7 @@ impl Serialize for crate::Process {
         state.serialize_field("run_time", &self.run_time())?;
         state.serialize_field("cpu_usage", &self.cpu_usage())?;
         state.serialize_field("accumulated_cpu_time", &self.accumulated_cpu_time())?;
+        state.serialize_field("children_cpu_time", &self.children_cpu_time())?;
         state.serialize_field("disk_usage", &self.disk_usage())?;
         state.serialize_field("user_id", &self.user_id())?;
         state.serialize_field("group_id", &self.group_id())?;
@@ -111,14 +207,19 @@ impl Serialize for crate::Cpu {
     where
         S: Serializer,
     {
-        // `5` corresponds to the number of fields.
-        let mut state = serializer.serialize_struct("Cpu", 5)?;
+        // `10` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("Cpu", 10)?;
 
         state.serialize_field("cpu_usage", &self.cpu_usage())?;
         state.serialize_field("name", &self.name())?;
         state.serialize_field("vendor_id", &self.vendor_id())?;
         state.serialize_field("brand", &self.brand())?;
         state.serialize_field("frequency", &self.frequency())?;
+        state.serialize_field("boost_active", &self.boost_active())?;
+        state.serialize_field("run_queue_length", &self.run_queue_length())?;
+        state.serialize_field("run_queue_wait_time", &self.run_queue_wait_time())?;
+        state.serialize_field("idle_states", &self.idle_states())?;
+        state.serialize_field("peak_usage", &self.peak_usage())?;
 
         state.end()
     }
@@ -198,6 +299,23 @@ impl serde::Serialize for crate::Product {
     }
 }
 
+#[cfg(feature = "system")]
+impl Serialize for crate::SecurityInfo {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `3` corresponds to the number of "fields".
+        let mut state = serializer.serialize_struct("SecurityInfo", 3)?;
+
+        state.serialize_field("secure_boot_enabled", &Self::secure_boot_enabled())?;
+        state.serialize_field("tpm_present", &Self::tpm_present())?;
+        state.serialize_field("root_disk_encrypted", &Self::root_disk_encrypted())?;
+
+        state.end()
+    }
+}
+
 #[cfg(feature = "system")]
 impl Serialize for crate::CGroupLimits {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -215,6 +333,60 @@ impl Serialize for crate::CGroupLimits {
     }
 }
 
+#[cfg(feature = "system")]
+impl Serialize for crate::CGroupUsage {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `4` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("CGroupUsage", 4)?;
+
+        state.serialize_field("container_id", &self.container_id)?;
+        state.serialize_field("process_count", &self.process_count)?;
+        state.serialize_field("cpu_usage", &self.cpu_usage)?;
+        state.serialize_field("memory", &self.memory)?;
+
+        state.end()
+    }
+}
+
+#[cfg(feature = "system")]
+impl Serialize for crate::ExeUsage {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `3` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("ExeUsage", 3)?;
+
+        state.serialize_field("pids", &self.pids)?;
+        state.serialize_field("cpu_usage", &self.cpu_usage)?;
+        state.serialize_field("memory", &self.memory)?;
+
+        state.end()
+    }
+}
+
+#[cfg(feature = "system")]
+impl Serialize for crate::PathUsage {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `5` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("PathUsage", 5)?;
+
+        state.serialize_field("cwd", &self.cwd)?;
+        state.serialize_field("root", &self.root)?;
+        state.serialize_field("exe", &self.exe)?;
+        state.serialize_field("open_file", &self.open_file)?;
+        state.serialize_field("mapped", &self.mapped)?;
+
+        state.end()
+    }
+}
+
 #[cfg(feature = "system")]
 impl Serialize for crate::ThreadKind {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -230,6 +402,64 @@ impl Serialize for crate::ThreadKind {
     }
 }
 
+#[cfg(feature = "system")]
+impl Serialize for crate::ExeSignature {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match *self {
+            Self::Authenticode(ref signer) => {
+                serializer.serialize_newtype_variant("ExeSignature", 0, "Authenticode", signer)
+            }
+            Self::TeamId(ref team_id) => {
+                serializer.serialize_newtype_variant("ExeSignature", 1, "TeamId", team_id)
+            }
+            Self::Sha256(ref hash) => {
+                serializer.serialize_newtype_variant("ExeSignature", 2, "Sha256", hash)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "system")]
+impl Serialize for crate::ExeMetadata {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `4` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("ExeMetadata", 4)?;
+
+        state.serialize_field("build_id", &self.build_id())?;
+        state.serialize_field("interpreter", &self.interpreter())?;
+        state.serialize_field("link_time", &self.link_time())?;
+        state.serialize_field("is_stripped", &self.is_stripped())?;
+
+        state.end()
+    }
+}
+
+#[cfg(feature = "system")]
+impl Serialize for crate::QosClass {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (index, variant) = match *self {
+            Self::UserInteractive => (0, "UserInteractive"),
+            Self::UserInitiated => (1, "UserInitiated"),
+            Self::Default => (2, "Default"),
+            Self::Utility => (3, "Utility"),
+            Self::Background => (4, "Background"),
+            Self::Maintenance => (5, "Maintenance"),
+            Self::Legacy => (6, "Legacy"),
+        };
+
+        serializer.serialize_unit_variant("QosClass", index, variant)
+    }
+}
+
 #[cfg(feature = "system")]
 impl Serialize for crate::Signal {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -339,160 +569,816 @@ impl Serialize for crate::DiskUsage {
     }
 }
 
-#[cfg(feature = "component")]
-impl Serialize for crate::Components {
+#[cfg(feature = "system")]
+impl Serialize for crate::CpuIdleState {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        serializer.collect_seq(self.iter())
+        // `7` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("CpuIdleState", 7)?;
+
+        state.serialize_field("name", &self.name())?;
+        state.serialize_field("description", &self.description())?;
+        state.serialize_field("exit_latency", &self.exit_latency())?;
+        state.serialize_field("target_residency", &self.target_residency())?;
+        state.serialize_field("entry_count", &self.entry_count())?;
+        state.serialize_field("residency", &self.residency())?;
+        state.serialize_field("is_disabled", &self.is_disabled())?;
+
+        state.end()
     }
 }
 
-#[cfg(feature = "component")]
-impl Serialize for crate::Component {
+#[cfg(feature = "system")]
+impl Serialize for crate::CpuVulnerability {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        // `4` corresponds to the number of fields.
-        let mut state = serializer.serialize_struct("Component", 4)?;
+        // `3` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("CpuVulnerability", 3)?;
 
-        state.serialize_field("temperature", &self.temperature())?;
-        state.serialize_field("max", &self.max())?;
-        state.serialize_field("critical", &self.critical())?;
-        state.serialize_field("label", &self.label())?;
+        state.serialize_field("name", &self.name())?;
+        state.serialize_field("status", &self.status())?;
+        state.serialize_field("details", &self.details())?;
 
         state.end()
     }
 }
 
-#[cfg(feature = "network")]
-impl Serialize for crate::Networks {
+#[cfg(feature = "system")]
+impl Serialize for crate::FirewallProfile {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        serializer.collect_seq(self.iter())
+        // `2` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("FirewallProfile", 2)?;
+
+        state.serialize_field("name", &self.name())?;
+        state.serialize_field("is_enabled", &self.is_enabled())?;
+
+        state.end()
     }
 }
 
-#[cfg(feature = "network")]
-impl Serialize for crate::NetworkData {
+#[cfg(feature = "system")]
+impl Serialize for crate::SwapDevice {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        // `14` corresponds to the number of fields.
-        let mut state = serializer.serialize_struct("NetworkData", 14)?;
+        // `4` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("SwapDevice", 4)?;
 
-        state.serialize_field("received", &self.received())?;
-        state.serialize_field("total_received", &self.total_received())?;
-        state.serialize_field("transmitted", &self.transmitted())?;
-        state.serialize_field("total_transmitted", &self.total_transmitted())?;
-        state.serialize_field("packets_received", &self.packets_received())?;
-        state.serialize_field("total_packets_received", &self.total_packets_received())?;
-        state.serialize_field("packets_transmitted", &self.packets_transmitted())?;
-        state.serialize_field(
-            "total_packets_transmitted",
-            &self.total_packets_transmitted(),
-        )?;
-        state.serialize_field("errors_on_received", &self.errors_on_received())?;
-        state.serialize_field("total_errors_on_received", &self.total_errors_on_received())?;
-        state.serialize_field("errors_on_transmitted", &self.errors_on_transmitted())?;
-        state.serialize_field(
-            "total_errors_on_transmitted",
-            &self.total_errors_on_transmitted(),
-        )?;
-        state.serialize_field("mac_address", &self.mac_address())?;
-        state.serialize_field("ip_networks", &self.ip_networks())?;
-        state.serialize_field("mtu", &self.mtu())?;
+        state.serialize_field("path", &self.path())?;
+        state.serialize_field("total", &self.total())?;
+        state.serialize_field("used", &self.used())?;
+        state.serialize_field("priority", &self.priority())?;
 
         state.end()
     }
 }
 
-#[cfg(feature = "network")]
-impl Serialize for crate::MacAddr {
+#[cfg(feature = "system")]
+impl Serialize for crate::CompressedMemory {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        serializer.serialize_newtype_struct("MacAddr", &self.0)
+        // `3` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("CompressedMemory", 3)?;
+
+        state.serialize_field("original_size", &self.original_size())?;
+        state.serialize_field("compressed_size", &self.compressed_size())?;
+        state.serialize_field("compression_ratio", &self.compression_ratio())?;
+
+        state.end()
     }
 }
 
-#[cfg(feature = "network")]
-impl Serialize for crate::IpNetwork {
+#[cfg(feature = "system")]
+impl Serialize for crate::SystemFacts {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        let mut state = serializer.serialize_struct("IpNetwork", 2)?;
+        // `11` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("SystemFacts", 11)?;
 
-        state.serialize_field("addr", &self.addr)?;
-        state.serialize_field("prefix", &self.prefix)?;
+        state.serialize_field("host_name", &self.host_name())?;
+        state.serialize_field("os_name", &self.os_name())?;
+        state.serialize_field("os_version", &self.os_version())?;
+        state.serialize_field("kernel_version", &self.kernel_version())?;
+        state.serialize_field("cpu_arch", &self.cpu_arch())?;
+        state.serialize_field("cpu_brand", &self.cpu_brand())?;
+        state.serialize_field("cpu_count", &self.cpu_count())?;
+        state.serialize_field("physical_core_count", &self.physical_core_count())?;
+        state.serialize_field("total_memory", &self.total_memory())?;
+        state.serialize_field("boot_time", &self.boot_time())?;
+        state.serialize_field("virtualization", &self.virtualization())?;
 
         state.end()
     }
 }
 
-#[cfg(feature = "user")]
-impl Serialize for crate::Users {
+#[cfg(feature = "system")]
+impl Serialize for crate::CoreDumpConfig {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        serializer.collect_seq(self.iter())
+        // `4` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("CoreDumpConfig", 4)?;
+
+        state.serialize_field("pattern", &self.pattern())?;
+        state.serialize_field("handler", &self.handler())?;
+        state.serialize_field("soft_limit", &self.soft_limit())?;
+        state.serialize_field("hard_limit", &self.hard_limit())?;
+
+        state.end()
     }
 }
 
-#[cfg(feature = "user")]
-impl Serialize for crate::User {
+#[cfg(feature = "system")]
+impl Serialize for crate::KernelLogLevel {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        // `4` corresponds to the number of fields.
-        let mut state = serializer.serialize_struct("User", 4)?;
-
-        state.serialize_field("id", &self.id())?;
-        state.serialize_field("group_id", &self.group_id())?;
-        state.serialize_field("name", &self.name())?;
-        state.serialize_field("groups", &self.groups())?;
+        let (index, variant) = match *self {
+            Self::Emergency => (0, "Emergency"),
+            Self::Alert => (1, "Alert"),
+            Self::Critical => (2, "Critical"),
+            Self::Error => (3, "Error"),
+            Self::Warning => (4, "Warning"),
+            Self::Notice => (5, "Notice"),
+            Self::Info => (6, "Info"),
+            Self::Debug => (7, "Debug"),
+        };
 
-        state.end()
+        serializer.serialize_unit_variant("KernelLogLevel", index, variant)
     }
 }
 
-#[cfg(feature = "user")]
-impl Serialize for crate::Group {
+#[cfg(feature = "system")]
+impl Serialize for crate::KernelLogEntry {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        // `2` corresponds to the number of fields.
-        let mut state = serializer.serialize_struct("Group", 2)?;
+        // `4` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("KernelLogEntry", 4)?;
 
-        state.serialize_field("id", &self.id())?;
-        state.serialize_field("name", &self.name())?;
+        state.serialize_field("level", &self.level())?;
+        state.serialize_field("sequence", &self.sequence())?;
+        state.serialize_field("time_since_boot", &self.time_since_boot())?;
+        state.serialize_field("message", &self.message())?;
 
         state.end()
     }
 }
 
-#[cfg(any(feature = "user", feature = "system"))]
-impl Serialize for crate::Gid {
+#[cfg(feature = "system")]
+impl Serialize for crate::SocketSummary {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        serializer.serialize_newtype_struct("Gid", &self.to_string())
+        // `8` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("SocketSummary", 8)?;
+
+        state.serialize_field("tcp_sockets", &self.tcp_sockets())?;
+        state.serialize_field("tcp_established", &self.tcp_established())?;
+        state.serialize_field("tcp_syn_received", &self.tcp_syn_received())?;
+        state.serialize_field("tcp_time_wait", &self.tcp_time_wait())?;
+        state.serialize_field("tcp_orphaned", &self.tcp_orphaned())?;
+        state.serialize_field("tcp_listen_overflows", &self.tcp_listen_overflows())?;
+        state.serialize_field("tcp_syn_cookies_sent", &self.tcp_syn_cookies_sent())?;
+        state.serialize_field("udp_sockets", &self.udp_sockets())?;
+
+        state.end()
     }
 }
 
-#[cfg(any(feature = "user", feature = "system"))]
-impl Serialize for crate::Uid {
+#[cfg(feature = "system")]
+impl Serialize for crate::ThrottlingStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `9` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("ThrottlingStatus", 9)?;
+
+        state.serialize_field("is_under_voltage", &self.is_under_voltage())?;
+        state.serialize_field("is_frequency_capped", &self.is_frequency_capped())?;
+        state.serialize_field("is_throttled", &self.is_throttled())?;
+        state.serialize_field(
+            "is_soft_temperature_limit_active",
+            &self.is_soft_temperature_limit_active(),
+        )?;
+        state.serialize_field("has_been_under_voltage", &self.has_been_under_voltage())?;
+        state.serialize_field(
+            "has_been_frequency_capped",
+            &self.has_been_frequency_capped(),
+        )?;
+        state.serialize_field("has_been_throttled", &self.has_been_throttled())?;
+        state.serialize_field(
+            "has_reached_soft_temperature_limit",
+            &self.has_reached_soft_temperature_limit(),
+        )?;
+        state.serialize_field("raw", &self.raw())?;
+
+        state.end()
+    }
+}
+
+#[cfg(feature = "system")]
+impl Serialize for crate::InotifyUsage {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `4` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("InotifyUsage", 4)?;
+
+        state.serialize_field("instances", &self.instances())?;
+        state.serialize_field("max_instances", &self.max_instances())?;
+        state.serialize_field("watches", &self.watches())?;
+        state.serialize_field("max_watches", &self.max_watches())?;
+
+        state.end()
+    }
+}
+
+#[cfg(feature = "system")]
+impl Serialize for crate::MemoryHardwareInfo {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `4` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("MemoryHardwareInfo", 4)?;
+
+        state.serialize_field("ecc_supported", &self.ecc_supported())?;
+        state.serialize_field("ecc_enabled", &self.ecc_enabled())?;
+        state.serialize_field("slot_count", &self.slot_count())?;
+        state.serialize_field("modules", &self.modules())?;
+
+        state.end()
+    }
+}
+
+#[cfg(feature = "system")]
+impl Serialize for crate::MemoryModule {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `5` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("MemoryModule", 5)?;
+
+        state.serialize_field("locator", &self.locator())?;
+        state.serialize_field("size", &self.size())?;
+        state.serialize_field("speed", &self.speed())?;
+        state.serialize_field("manufacturer", &self.manufacturer())?;
+        state.serialize_field("part_number", &self.part_number())?;
+
+        state.end()
+    }
+}
+
+#[cfg(feature = "system")]
+impl Serialize for crate::CpuVulnerabilityStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (index, variant) = match *self {
+            Self::NotAffected => (0, "NotAffected"),
+            Self::Mitigated => (1, "Mitigated"),
+            Self::Vulnerable => (2, "Vulnerable"),
+            Self::Unknown => (3, "Unknown"),
+        };
+
+        serializer.serialize_unit_variant("CpuVulnerabilityStatus", index, variant)
+    }
+}
+
+#[cfg(feature = "system")]
+impl Serialize for crate::EnergyImpact {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `4` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("EnergyImpact", 4)?;
+
+        state.serialize_field("power", &self.power)?;
+        state.serialize_field("interrupt_wakeups", &self.interrupt_wakeups)?;
+        state.serialize_field("idle_wakeups", &self.idle_wakeups)?;
+        state.serialize_field("qos_class", &self.qos_class)?;
+
+        state.end()
+    }
+}
+
+#[cfg(feature = "system")]
+impl Serialize for crate::ProcessStringStats {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `2` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("ProcessStringStats", 2)?;
+
+        state.serialize_field("reused", &self.reused)?;
+        state.serialize_field("allocated", &self.allocated)?;
+
+        state.end()
+    }
+}
+
+#[cfg(feature = "system")]
+impl Serialize for crate::ProcessHandle {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `2` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("ProcessHandle", 2)?;
+
+        state.serialize_field("pid", &self.pid())?;
+        state.serialize_field("start_time_raw", &self.start_time_raw)?;
+
+        state.end()
+    }
+}
+
+#[cfg(feature = "system")]
+impl Serialize for crate::CurrentProcessUsage {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `3` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("CurrentProcessUsage", 3)?;
+
+        state.serialize_field("memory", &self.memory)?;
+        state.serialize_field("virtual_memory", &self.virtual_memory)?;
+        state.serialize_field("accumulated_cpu_time", &self.accumulated_cpu_time)?;
+
+        state.end()
+    }
+}
+
+#[cfg(feature = "system")]
+impl Serialize for crate::ChildrenUsage {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `2` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("ChildrenUsage", 2)?;
+
+        state.serialize_field("accumulated_cpu_time", &self.accumulated_cpu_time)?;
+        state.serialize_field("max_memory", &self.max_memory)?;
+
+        state.end()
+    }
+}
+
+#[cfg(feature = "system")]
+impl Serialize for crate::MediaUsage {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `3` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("MediaUsage", 3)?;
+
+        state.serialize_field("audio_capture", &self.audio_capture)?;
+        state.serialize_field("camera", &self.camera)?;
+        state.serialize_field("screen_recording", &self.screen_recording)?;
+
+        state.end()
+    }
+}
+
+#[cfg(feature = "system")]
+impl Serialize for crate::ConnectionCount {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `2` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("ConnectionCount", 2)?;
+
+        state.serialize_field("tcp", &self.tcp)?;
+        state.serialize_field("udp", &self.udp)?;
+
+        state.end()
+    }
+}
+
+#[cfg(feature = "system")]
+impl Serialize for crate::PidDelta {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `7` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("PidDelta", 7)?;
+
+        state.serialize_field("interval", &self.interval)?;
+        state.serialize_field("cpu_usage", &self.cpu_usage)?;
+        state.serialize_field("cpu_time", &self.cpu_time)?;
+        state.serialize_field("memory", &self.memory)?;
+        state.serialize_field("read_bytes", &self.read_bytes)?;
+        state.serialize_field("written_bytes", &self.written_bytes)?;
+        state.serialize_field("pid_reused", &self.pid_reused)?;
+
+        state.end()
+    }
+}
+
+#[cfg(feature = "system")]
+impl Serialize for crate::UserUsage {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `3` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("UserUsage", 3)?;
+
+        state.serialize_field("process_count", &self.process_count)?;
+        state.serialize_field("cpu_usage", &self.cpu_usage)?;
+        state.serialize_field("memory", &self.memory)?;
+
+        state.end()
+    }
+}
+
+#[cfg(feature = "system")]
+impl Serialize for crate::IoCounters {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `3` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("IoCounters", 3)?;
+
+        state.serialize_field("read_syscalls", &self.read_syscalls)?;
+        state.serialize_field("write_syscalls", &self.write_syscalls)?;
+        state.serialize_field("cancelled_write_bytes", &self.cancelled_write_bytes)?;
+
+        state.end()
+    }
+}
+
+#[cfg(feature = "system")]
+impl Serialize for crate::RefreshDurations {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `4` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("RefreshDurations", 4)?;
+
+        state.serialize_field("memory", &self.memory)?;
+        state.serialize_field("cpu", &self.cpu)?;
+        state.serialize_field("cpu_list", &self.cpu_list)?;
+        state.serialize_field("processes", &self.processes)?;
+
+        state.end()
+    }
+}
+
+#[cfg(feature = "system")]
+impl Serialize for crate::SchedStats {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `3` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("SchedStats", 3)?;
+
+        state.serialize_field("run_time", &self.run_time)?;
+        state.serialize_field("wait_time", &self.wait_time)?;
+        state.serialize_field("timeslices", &self.timeslices)?;
+
+        state.end()
+    }
+}
+
+#[cfg(feature = "component")]
+impl Serialize for crate::Components {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+#[cfg(feature = "component")]
+impl Serialize for crate::ThresholdLevel {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (index, variant) = match *self {
+            Self::Normal => (0, "Normal"),
+            Self::High => (1, "High"),
+            Self::Critical => (2, "Critical"),
+        };
+
+        serializer.serialize_unit_variant("ThresholdLevel", index, variant)
+    }
+}
+
+#[cfg(feature = "component")]
+impl Serialize for crate::ValueSource {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (index, variant) = match *self {
+            Self::Hardware => (0, "Hardware"),
+            Self::Inferred => (1, "Inferred"),
+        };
+
+        serializer.serialize_unit_variant("ValueSource", index, variant)
+    }
+}
+
+#[cfg(feature = "component")]
+impl Serialize for crate::Component {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `9` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("Component", 9)?;
+
+        state.serialize_field("temperature", &self.temperature())?;
+        state.serialize_field("max", &self.max())?;
+        state.serialize_field("max_source", &self.max_source())?;
+        state.serialize_field("max_observed", &self.max_observed())?;
+        state.serialize_field("critical", &self.critical())?;
+        state.serialize_field("critical_source", &self.critical_source())?;
+        state.serialize_field("label", &self.label())?;
+        state.serialize_field("raw_label", &self.raw_label())?;
+        state.serialize_field("chip", &self.chip())?;
+
+        state.end()
+    }
+}
+
+#[cfg(feature = "network")]
+impl Serialize for crate::Networks {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+#[cfg(feature = "network")]
+impl Serialize for crate::NetworkEvent {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `3` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("NetworkEvent", 3)?;
+
+        state.serialize_field("kind", &self.kind())?;
+        state.serialize_field("interface_name", &self.interface_name())?;
+        state.serialize_field("is_up", &self.is_up())?;
+
+        state.end()
+    }
+}
+
+#[cfg(feature = "network")]
+impl Serialize for crate::NetworkEventKind {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (index, variant) = match *self {
+            Self::Added => (0, "Added"),
+            Self::Removed => (1, "Removed"),
+            Self::LinkStateChanged => (2, "LinkStateChanged"),
+        };
+
+        serializer.serialize_unit_variant("NetworkEventKind", index, variant)
+    }
+}
+
+#[cfg(feature = "network")]
+impl Serialize for crate::NetworkData {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `29` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("NetworkData", 29)?;
+
+        state.serialize_field("received", &self.received())?;
+        state.serialize_field("total_received", &self.total_received())?;
+        state.serialize_field("transmitted", &self.transmitted())?;
+        state.serialize_field("total_transmitted", &self.total_transmitted())?;
+        state.serialize_field("packets_received", &self.packets_received())?;
+        state.serialize_field("total_packets_received", &self.total_packets_received())?;
+        state.serialize_field("packets_transmitted", &self.packets_transmitted())?;
+        state.serialize_field(
+            "total_packets_transmitted",
+            &self.total_packets_transmitted(),
+        )?;
+        state.serialize_field("errors_on_received", &self.errors_on_received())?;
+        state.serialize_field("total_errors_on_received", &self.total_errors_on_received())?;
+        state.serialize_field("errors_on_transmitted", &self.errors_on_transmitted())?;
+        state.serialize_field(
+            "total_errors_on_transmitted",
+            &self.total_errors_on_transmitted(),
+        )?;
+        state.serialize_field("drops_on_received", &self.drops_on_received())?;
+        state.serialize_field("total_drops_on_received", &self.total_drops_on_received())?;
+        state.serialize_field("drops_on_transmitted", &self.drops_on_transmitted())?;
+        state.serialize_field(
+            "total_drops_on_transmitted",
+            &self.total_drops_on_transmitted(),
+        )?;
+        state.serialize_field("fifo_errors_on_received", &self.fifo_errors_on_received())?;
+        state.serialize_field(
+            "total_fifo_errors_on_received",
+            &self.total_fifo_errors_on_received(),
+        )?;
+        state.serialize_field(
+            "fifo_errors_on_transmitted",
+            &self.fifo_errors_on_transmitted(),
+        )?;
+        state.serialize_field(
+            "total_fifo_errors_on_transmitted",
+            &self.total_fifo_errors_on_transmitted(),
+        )?;
+        state.serialize_field("collisions", &self.collisions())?;
+        state.serialize_field("total_collisions", &self.total_collisions())?;
+        state.serialize_field("carrier_errors", &self.carrier_errors())?;
+        state.serialize_field("total_carrier_errors", &self.total_carrier_errors())?;
+        state.serialize_field(
+            "multicast_packets_received",
+            &self.multicast_packets_received(),
+        )?;
+        state.serialize_field(
+            "total_multicast_packets_received",
+            &self.total_multicast_packets_received(),
+        )?;
+        state.serialize_field("mac_address", &self.mac_address())?;
+        state.serialize_field("ip_networks", &self.ip_networks())?;
+        state.serialize_field("mtu", &self.mtu())?;
+
+        state.end()
+    }
+}
+
+#[cfg(feature = "network")]
+impl Serialize for crate::DnsConfiguration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `2` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("DnsConfiguration", 2)?;
+
+        state.serialize_field("nameservers", &self.nameservers())?;
+        state.serialize_field("search_domains", &self.search_domains())?;
+
+        state.end()
+    }
+}
+
+#[cfg(feature = "network")]
+impl Serialize for crate::ProtocolStats {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `16` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("ProtocolStats", 16)?;
+
+        state.serialize_field("ip_header_errors", &self.ip_header_errors())?;
+        state.serialize_field("ip_address_errors", &self.ip_address_errors())?;
+        state.serialize_field("ip_discards", &self.ip_discards())?;
+        state.serialize_field("ip_reassembly_failures", &self.ip_reassembly_failures())?;
+        state.serialize_field("ip_fragment_failures", &self.ip_fragment_failures())?;
+        state.serialize_field("icmp_errors_received", &self.icmp_errors_received())?;
+        state.serialize_field("icmp_errors_sent", &self.icmp_errors_sent())?;
+        state.serialize_field("tcp_segments_received", &self.tcp_segments_received())?;
+        state.serialize_field("tcp_segments_sent", &self.tcp_segments_sent())?;
+        state.serialize_field(
+            "tcp_retransmitted_segments",
+            &self.tcp_retransmitted_segments(),
+        )?;
+        state.serialize_field("tcp_errors_received", &self.tcp_errors_received())?;
+        state.serialize_field("tcp_resets_sent", &self.tcp_resets_sent())?;
+        state.serialize_field("tcp_connection_resets", &self.tcp_connection_resets())?;
+        state.serialize_field(
+            "tcp_failed_connection_attempts",
+            &self.tcp_failed_connection_attempts(),
+        )?;
+        state.serialize_field("udp_receive_errors", &self.udp_receive_errors())?;
+        state.serialize_field("udp_no_ports", &self.udp_no_ports())?;
+
+        state.end()
+    }
+}
+
+#[cfg(feature = "network")]
+impl Serialize for crate::MacAddr {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_newtype_struct("MacAddr", &self.0)
+    }
+}
+
+#[cfg(feature = "network")]
+impl Serialize for crate::IpNetwork {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("IpNetwork", 2)?;
+
+        state.serialize_field("addr", &self.addr)?;
+        state.serialize_field("prefix", &self.prefix)?;
+
+        state.end()
+    }
+}
+
+#[cfg(feature = "user")]
+impl Serialize for crate::Users {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+#[cfg(feature = "user")]
+impl Serialize for crate::User {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `4` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("User", 4)?;
+
+        state.serialize_field("id", &self.id())?;
+        state.serialize_field("group_id", &self.group_id())?;
+        state.serialize_field("name", &self.name())?;
+        state.serialize_field("groups", &self.groups())?;
+
+        state.end()
+    }
+}
+
+#[cfg(feature = "user")]
+impl Serialize for crate::Group {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `2` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("Group", 2)?;
+
+        state.serialize_field("id", &self.id())?;
+        state.serialize_field("name", &self.name())?;
+
+        state.end()
+    }
+}
+
+#[cfg(any(feature = "user", feature = "system"))]
+impl Serialize for crate::Gid {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_newtype_struct("Gid", &self.to_string())
+    }
+}
+
+#[cfg(any(feature = "user", feature = "system"))]
+impl Serialize for crate::Uid {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -501,6 +1387,330 @@ impl Serialize for crate::Uid {
     }
 }
 
+#[cfg(feature = "usb")]
+impl Serialize for crate::UsbDevices {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+#[cfg(feature = "usb")]
+impl Serialize for crate::UsbDevice {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `8` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("UsbDevice", 8)?;
+
+        state.serialize_field("vendor_id", &self.vendor_id())?;
+        state.serialize_field("product_id", &self.product_id())?;
+        state.serialize_field("manufacturer", &self.manufacturer())?;
+        state.serialize_field("product", &self.product())?;
+        state.serialize_field("serial_number", &self.serial_number())?;
+        state.serialize_field("bus_number", &self.bus_number())?;
+        state.serialize_field("port_numbers", &self.port_numbers())?;
+        state.serialize_field("speed", &self.speed())?;
+
+        state.end()
+    }
+}
+
+#[cfg(feature = "usb")]
+impl Serialize for crate::UsbSpeed {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (index, variant) = match *self {
+            Self::Low => (0, "Low"),
+            Self::Full => (1, "Full"),
+            Self::High => (2, "High"),
+            Self::Super => (3, "Super"),
+            Self::SuperPlus => (4, "SuperPlus"),
+            Self::Unknown => (5, "Unknown"),
+        };
+
+        serializer.serialize_unit_variant("UsbSpeed", index, variant)
+    }
+}
+
+#[cfg(feature = "disk")]
+impl Serialize for crate::Volume {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `5` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("Volume", 5)?;
+
+        state.serialize_field("name", &self.name())?;
+        state.serialize_field("kind", &self.kind())?;
+        state.serialize_field("level", &self.level())?;
+        state.serialize_field("state", &self.state())?;
+        state.serialize_field("devices", &self.devices())?;
+
+        state.end()
+    }
+}
+
+#[cfg(feature = "disk")]
+impl Serialize for crate::VolumeKind {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (index, variant) = match *self {
+            Self::Raid => (0, "Raid"),
+            Self::LvmVolumeGroup => (1, "LvmVolumeGroup"),
+            Self::Btrfs => (2, "Btrfs"),
+        };
+
+        serializer.serialize_unit_variant("VolumeKind", index, variant)
+    }
+}
+
+#[cfg(feature = "disk")]
+impl Serialize for crate::VolumeState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (index, variant) = match *self {
+            Self::Healthy => (0, "Healthy"),
+            Self::Degraded => (1, "Degraded"),
+            Self::Rebuilding => (2, "Rebuilding"),
+            Self::Failed => (3, "Failed"),
+            Self::Unknown => (4, "Unknown"),
+        };
+
+        serializer.serialize_unit_variant("VolumeState", index, variant)
+    }
+}
+
+#[cfg(feature = "disk")]
+impl Serialize for crate::VolumeDevice {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `3` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("VolumeDevice", 3)?;
+
+        state.serialize_field("name", &self.name())?;
+        state.serialize_field("is_faulty", &self.is_faulty())?;
+        state.serialize_field("error_count", &self.error_count())?;
+
+        state.end()
+    }
+}
+
+#[cfg(feature = "zfs")]
+impl Serialize for crate::Zfs {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `2` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("Zfs", 2)?;
+
+        state.serialize_field("pools", &self.pools())?;
+        state.serialize_field("arc", &self.arc())?;
+
+        state.end()
+    }
+}
+
+#[cfg(feature = "zfs")]
+impl Serialize for crate::ZfsPool {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `5` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("ZfsPool", 5)?;
+
+        state.serialize_field("name", &self.name())?;
+        state.serialize_field("health", &self.health())?;
+        state.serialize_field("size", &self.size())?;
+        state.serialize_field("allocated", &self.allocated())?;
+        state.serialize_field("free", &self.free())?;
+
+        state.end()
+    }
+}
+
+#[cfg(feature = "zfs")]
+impl Serialize for crate::ZfsPoolHealth {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (index, variant) = match *self {
+            Self::Online => (0, "Online"),
+            Self::Degraded => (1, "Degraded"),
+            Self::Faulted => (2, "Faulted"),
+            Self::Offline => (3, "Offline"),
+            Self::Unavailable => (4, "Unavailable"),
+            Self::Removed => (5, "Removed"),
+            Self::Suspended => (6, "Suspended"),
+            Self::Unknown => (7, "Unknown"),
+        };
+
+        serializer.serialize_unit_variant("ZfsPoolHealth", index, variant)
+    }
+}
+
+#[cfg(feature = "zfs")]
+impl Serialize for crate::ZfsArc {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `7` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("ZfsArc", 7)?;
+
+        state.serialize_field("size", &self.size())?;
+        state.serialize_field("target_size", &self.target_size())?;
+        state.serialize_field("min_size", &self.min_size())?;
+        state.serialize_field("max_size", &self.max_size())?;
+        state.serialize_field("hits", &self.hits())?;
+        state.serialize_field("misses", &self.misses())?;
+        state.serialize_field("hit_rate", &self.hit_rate())?;
+
+        state.end()
+    }
+}
+
+#[cfg(feature = "audio")]
+impl Serialize for crate::AudioDevices {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+#[cfg(feature = "audio")]
+impl Serialize for crate::AudioDevice {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `4` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("AudioDevice", 4)?;
+
+        state.serialize_field("id", &self.id())?;
+        state.serialize_field("name", &self.name())?;
+        state.serialize_field("kind", &self.kind())?;
+        state.serialize_field("is_default", &self.is_default())?;
+
+        state.end()
+    }
+}
+
+#[cfg(feature = "audio")]
+impl Serialize for crate::AudioDeviceKind {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (index, variant) = match *self {
+            Self::Input => (0, "Input"),
+            Self::Output => (1, "Output"),
+        };
+
+        serializer.serialize_unit_variant("AudioDeviceKind", index, variant)
+    }
+}
+
+#[cfg(feature = "display")]
+impl Serialize for crate::Displays {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+#[cfg(feature = "display")]
+impl Serialize for crate::Display {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `7` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("Display", 7)?;
+
+        state.serialize_field("id", &self.id())?;
+        state.serialize_field("name", &self.name())?;
+        state.serialize_field("width", &self.width())?;
+        state.serialize_field("height", &self.height())?;
+        state.serialize_field("refresh_rate", &self.refresh_rate())?;
+        state.serialize_field("gpu", &self.gpu())?;
+        state.serialize_field("is_primary", &self.is_primary())?;
+
+        state.end()
+    }
+}
+
+#[cfg(feature = "apple-silicon")]
+impl Serialize for crate::AppleSiliconMetrics {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `4` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("AppleSiliconMetrics", 4)?;
+
+        state.serialize_field("clusters", &self.clusters())?;
+        state.serialize_field("cpu_power", &self.cpu_power())?;
+        state.serialize_field("gpu_power", &self.gpu_power())?;
+        state.serialize_field("package_power", &self.package_power())?;
+
+        state.end()
+    }
+}
+
+#[cfg(feature = "apple-silicon")]
+impl Serialize for crate::CpuCluster {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `4` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("CpuCluster", 4)?;
+
+        state.serialize_field("name", &self.name())?;
+        state.serialize_field("kind", &self.kind())?;
+        state.serialize_field("frequency", &self.frequency())?;
+        state.serialize_field("usage", &self.usage())?;
+
+        state.end()
+    }
+}
+
+#[cfg(feature = "apple-silicon")]
+impl Serialize for crate::CpuClusterKind {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (index, variant) = match *self {
+            Self::Efficiency => (0, "Efficiency"),
+            Self::Performance => (1, "Performance"),
+            Self::Unknown => (2, "Unknown"),
+        };
+
+        serializer.serialize_unit_variant("CpuClusterKind", index, variant)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     #[test]
@@ -0,0 +1,66 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::common::fake::FakeCpu;
+
+pub(crate) struct CpuInner {
+    data: FakeCpu,
+}
+
+impl CpuInner {
+    pub(crate) fn new(data: &FakeCpu, update_usage: bool, update_frequency: bool) -> Self {
+        let mut inner = Self {
+            data: FakeCpu {
+                frequency: 0,
+                cpu_usage: 0.,
+                ..data.clone()
+            },
+        };
+        inner.update(data, update_usage, update_frequency);
+        inner
+    }
+
+    pub(crate) fn update(&mut self, data: &FakeCpu, update_usage: bool, update_frequency: bool) {
+        if update_usage {
+            self.data.cpu_usage = data.cpu_usage;
+        }
+        if update_frequency {
+            self.data.frequency = data.frequency;
+        }
+    }
+
+    pub(crate) fn cpu_usage(&self) -> f32 {
+        self.data.cpu_usage
+    }
+
+    pub(crate) fn name(&self) -> &str {
+        &self.data.name
+    }
+
+    pub(crate) fn frequency(&self) -> u64 {
+        self.data.frequency
+    }
+
+    pub(crate) fn vendor_id(&self) -> &str {
+        &self.data.vendor_id
+    }
+
+    pub(crate) fn brand(&self) -> &str {
+        &self.data.brand
+    }
+
+    pub(crate) fn run_queue_length(&self) -> Option<usize> {
+        None
+    }
+
+    pub(crate) fn run_queue_wait_time(&self) -> Option<u64> {
+        None
+    }
+
+    pub(crate) fn boost_active(&self) -> Option<bool> {
+        None
+    }
+
+    pub(crate) fn idle_states(&self) -> &[crate::CpuIdleState] {
+        &[]
+    }
+}
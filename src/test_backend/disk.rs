@@ -0,0 +1,176 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::common::fake::{FakeDisk, SharedState};
+use crate::{Disk, DiskKind, DiskRefreshKind, DiskUsage, RefreshError};
+
+use std::ffi::OsStr;
+use std::path::Path;
+
+pub(crate) struct DiskInner {
+    data: FakeDisk,
+    state: SharedState,
+    updated: bool,
+}
+
+impl DiskInner {
+    fn update(&mut self, data: &FakeDisk, refreshes: DiskRefreshKind) {
+        if refreshes.kind() {
+            self.data.kind = data.kind;
+        }
+        if refreshes.storage() {
+            self.data.total_space = data.total_space;
+            self.data.available_space = data.available_space;
+        }
+        if refreshes.io_usage() {
+            self.data.usage = data.usage;
+        }
+        self.data.is_read_only = data.is_read_only;
+        self.updated = true;
+    }
+
+    pub(crate) fn kind(&self) -> DiskKind {
+        self.data.kind
+    }
+
+    pub(crate) fn name(&self) -> &OsStr {
+        &self.data.name
+    }
+
+    pub(crate) fn file_system(&self) -> &OsStr {
+        &self.data.file_system
+    }
+
+    pub(crate) fn mount_point(&self) -> &Path {
+        &self.data.mount_point
+    }
+
+    pub(crate) fn total_space(&self) -> u64 {
+        self.data.total_space
+    }
+
+    pub(crate) fn available_space(&self) -> u64 {
+        self.data.available_space
+    }
+
+    pub(crate) fn is_removable(&self) -> bool {
+        self.data.is_removable
+    }
+
+    pub(crate) fn is_read_only(&self) -> bool {
+        self.data.is_read_only
+    }
+
+    pub(crate) fn refresh_specifics(&mut self, refreshes: DiskRefreshKind) -> bool {
+        let state = self.state.clone();
+        let state = state.lock().unwrap();
+        match state
+            .disks
+            .iter()
+            .find(|disk| disk.mount_point == self.data.mount_point)
+        {
+            Some(data) => {
+                self.update(data, refreshes);
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub(crate) fn usage(&self) -> DiskUsage {
+        self.data.usage
+    }
+
+    pub(crate) fn network_fs_stats(&self) -> Option<crate::NetworkFsStats> {
+        None
+    }
+
+    pub(crate) fn io_stats(&self) -> Option<crate::DiskIoStats> {
+        None
+    }
+}
+
+pub(crate) struct DisksInner {
+    pub(crate) disks: Vec<Disk>,
+    state: SharedState,
+}
+
+impl DisksInner {
+    pub(crate) fn new() -> Self {
+        Self::with_state(Default::default())
+    }
+
+    pub(crate) fn with_state(state: SharedState) -> Self {
+        Self {
+            disks: Vec::new(),
+            state,
+        }
+    }
+
+    pub(crate) fn from_vec(disks: Vec<Disk>) -> Self {
+        Self {
+            disks,
+            state: Default::default(),
+        }
+    }
+
+    pub(crate) fn into_vec(self) -> Vec<Disk> {
+        self.disks
+    }
+
+    pub(crate) fn try_refresh_specifics(
+        &mut self,
+        remove_not_listed_disks: bool,
+        refreshes: DiskRefreshKind,
+    ) -> Result<(), RefreshError> {
+        self.refresh_specifics(remove_not_listed_disks, refreshes);
+        Ok(())
+    }
+
+    pub(crate) fn refresh_specifics(
+        &mut self,
+        remove_not_listed_disks: bool,
+        refreshes: DiskRefreshKind,
+    ) {
+        let state = self.state.lock().unwrap();
+        for data in &state.disks {
+            match self
+                .disks
+                .iter_mut()
+                .find(|disk| disk.inner.data.mount_point == data.mount_point)
+            {
+                Some(disk) => disk.inner.update(data, refreshes),
+                None => {
+                    let mut inner = DiskInner {
+                        data: FakeDisk::new(data.name.clone(), data.mount_point.clone())
+                            .with_file_system(data.file_system.clone())
+                            .with_removable(data.is_removable),
+                        state: self.state.clone(),
+                        updated: false,
+                    };
+                    inner.update(data, refreshes);
+                    self.disks.push(Disk { inner });
+                }
+            }
+        }
+        drop(state);
+
+        if remove_not_listed_disks {
+            self.disks.retain(|disk| disk.inner.updated);
+        }
+        for disk in &mut self.disks {
+            disk.inner.updated = false;
+        }
+    }
+
+    pub(crate) fn list(&self) -> &[Disk] {
+        &self.disks
+    }
+
+    pub(crate) fn list_mut(&mut self) -> &mut [Disk] {
+        &mut self.disks
+    }
+
+    pub(crate) fn usage_for_path(_path: &Path) -> Option<crate::DiskSpace> {
+        None
+    }
+}
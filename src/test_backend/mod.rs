@@ -0,0 +1,56 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+mod cpu;
+mod disk;
+mod process;
+mod system;
+
+pub(crate) use self::cpu::CpuInner;
+pub(crate) use self::disk::{DiskInner, DisksInner};
+pub(crate) use self::process::ProcessInner;
+pub(crate) use self::system::SystemInner;
+pub(crate) use crate::unknown::{
+    DiskWatcherInner, ExitedProcessesInner, MotherboardInner, ProductInner, SecurityInfoInner,
+    StorageInner,
+};
+pub use crate::unknown::{MINIMUM_CPU_UPDATE_INTERVAL, SUPPORTED_SIGNALS};
+
+cfg_if! {
+    if #[cfg(feature = "apple-silicon")] {
+        pub(crate) use crate::unknown::AppleSiliconMetricsInner;
+    }
+
+    if #[cfg(feature = "ebpf")] {
+        pub(crate) use crate::unknown::EbpfCollectorsInner;
+    }
+
+    if #[cfg(feature = "component")] {
+        pub(crate) use crate::unknown::{ComponentInner, ComponentsInner};
+    }
+
+    if #[cfg(feature = "network")] {
+        pub(crate) use crate::unknown::{NetworkDataInner, NetworkWatcherInner, NetworksInner};
+    }
+
+    if #[cfg(feature = "audio")] {
+        pub(crate) use crate::unknown::get_audio_devices;
+    }
+
+    if #[cfg(feature = "display")] {
+        pub(crate) use crate::unknown::get_displays;
+    }
+
+    if #[cfg(feature = "usb")] {
+        pub(crate) use crate::unknown::get_usb_devices;
+    }
+
+    if #[cfg(feature = "user")] {
+        pub(crate) use crate::unknown::{get_groups, get_users, UserInner};
+    }
+
+    if #[cfg(feature = "zfs")] {
+        pub(crate) use crate::unknown::{get_zfs_arc, get_zfs_pools};
+    }
+}
+
+pub use crate::unknown::IS_SUPPORTED_SYSTEM;
@@ -0,0 +1,316 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::common::fake::FakeProcess;
+use crate::{
+    CurrentProcessUsage, DiskUsage, EnergyImpact, ExeMetadata, ExeSignature, Gid, MediaUsage, Pid,
+    ProcessRefreshKind, ProcessStatus, Signal, ThreadKind, Uid,
+};
+
+use std::collections::HashSet;
+use std::ffi::{OsStr, OsString};
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
+use std::process::ExitStatus;
+
+pub(crate) struct ProcessInner {
+    data: FakeProcess,
+    // Only used on Linux, by `Process::tasks`.
+    #[allow(dead_code)]
+    pub(crate) tasks: Option<HashSet<Pid>>,
+    updated: bool,
+    exists: bool,
+}
+
+impl ProcessInner {
+    pub(crate) fn new(data: &FakeProcess, refresh_kind: ProcessRefreshKind) -> Self {
+        let mut inner = Self {
+            data: FakeProcess::new(data.pid, data.name.clone()),
+            tasks: None,
+            updated: false,
+            exists: true,
+        };
+        inner.update(data, refresh_kind);
+        inner
+    }
+
+    pub(crate) fn update(&mut self, data: &FakeProcess, refresh_kind: ProcessRefreshKind) {
+        let p = &mut self.data;
+        p.parent = data.parent;
+        p.name.clone_from(&data.name);
+        p.thread_count = data.thread_count;
+        p.status = data.status;
+        p.start_time = data.start_time;
+        p.run_time = data.run_time;
+        if refresh_kind.cmd().needs_update(|| p.cmd.is_empty()) {
+            p.cmd.clone_from(&data.cmd);
+        }
+        if refresh_kind.exe().needs_update(|| p.exe.is_none()) {
+            p.exe.clone_from(&data.exe);
+        }
+        if refresh_kind.environ().needs_update(|| p.environ.is_empty()) {
+            p.environ.clone_from(&data.environ);
+        }
+        if refresh_kind.cwd().needs_update(|| p.cwd.is_none()) {
+            p.cwd.clone_from(&data.cwd);
+        }
+        if refresh_kind.root().needs_update(|| p.root.is_none()) {
+            p.root.clone_from(&data.root);
+        }
+        if refresh_kind.user().needs_update(|| p.user_id.is_none()) {
+            p.user_id.clone_from(&data.user_id);
+            p.group_id = data.group_id;
+        }
+        if refresh_kind.memory() {
+            p.memory = data.memory;
+            p.virtual_memory = data.virtual_memory;
+        }
+        if refresh_kind.cpu() {
+            p.cpu_usage = data.cpu_usage;
+            p.accumulated_cpu_time = data.accumulated_cpu_time;
+            p.children_cpu_time = data.children_cpu_time;
+        }
+        if refresh_kind.disk_usage() {
+            p.disk_usage = data.disk_usage;
+        }
+        self.updated = true;
+        self.exists = true;
+    }
+
+    pub(crate) fn kill_with(&self, _signal: Signal) -> Option<bool> {
+        None
+    }
+
+    pub(crate) fn name(&self) -> &OsStr {
+        &self.data.name
+    }
+
+    pub(crate) fn cmd(&self) -> &[OsString] {
+        &self.data.cmd
+    }
+
+    pub(crate) fn exe(&self) -> Option<&Path> {
+        self.data.exe.as_deref()
+    }
+
+    pub(crate) fn pid(&self) -> Pid {
+        self.data.pid
+    }
+
+    pub(crate) fn environ(&self) -> &[OsString] {
+        &self.data.environ
+    }
+
+    pub(crate) fn cwd(&self) -> Option<&Path> {
+        self.data.cwd.as_deref()
+    }
+
+    pub(crate) fn root(&self) -> Option<&Path> {
+        self.data.root.as_deref()
+    }
+
+    pub(crate) fn memory(&self) -> u64 {
+        self.data.memory
+    }
+
+    pub(crate) fn virtual_memory(&self) -> u64 {
+        self.data.virtual_memory
+    }
+
+    pub(crate) fn parent(&self) -> Option<Pid> {
+        self.data.parent
+    }
+
+    pub(crate) fn thread_count(&self) -> Option<usize> {
+        self.data.thread_count
+    }
+
+    pub(crate) fn tty(&self) -> Option<String> {
+        None
+    }
+
+    pub(crate) fn reparented_to_init(&self) -> bool {
+        false
+    }
+
+    pub(crate) fn exec_count(&self) -> u64 {
+        0
+    }
+
+    pub(crate) fn status(&self) -> ProcessStatus {
+        self.data.status
+    }
+
+    pub(crate) fn start_time(&self) -> u64 {
+        self.data.start_time
+    }
+
+    pub(crate) fn start_time_raw(&self) -> u64 {
+        self.data.start_time
+    }
+
+    pub(crate) fn run_time(&self) -> u64 {
+        self.data.run_time
+    }
+
+    pub(crate) fn cpu_usage(&self) -> f32 {
+        self.data.cpu_usage
+    }
+
+    pub(crate) fn accumulated_cpu_time(&self) -> u64 {
+        self.data.accumulated_cpu_time
+    }
+
+    pub(crate) fn children_cpu_time(&self) -> u64 {
+        self.data.children_cpu_time
+    }
+
+    pub(crate) fn disk_usage(&self) -> DiskUsage {
+        self.data.disk_usage
+    }
+
+    pub(crate) fn user_id(&self) -> Option<&Uid> {
+        self.data.user_id.as_ref()
+    }
+
+    pub(crate) fn effective_user_id(&self) -> Option<&Uid> {
+        None
+    }
+
+    pub(crate) fn group_id(&self) -> Option<Gid> {
+        self.data.group_id
+    }
+
+    pub(crate) fn effective_group_id(&self) -> Option<Gid> {
+        None
+    }
+
+    pub(crate) fn wait(&self) -> Option<ExitStatus> {
+        None
+    }
+
+    pub(crate) fn session_id(&self) -> Option<Pid> {
+        None
+    }
+
+    pub(crate) fn process_group_id(&self) -> Option<Pid> {
+        None
+    }
+
+    pub(crate) fn current_usage() -> Option<CurrentProcessUsage> {
+        None
+    }
+
+    pub(crate) fn children_usage() -> Option<crate::ChildrenUsage> {
+        None
+    }
+
+    // Only used on Linux, by `Process::thread_kind`.
+    #[allow(dead_code)]
+    pub(crate) fn thread_kind(&self) -> Option<ThreadKind> {
+        None
+    }
+
+    pub(crate) fn switch_updated(&mut self) -> bool {
+        std::mem::replace(&mut self.updated, false)
+    }
+
+    pub(crate) fn set_nonexistent(&mut self) {
+        self.exists = false;
+    }
+
+    pub(crate) fn exists(&self) -> bool {
+        self.exists
+    }
+
+    pub(crate) fn open_files(&self) -> Option<usize> {
+        None
+    }
+
+    pub(crate) fn path_usage(&self, path: &Path) -> crate::PathUsage {
+        crate::PathUsage::from_process_paths(path, self.cwd(), self.root(), self.exe())
+    }
+
+    pub(crate) fn deleted_mappings(&self) -> Vec<PathBuf> {
+        Vec::new()
+    }
+
+    pub(crate) fn open_files_limit(&self) -> Option<usize> {
+        None
+    }
+
+    pub(crate) fn stack_size(&self) -> Option<u64> {
+        None
+    }
+
+    pub(crate) fn heap_size(&self) -> Option<u64> {
+        None
+    }
+
+    pub(crate) fn cgroup(&self) -> Option<String> {
+        None
+    }
+
+    pub(crate) fn container_id(&self) -> Option<String> {
+        None
+    }
+
+    pub(crate) fn sched_stats(&self) -> Option<crate::SchedStats> {
+        None
+    }
+
+    pub(crate) fn io_counters(&self) -> Option<crate::IoCounters> {
+        None
+    }
+
+    pub(crate) fn wait_channel(&self) -> Option<String> {
+        None
+    }
+
+    pub(crate) fn kernel_stack(&self) -> Result<Vec<String>, crate::RefreshError> {
+        Err(crate::RefreshError::Unsupported)
+    }
+
+    pub(crate) fn connection_count(&self) -> Option<crate::ConnectionCount> {
+        None
+    }
+
+    pub(crate) fn is_64_bit(&self) -> Option<bool> {
+        None
+    }
+
+    pub(crate) fn abi(&self) -> Option<&'static str> {
+        None
+    }
+
+    pub(crate) fn listening_ports(&self) -> Option<Vec<SocketAddr>> {
+        None
+    }
+
+    pub(crate) fn exe_signature(&self) -> Option<&ExeSignature> {
+        None
+    }
+
+    pub(crate) fn exe_metadata(&self) -> Option<&ExeMetadata> {
+        None
+    }
+
+    pub(crate) fn bundle_identifier(&self) -> Option<&str> {
+        None
+    }
+
+    pub(crate) fn bundle_name(&self) -> Option<&str> {
+        None
+    }
+
+    pub(crate) fn energy_impact(&self) -> Option<EnergyImpact> {
+        None
+    }
+
+    pub(crate) fn gpu_usage(&self) -> Option<f32> {
+        None
+    }
+
+    pub(crate) fn media_usage(&self) -> Option<MediaUsage> {
+        None
+    }
+}
@@ -0,0 +1,361 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::common::fake::{FakeProcess, FakeState, SharedState};
+use crate::{
+    Cpu, CpuInner, CpuRefreshKind, LoadAvg, MemoryRefreshKind, Pid, Process, ProcessInner,
+    ProcessRefreshKind, ProcessStringStats, ProcessesToUpdate, RefreshError,
+};
+
+use std::collections::HashMap;
+use std::time::Instant;
+
+pub(crate) struct SystemInner {
+    state: SharedState,
+    process_list: HashMap<Pid, Process>,
+    cpus: Vec<Cpu>,
+    global_cpu_usage: f32,
+    last_cpu_usage_update: Option<Instant>,
+    mem_total: u64,
+    mem_used: u64,
+    swap_total: u64,
+    swap_used: u64,
+}
+
+impl SystemInner {
+    pub(crate) fn new() -> Self {
+        Self::with_state(Default::default())
+    }
+
+    pub(crate) fn with_state(state: SharedState) -> Self {
+        Self {
+            state,
+            process_list: HashMap::new(),
+            cpus: Vec::new(),
+            global_cpu_usage: 0.,
+            last_cpu_usage_update: None,
+            mem_total: 0,
+            mem_used: 0,
+            swap_total: 0,
+            swap_used: 0,
+        }
+    }
+
+    pub(crate) fn refresh_memory_specifics(&mut self, refresh_kind: MemoryRefreshKind) {
+        let state = self.state.lock().unwrap();
+        if refresh_kind.ram() {
+            self.mem_total = state.total_memory;
+            self.mem_used = state.used_memory;
+        }
+        if refresh_kind.swap() {
+            self.swap_total = state.total_swap;
+            self.swap_used = state.used_swap;
+        }
+    }
+
+    pub(crate) fn cgroup_limits(&self) -> Option<crate::CGroupLimits> {
+        None
+    }
+
+    pub(crate) fn refresh_cpu_specifics(&mut self, refresh_kind: CpuRefreshKind) {
+        let state = self.state.lock().unwrap();
+        if self.cpus.len() != state.cpus.len() {
+            drop(state);
+            self.refresh_cpu_list(refresh_kind);
+            return;
+        }
+        for (cpu, data) in self.cpus.iter_mut().zip(state.cpus.iter()) {
+            cpu.inner
+                .update(data, refresh_kind.cpu_usage(), refresh_kind.frequency());
+        }
+        if refresh_kind.cpu_usage() {
+            self.global_cpu_usage = global_cpu_usage(&state);
+            self.last_cpu_usage_update = Some(Instant::now());
+        }
+    }
+
+    pub(crate) fn try_refresh_memory_specifics(
+        &mut self,
+        refresh_kind: MemoryRefreshKind,
+    ) -> Result<(), RefreshError> {
+        self.refresh_memory_specifics(refresh_kind);
+        Ok(())
+    }
+
+    pub(crate) fn try_refresh_cpu_specifics(
+        &mut self,
+        refresh_kind: CpuRefreshKind,
+    ) -> Result<(), RefreshError> {
+        self.refresh_cpu_specifics(refresh_kind);
+        Ok(())
+    }
+
+    pub(crate) fn check_processes_source() -> Result<(), RefreshError> {
+        Ok(())
+    }
+
+    pub(crate) fn refresh_cpu_list(&mut self, refresh_kind: CpuRefreshKind) {
+        let state = self.state.lock().unwrap();
+        self.cpus = state
+            .cpus
+            .iter()
+            .map(|data| Cpu {
+                inner: CpuInner::new(data, refresh_kind.cpu_usage(), refresh_kind.frequency()),
+                peak_usage: 0.,
+                measurement_interval: None,
+            })
+            .collect();
+        self.global_cpu_usage = if refresh_kind.cpu_usage() {
+            global_cpu_usage(&state)
+        } else {
+            0.
+        };
+        self.last_cpu_usage_update = refresh_kind.cpu_usage().then(Instant::now);
+    }
+
+    pub(crate) fn refresh_processes_specifics(
+        &mut self,
+        processes_to_update: ProcessesToUpdate<'_>,
+        refresh_kind: ProcessRefreshKind,
+    ) -> usize {
+        let state = self.state.lock().unwrap();
+        let mut update = |data: &FakeProcess| match self.process_list.get_mut(&data.pid) {
+            Some(process) => process.inner.update(data, refresh_kind),
+            None => {
+                self.process_list.insert(
+                    data.pid,
+                    Process {
+                        inner: ProcessInner::new(data, refresh_kind),
+                        last_refreshed_at: None,
+                    },
+                );
+            }
+        };
+        match processes_to_update {
+            ProcessesToUpdate::All => {
+                state.processes.values().for_each(&mut update);
+                state.processes.len()
+            }
+            ProcessesToUpdate::Some(pids) => pids
+                .iter()
+                .filter_map(|pid| state.processes.get(pid))
+                .map(update)
+                .count(),
+        }
+    }
+
+    // COMMON PART
+    //
+    // Need to be moved into a "common" file to avoid duplication.
+
+    pub(crate) fn processes(&self) -> &HashMap<Pid, Process> {
+        &self.process_list
+    }
+
+    pub(crate) fn process_string_stats(&self) -> ProcessStringStats {
+        ProcessStringStats::default()
+    }
+
+    pub(crate) fn set_max_kept_file_handles(&mut self, _max: usize) {}
+
+    pub(crate) fn kept_file_handles(&self) -> usize {
+        0
+    }
+
+    pub(crate) fn set_use_pidfds(&mut self, _use_pidfds: bool) {}
+
+    pub(crate) fn processes_mut(&mut self) -> &mut HashMap<Pid, Process> {
+        &mut self.process_list
+    }
+
+    pub(crate) fn process(&self, pid: Pid) -> Option<&Process> {
+        self.process_list.get(&pid)
+    }
+
+    pub(crate) fn global_cpu_usage(&self) -> f32 {
+        self.global_cpu_usage
+    }
+
+    pub(crate) fn last_cpu_usage_update(&self) -> Option<Instant> {
+        self.last_cpu_usage_update
+    }
+
+    pub(crate) fn cpus(&self) -> &[Cpu] {
+        &self.cpus
+    }
+
+    pub(crate) fn cpus_mut(&mut self) -> &mut [Cpu] {
+        &mut self.cpus
+    }
+
+    pub(crate) fn total_memory(&self) -> u64 {
+        self.mem_total
+    }
+
+    pub(crate) fn free_memory(&self) -> u64 {
+        self.mem_total - self.mem_used
+    }
+
+    pub(crate) fn available_memory(&self) -> u64 {
+        self.mem_total - self.mem_used
+    }
+
+    pub(crate) fn used_memory(&self) -> u64 {
+        self.mem_used
+    }
+
+    pub(crate) fn total_swap(&self) -> u64 {
+        self.swap_total
+    }
+
+    pub(crate) fn free_swap(&self) -> u64 {
+        self.swap_total - self.swap_used
+    }
+
+    pub(crate) fn used_swap(&self) -> u64 {
+        self.swap_used
+    }
+
+    pub(crate) fn swap_devices(&self) -> Vec<crate::SwapDevice> {
+        Vec::new()
+    }
+
+    pub(crate) fn compressed_memory(&self) -> Option<crate::CompressedMemory> {
+        None
+    }
+
+    pub(crate) fn inotify_usage(&self) -> Option<crate::InotifyUsage> {
+        None
+    }
+
+    pub(crate) fn uptime() -> u64 {
+        0
+    }
+
+    pub(crate) fn boot_time() -> u64 {
+        0
+    }
+
+    pub(crate) fn load_average() -> LoadAvg {
+        LoadAvg {
+            one: 0.,
+            five: 0.,
+            fifteen: 0.,
+        }
+    }
+
+    pub(crate) fn run_queue_length() -> Option<usize> {
+        None
+    }
+
+    pub(crate) fn name() -> Option<String> {
+        None
+    }
+
+    pub(crate) fn long_os_version() -> Option<String> {
+        None
+    }
+
+    pub(crate) fn kernel_version() -> Option<String> {
+        None
+    }
+
+    pub(crate) fn os_version() -> Option<String> {
+        None
+    }
+
+    pub(crate) fn distribution_id() -> String {
+        std::env::consts::OS.to_owned()
+    }
+
+    pub(crate) fn distribution_id_like() -> Vec<String> {
+        Vec::new()
+    }
+
+    pub(crate) fn kernel_name() -> Option<&'static str> {
+        None
+    }
+
+    pub(crate) fn host_name() -> Option<String> {
+        None
+    }
+
+    pub(crate) fn cpu_arch() -> Option<String> {
+        None
+    }
+
+    pub(crate) fn is_compat_mode() -> Option<bool> {
+        None
+    }
+
+    pub(crate) fn core_dump_config() -> Option<crate::CoreDumpConfig> {
+        None
+    }
+
+    pub(crate) fn physical_core_count() -> Option<usize> {
+        None
+    }
+
+    pub(crate) fn clock_ticks_per_second() -> Option<u64> {
+        None
+    }
+
+    pub(crate) fn page_size() -> Option<u64> {
+        None
+    }
+
+    pub(crate) fn open_files_limit() -> Option<usize> {
+        None
+    }
+
+    pub(crate) fn timezone() -> Option<String> {
+        None
+    }
+
+    pub(crate) fn locale() -> Option<String> {
+        None
+    }
+
+    pub(crate) fn cpu_vulnerabilities() -> Vec<crate::CpuVulnerability> {
+        Vec::new()
+    }
+
+    pub(crate) fn memory_hardware_info() -> Option<crate::MemoryHardwareInfo> {
+        None
+    }
+
+    pub(crate) fn virtualization() -> Option<String> {
+        crate::common::system::virtualization_from_product()
+    }
+
+    pub(crate) fn board_model() -> Option<String> {
+        None
+    }
+
+    pub(crate) fn turbo_enabled() -> Option<bool> {
+        None
+    }
+
+    pub(crate) fn throttling_status() -> Option<crate::ThrottlingStatus> {
+        None
+    }
+
+    pub(crate) fn socket_summary() -> Option<crate::SocketSummary> {
+        None
+    }
+
+    pub(crate) fn kernel_log(
+        _since: Option<Instant>,
+    ) -> std::io::Result<Vec<crate::KernelLogEntry>> {
+        Err(std::io::ErrorKind::Unsupported.into())
+    }
+}
+
+fn global_cpu_usage(state: &FakeState) -> f32 {
+    if let Some(usage) = state.global_cpu_usage {
+        return usage;
+    }
+    if state.cpus.is_empty() {
+        return 0.;
+    }
+    state.cpus.iter().map(|cpu| cpu.cpu_usage).sum::<f32>() / state.cpus.len() as f32
+}
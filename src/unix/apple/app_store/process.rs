@@ -1,10 +1,13 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
 use std::ffi::{OsStr, OsString};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::process::ExitStatus;
 
-use crate::{DiskUsage, Gid, Pid, ProcessStatus, Signal, Uid};
+use crate::{
+    CurrentProcessUsage, DiskUsage, EnergyImpact, ExeMetadata, ExeSignature, Gid, MediaUsage, Pid,
+    ProcessStatus, Signal, Uid,
+};
 
 pub(crate) struct ProcessInner;
 
@@ -53,6 +56,30 @@ impl ProcessInner {
         None
     }
 
+    pub(crate) fn thread_count(&self) -> Option<usize> {
+        None
+    }
+
+    pub(crate) fn tty(&self) -> Option<String> {
+        None
+    }
+
+    pub(crate) fn reparented_to_init(&self) -> bool {
+        false
+    }
+
+    pub(crate) fn path_usage(&self, path: &Path) -> crate::PathUsage {
+        crate::PathUsage::from_process_paths(path, self.cwd(), self.root(), self.exe())
+    }
+
+    pub(crate) fn deleted_mappings(&self) -> Vec<PathBuf> {
+        Vec::new()
+    }
+
+    pub(crate) fn exec_count(&self) -> u64 {
+        0
+    }
+
     pub(crate) fn status(&self) -> ProcessStatus {
         ProcessStatus::Unknown(0)
     }
@@ -61,6 +88,10 @@ impl ProcessInner {
         0
     }
 
+    pub(crate) fn start_time_raw(&self) -> u64 {
+        0
+    }
+
     pub(crate) fn run_time(&self) -> u64 {
         0
     }
@@ -73,6 +104,10 @@ impl ProcessInner {
         0
     }
 
+    pub(crate) fn children_cpu_time(&self) -> u64 {
+        0
+    }
+
     pub(crate) fn disk_usage(&self) -> DiskUsage {
         DiskUsage::default()
     }
@@ -101,6 +136,18 @@ impl ProcessInner {
         None
     }
 
+    pub(crate) fn process_group_id(&self) -> Option<Pid> {
+        None
+    }
+
+    pub(crate) fn current_usage() -> Option<CurrentProcessUsage> {
+        None
+    }
+
+    pub(crate) fn children_usage() -> Option<crate::ChildrenUsage> {
+        None
+    }
+
     pub(crate) fn switch_updated(&mut self) -> bool {
         false
     }
@@ -110,4 +157,32 @@ impl ProcessInner {
     pub(crate) fn exists(&self) -> bool {
         false
     }
+
+    pub(crate) fn exe_signature(&self) -> Option<&ExeSignature> {
+        None
+    }
+
+    pub(crate) fn exe_metadata(&self) -> Option<&ExeMetadata> {
+        None
+    }
+
+    pub(crate) fn bundle_identifier(&self) -> Option<&str> {
+        None
+    }
+
+    pub(crate) fn bundle_name(&self) -> Option<&str> {
+        None
+    }
+
+    pub(crate) fn energy_impact(&self) -> Option<EnergyImpact> {
+        None
+    }
+
+    pub(crate) fn gpu_usage(&self) -> Option<f32> {
+        None
+    }
+
+    pub(crate) fn media_usage(&self) -> Option<MediaUsage> {
+        None
+    }
 }
@@ -0,0 +1,13 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+cfg_if! {
+    if #[cfg(all(target_os = "macos", not(feature = "apple-sandbox")))] {
+        pub(crate) use crate::sys::inner::audio::get_audio_devices;
+    } else {
+        use crate::AudioDevice;
+
+        pub(crate) fn get_audio_devices(devices: &mut Vec<AudioDevice>) {
+            devices.clear();
+        }
+    }
+}
@@ -16,7 +16,7 @@ pub(crate) struct CpusWrapper {
     pub(crate) cpus: Vec<Cpu>,
     pub(crate) got_cpu_frequency: bool,
     /// This field is needed to prevent updating when not enough time passed since last update.
-    last_update: Option<Instant>,
+    pub(crate) last_update: Option<Instant>,
 }
 
 impl CpusWrapper {
@@ -198,6 +198,22 @@ impl CpuInner {
     pub(crate) fn brand(&self) -> &str {
         &self.brand
     }
+
+    pub(crate) fn run_queue_length(&self) -> Option<usize> {
+        None
+    }
+
+    pub(crate) fn run_queue_wait_time(&self) -> Option<u64> {
+        None
+    }
+
+    pub(crate) fn boost_active(&self) -> Option<bool> {
+        None
+    }
+
+    pub(crate) fn idle_states(&self) -> &[crate::CpuIdleState] {
+        &[]
+    }
 }
 
 pub(crate) unsafe fn get_cpu_frequency(#[allow(unused_variables)] brand: &str) -> u64 {
@@ -352,6 +368,8 @@ pub(crate) fn init_cpus(
                     vendor_id.clone(),
                     brand.clone(),
                 ),
+                peak_usage: 0.,
+                measurement_interval: None,
             };
             if refresh_kind.cpu_usage() {
                 let cpu_usage = compute_usage_of_cpu(&cpu, cpu_info, offset);
@@ -1,6 +1,6 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
-use crate::{Disk, DiskKind, DiskRefreshKind};
+use crate::{Disk, DiskKind, DiskRefreshKind, RefreshError};
 use crate::{DiskUsage, sys::ffi};
 
 use objc2_core_foundation::{
@@ -124,6 +124,14 @@ impl DiskInner {
         }
     }
 
+    pub(crate) fn network_fs_stats(&self) -> Option<crate::NetworkFsStats> {
+        None
+    }
+
+    pub(crate) fn io_stats(&self) -> Option<crate::DiskIoStats> {
+        None
+    }
+
     fn refresh_kind(&mut self, refresh_kind: DiskRefreshKind) {
         if refresh_kind.kind() && self.type_ == DiskKind::Unknown(-1) {
             #[cfg(target_os = "macos")]
@@ -178,6 +186,15 @@ impl crate::DisksInner {
         }
     }
 
+    pub(crate) fn try_refresh_specifics(
+        &mut self,
+        remove_not_listed_disks: bool,
+        refresh_kind: DiskRefreshKind,
+    ) -> Result<(), RefreshError> {
+        self.refresh_specifics(remove_not_listed_disks, refresh_kind);
+        Ok(())
+    }
+
     pub(crate) fn refresh_specifics(
         &mut self,
         remove_not_listed_disks: bool,
@@ -213,6 +230,10 @@ impl crate::DisksInner {
     pub(crate) fn list_mut(&mut self) -> &mut [Disk] {
         &mut self.disks
     }
+
+    pub(crate) fn usage_for_path(path: &Path) -> Option<crate::DiskSpace> {
+        crate::unix::utils::statfs_space(path)
+    }
 }
 
 unsafe fn get_list(container: &mut Vec<Disk>, refresh_kind: DiskRefreshKind) {
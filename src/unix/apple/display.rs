@@ -0,0 +1,13 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+cfg_if! {
+    if #[cfg(all(target_os = "macos", not(feature = "apple-sandbox")))] {
+        pub(crate) use crate::sys::inner::display::get_displays;
+    } else {
+        use crate::Display;
+
+        pub(crate) fn get_displays(displays: &mut Vec<Display>) {
+            displays.clear();
+        }
+    }
+}
@@ -6,3 +6,19 @@ unsafe extern "C" {
     pub fn objc_autoreleasePoolPop(pool: *mut libc::c_void);
     pub fn objc_autoreleasePoolPush() -> *mut libc::c_void;
 }
+
+// Dynamic store APIs from the `SystemConfiguration` framework.
+#[cfg(all(target_os = "macos", feature = "network"))]
+#[link(name = "SystemConfiguration", kind = "framework")]
+unsafe extern "C" {
+    pub fn SCDynamicStoreCreate(
+        allocator: *const libc::c_void,
+        name: &objc2_core_foundation::CFString,
+        callout: *const libc::c_void,
+        context: *mut libc::c_void,
+    ) -> *mut objc2_core_foundation::CFType;
+    pub fn SCDynamicStoreCopyValue(
+        store: &objc2_core_foundation::CFType,
+        key: &objc2_core_foundation::CFString,
+    ) -> *mut objc2_core_foundation::CFType;
+}
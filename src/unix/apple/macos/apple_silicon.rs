@@ -0,0 +1,319 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::common::apple_silicon::PowerUsage;
+use crate::sys::inner::ffi::{
+    IOReportChannelGetChannelName, IOReportChannelGetGroup, IOReportChannelGetSubGroup,
+    IOReportChannelGetUnitLabel, IOReportCopyChannelsInGroup, IOReportCreateSamples,
+    IOReportCreateSamplesDelta, IOReportCreateSubscription, IOReportMergeChannels,
+    IOReportSimpleGetIntegerValue, IOReportStateGetCount, IOReportStateGetNameForIndex,
+    IOReportStateGetResidency, IOReportSubscription,
+};
+use crate::sys::macos::utils::IOReleaser;
+use crate::{CpuCluster, CpuClusterKind};
+
+use objc2_core_foundation::{
+    CFArray, CFData, CFDictionary, CFMutableDictionary, CFRetained, CFString, kCFAllocatorDefault,
+};
+use objc2_io_kit::{
+    IOIteratorNext, IORegistryEntryCreateCFProperty, IOServiceGetMatchingServices,
+    IOServiceMatching, io_iterator_t, kIOMainPortDefault,
+};
+
+use std::io;
+use std::ptr::{self, NonNull};
+use std::time::Instant;
+
+const CLUSTERS_GROUP: &str = "CPU Stats";
+const CLUSTERS_SUBGROUP: &str = "CPU Complex Performance States";
+const ENERGY_GROUP: &str = "Energy Model";
+
+pub(crate) struct AppleSiliconMetricsInner {
+    subscription: CFRetained<IOReportSubscription>,
+    channels: CFRetained<CFMutableDictionary>,
+    previous: Option<(CFRetained<CFDictionary>, Instant)>,
+    /// Frequencies (in MHz) of the performance states of the efficiency clusters.
+    efficiency_frequencies: Vec<u64>,
+    /// Frequencies (in MHz) of the performance states of the performance clusters.
+    performance_frequencies: Vec<u64>,
+}
+
+// SAFETY: The `IOReport` objects are never updated in a `&self` context, so it's safe to make
+// the type `Sync` and `Send`.
+unsafe impl Send for AppleSiliconMetricsInner {}
+unsafe impl Sync for AppleSiliconMetricsInner {}
+
+fn copy_channels(group: &str, subgroup: Option<&str>) -> Option<CFRetained<CFMutableDictionary>> {
+    let group = CFString::from_str(group);
+    let subgroup = subgroup.map(CFString::from_str);
+    let channels =
+        unsafe { IOReportCopyChannelsInGroup(Some(&group), subgroup.as_deref(), 0, 0, 0) }?;
+    // SAFETY: `IOReportCopyChannelsInGroup` is a "copy" function, so the dictionary has +1
+    // retain count.
+    Some(unsafe { CFRetained::from_raw(channels) })
+}
+
+fn to_string(s: *const CFString) -> Option<String> {
+    // SAFETY: The `IOReport` "get" functions return either null or a valid `CFString` owned by
+    // the sample, which outlives this call.
+    unsafe { s.as_ref() }.map(CFString::to_string)
+}
+
+impl AppleSiliconMetricsInner {
+    pub(crate) fn start() -> io::Result<Self> {
+        let unsupported = || {
+            io::Error::new(
+                io::ErrorKind::Unsupported,
+                "the IOReport channels of Apple Silicon chips are not available",
+            )
+        };
+        let channels = copy_channels(CLUSTERS_GROUP, Some(CLUSTERS_SUBGROUP))
+            .filter(|channels| !channels.is_empty())
+            .ok_or_else(unsupported)?;
+        if let Some(energy) = copy_channels(ENERGY_GROUP, None) {
+            unsafe { IOReportMergeChannels(&channels, &energy, ptr::null()) };
+        }
+
+        let mut subscribed: *mut CFMutableDictionary = ptr::null_mut();
+        let subscription = unsafe {
+            IOReportCreateSubscription(ptr::null(), &channels, &mut subscribed, 0, ptr::null())
+        }
+        .ok_or_else(unsupported)?;
+        // SAFETY: `IOReportCreateSubscription` is a "create" function, so the subscription has
+        // +1 retain count.
+        let subscription = unsafe { CFRetained::from_raw(subscription) };
+        let subscribed = NonNull::new(subscribed).ok_or_else(unsupported)?;
+        // SAFETY: The ownership of the subscribed channels isn't documented, so they're retained
+        // to be sure they outlive the subscription.
+        let channels = unsafe { CFRetained::retain(subscribed) };
+
+        let (efficiency_frequencies, performance_frequencies) = dvfs_frequencies();
+        Ok(Self {
+            subscription,
+            channels,
+            previous: None,
+            efficiency_frequencies,
+            performance_frequencies,
+        })
+    }
+
+    pub(crate) fn refresh(&mut self, clusters: &mut Vec<CpuCluster>, power: &mut PowerUsage) {
+        let Some(current) =
+            (unsafe { IOReportCreateSamples(&self.subscription, &self.channels, ptr::null()) })
+        else {
+            sysinfo_debug!("IOReportCreateSamples failed");
+            return;
+        };
+        // SAFETY: `IOReportCreateSamples` is a "create" function, so the sample has +1 retain
+        // count.
+        let current = unsafe { CFRetained::from_raw(current) };
+        let now = Instant::now();
+        let Some((previous, previous_time)) = self.previous.replace((current.clone(), now)) else {
+            return;
+        };
+        let Some(delta) = (unsafe { IOReportCreateSamplesDelta(&previous, &current, ptr::null()) })
+        else {
+            sysinfo_debug!("IOReportCreateSamplesDelta failed");
+            return;
+        };
+        // SAFETY: `IOReportCreateSamplesDelta` is a "create" function, so the delta has +1
+        // retain count and its `IOReportChannels` entry is an array of channels.
+        let delta = unsafe {
+            CFRetained::cast_unchecked::<CFDictionary<CFString, CFArray<CFDictionary>>>(
+                CFRetained::from_raw(delta),
+            )
+        };
+        let Some(items) = delta.get(&CFString::from_static_str("IOReportChannels")) else {
+            return;
+        };
+        let interval = now.duration_since(previous_time).as_secs_f32();
+
+        clusters.clear();
+        *power = PowerUsage::default();
+        for item in items.iter() {
+            let group = to_string(unsafe { IOReportChannelGetGroup(&item) });
+            let Some(name) = to_string(unsafe { IOReportChannelGetChannelName(&item) }) else {
+                continue;
+            };
+            match group.as_deref() {
+                Some(ENERGY_GROUP) => {
+                    let value = unsafe { IOReportSimpleGetIntegerValue(&item, 0) };
+                    let unit = to_string(unsafe { IOReportChannelGetUnitLabel(&item) });
+                    let Some(watts) = energy_to_watts(value, unit.as_deref(), interval) else {
+                        continue;
+                    };
+                    let total = match name.as_str() {
+                        "CPU Energy" => &mut power.cpu,
+                        "GPU Energy" | "GPU" => &mut power.gpu,
+                        name if name.starts_with("ANE") => &mut power.ane,
+                        _ => continue,
+                    };
+                    *total = Some(total.unwrap_or(0.) + watts);
+                }
+                Some(CLUSTERS_GROUP)
+                    if to_string(unsafe { IOReportChannelGetSubGroup(&item) }).as_deref()
+                        == Some(CLUSTERS_SUBGROUP) =>
+                {
+                    let states = (0..unsafe { IOReportStateGetCount(&item) })
+                        .map(|index| {
+                            (
+                                to_string(unsafe { IOReportStateGetNameForIndex(&item, index) })
+                                    .unwrap_or_default(),
+                                unsafe { IOReportStateGetResidency(&item, index) },
+                            )
+                        })
+                        .collect::<Vec<_>>();
+                    let kind = match name.as_bytes().first() {
+                        Some(b'E') => CpuClusterKind::Efficiency,
+                        Some(b'P') => CpuClusterKind::Performance,
+                        _ => CpuClusterKind::Unknown,
+                    };
+                    let frequencies: &[u64] = match kind {
+                        CpuClusterKind::Efficiency => &self.efficiency_frequencies,
+                        CpuClusterKind::Performance => &self.performance_frequencies,
+                        CpuClusterKind::Unknown => &[],
+                    };
+                    let (usage, frequency) = usage_and_frequency(&states, frequencies);
+                    clusters.push(CpuCluster {
+                        name,
+                        kind,
+                        frequency,
+                        usage,
+                    });
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Converts an energy counter of the `Energy Model` group into watts.
+fn energy_to_watts(value: i64, unit: Option<&str>, interval: f32) -> Option<f32> {
+    let factor = match unit?.trim() {
+        "mJ" => 1e-3,
+        "uJ" | "µJ" => 1e-6,
+        "nJ" => 1e-9,
+        _ => return None,
+    };
+    (interval > 0.).then(|| value as f32 * factor / interval)
+}
+
+/// Computes the usage (in %) and the average frequency (in MHz) of a cluster from the residency
+/// of each of its states. The first states are the inactive ones (`OFF`, `IDLE` or `DOWN`),
+/// followed by one state for each entry of `frequencies`.
+fn usage_and_frequency(states: &[(String, i64)], frequencies: &[u64]) -> (f32, u64) {
+    let is_inactive = |name: &str| matches!(name, "OFF" | "IDLE" | "DOWN");
+    let total: i64 = states.iter().map(|(_, residency)| residency).sum();
+    let Some(offset) = states.iter().position(|(name, _)| !is_inactive(name)) else {
+        return (0., 0);
+    };
+    let active = &states[offset..];
+    let active_total: i64 = active.iter().map(|(_, residency)| residency).sum();
+    if total <= 0 || active_total <= 0 {
+        return (0., 0);
+    }
+    let weighted: f64 = active
+        .iter()
+        .zip(frequencies)
+        .map(|((_, residency), frequency)| *residency as f64 * *frequency as f64)
+        .sum();
+    (
+        active_total as f32 * 100. / total as f32,
+        (weighted / active_total as f64) as u64,
+    )
+}
+
+/// Parses a `voltage-states*-sram` property of the power manager, made of one
+/// `(frequency, voltage)` pair of `u32` for each performance state, and returns the frequencies
+/// in MHz.
+fn parse_voltage_states(data: &[u8]) -> Vec<u64> {
+    data.chunks_exact(8)
+        .map(|state| u64::from(u32::from_le_bytes([state[0], state[1], state[2], state[3]])))
+        .filter(|frequency| *frequency != 0)
+        // The frequencies are in Hz, except on M4 and later chips where they're in kHz.
+        .map(|frequency| {
+            if frequency >= 10_000_000 {
+                frequency / 1_000_000
+            } else {
+                frequency / 1_000
+            }
+        })
+        .collect()
+}
+
+/// Returns the frequencies (in MHz) of the performance states of the efficiency and performance
+/// clusters, retrieved from the `pmgr` device.
+fn dvfs_frequencies() -> (Vec<u64>, Vec<u64>) {
+    let Some(matching) = (unsafe { IOServiceMatching(c"AppleARMIODevice".as_ptr()) }) else {
+        sysinfo_debug!("IOServiceMatching call failed, `AppleARMIODevice` not found");
+        return Default::default();
+    };
+    let mut iterator: io_iterator_t = 0;
+    if unsafe {
+        IOServiceGetMatchingServices(
+            kIOMainPortDefault,
+            Some(matching.as_opaque().into()),
+            &mut iterator,
+        )
+    } != libc::KERN_SUCCESS
+    {
+        sysinfo_debug!("IOServiceGetMatchingServices failed");
+        return Default::default();
+    }
+    let Some(iterator) = IOReleaser::new(iterator) else {
+        return Default::default();
+    };
+
+    while let Some(entry) = IOReleaser::new(IOIteratorNext(iterator.inner())) {
+        let read = |key: &str| {
+            let key = CFString::from_str(key);
+            let data = unsafe {
+                IORegistryEntryCreateCFProperty(entry.inner(), Some(&key), kCFAllocatorDefault, 0)
+            }?
+            .downcast::<CFData>()
+            .ok()?;
+            Some(parse_voltage_states(&data.to_vec()))
+        };
+        if let (Some(efficiency), Some(performance)) =
+            (read("voltage-states1-sram"), read("voltage-states5-sram"))
+        {
+            return (efficiency, performance);
+        }
+    }
+    Default::default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_voltage_states() {
+        let mut data = Vec::new();
+        for (frequency, voltage) in [(600_000_000u32, 800u32), (0, 0), (2_064_000_000, 1000)] {
+            data.extend_from_slice(&frequency.to_le_bytes());
+            data.extend_from_slice(&voltage.to_le_bytes());
+        }
+        assert_eq!(parse_voltage_states(&data), [600, 2064]);
+
+        let mut data = Vec::new();
+        for frequency in [912_000u32, 4_512_000] {
+            data.extend_from_slice(&frequency.to_le_bytes());
+            data.extend_from_slice(&0u32.to_le_bytes());
+        }
+        assert_eq!(parse_voltage_states(&data), [912, 4512]);
+        assert!(parse_voltage_states(&[1, 2, 3]).is_empty());
+    }
+
+    #[test]
+    fn test_usage_and_frequency() {
+        let states = [
+            ("OFF".to_owned(), 0),
+            ("IDLE".to_owned(), 50),
+            ("V0P1".to_owned(), 25),
+            ("V1P2".to_owned(), 25),
+        ];
+        assert_eq!(usage_and_frequency(&states, &[1000, 2000]), (50., 1500));
+        assert_eq!(usage_and_frequency(&states[..2], &[1000, 2000]), (0., 0));
+        assert_eq!(usage_and_frequency(&[], &[]), (0., 0));
+    }
+}
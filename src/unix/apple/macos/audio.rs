@@ -0,0 +1,154 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::sys::macos::ffi::{
+    AudioObjectGetPropertyData, AudioObjectGetPropertyDataSize, AudioObjectPropertyAddress,
+    kAudioDevicePropertyDeviceUID, kAudioDevicePropertyStreams,
+    kAudioHardwarePropertyDefaultInputDevice, kAudioHardwarePropertyDefaultOutputDevice,
+    kAudioHardwarePropertyDevices, kAudioObjectPropertyElementMain, kAudioObjectPropertyName,
+    kAudioObjectPropertyScopeGlobal, kAudioObjectPropertyScopeInput,
+    kAudioObjectPropertyScopeOutput, kAudioObjectSystemObject,
+};
+use crate::{AudioDevice, AudioDeviceKind};
+
+use objc2_core_foundation::{CFRetained, CFString};
+
+use std::mem;
+use std::ptr::{self, NonNull};
+
+fn address(selector: u32, scope: u32) -> AudioObjectPropertyAddress {
+    AudioObjectPropertyAddress {
+        mSelector: selector,
+        mScope: scope,
+        mElement: kAudioObjectPropertyElementMain,
+    }
+}
+
+fn get_data_size(object_id: u32, address: &AudioObjectPropertyAddress) -> Option<u32> {
+    let mut size = 0;
+    if unsafe { AudioObjectGetPropertyDataSize(object_id, address, 0, ptr::null(), &mut size) } != 0
+    {
+        return None;
+    }
+    Some(size)
+}
+
+fn get_u32(object_id: u32, address: &AudioObjectPropertyAddress) -> Option<u32> {
+    let mut value = 0u32;
+    let mut size = mem::size_of::<u32>() as u32;
+    if unsafe {
+        AudioObjectGetPropertyData(
+            object_id,
+            address,
+            0,
+            ptr::null(),
+            &mut size,
+            (&mut value as *mut u32).cast(),
+        )
+    } != 0
+    {
+        return None;
+    }
+    Some(value)
+}
+
+fn get_string(object_id: u32, selector: u32) -> Option<String> {
+    let address = address(selector, kAudioObjectPropertyScopeGlobal);
+    let mut value: *const CFString = ptr::null();
+    let mut size = mem::size_of::<*const CFString>() as u32;
+    if unsafe {
+        AudioObjectGetPropertyData(
+            object_id,
+            &address,
+            0,
+            ptr::null(),
+            &mut size,
+            (&mut value as *mut *const CFString).cast(),
+        )
+    } != 0
+    {
+        return None;
+    }
+    // The returned string is owned by the caller.
+    let value = unsafe { CFRetained::from_raw(NonNull::new(value as *mut CFString)?) };
+    Some(value.to_string())
+}
+
+fn get_device_ids() -> Vec<u32> {
+    let address = address(
+        kAudioHardwarePropertyDevices,
+        kAudioObjectPropertyScopeGlobal,
+    );
+    let Some(size) = get_data_size(kAudioObjectSystemObject, &address) else {
+        sysinfo_debug!("failed to get the number of audio devices");
+        return Vec::new();
+    };
+    let mut ids = vec![0u32; size as usize / mem::size_of::<u32>()];
+    let mut size = (ids.len() * mem::size_of::<u32>()) as u32;
+    if unsafe {
+        AudioObjectGetPropertyData(
+            kAudioObjectSystemObject,
+            &address,
+            0,
+            ptr::null(),
+            &mut size,
+            ids.as_mut_ptr().cast(),
+        )
+    } != 0
+    {
+        sysinfo_debug!("failed to get the list of audio devices");
+        return Vec::new();
+    }
+    ids.truncate(size as usize / mem::size_of::<u32>());
+    ids
+}
+
+pub(crate) fn get_audio_devices(devices: &mut Vec<AudioDevice>) {
+    devices.clear();
+
+    let default_output = get_u32(
+        kAudioObjectSystemObject,
+        &address(
+            kAudioHardwarePropertyDefaultOutputDevice,
+            kAudioObjectPropertyScopeGlobal,
+        ),
+    );
+    let default_input = get_u32(
+        kAudioObjectSystemObject,
+        &address(
+            kAudioHardwarePropertyDefaultInputDevice,
+            kAudioObjectPropertyScopeGlobal,
+        ),
+    );
+
+    for device_id in get_device_ids() {
+        let Some(id) = get_string(device_id, kAudioDevicePropertyDeviceUID) else {
+            continue;
+        };
+        let name = get_string(device_id, kAudioObjectPropertyName).unwrap_or_else(|| id.clone());
+        for (kind, scope, default) in [
+            (
+                AudioDeviceKind::Output,
+                kAudioObjectPropertyScopeOutput,
+                default_output,
+            ),
+            (
+                AudioDeviceKind::Input,
+                kAudioObjectPropertyScopeInput,
+                default_input,
+            ),
+        ] {
+            // A device without any stream in a scope cannot be used in this direction.
+            let has_streams =
+                get_data_size(device_id, &address(kAudioDevicePropertyStreams, scope))
+                    .is_some_and(|size| size > 0);
+            if has_streams {
+                devices.push(AudioDevice {
+                    id: id.clone(),
+                    name: name.clone(),
+                    kind,
+                    is_default: default == Some(device_id),
+                });
+            }
+        }
+    }
+}
@@ -5,7 +5,6 @@ use objc2_core_foundation::{
 };
 use objc2_io_kit::{IOHIDEventSystemClient, IOHIDServiceClient};
 
-use crate::Component;
 use crate::sys::inner::ffi::{
     HID_DEVICE_PROPERTY_PRIMARY_USAGE, HID_DEVICE_PROPERTY_PRIMARY_USAGE_PAGE,
     HID_DEVICE_PROPERTY_PRODUCT, IOHIDEventFieldBase, IOHIDEventGetFloatValue,
@@ -13,6 +12,7 @@ use crate::sys::inner::ffi::{
     kHIDPage_AppleVendor, kHIDUsage_AppleVendor_TemperatureSensor, kIOHIDEventTypeTemperature,
     kIOHIDSerialNumberKey,
 };
+use crate::{Component, ValueSource};
 
 pub(crate) struct ComponentsInner {
     pub(crate) components: Vec<Component>,
@@ -117,7 +117,10 @@ impl ComponentsInner {
                 let mut component = ComponentInner::new(serial, name_str, None, None, service);
                 component.refresh();
 
-                self.components.push(Component { inner: component });
+                self.components.push(Component {
+                    inner: component,
+                    max_observed: None,
+                });
             }
         }
     }
@@ -169,10 +172,27 @@ impl ComponentInner {
         self.critical
     }
 
+    pub(crate) fn max_source(&self) -> Option<ValueSource> {
+        Some(ValueSource::Inferred)
+    }
+
+    pub(crate) fn critical_source(&self) -> Option<ValueSource> {
+        self.critical.map(|_| ValueSource::Hardware)
+    }
+
     pub(crate) fn label(&self) -> &str {
         &self.label
     }
 
+    // The label is the product name reported by the HID service.
+    pub(crate) fn raw_label(&self) -> Option<&str> {
+        Some(&self.label)
+    }
+
+    pub(crate) fn chip(&self) -> Option<&str> {
+        None
+    }
+
     pub(crate) fn id(&self) -> Option<&str> {
         self.id.as_deref()
     }
@@ -1,7 +1,7 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
-use crate::Component;
 use crate::sys::macos::{ffi, utils::IOReleaser};
+use crate::{Component, ValueSource};
 
 use libc::{c_char, c_int, c_void};
 use objc2_core_foundation::{CFDictionary, CFRetained};
@@ -118,7 +118,10 @@ impl ComponentsInner {
                 v,
                 connection,
             ) {
-                self.components.push(Component { inner: c });
+                self.components.push(Component {
+                    inner: c,
+                    max_observed: None,
+                });
             }
         }
     }
@@ -168,10 +171,26 @@ impl ComponentInner {
         self.critical
     }
 
+    pub(crate) fn max_source(&self) -> Option<ValueSource> {
+        Some(ValueSource::Inferred)
+    }
+
+    pub(crate) fn critical_source(&self) -> Option<ValueSource> {
+        self.critical.map(|_| ValueSource::Hardware)
+    }
+
     pub(crate) fn label(&self) -> &str {
         &self.label
     }
 
+    pub(crate) fn raw_label(&self) -> Option<&str> {
+        None
+    }
+
+    pub(crate) fn chip(&self) -> Option<&str> {
+        None
+    }
+
     pub(crate) fn id(&self) -> Option<&str> {
         Some(&self.id)
     }
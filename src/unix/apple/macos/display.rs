@@ -0,0 +1,50 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::Display;
+use crate::sys::macos::ffi::{
+    CGDirectDisplayID, CGDisplayCopyDisplayMode, CGDisplayModeGetPixelHeight,
+    CGDisplayModeGetPixelWidth, CGDisplayModeGetRefreshRate, CGDisplayModeRelease,
+    CGGetActiveDisplayList, CGMainDisplayID,
+};
+
+use std::ptr;
+
+pub(crate) fn get_displays(displays: &mut Vec<Display>) {
+    displays.clear();
+
+    unsafe {
+        let mut count = 0;
+        if CGGetActiveDisplayList(0, ptr::null_mut(), &mut count) != 0 {
+            sysinfo_debug!("CGGetActiveDisplayList failed");
+            return;
+        }
+        let mut ids: Vec<CGDirectDisplayID> = vec![0; count as usize];
+        if CGGetActiveDisplayList(count, ids.as_mut_ptr(), &mut count) != 0 {
+            sysinfo_debug!("CGGetActiveDisplayList failed");
+            return;
+        }
+        ids.truncate(count as usize);
+
+        let main_display = CGMainDisplayID();
+        for id in ids {
+            let mode = CGDisplayCopyDisplayMode(id);
+            if mode.is_null() {
+                continue;
+            }
+            let width = CGDisplayModeGetPixelWidth(mode) as u32;
+            let height = CGDisplayModeGetPixelHeight(mode) as u32;
+            // Built-in displays report a refresh rate of `0`.
+            let refresh_rate = Some(CGDisplayModeGetRefreshRate(mode)).filter(|rate| *rate > 0.);
+            CGDisplayModeRelease(mode);
+            displays.push(Display {
+                id: id.to_string(),
+                name: None,
+                width,
+                height,
+                refresh_rate,
+                gpu: None,
+                is_primary: id == main_display,
+            });
+        }
+    }
+}
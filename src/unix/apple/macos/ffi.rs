@@ -172,3 +172,228 @@ mod private {
     target_arch = "aarch64"
 ))]
 pub use private::*;
+
+/// Private `IOReport` APIs, used to retrieve the metrics of Apple Silicon chips.
+#[cfg(all(feature = "apple-silicon", not(feature = "apple-sandbox")))]
+mod ioreport {
+    use std::ffi::c_void;
+    use std::ptr::NonNull;
+
+    use objc2_core_foundation::{CFDictionary, CFMutableDictionary, CFString};
+
+    #[repr(C)]
+    pub struct IOReportSubscription(c_void);
+
+    objc2_core_foundation::cf_type!(
+        unsafe impl IOReportSubscription {}
+    );
+
+    #[link(name = "IOReport", kind = "dylib")]
+    unsafe extern "C" {
+        pub(crate) fn IOReportCopyChannelsInGroup(
+            group: Option<&CFString>,
+            subgroup: Option<&CFString>,
+            a: u64,
+            b: u64,
+            c: u64,
+        ) -> Option<NonNull<CFMutableDictionary>>;
+
+        pub(crate) fn IOReportMergeChannels(
+            a: &CFMutableDictionary,
+            b: &CFMutableDictionary,
+            nil: *const c_void,
+        );
+
+        pub(crate) fn IOReportCreateSubscription(
+            a: *const c_void,
+            desired_channels: &CFMutableDictionary,
+            subscribed_channels: *mut *mut CFMutableDictionary,
+            channel_id: u64,
+            b: *const c_void,
+        ) -> Option<NonNull<IOReportSubscription>>;
+
+        pub(crate) fn IOReportCreateSamples(
+            subscription: &IOReportSubscription,
+            subscribed_channels: &CFMutableDictionary,
+            a: *const c_void,
+        ) -> Option<NonNull<CFDictionary>>;
+
+        pub(crate) fn IOReportCreateSamplesDelta(
+            previous: &CFDictionary,
+            current: &CFDictionary,
+            a: *const c_void,
+        ) -> Option<NonNull<CFDictionary>>;
+
+        pub(crate) fn IOReportChannelGetGroup(item: &CFDictionary) -> *const CFString;
+        pub(crate) fn IOReportChannelGetSubGroup(item: &CFDictionary) -> *const CFString;
+        pub(crate) fn IOReportChannelGetChannelName(item: &CFDictionary) -> *const CFString;
+        pub(crate) fn IOReportChannelGetUnitLabel(item: &CFDictionary) -> *const CFString;
+        pub(crate) fn IOReportSimpleGetIntegerValue(item: &CFDictionary, index: i32) -> i64;
+        pub(crate) fn IOReportStateGetCount(item: &CFDictionary) -> i32;
+        pub(crate) fn IOReportStateGetNameForIndex(
+            item: &CFDictionary,
+            index: i32,
+        ) -> *const CFString;
+        pub(crate) fn IOReportStateGetResidency(item: &CFDictionary, index: i32) -> i64;
+    }
+}
+
+#[cfg(all(feature = "apple-silicon", not(feature = "apple-sandbox")))]
+pub(crate) use ioreport::*;
+
+/// Code signing APIs from the `Security` framework.
+#[cfg(all(feature = "system", not(feature = "apple-sandbox")))]
+mod security {
+    use objc2_core_foundation::{CFDictionary, CFString, CFType, CFURL};
+
+    #[allow(non_upper_case_globals)]
+    pub(crate) const kSecCSDefaultFlags: u32 = 0;
+    #[allow(non_upper_case_globals)]
+    pub(crate) const kSecCSSigningInformation: u32 = 1 << 1;
+
+    #[link(name = "Security", kind = "framework")]
+    unsafe extern "C" {
+        pub(crate) static kSecCodeInfoTeamIdentifier: &'static CFString;
+
+        pub(crate) fn SecStaticCodeCreateWithPath(
+            path: &CFURL,
+            flags: u32,
+            static_code: *mut *const CFType,
+        ) -> i32;
+
+        pub(crate) fn SecCodeCopySigningInformation(
+            code: &CFType,
+            flags: u32,
+            information: *mut *const CFDictionary,
+        ) -> i32;
+    }
+}
+
+#[cfg(all(feature = "system", not(feature = "apple-sandbox")))]
+pub(crate) use security::*;
+
+/// `libproc` file descriptor types which aren't provided by `libc`.
+#[cfg(all(feature = "system", not(feature = "apple-sandbox")))]
+mod libproc {
+    pub(crate) const PROC_PIDFDVNODEPATHINFO: libc::c_int = 2;
+
+    #[repr(C)]
+    pub(crate) struct proc_fileinfo {
+        pub(crate) fi_openflags: u32,
+        pub(crate) fi_status: u32,
+        pub(crate) fi_offset: libc::off_t,
+        pub(crate) fi_type: i32,
+        pub(crate) fi_guardflags: u32,
+    }
+
+    #[repr(C)]
+    pub(crate) struct vnode_fdinfowithpath {
+        pub(crate) pfi: proc_fileinfo,
+        pub(crate) pvip: libc::vnode_info_path,
+    }
+}
+
+#[cfg(all(feature = "system", not(feature = "apple-sandbox")))]
+pub(crate) use libproc::*;
+
+/// Device and process enumeration APIs from the `CoreAudio` framework.
+#[cfg(all(
+    any(feature = "audio", feature = "system"),
+    not(feature = "apple-sandbox")
+))]
+#[cfg_attr(not(all(feature = "audio", feature = "system")), allow(dead_code))]
+mod core_audio {
+    use std::ffi::c_void;
+
+    #[allow(non_upper_case_globals)]
+    pub(crate) const kAudioObjectSystemObject: u32 = 1;
+    #[allow(non_upper_case_globals)]
+    pub(crate) const kAudioObjectPropertyElementMain: u32 = 0;
+    #[allow(non_upper_case_globals)]
+    pub(crate) const kAudioObjectPropertyScopeGlobal: u32 = u32::from_be_bytes(*b"glob");
+    #[allow(non_upper_case_globals)]
+    pub(crate) const kAudioObjectPropertyScopeInput: u32 = u32::from_be_bytes(*b"inpt");
+    #[allow(non_upper_case_globals)]
+    pub(crate) const kAudioObjectPropertyScopeOutput: u32 = u32::from_be_bytes(*b"outp");
+    #[allow(non_upper_case_globals)]
+    pub(crate) const kAudioObjectPropertyName: u32 = u32::from_be_bytes(*b"lnam");
+    #[allow(non_upper_case_globals)]
+    pub(crate) const kAudioHardwarePropertyDevices: u32 = u32::from_be_bytes(*b"dev#");
+    #[allow(non_upper_case_globals)]
+    pub(crate) const kAudioHardwarePropertyDefaultInputDevice: u32 = u32::from_be_bytes(*b"dIn ");
+    #[allow(non_upper_case_globals)]
+    pub(crate) const kAudioHardwarePropertyDefaultOutputDevice: u32 = u32::from_be_bytes(*b"dOut");
+    #[allow(non_upper_case_globals)]
+    pub(crate) const kAudioDevicePropertyDeviceUID: u32 = u32::from_be_bytes(*b"uid ");
+    #[allow(non_upper_case_globals)]
+    pub(crate) const kAudioDevicePropertyStreams: u32 = u32::from_be_bytes(*b"stm#");
+    #[allow(non_upper_case_globals)]
+    pub(crate) const kAudioHardwarePropertyProcessObjectList: u32 = u32::from_be_bytes(*b"prs#");
+    #[allow(non_upper_case_globals)]
+    pub(crate) const kAudioProcessPropertyPID: u32 = u32::from_be_bytes(*b"ppid");
+    #[allow(non_upper_case_globals)]
+    pub(crate) const kAudioProcessPropertyIsRunningInput: u32 = u32::from_be_bytes(*b"piri");
+
+    #[repr(C)]
+    #[allow(non_snake_case)]
+    pub(crate) struct AudioObjectPropertyAddress {
+        pub(crate) mSelector: u32,
+        pub(crate) mScope: u32,
+        pub(crate) mElement: u32,
+    }
+
+    #[link(name = "CoreAudio", kind = "framework")]
+    unsafe extern "C" {
+        pub(crate) fn AudioObjectGetPropertyDataSize(
+            object_id: u32,
+            address: *const AudioObjectPropertyAddress,
+            qualifier_data_size: u32,
+            qualifier_data: *const c_void,
+            data_size: *mut u32,
+        ) -> i32;
+
+        pub(crate) fn AudioObjectGetPropertyData(
+            object_id: u32,
+            address: *const AudioObjectPropertyAddress,
+            qualifier_data_size: u32,
+            qualifier_data: *const c_void,
+            data_size: *mut u32,
+            data: *mut c_void,
+        ) -> i32;
+    }
+}
+
+#[cfg(all(
+    any(feature = "audio", feature = "system"),
+    not(feature = "apple-sandbox")
+))]
+pub(crate) use core_audio::*;
+
+/// Display APIs from the `CoreGraphics` framework.
+#[cfg(all(feature = "display", not(feature = "apple-sandbox")))]
+mod core_graphics {
+    use std::ffi::c_void;
+
+    pub(crate) type CGDirectDisplayID = u32;
+    pub(crate) type CGDisplayModeRef = *mut c_void;
+
+    #[link(name = "CoreGraphics", kind = "framework")]
+    unsafe extern "C" {
+        pub(crate) fn CGGetActiveDisplayList(
+            max_displays: u32,
+            active_displays: *mut CGDirectDisplayID,
+            display_count: *mut u32,
+        ) -> i32;
+
+        pub(crate) fn CGMainDisplayID() -> CGDirectDisplayID;
+
+        pub(crate) fn CGDisplayCopyDisplayMode(display: CGDirectDisplayID) -> CGDisplayModeRef;
+        pub(crate) fn CGDisplayModeGetPixelWidth(mode: CGDisplayModeRef) -> usize;
+        pub(crate) fn CGDisplayModeGetPixelHeight(mode: CGDisplayModeRef) -> usize;
+        pub(crate) fn CGDisplayModeGetRefreshRate(mode: CGDisplayModeRef) -> f64;
+        pub(crate) fn CGDisplayModeRelease(mode: CGDisplayModeRef);
+    }
+}
+
+#[cfg(all(feature = "display", not(feature = "apple-sandbox")))]
+pub(crate) use core_graphics::*;
@@ -19,6 +19,7 @@ cfg_if! {
                 not(feature = "apple-sandbox"),
                 any(
                     feature = "system",
+                    feature = "usb",
                     all(
                         feature = "component",
                         any(target_arch = "x86", target_arch = "x86_64")
@@ -34,6 +35,22 @@ cfg_if! {
         pub mod disk;
     }
 
+    if #[cfg(all(feature = "apple-silicon", not(feature = "apple-sandbox")))] {
+        pub(crate) mod apple_silicon;
+    }
+
+    if #[cfg(all(feature = "audio", not(feature = "apple-sandbox")))] {
+        pub mod audio;
+    }
+
+    if #[cfg(all(feature = "display", not(feature = "apple-sandbox")))] {
+        pub mod display;
+    }
+
+    if #[cfg(all(feature = "usb", not(feature = "apple-sandbox")))] {
+        pub mod usb;
+    }
+
     if #[cfg(feature = "apple-sandbox")] {
         #[cfg(feature = "component")]
         pub use crate::sys::app_store::component;
@@ -44,14 +61,22 @@ cfg_if! {
 
 // Make formattable by rustfmt.
 #[cfg(any())]
+mod apple_silicon;
+#[cfg(any())]
+mod audio;
+#[cfg(any())]
 mod component;
 #[cfg(any())]
 mod cpu;
 #[cfg(any())]
 mod disk;
 #[cfg(any())]
+mod display;
+#[cfg(any())]
 mod process;
 #[cfg(any())]
 mod system;
 #[cfg(any())]
+mod usb;
+#[cfg(any())]
 mod utils;
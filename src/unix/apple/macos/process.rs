@@ -1,15 +1,28 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
-use std::ffi::{OsStr, OsString};
+use std::collections::{HashMap, HashSet};
+use std::ffi::{CStr, OsStr, OsString};
 use std::mem::{self, MaybeUninit};
 use std::os::unix::ffi::{OsStrExt, OsStringExt};
 use std::path::{Path, PathBuf};
 use std::process::ExitStatus;
-
-use libc::{c_int, c_void, kill};
-
-use crate::{DiskUsage, Gid, Pid, Process, ProcessRefreshKind, ProcessStatus, Signal, Uid};
-
+use std::sync::{Mutex, OnceLock};
+use std::time::Instant;
+
+use libc::{c_char, c_int, c_void, kill};
+
+use crate::{
+    CurrentProcessUsage, DiskUsage, EnergyImpact, ExeMetadata, ExeSignature, Gid, MediaUsage,
+    PathUsage, Pid, Process, ProcessRefreshKind, ProcessStatus, ProcessesToUpdate, QosClass,
+    Signal, Uid,
+};
+
+use crate::sys::macos::ffi::{
+    AudioObjectGetPropertyData, AudioObjectGetPropertyDataSize, AudioObjectPropertyAddress,
+    kAudioHardwarePropertyProcessObjectList, kAudioObjectPropertyElementMain,
+    kAudioObjectPropertyScopeGlobal, kAudioObjectSystemObject, kAudioProcessPropertyIsRunningInput,
+    kAudioProcessPropertyPID,
+};
 use crate::sys::process::ThreadStatus;
 use crate::sys::system::Wrap;
 use crate::unix::utils::cstr_to_rust_with_size;
@@ -20,6 +33,13 @@ pub(crate) struct ProcessInner {
     pub(crate) exe: Option<PathBuf>,
     pid: Pid,
     parent: Option<Pid>,
+    reparented_to_init: bool,
+    exec_count: u64,
+    /// Last `pbi_comm` seen, used to detect `exec` calls.
+    comm: Option<[c_char; libc::MAXCOMLEN]>,
+    thread_count: Option<usize>,
+    tty_dev: Option<libc::dev_t>,
+    process_group_id: Option<Pid>,
     pub(crate) environ: Vec<OsString>,
     cwd: Option<PathBuf>,
     pub(crate) root: Option<PathBuf>,
@@ -46,15 +66,54 @@ pub(crate) struct ProcessInner {
     pub(crate) read_bytes: u64,
     pub(crate) written_bytes: u64,
     accumulated_cpu_time: u64,
+    children_cpu_time: u64,
+    exe_signature: OnceLock<Option<ExeSignature>>,
+    exe_metadata: OnceLock<Option<ExeMetadata>>,
+    bundle_info: OnceLock<BundleInfo>,
+    old_energy_sample: Option<EnergySample>,
+    energy_impact: Option<EnergyImpact>,
+    media_usage: Option<MediaUsage>,
     exists: bool,
 }
 
+struct EnergySample {
+    time: Instant,
+    /// In nanojoules.
+    billed_energy: u64,
+    interrupt_wakeups: u64,
+    idle_wakeups: u64,
+    /// CPU time spent in each QoS class, in the same order as `QOS_CLASSES`.
+    qos_cpu_times: [u64; 7],
+}
+
+const QOS_CLASSES: [QosClass; 7] = [
+    QosClass::UserInteractive,
+    QosClass::UserInitiated,
+    QosClass::Default,
+    QosClass::Utility,
+    QosClass::Background,
+    QosClass::Maintenance,
+    QosClass::Legacy,
+];
+
+#[derive(Default)]
+struct BundleInfo {
+    identifier: Option<String>,
+    name: Option<String>,
+}
+
 impl ProcessInner {
     pub(crate) fn new_empty(pid: Pid) -> Self {
         Self {
             name: OsString::new(),
             pid,
             parent: None,
+            reparented_to_init: false,
+            exec_count: 0,
+            comm: None,
+            thread_count: None,
+            tty_dev: None,
+            process_group_id: None,
             cmd: Vec::new(),
             environ: Vec::new(),
             exe: None,
@@ -79,6 +138,13 @@ impl ProcessInner {
             read_bytes: 0,
             written_bytes: 0,
             accumulated_cpu_time: 0,
+            children_cpu_time: 0,
+            exe_signature: OnceLock::new(),
+            exe_metadata: OnceLock::new(),
+            bundle_info: OnceLock::new(),
+            old_energy_sample: None,
+            energy_impact: None,
+            media_usage: None,
             exists: true,
         }
     }
@@ -88,6 +154,12 @@ impl ProcessInner {
             name: OsString::new(),
             pid,
             parent,
+            reparented_to_init: false,
+            exec_count: 0,
+            comm: None,
+            thread_count: None,
+            tty_dev: None,
+            process_group_id: None,
             cmd: Vec::new(),
             environ: Vec::new(),
             exe: None,
@@ -112,6 +184,13 @@ impl ProcessInner {
             read_bytes: 0,
             written_bytes: 0,
             accumulated_cpu_time: 0,
+            children_cpu_time: 0,
+            exe_signature: OnceLock::new(),
+            exe_metadata: OnceLock::new(),
+            bundle_info: OnceLock::new(),
+            old_energy_sample: None,
+            energy_impact: None,
+            media_usage: None,
             exists: true,
         }
     }
@@ -161,6 +240,77 @@ impl ProcessInner {
         self.parent
     }
 
+    pub(crate) fn thread_count(&self) -> Option<usize> {
+        self.thread_count
+    }
+
+    pub(crate) fn tty(&self) -> Option<String> {
+        get_tty_name(self.tty_dev?)
+    }
+
+    pub(crate) fn reparented_to_init(&self) -> bool {
+        self.reparented_to_init
+    }
+
+    pub(crate) fn exec_count(&self) -> u64 {
+        self.exec_count
+    }
+
+    pub(crate) fn path_usage(&self, path: &Path) -> PathUsage {
+        use crate::sys::inner::ffi::{PROC_PIDFDVNODEPATHINFO, vnode_fdinfowithpath};
+
+        let mut usage = PathUsage::from_process_paths(path, self.cwd(), self.root(), self.exe());
+        let buffer_size_bytes = unsafe {
+            libc::proc_pidinfo(
+                self.pid.0,
+                libc::PROC_PIDLISTFDS,
+                0,
+                std::ptr::null_mut(),
+                0,
+            )
+        };
+        if buffer_size_bytes <= 0 {
+            return usage;
+        }
+        let mut fds = Vec::<libc::proc_fdinfo>::with_capacity(
+            buffer_size_bytes as usize / mem::size_of::<libc::proc_fdinfo>(),
+        );
+        unsafe {
+            let buffer_size_bytes = libc::proc_pidinfo(
+                self.pid.0,
+                libc::PROC_PIDLISTFDS,
+                0,
+                fds.as_mut_ptr() as *mut _,
+                (fds.capacity() * mem::size_of::<libc::proc_fdinfo>()) as _,
+            );
+            if buffer_size_bytes <= 0 {
+                return usage;
+            }
+            fds.set_len(buffer_size_bytes as usize / mem::size_of::<libc::proc_fdinfo>());
+        }
+        usage.open_file = fds
+            .iter()
+            .filter(|fd| fd.proc_fdtype == libc::PROX_FDTYPE_VNODE as u32)
+            .any(|fd| unsafe {
+                let mut info = mem::zeroed::<vnode_fdinfowithpath>();
+                let result = libc::proc_pidfdinfo(
+                    self.pid.0,
+                    fd.proc_fd,
+                    PROC_PIDFDVNODEPATHINFO,
+                    &mut info as *mut _ as *mut _,
+                    mem::size_of::<vnode_fdinfowithpath>() as _,
+                );
+                result > 0
+                    && convert_node_path_info(&info.pvip)
+                        .is_some_and(|target| target.starts_with(path))
+            });
+        usage
+    }
+
+    pub(crate) fn deleted_mappings(&self) -> Vec<PathBuf> {
+        Vec::new()
+    }
+
     pub(crate) fn status(&self) -> ProcessStatus {
         // If the status is `Run`, then it's very likely wrong so we instead
         // return a `ProcessStatus` converted from the `ThreadStatus`.
@@ -176,6 +326,10 @@ impl ProcessInner {
         self.start_time
     }
 
+    pub(crate) fn start_time_raw(&self) -> u64 {
+        self.start_time
+    }
+
     pub(crate) fn run_time(&self) -> u64 {
         self.run_time
     }
@@ -188,6 +342,10 @@ impl ProcessInner {
         self.accumulated_cpu_time
     }
 
+    pub(crate) fn children_cpu_time(&self) -> u64 {
+        self.children_cpu_time
+    }
+
     pub(crate) fn disk_usage(&self) -> DiskUsage {
         DiskUsage {
             read_bytes: self.read_bytes.saturating_sub(self.old_read_bytes),
@@ -228,6 +386,40 @@ impl ProcessInner {
         }
     }
 
+    pub(crate) fn process_group_id(&self) -> Option<Pid> {
+        self.process_group_id
+    }
+
+    pub(crate) fn current_usage() -> Option<CurrentProcessUsage> {
+        let mut info = MaybeUninit::<libc::mach_task_basic_info>::uninit();
+        let mut count = libc::MACH_TASK_BASIC_INFO_COUNT;
+        unsafe {
+            if libc::task_info(
+                #[allow(deprecated)]
+                libc::mach_task_self(),
+                libc::MACH_TASK_BASIC_INFO,
+                info.as_mut_ptr() as *mut _,
+                &mut count,
+            ) != libc::KERN_SUCCESS
+            {
+                return None;
+            }
+            let info = info.assume_init();
+            let to_ms = |time: libc::time_value_t| {
+                time.seconds as u64 * 1_000 + time.microseconds as u64 / 1_000
+            };
+            Some(CurrentProcessUsage {
+                memory: info.resident_size,
+                virtual_memory: info.virtual_size,
+                accumulated_cpu_time: to_ms(info.user_time).saturating_add(to_ms(info.system_time)),
+            })
+        }
+    }
+
+    pub(crate) fn children_usage() -> Option<crate::ChildrenUsage> {
+        crate::unix::utils::children_usage()
+    }
+
     pub(crate) fn switch_updated(&mut self) -> bool {
         std::mem::replace(&mut self.updated, false)
     }
@@ -239,6 +431,53 @@ impl ProcessInner {
     pub(crate) fn exists(&self) -> bool {
         self.exists
     }
+
+    pub(crate) fn exe_signature(&self) -> Option<&ExeSignature> {
+        let exe = self.exe.as_deref()?;
+        self.exe_signature
+            .get_or_init(|| {
+                get_team_id(exe)
+                    .map(ExeSignature::TeamId)
+                    .or_else(|| crate::sha256::sha256_file(exe).map(ExeSignature::Sha256))
+            })
+            .as_ref()
+    }
+
+    pub(crate) fn exe_metadata(&self) -> Option<&ExeMetadata> {
+        let exe = self.exe.as_deref()?;
+        self.exe_metadata
+            .get_or_init(|| crate::exe_metadata::read_exe_metadata(exe))
+            .as_ref()
+    }
+
+    pub(crate) fn bundle_identifier(&self) -> Option<&str> {
+        self.bundle_info().identifier.as_deref()
+    }
+
+    pub(crate) fn bundle_name(&self) -> Option<&str> {
+        self.bundle_info().name.as_deref()
+    }
+
+    pub(crate) fn energy_impact(&self) -> Option<EnergyImpact> {
+        self.energy_impact
+    }
+
+    pub(crate) fn gpu_usage(&self) -> Option<f32> {
+        None
+    }
+
+    pub(crate) fn media_usage(&self) -> Option<MediaUsage> {
+        self.media_usage
+    }
+
+    fn bundle_info(&self) -> &BundleInfo {
+        self.bundle_info.get_or_init(|| {
+            self.exe
+                .as_deref()
+                .and_then(get_bundle_info)
+                .unwrap_or_default()
+        })
+    }
 }
 
 #[allow(deprecated)] // Because of libc::mach_absolute_time.
@@ -293,6 +532,11 @@ pub(crate) fn compute_cpu_usage(
     }
 }
 
+/// Returns `None` if `proc_pidinfo` failed to fill `task_info` (a process has at least a thread).
+fn get_thread_count(task_info: &libc::proc_taskinfo) -> Option<usize> {
+    (task_info.pti_threadnum > 0).then_some(task_info.pti_threadnum as _)
+}
+
 unsafe fn get_task_info(pid: Pid) -> libc::proc_taskinfo {
     unsafe {
         let mut task_info = mem::zeroed::<libc::proc_taskinfo>();
@@ -310,7 +554,7 @@ unsafe fn get_task_info(pid: Pid) -> libc::proc_taskinfo {
 }
 
 #[inline]
-fn check_if_pid_is_alive(pid: Pid, check_if_alive: bool) -> bool {
+pub(crate) fn check_if_pid_is_alive(pid: Pid, check_if_alive: bool) -> bool {
     // In case we are iterating all pids we got from `proc_listallpids`, then
     // there is no point checking if the process is alive since it was returned
     // from this function.
@@ -347,6 +591,68 @@ unsafe fn get_bsd_info(pid: Pid) -> Option<libc::proc_bsdinfo> {
     }
 }
 
+/// Retrieves both the BSD and the task information with a single `proc_pidinfo` call.
+///
+/// Getting the task information requires more rights than the BSD information, so if it fails,
+/// only the BSD information is retrieved and the task information is left empty, like
+/// [`get_task_info`] does.
+unsafe fn get_bsd_and_task_info(pid: Pid) -> (Option<libc::proc_bsdinfo>, libc::proc_taskinfo) {
+    unsafe {
+        let mut info = mem::zeroed::<libc::proc_taskallinfo>();
+
+        if libc::proc_pidinfo(
+            pid.0,
+            libc::PROC_PIDTASKALLINFO,
+            0,
+            &mut info as *mut _ as *mut _,
+            mem::size_of::<libc::proc_taskallinfo>() as _,
+        ) == mem::size_of::<libc::proc_taskallinfo>() as c_int
+        {
+            (Some(info.pbsd), info.ptinfo)
+        } else {
+            (get_bsd_info(pid), mem::zeroed())
+        }
+    }
+}
+
+/// Returns the task information only if `refresh_kind` needs it, retrieving it with the BSD
+/// information when it does.
+unsafe fn get_infos(
+    pid: Pid,
+    refresh_kind: ProcessRefreshKind,
+) -> (Option<libc::proc_bsdinfo>, Option<libc::proc_taskinfo>) {
+    unsafe {
+        if refresh_kind.cpu() || refresh_kind.memory() {
+            let (info, task_info) = get_bsd_and_task_info(pid);
+            (info, Some(task_info))
+        } else {
+            (get_bsd_info(pid), None)
+        }
+    }
+}
+
+fn get_tty_dev(info: &libc::proc_bsdinfo) -> Option<libc::dev_t> {
+    // `NODEV` (`-1`) means there is no controlling terminal.
+    match info.e_tdev as libc::dev_t {
+        -1 => None,
+        dev => Some(dev),
+    }
+}
+
+fn get_tty_name(dev: libc::dev_t) -> Option<String> {
+    // `devname` returns a pointer to a static buffer.
+    static DEVNAME_LOCK: Mutex<()> = Mutex::new(());
+
+    let _guard = DEVNAME_LOCK.lock().ok()?;
+    let name = unsafe { libc::devname(dev, libc::S_IFCHR) };
+    if name.is_null() {
+        return None;
+    }
+    let name = unsafe { CStr::from_ptr(name) }.to_str().ok()?;
+    // `devname` returns `??` if it cannot find the device.
+    (name != "??").then(|| name.to_owned())
+}
+
 fn get_parent(info: &libc::proc_bsdinfo) -> Option<Pid> {
     match info.pbi_ppid as i32 {
         0 => None,
@@ -359,6 +665,7 @@ unsafe fn create_new_process(
     now: u64,
     refresh_kind: ProcessRefreshKind,
     info: Option<libc::proc_bsdinfo>,
+    task_info: Option<libc::proc_taskinfo>,
     timebase_to_ms: f64,
 ) -> Result<Option<Process>, ()> {
     let info = match info {
@@ -366,9 +673,12 @@ unsafe fn create_new_process(
         None => {
             let mut p = ProcessInner::new_empty(pid);
             unsafe {
-                if get_exe_and_name_backup(&mut p, refresh_kind, false) {
+                if get_exe_and_name_backup(&mut p, refresh_kind, false, false) {
                     get_cwd_root(&mut p, refresh_kind);
-                    return Ok(Some(Process { inner: p }));
+                    return Ok(Some(Process {
+                        inner: p,
+                        last_refreshed_at: None,
+                    }));
                 }
                 // If we can't even have the name, no point in keeping it.
                 return Err(());
@@ -382,9 +692,10 @@ unsafe fn create_new_process(
     let run_time = now.saturating_sub(start_time);
 
     let mut p = ProcessInner::new(pid, parent, start_time, run_time);
+    p.comm = Some(info.pbi_comm);
     unsafe {
-        if !get_process_infos(&mut p, refresh_kind)
-            && !get_exe_and_name_backup(&mut p, refresh_kind, false)
+        if !get_process_infos(&mut p, refresh_kind, false)
+            && !get_exe_and_name_backup(&mut p, refresh_kind, false, false)
         {
             // If we can't even have the name, no point in keeping it.
             return Err(());
@@ -392,10 +703,11 @@ unsafe fn create_new_process(
         get_cwd_root(&mut p, refresh_kind);
 
         if refresh_kind.cpu() || refresh_kind.memory() {
-            let task_info = get_task_info(pid);
+            let task_info = task_info.unwrap_or_else(|| get_task_info(pid));
 
             p.old_stime = task_info.pti_total_system;
             p.old_utime = task_info.pti_total_user;
+            p.thread_count = get_thread_count(&task_info);
             if refresh_kind.cpu() {
                 p.accumulated_cpu_time = (task_info
                     .pti_total_user
@@ -408,16 +720,24 @@ unsafe fn create_new_process(
                 p.virtual_memory = task_info.pti_virtual_size;
             }
         }
+        if refresh_kind.cpu() {
+            update_proc_energy(&mut p, timebase_to_ms);
+        }
 
         p.user_id = Some(Uid(info.pbi_ruid));
         p.effective_user_id = Some(Uid(info.pbi_uid));
         p.group_id = Some(Gid(info.pbi_rgid));
         p.effective_group_id = Some(Gid(info.pbi_gid));
+        p.tty_dev = get_tty_dev(&info);
+        p.process_group_id = Some(Pid(info.pbi_pgid as _));
         p.process_status = ProcessStatus::from(info.pbi_status);
         if refresh_kind.disk_usage() {
             update_proc_disk_activity(&mut p);
         }
-        Ok(Some(Process { inner: p }))
+        Ok(Some(Process {
+            inner: p,
+            last_refreshed_at: None,
+        }))
     }
 }
 
@@ -426,8 +746,11 @@ unsafe fn get_exe_and_name_backup(
     process: &mut ProcessInner,
     refresh_kind: ProcessRefreshKind,
     force_check: bool,
+    exec_detected: bool,
 ) -> bool {
-    let exe_needs_update = refresh_kind.exe().needs_update(|| process.exe.is_none());
+    let exe_needs_update = refresh_kind
+        .exe()
+        .needs_update_if_changed(exec_detected, || process.exe.is_none());
     if !process.name.is_empty() && !exe_needs_update && !force_check {
         return true;
     }
@@ -447,7 +770,11 @@ unsafe fn get_exe_and_name_backup(
                         .unwrap_or_default()
                         .clone_into(&mut process.name);
                 }
-                if exe_needs_update {
+                if exe_needs_update && process.exe.as_ref() != Some(&exe) {
+                    // The process called `exec`, so what was computed from `exe` is outdated.
+                    process.exe_signature = OnceLock::new();
+                    process.exe_metadata = OnceLock::new();
+                    process.bundle_info = OnceLock::new();
                     process.exe = Some(exe);
                 }
                 true
@@ -496,7 +823,51 @@ unsafe fn get_cwd_root(process: &mut ProcessInner, refresh_kind: ProcessRefreshK
     }
 }
 
-unsafe fn get_process_infos(process: &mut ProcessInner, refresh_kind: ProcessRefreshKind) -> bool {
+/// Returns the maximum size of the arguments and environment of a process (`KERN_ARGMAX`), so
+/// `KERN_PROCARGS2` can be retrieved without asking for its size first. It cannot change while the
+/// system is running so it's only retrieved once. Returns `0` if it could not be retrieved.
+fn get_arg_max() -> usize {
+    static ARG_MAX: OnceLock<usize> = OnceLock::new();
+
+    *ARG_MAX.get_or_init(|| {
+        let mut mib: [c_int; 2] = [libc::CTL_KERN, libc::KERN_ARGMAX];
+        let mut arg_max: c_int = 0;
+        let mut len = mem::size_of::<c_int>();
+        unsafe {
+            if libc::sysctl(
+                mib.as_mut_ptr(),
+                mib.len() as _,
+                &mut arg_max as *mut _ as *mut _,
+                &mut len,
+                std::ptr::null_mut(),
+                0,
+            ) == -1
+            {
+                return 0;
+            }
+        }
+        arg_max.max(0) as _
+    })
+}
+
+unsafe fn get_process_infos(
+    process: &mut ProcessInner,
+    refresh_kind: ProcessRefreshKind,
+    exec_detected: bool,
+) -> bool {
+    let exe_needs_update = refresh_kind
+        .exe()
+        .needs_update_if_changed(exec_detected, || process.exe.is_none());
+    let environ_needs_update = refresh_kind
+        .environ()
+        .needs_update_if_changed(exec_detected, || process.environ.is_empty());
+    let cmd_needs_update = refresh_kind
+        .cmd()
+        .needs_update_if_changed(exec_detected, || process.cmd.is_empty());
+    if !process.name.is_empty() && !exe_needs_update && !environ_needs_update && !cmd_needs_update {
+        // Nothing to retrieve, no need to copy the whole arguments and environment.
+        return true;
+    }
     /*
      * /---------------\ 0x00000000
      * | ::::::::::::: |
@@ -528,17 +899,19 @@ unsafe fn get_process_infos(process: &mut ProcessInner, refresh_kind: ProcessRef
      * \---------------/ 0xffffffff
      */
     let mut mib: [libc::c_int; 3] = [libc::CTL_KERN, libc::KERN_PROCARGS2, process.pid.0 as _];
-    let mut arg_max = 0;
+    let mut arg_max = get_arg_max();
     unsafe {
-        // First we retrieve the size we will need for our data (in `arg_max`).
-        if libc::sysctl(
-            mib.as_mut_ptr(),
-            mib.len() as _,
-            std::ptr::null_mut(),
-            &mut arg_max,
-            std::ptr::null_mut(),
-            0,
-        ) == -1
+        // If `KERN_ARGMAX` isn't available, we retrieve the size we will need for our data (in
+        // `arg_max`) first.
+        if arg_max == 0
+            && libc::sysctl(
+                mib.as_mut_ptr(),
+                mib.len() as _,
+                std::ptr::null_mut(),
+                &mut arg_max,
+                std::ptr::null_mut(),
+                0,
+            ) == -1
         {
             sysinfo_debug!(
                 "couldn't get arguments and environment size for PID {}",
@@ -587,14 +960,14 @@ unsafe fn get_process_infos(process: &mut ProcessInner, refresh_kind: ProcessRef
                 .clone_into(&mut process.name);
         }
 
-        if refresh_kind.exe().needs_update(|| process.exe.is_none()) {
+        if exe_needs_update && process.exe.as_deref() != Some(exe) {
+            // The process called `exec`, so what was computed from `exe` is outdated.
+            process.exe_signature = OnceLock::new();
+            process.exe_metadata = OnceLock::new();
+            process.bundle_info = OnceLock::new();
             process.exe = Some(exe.to_owned());
         }
 
-        let environ_needs_update = refresh_kind
-            .environ()
-            .needs_update(|| process.environ.is_empty());
-        let cmd_needs_update = refresh_kind.cmd().needs_update(|| process.cmd.is_empty());
         if !environ_needs_update && !cmd_needs_update {
             // Nothing else to be done!
             return true;
@@ -680,8 +1053,10 @@ pub(crate) fn update_process(
             let p = &mut p.inner;
 
             let mut extra_checked = false;
+            let mut exec_detected = false;
+            let (info, task_info) = get_infos(pid, refresh_kind);
 
-            if let Some(info) = get_bsd_info(pid) {
+            if let Some(info) = info {
                 if info.pbi_start_tvsec != p.start_time {
                     // We don't want it to be removed, just replaced.
                     p.updated = true;
@@ -689,17 +1064,40 @@ pub(crate) fn update_process(
                     p.name.clear();
                     p.exe = None;
                     // The owner of this PID changed.
-                    return create_new_process(pid, now, refresh_kind, Some(info), timebase_to_ms);
+                    return create_new_process(
+                        pid,
+                        now,
+                        refresh_kind,
+                        Some(info),
+                        task_info,
+                        timebase_to_ms,
+                    );
+                }
+                // `pbi_comm` is set by `exec` to the (truncated) name of the executable, so if it
+                // changed since the last refresh, `exec` was called.
+                if p.comm.is_some_and(|comm| comm != info.pbi_comm) {
+                    exec_detected = true;
+                    p.exec_count += 1;
+                    // To ensure the name will be updated.
+                    p.name.clear();
                 }
+                p.comm = Some(info.pbi_comm);
                 let parent = get_parent(&info);
+                p.tty_dev = get_tty_dev(&info);
+                p.process_group_id = Some(Pid(info.pbi_pgid as _));
                 // Update the parent if it changed.
                 if p.parent != parent {
+                    if p.parent.is_some_and(|old_parent| old_parent.0 != 1)
+                        && parent == Some(Pid(1))
+                    {
+                        p.reparented_to_init = true;
+                    }
                     p.parent = parent;
                 }
             } else {
                 // Weird that we can't get this information. Sometimes, mac can list PIDs that do
                 // not exist anymore. So let's ensure that the process is actually still alive.
-                if !get_exe_and_name_backup(p, refresh_kind, true) {
+                if !get_exe_and_name_backup(p, refresh_kind, true, false) {
                     // So it's not actually alive, then let's un-update it so it will be removed.
                     p.updated = false;
                     return Ok(None);
@@ -707,8 +1105,8 @@ pub(crate) fn update_process(
                 extra_checked = true;
             }
 
-            if !get_process_infos(p, refresh_kind) && !extra_checked {
-                get_exe_and_name_backup(p, refresh_kind, false);
+            if !get_process_infos(p, refresh_kind, exec_detected) && !extra_checked {
+                get_exe_and_name_backup(p, refresh_kind, false, exec_detected);
             }
             get_cwd_root(p, refresh_kind);
 
@@ -742,8 +1140,9 @@ pub(crate) fn update_process(
             p.run_time = now.saturating_sub(p.start_time);
 
             if refresh_kind.cpu() || refresh_kind.memory() {
-                let task_info = get_task_info(pid);
+                let task_info = task_info.unwrap_or_else(|| get_task_info(pid));
 
+                p.thread_count = get_thread_count(&task_info);
                 if refresh_kind.cpu() {
                     compute_cpu_usage(p, task_info, system_time, user_time, time_interval);
                     p.accumulated_cpu_time = (task_info
@@ -757,10 +1156,14 @@ pub(crate) fn update_process(
                     p.virtual_memory = task_info.pti_virtual_size;
                 }
             }
+            if refresh_kind.cpu() {
+                update_proc_energy(p, timebase_to_ms);
+            }
             p.updated = true;
             Ok(None)
         } else {
-            create_new_process(pid, now, refresh_kind, get_bsd_info(pid), timebase_to_ms)
+            let (info, task_info) = get_infos(pid, refresh_kind);
+            create_new_process(pid, now, refresh_kind, info, task_info, timebase_to_ms)
         }
     }
 }
@@ -788,6 +1191,170 @@ fn update_proc_disk_activity(p: &mut ProcessInner) {
     }
 }
 
+/// Also updates the CPU time of the children since it comes from the same `rusage_info`.
+fn update_proc_energy(p: &mut ProcessInner, timebase_to_ms: f64) {
+    let mut pidrusage = MaybeUninit::<libc::rusage_info_v4>::uninit();
+
+    let pidrusage = unsafe {
+        let retval = libc::proc_pid_rusage(
+            p.pid().0 as _,
+            libc::RUSAGE_INFO_V4,
+            pidrusage.as_mut_ptr() as _,
+        );
+
+        if retval < 0 {
+            sysinfo_debug!("proc_pid_rusage failed: {:?}", retval);
+            return;
+        }
+        pidrusage.assume_init()
+    };
+    p.children_cpu_time = (pidrusage
+        .ri_child_user_time
+        .saturating_add(pidrusage.ri_child_system_time) as f64
+        * timebase_to_ms) as u64;
+    let new = EnergySample {
+        time: Instant::now(),
+        billed_energy: pidrusage.ri_billed_energy,
+        interrupt_wakeups: pidrusage.ri_interrupt_wkups,
+        idle_wakeups: pidrusage.ri_pkg_idle_wkups,
+        qos_cpu_times: [
+            pidrusage.ri_cpu_time_qos_user_interactive,
+            pidrusage.ri_cpu_time_qos_user_initiated,
+            pidrusage.ri_cpu_time_qos_default,
+            pidrusage.ri_cpu_time_qos_utility,
+            pidrusage.ri_cpu_time_qos_background,
+            pidrusage.ri_cpu_time_qos_maintenance,
+            pidrusage.ri_cpu_time_qos_legacy,
+        ],
+    };
+    if let Some(old) = p.old_energy_sample.take() {
+        let elapsed = new.time.duration_since(old.time).as_secs_f64();
+        let energy = new.billed_energy.saturating_sub(old.billed_energy) as f64 / 1_000_000_000.;
+        let qos_class = QOS_CLASSES
+            .iter()
+            .zip(new.qos_cpu_times.iter().zip(old.qos_cpu_times.iter()))
+            .map(|(class, (new, old))| (class, new.saturating_sub(*old)))
+            .filter(|(_, time)| *time > 0)
+            // In case of equality, `max_by_key` returns the last element so we reverse the
+            // iterator to keep the "highest" QoS class.
+            .rev()
+            .max_by_key(|(_, time)| *time)
+            .map(|(class, _)| *class);
+        p.energy_impact = Some(EnergyImpact {
+            power: if elapsed > 0. { energy / elapsed } else { 0. },
+            interrupt_wakeups: new.interrupt_wakeups.saturating_sub(old.interrupt_wakeups),
+            idle_wakeups: new.idle_wakeups.saturating_sub(old.idle_wakeups),
+            qos_class,
+        });
+    }
+    p.old_energy_sample = Some(new);
+}
+
+/// Updates the media usage of the processes in `processes_to_update`.
+///
+/// Only the audio capture can be detected, through the process objects of `CoreAudio` (which
+/// are available since macOS 14.0).
+pub(crate) fn update_processes_media_usage(
+    process_list: &mut HashMap<Pid, Process>,
+    processes_to_update: ProcessesToUpdate<'_>,
+) {
+    let capturing = get_audio_capture_pids();
+    let usage = |pid: &Pid| {
+        capturing.as_ref().map(|capturing| MediaUsage {
+            audio_capture: Some(capturing.contains(pid)),
+            camera: None,
+            screen_recording: None,
+        })
+    };
+    match processes_to_update {
+        ProcessesToUpdate::All => {
+            for (pid, proc_) in process_list.iter_mut() {
+                proc_.inner.media_usage = usage(pid);
+            }
+        }
+        ProcessesToUpdate::Some(pids) => {
+            for pid in pids {
+                if let Some(proc_) = process_list.get_mut(pid) {
+                    proc_.inner.media_usage = usage(pid);
+                }
+            }
+        }
+    }
+}
+
+fn core_audio_address(selector: u32) -> AudioObjectPropertyAddress {
+    AudioObjectPropertyAddress {
+        mSelector: selector,
+        mScope: kAudioObjectPropertyScopeGlobal,
+        mElement: kAudioObjectPropertyElementMain,
+    }
+}
+
+fn get_core_audio_u32(object_id: u32, selector: u32) -> Option<u32> {
+    let address = core_audio_address(selector);
+    let mut value = 0u32;
+    let mut size = mem::size_of::<u32>() as u32;
+    if unsafe {
+        AudioObjectGetPropertyData(
+            object_id,
+            &address,
+            0,
+            std::ptr::null(),
+            &mut size,
+            (&mut value as *mut u32).cast(),
+        )
+    } != 0
+    {
+        return None;
+    }
+    Some(value)
+}
+
+/// Returns the PIDs of the processes currently recording audio, or `None` if `CoreAudio`
+/// doesn't provide this information.
+fn get_audio_capture_pids() -> Option<HashSet<Pid>> {
+    let address = core_audio_address(kAudioHardwarePropertyProcessObjectList);
+    let mut size = 0;
+    if unsafe {
+        AudioObjectGetPropertyDataSize(
+            kAudioObjectSystemObject,
+            &address,
+            0,
+            std::ptr::null(),
+            &mut size,
+        )
+    } != 0
+    {
+        return None;
+    }
+    let mut objects = vec![0u32; size as usize / mem::size_of::<u32>()];
+    let mut size = (objects.len() * mem::size_of::<u32>()) as u32;
+    if unsafe {
+        AudioObjectGetPropertyData(
+            kAudioObjectSystemObject,
+            &address,
+            0,
+            std::ptr::null(),
+            &mut size,
+            objects.as_mut_ptr().cast(),
+        )
+    } != 0
+    {
+        return None;
+    }
+    objects.truncate(size as usize / mem::size_of::<u32>());
+    Some(
+        objects
+            .into_iter()
+            .filter(|object| {
+                get_core_audio_u32(*object, kAudioProcessPropertyIsRunningInput) == Some(1)
+            })
+            .filter_map(|object| get_core_audio_u32(object, kAudioProcessPropertyPID))
+            .map(|pid| Pid(pid as _))
+            .collect(),
+    )
+}
+
 #[allow(clippy::uninit_vec)]
 pub(crate) fn get_proc_list() -> Option<Vec<Pid>> {
     unsafe {
@@ -808,3 +1375,75 @@ pub(crate) fn get_proc_list() -> Option<Vec<Pid>> {
         }
     }
 }
+
+/// Returns the Team ID of the code signature of `exe`, if any.
+fn get_team_id(exe: &Path) -> Option<String> {
+    use objc2_core_foundation::{CFDictionary, CFRetained, CFString, CFType, CFURL};
+    use std::ptr::{NonNull, null};
+
+    use crate::sys::inner::ffi::{
+        SecCodeCopySigningInformation, SecStaticCodeCreateWithPath, kSecCSDefaultFlags,
+        kSecCSSigningInformation, kSecCodeInfoTeamIdentifier,
+    };
+
+    let path = exe.as_os_str().as_bytes();
+    unsafe {
+        let url =
+            CFURL::from_file_system_representation(None, path.as_ptr(), path.len() as _, false)?;
+
+        let mut code: *const CFType = null();
+        if SecStaticCodeCreateWithPath(&url, kSecCSDefaultFlags, &mut code) != 0 {
+            sysinfo_debug!("SecStaticCodeCreateWithPath failed for {exe:?}");
+            return None;
+        }
+        let code: CFRetained<CFType> = CFRetained::from_raw(NonNull::new(code as *mut _)?);
+
+        let mut info: *const CFDictionary = null();
+        if SecCodeCopySigningInformation(&code, kSecCSSigningInformation, &mut info) != 0 {
+            sysinfo_debug!("SecCodeCopySigningInformation failed for {exe:?}");
+            return None;
+        }
+        let info: CFRetained<CFDictionary> = CFRetained::from_raw(NonNull::new(info as *mut _)?);
+
+        let mut value = null();
+        let key: *const CFString = kSecCodeInfoTeamIdentifier;
+        if info.value_if_present(key.cast(), &mut value) && !value.is_null() {
+            Some((*value.cast::<CFString>()).to_string())
+        } else {
+            None
+        }
+    }
+}
+
+/// Looks for the closest `.app` bundle containing `exe` and reads its `Info.plist`.
+fn get_bundle_info(exe: &Path) -> Option<BundleInfo> {
+    use objc2_core_foundation::{
+        CFBundle, CFString, CFURL, kCFBundleIdentifierKey, kCFBundleNameKey,
+    };
+    use std::ptr::null;
+
+    let bundle = exe
+        .ancestors()
+        .skip(1)
+        .find(|p| p.extension().is_some_and(|ext| ext == "app"))?;
+    let path = bundle.as_os_str().as_bytes();
+    unsafe {
+        let url =
+            CFURL::from_file_system_representation(None, path.as_ptr(), path.len() as _, true)?;
+        let info = CFBundle::info_dictionary_for_url(Some(&url))?;
+
+        let get = |key: Option<&'static CFString>| {
+            let key: *const CFString = key?;
+            let mut value = null();
+            if info.value_if_present(key.cast(), &mut value) && !value.is_null() {
+                Some((*value.cast::<CFString>()).to_string())
+            } else {
+                None
+            }
+        };
+        Some(BundleInfo {
+            identifier: get(kCFBundleIdentifierKey),
+            name: get(kCFBundleNameKey),
+        })
+    }
+}
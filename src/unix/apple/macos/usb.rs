@@ -0,0 +1,111 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::sys::macos::utils::IOReleaser;
+use crate::{UsbDevice, UsbSpeed};
+
+use objc2_core_foundation::{CFNumber, CFRetained, CFString, CFType, kCFAllocatorDefault};
+use objc2_io_kit::{
+    IOIteratorNext, IORegistryEntryCreateCFProperty, IOServiceGetMatchingServices,
+    IOServiceMatching, io_iterator_t, io_registry_entry_t, kIOMainPortDefault,
+};
+
+fn get_property(entry: io_registry_entry_t, key: &str) -> Option<CFRetained<CFType>> {
+    let key = CFString::from_str(key);
+    unsafe { IORegistryEntryCreateCFProperty(entry, Some(&key), kCFAllocatorDefault, 0) }
+}
+
+fn get_string(entry: io_registry_entry_t, key: &str) -> Option<String> {
+    get_property(entry, key)?
+        .downcast::<CFString>()
+        .ok()
+        .map(|s| s.to_string())
+}
+
+fn get_number(entry: io_registry_entry_t, key: &str) -> Option<i64> {
+    get_property(entry, key)?
+        .downcast::<CFNumber>()
+        .ok()?
+        .as_i64()
+}
+
+/// The location ID is made of the bus number in its highest byte followed by one port number per
+/// nibble (ending with zeros).
+fn parse_location_id(location_id: u32) -> (u8, Vec<u8>) {
+    let bus = (location_id >> 24) as u8;
+    let ports = (0..6)
+        .rev()
+        .map(|i| ((location_id >> (i * 4)) & 0xf) as u8)
+        .take_while(|port| *port != 0)
+        .collect();
+    (bus, ports)
+}
+
+pub(crate) fn get_usb_devices(devices: &mut Vec<UsbDevice>) {
+    devices.clear();
+
+    let Some(matching) = (unsafe { IOServiceMatching(c"IOUSBHostDevice".as_ptr()) }) else {
+        sysinfo_debug!("IOServiceMatching call failed, `IOUSBHostDevice` not found");
+        return;
+    };
+    let mut iterator: io_iterator_t = 0;
+    if unsafe {
+        IOServiceGetMatchingServices(
+            kIOMainPortDefault,
+            Some(matching.as_opaque().into()),
+            &mut iterator,
+        )
+    } != libc::KERN_SUCCESS
+    {
+        sysinfo_debug!("IOServiceGetMatchingServices failed");
+        return;
+    }
+    let Some(iterator) = IOReleaser::new(iterator) else {
+        return;
+    };
+
+    while let Some(entry) = IOReleaser::new(IOIteratorNext(iterator.inner())) {
+        let entry = entry.inner();
+        let (Some(vendor_id), Some(product_id)) = (
+            get_number(entry, "idVendor"),
+            get_number(entry, "idProduct"),
+        ) else {
+            continue;
+        };
+        let (bus_number, port_numbers) = match get_number(entry, "locationID") {
+            Some(location_id) => {
+                let (bus, ports) = parse_location_id(location_id as u32);
+                (Some(bus), ports)
+            }
+            None => (None, Vec::new()),
+        };
+        devices.push(UsbDevice {
+            vendor_id: vendor_id as u16,
+            product_id: product_id as u16,
+            manufacturer: get_string(entry, "USB Vendor Name"),
+            product: get_string(entry, "USB Product Name"),
+            serial_number: get_string(entry, "USB Serial Number"),
+            bus_number,
+            port_numbers,
+            // Values of `USBDeviceSpeed` in `IOUSBHostFamily`.
+            speed: match get_number(entry, "Device Speed") {
+                Some(0) => UsbSpeed::Low,
+                Some(1) => UsbSpeed::Full,
+                Some(2) => UsbSpeed::High,
+                Some(3) => UsbSpeed::Super,
+                Some(4) => UsbSpeed::SuperPlus,
+                _ => UsbSpeed::Unknown,
+            },
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_location_id;
+
+    #[test]
+    fn test_parse_location_id() {
+        assert_eq!(parse_location_id(0x14000000), (0x14, vec![]));
+        assert_eq!(parse_location_id(0x14320000), (0x14, vec![3, 2]));
+    }
+}
@@ -7,7 +7,7 @@ mod ffi;
 mod utils;
 
 cfg_if! {
-    if #[cfg(all(target_os = "macos", any(feature = "disk", feature = "system", feature = "component")))] {
+    if #[cfg(all(target_os = "macos", any(feature = "disk", feature = "system", feature = "component", all(any(feature = "audio", feature = "display", feature = "usb"), not(feature = "apple-sandbox")))))] {
         pub(crate) mod macos;
         pub(crate) use self::macos as inner;
     } else if #[cfg(all(target_os = "ios", any(feature = "system", feature = "component")))] {
@@ -22,20 +22,32 @@ cfg_if! {
         pub mod motherboard;
         pub mod process;
         pub mod product;
+        pub mod security;
         pub mod system;
 
         pub(crate) use self::cpu::CpuInner;
+        pub(crate) use crate::unix::ExitedProcessesInner;
         pub(crate) use self::motherboard::MotherboardInner;
         pub(crate) use self::process::ProcessInner;
         pub(crate) use self::product::ProductInner;
+        pub(crate) use self::security::SecurityInfoInner;
         pub(crate) use self::system::SystemInner;
         pub use self::system::{MINIMUM_CPU_UPDATE_INTERVAL, SUPPORTED_SIGNALS};
     }
+    if #[cfg(all(feature = "apple-silicon", target_os = "macos", not(feature = "apple-sandbox")))] {
+        pub(crate) use self::macos::apple_silicon::AppleSiliconMetricsInner;
+    } else if #[cfg(feature = "apple-silicon")] {
+        pub(crate) use crate::unix::AppleSiliconMetricsInner;
+    }
+    if #[cfg(feature = "ebpf")] {
+        pub(crate) use crate::unix::EbpfCollectorsInner;
+    }
     if #[cfg(feature = "disk")] {
         pub mod disk;
 
         pub(crate) use self::disk::DiskInner;
-        pub(crate) use crate::unix::DisksInner;
+        pub(crate) use crate::unix::{DisksInner, StorageInner};
+        pub(crate) use crate::unix::disk_watcher::DiskWatcherInner;
     }
 
     if #[cfg(feature = "component")] {
@@ -48,6 +60,25 @@ cfg_if! {
         pub mod network;
 
         pub(crate) use self::network::{NetworkDataInner, NetworksInner};
+        pub(crate) use crate::unix::NetworkWatcherInner;
+    }
+
+    if #[cfg(feature = "audio")] {
+        pub mod audio;
+
+        pub(crate) use self::audio::get_audio_devices;
+    }
+
+    if #[cfg(feature = "display")] {
+        pub mod display;
+
+        pub(crate) use self::display::get_displays;
+    }
+
+    if #[cfg(feature = "usb")] {
+        pub mod usb;
+
+        pub(crate) use self::usb::get_usb_devices;
     }
 
     if #[cfg(feature = "user")] {
@@ -56,6 +87,10 @@ cfg_if! {
         pub(crate) use crate::unix::groups::get_groups;
         pub(crate) use crate::unix::users::{get_users, UserInner};
     }
+
+    if #[cfg(feature = "zfs")] {
+        pub(crate) use crate::unix::{get_zfs_arc, get_zfs_pools};
+    }
 }
 
 #[doc = include_str!("../../../md_doc/is_supported.md")]
@@ -63,12 +98,16 @@ pub const IS_SUPPORTED_SYSTEM: bool = true;
 
 // Make formattable by rustfmt.
 #[cfg(any())]
+mod audio;
+#[cfg(any())]
 mod component;
 #[cfg(any())]
 mod cpu;
 #[cfg(any())]
 mod disk;
 #[cfg(any())]
+mod display;
+#[cfg(any())]
 mod ios;
 #[cfg(any())]
 mod macos;
@@ -81,6 +120,10 @@ mod process;
 #[cfg(any())]
 mod product;
 #[cfg(any())]
+mod security;
+#[cfg(any())]
 mod system;
 #[cfg(any())]
+mod usb;
+#[cfg(any())]
 mod users;
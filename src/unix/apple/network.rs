@@ -7,10 +7,13 @@ use libc::{
 
 use std::collections::{HashMap, hash_map};
 use std::mem::{MaybeUninit, size_of};
+use std::net::IpAddr;
 use std::ptr::null_mut;
 
 use crate::network::refresh_networks_addresses;
-use crate::{IpNetwork, MacAddr, NetworkData};
+#[cfg(target_os = "macos")]
+use crate::network_helper::get_default_route;
+use crate::{DnsConfiguration, IpNetwork, MacAddr, NetworkData, RefreshError};
 
 // FIXME: To be removed once https://github.com/rust-lang/libc/pull/4022 is merged and released.
 #[repr(C)]
@@ -37,7 +40,8 @@ fn update_field(old_field: &mut u64, new_field: &mut u64, value: u64) {
     *new_field = value;
 }
 
-fn update_network_data(inner: &mut NetworkDataInner, data: &if_data64) {
+fn update_network_data(inner: &mut NetworkDataInner, mib_data: &ifmibdata) {
+    let data = &mib_data.ifmd_data;
     update_field(&mut inner.old_out, &mut inner.current_out, data.ifi_obytes);
     update_field(&mut inner.old_in, &mut inner.current_in, data.ifi_ibytes);
 
@@ -62,6 +66,73 @@ fn update_network_data(inner: &mut NetworkDataInner, data: &if_data64) {
         &mut inner.errors_out,
         data.ifi_oerrors,
     );
+
+    update_field(
+        &mut inner.old_drops_in,
+        &mut inner.drops_in,
+        data.ifi_iqdrops,
+    );
+    update_field(
+        &mut inner.old_drops_out,
+        &mut inner.drops_out,
+        mib_data.ifmd_snd_drops as _,
+    );
+    update_field(
+        &mut inner.old_collisions,
+        &mut inner.collisions,
+        data.ifi_collisions,
+    );
+    update_field(
+        &mut inner.old_multicast_in,
+        &mut inner.multicast_in,
+        data.ifi_imcasts,
+    );
+}
+
+/// Reads the global DNS configuration from the dynamic store of the `SystemConfiguration`
+/// framework.
+#[cfg(target_os = "macos")]
+fn get_dns_configuration() -> Option<DnsConfiguration> {
+    use crate::sys::ffi::{SCDynamicStoreCopyValue, SCDynamicStoreCreate};
+    use objc2_core_foundation::{CFArray, CFDictionary, CFRetained, CFString, CFType};
+    use std::ptr::{NonNull, null};
+
+    unsafe {
+        let name = CFString::from_static_str("sysinfo");
+        let store = SCDynamicStoreCreate(null(), &name, null(), null_mut());
+        let store: CFRetained<CFType> = CFRetained::from_raw(NonNull::new(store)?);
+        let key = CFString::from_static_str("State:/Network/Global/DNS");
+        let value = SCDynamicStoreCopyValue(&store, &key);
+        let value: CFRetained<CFType> = CFRetained::from_raw(NonNull::new(value)?);
+        let dict = value.downcast::<CFDictionary>().ok()?;
+
+        let get_strings = |key: &'static str| -> Vec<String> {
+            let key = CFString::from_static_str(key);
+            let key: *const CFString = &*key;
+            let mut value = null();
+            if !dict.value_if_present(key.cast(), &mut value) || value.is_null() {
+                return Vec::new();
+            }
+            let Some(array) = (*value.cast::<CFType>()).downcast_ref::<CFArray>() else {
+                return Vec::new();
+            };
+            (0..array.count())
+                .filter_map(|index| {
+                    let item = &*array.value_at_index(index).cast::<CFType>();
+                    Some(item.downcast_ref::<CFString>()?.to_string())
+                })
+                .collect()
+        };
+
+        Some(DnsConfiguration {
+            // IPv6 link-local addresses can have a zone index (like `fe80::1%en0`).
+            nameservers: get_strings("ServerAddresses")
+                .iter()
+                .filter_map(|addr| addr.split('%').next()?.parse().ok())
+                .collect(),
+            search_domains: get_strings("SearchDomains"),
+        })
+    }
 }
 
 pub(crate) struct NetworksInner {
@@ -79,6 +150,14 @@ impl NetworksInner {
         &self.interfaces
     }
 
+    pub(crate) fn try_refresh(
+        &mut self,
+        remove_not_listed_interfaces: bool,
+    ) -> Result<(), RefreshError> {
+        self.refresh(remove_not_listed_interfaces);
+        Ok(())
+    }
+
     pub(crate) fn refresh(&mut self, remove_not_listed_interfaces: bool) {
         self.update_networks();
         if remove_not_listed_interfaces {
@@ -93,6 +172,40 @@ impl NetworksInner {
         refresh_networks_addresses(&mut self.interfaces);
     }
 
+    pub(crate) fn dns_configuration() -> DnsConfiguration {
+        cfg_if! {
+            if #[cfg(target_os = "macos")] {
+                get_dns_configuration().unwrap_or_default()
+            } else {
+                DnsConfiguration::default()
+            }
+        }
+    }
+
+    pub(crate) fn default_gateway() -> Option<IpAddr> {
+        cfg_if! {
+            if #[cfg(target_os = "macos")] {
+                get_default_route().map(|(gateway, _)| gateway)
+            } else {
+                None
+            }
+        }
+    }
+
+    pub(crate) fn primary_interface() -> Option<String> {
+        cfg_if! {
+            if #[cfg(target_os = "macos")] {
+                get_default_route().and_then(|(_, interface)| interface)
+            } else {
+                None
+            }
+        }
+    }
+
+    pub(crate) fn protocol_stats() -> Option<crate::ProtocolStats> {
+        None
+    }
+
     #[allow(clippy::cast_ptr_alignment)]
     #[allow(clippy::uninit_vec)]
     fn update_networks(&mut self) {
@@ -186,7 +299,7 @@ impl NetworksInner {
                                 );
                             } else {
                                 let data = mib_data.assume_init();
-                                update_network_data(interface, &data.ifmd_data);
+                                update_network_data(interface, &data);
                             }
                             if interface.mtu != mtu {
                                 interface.mtu = mtu
@@ -200,6 +313,10 @@ impl NetworksInner {
                             let packets_out;
                             let errors_in;
                             let errors_out;
+                            let drops_in;
+                            let drops_out;
+                            let collisions;
+                            let multicast_in;
 
                             if ret < 0 {
                                 sysinfo_debug!(
@@ -212,9 +329,13 @@ impl NetworksInner {
                                 packets_out = 0;
                                 errors_in = 0;
                                 errors_out = 0;
+                                drops_in = 0;
+                                drops_out = 0;
+                                collisions = 0;
+                                multicast_in = 0;
                             } else {
-                                let data = mib_data.assume_init();
-                                let data = data.ifmd_data;
+                                let mib_data = mib_data.assume_init();
+                                let data = mib_data.ifmd_data;
 
                                 current_in = data.ifi_ibytes;
                                 current_out = data.ifi_obytes;
@@ -222,6 +343,10 @@ impl NetworksInner {
                                 packets_out = data.ifi_opackets;
                                 errors_in = data.ifi_ierrors;
                                 errors_out = data.ifi_oerrors;
+                                drops_in = data.ifi_iqdrops;
+                                drops_out = mib_data.ifmd_snd_drops as _;
+                                collisions = data.ifi_collisions;
+                                multicast_in = data.ifi_imcasts;
                             }
 
                             e.insert(NetworkData {
@@ -238,6 +363,14 @@ impl NetworksInner {
                                     old_errors_in: errors_in,
                                     errors_out,
                                     old_errors_out: errors_out,
+                                    drops_in,
+                                    old_drops_in: drops_in,
+                                    drops_out,
+                                    old_drops_out: drops_out,
+                                    collisions,
+                                    old_collisions: collisions,
+                                    multicast_in,
+                                    old_multicast_in: multicast_in,
                                     updated: true,
                                     mac_addr: MacAddr::UNSPECIFIED,
                                     ip_networks: vec![],
@@ -266,6 +399,14 @@ pub(crate) struct NetworkDataInner {
     old_errors_in: u64,
     errors_out: u64,
     old_errors_out: u64,
+    drops_in: u64,
+    old_drops_in: u64,
+    drops_out: u64,
+    old_drops_out: u64,
+    collisions: u64,
+    old_collisions: u64,
+    multicast_in: u64,
+    old_multicast_in: u64,
     updated: bool,
     /// MAC address
     pub(crate) mac_addr: MacAddr,
@@ -324,6 +465,62 @@ impl NetworkDataInner {
         self.errors_out
     }
 
+    pub(crate) fn drops_on_received(&self) -> u64 {
+        self.drops_in.saturating_sub(self.old_drops_in)
+    }
+
+    pub(crate) fn total_drops_on_received(&self) -> u64 {
+        self.drops_in
+    }
+
+    pub(crate) fn drops_on_transmitted(&self) -> u64 {
+        self.drops_out.saturating_sub(self.old_drops_out)
+    }
+
+    pub(crate) fn total_drops_on_transmitted(&self) -> u64 {
+        self.drops_out
+    }
+
+    pub(crate) fn fifo_errors_on_received(&self) -> u64 {
+        0
+    }
+
+    pub(crate) fn total_fifo_errors_on_received(&self) -> u64 {
+        0
+    }
+
+    pub(crate) fn fifo_errors_on_transmitted(&self) -> u64 {
+        0
+    }
+
+    pub(crate) fn total_fifo_errors_on_transmitted(&self) -> u64 {
+        0
+    }
+
+    pub(crate) fn collisions(&self) -> u64 {
+        self.collisions.saturating_sub(self.old_collisions)
+    }
+
+    pub(crate) fn total_collisions(&self) -> u64 {
+        self.collisions
+    }
+
+    pub(crate) fn carrier_errors(&self) -> u64 {
+        0
+    }
+
+    pub(crate) fn total_carrier_errors(&self) -> u64 {
+        0
+    }
+
+    pub(crate) fn multicast_packets_received(&self) -> u64 {
+        self.multicast_in.saturating_sub(self.old_multicast_in)
+    }
+
+    pub(crate) fn total_multicast_packets_received(&self) -> u64 {
+        self.multicast_in
+    }
+
     pub(crate) fn mac_address(&self) -> MacAddr {
         self.mac_addr
     }
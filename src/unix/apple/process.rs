@@ -1,6 +1,7 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
 use std::fmt;
+use std::net::SocketAddr;
 
 use crate::ProcessStatus;
 pub(crate) use crate::sys::inner::process::*;
@@ -101,4 +102,52 @@ impl ProcessInner {
     pub(crate) fn open_files_limit(&self) -> Option<usize> {
         crate::System::open_files_limit()
     }
+
+    pub(crate) fn stack_size(&self) -> Option<u64> {
+        None
+    }
+
+    pub(crate) fn heap_size(&self) -> Option<u64> {
+        None
+    }
+
+    pub(crate) fn cgroup(&self) -> Option<String> {
+        None
+    }
+
+    pub(crate) fn container_id(&self) -> Option<String> {
+        None
+    }
+
+    pub(crate) fn sched_stats(&self) -> Option<crate::SchedStats> {
+        None
+    }
+
+    pub(crate) fn io_counters(&self) -> Option<crate::IoCounters> {
+        None
+    }
+
+    pub(crate) fn wait_channel(&self) -> Option<String> {
+        None
+    }
+
+    pub(crate) fn kernel_stack(&self) -> Result<Vec<String>, crate::RefreshError> {
+        Err(crate::RefreshError::Unsupported)
+    }
+
+    pub(crate) fn connection_count(&self) -> Option<crate::ConnectionCount> {
+        None
+    }
+
+    pub(crate) fn is_64_bit(&self) -> Option<bool> {
+        None
+    }
+
+    pub(crate) fn abi(&self) -> Option<&'static str> {
+        None
+    }
+
+    pub(crate) fn listening_ports(&self) -> Option<Vec<SocketAddr>> {
+        None
+    }
 }
@@ -0,0 +1,66 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::FirewallProfile;
+
+pub(crate) struct SecurityInfoInner;
+
+impl SecurityInfoInner {
+    pub(crate) fn secure_boot_enabled() -> Option<bool> {
+        None
+    }
+
+    pub(crate) fn tpm_present() -> Option<bool> {
+        Some(false)
+    }
+
+    pub(crate) fn root_disk_encrypted() -> Option<bool> {
+        cfg_if! {
+            if #[cfg(all(target_os = "macos", not(feature = "apple-sandbox")))] {
+                // There is no public API to get the FileVault status and `fdesetup status`
+                // doesn't require root privileges.
+                let output = std::process::Command::new("/usr/bin/fdesetup")
+                    .arg("status")
+                    .output()
+                    .ok()?;
+                let output = String::from_utf8_lossy(&output.stdout);
+                if output.starts_with("FileVault is On") {
+                    Some(true)
+                } else if output.starts_with("FileVault is Off") {
+                    Some(false)
+                } else {
+                    None
+                }
+            } else {
+                None
+            }
+        }
+    }
+
+    pub(crate) fn firewall_status() -> Vec<FirewallProfile> {
+        cfg_if! {
+            if #[cfg(all(target_os = "macos", not(feature = "apple-sandbox")))] {
+                let Ok(output) =
+                    std::process::Command::new("/usr/libexec/ApplicationFirewall/socketfilterfw")
+                        .arg("--getglobalstate")
+                        .output()
+                else {
+                    return Vec::new();
+                };
+                let output = String::from_utf8_lossy(&output.stdout);
+                let enabled = if output.starts_with("Firewall is enabled") {
+                    true
+                } else if output.starts_with("Firewall is disabled") {
+                    false
+                } else {
+                    return Vec::new();
+                };
+                vec![FirewallProfile {
+                    name: "Application Firewall".to_owned(),
+                    enabled,
+                }]
+            } else {
+                Vec::new()
+            }
+        }
+    }
+}
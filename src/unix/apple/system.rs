@@ -7,7 +7,7 @@ use crate::sys::utils::{get_sys_value, get_sys_value_by_name};
 
 use crate::{
     Cpu, CpuRefreshKind, LoadAvg, MemoryRefreshKind, Pid, Process, ProcessRefreshKind,
-    ProcessesToUpdate,
+    ProcessStringStats, ProcessesToUpdate, RefreshError,
 };
 
 #[cfg(all(target_os = "macos", not(feature = "apple-sandbox")))]
@@ -15,9 +15,12 @@ use std::cell::UnsafeCell;
 use std::collections::HashMap;
 use std::ffi::CStr;
 use std::mem;
-use std::time::Duration;
+use std::sync::OnceLock;
 #[cfg(all(target_os = "macos", not(feature = "apple-sandbox")))]
 use std::time::SystemTime;
+use std::time::{Duration, Instant};
+
+use objc2_core_foundation::{CFLocale, CFTimeZone};
 
 use libc::{
     _SC_PAGESIZE, c_int, c_void, host_statistics64, mach_port_t, sysconf, sysctl, timeval,
@@ -96,7 +99,20 @@ unsafe impl Send for Wrap<'_> {}
 #[cfg(all(target_os = "macos", not(feature = "apple-sandbox")))]
 unsafe impl Sync for Wrap<'_> {}
 
+/// The boot time doesn't change while the system is running, so it's only retrieved once.
 fn boot_time() -> u64 {
+    static BOOT_TIME: OnceLock<u64> = OnceLock::new();
+
+    let boot_time = *BOOT_TIME.get_or_init(get_boot_time);
+    if boot_time == 0 {
+        // Retrieving it failed, no need to keep this value.
+        get_boot_time()
+    } else {
+        boot_time
+    }
+}
+
+fn get_boot_time() -> u64 {
     let mut boot_time = timeval {
         tv_sec: 0,
         tv_usec: 0,
@@ -223,6 +239,26 @@ impl SystemInner {
         self.cpus.refresh(refresh_kind, self.port);
     }
 
+    pub(crate) fn try_refresh_memory_specifics(
+        &mut self,
+        refresh_kind: MemoryRefreshKind,
+    ) -> Result<(), RefreshError> {
+        self.refresh_memory_specifics(refresh_kind);
+        Ok(())
+    }
+
+    pub(crate) fn try_refresh_cpu_specifics(
+        &mut self,
+        refresh_kind: CpuRefreshKind,
+    ) -> Result<(), RefreshError> {
+        self.refresh_cpu_specifics(refresh_kind);
+        Ok(())
+    }
+
+    pub(crate) fn check_processes_source() -> Result<(), RefreshError> {
+        Ok(())
+    }
+
     pub(crate) fn refresh_cpu_list(&mut self, refresh_kind: CpuRefreshKind) {
         self.cpus = CpusWrapper::new();
         self.cpus.refresh(refresh_kind, self.port);
@@ -244,80 +280,64 @@ impl SystemInner {
         refresh_kind: ProcessRefreshKind,
     ) -> usize {
         use crate::utils::into_iter;
-        use std::sync::atomic::{AtomicUsize, Ordering};
 
-        unsafe {
-            let count = libc::proc_listallpids(::std::ptr::null_mut(), 0);
-            if count < 1 {
-                return 0;
+        let (mut pids, check_if_alive) = match processes_to_update {
+            ProcessesToUpdate::All => match get_proc_list() {
+                Some(pids) => (pids, false),
+                None => return 0,
+            },
+            ProcessesToUpdate::Some(pids) => {
+                // No need to list all processes to only update a few of them, we check that they
+                // are alive instead.
+                let pids = pids
+                    .iter()
+                    .copied()
+                    .filter(|pid| check_if_pid_is_alive(*pid, true))
+                    .collect::<Vec<_>>();
+                (pids, true)
             }
+        };
+        // A process must not be updated twice at the same time.
+        pids.sort_unstable();
+        pids.dedup();
+        let nb_updated = pids.len();
+
+        let now = get_now();
+        let port = self.port;
+        let time_interval = self.clock_info.as_mut().map(|c| c.get_time_interval(port));
+        let timebase_to_ms = self
+            .clock_info
+            .as_ref()
+            .map(|c| c.timebase_to_ms)
+            .unwrap_or_default();
+        let entries: Vec<Process> = {
+            let wrap = &Wrap(UnsafeCell::new(&mut self.process_list));
+
+            #[cfg(feature = "multithread")]
+            use rayon::iter::ParallelIterator;
+
+            into_iter(pids)
+                .flat_map(|pid| {
+                    update_process(
+                        wrap,
+                        pid,
+                        time_interval,
+                        now,
+                        refresh_kind,
+                        check_if_alive,
+                        timebase_to_ms,
+                    )
+                    .unwrap_or_default()
+                })
+                .collect()
+        };
+        entries.into_iter().for_each(|entry| {
+            self.process_list.insert(entry.pid(), entry);
+        });
+        if refresh_kind.media_usage() {
+            update_processes_media_usage(&mut self.process_list, processes_to_update);
         }
-        if let Some(pids) = get_proc_list() {
-            #[inline(always)]
-            fn real_filter(e: Pid, filter: &[Pid]) -> bool {
-                filter.contains(&e)
-            }
-
-            #[inline(always)]
-            fn empty_filter(_e: Pid, _filter: &[Pid]) -> bool {
-                true
-            }
-
-            #[allow(clippy::type_complexity)]
-            let (filter, filter_callback): (
-                &[Pid],
-                &(dyn Fn(Pid, &[Pid]) -> bool + Sync + Send),
-            ) = match processes_to_update {
-                ProcessesToUpdate::All => (&[], &empty_filter),
-                ProcessesToUpdate::Some(pids_to_refresh) => {
-                    if pids_to_refresh.is_empty() {
-                        return 0;
-                    }
-                    (pids_to_refresh, &real_filter)
-                }
-            };
-
-            let nb_updated = AtomicUsize::new(0);
-            let now = get_now();
-            let port = self.port;
-            let time_interval = self.clock_info.as_mut().map(|c| c.get_time_interval(port));
-            let timebase_to_ms = self
-                .clock_info
-                .as_ref()
-                .map(|c| c.timebase_to_ms)
-                .unwrap_or_default();
-            let entries: Vec<Process> = {
-                let wrap = &Wrap(UnsafeCell::new(&mut self.process_list));
-
-                #[cfg(feature = "multithread")]
-                use rayon::iter::ParallelIterator;
-
-                into_iter(pids)
-                    .flat_map(|pid| {
-                        if !filter_callback(pid, filter) {
-                            return None;
-                        }
-                        nb_updated.fetch_add(1, Ordering::Relaxed);
-                        update_process(
-                            wrap,
-                            pid,
-                            time_interval,
-                            now,
-                            refresh_kind,
-                            false,
-                            timebase_to_ms,
-                        )
-                        .unwrap_or_default()
-                    })
-                    .collect()
-            };
-            entries.into_iter().for_each(|entry| {
-                self.process_list.insert(entry.pid(), entry);
-            });
-            nb_updated.into_inner()
-        } else {
-            0
-        }
+        nb_updated
     }
 
     // COMMON PART
@@ -328,6 +348,18 @@ impl SystemInner {
         &self.process_list
     }
 
+    pub(crate) fn process_string_stats(&self) -> ProcessStringStats {
+        ProcessStringStats::default()
+    }
+
+    pub(crate) fn set_max_kept_file_handles(&mut self, _max: usize) {}
+
+    pub(crate) fn kept_file_handles(&self) -> usize {
+        0
+    }
+
+    pub(crate) fn set_use_pidfds(&mut self, _use_pidfds: bool) {}
+
     pub(crate) fn processes_mut(&mut self) -> &mut HashMap<Pid, Process> {
         &mut self.process_list
     }
@@ -340,10 +372,18 @@ impl SystemInner {
         self.cpus.global_cpu.percent()
     }
 
+    pub(crate) fn last_cpu_usage_update(&self) -> Option<Instant> {
+        self.cpus.last_update
+    }
+
     pub(crate) fn cpus(&self) -> &[Cpu] {
         &self.cpus.cpus
     }
 
+    pub(crate) fn cpus_mut(&mut self) -> &mut [Cpu] {
+        &mut self.cpus.cpus
+    }
+
     pub(crate) fn total_memory(&self) -> u64 {
         self.mem_total
     }
@@ -373,6 +413,37 @@ impl SystemInner {
         self.swap_total - self.swap_free
     }
 
+    pub(crate) fn swap_devices(&self) -> Vec<crate::SwapDevice> {
+        Vec::new()
+    }
+
+    pub(crate) fn compressed_memory(&self) -> Option<crate::CompressedMemory> {
+        unsafe {
+            let mut count: u32 = libc::HOST_VM_INFO64_COUNT as _;
+            let mut stat = mem::zeroed::<vm_statistics64>();
+            if host_statistics64(
+                self.port,
+                libc::HOST_VM_INFO64,
+                &mut stat as *mut vm_statistics64 as *mut _,
+                &mut count,
+            ) != libc::KERN_SUCCESS
+            {
+                return None;
+            }
+            Some(crate::CompressedMemory {
+                original_size: stat
+                    .total_uncompressed_pages_in_compressor
+                    .saturating_mul(self.page_size_b),
+                compressed_size: u64::from(stat.compressor_page_count)
+                    .saturating_mul(self.page_size_b),
+            })
+        }
+    }
+
+    pub(crate) fn inotify_usage(&self) -> Option<crate::InotifyUsage> {
+        None
+    }
+
     pub(crate) fn uptime() -> u64 {
         unsafe {
             let csec = libc::time(::std::ptr::null_mut());
@@ -394,6 +465,10 @@ impl SystemInner {
         }
     }
 
+    pub(crate) fn run_queue_length() -> Option<usize> {
+        None
+    }
+
     pub(crate) fn boot_time() -> u64 {
         boot_time()
     }
@@ -506,6 +581,10 @@ impl SystemInner {
     }
 
     pub(crate) fn cpu_arch() -> Option<String> {
+        // `hw.machine` returns "x86_64" in processes translated by Rosetta.
+        if Self::is_compat_mode() == Some(true) {
+            return Some("arm64".to_owned());
+        }
         let mut arch_str: [u8; 32] = [0; 32];
         let mut mib = [libc::CTL_HW as _, libc::HW_MACHINE as _];
 
@@ -527,10 +606,71 @@ impl SystemInner {
         }
     }
 
+    pub(crate) fn is_compat_mode() -> Option<bool> {
+        let mut translated: libc::c_int = 0;
+        let mut size = mem::size_of::<libc::c_int>();
+        unsafe {
+            if get_sys_value_by_name(
+                b"sysctl.proc_translated\0",
+                &mut size,
+                &mut translated as *mut _ as *mut _,
+            ) {
+                Some(translated == 1)
+            } else {
+                // The sysctl doesn't exist on Intel Macs.
+                Some(false)
+            }
+        }
+    }
+
+    pub(crate) fn core_dump_config() -> Option<crate::CoreDumpConfig> {
+        cfg_if! {
+            if #[cfg(all(target_os = "macos", not(feature = "apple-sandbox")))] {
+                let mut size = 0;
+                let mut buf = unsafe {
+                    if !get_sys_value_by_name(b"kern.corefile\0", &mut size, std::ptr::null_mut())
+                        || size == 0
+                    {
+                        return None;
+                    }
+                    let mut buf = vec![0_u8; size];
+                    if !get_sys_value_by_name(
+                        b"kern.corefile\0",
+                        &mut size,
+                        buf.as_mut_ptr() as *mut c_void,
+                    ) {
+                        return None;
+                    }
+                    buf
+                };
+                if let Some(pos) = buf.iter().position(|x| *x == 0) {
+                    buf.truncate(pos);
+                }
+                let (soft_limit, hard_limit) = crate::unix::utils::core_size_limits()?;
+                Some(crate::CoreDumpConfig {
+                    pattern: String::from_utf8(buf).ok()?,
+                    handler: None,
+                    soft_limit,
+                    hard_limit,
+                })
+            } else {
+                None
+            }
+        }
+    }
+
     pub(crate) fn physical_core_count() -> Option<usize> {
         physical_core_count()
     }
 
+    pub(crate) fn clock_ticks_per_second() -> Option<u64> {
+        crate::unix::utils::sysconf(libc::_SC_CLK_TCK)
+    }
+
+    pub(crate) fn page_size() -> Option<u64> {
+        crate::unix::utils::sysconf(libc::_SC_PAGESIZE)
+    }
+
     // FIXME: Would be better to query this information instead of using a "default" value like this.
     pub(crate) fn open_files_limit() -> Option<usize> {
         #[cfg(target_os = "ios")]
@@ -542,6 +682,48 @@ impl SystemInner {
             Some(10_240)
         }
     }
+
+    pub(crate) fn timezone() -> Option<String> {
+        Some(CFTimeZone::system()?.name()?.to_string())
+    }
+
+    pub(crate) fn locale() -> Option<String> {
+        Some(CFLocale::current()?.identifier()?.to_string())
+    }
+
+    pub(crate) fn cpu_vulnerabilities() -> Vec<crate::CpuVulnerability> {
+        Vec::new()
+    }
+
+    pub(crate) fn memory_hardware_info() -> Option<crate::MemoryHardwareInfo> {
+        None
+    }
+
+    pub(crate) fn virtualization() -> Option<String> {
+        crate::common::system::virtualization_from_product()
+    }
+
+    pub(crate) fn board_model() -> Option<String> {
+        None
+    }
+
+    pub(crate) fn turbo_enabled() -> Option<bool> {
+        None
+    }
+
+    pub(crate) fn throttling_status() -> Option<crate::ThrottlingStatus> {
+        None
+    }
+
+    pub(crate) fn socket_summary() -> Option<crate::SocketSummary> {
+        None
+    }
+
+    pub(crate) fn kernel_log(
+        _since: Option<Instant>,
+    ) -> std::io::Result<Vec<crate::KernelLogEntry>> {
+        Err(std::io::ErrorKind::Unsupported.into())
+    }
 }
 
 fn get_system_info(value: c_int, default: Option<&str>) -> Option<String> {
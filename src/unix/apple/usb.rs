@@ -0,0 +1,13 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+cfg_if! {
+    if #[cfg(all(target_os = "macos", not(feature = "apple-sandbox")))] {
+        pub(crate) use crate::sys::inner::usb::get_usb_devices;
+    } else {
+        use crate::UsbDevice;
+
+        pub(crate) fn get_usb_devices(devices: &mut Vec<UsbDevice>) {
+            devices.clear();
+        }
+    }
+}
@@ -0,0 +1,68 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::common::disk_watcher::{MountEntry, list_disks_mounts};
+
+use std::io;
+use std::os::fd::{AsRawFd, FromRawFd, OwnedFd};
+use std::time::Duration;
+
+/// Watches the mount table with a `kqueue` and its `EVFILT_FS` filter, which is triggered when
+/// a file system is mounted or unmounted (see `man 2 kqueue`).
+pub(crate) struct DiskWatcherInner {
+    kqueue: OwnedFd,
+}
+
+impl DiskWatcherInner {
+    pub(crate) fn open() -> io::Result<Self> {
+        let fd = unsafe { libc::kqueue() };
+        if fd < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        let kqueue = unsafe { OwnedFd::from_raw_fd(fd) };
+        if unsafe { libc::fcntl(kqueue.as_raw_fd(), libc::F_SETFD, libc::FD_CLOEXEC) } < 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        let mut event: libc::kevent = unsafe { std::mem::zeroed() };
+        event.filter = libc::EVFILT_FS;
+        event.flags = libc::EV_ADD | libc::EV_CLEAR;
+        if unsafe {
+            libc::kevent(
+                kqueue.as_raw_fd(),
+                &event,
+                1,
+                std::ptr::null_mut(),
+                0,
+                std::ptr::null(),
+            )
+        } < 0
+        {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(Self { kqueue })
+    }
+
+    pub(crate) fn wait(&mut self, timeout: Duration) -> bool {
+        let timeout = libc::timespec {
+            tv_sec: timeout.as_secs() as _,
+            tv_nsec: timeout.subsec_nanos() as _,
+        };
+        // The events are only used to know that the mount table changed, so with `EV_CLEAR`,
+        // getting one of them is enough.
+        let mut event: libc::kevent = unsafe { std::mem::zeroed() };
+        unsafe {
+            libc::kevent(
+                self.kqueue.as_raw_fd(),
+                std::ptr::null(),
+                0,
+                &mut event,
+                1,
+                &timeout,
+            ) > 0
+        }
+    }
+
+    pub(crate) fn mounts(&mut self) -> io::Result<Vec<MountEntry>> {
+        list_disks_mounts()
+    }
+}
@@ -0,0 +1,97 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::{AudioDevice, AudioDeviceKind};
+
+use std::fs;
+
+/// Parses `/dev/sndstat`, which looks like:
+///
+/// ```text
+/// Installed devices:
+/// pcm0: <Realtek ALC892 (Analog)> (play/rec) default
+/// pcm1: <Realtek ALC892 (Rear Digital)> (play)
+/// ```
+fn parse_sndstat(data: &str, devices: &mut Vec<AudioDevice>) {
+    for line in data.lines() {
+        let Some((id, rest)) = line.split_once(": <") else {
+            continue;
+        };
+        if !id.starts_with("pcm") {
+            continue;
+        }
+        let Some((name, rest)) = rest.split_once("> (") else {
+            continue;
+        };
+        let Some((capabilities, rest)) = rest.split_once(')') else {
+            continue;
+        };
+        let is_default = rest.split_whitespace().any(|word| word == "default");
+        for capability in capabilities.split('/') {
+            let kind = match capability {
+                "play" => AudioDeviceKind::Output,
+                "rec" => AudioDeviceKind::Input,
+                _ => continue,
+            };
+            devices.push(AudioDevice {
+                id: id.to_owned(),
+                name: name.to_owned(),
+                kind,
+                is_default,
+            });
+        }
+    }
+}
+
+pub(crate) fn get_audio_devices(devices: &mut Vec<AudioDevice>) {
+    devices.clear();
+
+    if let Ok(data) = fs::read_to_string("/dev/sndstat") {
+        parse_sndstat(&data, devices);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_sndstat;
+    use crate::AudioDeviceKind;
+
+    #[test]
+    fn test_parse_sndstat() {
+        let mut devices = Vec::new();
+        parse_sndstat(
+            "Installed devices:
+pcm0: <Realtek ALC892 (Analog)> (play/rec) default
+pcm1: <Realtek ALC892 (Rear Digital)> (play)
+No devices installed from userspace.
+",
+            &mut devices,
+        );
+        let devices = devices
+            .iter()
+            .map(|d| (d.id(), d.name(), d.kind(), d.is_default()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            devices,
+            [
+                (
+                    "pcm0",
+                    "Realtek ALC892 (Analog)",
+                    AudioDeviceKind::Output,
+                    true
+                ),
+                (
+                    "pcm0",
+                    "Realtek ALC892 (Analog)",
+                    AudioDeviceKind::Input,
+                    true
+                ),
+                (
+                    "pcm1",
+                    "Realtek ALC892 (Rear Digital)",
+                    AudioDeviceKind::Output,
+                    false
+                ),
+            ]
+        );
+    }
+}
@@ -1,7 +1,7 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
 use super::utils::get_sys_value_by_name;
-use crate::Component;
+use crate::{Component, ValueSource};
 
 pub(crate) struct ComponentInner {
     id: Vec<u8>,
@@ -36,6 +36,14 @@ impl ComponentInner {
         None
     }
 
+    pub(crate) fn max_source(&self) -> Option<ValueSource> {
+        Some(ValueSource::Inferred)
+    }
+
+    pub(crate) fn critical_source(&self) -> Option<ValueSource> {
+        None
+    }
+
     pub(crate) fn id(&self) -> Option<&str> {
         Some(&self.component_id)
     }
@@ -44,6 +52,14 @@ impl ComponentInner {
         &self.label
     }
 
+    pub(crate) fn raw_label(&self) -> Option<&str> {
+        None
+    }
+
+    pub(crate) fn chip(&self) -> Option<&str> {
+        None
+    }
+
     pub(crate) fn refresh(&mut self) {
         unsafe {
             self.temperature = refresh_component(&self.id);
@@ -107,6 +123,7 @@ impl ComponentsInner {
                     if let Some(temperature) = refresh_component(&id) {
                         self.components.push(Component {
                             inner: ComponentInner::new(id, temperature, core),
+                            max_observed: None,
                         });
                     }
                 }
@@ -129,10 +146,12 @@ mod tests {
     fn test_components() {
         let component1 = Component {
             inner: ComponentInner::new(b"dev.cpu.0.temperature\0".to_vec(), 1.234, 0),
+            max_observed: None,
         };
 
         let component2 = Component {
             inner: ComponentInner::new(b"dev.cpu.1.temperature\0".to_vec(), 5.678, 1),
+            max_observed: None,
         };
         assert_eq!(component1.id(), Some("cpu_1"));
         assert_eq!(component1.label(), "CPU 1");
@@ -7,6 +7,8 @@ use crate::{Cpu, CpuRefreshKind};
 
 use libc::{c_int, c_ulong};
 
+use std::time::Instant;
+
 pub(crate) struct CpusWrapper {
     pub(crate) global_cpu_usage: f32,
     pub(crate) cpus: Vec<Cpu>,
@@ -18,6 +20,7 @@ pub(crate) struct CpusWrapper {
     // For each CPU usage.
     cp_times: VecSwitcher<c_ulong>,
     nb_cpus: usize,
+    pub(crate) last_update: Option<Instant>,
 }
 
 impl CpusWrapper {
@@ -38,6 +41,7 @@ impl CpusWrapper {
                 cp_time: VecSwitcher::new(vec![0; libc::CPUSTATES as usize]),
                 cp_times: VecSwitcher::new(vec![0; nb_cpus * libc::CPUSTATES as usize]),
                 nb_cpus,
+                last_update: None,
             }
         }
     }
@@ -58,6 +62,8 @@ impl CpusWrapper {
                 }
                 self.cpus.push(Cpu {
                     inner: CpuInner::new(format!("cpu {pos}"), vendor_id.clone(), frequency),
+                    peak_usage: 0.,
+                    measurement_interval: None,
                 });
             }
             self.got_cpu_frequency = refresh_kind.frequency();
@@ -69,7 +75,13 @@ impl CpusWrapper {
             }
             self.got_cpu_frequency = true;
         }
-        if refresh_kind.cpu_usage() {
+        // If the last CPU usage update is too close (less than `MINIMUM_CPU_UPDATE_INTERVAL`),
+        // we don't want to update CPUs usage.
+        if refresh_kind.cpu_usage()
+            && self.last_update.is_none_or(|last_update| {
+                last_update.elapsed() >= crate::MINIMUM_CPU_UPDATE_INTERVAL
+            })
+        {
             self.get_cpu_usage();
         }
     }
@@ -79,6 +91,7 @@ impl CpusWrapper {
             get_sys_value_array(&self.mib_cp_time, self.cp_time.get_mut());
             get_sys_value_array(&self.mib_cp_times, self.cp_times.get_mut());
         }
+        self.last_update = Some(Instant::now());
 
         fn compute_cpu_usage(new_cp_time: &[c_ulong], old_cp_time: &[c_ulong]) -> f32 {
             let mut total_new: u64 = 0;
@@ -150,6 +163,22 @@ impl CpuInner {
     pub(crate) fn brand(&self) -> &str {
         ""
     }
+
+    pub(crate) fn run_queue_length(&self) -> Option<usize> {
+        None
+    }
+
+    pub(crate) fn run_queue_wait_time(&self) -> Option<u64> {
+        None
+    }
+
+    pub(crate) fn boost_active(&self) -> Option<bool> {
+        None
+    }
+
+    pub(crate) fn idle_states(&self) -> &[crate::CpuIdleState] {
+        &[]
+    }
 }
 
 pub(crate) fn physical_core_count() -> Option<usize> {
@@ -15,7 +15,7 @@ use super::ffi::{
     geom_stats_snapshot_get, geom_stats_snapshot_next, geom_stats_snapshot_reset,
 };
 use super::utils::{c_buf_to_utf8_str, get_sys_value_str_by_name};
-use crate::{Disk, DiskKind, DiskRefreshKind, DiskUsage};
+use crate::{Disk, DiskKind, DiskRefreshKind, DiskUsage, RefreshError};
 
 #[derive(Debug)]
 pub(crate) struct DiskInner {
@@ -81,6 +81,14 @@ impl DiskInner {
             total_written_bytes: self.written_bytes,
         }
     }
+
+    pub(crate) fn network_fs_stats(&self) -> Option<crate::NetworkFsStats> {
+        None
+    }
+
+    pub(crate) fn io_stats(&self) -> Option<crate::DiskIoStats> {
+        None
+    }
 }
 
 impl crate::DisksInner {
@@ -90,6 +98,15 @@ impl crate::DisksInner {
         }
     }
 
+    pub(crate) fn try_refresh_specifics(
+        &mut self,
+        remove_not_listed_disks: bool,
+        refresh_kind: DiskRefreshKind,
+    ) -> Result<(), RefreshError> {
+        self.refresh_specifics(remove_not_listed_disks, refresh_kind);
+        Ok(())
+    }
+
     pub(crate) fn refresh_specifics(
         &mut self,
         remove_not_listed_disks: bool,
@@ -105,6 +122,10 @@ impl crate::DisksInner {
     pub(crate) fn list_mut(&mut self) -> &mut [Disk] {
         &mut self.disks
     }
+
+    pub(crate) fn usage_for_path(path: &Path) -> Option<crate::DiskSpace> {
+        crate::unix::utils::statfs_space(path)
+    }
 }
 
 trait GetValues {
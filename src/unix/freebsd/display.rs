@@ -0,0 +1,7 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::Display;
+
+pub(crate) fn get_displays(displays: &mut Vec<Display>) {
+    displays.clear();
+}
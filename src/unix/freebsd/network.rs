@@ -2,10 +2,13 @@
 
 use std::collections::{HashMap, hash_map};
 use std::mem::MaybeUninit;
+use std::net::IpAddr;
 
 use super::utils;
 use crate::network::refresh_networks_addresses;
-use crate::{IpNetwork, MacAddr, NetworkData};
+use crate::network_helper::get_default_route;
+use crate::unix::utils::parse_resolv_conf;
+use crate::{DnsConfiguration, IpNetwork, MacAddr, NetworkData, RefreshError};
 
 macro_rules! old_and_new {
     ($ty_:expr, $name:ident, $old:ident, $data:expr) => {{
@@ -29,6 +32,14 @@ impl NetworksInner {
         &self.interfaces
     }
 
+    pub(crate) fn try_refresh(
+        &mut self,
+        remove_not_listed_interfaces: bool,
+    ) -> Result<(), RefreshError> {
+        self.refresh(remove_not_listed_interfaces);
+        Ok(())
+    }
+
     pub(crate) fn refresh(&mut self, remove_not_listed_interfaces: bool) {
         unsafe {
             self.refresh_interfaces(true);
@@ -46,6 +57,24 @@ impl NetworksInner {
         refresh_networks_addresses(&mut self.interfaces);
     }
 
+    pub(crate) fn dns_configuration() -> DnsConfiguration {
+        std::fs::read_to_string("/etc/resolv.conf")
+            .map(|data| parse_resolv_conf(&data))
+            .unwrap_or_default()
+    }
+
+    pub(crate) fn default_gateway() -> Option<IpAddr> {
+        get_default_route().map(|(gateway, _)| gateway)
+    }
+
+    pub(crate) fn primary_interface() -> Option<String> {
+        get_default_route().and_then(|(_, interface)| interface)
+    }
+
+    pub(crate) fn protocol_stats() -> Option<crate::ProtocolStats> {
+        None
+    }
+
     unsafe fn refresh_interfaces(&mut self, refresh_all: bool) {
         let mut nb_interfaces: libc::c_int = 0;
         if unsafe {
@@ -96,6 +125,10 @@ impl NetworksInner {
                         old_and_new!(interface, ifi_opackets, old_ifi_opackets, data);
                         old_and_new!(interface, ifi_ierrors, old_ifi_ierrors, data);
                         old_and_new!(interface, ifi_oerrors, old_ifi_oerrors, data);
+                        old_and_new!(interface, ifi_iqdrops, old_ifi_iqdrops, data);
+                        old_and_new!(interface, ifi_oqdrops, old_ifi_oqdrops, data);
+                        old_and_new!(interface, ifi_collisions, old_ifi_collisions, data);
+                        old_and_new!(interface, ifi_imcasts, old_ifi_imcasts, data);
                         if interface.mtu != mtu {
                             interface.mtu = mtu;
                         }
@@ -120,6 +153,14 @@ impl NetworksInner {
                                 old_ifi_ierrors: 0,
                                 ifi_oerrors: data.ifi_oerrors,
                                 old_ifi_oerrors: 0,
+                                ifi_iqdrops: data.ifi_iqdrops,
+                                old_ifi_iqdrops: 0,
+                                ifi_oqdrops: data.ifi_oqdrops,
+                                old_ifi_oqdrops: 0,
+                                ifi_collisions: data.ifi_collisions,
+                                old_ifi_collisions: 0,
+                                ifi_imcasts: data.ifi_imcasts,
+                                old_ifi_imcasts: 0,
                                 updated: true,
                                 mac_addr: MacAddr::UNSPECIFIED,
                                 ip_networks: vec![],
@@ -154,6 +195,18 @@ pub(crate) struct NetworkDataInner {
     /// similar to `ifi_ierrors`
     ifi_oerrors: u64,
     old_ifi_oerrors: u64,
+    /// Total number of packets dropped on input.
+    ifi_iqdrops: u64,
+    old_ifi_iqdrops: u64,
+    /// Total number of packets dropped on output.
+    ifi_oqdrops: u64,
+    old_ifi_oqdrops: u64,
+    /// Total number of collisions on csma interfaces.
+    ifi_collisions: u64,
+    old_ifi_collisions: u64,
+    /// Total number of multicast packets received.
+    ifi_imcasts: u64,
+    old_ifi_imcasts: u64,
     /// Whether or not the above data has been updated during refresh
     updated: bool,
     /// MAC address
@@ -213,6 +266,62 @@ impl NetworkDataInner {
         self.ifi_oerrors
     }
 
+    pub(crate) fn drops_on_received(&self) -> u64 {
+        self.ifi_iqdrops.saturating_sub(self.old_ifi_iqdrops)
+    }
+
+    pub(crate) fn total_drops_on_received(&self) -> u64 {
+        self.ifi_iqdrops
+    }
+
+    pub(crate) fn drops_on_transmitted(&self) -> u64 {
+        self.ifi_oqdrops.saturating_sub(self.old_ifi_oqdrops)
+    }
+
+    pub(crate) fn total_drops_on_transmitted(&self) -> u64 {
+        self.ifi_oqdrops
+    }
+
+    pub(crate) fn fifo_errors_on_received(&self) -> u64 {
+        0
+    }
+
+    pub(crate) fn total_fifo_errors_on_received(&self) -> u64 {
+        0
+    }
+
+    pub(crate) fn fifo_errors_on_transmitted(&self) -> u64 {
+        0
+    }
+
+    pub(crate) fn total_fifo_errors_on_transmitted(&self) -> u64 {
+        0
+    }
+
+    pub(crate) fn collisions(&self) -> u64 {
+        self.ifi_collisions.saturating_sub(self.old_ifi_collisions)
+    }
+
+    pub(crate) fn total_collisions(&self) -> u64 {
+        self.ifi_collisions
+    }
+
+    pub(crate) fn carrier_errors(&self) -> u64 {
+        0
+    }
+
+    pub(crate) fn total_carrier_errors(&self) -> u64 {
+        0
+    }
+
+    pub(crate) fn multicast_packets_received(&self) -> u64 {
+        self.ifi_imcasts.saturating_sub(self.old_ifi_imcasts)
+    }
+
+    pub(crate) fn total_multicast_packets_received(&self) -> u64 {
+        self.ifi_imcasts
+    }
+
     pub(crate) fn mac_address(&self) -> MacAddr {
         self.mac_addr
     }
@@ -1,13 +1,18 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
-use crate::{DiskUsage, Gid, Pid, Process, ProcessRefreshKind, ProcessStatus, Signal, Uid};
+use crate::{
+    CurrentProcessUsage, DiskUsage, EnergyImpact, ExeMetadata, ExeSignature, Gid, MediaUsage, Pid,
+    Process, ProcessRefreshKind, ProcessStatus, Signal, Uid,
+};
 
-use std::ffi::{OsStr, OsString};
+use std::ffi::{CStr, OsStr, OsString};
 use std::fmt;
+use std::net::SocketAddr;
 use std::path::{Path, PathBuf};
 use std::process::ExitStatus;
+use std::sync::OnceLock;
 
-use super::utils::{WrapMap, get_sys_value_str, get_sysctl_raw};
+use super::utils::{WrapMap, get_sys_value, get_sys_value_str, get_sysctl_raw};
 
 #[doc(hidden)]
 impl From<libc::c_char> for ProcessStatus {
@@ -46,6 +51,11 @@ pub(crate) struct ProcessInner {
     pub(crate) exe: Option<PathBuf>,
     pub(crate) pid: Pid,
     parent: Option<Pid>,
+    reparented_to_init: bool,
+    thread_count: Option<usize>,
+    tty_dev: Option<libc::dev_t>,
+    session_id: Option<Pid>,
+    process_group_id: Option<Pid>,
     pub(crate) environ: Vec<OsString>,
     pub(crate) cwd: Option<PathBuf>,
     pub(crate) root: Option<PathBuf>,
@@ -65,6 +75,9 @@ pub(crate) struct ProcessInner {
     written_bytes: u64,
     old_written_bytes: u64,
     accumulated_cpu_time: u64,
+    children_cpu_time: u64,
+    exe_signature: OnceLock<Option<ExeSignature>>,
+    exe_metadata: OnceLock<Option<ExeMetadata>>,
     exists: bool,
 }
 
@@ -114,6 +127,30 @@ impl ProcessInner {
         self.parent
     }
 
+    pub(crate) fn thread_count(&self) -> Option<usize> {
+        self.thread_count
+    }
+
+    pub(crate) fn tty(&self) -> Option<String> {
+        let dev = self.tty_dev?;
+        let mut buf = [0 as libc::c_char; libc::SPECNAMELEN as usize + 1];
+        let name = unsafe { libc::devname_r(dev, libc::S_IFCHR, buf.as_mut_ptr(), buf.len() as _) };
+        if name.is_null() {
+            return None;
+        }
+        let name = unsafe { CStr::from_ptr(name) }.to_str().ok()?;
+        // `devname_r` returns `#C:[major]:[minor]` if it cannot find the device.
+        (!name.starts_with('#')).then(|| name.to_owned())
+    }
+
+    pub(crate) fn reparented_to_init(&self) -> bool {
+        self.reparented_to_init
+    }
+
+    pub(crate) fn exec_count(&self) -> u64 {
+        0
+    }
+
     pub(crate) fn status(&self) -> ProcessStatus {
         self.status
     }
@@ -122,6 +159,10 @@ impl ProcessInner {
         self.start_time
     }
 
+    pub(crate) fn start_time_raw(&self) -> u64 {
+        self.start_time
+    }
+
     pub(crate) fn run_time(&self) -> u64 {
         self.run_time
     }
@@ -134,6 +175,10 @@ impl ProcessInner {
         self.accumulated_cpu_time
     }
 
+    pub(crate) fn children_cpu_time(&self) -> u64 {
+        self.children_cpu_time
+    }
+
     pub(crate) fn disk_usage(&self) -> DiskUsage {
         DiskUsage {
             written_bytes: self.written_bytes.saturating_sub(self.old_written_bytes),
@@ -164,14 +209,34 @@ impl ProcessInner {
     }
 
     pub(crate) fn session_id(&self) -> Option<Pid> {
-        unsafe {
-            let session_id = libc::getsid(self.pid.0);
-            if session_id < 0 {
-                None
-            } else {
-                Some(Pid(session_id))
-            }
+        self.session_id
+    }
+
+    pub(crate) fn process_group_id(&self) -> Option<Pid> {
+        self.process_group_id
+    }
+
+    pub(crate) fn current_usage() -> Option<CurrentProcessUsage> {
+        let mut kproc: libc::kinfo_proc = unsafe { std::mem::zeroed() };
+        let mib = [
+            libc::CTL_KERN,
+            libc::KERN_PROC,
+            libc::KERN_PROC_PID,
+            unsafe { libc::getpid() },
+        ];
+        if !unsafe { get_sys_value(&mib, &mut kproc) } {
+            return None;
         }
+        let page_size = unsafe { libc::sysconf(libc::_SC_PAGESIZE) } as u64;
+        Some(CurrentProcessUsage {
+            memory: (kproc.ki_rssize as u64).saturating_mul(page_size),
+            virtual_memory: kproc.ki_size as _,
+            accumulated_cpu_time: get_accumulated_cpu_time(&kproc),
+        })
+    }
+
+    pub(crate) fn children_usage() -> Option<crate::ChildrenUsage> {
+        crate::unix::utils::children_usage()
     }
 
     pub(crate) fn switch_updated(&mut self) -> bool {
@@ -225,9 +290,99 @@ impl ProcessInner {
         }
     }
 
+    pub(crate) fn path_usage(&self, path: &Path) -> crate::PathUsage {
+        crate::PathUsage::from_process_paths(path, self.cwd(), self.root(), self.exe())
+    }
+
+    pub(crate) fn deleted_mappings(&self) -> Vec<PathBuf> {
+        Vec::new()
+    }
+
     pub(crate) fn open_files_limit(&self) -> Option<usize> {
         crate::System::open_files_limit()
     }
+
+    pub(crate) fn stack_size(&self) -> Option<u64> {
+        None
+    }
+
+    pub(crate) fn heap_size(&self) -> Option<u64> {
+        None
+    }
+
+    pub(crate) fn cgroup(&self) -> Option<String> {
+        None
+    }
+
+    pub(crate) fn container_id(&self) -> Option<String> {
+        None
+    }
+
+    pub(crate) fn sched_stats(&self) -> Option<crate::SchedStats> {
+        None
+    }
+
+    pub(crate) fn io_counters(&self) -> Option<crate::IoCounters> {
+        None
+    }
+
+    pub(crate) fn wait_channel(&self) -> Option<String> {
+        None
+    }
+
+    pub(crate) fn kernel_stack(&self) -> Result<Vec<String>, crate::RefreshError> {
+        Err(crate::RefreshError::Unsupported)
+    }
+
+    pub(crate) fn connection_count(&self) -> Option<crate::ConnectionCount> {
+        None
+    }
+
+    pub(crate) fn is_64_bit(&self) -> Option<bool> {
+        None
+    }
+
+    pub(crate) fn abi(&self) -> Option<&'static str> {
+        None
+    }
+
+    pub(crate) fn listening_ports(&self) -> Option<Vec<SocketAddr>> {
+        None
+    }
+
+    pub(crate) fn exe_signature(&self) -> Option<&ExeSignature> {
+        let exe = self.exe.as_deref()?;
+        self.exe_signature
+            .get_or_init(|| crate::sha256::sha256_file(exe).map(ExeSignature::Sha256))
+            .as_ref()
+    }
+
+    pub(crate) fn exe_metadata(&self) -> Option<&ExeMetadata> {
+        let exe = self.exe.as_deref()?;
+        self.exe_metadata
+            .get_or_init(|| crate::exe_metadata::read_exe_metadata(exe))
+            .as_ref()
+    }
+
+    pub(crate) fn bundle_identifier(&self) -> Option<&str> {
+        None
+    }
+
+    pub(crate) fn bundle_name(&self) -> Option<&str> {
+        None
+    }
+
+    pub(crate) fn energy_impact(&self) -> Option<EnergyImpact> {
+        None
+    }
+
+    pub(crate) fn gpu_usage(&self) -> Option<f32> {
+        None
+    }
+
+    pub(crate) fn media_usage(&self) -> Option<MediaUsage> {
+        None
+    }
 }
 
 struct AllocatedPtr<T>(*mut T);
@@ -261,6 +416,17 @@ fn get_accumulated_cpu_time(kproc: &libc::kinfo_proc) -> u64 {
     kproc.ki_runtime / 1_000
 }
 
+#[inline]
+fn get_children_cpu_time(kproc: &libc::kinfo_proc) -> u64 {
+    let to_ms = |time: libc::timeval| time.tv_sec as u64 * 1_000 + time.tv_usec as u64 / 1_000;
+    to_ms(kproc.ki_rusage_ch.ru_utime).saturating_add(to_ms(kproc.ki_rusage_ch.ru_stime))
+}
+
+fn get_tty_dev(kproc: &libc::kinfo_proc) -> Option<libc::dev_t> {
+    // `NODEV` means there is no controlling terminal.
+    (kproc.ki_tdev != libc::dev_t::MAX).then_some(kproc.ki_tdev)
+}
+
 pub(crate) unsafe fn get_process_data(
     kproc: &libc::kinfo_proc,
     wrap: &WrapMap,
@@ -312,8 +478,15 @@ pub(crate) unsafe fn get_process_data(
             if let Some(cpu_usage) = cpu_usage {
                 proc_.cpu_usage = cpu_usage;
             }
+            if proc_.parent.is_some_and(|old_parent| old_parent.0 != 1) && parent == Some(Pid(1)) {
+                proc_.reparented_to_init = true;
+            }
             proc_.parent = parent;
             proc_.status = status;
+            proc_.thread_count = Some(kproc.ki_numthreads as _);
+            proc_.tty_dev = get_tty_dev(kproc);
+            proc_.session_id = Some(Pid(kproc.ki_sid));
+            proc_.process_group_id = Some(Pid(kproc.ki_pgid));
             if refresh_kind.memory() {
                 proc_.virtual_memory = virtual_memory;
                 proc_.memory = memory;
@@ -328,6 +501,7 @@ pub(crate) unsafe fn get_process_data(
             }
             if refresh_kind.cpu() {
                 proc_.accumulated_cpu_time = get_accumulated_cpu_time(kproc);
+                proc_.children_cpu_time = get_children_cpu_time(kproc);
             }
 
             return Ok(None);
@@ -354,6 +528,11 @@ pub(crate) unsafe fn get_process_data(
         inner: ProcessInner {
             pid: Pid(kproc.ki_pid),
             parent,
+            reparented_to_init: false,
+            thread_count: Some(kproc.ki_numthreads as _),
+            tty_dev: get_tty_dev(kproc),
+            session_id: Some(Pid(kproc.ki_sid)),
+            process_group_id: Some(Pid(kproc.ki_pgid)),
             user_id: Uid(kproc.ki_ruid),
             effective_user_id: Uid(kproc.ki_uid),
             group_id: Gid(kproc.ki_rgid),
@@ -384,31 +563,41 @@ pub(crate) unsafe fn get_process_data(
             } else {
                 0
             },
+            children_cpu_time: if refresh_kind.cpu() {
+                get_children_cpu_time(kproc)
+            } else {
+                0
+            },
             updated: true,
+            exe_signature: OnceLock::new(),
+            exe_metadata: OnceLock::new(),
             exists: true,
         },
+        last_refreshed_at: None,
     }))
 }
 
-pub(crate) unsafe fn get_exe(
-    exe: &mut Option<PathBuf>,
-    pid: crate::Pid,
-    refresh_kind: ProcessRefreshKind,
-) {
-    if refresh_kind.exe().needs_update(|| exe.is_none()) {
+pub(crate) unsafe fn get_exe(p: &mut ProcessInner, refresh_kind: ProcessRefreshKind) {
+    if refresh_kind.exe().needs_update(|| p.exe.is_none()) {
         let mut buffer = [0; libc::PATH_MAX as usize + 1];
 
-        unsafe {
-            *exe = get_sys_value_str(
+        let exe = unsafe {
+            get_sys_value_str(
                 &[
                     libc::CTL_KERN,
                     libc::KERN_PROC,
                     libc::KERN_PROC_PATHNAME,
-                    pid.0,
+                    p.pid.0,
                 ],
                 &mut buffer,
             )
-            .map(PathBuf::from);
+            .map(PathBuf::from)
+        };
+        if exe != p.exe {
+            // The process called `exec`, so what was computed from `exe` is outdated.
+            p.exe_signature = OnceLock::new();
+            p.exe_metadata = OnceLock::new();
+            p.exe = exe;
         }
     }
 }
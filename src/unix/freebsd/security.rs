@@ -0,0 +1,51 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use super::utils::{c_buf_to_utf8_str, get_sys_value_by_name};
+use crate::FirewallProfile;
+
+use std::mem::MaybeUninit;
+use std::path::Path;
+
+pub(crate) struct SecurityInfoInner;
+
+impl SecurityInfoInner {
+    pub(crate) fn secure_boot_enabled() -> Option<bool> {
+        None
+    }
+
+    pub(crate) fn tpm_present() -> Option<bool> {
+        Some(Path::new("/dev/tpm0").exists())
+    }
+
+    pub(crate) fn root_disk_encrypted() -> Option<bool> {
+        let mut fs_info = MaybeUninit::<libc::statfs>::uninit();
+        if unsafe { libc::statfs(c"/".as_ptr(), fs_info.as_mut_ptr()) } != 0 {
+            sysinfo_debug!("statfs failed on `/`");
+            return None;
+        }
+        let fs_info = unsafe { fs_info.assume_init() };
+        if c_buf_to_utf8_str(&fs_info.f_fstypename)? == "zfs" {
+            // ZFS native encryption is a dataset property, not a GEOM provider.
+            return None;
+        }
+        let source = c_buf_to_utf8_str(&fs_info.f_mntfromname)?;
+        if !source.starts_with("/dev/") {
+            return None;
+        }
+        // GELI providers are named after the underlying provider with a `.eli` suffix.
+        Some(source.ends_with(".eli"))
+    }
+
+    pub(crate) fn firewall_status() -> Vec<FirewallProfile> {
+        let mut enabled: libc::c_int = 0;
+        // This sysctl only exists if the `ipfw` module is loaded.
+        if unsafe { get_sys_value_by_name(b"net.inet.ip.fw.enable\0", &mut enabled) } {
+            vec![FirewallProfile {
+                name: "ipfw".to_owned(),
+                enabled: enabled != 0,
+            }]
+        } else {
+            Vec::new()
+        }
+    }
+}
@@ -2,7 +2,7 @@
 
 use crate::{
     Cpu, CpuRefreshKind, LoadAvg, MemoryRefreshKind, Pid, Process, ProcessInner,
-    ProcessRefreshKind, ProcessesToUpdate,
+    ProcessRefreshKind, ProcessStringStats, ProcessesToUpdate, RefreshError,
 };
 
 use std::cell::UnsafeCell;
@@ -12,13 +12,13 @@ use std::mem::MaybeUninit;
 use std::path::{Path, PathBuf};
 use std::ptr::NonNull;
 use std::sync::atomic::{AtomicUsize, Ordering};
-use std::time::{Duration, SystemTime};
+use std::time::{Duration, Instant, SystemTime};
 
 use crate::sys::cpu::{CpusWrapper, physical_core_count};
 use crate::sys::process::get_exe;
 use crate::sys::utils::{
     self, boot_time, c_buf_to_os_string, c_buf_to_utf8_string, from_cstr_array, get_sys_value,
-    get_sys_value_by_name, init_mib,
+    get_sys_value_by_name, get_sys_value_str_by_name, init_mib,
 };
 
 use libc::c_int;
@@ -111,6 +111,26 @@ impl SystemInner {
         self.cpus.refresh(refresh_kind)
     }
 
+    pub(crate) fn try_refresh_memory_specifics(
+        &mut self,
+        refresh_kind: MemoryRefreshKind,
+    ) -> Result<(), RefreshError> {
+        self.refresh_memory_specifics(refresh_kind);
+        Ok(())
+    }
+
+    pub(crate) fn try_refresh_cpu_specifics(
+        &mut self,
+        refresh_kind: CpuRefreshKind,
+    ) -> Result<(), RefreshError> {
+        self.refresh_cpu_specifics(refresh_kind);
+        Ok(())
+    }
+
+    pub(crate) fn check_processes_source() -> Result<(), RefreshError> {
+        Ok(())
+    }
+
     pub(crate) fn refresh_cpu_list(&mut self, refresh_kind: CpuRefreshKind) {
         self.cpus = CpusWrapper::new();
         self.cpus.refresh(refresh_kind);
@@ -132,6 +152,18 @@ impl SystemInner {
         &self.process_list
     }
 
+    pub(crate) fn process_string_stats(&self) -> ProcessStringStats {
+        ProcessStringStats::default()
+    }
+
+    pub(crate) fn set_max_kept_file_handles(&mut self, _max: usize) {}
+
+    pub(crate) fn kept_file_handles(&self) -> usize {
+        0
+    }
+
+    pub(crate) fn set_use_pidfds(&mut self, _use_pidfds: bool) {}
+
     pub(crate) fn processes_mut(&mut self) -> &mut HashMap<Pid, Process> {
         &mut self.process_list
     }
@@ -144,10 +176,18 @@ impl SystemInner {
         self.cpus.global_cpu_usage
     }
 
+    pub(crate) fn last_cpu_usage_update(&self) -> Option<Instant> {
+        self.cpus.last_update
+    }
+
     pub(crate) fn cpus(&self) -> &[Cpu] {
         &self.cpus.cpus
     }
 
+    pub(crate) fn cpus_mut(&mut self) -> &mut [Cpu] {
+        &mut self.cpus.cpus
+    }
+
     pub(crate) fn total_memory(&self) -> u64 {
         self.mem_total
     }
@@ -177,6 +217,18 @@ impl SystemInner {
         self.swap_used
     }
 
+    pub(crate) fn swap_devices(&self) -> Vec<crate::SwapDevice> {
+        self.system_info.get_swap_devices()
+    }
+
+    pub(crate) fn compressed_memory(&self) -> Option<crate::CompressedMemory> {
+        None
+    }
+
+    pub(crate) fn inotify_usage(&self) -> Option<crate::InotifyUsage> {
+        None
+    }
+
     pub(crate) fn uptime() -> u64 {
         unsafe {
             let csec = libc::time(std::ptr::null_mut());
@@ -201,6 +253,10 @@ impl SystemInner {
         }
     }
 
+    pub(crate) fn run_queue_length() -> Option<usize> {
+        None
+    }
+
     pub(crate) fn name() -> Option<String> {
         let mut os_type: [c_int; 2] = [0; 2];
         unsafe {
@@ -276,10 +332,33 @@ impl SystemInner {
         }
     }
 
+    pub(crate) fn is_compat_mode() -> Option<bool> {
+        None
+    }
+
+    pub(crate) fn core_dump_config() -> Option<crate::CoreDumpConfig> {
+        let pattern = get_sys_value_str_by_name(b"kern.corefile\0")?;
+        let (soft_limit, hard_limit) = crate::unix::utils::core_size_limits()?;
+        Some(crate::CoreDumpConfig {
+            pattern,
+            handler: None,
+            soft_limit,
+            hard_limit,
+        })
+    }
+
     pub(crate) fn physical_core_count() -> Option<usize> {
         physical_core_count()
     }
 
+    pub(crate) fn clock_ticks_per_second() -> Option<u64> {
+        crate::unix::utils::sysconf(libc::_SC_CLK_TCK)
+    }
+
+    pub(crate) fn page_size() -> Option<u64> {
+        crate::unix::utils::sysconf(libc::_SC_PAGESIZE)
+    }
+
     pub(crate) fn open_files_limit() -> Option<usize> {
         let mut value = 0u32;
         unsafe {
@@ -290,6 +369,79 @@ impl SystemInner {
             }
         }
     }
+
+    pub(crate) fn timezone() -> Option<String> {
+        crate::unix::utils::get_timezone().or_else(|| {
+            // `tzsetup` stores the selected time zone in `/var/db/zoneinfo`.
+            let tz = std::fs::read_to_string("/var/db/zoneinfo").ok()?;
+            let tz = tz.trim();
+            (!tz.is_empty()).then(|| tz.to_owned())
+        })
+    }
+
+    pub(crate) fn locale() -> Option<String> {
+        crate::unix::utils::get_locale_from_env()
+    }
+
+    pub(crate) fn cpu_vulnerabilities() -> Vec<crate::CpuVulnerability> {
+        Vec::new()
+    }
+
+    pub(crate) fn memory_hardware_info() -> Option<crate::MemoryHardwareInfo> {
+        None
+    }
+
+    pub(crate) fn virtualization() -> Option<String> {
+        crate::common::system::virtualization_from_product()
+    }
+
+    pub(crate) fn board_model() -> Option<String> {
+        None
+    }
+
+    pub(crate) fn turbo_enabled() -> Option<bool> {
+        None
+    }
+
+    pub(crate) fn throttling_status() -> Option<crate::ThrottlingStatus> {
+        None
+    }
+
+    pub(crate) fn socket_summary() -> Option<crate::SocketSummary> {
+        // Number of TCP connections in each state, indexed by the `TCPS_*` constants of
+        // `netinet/tcp_fsm.h`.
+        const TCPS_SYN_RECEIVED: usize = 3;
+        const TCPS_ESTABLISHED: usize = 4;
+        const TCPS_TIME_WAIT: usize = 10;
+        let mut states = [0u64; 11];
+        let mut len = std::mem::size_of_val(&states) as libc::size_t;
+        if unsafe {
+            libc::sysctlbyname(
+                c"net.inet.tcp.states".as_ptr(),
+                states.as_mut_ptr() as *mut _,
+                &mut len,
+                std::ptr::null(),
+                0,
+            )
+        } != 0
+        {
+            sysinfo_debug!("failed to retrieve `net.inet.tcp.states`");
+            return None;
+        }
+        Some(crate::SocketSummary {
+            tcp_sockets: Some(states.iter().sum()),
+            tcp_established: Some(states[TCPS_ESTABLISHED]),
+            tcp_syn_received: Some(states[TCPS_SYN_RECEIVED]),
+            tcp_time_wait: Some(states[TCPS_TIME_WAIT]),
+            ..Default::default()
+        })
+    }
+
+    pub(crate) fn kernel_log(
+        _since: Option<Instant>,
+    ) -> std::io::Result<Vec<crate::KernelLogEntry>> {
+        Err(std::io::ErrorKind::Unsupported.into())
+    }
 }
 
 impl SystemInner {
@@ -418,7 +570,7 @@ unsafe fn add_missing_proc_info(
             }
         }
         unsafe {
-            get_exe(&mut proc_inner.exe, proc_inner.pid, refresh_kind);
+            get_exe(proc_inner, refresh_kind);
             system_info.get_proc_missing_info(kproc, proc_inner, refresh_kind);
         }
         if proc_inner.name.is_empty() {
@@ -557,34 +709,59 @@ impl SystemInfo {
         }
     }
 
-    /// Returns (used, total).
-    fn get_swap_info(&self) -> (u64, u64) {
+    /// Returns the `kvm_swap` entry of each swap device.
+    fn get_swap_entries(&self) -> Vec<libc::kvm_swap> {
         // Magic number used in htop. Cannot find how they got it when reading `kvm_getswapinfo`
         // source code so here we go...
         const LEN: usize = 16;
         let mut swap = MaybeUninit::<[libc::kvm_swap; LEN]>::uninit();
         unsafe {
             let nswap =
-                libc::kvm_getswapinfo(self.kd.as_ptr(), swap.as_mut_ptr() as *mut _, LEN as _, 0)
-                    as usize;
+                libc::kvm_getswapinfo(self.kd.as_ptr(), swap.as_mut_ptr() as *mut _, LEN as _, 0);
             if nswap < 1 {
-                return (0, 0);
+                return Vec::new();
             }
-            let swap =
-                std::slice::from_raw_parts(swap.as_ptr() as *mut libc::kvm_swap, nswap.min(LEN));
-            let (used, total) = swap.iter().fold((0, 0), |(used, total): (u64, u64), swap| {
-                (
-                    used.saturating_add(swap.ksw_used as _),
-                    total.saturating_add(swap.ksw_total as _),
-                )
-            });
-            (
-                used.saturating_mul(self.page_size as _),
-                total.saturating_mul(self.page_size as _),
+            std::slice::from_raw_parts(
+                swap.as_ptr() as *mut libc::kvm_swap,
+                (nswap as usize).min(LEN),
             )
+            .to_vec()
         }
     }
 
+    /// Returns (used, total).
+    fn get_swap_info(&self) -> (u64, u64) {
+        let (used, total) =
+            self.get_swap_entries()
+                .iter()
+                .fold((0, 0), |(used, total): (u64, u64), swap| {
+                    (
+                        used.saturating_add(swap.ksw_used as _),
+                        total.saturating_add(swap.ksw_total as _),
+                    )
+                });
+        (
+            used.saturating_mul(self.page_size as _),
+            total.saturating_mul(self.page_size as _),
+        )
+    }
+
+    fn get_swap_devices(&self) -> Vec<crate::SwapDevice> {
+        self.get_swap_entries()
+            .iter()
+            .map(|swap| {
+                // SAFETY: `ksw_devname` is a nul-terminated string filled by `kvm_getswapinfo`.
+                let name = unsafe { std::ffi::CStr::from_ptr(swap.ksw_devname.as_ptr()) };
+                crate::SwapDevice {
+                    path: std::path::Path::new("/dev").join(name.to_string_lossy().as_ref()),
+                    total: (swap.ksw_total as u64).saturating_mul(self.page_size as _),
+                    used: (swap.ksw_used as u64).saturating_mul(self.page_size as _),
+                    priority: None,
+                }
+            })
+            .collect()
+    }
+
     fn get_total_memory(&self) -> u64 {
         let mut nb_pages: u64 = 0;
         unsafe {
@@ -0,0 +1,28 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::sys::utils::get_sys_value_by_name;
+use crate::{ZfsArc, ZfsPool};
+
+pub(crate) fn get_zfs_pools(pools: &mut Vec<ZfsPool>) {
+    // No need to run `zpool` if the ZFS module isn't loaded.
+    if get_zfs_arc().is_none() {
+        pools.clear();
+        return;
+    }
+    crate::unix::zfs::get_zfs_pools(pools);
+}
+
+pub(crate) fn get_zfs_arc() -> Option<ZfsArc> {
+    let get = |name: &[u8]| {
+        let mut value: u64 = 0;
+        unsafe { get_sys_value_by_name(name, &mut value) }.then_some(value)
+    };
+    Some(ZfsArc {
+        size: get(b"kstat.zfs.misc.arcstats.size\0")?,
+        target_size: get(b"kstat.zfs.misc.arcstats.c\0").unwrap_or(0),
+        min_size: get(b"kstat.zfs.misc.arcstats.c_min\0").unwrap_or(0),
+        max_size: get(b"kstat.zfs.misc.arcstats.c_max\0").unwrap_or(0),
+        hits: get(b"kstat.zfs.misc.arcstats.hits\0").unwrap_or(0),
+        misses: get(b"kstat.zfs.misc.arcstats.misses\0").unwrap_or(0),
+    })
+}
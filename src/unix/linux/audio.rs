@@ -0,0 +1,143 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::{AudioDevice, AudioDeviceKind};
+
+use std::collections::HashMap;
+use std::fs;
+
+/// Parses `/proc/asound/cards`, which looks like:
+///
+/// ```text
+///  0 [PCH            ]: HDA-Intel - HDA Intel PCH
+///                       HDA Intel PCH at 0xf7f10000 irq 32
+/// ```
+///
+/// and returns the card names indexed by card number.
+fn parse_cards(data: &str) -> HashMap<u32, &str> {
+    data.lines()
+        .filter_map(|line| {
+            let (number, rest) = line.trim_start().split_once(" [")?;
+            let number = number.parse().ok()?;
+            let (_, name) = rest.split_once(" - ")?;
+            Some((number, name.trim()))
+        })
+        .collect()
+}
+
+/// Parses `/proc/asound/pcm`, which looks like:
+///
+/// ```text
+/// 00-00: ALC892 Analog : ALC892 Analog : playback 1 : capture 1
+/// 00-03: HDMI 0 : HDMI 0 : playback 1
+/// ```
+///
+/// ALSA's `default` device is the first device of the first card, so it is the one marked as
+/// default for each direction.
+fn parse_pcm(data: &str, cards: &HashMap<u32, &str>, devices: &mut Vec<AudioDevice>) {
+    for line in data.lines() {
+        let mut parts = line.split(" : ");
+        let Some((ids, pcm_name)) = parts.next().and_then(|p| p.split_once(": ")) else {
+            continue;
+        };
+        let Some((card, device)) = ids.split_once('-') else {
+            continue;
+        };
+        let (Ok(card), Ok(device)) = (card.parse::<u32>(), device.parse::<u32>()) else {
+            continue;
+        };
+        let pcm_name = pcm_name.trim();
+        let name = match cards.get(&card) {
+            Some(card_name) => format!("{card_name}, {pcm_name}"),
+            None => pcm_name.to_owned(),
+        };
+        // We skip the second name (which is the subdevice name).
+        for part in parts.skip(1) {
+            let kind = if part.starts_with("playback") {
+                AudioDeviceKind::Output
+            } else if part.starts_with("capture") {
+                AudioDeviceKind::Input
+            } else {
+                continue;
+            };
+            devices.push(AudioDevice {
+                id: format!("hw:{card},{device}"),
+                name: name.clone(),
+                kind,
+                is_default: card == 0 && device == 0,
+            });
+        }
+    }
+}
+
+pub(crate) fn get_audio_devices(devices: &mut Vec<AudioDevice>) {
+    devices.clear();
+
+    let Ok(pcm) = fs::read_to_string("/proc/asound/pcm") else {
+        return;
+    };
+    let cards = fs::read_to_string("/proc/asound/cards").unwrap_or_default();
+    parse_pcm(&pcm, &parse_cards(&cards), devices);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_cards, parse_pcm};
+    use crate::AudioDeviceKind;
+
+    #[test]
+    fn test_parse_asound() {
+        let cards = parse_cards(
+            " 0 [PCH            ]: HDA-Intel - HDA Intel PCH
+                      HDA Intel PCH at 0xf7f10000 irq 32
+ 1 [Device         ]: USB-Audio - USB Audio Device
+                      C-Media Electronics Inc. USB Audio Device at usb-0000:00:14.0-2, full speed
+",
+        );
+        assert_eq!(cards.len(), 2);
+        assert_eq!(cards[&0], "HDA Intel PCH");
+        assert_eq!(cards[&1], "USB Audio Device");
+
+        let mut devices = Vec::new();
+        parse_pcm(
+            "00-00: ALC892 Analog : ALC892 Analog : playback 1 : capture 1
+00-03: HDMI 0 : HDMI 0 : playback 1
+01-00: USB Audio : USB Audio : capture 1
+",
+            &cards,
+            &mut devices,
+        );
+        let devices = devices
+            .iter()
+            .map(|d| (d.id(), d.name(), d.kind(), d.is_default()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            devices,
+            [
+                (
+                    "hw:0,0",
+                    "HDA Intel PCH, ALC892 Analog",
+                    AudioDeviceKind::Output,
+                    true
+                ),
+                (
+                    "hw:0,0",
+                    "HDA Intel PCH, ALC892 Analog",
+                    AudioDeviceKind::Input,
+                    true
+                ),
+                (
+                    "hw:0,3",
+                    "HDA Intel PCH, HDMI 0",
+                    AudioDeviceKind::Output,
+                    false
+                ),
+                (
+                    "hw:1,0",
+                    "USB Audio Device, USB Audio",
+                    AudioDeviceKind::Input,
+                    false
+                ),
+            ]
+        );
+    }
+}
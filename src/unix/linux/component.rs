@@ -4,7 +4,7 @@
 //
 // Values in /sys/class/hwmonN are `c_long` or `c_ulong`
 // transposed to rust we only read `u32` or `i32` values.
-use crate::Component;
+use crate::{Component, ValueSource};
 
 use std::collections::HashMap;
 use std::ffi::OsStr;
@@ -95,6 +95,10 @@ pub(crate) struct ComponentInner {
     input_file: Option<PathBuf>,
     /// `temp[1-*]_highest file` to read if available highest value.
     highest_file: Option<PathBuf>,
+    /// Whether `max` was read from `highest_file` or computed by `sysinfo`.
+    max_from_hardware: bool,
+    /// Content of `temp[1-*]_label`, before `label` is computed.
+    raw_label: Option<String>,
     pub(crate) updated: bool,
 }
 
@@ -108,16 +112,21 @@ impl ComponentInner {
                     max,
                     input_file,
                     highest_file,
+                    max_from_hardware,
                     ..
                 },
+            ..
         }: Component,
     ) {
         if let Some(temp) = temperature {
             self.temperature = Some(temp);
         }
         match (max, self.max) {
-            (Some(new_max), Some(old_max)) => self.max = Some(new_max.max(old_max)),
-            (Some(max), None) => self.max = Some(max),
+            (Some(new_max), Some(old_max)) if new_max <= old_max => {}
+            (Some(max), _) => {
+                self.max = Some(max);
+                self.max_from_hardware = max_from_hardware;
+            }
             _ => {}
         }
         if input_file.is_some() && input_file != self.input_file {
@@ -230,7 +239,9 @@ fn fill_component(component: &mut ComponentInner, item: &str, folder: &Path, fil
         }
         "label" => component.label = get_file_line(&hwmon_file, 10).unwrap_or_default(),
         "highest" => {
-            component.max = get_temperature_from_file(&hwmon_file).or(component.temperature);
+            let highest = get_temperature_from_file(&hwmon_file);
+            component.max_from_hardware = highest.is_some();
+            component.max = highest.or(component.temperature);
             component.highest_file = Some(hwmon_file);
         }
         // "max" => component.threshold_max = get_temperature_from_file(&hwmon_file),
@@ -267,13 +278,25 @@ impl ComponentInner {
     ///
     /// Where `N` is a `u32` associated to a sensor like `temp1_max`, `temp1_input`.
     ///
+    /// The `hwmonN` numbering depends on the order in which the drivers were loaded, so the ID
+    /// of the components is computed from the path of their device in `devices` (like
+    /// `pci0000:00/0000:00:18.3/temp1`) if it has one, which doesn't change across reboots.
+    ///
     /// ## Doc to Linux kernel API.
     ///
     /// Kernel hwmon API: https://www.kernel.org/doc/html/latest/hwmon/hwmon-kernel-api.html
     /// DriveTemp kernel API: https://docs.kernel.org/gpu/amdgpu/thermal.html#hwmon-interfaces
     /// Amdgpu hwmon interface: https://www.kernel.org/doc/html/latest/hwmon/drivetemp.html
-    fn from_hwmon(components: &mut Vec<Component>, folder: &Path) -> Option<()> {
+    fn from_hwmon(
+        components: &mut Vec<Component>,
+        folder: &Path,
+        devices: Option<&Path>,
+    ) -> Option<()> {
         let dir = read_dir(folder).ok()?;
+        let device_path = devices.and_then(|devices| {
+            let path = folder.join("device").canonicalize().ok()?;
+            Some(path.strip_prefix(devices).ok()?.to_str()?.to_owned())
+        });
         let mut matchings: HashMap<u32, Component> = HashMap::with_capacity(10);
         for entry in dir.flatten() {
             if !entry.file_type().is_ok_and(|file_type| !file_type.is_dir()) {
@@ -292,13 +315,17 @@ impl ComponentInner {
 
             let component = matchings.entry(id).or_insert_with(|| Component {
                 inner: ComponentInner::default(),
+                max_observed: None,
             });
             let component = &mut component.inner;
             let name = get_file_line(&folder.join("name"), 16);
-            let component_id = folder
-                .file_name()
-                .and_then(OsStr::to_str)
-                .map(|f| format!("{f}_{id}"));
+            let component_id = match device_path {
+                Some(ref device_path) => Some(format!("{device_path}/temp{id}")),
+                None => folder
+                    .file_name()
+                    .and_then(OsStr::to_str)
+                    .map(|f| format!("{f}_{id}")),
+            };
             component.name = name.unwrap_or_default();
             component.id = component_id;
             let device_model = get_file_line(&folder.join("device/model"), 16);
@@ -312,10 +339,14 @@ impl ComponentInner {
             .filter(|(_, c)| c.inner.input_file.is_some())
         {
             // compute label from known data
+            if !new_comp.inner.label.is_empty() {
+                new_comp.inner.raw_label = Some(new_comp.inner.label.clone());
+            }
             new_comp.inner.label = new_comp.inner.format_label("temp", id);
+            // Labels aren't unique (several identical chips for example), unlike IDs.
             if let Some(comp) = components
                 .iter_mut()
-                .find(|comp| comp.inner.label == new_comp.inner.label)
+                .find(|comp| comp.inner.id == new_comp.inner.id)
             {
                 comp.inner.update_from(new_comp);
             } else {
@@ -359,10 +390,32 @@ impl ComponentInner {
         self.threshold_critical
     }
 
+    pub(crate) fn max_source(&self) -> Option<ValueSource> {
+        self.max.map(|_| {
+            if self.max_from_hardware {
+                ValueSource::Hardware
+            } else {
+                ValueSource::Inferred
+            }
+        })
+    }
+
+    pub(crate) fn critical_source(&self) -> Option<ValueSource> {
+        self.threshold_critical.map(|_| ValueSource::Hardware)
+    }
+
     pub(crate) fn label(&self) -> &str {
         &self.label
     }
 
+    pub(crate) fn raw_label(&self) -> Option<&str> {
+        self.raw_label.as_deref()
+    }
+
+    pub(crate) fn chip(&self) -> Option<&str> {
+        Some(self.name.as_str()).filter(|name| !name.is_empty())
+    }
+
     pub(crate) fn id(&self) -> Option<&str> {
         self.id.as_deref()
     }
@@ -373,15 +426,16 @@ impl ComponentInner {
             .as_ref()
             .and_then(|file| get_temperature_from_file(file.as_path()));
         // tries to read out kernel highest if not compute something from temperature.
-        let max = self
+        let highest = self
             .highest_file
             .as_ref()
-            .and_then(|file| get_temperature_from_file(file.as_path()))
-            .or_else(|| {
-                let last = self.temperature?;
-                let current = current?;
-                Some(last.max(current))
-            });
+            .and_then(|file| get_temperature_from_file(file.as_path()));
+        self.max_from_hardware = highest.is_some();
+        let max = highest.or_else(|| {
+            let last = self.temperature?;
+            let current = current?;
+            Some(last.max(current))
+        });
         self.max = max;
         self.temperature = current;
     }
@@ -406,6 +460,20 @@ fn read_temp_dir<F: FnMut(PathBuf)>(path: &Path, starts_with: &str, mut f: F) {
     }
 }
 
+/// Returns the temperature of the `critical` trip point of a thermal zone, at which the system
+/// is shut down.
+fn thermal_zone_critical_trip_point(zone: &Path) -> Option<f32> {
+    (0..)
+        .map_while(|index| {
+            get_file_line(&zone.join(format!("trip_point_{index}_type")), 10)
+                .map(|kind| (index, kind))
+        })
+        .find(|(_, kind)| kind == "critical")
+        .and_then(|(index, _)| {
+            get_temperature_from_file(&zone.join(format!("trip_point_{index}_temp")))
+        })
+}
+
 pub(crate) struct ComponentsInner {
     pub(crate) components: Vec<Component>,
 }
@@ -438,8 +506,12 @@ impl ComponentsInner {
     }
 
     fn refresh_from_sys_class_path(&mut self, path: &Path) {
+        let devices = path
+            .parent()
+            .map(|sys| sys.join("devices"))
+            .and_then(|devices| devices.canonicalize().ok());
         read_temp_dir(&path.join("hwmon"), "hwmon", |path| {
-            ComponentInner::from_hwmon(&mut self.components, &path);
+            ComponentInner::from_hwmon(&mut self.components, &path, devices.as_deref());
         });
         if self.components.is_empty() {
             // Normally should only be used by raspberry pi.
@@ -450,13 +522,20 @@ impl ComponentsInner {
                         return;
                     };
                     let component_id = path.file_name().and_then(OsStr::to_str).map(str::to_string);
+                    // Thermal zones have no label, their type (like `cpu-thermal`) is the best
+                    // description available.
                     let mut component = ComponentInner {
+                        label: name.clone(),
                         name,
                         id: component_id,
+                        threshold_critical: thermal_zone_critical_trip_point(&path),
                         ..Default::default()
                     };
                     fill_component(&mut component, "input", &path, "temp");
-                    self.components.push(Component { inner: component });
+                    self.components.push(Component {
+                        inner: component,
+                        max_observed: None,
+                    });
                 }
             });
         }
@@ -611,6 +690,78 @@ mod tests {
         assert_eq!(components[1].id(), Some("hwmon0_2"));
     }
 
+    #[test]
+    fn test_component_provenance() {
+        let temp_dir = tempfile::tempdir().expect("failed to create temporary directory");
+        let hwmon0_dir = temp_dir.path().join("hwmon/hwmon0");
+        fs::create_dir_all(&hwmon0_dir).expect("failed to create hwmon/hwmon0 directory");
+
+        fs::write(hwmon0_dir.join("name"), "coretemp").expect("failed to write to name file");
+
+        fs::write(hwmon0_dir.join("temp1_label"), "Package id 0")
+            .expect("failed to write to temp1_label file");
+        fs::write(hwmon0_dir.join("temp1_input"), "40000")
+            .expect("failed to write to temp1_input file");
+        fs::write(hwmon0_dir.join("temp1_highest"), "70000")
+            .expect("failed to write to temp1_highest file");
+        fs::write(hwmon0_dir.join("temp1_crit"), "100000")
+            .expect("failed to write to temp1_crit file");
+
+        fs::write(hwmon0_dir.join("temp2_input"), "50000")
+            .expect("failed to write to temp2_input file");
+
+        let mut components = ComponentsInner::new();
+        components.refresh_from_sys_class_path(temp_dir.path());
+        let mut components = components.into_vec();
+        components.sort_by(|a, b| a.id().cmp(&b.id()));
+
+        assert_eq!(components.len(), 2);
+        assert_eq!(components[0].chip(), Some("coretemp"));
+        assert_eq!(components[0].raw_label(), Some("Package id 0"));
+        assert_eq!(components[0].label(), "coretemp Package id 0");
+        assert_eq!(components[0].max(), Some(70.));
+        assert_eq!(components[0].max_source(), Some(ValueSource::Hardware));
+        assert_eq!(components[0].critical_source(), Some(ValueSource::Hardware));
+
+        assert_eq!(components[1].chip(), Some("coretemp"));
+        assert_eq!(components[1].raw_label(), None);
+        assert_eq!(components[1].label(), "coretemp temp2");
+        assert_eq!(components[1].max(), Some(50.));
+        assert_eq!(components[1].max_source(), Some(ValueSource::Inferred));
+        assert_eq!(components[1].critical_source(), None);
+    }
+
+    #[test]
+    fn test_component_id_from_device() {
+        let temp_dir = tempfile::tempdir().expect("failed to create temporary directory");
+        let class_dir = temp_dir.path().join("class");
+        let mut components = ComponentsInner::new();
+
+        // The same chip at two different addresses, registered in a different order.
+        for (hwmon, device) in [("hwmon0", "i2c-1/1-004c"), ("hwmon1", "i2c-1/1-004d")] {
+            let device_dir = temp_dir.path().join("devices").join(device);
+            let hwmon_dir = class_dir.join("hwmon").join(hwmon);
+            fs::create_dir_all(&device_dir).expect("failed to create device directory");
+            fs::create_dir_all(&hwmon_dir).expect("failed to create hwmon directory");
+            std::os::unix::fs::symlink(&device_dir, hwmon_dir.join("device"))
+                .expect("failed to create device symlink");
+            fs::write(hwmon_dir.join("name"), "lm90").expect("failed to write to name file");
+            fs::write(hwmon_dir.join("temp1_input"), "1234")
+                .expect("failed to write to temp1_input file");
+        }
+
+        components.refresh_from_sys_class_path(&class_dir);
+        components.refresh_from_sys_class_path(&class_dir);
+        let mut components = components.into_vec();
+        components.sort_by(|a, b| a.id().cmp(&b.id()));
+
+        // Both have the same label but are still listed separately.
+        assert_eq!(components.len(), 2);
+        assert_eq!(components[0].label(), components[1].label());
+        assert_eq!(components[0].id(), Some("i2c-1/1-004c/temp1"));
+        assert_eq!(components[1].id(), Some("i2c-1/1-004d/temp1"));
+    }
+
     #[test]
     fn test_thermal_zone() {
         let temp_dir = tempfile::tempdir().expect("failed to create temporary directory");
@@ -624,6 +775,14 @@ mod tests {
         fs::write(thermal_zone0_dir.join("type"), "test_name")
             .expect("failed to write to name file");
         fs::write(thermal_zone0_dir.join("temp"), "1234").expect("failed to write to temp file");
+        fs::write(thermal_zone0_dir.join("trip_point_0_type"), "passive")
+            .expect("failed to write to trip_point_0_type file");
+        fs::write(thermal_zone0_dir.join("trip_point_0_temp"), "60000")
+            .expect("failed to write to trip_point_0_temp file");
+        fs::write(thermal_zone0_dir.join("trip_point_1_type"), "critical")
+            .expect("failed to write to trip_point_1_type file");
+        fs::write(thermal_zone0_dir.join("trip_point_1_temp"), "90000")
+            .expect("failed to write to trip_point_1_temp file");
 
         // create thermal zone files
         fs::create_dir_all(thermal_zone1_dir.join("device"))
@@ -640,15 +799,17 @@ mod tests {
 
         assert_eq!(components.len(), 2);
         assert_eq!(components[0].inner.name, "test_name");
-        assert_eq!(components[0].label(), "");
+        assert_eq!(components[0].label(), "test_name");
         assert_eq!(components[0].temperature(), Some(1.234));
         assert_eq!(components[0].max(), Some(1.234));
+        assert_eq!(components[0].critical(), Some(90.));
         assert_eq!(components[0].id(), Some("thermal_zone0"));
 
         assert_eq!(components[1].inner.name, "test_name2");
-        assert_eq!(components[1].label(), "");
+        assert_eq!(components[1].label(), "test_name2");
         assert_eq!(components[1].temperature(), Some(5.678));
         assert_eq!(components[1].max(), Some(5.678));
+        assert_eq!(components[1].critical(), None);
         assert_eq!(components[1].id(), Some("thermal_zone1"));
     }
 }
@@ -7,8 +7,9 @@ use std::fs::File;
 use std::io::{BufRead, BufReader, Read};
 use std::time::Instant;
 
+use crate::parsing::{parse_sched_debug_nr_running, parse_schedstat_run_delays};
 use crate::sys::utils::to_u64;
-use crate::{Cpu, CpuRefreshKind};
+use crate::{Cpu, CpuIdleState, CpuRefreshKind, RefreshError};
 
 macro_rules! to_str {
     ($e:expr) => {
@@ -21,7 +22,10 @@ pub(crate) struct CpusWrapper {
     pub(crate) cpus: Vec<Cpu>,
     got_cpu_frequency: bool,
     /// This field is needed to prevent updating when not enough time passed since last update.
-    last_update: Option<Instant>,
+    pub(crate) last_update: Option<Instant>,
+    /// When the per-CPU times were last updated. It differs from `last_update` if only the
+    /// global CPU times were updated (when computing the processes CPU usage).
+    pub(crate) last_cpus_update: Option<Instant>,
 }
 
 impl CpusWrapper {
@@ -31,6 +35,7 @@ impl CpusWrapper {
             cpus: Vec::with_capacity(4),
             got_cpu_frequency: false,
             last_update: None,
+            last_cpus_update: None,
         }
     }
 
@@ -39,10 +44,16 @@ impl CpusWrapper {
         only_update_global_cpu: bool,
         refresh_kind: CpuRefreshKind,
     ) {
-        self.refresh(only_update_global_cpu, refresh_kind);
+        if let Err(_e) = self.refresh(only_update_global_cpu, refresh_kind) {
+            sysinfo_debug!("failed to retrieve CPU information: {:?}", _e);
+        }
     }
 
-    pub(crate) fn refresh(&mut self, only_update_global_cpu: bool, refresh_kind: CpuRefreshKind) {
+    pub(crate) fn refresh(
+        &mut self,
+        only_update_global_cpu: bool,
+        refresh_kind: CpuRefreshKind,
+    ) -> Result<(), RefreshError> {
         let need_cpu_usage_update = self
             .last_update
             .map(|last_update| last_update.elapsed() >= crate::MINIMUM_CPU_UPDATE_INTERVAL)
@@ -56,99 +67,99 @@ impl CpusWrapper {
         };
 
         // If the last CPU usage update is too close (less than `MINIMUM_CPU_UPDATE_INTERVAL`),
-        // we don't want to update CPUs times.
-        if need_cpu_usage_update {
-            self.last_update = Some(Instant::now());
-            let f = match File::open("/proc/stat") {
-                Ok(f) => f,
-                Err(_e) => {
-                    sysinfo_debug!("failed to retrieve CPU information: {:?}", _e);
-                    return;
-                }
-            };
+        // we don't want to update CPUs times. No need to open `/proc/stat` either if the CPU usage
+        // isn't requested.
+        if need_cpu_usage_update && (first || refresh_kind.cpu_usage()) {
+            let now = Instant::now();
+            let f = File::open("/proc/stat").map_err(|e| RefreshError::from_io("/proc/stat", e))?;
             let buf = BufReader::new(f);
 
             let mut i: usize = 0;
             let mut it = buf.split(b'\n');
 
-            if first || refresh_kind.cpu_usage() {
-                if let Some(Ok(line)) = it.next() {
-                    if line.len() < 4 || &line[..4] != b"cpu " {
-                        return;
+            {
+                let line = match it.next() {
+                    Some(Ok(line)) if line.starts_with(b"cpu ") => line,
+                    Some(Err(e)) => return Err(RefreshError::from_io("/proc/stat", e)),
+                    _ => return Err(RefreshError::Parse("/proc/stat".into())),
+                };
+                self.last_update = Some(now);
+                let mut parts = line.split(|x| *x == b' ').filter(|s| !s.is_empty()).skip(1);
+                self.global_cpu.set(
+                    parts.next().map(to_u64).unwrap_or(0),
+                    parts.next().map(to_u64).unwrap_or(0),
+                    parts.next().map(to_u64).unwrap_or(0),
+                    parts.next().map(to_u64).unwrap_or(0),
+                    parts.next().map(to_u64).unwrap_or(0),
+                    parts.next().map(to_u64).unwrap_or(0),
+                    parts.next().map(to_u64).unwrap_or(0),
+                    parts.next().map(to_u64).unwrap_or(0),
+                    parts.next().map(to_u64).unwrap_or(0),
+                    parts.next().map(to_u64).unwrap_or(0),
+                );
+            }
+            if first || !only_update_global_cpu {
+                self.last_cpus_update = self.last_update;
+                for line in it {
+                    let line = line.map_err(|e| RefreshError::from_io("/proc/stat", e))?;
+                    if line.len() < 3 || &line[..3] != b"cpu" {
+                        break;
                     }
-                    let mut parts = line.split(|x| *x == b' ').filter(|s| !s.is_empty()).skip(1);
-                    self.global_cpu.set(
-                        parts.next().map(to_u64).unwrap_or(0),
-                        parts.next().map(to_u64).unwrap_or(0),
-                        parts.next().map(to_u64).unwrap_or(0),
-                        parts.next().map(to_u64).unwrap_or(0),
-                        parts.next().map(to_u64).unwrap_or(0),
-                        parts.next().map(to_u64).unwrap_or(0),
-                        parts.next().map(to_u64).unwrap_or(0),
-                        parts.next().map(to_u64).unwrap_or(0),
-                        parts.next().map(to_u64).unwrap_or(0),
-                        parts.next().map(to_u64).unwrap_or(0),
-                    );
-                }
-                if first || !only_update_global_cpu {
-                    while let Some(Ok(line)) = it.next() {
-                        if line.len() < 3 || &line[..3] != b"cpu" {
-                            break;
-                        }
 
-                        let mut parts = line.split(|x| *x == b' ').filter(|s| !s.is_empty());
-                        if first {
-                            let (vendor_id, brand) = match vendors_brands.remove(&i) {
-                                Some((vendor_id, brand)) => (vendor_id, brand),
-                                None => (String::new(), String::new()),
-                            };
-                            self.cpus.push(Cpu {
-                                inner: CpuInner::new_with_values(
-                                    to_str!(parts.next().unwrap_or(&[])),
-                                    parts.next().map(to_u64).unwrap_or(0),
-                                    parts.next().map(to_u64).unwrap_or(0),
-                                    parts.next().map(to_u64).unwrap_or(0),
-                                    parts.next().map(to_u64).unwrap_or(0),
-                                    parts.next().map(to_u64).unwrap_or(0),
-                                    parts.next().map(to_u64).unwrap_or(0),
-                                    parts.next().map(to_u64).unwrap_or(0),
-                                    parts.next().map(to_u64).unwrap_or(0),
-                                    parts.next().map(to_u64).unwrap_or(0),
-                                    parts.next().map(to_u64).unwrap_or(0),
-                                    0,
-                                    vendor_id,
-                                    brand,
-                                ),
-                            });
+                    let mut parts = line.split(|x| *x == b' ').filter(|s| !s.is_empty());
+                    if first {
+                        let (vendor_id, brand) = match vendors_brands.remove(&i) {
+                            Some((vendor_id, brand)) => (vendor_id, brand),
+                            None => (String::new(), String::new()),
+                        };
+                        self.cpus.push(Cpu {
+                            inner: CpuInner::new_with_values(
+                                to_str!(parts.next().unwrap_or(&[])),
+                                parts.next().map(to_u64).unwrap_or(0),
+                                parts.next().map(to_u64).unwrap_or(0),
+                                parts.next().map(to_u64).unwrap_or(0),
+                                parts.next().map(to_u64).unwrap_or(0),
+                                parts.next().map(to_u64).unwrap_or(0),
+                                parts.next().map(to_u64).unwrap_or(0),
+                                parts.next().map(to_u64).unwrap_or(0),
+                                parts.next().map(to_u64).unwrap_or(0),
+                                parts.next().map(to_u64).unwrap_or(0),
+                                parts.next().map(to_u64).unwrap_or(0),
+                                0,
+                                vendor_id,
+                                brand,
+                            ),
+                            peak_usage: 0.,
+                            measurement_interval: None,
+                        });
+                    } else {
+                        parts.next(); // we don't want the name again
+                        if let Some(cpu) = self.cpus.get_mut(i) {
+                            cpu.inner.set(
+                                parts.next().map(to_u64).unwrap_or(0),
+                                parts.next().map(to_u64).unwrap_or(0),
+                                parts.next().map(to_u64).unwrap_or(0),
+                                parts.next().map(to_u64).unwrap_or(0),
+                                parts.next().map(to_u64).unwrap_or(0),
+                                parts.next().map(to_u64).unwrap_or(0),
+                                parts.next().map(to_u64).unwrap_or(0),
+                                parts.next().map(to_u64).unwrap_or(0),
+                                parts.next().map(to_u64).unwrap_or(0),
+                                parts.next().map(to_u64).unwrap_or(0),
+                            );
                         } else {
-                            parts.next(); // we don't want the name again
-                            if let Some(cpu) = self.cpus.get_mut(i) {
-                                cpu.inner.set(
-                                    parts.next().map(to_u64).unwrap_or(0),
-                                    parts.next().map(to_u64).unwrap_or(0),
-                                    parts.next().map(to_u64).unwrap_or(0),
-                                    parts.next().map(to_u64).unwrap_or(0),
-                                    parts.next().map(to_u64).unwrap_or(0),
-                                    parts.next().map(to_u64).unwrap_or(0),
-                                    parts.next().map(to_u64).unwrap_or(0),
-                                    parts.next().map(to_u64).unwrap_or(0),
-                                    parts.next().map(to_u64).unwrap_or(0),
-                                    parts.next().map(to_u64).unwrap_or(0),
-                                );
-                            } else {
-                                // A new CPU was added, so let's ignore it. If they want it into
-                                // the list, they need to use `refresh_cpu_list`.
-                                sysinfo_debug!("ignoring new CPU added");
-                            }
+                            // A new CPU was added, so let's ignore it. If they want it into
+                            // the list, they need to use `refresh_cpu_list`.
+                            sysinfo_debug!("ignoring new CPU added");
                         }
-
-                        i += 1;
                     }
-                }
-                if i < self.cpus.len() {
-                    sysinfo_debug!("{} CPU(s) seem to have been removed", self.cpus.len() - i);
+
+                    i += 1;
                 }
             }
+            if i < self.cpus.len() && (first || !only_update_global_cpu) {
+                sysinfo_debug!("{} CPU(s) seem to have been removed", self.cpus.len() - i);
+            }
         }
 
         if refresh_kind.frequency() {
@@ -173,13 +184,27 @@ impl CpusWrapper {
                 val.iter_mut()
             }
 
+            let global_boost = get_global_boost();
             // `get_cpu_frequency` is very slow, so better run it in parallel.
             iter_mut(&mut self.cpus)
                 .enumerate()
-                .for_each(|(pos, proc_)| proc_.inner.frequency = get_cpu_frequency(pos));
+                .for_each(|(pos, proc_)| {
+                    proc_.inner.frequency = get_cpu_frequency(pos);
+                    proc_.inner.boost_active =
+                        get_cpu_boost(pos, &proc_.inner.vendor_id, global_boost);
+                });
 
             self.got_cpu_frequency = true;
         }
+
+        if refresh_kind.run_queue() {
+            refresh_run_queues(&mut self.cpus);
+        }
+
+        if refresh_kind.idle_states() {
+            refresh_idle_states(&mut self.cpus);
+        }
+        Ok(())
     }
 
     pub(crate) fn get_global_raw_times(&self) -> (u64, u64) {
@@ -368,6 +393,10 @@ pub(crate) struct CpuInner {
     pub(crate) frequency: u64,
     pub(crate) vendor_id: String,
     pub(crate) brand: String,
+    run_queue_length: Option<usize>,
+    run_queue_wait_time: Option<u64>,
+    boost_active: Option<bool>,
+    idle_states: Vec<CpuIdleState>,
 }
 
 impl CpuInner {
@@ -395,6 +424,10 @@ impl CpuInner {
             frequency,
             vendor_id,
             brand,
+            run_queue_length: None,
+            run_queue_wait_time: None,
+            boost_active: None,
+            idle_states: Vec::new(),
         }
     }
 
@@ -436,6 +469,115 @@ impl CpuInner {
     pub(crate) fn brand(&self) -> &str {
         &self.brand
     }
+
+    pub(crate) fn run_queue_length(&self) -> Option<usize> {
+        self.run_queue_length
+    }
+
+    pub(crate) fn run_queue_wait_time(&self) -> Option<u64> {
+        self.run_queue_wait_time
+    }
+
+    pub(crate) fn boost_active(&self) -> Option<bool> {
+        self.boost_active
+    }
+
+    pub(crate) fn idle_states(&self) -> &[CpuIdleState] {
+        &self.idle_states
+    }
+}
+
+/// Updates the run queue information of `cpus`.
+fn refresh_run_queues(cpus: &mut [Cpu]) {
+    let mut s = String::new();
+    let mut lengths = Vec::new();
+    for path in ["/sys/kernel/debug/sched/debug", "/proc/sched_debug"] {
+        if File::open(path)
+            .and_then(|mut f| f.read_to_string(&mut s))
+            .is_ok()
+        {
+            lengths = parse_sched_debug_nr_running(&s);
+            break;
+        }
+        s.clear();
+    }
+    s.clear();
+    let delays = if File::open("/proc/schedstat")
+        .and_then(|mut f| f.read_to_string(&mut s))
+        .is_ok()
+    {
+        parse_schedstat_run_delays(&s)
+    } else {
+        Vec::new()
+    };
+
+    // CPUs are listed in the same order everywhere but offline CPUs are skipped, so their
+    // number is used to match them.
+    for cpu in cpus.iter_mut() {
+        let number = cpu
+            .inner
+            .name
+            .strip_prefix("cpu")
+            .and_then(|number| number.parse::<usize>().ok());
+        cpu.inner.run_queue_length = number.and_then(|number| {
+            lengths
+                .iter()
+                .find(|(index, _)| *index == number)
+                .map(|(_, length)| *length)
+        });
+        cpu.inner.run_queue_wait_time = number.and_then(|number| {
+            delays
+                .iter()
+                .find(|(index, _)| *index == number)
+                .map(|(_, delay)| delay / 1_000_000)
+        });
+    }
+}
+
+/// Updates the idle states of `cpus` from cpuidle.
+fn refresh_idle_states(cpus: &mut [Cpu]) {
+    for cpu in cpus.iter_mut() {
+        cpu.inner.idle_states.clear();
+        let Ok(entries) = std::fs::read_dir(format!(
+            "/sys/devices/system/cpu/{}/cpuidle",
+            cpu.inner.name
+        )) else {
+            continue;
+        };
+        let mut states = entries
+            .flatten()
+            .filter_map(|entry| {
+                let index = entry
+                    .file_name()
+                    .to_str()?
+                    .strip_prefix("state")?
+                    .parse::<usize>()
+                    .ok()?;
+                Some((index, entry.path()))
+            })
+            .collect::<Vec<_>>();
+        states.sort_unstable_by_key(|(index, _)| *index);
+
+        cpu.inner
+            .idle_states
+            .extend(states.into_iter().map(|(_, path)| {
+                let read = |name: &str| {
+                    std::fs::read_to_string(path.join(name))
+                        .map(|value| value.trim().to_owned())
+                        .unwrap_or_default()
+                };
+                let read_u64 = |name: &str| read(name).parse().unwrap_or(0);
+                CpuIdleState {
+                    name: read("name"),
+                    description: read("desc"),
+                    exit_latency: read_u64("latency"),
+                    target_residency: read_u64("residency"),
+                    entry_count: read_u64("usage"),
+                    residency: read_u64("time"),
+                    is_disabled: read("disable") == "1",
+                }
+            }));
+    }
 }
 
 pub(crate) fn get_cpu_frequency(cpu_core_index: usize) -> u64 {
@@ -474,6 +616,62 @@ pub(crate) fn get_cpu_frequency(cpu_core_index: usize) -> u64 {
         .unwrap_or_default()
 }
 
+fn read_sysfs_bool(path: &str) -> Option<bool> {
+    match std::fs::read_to_string(path).ok()?.trim() {
+        "0" => Some(false),
+        "1" => Some(true),
+        _ => None,
+    }
+}
+
+/// Returns whether the boost (Intel Turbo Boost or AMD Core Performance Boost) is enabled for
+/// all CPUs, as reported by the cpufreq driver.
+pub(crate) fn get_global_boost() -> Option<bool> {
+    // `intel_pstate` reports whether the turbo is *disabled*.
+    if let Some(no_turbo) = read_sysfs_bool("/sys/devices/system/cpu/intel_pstate/no_turbo") {
+        return Some(!no_turbo);
+    }
+    read_sysfs_bool("/sys/devices/system/cpu/cpufreq/boost")
+}
+
+/// Returns whether the boost is enabled for the CPU `cpu_core_index`.
+pub(crate) fn get_cpu_boost(
+    cpu_core_index: usize,
+    vendor_id: &str,
+    global_boost: Option<bool>,
+) -> Option<bool> {
+    // Since Linux 6.11, drivers like `amd-pstate` allow to enable the boost per cpufreq policy.
+    read_sysfs_bool(&format!(
+        "/sys/devices/system/cpu/cpu{cpu_core_index}/cpufreq/boost"
+    ))
+    .or(global_boost)
+    .or_else(|| get_msr_boost(cpu_core_index, vendor_id))
+}
+
+/// Reads whether the boost is enabled from the model-specific registers of the CPU, which
+/// requires the `msr` kernel module and root privileges.
+fn get_msr_boost(cpu_core_index: usize, vendor_id: &str) -> Option<bool> {
+    use std::os::unix::fs::FileExt;
+
+    let (register, disable_mask) = boost_msr(vendor_id)?;
+    let file = File::open(format!("/dev/cpu/{cpu_core_index}/msr")).ok()?;
+    let mut value = [0; 8];
+    file.read_exact_at(&mut value, register).ok()?;
+    Some(u64::from_ne_bytes(value) & disable_mask == 0)
+}
+
+/// Returns the model-specific register which can disable the boost and the mask of the bit
+/// disabling it.
+fn boost_msr(vendor_id: &str) -> Option<(u64, u64)> {
+    match vendor_id {
+        // `IA32_MISC_ENABLE`, "Turbo Mode Disable" bit.
+        "GenuineIntel" => Some((0x1a0, 1 << 38)),
+        // `HWCR`, "CpbDis" bit.
+        "AuthenticAMD" | "HygonGenuine" => Some((0xc001_0015, 1 << 25)),
+        _ => None,
+    }
+}
+
 #[allow(unused_assignments)]
 pub(crate) fn get_physical_core_count() -> Option<usize> {
     let mut s = String::new();
@@ -762,7 +960,23 @@ pub(crate) fn get_vendor_id_and_brand() -> HashMap<usize, (String, String)> {
     {
         return HashMap::new();
     }
-    get_vendor_id_and_brand_inner(&s)
+    get_vendor_id_and_brand_inner(&s, |index| {
+        std::fs::read(format!(
+            "/sys/devices/system/cpu/cpu{index}/of_node/compatible"
+        ))
+        .ok()
+    })
+}
+
+/// Returns the vendor of a RISC-V CPU from its JEDEC manufacturer ID (`mvendorid`).
+fn get_riscv_vendor(mvendorid: u64) -> Option<&'static str> {
+    Some(match mvendorid {
+        0x31e => "Andes",
+        0x489 => "SiFive",
+        0x5b7 => "T-Head",
+        0x710 => "SpacemiT",
+        _ => return None,
+    })
 }
 
 #[inline]
@@ -771,7 +985,13 @@ fn cpuinfo_is_key(line: &str, key: &[u8]) -> bool {
     line.len() > key.len() && line[..key.len()].eq_ignore_ascii_case(key)
 }
 
-fn get_vendor_id_and_brand_inner(data: &str) -> HashMap<usize, (String, String)> {
+/// `devicetree_compatible` returns the devicetree `compatible` property of the given CPU. It is
+/// used when `/proc/cpuinfo` doesn't provide the vendor or the brand (on RISC-V and on a lot of
+/// ARM boards).
+fn get_vendor_id_and_brand_inner<F: Fn(usize) -> Option<Vec<u8>>>(
+    data: &str,
+    devicetree_compatible: F,
+) -> HashMap<usize, (String, String)> {
     fn get_value(s: &str) -> String {
         s.split(':')
             .next_back()
@@ -800,6 +1020,10 @@ fn get_vendor_id_and_brand_inner(data: &str) -> HashMap<usize, (String, String)>
         brand: Option<String>,
         implementer: Option<u32>,
         part: Option<u32>,
+        // RISC-V specific fields.
+        isa: Option<String>,
+        uarch: Option<String>,
+        mvendorid: Option<u64>,
     }
 
     impl CpuInfo {
@@ -808,7 +1032,10 @@ fn get_vendor_id_and_brand_inner(data: &str) -> HashMap<usize, (String, String)>
                 || (self.implementer.is_some() && self.part.is_some())
         }
 
-        fn convert(mut self) -> (usize, String, String) {
+        fn convert<F: Fn(usize) -> Option<Vec<u8>>>(
+            mut self,
+            devicetree_compatible: F,
+        ) -> (usize, String, String) {
             let (vendor_id, brand) = if let (Some(implementer), Some(part)) =
                 (self.implementer.take(), self.part.take())
             {
@@ -828,7 +1055,34 @@ fn get_vendor_id_and_brand_inner(data: &str) -> HashMap<usize, (String, String)>
                     .or_else(|| self.brand.take());
                 (vendor_id, brand)
             } else {
-                (self.vendor_id.take(), self.brand.take())
+                // The `uarch` field of RISC-V CPUs has the same `<vendor>,<model>` format as the
+                // devicetree `compatible` property.
+                let (vendor_id, brand) = match self.uarch.as_deref().and_then(|u| u.split_once(','))
+                {
+                    Some((vendor_id, brand)) => (
+                        self.vendor_id.take().or_else(|| Some(vendor_id.to_owned())),
+                        self.brand.take().or_else(|| Some(brand.to_owned())),
+                    ),
+                    None => (self.vendor_id.take(), self.brand.take()),
+                };
+                let vendor_id = vendor_id
+                    .or_else(|| self.mvendorid.and_then(get_riscv_vendor).map(String::from));
+                (vendor_id, brand)
+            };
+            let (vendor_id, brand) = if vendor_id.is_none() || brand.is_none() {
+                let compatible = devicetree_compatible(self.index);
+                let compatible = compatible
+                    .as_deref()
+                    .and_then(crate::parsing::parse_devicetree_compatible);
+                (
+                    vendor_id.or_else(|| compatible.map(|(vendor_id, _)| vendor_id.to_owned())),
+                    brand
+                        .or_else(|| compatible.map(|(_, brand)| brand.to_owned()))
+                        // The ISA string (like `rv64imafdc_zicsr`) is better than nothing.
+                        .or_else(|| self.isa.take()),
+                )
+            } else {
+                (vendor_id, brand)
             };
             (
                 self.index,
@@ -869,12 +1123,20 @@ fn get_vendor_id_and_brand_inner(data: &str) -> HashMap<usize, (String, String)>
                     info.implementer = Some(get_hex_value(line));
                 } else if cpuinfo_is_key(line, b"CPU part\t") {
                     info.part = Some(get_hex_value(line));
+                } else if cpuinfo_is_key(line, b"isa\t") {
+                    info.isa = Some(get_value(line));
+                } else if cpuinfo_is_key(line, b"uarch\t") {
+                    info.uarch = Some(get_value(line));
+                } else if cpuinfo_is_key(line, b"mvendorid\t") {
+                    info.mvendorid = get_value(line)
+                        .strip_prefix("0x")
+                        .and_then(|id| u64::from_str_radix(id, 16).ok());
                 } else if info.has_all_info() || is_new_processor(line) {
                     break;
                 }
                 lines.next();
             }
-            let (index, vendor_id, brand) = info.convert();
+            let (index, vendor_id, brand) = info.convert(&devicetree_compatible);
             cpus.insert(index, (vendor_id, brand));
         }
     }
@@ -883,7 +1145,7 @@ fn get_vendor_id_and_brand_inner(data: &str) -> HashMap<usize, (String, String)>
 
 #[cfg(test)]
 mod test {
-    use super::get_vendor_id_and_brand_inner;
+    use super::{boost_msr, get_vendor_id_and_brand_inner};
 
     // The iterator was skipping the `is_new_processor` check because we already moved past
     // the line where `processor]\t` is located.
@@ -935,7 +1197,57 @@ model name		: Loongson-3A R4 (Loongson-3B4000) @ 1800MHz
 CPU MHz			: 1800.00
 core			: 3"#;
 
-        let cpus = get_vendor_id_and_brand_inner(DATA);
+        let cpus = get_vendor_id_and_brand_inner(DATA, |_| None);
         assert_eq!(cpus.len(), 7);
     }
+
+    #[test]
+    fn test_riscv_cpu_retrieval() {
+        const DATA: &str = "processor\t: 0
+hart\t\t: 1
+isa\t\t: rv64imafdc_zicntr_zicsr_zifencei_zihpm
+mmu\t\t: sv39
+uarch\t\t: sifive,u74-mc
+mvendorid\t: 0x489
+marchid\t\t: 0x8000000000000007
+mimpid\t\t: 0x4210427
+
+processor\t: 1
+hart\t\t: 2
+isa\t\t: rv64imafdc_zicntr_zicsr_zifencei_zihpm
+mmu\t\t: sv39
+mvendorid\t: 0x5b7
+marchid\t\t: 0x0
+
+processor\t: 2
+hart\t\t: 3
+isa\t\t: rv64imafdc
+mmu\t\t: sv39
+";
+
+        let cpus = get_vendor_id_and_brand_inner(DATA, |index| {
+            (index == 1).then(|| b"thead,c910\0riscv\0".to_vec())
+        });
+        assert_eq!(cpus[&0], ("sifive".to_owned(), "u74-mc".to_owned()));
+        assert_eq!(cpus[&1], ("T-Head".to_owned(), "c910".to_owned()));
+        assert_eq!(cpus[&2], (String::new(), "rv64imafdc".to_owned()));
+    }
+
+    #[test]
+    fn test_arm_devicetree_cpu_retrieval() {
+        const DATA: &str = "processor\t: 0
+BogoMIPS\t: 48.00
+Features\t: fp asimd evtstrm crc32 cpuid
+";
+
+        let cpus = get_vendor_id_and_brand_inner(DATA, |_| Some(b"arm,cortex-a53\0".to_vec()));
+        assert_eq!(cpus[&0], ("arm".to_owned(), "cortex-a53".to_owned()));
+    }
+
+    #[test]
+    fn test_boost_msr() {
+        assert_eq!(boost_msr("GenuineIntel"), Some((0x1a0, 1 << 38)));
+        assert_eq!(boost_msr("AuthenticAMD"), Some((0xc001_0015, 1 << 25)));
+        assert_eq!(boost_msr("ARM"), None);
+    }
 }
@@ -1,7 +1,11 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
+use crate::parsing::parse_mountstats;
 use crate::sys::utils::{get_all_utf8_data, to_cpath};
-use crate::{Disk, DiskKind, DiskRefreshKind, DiskUsage};
+use crate::{
+    Disk, DiskIoStats, DiskKind, DiskRefreshKind, DiskSpace, DiskUsage, NetworkFsStats,
+    RefreshError,
+};
 
 use libc::statvfs;
 use std::collections::HashMap;
@@ -11,6 +15,7 @@ use std::mem::MaybeUninit;
 use std::os::unix::ffi::OsStrExt;
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
+use std::time::{Duration, Instant};
 
 /// Copied from [`psutil`]:
 ///
@@ -48,6 +53,10 @@ pub(crate) struct DiskInner {
     old_read_bytes: u64,
     written_bytes: u64,
     read_bytes: u64,
+    /// The `/proc/diskstats` values of the last refresh, used to compute `io_stats`.
+    last_disk_stat: Option<(Instant, DiskStat)>,
+    io_stats: Option<DiskIoStats>,
+    network_fs_stats: Option<NetworkFsStats>,
     updated: bool,
 }
 
@@ -85,13 +94,19 @@ impl DiskInner {
     }
 
     pub(crate) fn refresh_specifics(&mut self, refresh_kind: DiskRefreshKind) -> bool {
-        self.efficient_refresh(refresh_kind, &disk_stats(&refresh_kind), false)
+        self.efficient_refresh(
+            refresh_kind,
+            &disk_stats(&refresh_kind),
+            &mount_stats(&refresh_kind),
+            false,
+        )
     }
 
     fn efficient_refresh(
         &mut self,
         refresh_kind: DiskRefreshKind,
         procfs_disk_stats: &HashMap<String, DiskStat>,
+        procfs_mount_stats: &HashMap<PathBuf, NetworkFsStats>,
         first: bool,
     ) -> bool {
         if refresh_kind.io_usage() {
@@ -107,9 +122,15 @@ impl DiskInner {
                 self.old_written_bytes = self.written_bytes;
                 self.read_bytes = stat.sectors_read * SECTOR_SIZE;
                 self.written_bytes = stat.sectors_written * SECTOR_SIZE;
+                let now = Instant::now();
+                if let Some((last_update, last_stat)) = &self.last_disk_stat {
+                    self.io_stats = Some(stat.io_stats(last_stat, now - *last_update));
+                }
+                self.last_disk_stat = Some((now, *stat));
             } else {
                 sysinfo_debug!("Failed to update disk i/o stats");
             }
+            self.network_fs_stats = procfs_mount_stats.get(&self.mount_point).copied();
         }
 
         if refresh_kind.kind() && self.type_ == DiskKind::Unknown(-1) {
@@ -138,6 +159,14 @@ impl DiskInner {
             total_written_bytes: self.written_bytes,
         }
     }
+
+    pub(crate) fn network_fs_stats(&self) -> Option<NetworkFsStats> {
+        self.network_fs_stats
+    }
+
+    pub(crate) fn io_stats(&self) -> Option<DiskIoStats> {
+        self.io_stats
+    }
 }
 
 impl crate::DisksInner {
@@ -147,16 +176,14 @@ impl crate::DisksInner {
         }
     }
 
-    pub(crate) fn refresh_specifics(
+    pub(crate) fn try_refresh_specifics(
         &mut self,
         remove_not_listed_disks: bool,
         refresh_kind: DiskRefreshKind,
-    ) {
-        get_all_list(
-            &mut self.disks,
-            &get_all_utf8_data("/proc/mounts", 16_385).unwrap_or_default(),
-            refresh_kind,
-        );
+    ) -> Result<(), RefreshError> {
+        let mounts = get_all_utf8_data("/proc/mounts", 16_385)
+            .map_err(|e| RefreshError::from_io("/proc/mounts", e))?;
+        get_all_list(&mut self.disks, &mounts, refresh_kind);
 
         if remove_not_listed_disks {
             self.disks.retain_mut(|disk| {
@@ -171,6 +198,17 @@ impl crate::DisksInner {
                 c.inner.updated = false;
             }
         }
+        Ok(())
+    }
+
+    pub(crate) fn refresh_specifics(
+        &mut self,
+        remove_not_listed_disks: bool,
+        refresh_kind: DiskRefreshKind,
+    ) {
+        if let Err(_e) = self.try_refresh_specifics(remove_not_listed_disks, refresh_kind) {
+            sysinfo_debug!("failed to refresh disks: {_e:?}");
+        }
     }
 
     pub(crate) fn list(&self) -> &[Disk] {
@@ -180,6 +218,47 @@ impl crate::DisksInner {
     pub(crate) fn list_mut(&mut self) -> &mut [Disk] {
         &mut self.disks
     }
+
+    pub(crate) fn usage_for_path(path: &Path) -> Option<DiskSpace> {
+        let path = fs::canonicalize(path).ok()?;
+        let mounts = get_all_utf8_data("/proc/self/mounts", 16_385).ok()?;
+        // Mount points are listed in the order they were mounted. If the same mount point is
+        // used more than once, only the last one is visible, and `max_by_key` returns the last
+        // maximum element.
+        let mount_point = mounts
+            .lines()
+            .filter_map(|line| {
+                Some(PathBuf::from(unescape_mount_point(
+                    line.split_whitespace().nth(1)?,
+                )))
+            })
+            .filter(|mount_point| path.starts_with(mount_point))
+            .max_by_key(|mount_point| mount_point.components().count())?;
+
+        let path = to_cpath(&path);
+        let mut stat: MaybeUninit<statvfs> = MaybeUninit::uninit();
+        if unsafe { retry_eintr!(statvfs(path.as_ptr() as *const _, stat.as_mut_ptr())) } != 0 {
+            return None;
+        }
+        let stat = unsafe { stat.assume_init() };
+        let bsize = cast!(stat.f_bsize);
+        Some(DiskSpace {
+            mount_point,
+            total_space: bsize.saturating_mul(cast!(stat.f_blocks)),
+            available_space: bsize.saturating_mul(cast!(stat.f_bavail)),
+            used_space: bsize
+                .saturating_mul(cast!(stat.f_blocks).saturating_sub(cast!(stat.f_bfree))),
+        })
+    }
+}
+
+/// Replaces the characters escaped in the mount points of `/proc/mounts`.
+fn unescape_mount_point(mount_point: &str) -> String {
+    mount_point
+        .replace("\\134", "\\")
+        .replace("\\040", " ")
+        .replace("\\011", "\t")
+        .replace("\\012", "\n")
 }
 
 /// Resolves the actual device name for a specified `device` from `/proc/mounts`
@@ -235,6 +314,7 @@ fn new_disk(
     file_system: &OsStr,
     removable_entries: &[PathBuf],
     procfs_disk_stats: &HashMap<String, DiskStat>,
+    procfs_mount_stats: &HashMap<PathBuf, NetworkFsStats>,
     refresh_kind: DiskRefreshKind,
 ) -> Disk {
     let is_removable = removable_entries
@@ -256,11 +336,14 @@ fn new_disk(
             old_written_bytes: 0,
             read_bytes: 0,
             written_bytes: 0,
+            last_disk_stat: None,
+            io_stats: None,
+            network_fs_stats: None,
             updated: true,
         },
     };
     disk.inner
-        .efficient_refresh(refresh_kind, procfs_disk_stats, true);
+        .efficient_refresh(refresh_kind, procfs_disk_stats, procfs_mount_stats, true);
     disk
 }
 
@@ -356,6 +439,7 @@ fn get_all_list(container: &mut Vec<Disk>, content: &str, refresh_kind: DiskRefr
     };
 
     let procfs_disk_stats = disk_stats(&refresh_kind);
+    let procfs_mount_stats = mount_stats(&refresh_kind);
 
     for (fs_spec, fs_file, fs_vfstype) in content
         .lines()
@@ -366,13 +450,7 @@ fn get_all_list(container: &mut Vec<Disk>, content: &str, refresh_kind: DiskRefr
             // fs_spec<tab>fs_file<tab>fs_vfstype<tab>other fields
             let mut fields = line.split_whitespace();
             let fs_spec = fields.next().unwrap_or("");
-            let fs_file = fields
-                .next()
-                .unwrap_or("")
-                .replace("\\134", "\\")
-                .replace("\\040", " ")
-                .replace("\\011", "\t")
-                .replace("\\012", "\n");
+            let fs_file = unescape_mount_point(fields.next().unwrap_or(""));
             let fs_vfstype = fields.next().unwrap_or("");
             (fs_spec, fs_file, fs_vfstype)
         })
@@ -412,8 +490,12 @@ fn get_all_list(container: &mut Vec<Disk>, content: &str, refresh_kind: DiskRefr
                 && d.inner.device_name == fs_spec
                 && d.inner.file_system == fs_vfstype
         }) {
-            disk.inner
-                .efficient_refresh(refresh_kind, &procfs_disk_stats, false);
+            disk.inner.efficient_refresh(
+                refresh_kind,
+                &procfs_disk_stats,
+                &procfs_mount_stats,
+                false,
+            );
             disk.inner.updated = true;
             continue;
         }
@@ -423,6 +505,7 @@ fn get_all_list(container: &mut Vec<Disk>, content: &str, refresh_kind: DiskRefr
             fs_vfstype.as_ref(),
             &removable_entries,
             &procfs_disk_stats,
+            &procfs_mount_stats,
             refresh_kind,
         ));
     }
@@ -454,10 +537,17 @@ fn get_all_list(container: &mut Vec<Disk>, content: &str, refresh_kind: DiskRefr
 /// Doc reference: https://www.kernel.org/doc/Documentation/ABI/testing/procfs-diskstats
 ///
 /// Doc reference: https://www.kernel.org/doc/Documentation/iostats.txt
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
 struct DiskStat {
+    reads: u64,
     sectors_read: u64,
+    read_ms: u64,
+    writes: u64,
     sectors_written: u64,
+    write_ms: u64,
+    in_flight: u64,
+    io_ms: u64,
+    weighted_io_ms: u64,
 }
 
 impl DiskStat {
@@ -466,18 +556,64 @@ impl DiskStat {
         let mut iter = line.split_whitespace();
         // 3rd field
         let name = iter.nth(2).map(ToString::to_string)?;
-        // 6th field
-        let sectors_read = iter.nth(2).and_then(|v| u64::from_str(v).ok()).unwrap_or(0);
-        // 10th field
-        let sectors_written = iter.nth(3).and_then(|v| u64::from_str(v).ok()).unwrap_or(0);
+        let mut values = iter.map(|v| u64::from_str(v).unwrap_or(0));
+        let mut next = || values.next().unwrap_or(0);
+        let reads = next();
+        // Reads merged.
+        next();
+        let sectors_read = next();
+        let read_ms = next();
+        let writes = next();
+        // Writes merged.
+        next();
+        let sectors_written = next();
+        let write_ms = next();
+        let in_flight = next();
+        let io_ms = next();
+        let weighted_io_ms = next();
         Some((
             name,
             Self {
+                reads,
                 sectors_read,
+                read_ms,
+                writes,
                 sectors_written,
+                write_ms,
+                in_flight,
+                io_ms,
+                weighted_io_ms,
             },
         ))
     }
+
+    /// Computes the statistics between `old` and `self`, which were retrieved `elapsed` apart.
+    fn io_stats(&self, old: &Self, elapsed: Duration) -> DiskIoStats {
+        let requests = (self.reads.saturating_sub(old.reads))
+            .saturating_add(self.writes.saturating_sub(old.writes));
+        let requests_ms = (self.read_ms.saturating_sub(old.read_ms))
+            .saturating_add(self.write_ms.saturating_sub(old.write_ms));
+        let average_latency = if requests == 0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64(requests_ms as f64 / requests as f64 / 1_000.)
+        };
+        let elapsed_ms = elapsed.as_secs_f64() * 1_000.;
+        let (utilization, average_queue_depth) = if elapsed_ms > 0. {
+            (
+                (self.io_ms.saturating_sub(old.io_ms) as f64 / elapsed_ms * 100.).min(100.),
+                self.weighted_io_ms.saturating_sub(old.weighted_io_ms) as f64 / elapsed_ms,
+            )
+        } else {
+            (0., 0.)
+        };
+        DiskIoStats {
+            average_latency,
+            in_flight: self.in_flight,
+            utilization: utilization as f32,
+            average_queue_depth: average_queue_depth as f32,
+        }
+    }
 }
 
 fn disk_stats(refresh_kind: &DiskRefreshKind) -> HashMap<String, DiskStat> {
@@ -495,6 +631,36 @@ fn disk_stats(refresh_kind: &DiskRefreshKind) -> HashMap<String, DiskStat> {
     }
 }
 
+/// Returns the statistics of the NFS mounts from `/proc/self/mountstats`, indexed by mount point.
+fn mount_stats(refresh_kind: &DiskRefreshKind) -> HashMap<PathBuf, NetworkFsStats> {
+    // Network file systems are only listed with the `linux-netdevs` feature.
+    if !refresh_kind.io_usage() || !cfg!(feature = "linux-netdevs") {
+        return HashMap::new();
+    }
+    let path = "/proc/self/mountstats";
+    match fs::read_to_string(path) {
+        Ok(content) => parse_mountstats(&content)
+            .into_iter()
+            .map(|stats| {
+                (
+                    PathBuf::from(stats.mount_point),
+                    NetworkFsStats {
+                        operations: stats.operations,
+                        transmissions: stats.transmissions,
+                        timeouts: stats.timeouts,
+                        rtt_ms: stats.rtt_ms,
+                        execute_ms: stats.execute_ms,
+                    },
+                )
+            })
+            .collect(),
+        Err(_error) => {
+            sysinfo_debug!("failed to read {path:?}: {_error:?}");
+            HashMap::new()
+        }
+    }
+}
+
 // We split this function out to make it possible to test it.
 fn disk_stats_inner(content: &str) -> HashMap<String, DiskStat> {
     let mut data = HashMap::new();
@@ -515,6 +681,7 @@ fn disk_stats_inner(content: &str) -> HashMap<String, DiskStat> {
 mod test {
     use super::{DiskStat, disk_stats_inner};
     use std::collections::HashMap;
+    use std::time::Duration;
 
     #[test]
     fn test_disk_stat_parsing() {
@@ -534,51 +701,100 @@ mod test {
             (
                 "nvme0n1".to_string(),
                 DiskStat {
+                    reads: 571695,
                     sectors_read: 38943220,
+                    read_ms: 165643,
+                    writes: 9824246,
                     sectors_written: 462375378,
+                    write_ms: 4140037,
+                    in_flight: 0,
+                    io_ms: 1038904,
+                    weighted_io_ms: 4740493,
                 },
             ),
             (
                 "nvme0n1p1".to_string(),
                 DiskStat {
+                    reads: 240,
                     sectors_read: 15468,
+                    read_ms: 48,
+                    writes: 2,
                     sectors_written: 2,
+                    write_ms: 0,
+                    in_flight: 0,
+                    io_ms: 21,
+                    weighted_io_ms: 50,
                 },
             ),
             (
                 "nvme0n1p2".to_string(),
                 DiskStat {
+                    reads: 243,
                     sectors_read: 11626,
+                    read_ms: 26,
+                    writes: 63,
                     sectors_written: 616,
+                    write_ms: 125,
+                    in_flight: 0,
+                    io_ms: 84,
+                    weighted_io_ms: 163,
                 },
             ),
             (
                 "nvme0n1p3".to_string(),
                 DiskStat {
+                    reads: 571069,
                     sectors_read: 38910302,
+                    read_ms: 165547,
+                    writes: 9824180,
                     sectors_written: 462374760,
+                    write_ms: 4139911,
+                    in_flight: 0,
+                    io_ms: 1084855,
+                    weighted_io_ms: 4373964,
                 },
             ),
             (
                 "dm-0".to_string(),
                 DiskStat {
+                    reads: 670206,
                     sectors_read: 38909056,
+                    read_ms: 259490,
+                    writes: 10900330,
                     sectors_written: 462374760,
+                    write_ms: 12906518,
+                    in_flight: 0,
+                    io_ms: 1177098,
+                    weighted_io_ms: 13195902,
                 },
             ),
             (
                 "zram0".to_string(),
                 DiskStat {
+                    reads: 2382,
                     sectors_read: 20984,
+                    read_ms: 11,
+                    writes: 260261,
                     sectors_written: 2082088,
+                    write_ms: 2063,
+                    in_flight: 0,
+                    io_ms: 1964,
+                    weighted_io_ms: 2074,
                 },
             ),
             // This one ensures that we read the correct fields.
             (
                 "bla".to_string(),
                 DiskStat {
+                    reads: 4,
                     sectors_read: 6,
+                    read_ms: 7,
+                    writes: 8,
                     sectors_written: 10,
+                    write_ms: 11,
+                    in_flight: 12,
+                    io_ms: 13,
+                    weighted_io_ms: 14,
                 },
             ),
         ]);
@@ -595,22 +811,47 @@ mod test {
 
         let data = disk_stats_inner(file_content);
         let expected_data: HashMap<String, DiskStat> = HashMap::from([
-            (
-                "autofs".to_string(),
-                DiskStat {
-                    sectors_read: 0,
-                    sectors_written: 0,
-                },
-            ),
-            (
-                "vfat".to_string(),
-                DiskStat {
-                    sectors_read: 0,
-                    sectors_written: 0,
-                },
-            ),
+            ("autofs".to_string(), DiskStat::default()),
+            ("vfat".to_string(), DiskStat::default()),
         ]);
 
         assert_eq!(data, expected_data);
     }
+
+    #[test]
+    fn test_disk_io_stats() {
+        let old = DiskStat {
+            reads: 100,
+            read_ms: 400,
+            writes: 50,
+            write_ms: 200,
+            in_flight: 1,
+            io_ms: 1_000,
+            weighted_io_ms: 2_000,
+            ..Default::default()
+        };
+        let new = DiskStat {
+            reads: 120,
+            read_ms: 500,
+            writes: 60,
+            write_ms: 350,
+            in_flight: 3,
+            io_ms: 1_500,
+            weighted_io_ms: 3_000,
+            ..Default::default()
+        };
+        let stats = new.io_stats(&old, Duration::from_secs(1));
+        // 250ms for 30 requests.
+        assert_eq!(
+            stats.average_latency(),
+            Duration::from_secs_f64(250. / 30. / 1_000.)
+        );
+        assert_eq!(stats.in_flight(), 3);
+        assert_eq!(stats.utilization(), 50.);
+        assert_eq!(stats.average_queue_depth(), 1.);
+
+        let stats = old.io_stats(&old, Duration::ZERO);
+        assert_eq!(stats.average_latency(), Duration::ZERO);
+        assert_eq!(stats.utilization(), 0.);
+    }
 }
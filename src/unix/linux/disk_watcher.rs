@@ -0,0 +1,55 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::common::disk_watcher::MountEntry;
+use crate::parsing::parse_mountinfo_line;
+
+use std::fs::File;
+use std::io::{self, Read, Seek, SeekFrom};
+use std::os::fd::AsRawFd;
+use std::time::Duration;
+
+pub(crate) struct DiskWatcherInner {
+    mountinfo: File,
+    buffer: String,
+}
+
+impl DiskWatcherInner {
+    pub(crate) fn open() -> io::Result<Self> {
+        Ok(Self {
+            mountinfo: File::open("/proc/self/mountinfo")?,
+            buffer: String::new(),
+        })
+    }
+
+    /// The kernel signals a change of the mount table by setting `POLLPRI` on the opened
+    /// `mountinfo` files (see `man 5 proc_pid_mountinfo`).
+    pub(crate) fn wait(&mut self, timeout: Duration) -> bool {
+        let mut fd = libc::pollfd {
+            fd: self.mountinfo.as_raw_fd(),
+            events: libc::POLLPRI,
+            revents: 0,
+        };
+        let ret = unsafe { libc::poll(&mut fd, 1, timeout.as_millis() as _) };
+        ret > 0 && fd.revents & (libc::POLLPRI | libc::POLLERR) != 0
+    }
+
+    pub(crate) fn mounts(&mut self) -> io::Result<Vec<MountEntry>> {
+        self.buffer.clear();
+        // Reading the file again also acknowledges the change notified by `poll`.
+        self.mountinfo.seek(SeekFrom::Start(0))?;
+        self.mountinfo.read_to_string(&mut self.buffer)?;
+        Ok(self
+            .buffer
+            .lines()
+            .filter_map(parse_mountinfo_line)
+            .map(|line| MountEntry {
+                id: Some(line.mount_id),
+                name: line.source.into(),
+                mount_point: line.mount_point.into(),
+                file_system: line.file_system.into(),
+                is_read_only: line.options.split(',').any(|option| option == "ro"),
+                options: line.options.to_owned(),
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,141 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::Display;
+
+use std::fs::{self, read_dir};
+use std::path::Path;
+
+/// Parses a DRM mode (like `1920x1080` or `1920x1080i`) into its width and height.
+fn parse_mode(mode: &str) -> Option<(u32, u32)> {
+    let (width, height) = mode.trim().split_once('x')?;
+    let height = height.trim_end_matches(|c: char| !c.is_ascii_digit());
+    Some((width.parse().ok()?, height.parse().ok()?))
+}
+
+/// Information extracted from an EDID blob.
+#[derive(Debug, Default, PartialEq)]
+struct EdidInfo {
+    name: Option<String>,
+    /// Width, height and refresh rate of the preferred timing.
+    preferred_timing: Option<(u32, u32, f64)>,
+}
+
+/// Parses the four 18 bytes descriptors of an EDID blob. The first one contains the preferred
+/// timing and the monitor name is stored in a display descriptor with the `0xFC` tag.
+fn parse_edid(edid: &[u8]) -> EdidInfo {
+    let mut info = EdidInfo::default();
+    if edid.len() < 128 || edid[..8] != [0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x00] {
+        return info;
+    }
+    for descriptor in edid[54..126].chunks_exact(18) {
+        let pixel_clock = u16::from_le_bytes([descriptor[0], descriptor[1]]) as u64;
+        if pixel_clock != 0 {
+            if info.preferred_timing.is_some() {
+                continue;
+            }
+            let h_active = descriptor[2] as u32 | ((descriptor[4] as u32 & 0xF0) << 4);
+            let h_blank = descriptor[3] as u32 | ((descriptor[4] as u32 & 0x0F) << 8);
+            let v_active = descriptor[5] as u32 | ((descriptor[7] as u32 & 0xF0) << 4);
+            let v_blank = descriptor[6] as u32 | ((descriptor[7] as u32 & 0x0F) << 8);
+            let total = (h_active + h_blank) as u64 * (v_active + v_blank) as u64;
+            if total != 0 {
+                // The pixel clock is stored in units of 10 kHz.
+                let refresh_rate = (pixel_clock * 10_000) as f64 / total as f64;
+                info.preferred_timing = Some((h_active, v_active, refresh_rate));
+            }
+        } else if descriptor[3] == 0xFC {
+            let name = String::from_utf8_lossy(&descriptor[5..]);
+            let name = name.split('\n').next().unwrap_or_default().trim();
+            if !name.is_empty() {
+                info.name = Some(name.to_owned());
+            }
+        }
+    }
+    info
+}
+
+/// Returns the name of the kernel driver of the DRM card (like `amdgpu`).
+fn get_gpu(card: &Path) -> Option<String> {
+    let driver = fs::read_link(card.join("device/driver")).ok()?;
+    Some(driver.file_name()?.to_str()?.to_owned())
+}
+
+pub(crate) fn get_displays(displays: &mut Vec<Display>) {
+    displays.clear();
+
+    let Ok(entries) = read_dir("/sys/class/drm") else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let file_name = entry.file_name();
+        let Some(file_name) = file_name.to_str() else {
+            continue;
+        };
+        // Connectors are listed as `card[N]-[connector]`.
+        let Some((card, connector)) = file_name.split_once('-') else {
+            continue;
+        };
+        if !card.starts_with("card") {
+            continue;
+        }
+        let path = entry.path();
+        if fs::read_to_string(path.join("status")).ok().as_deref() != Some("connected\n") {
+            continue;
+        }
+        // The first mode is the preferred one.
+        let Some((width, height)) = fs::read_to_string(path.join("modes"))
+            .ok()
+            .and_then(|modes| parse_mode(modes.lines().next()?))
+        else {
+            continue;
+        };
+        let edid = parse_edid(&fs::read(path.join("edid")).unwrap_or_default());
+        displays.push(Display {
+            id: connector.to_owned(),
+            name: edid.name,
+            width,
+            height,
+            refresh_rate: edid
+                .preferred_timing
+                .filter(|(w, h, _)| *w == width && *h == height)
+                .map(|(_, _, refresh_rate)| refresh_rate),
+            gpu: get_gpu(&Path::new("/sys/class/drm").join(card)),
+            is_primary: false,
+        });
+    }
+    displays.sort_unstable_by(|a, b| a.id.cmp(&b.id));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{EdidInfo, parse_edid, parse_mode};
+
+    #[test]
+    fn test_parse_mode() {
+        assert_eq!(parse_mode("1920x1080\n"), Some((1920, 1080)));
+        assert_eq!(parse_mode("1920x1080i"), Some((1920, 1080)));
+        assert_eq!(parse_mode("foo"), None);
+    }
+
+    #[test]
+    fn test_parse_edid() {
+        assert_eq!(parse_edid(&[]), EdidInfo::default());
+
+        let mut edid = vec![0; 128];
+        edid[..8].copy_from_slice(&[0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x00]);
+        // 1920x1080 at 60 Hz (CEA-861 timing: 148.5 MHz, 2200x1125 total).
+        edid[54..72].copy_from_slice(&[
+            0x02, 0x3A, 0x80, 0x18, 0x71, 0x38, 0x2D, 0x40, 0x58, 0x2C, 0x45, 0x00, 0, 0, 0, 0, 0,
+            0x1E,
+        ]);
+        edid[72..90].copy_from_slice(&[
+            0, 0, 0, 0xFC, 0, b'D', b'E', b'L', b'L', b' ', b'U', b'2', b'4', b'1', b'5', b'\n',
+            b' ', b' ',
+        ]);
+        let info = parse_edid(&edid);
+        assert_eq!(info.name.as_deref(), Some("DELL U2415"));
+        let (width, height, refresh_rate) = info.preferred_timing.unwrap();
+        assert_eq!((width, height), (1920, 1080));
+        assert!((refresh_rate - 60.).abs() < 0.01);
+    }
+}
@@ -0,0 +1,603 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+// Minimal eBPF loader: the programs are assembled here and loaded with the `bpf` syscall
+// directly, so no compiler nor BPF library is needed to build `sysinfo`.
+
+use crate::Pid;
+use crate::common::ebpf::LatencyStats;
+
+use std::collections::HashMap;
+use std::ffi::CStr;
+use std::io;
+use std::mem;
+use std::os::fd::{AsRawFd, FromRawFd, OwnedFd};
+use std::time::Duration;
+
+// `bpf` syscall commands.
+const BPF_MAP_CREATE: libc::c_long = 0;
+const BPF_MAP_LOOKUP_ELEM: libc::c_long = 1;
+const BPF_MAP_GET_NEXT_KEY: libc::c_long = 4;
+const BPF_PROG_LOAD: libc::c_long = 5;
+const BPF_RAW_TRACEPOINT_OPEN: libc::c_long = 17;
+
+const BPF_MAP_TYPE_LRU_HASH: u32 = 9;
+const BPF_PROG_TYPE_RAW_TRACEPOINT: u32 = 17;
+
+// Flags of `bpf_map_update_elem`.
+const BPF_ANY: i32 = 0;
+const BPF_NOEXIST: i32 = 1;
+
+// Helper functions.
+const MAP_LOOKUP_ELEM: i32 = 1;
+const MAP_UPDATE_ELEM: i32 = 2;
+const MAP_DELETE_ELEM: i32 = 3;
+const KTIME_GET_NS: i32 = 5;
+const GET_CURRENT_PID_TGID: i32 = 14;
+
+// Registers.
+const R0: u8 = 0;
+const R1: u8 = 1;
+const R2: u8 = 2;
+const R3: u8 = 3;
+const R4: u8 = 4;
+const R6: u8 = 6;
+const R7: u8 = 7;
+const R8: u8 = 8;
+const R9: u8 = 9;
+const R10: u8 = 10;
+
+/// `TCP_SYN_SENT` from `include/net/tcp_states.h`.
+const TCP_SYN_SENT: i32 = 2;
+
+/// A `struct bpf_insn`.
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct Insn {
+    code: u8,
+    regs: u8,
+    off: i16,
+    imm: i32,
+}
+
+/// Assembles a BPF program, resolving the jumps to labels.
+#[derive(Default)]
+struct Asm {
+    insns: Vec<Insn>,
+    labels: HashMap<&'static str, usize>,
+    jumps: Vec<(usize, &'static str)>,
+}
+
+impl Asm {
+    fn push(&mut self, code: u8, dst: u8, src: u8, off: i16, imm: i32) -> &mut Self {
+        self.insns.push(Insn {
+            code,
+            regs: dst | (src << 4),
+            off,
+            imm,
+        });
+        self
+    }
+
+    fn jump(&mut self, code: u8, dst: u8, src: u8, imm: i32, label: &'static str) -> &mut Self {
+        self.jumps.push((self.insns.len(), label));
+        self.push(code, dst, src, 0, imm)
+    }
+
+    fn label(&mut self, label: &'static str) -> &mut Self {
+        self.labels.insert(label, self.insns.len());
+        self
+    }
+
+    fn mov_imm(&mut self, dst: u8, imm: i32) -> &mut Self {
+        self.push(0xb7, dst, 0, 0, imm)
+    }
+
+    fn mov(&mut self, dst: u8, src: u8) -> &mut Self {
+        self.push(0xbf, dst, src, 0, 0)
+    }
+
+    fn add_imm(&mut self, dst: u8, imm: i32) -> &mut Self {
+        self.push(0x07, dst, 0, 0, imm)
+    }
+
+    fn sub(&mut self, dst: u8, src: u8) -> &mut Self {
+        self.push(0x1f, dst, src, 0, 0)
+    }
+
+    fn rsh_imm(&mut self, dst: u8, imm: i32) -> &mut Self {
+        self.push(0x77, dst, 0, 0, imm)
+    }
+
+    /// `dst = *(u64 *)(src + off)`.
+    fn load(&mut self, dst: u8, src: u8, off: i16) -> &mut Self {
+        self.push(0x79, dst, src, off, 0)
+    }
+
+    /// `*(u64 *)(dst + off) = src`.
+    fn store(&mut self, dst: u8, off: i16, src: u8) -> &mut Self {
+        self.push(0x7b, dst, src, off, 0)
+    }
+
+    /// `*(u32 *)(dst + off) = src`.
+    fn store32(&mut self, dst: u8, off: i16, src: u8) -> &mut Self {
+        self.push(0x63, dst, src, off, 0)
+    }
+
+    /// `*(u64 *)(dst + off) = imm`.
+    fn store_imm(&mut self, dst: u8, off: i16, imm: i32) -> &mut Self {
+        self.push(0x7a, dst, 0, off, imm)
+    }
+
+    /// `*(u64 *)(dst + off) += src`, atomically.
+    fn atomic_add(&mut self, dst: u8, off: i16, src: u8) -> &mut Self {
+        self.push(0xdb, dst, src, off, 0)
+    }
+
+    fn load_map(&mut self, dst: u8, map: &Map) -> &mut Self {
+        // `BPF_PSEUDO_MAP_FD`.
+        self.push(0x18, dst, 1, 0, map.fd.as_raw_fd())
+            .push(0, 0, 0, 0, 0)
+    }
+
+    fn call(&mut self, helper: i32) -> &mut Self {
+        self.push(0x85, 0, 0, 0, helper)
+    }
+
+    fn jeq_imm(&mut self, dst: u8, imm: i32, label: &'static str) -> &mut Self {
+        self.jump(0x15, dst, 0, imm, label)
+    }
+
+    fn jne_imm(&mut self, dst: u8, imm: i32, label: &'static str) -> &mut Self {
+        self.jump(0x55, dst, 0, imm, label)
+    }
+
+    fn jge(&mut self, dst: u8, src: u8, label: &'static str) -> &mut Self {
+        self.jump(0x3d, dst, src, 0, label)
+    }
+
+    fn exit(&mut self) -> &mut Self {
+        self.mov_imm(R0, 0).push(0x95, 0, 0, 0, 0)
+    }
+
+    /// Calls `bpf_map_update_elem(map, r10 + key, r10 + value, flags)`.
+    fn update(&mut self, map: &Map, key: i16, value: i16, flags: i32) -> &mut Self {
+        self.load_map(R1, map)
+            .mov(R2, R10)
+            .add_imm(R2, key.into())
+            .mov(R3, R10)
+            .add_imm(R3, value.into())
+            .mov_imm(R4, flags)
+            .call(MAP_UPDATE_ELEM)
+    }
+
+    /// Calls `bpf_map_lookup_elem(map, r10 + key)`.
+    fn lookup(&mut self, map: &Map, key: i16) -> &mut Self {
+        self.load_map(R1, map)
+            .mov(R2, R10)
+            .add_imm(R2, key.into())
+            .call(MAP_LOOKUP_ELEM)
+    }
+
+    /// Calls `bpf_map_delete_elem(map, r10 + key)`.
+    fn delete(&mut self, map: &Map, key: i16) -> &mut Self {
+        self.load_map(R1, map)
+            .mov(R2, R10)
+            .add_imm(R2, key.into())
+            .call(MAP_DELETE_ELEM)
+    }
+
+    /// Adds the latency stored in `r9` to the [`RawLatency`] of `map` whose (`u32`) key is
+    /// stored at `r10 + key`, then jumps to `done`. Uses the stack below `key`.
+    fn add_latency(&mut self, map: &Map, key: i16, done: &'static str) -> &mut Self {
+        let value = key - mem::size_of::<RawLatency>() as i16;
+        self.lookup(map, key)
+            .jne_imm(R0, 0, "add_latency")
+            .store_imm(R10, value, 0)
+            .store_imm(R10, value + 8, 0)
+            .store_imm(R10, value + 16, 0)
+            .update(map, key, value, BPF_NOEXIST)
+            .lookup(map, key)
+            .jeq_imm(R0, 0, done)
+            .label("add_latency")
+            .atomic_add(R0, 0, R9)
+            .mov_imm(R1, 1)
+            .atomic_add(R0, 8, R1)
+            .load(R1, R0, 16)
+            .jge(R1, R9, done)
+            .store(R0, 16, R9)
+    }
+
+    fn finish(&self) -> Vec<Insn> {
+        let mut insns = self.insns.clone();
+        for &(index, label) in &self.jumps {
+            insns[index].off = (self.labels[label] as isize - index as isize - 1) as i16;
+        }
+        insns
+    }
+}
+
+/// Value of the latency maps, converted into [`LatencyStats`].
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+struct RawLatency {
+    total_ns: u64,
+    count: u64,
+    max_ns: u64,
+}
+
+impl From<RawLatency> for LatencyStats {
+    fn from(raw: RawLatency) -> Self {
+        Self {
+            count: raw.count,
+            total: Duration::from_nanos(raw.total_ns),
+            max: Duration::from_nanos(raw.max_ns),
+        }
+    }
+}
+
+fn bpf<T>(cmd: libc::c_long, attr: &mut T) -> io::Result<libc::c_long> {
+    let ret = unsafe {
+        libc::syscall(
+            libc::SYS_bpf,
+            cmd,
+            (attr as *mut T).cast::<libc::c_void>(),
+            mem::size_of::<T>() as libc::c_uint,
+        )
+    };
+    if ret < 0 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(ret)
+}
+
+fn into_fd(fd: libc::c_long) -> OwnedFd {
+    unsafe { OwnedFd::from_raw_fd(fd as _) }
+}
+
+struct Map {
+    fd: OwnedFd,
+}
+
+impl Map {
+    fn new(key_size: u32, value_size: u32, max_entries: u32) -> io::Result<Self> {
+        #[repr(C)]
+        struct Attr {
+            map_type: u32,
+            key_size: u32,
+            value_size: u32,
+            max_entries: u32,
+        }
+
+        let fd = bpf(
+            BPF_MAP_CREATE,
+            &mut Attr {
+                map_type: BPF_MAP_TYPE_LRU_HASH,
+                key_size,
+                value_size,
+                max_entries,
+            },
+        )?;
+        Ok(Self { fd: into_fd(fd) })
+    }
+
+    /// Returns all the entries of a map with `u32` keys.
+    fn entries<V: Copy + Default>(&self) -> Vec<(u32, V)> {
+        #[repr(C)]
+        struct Attr {
+            map_fd: u32,
+            _pad: u32,
+            key: u64,
+            value: u64,
+            flags: u64,
+        }
+
+        let mut entries = Vec::new();
+        let mut key: Option<u32> = None;
+        loop {
+            let mut next_key = 0u32;
+            let mut attr = Attr {
+                map_fd: self.fd.as_raw_fd() as _,
+                _pad: 0,
+                key: key
+                    .as_ref()
+                    .map(|key| key as *const u32 as u64)
+                    .unwrap_or(0),
+                value: &mut next_key as *mut u32 as u64,
+                flags: 0,
+            };
+            if bpf(BPF_MAP_GET_NEXT_KEY, &mut attr).is_err() {
+                // `ENOENT` is returned once all keys have been returned.
+                return entries;
+            }
+            let mut value = V::default();
+            let mut attr = Attr {
+                map_fd: self.fd.as_raw_fd() as _,
+                _pad: 0,
+                key: &next_key as *const u32 as u64,
+                value: &mut value as *mut V as u64,
+                flags: 0,
+            };
+            // The entry may have been removed in the meantime.
+            if bpf(BPF_MAP_LOOKUP_ELEM, &mut attr).is_ok() {
+                entries.push((next_key, value));
+            }
+            key = Some(next_key);
+        }
+    }
+}
+
+/// A program attached to a raw tracepoint. It is detached when dropped.
+struct Attached {
+    _prog: OwnedFd,
+    _link: OwnedFd,
+}
+
+fn load_and_attach(asm: &Asm, tracepoints: &[&CStr]) -> io::Result<Vec<Attached>> {
+    #[repr(C)]
+    struct LoadAttr {
+        prog_type: u32,
+        insn_cnt: u32,
+        insns: u64,
+        license: u64,
+        log_level: u32,
+        log_size: u32,
+        log_buf: u64,
+    }
+    #[repr(C)]
+    struct OpenAttr {
+        name: u64,
+        prog_fd: u32,
+        _pad: u32,
+    }
+
+    let insns = asm.finish();
+    let mut attached = Vec::with_capacity(tracepoints.len());
+    for tracepoint in tracepoints {
+        // A program can only be attached once.
+        let prog = into_fd(bpf(
+            BPF_PROG_LOAD,
+            &mut LoadAttr {
+                prog_type: BPF_PROG_TYPE_RAW_TRACEPOINT,
+                insn_cnt: insns.len() as _,
+                insns: insns.as_ptr() as u64,
+                license: c"Dual MIT/GPL".as_ptr() as u64,
+                log_level: 0,
+                log_size: 0,
+                log_buf: 0,
+            },
+        )?);
+        let link = into_fd(bpf(
+            BPF_RAW_TRACEPOINT_OPEN,
+            &mut OpenAttr {
+                name: tracepoint.as_ptr() as u64,
+                prog_fd: prog.as_raw_fd() as _,
+                _pad: 0,
+            },
+        )?);
+        attached.push(Attached {
+            _prog: prog,
+            _link: link,
+        });
+    }
+    Ok(attached)
+}
+
+/// Measures how long the tasks wait in a run queue.
+fn run_queue_latency(stats: &Map) -> io::Result<Vec<Attached>> {
+    // Task pointer => time when it was woken up (or preempted).
+    let queued = Map::new(8, 8, 16 * 1024)?;
+    // Task pointer => TGID (the PID of its process).
+    let tgids = Map::new(8, 8, 64 * 1024)?;
+
+    // `sched_wakeup(struct task_struct *p)` and `sched_wakeup_new(struct task_struct *p)`.
+    let mut wakeup = Asm::default();
+    wakeup
+        .load(R6, R1, 0)
+        .store(R10, -8, R6)
+        .call(KTIME_GET_NS)
+        .store(R10, -16, R0)
+        .update(&queued, -8, -16, BPF_ANY)
+        .exit();
+    let mut attached = load_and_attach(&wakeup, &[c"sched_wakeup", c"sched_wakeup_new"])?;
+
+    // `sched_switch(bool preempt, struct task_struct *prev, struct task_struct *next)`.
+    let mut switch = Asm::default();
+    switch
+        .mov(R6, R1)
+        .load(R7, R6, 8)
+        .load(R8, R6, 16)
+        // `prev` is the current task so we can retrieve its TGID.
+        .call(GET_CURRENT_PID_TGID)
+        .rsh_imm(R0, 32)
+        .store(R10, -8, R7)
+        .store(R10, -16, R0)
+        .update(&tgids, -8, -16, BPF_ANY)
+        .call(KTIME_GET_NS)
+        .mov(R9, R0)
+        // A preempted task goes back to the run queue.
+        .load(R1, R6, 0)
+        .jeq_imm(R1, 0, "next")
+        .store(R10, -8, R7)
+        .store(R10, -16, R9)
+        .update(&queued, -8, -16, BPF_ANY)
+        .label("next")
+        .store(R10, -8, R8)
+        .lookup(&queued, -8)
+        .jeq_imm(R0, 0, "done")
+        .load(R1, R0, 0)
+        .sub(R9, R1)
+        .delete(&queued, -8)
+        .lookup(&tgids, -8)
+        .jeq_imm(R0, 0, "done")
+        .load(R1, R0, 0)
+        .store32(R10, -24, R1)
+        .add_latency(stats, -24, "done")
+        .label("done")
+        .exit();
+    attached.extend(load_and_attach(&switch, &[c"sched_switch"])?);
+    Ok(attached)
+}
+
+/// Measures the latency of block I/O requests.
+fn io_latency(stats: &Map) -> io::Result<Vec<Attached>> {
+    // Request pointer => (start time, TGID).
+    let started = Map::new(8, 16, 16 * 1024)?;
+
+    // `block_io_start(struct request *rq)`, or `block_rq_issue(struct request *rq)` before
+    // Linux 6.5.
+    let mut start = Asm::default();
+    start
+        .load(R6, R1, 0)
+        .store(R10, -8, R6)
+        .call(GET_CURRENT_PID_TGID)
+        .rsh_imm(R0, 32)
+        .store(R10, -16, R0)
+        .call(KTIME_GET_NS)
+        .store(R10, -24, R0)
+        .update(&started, -8, -24, BPF_ANY)
+        .exit();
+    // `block_io_done(struct request *rq)`, or `block_rq_complete(struct request *rq, ...)`
+    // before Linux 6.5.
+    let mut done = Asm::default();
+    done.load(R6, R1, 0)
+        .store(R10, -8, R6)
+        .lookup(&started, -8)
+        .jeq_imm(R0, 0, "done")
+        .load(R9, R0, 0)
+        .load(R1, R0, 8)
+        .store32(R10, -16, R1)
+        .call(KTIME_GET_NS)
+        .sub(R0, R9)
+        .mov(R9, R0)
+        .delete(&started, -8)
+        .add_latency(stats, -16, "done")
+        .label("done")
+        .exit();
+
+    let mut attached = match load_and_attach(&start, &[c"block_io_start"]) {
+        Ok(attached) => attached,
+        Err(_) => load_and_attach(&start, &[c"block_rq_issue"])?,
+    };
+    match load_and_attach(&done, &[c"block_io_done"]) {
+        Ok(done) => attached.extend(done),
+        Err(_) => attached.extend(load_and_attach(&done, &[c"block_rq_complete"])?),
+    }
+    Ok(attached)
+}
+
+/// Counts the TCP retransmits.
+fn tcp_retransmits(counts: &Map) -> io::Result<Vec<Attached>> {
+    // Socket pointer => TGID of its owner.
+    let owners = Map::new(8, 8, 64 * 1024)?;
+
+    let mut set_owner = Asm::default();
+    set_owner
+        .load(R6, R1, 0)
+        .store(R10, -8, R6)
+        .call(GET_CURRENT_PID_TGID)
+        .rsh_imm(R0, 32)
+        .store(R10, -16, R0)
+        .update(&owners, -8, -16, BPF_ANY)
+        .exit();
+    // `inet_sock_set_state(const struct sock *sk, const int oldstate, const int newstate)`:
+    // only `connect` runs in the context of the process owning the socket.
+    let mut set_state = Asm::default();
+    set_state
+        .load(R2, R1, 16)
+        .jeq_imm(R2, TCP_SYN_SENT, "connect")
+        .exit()
+        .label("connect");
+    set_state.insns.extend(set_owner.finish());
+    // `tcp_retransmit_skb(const struct sock *sk, const struct sk_buff *skb, ...)`.
+    let mut retransmit = Asm::default();
+    retransmit
+        .load(R6, R1, 0)
+        .store(R10, -8, R6)
+        .lookup(&owners, -8)
+        .jeq_imm(R0, 0, "done")
+        .load(R1, R0, 0)
+        .store32(R10, -16, R1)
+        .lookup(counts, -16)
+        .jne_imm(R0, 0, "add")
+        .store_imm(R10, -24, 0)
+        .update(counts, -16, -24, BPF_NOEXIST)
+        .lookup(counts, -16)
+        .jeq_imm(R0, 0, "done")
+        .label("add")
+        .mov_imm(R1, 1)
+        .atomic_add(R0, 0, R1)
+        .label("done")
+        .exit();
+
+    let mut attached = load_and_attach(&set_state, &[c"inet_sock_set_state"])?;
+    // `sock_send_length(struct sock *sk, int ret, int flags)` only exists since Linux 6.3.
+    if let Ok(send) = load_and_attach(&set_owner, &[c"sock_send_length"]) {
+        attached.extend(send);
+    }
+    attached.extend(load_and_attach(&retransmit, &[c"tcp_retransmit_skb"])?);
+    Ok(attached)
+}
+
+pub(crate) struct EbpfCollectorsInner {
+    io_latency: Option<Map>,
+    tcp_retransmits: Option<Map>,
+    run_queue_latency: Map,
+    _attached: Vec<Attached>,
+}
+
+impl EbpfCollectorsInner {
+    pub(crate) fn start() -> io::Result<Self> {
+        let run_queue_latency = Map::new(4, mem::size_of::<RawLatency>() as _, 16 * 1024)?;
+        let mut attached = self::run_queue_latency(&run_queue_latency)?;
+
+        let io_latency = Map::new(4, mem::size_of::<RawLatency>() as _, 16 * 1024)
+            .and_then(|map| Ok((self::io_latency(&map)?, map)))
+            .map(|(io_attached, map)| {
+                attached.extend(io_attached);
+                map
+            })
+            .inspect_err(|_error| sysinfo_debug!("cannot collect I/O latency: {_error:?}"))
+            .ok();
+        let tcp_retransmits = Map::new(4, 8, 16 * 1024)
+            .and_then(|map| Ok((self::tcp_retransmits(&map)?, map)))
+            .map(|(tcp_attached, map)| {
+                attached.extend(tcp_attached);
+                map
+            })
+            .inspect_err(|_error| sysinfo_debug!("cannot collect TCP retransmits: {_error:?}"))
+            .ok();
+
+        Ok(Self {
+            io_latency,
+            tcp_retransmits,
+            run_queue_latency,
+            _attached: attached,
+        })
+    }
+
+    pub(crate) fn refresh(
+        &mut self,
+        io_latency: &mut HashMap<Pid, LatencyStats>,
+        tcp_retransmits: &mut HashMap<Pid, u64>,
+        run_queue_latency: &mut HashMap<Pid, LatencyStats>,
+    ) {
+        fn latencies(map: &Map) -> HashMap<Pid, LatencyStats> {
+            map.entries::<RawLatency>()
+                .into_iter()
+                .map(|(tgid, raw)| (Pid::from_u32(tgid), raw.into()))
+                .collect()
+        }
+
+        if let Some(map) = &self.io_latency {
+            *io_latency = latencies(map);
+        }
+        if let Some(map) = &self.tcp_retransmits {
+            *tcp_retransmits = map
+                .entries::<u64>()
+                .into_iter()
+                .map(|(tgid, count)| (Pid::from_u32(tgid), count))
+                .collect();
+        }
+        *run_queue_latency = latencies(&self.run_queue_latency);
+    }
+}
@@ -0,0 +1,437 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::Pid;
+use crate::common::exited_process::ExitedProcess;
+use crate::parsing::{
+    ProcExitEvent, ProcIndex, TaskExitStats, parse_genl_family_id, parse_netlink_ack,
+    parse_proc_connector_exits, parse_stat_file, parse_taskstats_exits,
+};
+use crate::sys::SystemInner;
+
+use std::collections::HashMap;
+use std::ffi::{OsStr, OsString};
+use std::fs;
+use std::io;
+use std::mem;
+use std::os::fd::{AsRawFd, FromRawFd, OwnedFd};
+use std::os::unix::ffi::{OsStrExt, OsStringExt};
+use std::os::unix::process::ExitStatusExt;
+use std::process::ExitStatus;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+// From `linux/connector.h` and `linux/cn_proc.h`.
+const CN_IDX_PROC: u32 = 1;
+const CN_VAL_PROC: u32 = 1;
+const PROC_CN_MCAST_LISTEN: u32 = 1;
+
+// From `linux/netlink.h`, `linux/genetlink.h` and `linux/taskstats.h`.
+const NLM_F_REQUEST: u16 = 1;
+const NLM_F_ACK: u16 = 4;
+const GENL_ID_CTRL: u16 = 0x10;
+const CTRL_CMD_GETFAMILY: u8 = 3;
+const CTRL_ATTR_FAMILY_NAME: u16 = 2;
+const TASKSTATS_CMD_GET: u8 = 1;
+const TASKSTATS_CMD_ATTR_REGISTER_CPUMASK: u16 = 3;
+
+/// Maximum number of exited processes kept between two refreshes.
+const MAX_PENDING_EXITS: usize = 100_000;
+
+#[derive(Default)]
+struct Pending {
+    processes: Vec<ExitedProcess>,
+    missed_exits: bool,
+}
+
+pub(crate) struct ExitedProcessesInner {
+    pending: Arc<Mutex<Pending>>,
+    stop: Arc<AtomicBool>,
+}
+
+impl ExitedProcessesInner {
+    pub(crate) fn start() -> io::Result<Self> {
+        let socket = open_socket()?;
+        subscribe(&socket)?;
+        // The information of the processes is read from their `/proc/[pid]/stat` file when they
+        // exit, but it's usually already gone for the short-lived ones. Taskstats sends this
+        // information with the exit so it's used for them (if available).
+        let taskstats = match open_taskstats_socket() {
+            Ok(taskstats) => Some(taskstats),
+            Err(_error) => {
+                sysinfo_debug!("failed to listen to taskstats: {_error:?}");
+                None
+            }
+        };
+
+        let pending = Arc::new(Mutex::new(Pending::default()));
+        let stop = Arc::new(AtomicBool::new(false));
+        let thread_pending = Arc::clone(&pending);
+        let thread_stop = Arc::clone(&stop);
+        std::thread::Builder::new()
+            .name("sysinfo-exited-processes".to_owned())
+            .spawn(move || listen(socket, taskstats, &thread_pending, &thread_stop))?;
+        Ok(Self { pending, stop })
+    }
+
+    pub(crate) fn take(&mut self, processes: &mut Vec<ExitedProcess>) -> bool {
+        let mut pending = self
+            .pending
+            .lock()
+            .unwrap_or_else(|error| error.into_inner());
+        *processes = mem::take(&mut pending.processes);
+        mem::replace(&mut pending.missed_exits, false)
+    }
+}
+
+impl Drop for ExitedProcessesInner {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+    }
+}
+
+fn setsockopt<T>(socket: &OwnedFd, option: libc::c_int, value: &T) -> io::Result<()> {
+    if unsafe {
+        libc::setsockopt(
+            socket.as_raw_fd(),
+            libc::SOL_SOCKET,
+            option,
+            (value as *const T).cast(),
+            mem::size_of::<T>() as _,
+        )
+    } != 0
+    {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(())
+}
+
+fn open_socket() -> io::Result<OwnedFd> {
+    let fd = unsafe {
+        libc::socket(
+            libc::AF_NETLINK,
+            libc::SOCK_DGRAM | libc::SOCK_CLOEXEC,
+            libc::NETLINK_CONNECTOR,
+        )
+    };
+    if fd < 0 {
+        return Err(io::Error::last_os_error());
+    }
+    let socket = unsafe { OwnedFd::from_raw_fd(fd) };
+
+    let mut addr: libc::sockaddr_nl = unsafe { mem::zeroed() };
+    addr.nl_family = libc::AF_NETLINK as _;
+    addr.nl_groups = CN_IDX_PROC;
+    if unsafe {
+        libc::bind(
+            socket.as_raw_fd(),
+            (&addr as *const libc::sockaddr_nl).cast(),
+            mem::size_of::<libc::sockaddr_nl>() as _,
+        )
+    } != 0
+    {
+        return Err(io::Error::last_os_error());
+    }
+    // So the listening thread can regularly check if it needs to stop.
+    setsockopt(
+        &socket,
+        libc::SO_RCVTIMEO,
+        &libc::timeval {
+            tv_sec: 0,
+            tv_usec: 200_000,
+        },
+    )?;
+    // Bursts of exits (like a fork bomb) quickly fill the default buffer. Not being able to
+    // increase it isn't a problem in itself though.
+    let _ = setsockopt(&socket, libc::SO_RCVBUF, &(4 * 1024 * 1024 as libc::c_int));
+    Ok(socket)
+}
+
+fn subscribe(socket: &OwnedFd) -> io::Result<()> {
+    let mut message = Vec::with_capacity(40);
+    // `struct nlmsghdr`: length, type (`NLMSG_DONE`), flags, sequence number and port ID.
+    message.extend(40u32.to_ne_bytes());
+    message.extend((libc::NLMSG_DONE as u16).to_ne_bytes());
+    message.extend(0u16.to_ne_bytes());
+    message.extend(0u32.to_ne_bytes());
+    message.extend(std::process::id().to_ne_bytes());
+    // `struct cn_msg`: index, value, sequence number, ack, length and flags.
+    message.extend(CN_IDX_PROC.to_ne_bytes());
+    message.extend(CN_VAL_PROC.to_ne_bytes());
+    message.extend(0u32.to_ne_bytes());
+    message.extend(0u32.to_ne_bytes());
+    message.extend(4u16.to_ne_bytes());
+    message.extend(0u16.to_ne_bytes());
+    // `enum proc_cn_mcast_op`.
+    message.extend(PROC_CN_MCAST_LISTEN.to_ne_bytes());
+
+    if unsafe {
+        libc::send(
+            socket.as_raw_fd(),
+            message.as_ptr().cast(),
+            message.len(),
+            0,
+        )
+    } < 0
+    {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(())
+}
+
+/// Builds a generic netlink request with a single string attribute.
+fn genl_request(family: u16, flags: u16, command: u8, attribute: u16, value: &str) -> Vec<u8> {
+    // `struct nlattr` header, the value and its trailing nul byte, aligned on 4 bytes.
+    let attribute_len = 4 + value.len() + 1;
+    let len = 16 + 4 + attribute_len.next_multiple_of(4);
+    let mut message = Vec::with_capacity(len);
+    // `struct nlmsghdr`: length, type, flags, sequence number and port ID (set by the kernel).
+    message.extend((len as u32).to_ne_bytes());
+    message.extend(family.to_ne_bytes());
+    message.extend((NLM_F_REQUEST | flags).to_ne_bytes());
+    message.extend(0u32.to_ne_bytes());
+    message.extend(0u32.to_ne_bytes());
+    // `struct genlmsghdr`: command, version and reserved.
+    message.extend([command, 1, 0, 0]);
+    message.extend((attribute_len as u16).to_ne_bytes());
+    message.extend(attribute.to_ne_bytes());
+    message.extend(value.as_bytes());
+    message.resize(len, 0);
+    message
+}
+
+/// Sends `request` on `socket` and returns the answer.
+fn genl_send(socket: &OwnedFd, request: &[u8], buffer: &mut [u8]) -> io::Result<usize> {
+    if unsafe {
+        libc::send(
+            socket.as_raw_fd(),
+            request.as_ptr().cast(),
+            request.len(),
+            0,
+        )
+    } < 0
+    {
+        return Err(io::Error::last_os_error());
+    }
+    let len = unsafe {
+        libc::recv(
+            socket.as_raw_fd(),
+            buffer.as_mut_ptr().cast(),
+            buffer.len(),
+            0,
+        )
+    };
+    if len < 0 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(len as usize)
+}
+
+/// Opens a generic netlink socket receiving the statistics of all the tasks when they exit.
+fn open_taskstats_socket() -> io::Result<OwnedFd> {
+    let fd = unsafe {
+        libc::socket(
+            libc::AF_NETLINK,
+            libc::SOCK_RAW | libc::SOCK_CLOEXEC,
+            libc::NETLINK_GENERIC,
+        )
+    };
+    if fd < 0 {
+        return Err(io::Error::last_os_error());
+    }
+    let socket = unsafe { OwnedFd::from_raw_fd(fd) };
+    // So we don't wait forever if the kernel doesn't answer.
+    setsockopt(
+        &socket,
+        libc::SO_RCVTIMEO,
+        &libc::timeval {
+            tv_sec: 1,
+            tv_usec: 0,
+        },
+    )?;
+    let _ = setsockopt(&socket, libc::SO_RCVBUF, &(4 * 1024 * 1024 as libc::c_int));
+
+    let mut buffer = [0u8; 1024];
+    let request = genl_request(
+        GENL_ID_CTRL,
+        0,
+        CTRL_CMD_GETFAMILY,
+        CTRL_ATTR_FAMILY_NAME,
+        "TASKSTATS",
+    );
+    let len = genl_send(&socket, &request, &mut buffer)?;
+    let family = parse_genl_family_id(&buffer[..len])
+        .ok_or_else(|| io::Error::new(io::ErrorKind::Unsupported, "taskstats not available"))?;
+
+    // Every CPU which can be brought online needs to be listened to.
+    let cpus = fs::read_to_string("/sys/devices/system/cpu/possible")?;
+    let request = genl_request(
+        family,
+        NLM_F_ACK,
+        TASKSTATS_CMD_GET,
+        TASKSTATS_CMD_ATTR_REGISTER_CPUMASK,
+        cpus.trim(),
+    );
+    let len = genl_send(&socket, &request, &mut buffer)?;
+    match parse_netlink_ack(&buffer[..len]) {
+        Some(0) => Ok(socket),
+        Some(error) => Err(io::Error::from_raw_os_error(-error)),
+        None => Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "invalid taskstats answer",
+        )),
+    }
+}
+
+/// Reads the statistics received on the taskstats socket without waiting.
+fn receive_task_exits(
+    socket: &OwnedFd,
+    buffer: &mut [u8],
+    exits: &mut HashMap<u32, TaskExitStats>,
+) {
+    loop {
+        let len = unsafe {
+            libc::recv(
+                socket.as_raw_fd(),
+                buffer.as_mut_ptr().cast(),
+                buffer.len(),
+                libc::MSG_DONTWAIT,
+            )
+        };
+        if len < 0 {
+            match io::Error::last_os_error().raw_os_error() {
+                Some(libc::EINTR) => continue,
+                Some(libc::EAGAIN) => {}
+                // The kernel dropped some statistics because the buffer was full. The
+                // `/proc/[pid]/stat` files will be used instead for the missed ones.
+                Some(libc::ENOBUFS) => continue,
+                _error => sysinfo_debug!("failed to receive taskstats: {_error:?}"),
+            }
+            return;
+        }
+        // The statistics of the tasks whose exit event was missed are never removed.
+        if exits.len() >= MAX_PENDING_EXITS {
+            exits.clear();
+        }
+        exits.extend(
+            parse_taskstats_exits(&buffer[..len as usize])
+                .into_iter()
+                .map(|exit| (exit.pid, exit)),
+        );
+    }
+}
+
+fn listen(
+    socket: OwnedFd,
+    taskstats: Option<OwnedFd>,
+    pending: &Mutex<Pending>,
+    stop: &AtomicBool,
+) {
+    let boot_time = SystemInner::boot_time();
+    let clock_ticks = SystemInner::clock_ticks_per_second().unwrap_or(100);
+    let mut buffer = vec![0u8; 64 * 1024];
+    let mut task_exits = HashMap::new();
+
+    while !stop.load(Ordering::Relaxed) {
+        let len = unsafe {
+            libc::recv(
+                socket.as_raw_fd(),
+                buffer.as_mut_ptr().cast(),
+                buffer.len(),
+                0,
+            )
+        };
+        if len < 0 {
+            match io::Error::last_os_error().raw_os_error() {
+                Some(libc::EAGAIN | libc::EINTR) => {}
+                // The kernel dropped some events because the buffer was full.
+                Some(libc::ENOBUFS) => {
+                    if let Ok(mut pending) = pending.lock() {
+                        pending.missed_exits = true;
+                    }
+                }
+                _error => {
+                    sysinfo_debug!("failed to receive process events: {_error:?}");
+                    return;
+                }
+            }
+            continue;
+        }
+        let events = parse_proc_connector_exits(&buffer[..len as usize]);
+        // The taskstats are sent before the exit event, so they're always received by now.
+        if let Some(ref taskstats) = taskstats {
+            receive_task_exits(taskstats, &mut buffer, &mut task_exits);
+        }
+        let exited = events
+            .into_iter()
+            .filter_map(|event| {
+                let task_exit = task_exits.remove(&event.pid);
+                // We're only interested in processes, not in threads.
+                (event.pid == event.tgid)
+                    .then(|| exited_process(event, task_exit, boot_time, clock_ticks))
+            })
+            .collect::<Vec<_>>();
+        if exited.is_empty() {
+            continue;
+        }
+        let Ok(mut pending) = pending.lock() else {
+            return;
+        };
+        let available = MAX_PENDING_EXITS.saturating_sub(pending.processes.len());
+        if exited.len() > available {
+            pending.missed_exits = true;
+        }
+        pending.processes.extend(exited.into_iter().take(available));
+    }
+}
+
+fn exited_process(
+    event: ProcExitEvent,
+    task_exit: Option<TaskExitStats>,
+    boot_time: u64,
+    clock_ticks: u64,
+) -> ExitedProcess {
+    let mut process = ExitedProcess {
+        pid: Pid::from_u32(event.pid),
+        parent: (event.parent_tgid != 0).then(|| Pid::from_u32(event.parent_tgid)),
+        name: OsString::new(),
+        exit_status: Some(ExitStatus::from_raw(event.exit_code as _)),
+        start_time: 0,
+        run_time: 0,
+        accumulated_cpu_time: 0,
+    };
+    if let Some(task_exit) = task_exit {
+        process.name = OsString::from_vec(task_exit.name);
+        process.start_time = task_exit.start_time;
+        process.run_time = task_exit.run_time / 1_000_000;
+        process.accumulated_cpu_time = task_exit.cpu_time / 1_000;
+    }
+    // The event is sent while the process is exiting, so its information is still available
+    // unless it has already been reaped. Taskstats only has the CPU time of the main thread, so
+    // the `stat` file is still preferred when available.
+    let Ok(data) = fs::read(format!("/proc/{}/stat", event.pid)) else {
+        return process;
+    };
+    let Some(parts) = parse_stat_file(&data) else {
+        return process;
+    };
+    // If the process was already reaped, the PID might have been reused by another process.
+    if !matches!(parts.str_parts[ProcIndex::State as usize], "Z" | "X") {
+        return process;
+    }
+    let field = |index: ProcIndex| u64::from_str(parts.str_parts[index as usize]).unwrap_or(0);
+
+    process.name = OsStr::from_bytes(parts.short_exe).to_owned();
+    process.accumulated_cpu_time = field(ProcIndex::UserTime)
+        .saturating_add(field(ProcIndex::SystemTime))
+        .saturating_mul(1_000)
+        / clock_ticks;
+    process.start_time = boot_time.saturating_add(field(ProcIndex::StartTime) / clock_ticks);
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    process.run_time = now.saturating_sub(process.start_time);
+    process
+}
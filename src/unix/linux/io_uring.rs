@@ -0,0 +1,335 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+// Minimal io_uring used to read many small files with a single system call. The rings are set up
+// with the `io_uring_setup` and `io_uring_enter` syscalls directly, so no library is needed.
+
+use std::io;
+use std::mem;
+use std::os::fd::{AsRawFd, FromRawFd, OwnedFd, RawFd};
+use std::ptr;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+const IORING_OFF_SQ_RING: libc::off_t = 0;
+const IORING_OFF_SQES: libc::off_t = 0x1000_0000;
+const IORING_FEAT_SINGLE_MMAP: u32 = 1;
+const IORING_ENTER_GETEVENTS: libc::c_uint = 1;
+const IORING_OP_READ: u8 = 22;
+
+#[repr(C)]
+#[derive(Default)]
+struct SqringOffsets {
+    head: u32,
+    tail: u32,
+    ring_mask: u32,
+    ring_entries: u32,
+    flags: u32,
+    dropped: u32,
+    array: u32,
+    resv1: u32,
+    user_addr: u64,
+}
+
+#[repr(C)]
+#[derive(Default)]
+struct CqringOffsets {
+    head: u32,
+    tail: u32,
+    ring_mask: u32,
+    ring_entries: u32,
+    overflow: u32,
+    cqes: u32,
+    flags: u32,
+    resv1: u32,
+    user_addr: u64,
+}
+
+#[repr(C)]
+#[derive(Default)]
+struct Params {
+    sq_entries: u32,
+    cq_entries: u32,
+    flags: u32,
+    sq_thread_cpu: u32,
+    sq_thread_idle: u32,
+    features: u32,
+    wq_fd: u32,
+    resv: [u32; 3],
+    sq_off: SqringOffsets,
+    cq_off: CqringOffsets,
+}
+
+#[repr(C)]
+#[derive(Default)]
+struct Sqe {
+    opcode: u8,
+    flags: u8,
+    ioprio: u16,
+    fd: i32,
+    off: u64,
+    addr: u64,
+    len: u32,
+    rw_flags: u32,
+    user_data: u64,
+    pad: [u64; 3],
+}
+
+#[repr(C)]
+struct Cqe {
+    user_data: u64,
+    res: i32,
+    flags: u32,
+}
+
+/// Memory shared with the kernel, unmapped when dropped.
+struct Mmap {
+    ptr: *mut libc::c_void,
+    len: usize,
+}
+
+impl Mmap {
+    fn new(fd: RawFd, len: usize, offset: libc::off_t) -> io::Result<Self> {
+        let ptr = unsafe {
+            libc::mmap(
+                ptr::null_mut(),
+                len,
+                libc::PROT_READ | libc::PROT_WRITE,
+                libc::MAP_SHARED | libc::MAP_POPULATE,
+                fd,
+                offset,
+            )
+        };
+        if ptr == libc::MAP_FAILED {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(Self { ptr, len })
+    }
+
+    /// Returns a pointer to the `T` at `offset` bytes from the start of the mapping.
+    fn at<T>(&self, offset: u32) -> *mut T {
+        unsafe { self.ptr.cast::<u8>().add(offset as usize).cast() }
+    }
+}
+
+impl Drop for Mmap {
+    fn drop(&mut self) {
+        unsafe {
+            libc::munmap(self.ptr, self.len);
+        }
+    }
+}
+
+pub(crate) struct IoUring {
+    // The mappings must be dropped before the file descriptor is closed.
+    ring: Mmap,
+    sqes: Mmap,
+    fd: OwnedFd,
+    sq_off: SqringOffsets,
+    cq_off: CqringOffsets,
+    sq_entries: u32,
+}
+
+// The rings are only accessed through `&mut IoUring`.
+unsafe impl Send for IoUring {}
+unsafe impl Sync for IoUring {}
+
+impl IoUring {
+    /// Creates a ring able to run `entries` reads at once. It fails if io_uring is not available
+    /// (it requires Linux 5.6 and can be disabled with the `kernel.io_uring_disabled` sysctl or
+    /// by a seccomp filter).
+    pub(crate) fn new(entries: u32) -> io::Result<Self> {
+        let mut params = Params::default();
+        let fd = unsafe {
+            libc::syscall(
+                libc::SYS_io_uring_setup,
+                entries,
+                &mut params as *mut Params,
+            )
+        };
+        if fd < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        let fd = unsafe { OwnedFd::from_raw_fd(fd as _) };
+        // Kernels without a single mapping for both rings are too old to support
+        // `IORING_OP_READ` anyway.
+        if params.features & IORING_FEAT_SINGLE_MMAP == 0 {
+            return Err(io::ErrorKind::Unsupported.into());
+        }
+        let sq_len = params.sq_off.array as usize + params.sq_entries as usize * 4;
+        let cq_len =
+            params.cq_off.cqes as usize + params.cq_entries as usize * mem::size_of::<Cqe>();
+        let ring = Mmap::new(fd.as_raw_fd(), sq_len.max(cq_len), IORING_OFF_SQ_RING)?;
+        let sqes = Mmap::new(
+            fd.as_raw_fd(),
+            params.sq_entries as usize * mem::size_of::<Sqe>(),
+            IORING_OFF_SQES,
+        )?;
+        Ok(Self {
+            ring,
+            sqes,
+            fd,
+            sq_off: params.sq_off,
+            cq_off: params.cq_off,
+            sq_entries: params.sq_entries,
+        })
+    }
+
+    fn atomic(&self, offset: u32) -> &AtomicU32 {
+        unsafe { AtomicU32::from_ptr(self.ring.at(offset)) }
+    }
+
+    /// Reads the beginning of each file, from offset 0, into the spare capacity of the
+    /// corresponding buffer, which is cleared first. Returns, for each file, the number of read
+    /// bytes or the error.
+    ///
+    /// If the ring itself fails, the error is returned and the ring must not be used anymore.
+    pub(crate) fn read_files(
+        &mut self,
+        reads: &mut [(RawFd, &mut Vec<u8>)],
+    ) -> io::Result<Vec<io::Result<usize>>> {
+        let mut results = Vec::with_capacity(reads.len());
+        for chunk in reads.chunks_mut(self.sq_entries as usize) {
+            results.extend(self.read_chunk(chunk)?);
+        }
+        Ok(results)
+    }
+
+    fn read_chunk(
+        &mut self,
+        reads: &mut [(RawFd, &mut Vec<u8>)],
+    ) -> io::Result<Vec<io::Result<usize>>> {
+        let mask = unsafe { *self.ring.at::<u32>(self.sq_off.ring_mask) };
+        let array = self.ring.at::<u32>(self.sq_off.array);
+        let sqes = self.sqes.at::<Sqe>(0);
+        let first_head = self.atomic(self.sq_off.head).load(Ordering::Acquire);
+        let mut tail = self.atomic(self.sq_off.tail).load(Ordering::Relaxed);
+        for (index, (fd, buf)) in reads.iter_mut().enumerate() {
+            buf.clear();
+            let slot = tail & mask;
+            unsafe {
+                sqes.add(slot as usize).write(Sqe {
+                    opcode: IORING_OP_READ,
+                    fd: *fd,
+                    addr: buf.as_mut_ptr() as u64,
+                    len: buf.capacity() as u32,
+                    user_data: index as u64,
+                    ..Sqe::default()
+                });
+                array.add(slot as usize).write(slot);
+            }
+            tail = tail.wrapping_add(1);
+        }
+        self.atomic(self.sq_off.tail).store(tail, Ordering::Release);
+
+        let mut results = reads
+            .iter()
+            .map(|_| None)
+            .collect::<Vec<Option<io::Result<usize>>>>();
+        let mut remaining = reads.len();
+        let mut to_submit = reads.len() as libc::c_uint;
+        let mut failure = None;
+        while remaining > 0 {
+            let ret = unsafe {
+                libc::syscall(
+                    libc::SYS_io_uring_enter,
+                    self.fd.as_raw_fd(),
+                    to_submit,
+                    remaining as libc::c_uint,
+                    IORING_ENTER_GETEVENTS,
+                    ptr::null::<libc::sigset_t>(),
+                    0,
+                )
+            };
+            if ret < 0 {
+                let error = io::Error::last_os_error();
+                if matches!(
+                    error.raw_os_error(),
+                    Some(libc::EINTR | libc::EAGAIN | libc::EBUSY)
+                ) {
+                    continue;
+                }
+                if failure.is_some() {
+                    // The reads already submitted can't be waited for, so the kernel might
+                    // still write into their buffers: they're leaked instead of being freed.
+                    for ((_, buf), result) in reads.iter_mut().zip(&results) {
+                        if result.is_none() {
+                            mem::forget(mem::take(*buf));
+                        }
+                    }
+                    return Err(error);
+                }
+                // The reads the kernel didn't take are withdrawn and the ones it took are
+                // waited for, since they write into the buffers of `reads`.
+                let head = self.atomic(self.sq_off.head).load(Ordering::Acquire);
+                self.atomic(self.sq_off.tail).store(head, Ordering::Release);
+                let submitted = head.wrapping_sub(first_head) as usize;
+                remaining = submitted - (reads.len() - remaining);
+                to_submit = 0;
+                failure = Some(error);
+                continue;
+            }
+            to_submit = to_submit.saturating_sub(ret as _);
+
+            let cq_mask = unsafe { *self.ring.at::<u32>(self.cq_off.ring_mask) };
+            let cqes = self.ring.at::<Cqe>(self.cq_off.cqes);
+            let mut head = self.atomic(self.cq_off.head).load(Ordering::Relaxed);
+            let cq_tail = self.atomic(self.cq_off.tail).load(Ordering::Acquire);
+            while head != cq_tail {
+                let cqe = unsafe { cqes.add((head & cq_mask) as usize).read() };
+                head = head.wrapping_add(1);
+                let index = cqe.user_data as usize;
+                if let Some((_, buf)) = reads.get_mut(index) {
+                    results[index] = Some(if cqe.res < 0 {
+                        Err(io::Error::from_raw_os_error(-cqe.res))
+                    } else {
+                        let len = (cqe.res as usize).min(buf.capacity());
+                        unsafe { buf.set_len(len) };
+                        Ok(len)
+                    });
+                    remaining = remaining.saturating_sub(1);
+                }
+            }
+            self.atomic(self.cq_off.head).store(head, Ordering::Release);
+        }
+        match failure {
+            Some(error) => Err(error),
+            None => Ok(results
+                .into_iter()
+                .map(|result| result.unwrap_or_else(|| Err(io::ErrorKind::Interrupted.into())))
+                .collect()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::IoUring;
+    use std::fs::File;
+    use std::io::Write;
+    use std::os::fd::AsRawFd;
+
+    #[test]
+    fn test_read_files() {
+        // io_uring can be disabled, like in some containers.
+        let Ok(mut ring) = IoUring::new(2) else {
+            return;
+        };
+        let mut file = tempfile::tempfile().unwrap();
+        file.write_all(b"hello").unwrap();
+        let stat = File::open("/proc/self/stat").unwrap();
+        let mut bufs = (0..3)
+            .map(|_| Vec::with_capacity(1_024))
+            .collect::<Vec<_>>();
+        let [a, b, c] = &mut bufs[..] else {
+            unreachable!()
+        };
+        let mut reads = [(file.as_raw_fd(), a), (stat.as_raw_fd(), b), (-1, c)];
+        let results = ring.read_files(&mut reads).unwrap();
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].as_ref().ok(), Some(&5));
+        assert_eq!(reads[0].1.as_slice(), b"hello");
+        if results[1].is_ok() {
+            assert!(reads[1].1.ends_with(b"\n"));
+        }
+        assert!(results[2].is_err());
+    }
+}
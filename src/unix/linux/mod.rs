@@ -5,22 +5,41 @@ pub(crate) mod utils;
 cfg_if! {
     if #[cfg(feature = "system")] {
         pub mod cpu;
+        pub mod exited_process;
         pub mod motherboard;
         pub mod process;
         pub mod product;
+        pub mod security;
         pub mod system;
 
         pub(crate) use self::cpu::CpuInner;
+        pub(crate) use self::exited_process::ExitedProcessesInner;
         pub(crate) use self::motherboard::MotherboardInner;
         pub(crate) use self::process::ProcessInner;
         pub(crate) use self::product::ProductInner;
+        pub(crate) use self::security::SecurityInfoInner;
         pub(crate) use self::system::SystemInner;
         pub use self::system::{MINIMUM_CPU_UPDATE_INTERVAL, SUPPORTED_SIGNALS};
     }
+    if #[cfg(feature = "ebpf")] {
+        pub mod ebpf;
+
+        pub(crate) use self::ebpf::EbpfCollectorsInner;
+    }
+    if #[cfg(feature = "io-uring")] {
+        pub(crate) mod io_uring;
+    }
+    if #[cfg(feature = "apple-silicon")] {
+        pub(crate) use crate::unix::AppleSiliconMetricsInner;
+    }
     if #[cfg(feature = "disk")] {
         pub mod disk;
+        pub mod disk_watcher;
+        pub mod storage;
 
         pub(crate) use self::disk::DiskInner;
+        pub(crate) use self::disk_watcher::DiskWatcherInner;
+        pub(crate) use self::storage::StorageInner;
         pub(crate) use crate::unix::DisksInner;
     }
 
@@ -34,12 +53,37 @@ cfg_if! {
         pub mod network;
 
         pub(crate) use self::network::{NetworkDataInner, NetworksInner};
+        pub(crate) use crate::unix::NetworkWatcherInner;
+    }
+
+    if #[cfg(feature = "audio")] {
+        pub mod audio;
+
+        pub(crate) use self::audio::get_audio_devices;
+    }
+
+    if #[cfg(feature = "display")] {
+        pub mod display;
+
+        pub(crate) use self::display::get_displays;
+    }
+
+    if #[cfg(feature = "usb")] {
+        pub mod usb;
+
+        pub(crate) use self::usb::get_usb_devices;
     }
 
     if #[cfg(feature = "user")] {
         pub(crate) use crate::unix::groups::get_groups;
         pub(crate) use crate::unix::users::{get_users, UserInner};
     }
+
+    if #[cfg(feature = "zfs")] {
+        pub mod zfs;
+
+        pub(crate) use self::zfs::{get_zfs_arc, get_zfs_pools};
+    }
 }
 
 #[doc = include_str!("../../../md_doc/is_supported.md")]
@@ -47,12 +91,24 @@ pub const IS_SUPPORTED_SYSTEM: bool = true;
 
 // Make formattable by rustfmt.
 #[cfg(any())]
+mod audio;
+#[cfg(any())]
 mod component;
 #[cfg(any())]
 mod cpu;
 #[cfg(any())]
 mod disk;
 #[cfg(any())]
+mod disk_watcher;
+#[cfg(any())]
+mod display;
+#[cfg(any())]
+mod ebpf;
+#[cfg(any())]
+mod exited_process;
+#[cfg(any())]
+mod io_uring;
+#[cfg(any())]
 mod motherboard;
 #[cfg(any())]
 mod network;
@@ -61,4 +117,12 @@ mod process;
 #[cfg(any())]
 mod product;
 #[cfg(any())]
+mod security;
+#[cfg(any())]
+mod storage;
+#[cfg(any())]
 mod system;
+#[cfg(any())]
+mod usb;
+#[cfg(any())]
+mod zfs;
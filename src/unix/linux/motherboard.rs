@@ -1,5 +1,7 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
+use crate::parsing::parse_devicetree_compatible;
+
 use std::fs::{read, read_to_string};
 
 pub(crate) struct MotherboardInner;
@@ -47,16 +49,8 @@ impl MotherboardInner {
 }
 
 // Parses the first entry of the file `/proc/device-tree/compatible`, to extract the vendor and
-// motherboard name. This file contains several `\0` separated strings; the first one include the
-// vendor and the motherboard name, separated by a comma.
-//
-// According to the specification: https://github.com/devicetree-org/devicetree-specification
-// a compatible string must contain only one comma.
+// motherboard name.
 fn parse_device_tree_compatible() -> Option<(String, String)> {
     let bytes = read("/proc/device-tree/compatible").ok()?;
-    let first_line = bytes.split(|&b| b == 0).next()?;
-    std::str::from_utf8(first_line)
-        .ok()?
-        .split_once(',')
-        .map(|(a, b)| (a.to_owned(), b.to_owned()))
+    parse_devicetree_compatible(&bytes).map(|(a, b)| (a.to_owned(), b.to_owned()))
 }
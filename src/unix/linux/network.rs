@@ -3,10 +3,12 @@
 use std::collections::{HashMap, hash_map};
 use std::fs::File;
 use std::io::Read;
+use std::net::{IpAddr, Ipv6Addr};
 use std::path::Path;
 
 use crate::network::refresh_networks_addresses;
-use crate::{IpNetwork, MacAddr, NetworkData};
+use crate::unix::utils::parse_resolv_conf;
+use crate::{DnsConfiguration, IpNetwork, MacAddr, NetworkData, RefreshError};
 
 macro_rules! old_and_new {
     ($ty_:expr, $name:ident, $old:ident) => {{
@@ -42,77 +44,104 @@ fn refresh_networks_list_from_sysfs(
     interfaces: &mut HashMap<String, NetworkData>,
     remove_not_listed_interfaces: bool,
     sysfs_net: &Path,
-) {
-    if let Ok(dir) = std::fs::read_dir(sysfs_net) {
-        let mut data = vec![0; 30];
+) -> std::io::Result<()> {
+    let dir = std::fs::read_dir(sysfs_net)?;
+    let mut data = vec![0; 30];
 
-        for stats in interfaces.values_mut() {
-            stats.inner.updated = false;
-        }
+    for stats in interfaces.values_mut() {
+        stats.inner.updated = false;
+    }
 
-        for entry in dir.flatten() {
-            let parent = &entry.path().join("statistics");
-            let entry_path = &entry.path();
-            let entry = match entry.file_name().into_string() {
-                Ok(entry) => entry,
-                Err(_) => continue,
-            };
-            let rx_bytes = read(parent, "rx_bytes", &mut data);
-            let tx_bytes = read(parent, "tx_bytes", &mut data);
-            let rx_packets = read(parent, "rx_packets", &mut data);
-            let tx_packets = read(parent, "tx_packets", &mut data);
-            let rx_errors = read(parent, "rx_errors", &mut data);
-            let tx_errors = read(parent, "tx_errors", &mut data);
-            // let rx_compressed = read(parent, "rx_compressed", &mut data);
-            // let tx_compressed = read(parent, "tx_compressed", &mut data);
-            let mtu = read(entry_path, "mtu", &mut data);
-
-            match interfaces.entry(entry) {
-                hash_map::Entry::Occupied(mut e) => {
-                    let interface = e.get_mut();
-                    let interface = &mut interface.inner;
-
-                    old_and_new!(interface, rx_bytes, old_rx_bytes);
-                    old_and_new!(interface, tx_bytes, old_tx_bytes);
-                    old_and_new!(interface, rx_packets, old_rx_packets);
-                    old_and_new!(interface, tx_packets, old_tx_packets);
-                    old_and_new!(interface, rx_errors, old_rx_errors);
-                    old_and_new!(interface, tx_errors, old_tx_errors);
-                    // old_and_new!(e, rx_compressed, old_rx_compressed);
-                    // old_and_new!(e, tx_compressed, old_tx_compressed);
-                    if interface.mtu != mtu {
-                        interface.mtu = mtu;
-                    }
-                    interface.updated = true;
+    for entry in dir.flatten() {
+        let parent = &entry.path().join("statistics");
+        let entry_path = &entry.path();
+        let entry = match entry.file_name().into_string() {
+            Ok(entry) => entry,
+            Err(_) => continue,
+        };
+        let rx_bytes = read(parent, "rx_bytes", &mut data);
+        let tx_bytes = read(parent, "tx_bytes", &mut data);
+        let rx_packets = read(parent, "rx_packets", &mut data);
+        let tx_packets = read(parent, "tx_packets", &mut data);
+        let rx_errors = read(parent, "rx_errors", &mut data);
+        let tx_errors = read(parent, "tx_errors", &mut data);
+        let rx_dropped = read(parent, "rx_dropped", &mut data);
+        let tx_dropped = read(parent, "tx_dropped", &mut data);
+        let rx_fifo_errors = read(parent, "rx_fifo_errors", &mut data);
+        let tx_fifo_errors = read(parent, "tx_fifo_errors", &mut data);
+        let collisions = read(parent, "collisions", &mut data);
+        let tx_carrier_errors = read(parent, "tx_carrier_errors", &mut data);
+        let multicast = read(parent, "multicast", &mut data);
+        // let rx_compressed = read(parent, "rx_compressed", &mut data);
+        // let tx_compressed = read(parent, "tx_compressed", &mut data);
+        let mtu = read(entry_path, "mtu", &mut data);
+
+        match interfaces.entry(entry) {
+            hash_map::Entry::Occupied(mut e) => {
+                let interface = e.get_mut();
+                let interface = &mut interface.inner;
+
+                old_and_new!(interface, rx_bytes, old_rx_bytes);
+                old_and_new!(interface, tx_bytes, old_tx_bytes);
+                old_and_new!(interface, rx_packets, old_rx_packets);
+                old_and_new!(interface, tx_packets, old_tx_packets);
+                old_and_new!(interface, rx_errors, old_rx_errors);
+                old_and_new!(interface, tx_errors, old_tx_errors);
+                old_and_new!(interface, rx_dropped, old_rx_dropped);
+                old_and_new!(interface, tx_dropped, old_tx_dropped);
+                old_and_new!(interface, rx_fifo_errors, old_rx_fifo_errors);
+                old_and_new!(interface, tx_fifo_errors, old_tx_fifo_errors);
+                old_and_new!(interface, collisions, old_collisions);
+                old_and_new!(interface, tx_carrier_errors, old_tx_carrier_errors);
+                old_and_new!(interface, multicast, old_multicast);
+                // old_and_new!(e, rx_compressed, old_rx_compressed);
+                // old_and_new!(e, tx_compressed, old_tx_compressed);
+                if interface.mtu != mtu {
+                    interface.mtu = mtu;
                 }
-                hash_map::Entry::Vacant(e) => {
-                    e.insert(NetworkData {
-                        inner: NetworkDataInner {
-                            rx_bytes,
-                            old_rx_bytes: rx_bytes,
-                            tx_bytes,
-                            old_tx_bytes: tx_bytes,
-                            rx_packets,
-                            old_rx_packets: rx_packets,
-                            tx_packets,
-                            old_tx_packets: tx_packets,
-                            rx_errors,
-                            old_rx_errors: rx_errors,
-                            tx_errors,
-                            old_tx_errors: tx_errors,
-                            mac_addr: MacAddr::UNSPECIFIED,
-                            ip_networks: vec![],
-                            // rx_compressed,
-                            // old_rx_compressed: rx_compressed,
-                            // tx_compressed,
-                            // old_tx_compressed: tx_compressed,
-                            mtu,
-                            updated: true,
-                        },
-                    });
-                }
-            };
-        }
+                interface.updated = true;
+            }
+            hash_map::Entry::Vacant(e) => {
+                e.insert(NetworkData {
+                    inner: NetworkDataInner {
+                        rx_bytes,
+                        old_rx_bytes: rx_bytes,
+                        tx_bytes,
+                        old_tx_bytes: tx_bytes,
+                        rx_packets,
+                        old_rx_packets: rx_packets,
+                        tx_packets,
+                        old_tx_packets: tx_packets,
+                        rx_errors,
+                        old_rx_errors: rx_errors,
+                        tx_errors,
+                        old_tx_errors: tx_errors,
+                        rx_dropped,
+                        old_rx_dropped: rx_dropped,
+                        tx_dropped,
+                        old_tx_dropped: tx_dropped,
+                        rx_fifo_errors,
+                        old_rx_fifo_errors: rx_fifo_errors,
+                        tx_fifo_errors,
+                        old_tx_fifo_errors: tx_fifo_errors,
+                        collisions,
+                        old_collisions: collisions,
+                        tx_carrier_errors,
+                        old_tx_carrier_errors: tx_carrier_errors,
+                        multicast,
+                        old_multicast: multicast,
+                        mac_addr: MacAddr::UNSPECIFIED,
+                        ip_networks: vec![],
+                        // rx_compressed,
+                        // old_rx_compressed: rx_compressed,
+                        // tx_compressed,
+                        // old_tx_compressed: tx_compressed,
+                        mtu,
+                        updated: true,
+                    },
+                });
+            }
+        };
     }
     // We do this here because `refresh_networks_list_remove_interface` test is checking that
     // this is working as expected.
@@ -126,6 +155,66 @@ fn refresh_networks_list_from_sysfs(
             true
         });
     }
+    Ok(())
+}
+
+const RTF_UP_GATEWAY: u32 = (libc::RTF_UP | libc::RTF_GATEWAY) as u32;
+
+/// Returns the gateway and the interface of the IPv4 default route with the lowest metric from
+/// the content of `/proc/net/route`.
+fn parse_ipv4_default_route(data: &str) -> Option<(IpAddr, String)> {
+    data.lines()
+        .skip(1)
+        .filter_map(|line| {
+            let mut fields = line.split_whitespace();
+            let interface = fields.next()?;
+            let destination = fields.next()?;
+            let gateway = u32::from_str_radix(fields.next()?, 16).ok()?;
+            let flags = u32::from_str_radix(fields.next()?, 16).ok()?;
+            let metric = fields.nth(2)?.parse::<u32>().ok()?;
+            let mask = fields.next()?;
+            if destination != "00000000"
+                || mask != "00000000"
+                || flags & RTF_UP_GATEWAY != RTF_UP_GATEWAY
+            {
+                return None;
+            }
+            // The addresses are in network byte order.
+            Some((metric, IpAddr::from(gateway.to_ne_bytes()), interface))
+        })
+        .min_by_key(|(metric, _, _)| *metric)
+        .map(|(_, gateway, interface)| (gateway, interface.to_owned()))
+}
+
+/// Returns the gateway and the interface of the IPv6 default route with the lowest metric from
+/// the content of `/proc/net/ipv6_route`.
+fn parse_ipv6_default_route(data: &str) -> Option<(IpAddr, String)> {
+    data.lines()
+        .filter_map(|line| {
+            let fields = line.split_whitespace().collect::<Vec<_>>();
+            let [_, prefix_len, _, _, gateway, metric, _, _, flags, interface] = fields[..] else {
+                return None;
+            };
+            let gateway = u128::from_str_radix(gateway, 16).ok()?;
+            let flags = u32::from_str_radix(flags, 16).ok()?;
+            if prefix_len != "00" || gateway == 0 || flags & RTF_UP_GATEWAY != RTF_UP_GATEWAY {
+                return None;
+            }
+            let metric = u32::from_str_radix(metric, 16).ok()?;
+            Some((metric, IpAddr::from(Ipv6Addr::from(gateway)), interface))
+        })
+        .min_by_key(|(metric, _, _)| *metric)
+        .map(|(_, gateway, interface)| (gateway, interface.to_owned()))
+}
+
+fn get_default_route() -> Option<(IpAddr, String)> {
+    std::fs::read_to_string("/proc/net/route")
+        .ok()
+        .and_then(|data| parse_ipv4_default_route(&data))
+        .or_else(|| {
+            let data = std::fs::read_to_string("/proc/net/ipv6_route").ok()?;
+            parse_ipv6_default_route(&data)
+        })
 }
 
 pub(crate) struct NetworksInner {
@@ -143,13 +232,96 @@ impl NetworksInner {
         &self.interfaces
     }
 
-    pub(crate) fn refresh(&mut self, remove_not_listed_interfaces: bool) {
-        refresh_networks_list_from_sysfs(
+    pub(crate) fn try_refresh(
+        &mut self,
+        remove_not_listed_interfaces: bool,
+    ) -> Result<(), RefreshError> {
+        let path = Path::new("/sys/class/net/");
+        let ret = refresh_networks_list_from_sysfs(
             &mut self.interfaces,
             remove_not_listed_interfaces,
-            Path::new("/sys/class/net/"),
-        );
+            path,
+        )
+        .map_err(|e| RefreshError::from_io(path, e));
         refresh_networks_addresses(&mut self.interfaces);
+        ret
+    }
+
+    pub(crate) fn refresh(&mut self, remove_not_listed_interfaces: bool) {
+        if let Err(_e) = self.try_refresh(remove_not_listed_interfaces) {
+            sysinfo_debug!("failed to refresh network interfaces: {_e:?}");
+        }
+    }
+
+    pub(crate) fn dns_configuration() -> DnsConfiguration {
+        let config = std::fs::read_to_string("/etc/resolv.conf")
+            .map(|data| parse_resolv_conf(&data))
+            .unwrap_or_default();
+        // With `systemd-resolved`, `/etc/resolv.conf` usually only contains its local stub
+        // resolver so we use the upstream configuration instead.
+        if !config.nameservers.is_empty()
+            && config
+                .nameservers
+                .iter()
+                .all(|addr| *addr == IpAddr::from([127, 0, 0, 53]))
+            && let Ok(data) = std::fs::read_to_string("/run/systemd/resolve/resolv.conf")
+        {
+            return parse_resolv_conf(&data);
+        }
+        config
+    }
+
+    pub(crate) fn default_gateway() -> Option<IpAddr> {
+        get_default_route().map(|(gateway, _)| gateway)
+    }
+
+    pub(crate) fn primary_interface() -> Option<String> {
+        get_default_route().map(|(_, interface)| interface)
+    }
+
+    pub(crate) fn protocol_stats() -> Option<crate::ProtocolStats> {
+        let snmp = std::fs::read_to_string("/proc/net/snmp").ok()?;
+        let snmp = crate::parsing::parse_snmp(&snmp);
+        let snmp6 = std::fs::read_to_string("/proc/net/snmp6").unwrap_or_default();
+        let snmp6 = crate::parsing::parse_snmp6(&snmp6);
+        // The IPv6 counters have the same names, prefixed with the protocol (like
+        // `Ip6InHdrErrors`). TCP counters are shared between IPv4 and IPv6.
+        let get = |protocol: &str, counter: &str| {
+            let v4 = snmp
+                .iter()
+                .find(|(p, name, _)| *p == protocol && *name == counter)
+                .map(|(_, _, value)| *value)
+                .unwrap_or(0);
+            let v6 = snmp6
+                .iter()
+                .find(|(name, _)| {
+                    name.strip_prefix(protocol)
+                        .and_then(|name| name.strip_prefix('6'))
+                        == Some(counter)
+                })
+                .map(|(_, value)| *value)
+                .unwrap_or(0);
+            v4 + v6
+        };
+
+        Some(crate::ProtocolStats {
+            ip_header_errors: get("Ip", "InHdrErrors"),
+            ip_address_errors: get("Ip", "InAddrErrors"),
+            ip_discards: get("Ip", "InDiscards"),
+            ip_reassembly_failures: get("Ip", "ReasmFails"),
+            ip_fragment_failures: get("Ip", "FragFails"),
+            icmp_errors_received: get("Icmp", "InErrors"),
+            icmp_errors_sent: get("Icmp", "OutErrors"),
+            tcp_segments_received: get("Tcp", "InSegs"),
+            tcp_segments_sent: get("Tcp", "OutSegs"),
+            tcp_retransmitted_segments: get("Tcp", "RetransSegs"),
+            tcp_errors_received: get("Tcp", "InErrs"),
+            tcp_resets_sent: get("Tcp", "OutRsts"),
+            tcp_connection_resets: get("Tcp", "EstabResets"),
+            tcp_failed_connection_attempts: get("Tcp", "AttemptFails"),
+            udp_receive_errors: get("Udp", "InErrors"),
+            udp_no_ports: get("Udp", "NoPorts"),
+        })
     }
 }
 
@@ -174,6 +346,27 @@ pub(crate) struct NetworkDataInner {
     /// similar to `rx_errors`
     tx_errors: u64,
     old_tx_errors: u64,
+    /// Number of packets received but dropped by the kernel (like when running out of buffers).
+    rx_dropped: u64,
+    old_rx_dropped: u64,
+    /// Number of packets dropped on their way to transmission.
+    tx_dropped: u64,
+    old_tx_dropped: u64,
+    /// Number of receiver FIFO overruns.
+    rx_fifo_errors: u64,
+    old_rx_fifo_errors: u64,
+    /// Number of transmitter FIFO underruns.
+    tx_fifo_errors: u64,
+    old_tx_fifo_errors: u64,
+    /// Number of collisions during packet transmissions.
+    collisions: u64,
+    old_collisions: u64,
+    /// Number of frames transmission errors due to loss of carrier.
+    tx_carrier_errors: u64,
+    old_tx_carrier_errors: u64,
+    /// Number of multicast packets received.
+    multicast: u64,
+    old_multicast: u64,
     /// MAC address
     pub(crate) mac_addr: MacAddr,
     pub(crate) ip_networks: Vec<IpNetwork>,
@@ -242,6 +435,63 @@ impl NetworkDataInner {
         self.tx_errors
     }
 
+    pub(crate) fn drops_on_received(&self) -> u64 {
+        self.rx_dropped.saturating_sub(self.old_rx_dropped)
+    }
+
+    pub(crate) fn total_drops_on_received(&self) -> u64 {
+        self.rx_dropped
+    }
+
+    pub(crate) fn drops_on_transmitted(&self) -> u64 {
+        self.tx_dropped.saturating_sub(self.old_tx_dropped)
+    }
+
+    pub(crate) fn total_drops_on_transmitted(&self) -> u64 {
+        self.tx_dropped
+    }
+
+    pub(crate) fn fifo_errors_on_received(&self) -> u64 {
+        self.rx_fifo_errors.saturating_sub(self.old_rx_fifo_errors)
+    }
+
+    pub(crate) fn total_fifo_errors_on_received(&self) -> u64 {
+        self.rx_fifo_errors
+    }
+
+    pub(crate) fn fifo_errors_on_transmitted(&self) -> u64 {
+        self.tx_fifo_errors.saturating_sub(self.old_tx_fifo_errors)
+    }
+
+    pub(crate) fn total_fifo_errors_on_transmitted(&self) -> u64 {
+        self.tx_fifo_errors
+    }
+
+    pub(crate) fn collisions(&self) -> u64 {
+        self.collisions.saturating_sub(self.old_collisions)
+    }
+
+    pub(crate) fn total_collisions(&self) -> u64 {
+        self.collisions
+    }
+
+    pub(crate) fn carrier_errors(&self) -> u64 {
+        self.tx_carrier_errors
+            .saturating_sub(self.old_tx_carrier_errors)
+    }
+
+    pub(crate) fn total_carrier_errors(&self) -> u64 {
+        self.tx_carrier_errors
+    }
+
+    pub(crate) fn multicast_packets_received(&self) -> u64 {
+        self.multicast.saturating_sub(self.old_multicast)
+    }
+
+    pub(crate) fn total_multicast_packets_received(&self) -> u64 {
+        self.multicast
+    }
+
     pub(crate) fn mac_address(&self) -> MacAddr {
         self.mac_addr
     }
@@ -257,7 +507,9 @@ impl NetworkDataInner {
 
 #[cfg(test)]
 mod test {
-    use super::refresh_networks_list_from_sysfs;
+    use super::{
+        parse_ipv4_default_route, parse_ipv6_default_route, refresh_networks_list_from_sysfs,
+    };
     use std::collections::HashMap;
     use std::fs;
 
@@ -269,12 +521,12 @@ mod test {
 
         let mut interfaces = HashMap::new();
 
-        refresh_networks_list_from_sysfs(&mut interfaces, false, sys_net_dir.path());
+        refresh_networks_list_from_sysfs(&mut interfaces, false, sys_net_dir.path()).unwrap();
         assert_eq!(interfaces.keys().collect::<Vec<_>>(), ["itf1"]);
 
         fs::create_dir(sys_net_dir.path().join("itf2")).expect("failed to create subdirectory");
 
-        refresh_networks_list_from_sysfs(&mut interfaces, false, sys_net_dir.path());
+        refresh_networks_list_from_sysfs(&mut interfaces, false, sys_net_dir.path()).unwrap();
         let mut itf_names: Vec<String> = interfaces.keys().map(|n| n.to_owned()).collect();
         itf_names.sort();
         assert_eq!(itf_names, ["itf1", "itf2"]);
@@ -291,14 +543,76 @@ mod test {
 
         let mut interfaces = HashMap::new();
 
-        refresh_networks_list_from_sysfs(&mut interfaces, false, sys_net_dir.path());
+        refresh_networks_list_from_sysfs(&mut interfaces, false, sys_net_dir.path()).unwrap();
         let mut itf_names: Vec<String> = interfaces.keys().map(|n| n.to_owned()).collect();
         itf_names.sort();
         assert_eq!(itf_names, ["itf1", "itf2"]);
 
         fs::remove_dir(&itf1_dir).expect("failed to remove subdirectory");
 
-        refresh_networks_list_from_sysfs(&mut interfaces, true, sys_net_dir.path());
+        refresh_networks_list_from_sysfs(&mut interfaces, true, sys_net_dir.path()).unwrap();
         assert_eq!(interfaces.keys().collect::<Vec<_>>(), ["itf2"]);
     }
+
+    #[test]
+    fn refresh_networks_list_link_health_counters() {
+        let sys_net_dir = tempfile::tempdir().expect("failed to create temporary directory");
+
+        let stats_dir = sys_net_dir.path().join("itf1/statistics");
+        fs::create_dir_all(&stats_dir).expect("failed to create subdirectory");
+        for (name, value) in [
+            ("rx_dropped", "3"),
+            ("collisions", "1"),
+            ("multicast", "10"),
+        ] {
+            fs::write(stats_dir.join(name), value).expect("failed to write statistic");
+        }
+
+        let mut interfaces = HashMap::new();
+
+        refresh_networks_list_from_sysfs(&mut interfaces, false, sys_net_dir.path()).unwrap();
+        let itf = &interfaces["itf1"].inner;
+        assert_eq!(itf.total_drops_on_received(), 3);
+        assert_eq!(itf.drops_on_received(), 0);
+        assert_eq!(itf.total_collisions(), 1);
+        assert_eq!(itf.total_multicast_packets_received(), 10);
+        assert_eq!(itf.total_carrier_errors(), 0);
+
+        fs::write(stats_dir.join("rx_dropped"), "7").expect("failed to write statistic");
+
+        refresh_networks_list_from_sysfs(&mut interfaces, false, sys_net_dir.path()).unwrap();
+        let itf = &interfaces["itf1"].inner;
+        assert_eq!(itf.total_drops_on_received(), 7);
+        assert_eq!(itf.drops_on_received(), 4);
+    }
+
+    #[test]
+    #[cfg(target_endian = "little")]
+    fn test_parse_ipv4_default_route() {
+        let route = "\
+Iface\tDestination\tGateway \tFlags\tRefCnt\tUse\tMetric\tMask\t\tMTU\tWindow\tIRTT
+wlan0\t00000000\t0101A8C0\t0003\t0\t0\t600\t00000000\t0\t0\t0
+eth0\t00000000\t010200C0\t0003\t0\t0\t100\t00000000\t0\t0\t0
+eth0\t000200C0\t00000000\t0001\t0\t0\t100\t00FFFFFF\t0\t0\t0
+";
+        assert_eq!(
+            parse_ipv4_default_route(route),
+            Some(("192.0.2.1".parse().unwrap(), "eth0".to_owned())),
+        );
+        assert_eq!(parse_ipv4_default_route(""), None);
+    }
+
+    #[test]
+    fn test_parse_ipv6_default_route() {
+        let route = "\
+fe800000000000000000000000000000 40 00000000000000000000000000000000 00 00000000000000000000000000000000 00000100 00000002 00000000 00000001     eth0
+00000000000000000000000000000000 00 00000000000000000000000000000000 00 fe800000000000000000000000000001 00000400 00000001 00000000 00000003     eth0
+00000000000000000000000000000000 00 00000000000000000000000000000000 00 00000000000000000000000000000000 ffffffff 00000001 00000000 00200200       lo
+";
+        assert_eq!(
+            parse_ipv6_default_route(route),
+            Some(("fe80::1".parse().unwrap(), "eth0".to_owned())),
+        );
+        assert_eq!(parse_ipv6_default_route(""), None);
+    }
 }
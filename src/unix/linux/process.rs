@@ -6,21 +6,32 @@ use std::ffi::{OsStr, OsString};
 use std::fmt;
 use std::fs::{self, DirEntry, File, read_dir};
 use std::io::Read;
+use std::mem::MaybeUninit;
+use std::net::{Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::os::fd::{AsRawFd, FromRawFd, OwnedFd};
 use std::os::unix::ffi::OsStrExt;
 use std::path::{Path, PathBuf};
 use std::process::ExitStatus;
 use std::str::{self, FromStr};
 use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, OnceLock};
+use std::time::{Duration, Instant};
 
 use libc::{c_ulong, gid_t, uid_t};
 
-use crate::sys::system::SystemInfo;
+use crate::parsing::{
+    ProcIndex, StatParts, container_id_from_cgroup, parse_cgroup, parse_elf_header,
+    parse_kernel_stack_line, parse_maps_deleted_executables, parse_maps_paths,
+    parse_maps_stack_and_heap, parse_process_io_counters, parse_process_schedstat, parse_stat_file,
+};
+use crate::sys::system::{KeptFiles, SystemInfo};
 use crate::sys::utils::{
-    PathHandler, PathPush, get_all_data_from_file, get_all_utf8_data, realpath,
+    PathHandler, PathPush, read_all_data_from_file, read_utf8_data, realpath, with_scratch_buffer,
 };
 use crate::{
-    DiskUsage, Gid, Pid, Process, ProcessRefreshKind, ProcessStatus, ProcessesToUpdate, Signal,
-    ThreadKind, Uid,
+    ConnectionCount, CurrentProcessUsage, DiskUsage, EnergyImpact, ExeMetadata, ExeSignature, Gid,
+    IoCounters, MediaUsage, PathUsage, Pid, Process, ProcessRefreshKind, ProcessStatus,
+    ProcessesToUpdate, RefreshError, SchedStats, Signal, ThreadKind, Uid,
 };
 
 use crate::sys::system::remaining_files;
@@ -64,41 +75,22 @@ impl fmt::Display for ProcessStatus {
     }
 }
 
-#[allow(dead_code)]
-#[repr(usize)]
-enum ProcIndex {
-    Pid = 0,
-    State,
-    ParentPid,
-    GroupId,
-    SessionId,
-    Tty,
-    ForegroundProcessGroupId,
-    Flags,
-    MinorFaults,
-    ChildrenMinorFaults,
-    MajorFaults,
-    ChildrenMajorFaults,
-    UserTime,
-    SystemTime,
-    ChildrenUserTime,
-    ChildrenKernelTime,
-    Priority,
-    Nice,
-    NumberOfThreads,
-    IntervalTimerSigalarm,
-    StartTime,
-    VirtualSize,
-    ResidentSetSize,
-    // More exist but we only use the listed ones. For more, take a look at `man proc`.
-}
-
 pub(crate) struct ProcessInner {
-    pub(crate) name: OsString,
+    pub(crate) name: Arc<OsStr>,
     pub(crate) cmd: Vec<OsString>,
-    pub(crate) exe: Option<PathBuf>,
+    pub(crate) exe: Option<Arc<Path>>,
     pub(crate) pid: Pid,
     parent: Option<Pid>,
+    reparented_to_init: bool,
+    exec_count: u64,
+    /// Device and inode of the executable, to tell `exec` calls from name changes. `None` if
+    /// `/proc/[pid]/exe` cannot be read (kernel threads, processes of other users...).
+    exe_id: Option<(u64, u64)>,
+    thread_count: Option<usize>,
+    session_id: Option<Pid>,
+    process_group_id: Option<Pid>,
+    /// Device number of the controlling terminal, `0` if there is none.
+    tty_nr: u64,
     pub(crate) environ: Vec<OsString>,
     pub(crate) cwd: Option<PathBuf>,
     pub(crate) root: Option<PathBuf>,
@@ -121,6 +113,8 @@ pub(crate) struct ProcessInner {
     pub(crate) status: ProcessStatus,
     pub(crate) tasks: Option<HashSet<Pid>>,
     stat_file: Option<FileCounter>,
+    /// Only set if the `System` was asked to track processes with pidfds.
+    pidfd: Option<OwnedFd>,
     old_read_bytes: u64,
     old_written_bytes: u64,
     read_bytes: u64,
@@ -128,15 +122,30 @@ pub(crate) struct ProcessInner {
     thread_kind: Option<ThreadKind>,
     proc_path: PathBuf,
     accumulated_cpu_time: u64,
+    children_cpu_time: u64,
+    exe_signature: OnceLock<Option<ExeSignature>>,
+    exe_metadata: OnceLock<Option<ExeMetadata>>,
+    /// Busy time (in nanoseconds) of each DRM engine used by this process, with the time at
+    /// which it was retrieved.
+    old_gpu_engines: Option<(Instant, HashMap<String, u64>)>,
+    gpu_usage: Option<f32>,
+    media_usage: Option<MediaUsage>,
     exists: bool,
 }
 
 impl ProcessInner {
     pub(crate) fn new(pid: Pid, proc_path: PathBuf) -> Self {
         Self {
-            name: OsString::new(),
+            name: Arc::from(OsStr::new("")),
             pid,
             parent: None,
+            reparented_to_init: false,
+            exec_count: 0,
+            exe_id: None,
+            thread_count: None,
+            session_id: None,
+            process_group_id: None,
+            tty_nr: 0,
             cmd: Vec::new(),
             environ: Vec::new(),
             exe: None,
@@ -161,6 +170,7 @@ impl ProcessInner {
             status: ProcessStatus::Unknown(0),
             tasks: None,
             stat_file: None,
+            pidfd: None,
             old_read_bytes: 0,
             old_written_bytes: 0,
             read_bytes: 0,
@@ -168,12 +178,32 @@ impl ProcessInner {
             thread_kind: None,
             proc_path,
             accumulated_cpu_time: 0,
+            children_cpu_time: 0,
+            exe_signature: OnceLock::new(),
+            exe_metadata: OnceLock::new(),
+            old_gpu_engines: None,
+            gpu_usage: None,
+            media_usage: None,
             exists: true,
         }
     }
 
     pub(crate) fn kill_with(&self, signal: Signal) -> Option<bool> {
         let c_signal = crate::sys::system::convert_signal(signal)?;
+        if let Some(ref pidfd) = self.pidfd {
+            // Unlike `kill`, it cannot reach another process which would have reused the PID.
+            return unsafe {
+                Some(
+                    libc::syscall(
+                        libc::SYS_pidfd_send_signal,
+                        pidfd.as_raw_fd(),
+                        c_signal,
+                        std::ptr::null::<libc::siginfo_t>(),
+                        0,
+                    ) == 0,
+                )
+            };
+        }
         unsafe { Some(libc::kill(self.pid.0, c_signal) == 0) }
     }
 
@@ -217,6 +247,22 @@ impl ProcessInner {
         self.parent
     }
 
+    pub(crate) fn thread_count(&self) -> Option<usize> {
+        self.thread_count
+    }
+
+    pub(crate) fn tty(&self) -> Option<String> {
+        get_tty_name(self.tty_nr)
+    }
+
+    pub(crate) fn reparented_to_init(&self) -> bool {
+        self.reparented_to_init
+    }
+
+    pub(crate) fn exec_count(&self) -> u64 {
+        self.exec_count
+    }
+
     pub(crate) fn status(&self) -> ProcessStatus {
         self.status
     }
@@ -225,6 +271,10 @@ impl ProcessInner {
         self.start_time
     }
 
+    pub(crate) fn start_time_raw(&self) -> u64 {
+        self.start_time_raw
+    }
+
     pub(crate) fn run_time(&self) -> u64 {
         self.run_time
     }
@@ -237,6 +287,10 @@ impl ProcessInner {
         self.accumulated_cpu_time
     }
 
+    pub(crate) fn children_cpu_time(&self) -> u64 {
+        self.children_cpu_time
+    }
+
     pub(crate) fn disk_usage(&self) -> DiskUsage {
         DiskUsage {
             written_bytes: self.written_bytes.saturating_sub(self.old_written_bytes),
@@ -263,11 +317,51 @@ impl ProcessInner {
     }
 
     pub(crate) fn wait(&self) -> Option<ExitStatus> {
+        if let Some(ref pidfd) = self.pidfd {
+            use std::os::unix::process::ExitStatusExt;
+
+            pidfd_has_exited(pidfd, -1);
+            // Waiting through the pidfd (instead of the PID) ensures that we never reap another
+            // process reusing the PID if this one was already reaped by someone else.
+            let mut info = MaybeUninit::<libc::siginfo_t>::zeroed();
+            let mut errno = 0;
+            let ret = retry_eintr!(errno => unsafe {
+                libc::waitid(
+                    libc::P_PIDFD,
+                    pidfd.as_raw_fd() as _,
+                    info.as_mut_ptr(),
+                    libc::WEXITED,
+                )
+            });
+            if ret == 0 {
+                return exit_status_from_siginfo(unsafe { info.assume_init_ref() });
+            }
+            // `P_PIDFD` was added in Linux 5.4 whereas pidfds exist since Linux 5.3.
+            if errno != libc::EINVAL {
+                // Not one of our children (or already reaped), so its exit status is lost.
+                return None;
+            }
+            // Until it is reaped, the PID of the process cannot be reused, so if it's one of our
+            // children, we can safely get its exit status.
+            let mut status = 0;
+            return match retry_eintr!(unsafe {
+                libc::waitpid(self.pid.0, &mut status, libc::WNOHANG)
+            }) {
+                // Not one of our children (or already reaped), so its exit status is lost.
+                ret if ret < 0 => None,
+                // It didn't exit after all (the poll failed), so we wait for it the usual way.
+                0 => crate::unix::utils::wait_process(self.pid),
+                _ => Some(ExitStatus::from_raw(status)),
+            };
+        }
+
         // If anything fails when trying to retrieve the start time, better to return `None`.
-        let (data, _) = _get_stat_data_and_file(&self.proc_path).ok()?;
-        let parts = parse_stat_file(&data)?;
+        let start_time_raw = with_scratch_buffer(|data| {
+            _get_stat_data(&self.proc_path, data).ok()?;
+            parse_stat_file(data).map(|parts| start_time_raw(&parts))
+        })?;
 
-        if start_time_raw(&parts) != self.start_time_raw {
+        if start_time_raw != self.start_time_raw {
             sysinfo_debug!("Seems to not be the same process anymore");
             return None;
         }
@@ -276,14 +370,37 @@ impl ProcessInner {
     }
 
     pub(crate) fn session_id(&self) -> Option<Pid> {
-        unsafe {
-            let session_id = libc::getsid(self.pid.0);
-            if session_id < 0 {
-                None
-            } else {
-                Some(Pid(session_id))
-            }
+        self.session_id
+    }
+
+    pub(crate) fn process_group_id(&self) -> Option<Pid> {
+        self.process_group_id
+    }
+
+    pub(crate) fn current_usage() -> Option<CurrentProcessUsage> {
+        // The first two fields are the total program size and the resident set size, in pages.
+        let statm = fs::read_to_string("/proc/self/statm").ok()?;
+        let mut parts = statm.split_ascii_whitespace();
+        let virtual_memory = u64::from_str(parts.next()?).ok()?;
+        let memory = u64::from_str(parts.next()?).ok()?;
+        let page_size = unsafe { libc::sysconf(libc::_SC_PAGESIZE) } as u64;
+
+        let mut usage = MaybeUninit::<libc::rusage>::uninit();
+        if unsafe { libc::getrusage(libc::RUSAGE_SELF, usage.as_mut_ptr()) } != 0 {
+            return None;
         }
+        let usage = unsafe { usage.assume_init() };
+        let to_ms = |time: libc::timeval| time.tv_sec as u64 * 1_000 + time.tv_usec as u64 / 1_000;
+
+        Some(CurrentProcessUsage {
+            memory: memory.saturating_mul(page_size),
+            virtual_memory: virtual_memory.saturating_mul(page_size),
+            accumulated_cpu_time: to_ms(usage.ru_utime).saturating_add(to_ms(usage.ru_stime)),
+        })
+    }
+
+    pub(crate) fn children_usage() -> Option<crate::ChildrenUsage> {
+        crate::unix::utils::children_usage()
     }
 
     pub(crate) fn thread_kind(&self) -> Option<ThreadKind> {
@@ -316,6 +433,38 @@ impl ProcessInner {
         }
     }
 
+    pub(crate) fn path_usage(&self, path: &Path) -> PathUsage {
+        let proc_path = self.proc_path.as_path();
+        let is_used =
+            |link: &Path| fs::read_link(link).is_ok_and(|target| target.starts_with(path));
+        let mut usage = PathUsage {
+            cwd: is_used(&proc_path.join("cwd")),
+            root: is_used(&proc_path.join("root")),
+            exe: is_used(&proc_path.join("exe")),
+            ..PathUsage::default()
+        };
+        if let Ok(entries) = fs::read_dir(proc_path.join("fd")) {
+            usage.open_file = entries.flatten().any(|entry| is_used(&entry.path()));
+        }
+        if let Ok(maps) = fs::read(proc_path.join("maps")) {
+            usage.mapped = parse_maps_paths(&maps)
+                .any(|mapped| Path::new(OsStr::from_bytes(mapped)).starts_with(path));
+        }
+        usage
+    }
+
+    pub(crate) fn deleted_mappings(&self) -> Vec<PathBuf> {
+        let Ok(maps) = fs::read(self.proc_path.as_path().join("maps")) else {
+            return Vec::new();
+        };
+        let mut paths = parse_maps_deleted_executables(&maps)
+            .map(|path| PathBuf::from(OsStr::from_bytes(path)))
+            .collect::<Vec<_>>();
+        paths.sort_unstable();
+        paths.dedup();
+        paths
+    }
+
     pub(crate) fn open_files_limit(&self) -> Option<usize> {
         let limits_files = self.proc_path.as_path().join("limits");
         match fs::read_to_string(&limits_files) {
@@ -338,6 +487,193 @@ impl ProcessInner {
             }
         }
     }
+
+    fn stack_and_heap_sizes(&self) -> Option<(u64, u64)> {
+        let maps = fs::read_to_string(self.proc_path.as_path().join("maps")).ok()?;
+        Some(parse_maps_stack_and_heap(&maps))
+    }
+
+    pub(crate) fn stack_size(&self) -> Option<u64> {
+        self.stack_and_heap_sizes().map(|(stack, _)| stack)
+    }
+
+    pub(crate) fn heap_size(&self) -> Option<u64> {
+        self.stack_and_heap_sizes().map(|(_, heap)| heap)
+    }
+
+    pub(crate) fn cgroup(&self) -> Option<String> {
+        let content = fs::read_to_string(self.proc_path.as_path().join("cgroup")).ok()?;
+        parse_cgroup(&content).map(str::to_owned)
+    }
+
+    pub(crate) fn container_id(&self) -> Option<String> {
+        self.cgroup()
+            .as_deref()
+            .and_then(container_id_from_cgroup)
+            .map(str::to_owned)
+    }
+
+    pub(crate) fn sched_stats(&self) -> Option<SchedStats> {
+        let content = fs::read_to_string(self.proc_path.as_path().join("schedstat")).ok()?;
+        let (run_time, wait_time, timeslices) = parse_process_schedstat(&content)?;
+        Some(SchedStats {
+            run_time: Duration::from_nanos(run_time),
+            wait_time: Duration::from_nanos(wait_time),
+            timeslices,
+        })
+    }
+
+    pub(crate) fn io_counters(&self) -> Option<IoCounters> {
+        let content = fs::read_to_string(self.proc_path.as_path().join("io")).ok()?;
+        let (read_syscalls, write_syscalls, cancelled_write_bytes) =
+            parse_process_io_counters(&content)?;
+        Some(IoCounters {
+            read_syscalls,
+            write_syscalls,
+            cancelled_write_bytes,
+        })
+    }
+
+    pub(crate) fn wait_channel(&self) -> Option<String> {
+        let wchan = fs::read_to_string(self.proc_path.as_path().join("wchan")).ok()?;
+        let wchan = wchan.trim();
+        // `0` is written when the process isn't sleeping or when it isn't allowed to know.
+        if wchan.is_empty() || wchan == "0" {
+            return None;
+        }
+        Some(wchan.to_owned())
+    }
+
+    pub(crate) fn kernel_stack(&self) -> Result<Vec<String>, RefreshError> {
+        let path = self.proc_path.as_path().join("stack");
+        let content =
+            fs::read_to_string(&path).map_err(|error| RefreshError::from_io(&path, error))?;
+        Ok(content
+            .lines()
+            .filter_map(parse_kernel_stack_line)
+            .map(str::to_owned)
+            .collect())
+    }
+
+    /// Returns the class and the machine of the ELF header of the executable.
+    fn elf_header(&self) -> Option<(bool, u16)> {
+        let mut header = [0; 20];
+        let mut file = File::open(self.proc_path.as_path().join("exe")).ok()?;
+        file.read_exact(&mut header).ok()?;
+        parse_elf_header(&header)
+    }
+
+    pub(crate) fn is_64_bit(&self) -> Option<bool> {
+        self.elf_header().map(|(is_64_bit, _)| is_64_bit)
+    }
+
+    pub(crate) fn abi(&self) -> Option<&'static str> {
+        let (is_64_bit, machine) = self.elf_header()?;
+        elf_machine_abi(is_64_bit, machine)
+    }
+
+    /// Returns the inodes of the sockets opened by the process.
+    fn socket_inodes(&self) -> Option<HashSet<u64>> {
+        let open_files_dir = self.proc_path.as_path().join("fd");
+        match fs::read_dir(&open_files_dir) {
+            Ok(entries) => Some(
+                entries
+                    .flatten()
+                    .filter_map(|entry| {
+                        let target = fs::read_link(entry.path()).ok()?;
+                        target
+                            .to_str()?
+                            .strip_prefix("socket:[")?
+                            .strip_suffix(']')?
+                            .parse::<u64>()
+                            .ok()
+                    })
+                    .collect(),
+            ),
+            Err(_error) => {
+                sysinfo_debug!(
+                    "Failed to get open files in `{}`: {_error:?}",
+                    open_files_dir.display(),
+                );
+                None
+            }
+        }
+    }
+
+    pub(crate) fn connection_count(&self) -> Option<ConnectionCount> {
+        let inodes = self.socket_inodes()?;
+        let mut count = ConnectionCount::default();
+        if inodes.is_empty() {
+            return Some(count);
+        }
+        let count_in = |tables: [&str; 2]| {
+            tables
+                .into_iter()
+                .filter_map(|table| fs::read_to_string(self.proc_path.as_path().join(table)).ok())
+                .map(|content| count_sockets(&content, &inodes))
+                .sum()
+        };
+        count.tcp = count_in(["net/tcp", "net/tcp6"]);
+        count.udp = count_in(["net/udp", "net/udp6"]);
+        Some(count)
+    }
+
+    pub(crate) fn listening_ports(&self) -> Option<Vec<SocketAddr>> {
+        let inodes = self.socket_inodes()?;
+        let mut ports = Vec::new();
+        if inodes.is_empty() {
+            return Some(ports);
+        }
+        for table in ["net/tcp", "net/tcp6"] {
+            if let Ok(content) = fs::read_to_string(self.proc_path.as_path().join(table)) {
+                parse_tcp_listening_sockets(&content, &inodes, &mut ports);
+            }
+        }
+        ports.sort_unstable();
+        ports.dedup();
+        Some(ports)
+    }
+
+    pub(crate) fn exe_signature(&self) -> Option<&ExeSignature> {
+        self.exe.as_ref()?;
+        // `/proc/[pid]/exe` is used rather than `exe` so the file which is actually running is
+        // read, even if it was removed or replaced since then.
+        self.exe_signature
+            .get_or_init(|| {
+                crate::sha256::sha256_file(&self.proc_path.as_path().join("exe"))
+                    .map(ExeSignature::Sha256)
+            })
+            .as_ref()
+    }
+
+    pub(crate) fn exe_metadata(&self) -> Option<&ExeMetadata> {
+        self.exe.as_ref()?;
+        self.exe_metadata
+            .get_or_init(|| {
+                crate::exe_metadata::read_exe_metadata(&self.proc_path.as_path().join("exe"))
+            })
+            .as_ref()
+    }
+
+    pub(crate) fn bundle_identifier(&self) -> Option<&str> {
+        None
+    }
+
+    pub(crate) fn bundle_name(&self) -> Option<&str> {
+        None
+    }
+
+    pub(crate) fn energy_impact(&self) -> Option<EnergyImpact> {
+        None
+    }
+
+    pub(crate) fn gpu_usage(&self) -> Option<f32> {
+        self.gpu_usage
+    }
+
+    pub(crate) fn media_usage(&self) -> Option<MediaUsage> {
+        self.media_usage
+    }
 }
 
 pub(crate) fn compute_cpu_usage(p: &mut ProcessInner, total_time: f32, max_value: f32) {
@@ -365,10 +701,14 @@ pub(crate) fn set_time(p: &mut ProcessInner, utime: u64, stime: u64) {
 }
 
 pub(crate) fn update_process_disk_activity(p: &mut ProcessInner, path: &mut PathHandler) {
-    let data = match get_all_utf8_data(path.replace_and_join("io"), 16_384) {
-        Ok(d) => d,
-        Err(_) => return,
-    };
+    with_scratch_buffer(|buf| {
+        if let Ok(data) = read_utf8_data(path.replace_and_join("io"), buf) {
+            update_disk_activity_from_io(p, data);
+        }
+    });
+}
+
+fn update_disk_activity_from_io(p: &mut ProcessInner, data: &str) {
     let mut done = 0;
     for line in data.split('\n') {
         let mut parts = line.split(": ");
@@ -397,6 +737,299 @@ pub(crate) fn update_process_disk_activity(p: &mut ProcessInner, path: &mut Path
     }
 }
 
+// Based on <https://docs.kernel.org/gpu/drm-usage-stats.html>.
+pub(crate) fn update_process_gpu_usage(p: &mut ProcessInner, path: &mut PathHandler) {
+    let Ok(entries) = read_dir(path.replace_and_join("fdinfo")) else {
+        return;
+    };
+    let now = Instant::now();
+    let mut clients = HashSet::new();
+    let mut engines = HashMap::new();
+    for entry in entries.flatten() {
+        // Most file descriptors are not DRM ones so we only read the beginning of the file.
+        with_scratch_buffer(|buf| {
+            if let Ok(data) = read_utf8_data(entry.path(), buf) {
+                parse_drm_fdinfo(data, &mut clients, &mut engines);
+            }
+        });
+    }
+    if engines.is_empty() {
+        p.old_gpu_engines = None;
+        p.gpu_usage = None;
+        return;
+    }
+    if let Some((old_time, old_engines)) = &p.old_gpu_engines {
+        let elapsed = now.duration_since(*old_time).as_nanos() as f64;
+        if elapsed > 0. {
+            // Engines run in parallel so we use the busiest one.
+            let busiest = engines
+                .iter()
+                .map(|(name, busy)| {
+                    busy.saturating_sub(old_engines.get(name).copied().unwrap_or(0))
+                })
+                .max()
+                .unwrap_or(0);
+            p.gpu_usage = Some((busiest as f64 / elapsed * 100.).min(100.) as f32);
+        }
+    } else {
+        p.gpu_usage = Some(0.);
+    }
+    p.old_gpu_engines = Some((now, engines));
+}
+
+pub(crate) fn update_process_media_usage(p: &mut ProcessInner, path: &mut PathHandler) {
+    let Ok(entries) = read_dir(path.replace_and_join("fd")) else {
+        p.media_usage = None;
+        return;
+    };
+    let mut usage = MediaUsage {
+        audio_capture: Some(false),
+        camera: Some(false),
+        screen_recording: None,
+    };
+    for entry in entries.flatten() {
+        let Ok(target) = fs::read_link(entry.path()) else {
+            continue;
+        };
+        let Some(device) = target.to_str().and_then(|t| t.strip_prefix("/dev/")) else {
+            continue;
+        };
+        if is_audio_capture_device(device) {
+            usage.audio_capture = Some(true);
+        } else if device.starts_with("video") {
+            usage.camera = Some(true);
+        }
+    }
+    p.media_usage = Some(usage);
+}
+
+/// Returns the name of the terminal device with the `tty_nr` device number (as written in
+/// `/proc/[pid]/stat`), without the `/dev/` prefix.
+fn get_tty_name(tty_nr: u64) -> Option<String> {
+    if tty_nr == 0 {
+        return None;
+    }
+    let major = (tty_nr >> 8) & 0xfff;
+    let minor = (tty_nr & 0xff) | ((tty_nr >> 12) & 0xfff00);
+    if let Some(name) = get_known_tty_name(major, minor) {
+        return Some(name);
+    }
+    let path = fs::read_link(format!("/sys/dev/char/{major}:{minor}")).ok()?;
+    Some(path.file_name()?.to_str()?.to_owned())
+}
+
+/// Returns the name of the terminal devices which don't have an entry in `/sys/dev/char`.
+fn get_known_tty_name(major: u64, minor: u64) -> Option<String> {
+    match major {
+        // Unix98 pseudo-terminals use the majors 136 to 143.
+        136..=143 => Some(format!("pts/{}", (major - 136) * 256 + minor)),
+        4 if minor < 64 => Some(format!("tty{minor}")),
+        4 => Some(format!("ttyS{}", minor - 64)),
+        _ => None,
+    }
+}
+
+/// Marks the processes recording audio through the sound server as capturing audio.
+///
+/// Applications going through PulseAudio or PipeWire (with its PulseAudio compatibility
+/// server) don't open the ALSA capture devices themselves, so the streams of the sound server
+/// of the current user are retrieved with `pactl`.
+pub(crate) fn update_sound_server_media_usage(
+    proc_list: &mut HashMap<Pid, Process>,
+    processes_to_update: ProcessesToUpdate<'_>,
+) {
+    let Ok(output) = std::process::Command::new("pactl")
+        .args(["list", "source-outputs"])
+        // The output is translated otherwise.
+        .env("LC_ALL", "C")
+        .stdin(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .output()
+    else {
+        return;
+    };
+    if !output.status.success() {
+        return;
+    }
+    for pid in parse_pactl_source_outputs(&String::from_utf8_lossy(&output.stdout)) {
+        if let ProcessesToUpdate::Some(pids) = processes_to_update
+            && !pids.contains(&pid)
+        {
+            continue;
+        }
+        if let Some(proc_) = proc_list.get_mut(&pid)
+            && let Some(usage) = &mut proc_.inner.media_usage
+        {
+            usage.audio_capture = Some(true);
+        }
+    }
+}
+
+/// Returns the PIDs of the processes owning a recording stream which isn't paused in the
+/// output of `pactl list source-outputs`.
+fn parse_pactl_source_outputs(output: &str) -> Vec<Pid> {
+    let mut pids = Vec::new();
+    let mut pid = None;
+    let mut corked = false;
+    for line in output.lines() {
+        // Each stream starts with an unindented `Source Output #[index]` line.
+        if !line.starts_with(char::is_whitespace) {
+            if let Some(pid) = pid.take()
+                && !corked
+            {
+                pids.push(pid);
+            }
+            corked = false;
+            continue;
+        }
+        let line = line.trim();
+        if line == "Corked: yes" {
+            corked = true;
+        } else if let Some(value) = line.strip_prefix("application.process.id = ") {
+            pid = Pid::from_str(value.trim_matches('"')).ok();
+        }
+    }
+    if let Some(pid) = pid
+        && !corked
+    {
+        pids.push(pid);
+    }
+    pids
+}
+
+/// ALSA PCM capture devices are named `pcmC[card]D[device]c`.
+fn is_audio_capture_device(device: &str) -> bool {
+    device
+        .strip_prefix("snd/pcmC")
+        .and_then(|d| d.strip_suffix('c'))
+        .is_some_and(|d| d.contains('D'))
+}
+
+/// Adds the busy time of each engine listed in a `/proc/[pid]/fdinfo/[fd]` file to `engines`.
+///
+/// The same DRM client can be opened through multiple file descriptors so `clients` is used to
+/// only count each client once.
+fn parse_drm_fdinfo(
+    data: &str,
+    clients: &mut HashSet<(String, String)>,
+    engines: &mut HashMap<String, u64>,
+) {
+    let mut client_id = None;
+    let mut pdev = "";
+    let mut busy = Vec::new();
+    for line in data.lines() {
+        let Some((key, value)) = line.split_once(':') else {
+            continue;
+        };
+        let value = value.trim();
+        match key {
+            "drm-client-id" => client_id = Some(value),
+            "drm-pdev" => pdev = value,
+            _ => {
+                if let Some(engine) = key.strip_prefix("drm-engine-")
+                    && let Some(ns) = value.strip_suffix(" ns")
+                    && let Ok(ns) = ns.trim().parse::<u64>()
+                {
+                    busy.push((engine, ns));
+                }
+            }
+        }
+    }
+    let Some(client_id) = client_id else {
+        // Not a DRM file descriptor.
+        return;
+    };
+    if !clients.insert((pdev.to_owned(), client_id.to_owned())) {
+        return;
+    }
+    for (engine, ns) in busy {
+        let total = engines.entry(format!("{pdev}/{engine}")).or_insert(0);
+        *total = total.saturating_add(ns);
+    }
+}
+
+/// Parses an address of `/proc/[pid]/net/tcp` or `/proc/[pid]/net/tcp6`. The IP address is
+/// written as 32-bit words in host byte order and the port in hexadecimal.
+fn parse_proc_net_address(address: &str) -> Option<SocketAddr> {
+    let (ip, port) = address.split_once(':')?;
+    let port = u16::from_str_radix(port, 16).ok()?;
+    let parse_word = |index: usize| {
+        let word = u32::from_str_radix(ip.get(index * 8..(index + 1) * 8)?, 16).ok()?;
+        Some(word.to_ne_bytes())
+    };
+    let ip = match ip.len() {
+        8 => Ipv4Addr::from(parse_word(0)?).into(),
+        32 => {
+            let mut bytes = [0; 16];
+            for (index, chunk) in bytes.chunks_exact_mut(4).enumerate() {
+                chunk.copy_from_slice(&parse_word(index)?);
+            }
+            Ipv6Addr::from(bytes).into()
+        }
+        _ => return None,
+    };
+    Some(SocketAddr::new(ip, port))
+}
+
+/// Adds the local address of the sockets of `content` (the content of `/proc/[pid]/net/tcp` or
+/// `/proc/[pid]/net/tcp6`) in the `LISTEN` state and whose inode is in `inodes` to `ports`.
+fn parse_tcp_listening_sockets(content: &str, inodes: &HashSet<u64>, ports: &mut Vec<SocketAddr>) {
+    // `0A` is `TCP_LISTEN`.
+    const TCP_LISTEN: &str = "0A";
+
+    for line in content.lines().skip(1) {
+        let fields = line.split_whitespace().collect::<Vec<_>>();
+        if fields.len() < 10 || fields[3] != TCP_LISTEN {
+            continue;
+        }
+        if fields[9]
+            .parse::<u64>()
+            .is_ok_and(|inode| inodes.contains(&inode))
+            && let Some(address) = parse_proc_net_address(fields[1])
+        {
+            ports.push(address);
+        }
+    }
+}
+
+/// Returns the number of sockets of `content` (the content of a `/proc/[pid]/net/tcp` or
+/// `/proc/[pid]/net/udp` file) whose inode is in `inodes`.
+/// Converts an ELF machine (`e_machine`) into an architecture name.
+fn elf_machine_abi(is_64_bit: bool, machine: u16) -> Option<&'static str> {
+    Some(match (machine, is_64_bit) {
+        (3, false) => "x86",
+        (62, true) => "x86_64",
+        (62, false) => "x32",
+        (40, false) => "arm",
+        (183, true) => "aarch64",
+        (8, false) => "mips",
+        (8, true) => "mips64",
+        (20, false) => "powerpc",
+        (21, true) => "powerpc64",
+        (22, false) => "s390",
+        (22, true) => "s390x",
+        (243, false) => "riscv32",
+        (243, true) => "riscv64",
+        (258, true) => "loongarch64",
+        (2 | 18, false) => "sparc",
+        (43, true) => "sparc64",
+        _ => return None,
+    })
+}
+
+fn count_sockets(content: &str, inodes: &HashSet<u64>) -> usize {
+    content
+        .lines()
+        .skip(1)
+        .filter(|line| {
+            line.split_whitespace()
+                .nth(9)
+                .and_then(|inode| inode.parse::<u64>().ok())
+                .is_some_and(|inode| inodes.contains(&inode))
+        })
+        .count()
+}
+
 struct Wrap<'a, T>(UnsafeCell<&'a mut T>);
 
 impl<'a, T> Wrap<'a, T> {
@@ -410,28 +1043,22 @@ unsafe impl<T> Send for Wrap<'_, T> {}
 unsafe impl<T> Sync for Wrap<'_, T> {}
 
 #[inline(always)]
-fn start_time_raw(parts: &Parts<'_>) -> u64 {
+fn start_time_raw(parts: &StatParts<'_>) -> u64 {
     u64::from_str(parts.str_parts[ProcIndex::StartTime as usize]).unwrap_or(0)
 }
 
 #[inline(always)]
-fn compute_start_time_without_boot_time(parts: &Parts<'_>, info: &SystemInfo) -> (u64, u64) {
+fn compute_start_time_without_boot_time(parts: &StatParts<'_>, info: &SystemInfo) -> (u64, u64) {
     let raw = start_time_raw(parts);
     // To be noted that the start time is invalid here, it still needs to be converted into
     // "real" time.
     (raw, raw / info.clock_cycle)
 }
 
-fn _get_stat_data_and_file(path: &Path) -> Result<(Vec<u8>, File), ()> {
-    let mut file = File::open(path.join("stat")).map_err(|_| ())?;
-    let data = get_all_data_from_file(&mut file, 1024).map_err(|_| ())?;
-    Ok((data, file))
-}
-
-fn _get_stat_data(path: &Path, stat_file: &mut Option<FileCounter>) -> Result<Vec<u8>, ()> {
-    let (data, file) = _get_stat_data_and_file(path)?;
-    *stat_file = FileCounter::new(file);
-    Ok(data)
+fn _get_stat_data(path: &Path, data: &mut Vec<u8>) -> Result<File, ()> {
+    let file = File::open(path.join("stat")).map_err(|_| ())?;
+    read_all_data_from_file(&file, data).map_err(|_| ())?;
+    Ok(file)
 }
 
 #[inline(always)]
@@ -447,8 +1074,12 @@ fn refresh_user_group_ids(
     p: &mut ProcessInner,
     path: &mut PathHandler,
     refresh_kind: ProcessRefreshKind,
+    exec_detected: bool,
 ) {
-    if !refresh_kind.user().needs_update(|| p.user_id.is_none()) {
+    if !refresh_kind
+        .user()
+        .needs_update_if_changed(exec_detected, || p.user_id.is_none())
+    {
         return;
     }
 
@@ -471,21 +1102,29 @@ fn update_proc_info(
     str_parts: &[&str],
     uptime: u64,
     info: &SystemInfo,
+    exec_detected: bool,
 ) {
     update_parent_pid(p, parent_pid, str_parts);
 
     get_status(p, str_parts[ProcIndex::State as usize]);
-    refresh_user_group_ids(p, proc_path, refresh_kind);
-
-    if refresh_kind.exe().needs_update(|| p.exe.is_none()) {
+    p.thread_count = usize::from_str(str_parts[ProcIndex::NumberOfThreads as usize]).ok();
+    p.tty_nr = u64::from_str(str_parts[ProcIndex::Tty as usize]).unwrap_or(0);
+    p.session_id = Pid::from_str(str_parts[ProcIndex::SessionId as usize]).ok();
+    p.process_group_id = Pid::from_str(str_parts[ProcIndex::GroupId as usize]).ok();
+    refresh_user_group_ids(p, proc_path, refresh_kind, exec_detected);
+
+    if refresh_kind
+        .exe()
+        .needs_update_if_changed(exec_detected, || p.exe.is_none())
+    {
         // Do not use cmd[0] because it is not the same thing.
         // See https://github.com/GuillaumeGomez/sysinfo/issues/697.
-        p.exe = realpath(proc_path.replace_and_join("exe"));
+        let mut new_exe = realpath(proc_path.replace_and_join("exe"));
         // If the target executable file was modified or removed, linux appends ` (deleted)` at
         // the end. We need to remove it.
         // See https://github.com/GuillaumeGomez/sysinfo/issues/1585.
         let deleted = b" (deleted)";
-        if let Some(exe) = &mut p.exe
+        if let Some(exe) = &mut new_exe
             && let Some(file_name) = exe.file_name()
             && file_name.as_encoded_bytes().ends_with(deleted)
         {
@@ -495,13 +1134,25 @@ fn update_proc_info(
                 exe.set_file_name(OsString::from_encoded_bytes_unchecked(file_name));
             }
         }
+        if new_exe.as_deref() != p.exe.as_deref() {
+            // The process called `exec`, so the computed signature is not valid anymore.
+            p.exe_signature = OnceLock::new();
+            p.exe_metadata = OnceLock::new();
+            p.exe = new_exe.map(|exe| info.intern_exe(&exe));
+        }
     }
 
-    if refresh_kind.cmd().needs_update(|| p.cmd.is_empty()) {
-        p.cmd = copy_from_file(proc_path.replace_and_join("cmdline"));
+    if refresh_kind
+        .cmd()
+        .needs_update_if_changed(exec_detected, || p.cmd.is_empty())
+    {
+        update_from_file(proc_path.replace_and_join("cmdline"), &mut p.cmd, info);
     }
-    if refresh_kind.environ().needs_update(|| p.environ.is_empty()) {
-        p.environ = copy_from_file(proc_path.replace_and_join("environ"));
+    if refresh_kind
+        .environ()
+        .needs_update_if_changed(exec_detected, || p.environ.is_empty())
+    {
+        update_from_file(proc_path.replace_and_join("environ"), &mut p.environ, info);
     }
     if refresh_kind.cwd().needs_update(|| p.cwd.is_none()) {
         p.cwd = realpath(proc_path.replace_and_join("cwd"));
@@ -514,30 +1165,46 @@ fn update_proc_info(
     if refresh_kind.disk_usage() {
         update_process_disk_activity(p, proc_path);
     }
+    if refresh_kind.gpu_usage() {
+        update_process_gpu_usage(p, proc_path);
+    }
+    if refresh_kind.media_usage() {
+        update_process_media_usage(p, proc_path);
+    }
     // Needs to be after `update_time_and_memory`.
     if refresh_kind.cpu() {
         // The external values for CPU times are in "ticks", which are
         // scaled by "HZ", which is pegged externally at 100 ticks/second.
         p.accumulated_cpu_time =
             p.utime.saturating_add(p.stime).saturating_mul(1_000) / info.clock_cycle;
+        let children_time =
+            |index: ProcIndex| u64::from_str(str_parts[index as usize]).unwrap_or(0);
+        p.children_cpu_time = children_time(ProcIndex::ChildrenUserTime)
+            .saturating_add(children_time(ProcIndex::ChildrenKernelTime))
+            .saturating_mul(1_000)
+            / info.clock_cycle;
     }
     p.updated = true;
 }
 
 fn update_parent_pid(p: &mut ProcessInner, parent_pid: Option<Pid>, str_parts: &[&str]) {
-    p.parent = match parent_pid {
+    let parent = match parent_pid {
         Some(parent_pid) if parent_pid.0 != 0 => Some(parent_pid),
         _ => match Pid::from_str(str_parts[ProcIndex::ParentPid as usize]) {
             Ok(p) if p.0 != 0 => Some(p),
             _ => None,
         },
     };
+    if p.parent.is_some_and(|old_parent| old_parent.0 != 1) && parent == Some(Pid(1)) {
+        p.reparented_to_init = true;
+    }
+    p.parent = parent;
 }
 
 fn retrieve_all_new_process_info(
     pid: Pid,
     parent_pid: Option<Pid>,
-    parts: &Parts<'_>,
+    parts: &StatParts<'_>,
     path: &Path,
     info: &SystemInfo,
     refresh_kind: ProcessRefreshKind,
@@ -555,7 +1222,7 @@ fn retrieve_all_new_process_info(
         .start_time_without_boot_time
         .saturating_add(info.boot_time);
 
-    p.name = OsStr::from_bytes(name).to_os_string();
+    p.name = info.intern_name(OsStr::from_bytes(name));
     if c_ulong::from_str(parts.str_parts[ProcIndex::Flags as usize])
         .map(|flags| flags & libc::PF_KTHREAD as c_ulong != 0)
         .unwrap_or(false)
@@ -564,6 +1231,9 @@ fn retrieve_all_new_process_info(
     } else if parent_pid.is_some() {
         p.thread_kind = Some(ThreadKind::Userland);
     }
+    if p.thread_kind != Some(ThreadKind::Kernel) {
+        p.exe_id = exe_id(&mut proc_path);
+    }
 
     update_proc_info(
         &mut p,
@@ -573,11 +1243,16 @@ fn retrieve_all_new_process_info(
         &parts.str_parts,
         uptime,
         info,
+        false,
     );
 
-    Process { inner: p }
+    Process {
+        inner: p,
+        last_refreshed_at: None,
+    }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn update_existing_process(
     proc: &mut Process,
     parent_pid: Option<Pid>,
@@ -585,35 +1260,84 @@ fn update_existing_process(
     info: &SystemInfo,
     refresh_kind: ProcessRefreshKind,
     tasks: Option<HashSet<Pid>>,
+    prefetched_stat: Option<&[u8]>,
+) -> Result<Option<Process>, ()> {
+    with_scratch_buffer(|data| {
+        update_existing_process_with_buffer(
+            proc,
+            parent_pid,
+            uptime,
+            info,
+            refresh_kind,
+            tasks,
+            prefetched_stat,
+            data,
+        )
+    })
+}
+
+#[allow(clippy::too_many_arguments)]
+fn update_existing_process_with_buffer(
+    proc: &mut Process,
+    parent_pid: Option<Pid>,
+    uptime: u64,
+    info: &SystemInfo,
+    refresh_kind: ProcessRefreshKind,
+    tasks: Option<HashSet<Pid>>,
+    prefetched_stat: Option<&[u8]>,
+    data: &mut Vec<u8>,
 ) -> Result<Option<Process>, ()> {
     let entry = &mut proc.inner;
-    let data = if let Some(mut f) = entry.stat_file.take() {
-        match get_all_data_from_file(&mut f, 1024) {
-            Ok(data) => {
-                // Everything went fine, we put back the file descriptor.
-                entry.stat_file = Some(f);
-                data
-            }
-            Err(_) => {
-                // It's possible that the file descriptor is no longer valid in case the
-                // original process was terminated and another one took its place.
-                _get_stat_data(&entry.proc_path, &mut entry.stat_file)?
-            }
+    if let Some(prefetched_stat) = prefetched_stat {
+        // The kept `stat` file was already read, no need to read it again.
+        data.clear();
+        data.extend_from_slice(prefetched_stat);
+    } else if let Some(f) = entry.stat_file.take() {
+        if read_all_data_from_file(&f, data).is_ok() {
+            // Everything went fine, we put back the file descriptor.
+            entry.stat_file = Some(f);
+        } else {
+            // It's possible that the file descriptor is no longer valid in case the
+            // original process was terminated and another one took its place.
+            let file = _get_stat_data(&entry.proc_path, data)?;
+            entry.stat_file = FileCounter::new(file, &info.kept_files);
         }
     } else {
-        _get_stat_data(&entry.proc_path, &mut entry.stat_file)?
-    };
+        let file = _get_stat_data(&entry.proc_path, data)?;
+        entry.stat_file = FileCounter::new(file, &info.kept_files);
+    }
     entry.tasks = tasks;
 
-    let parts = parse_stat_file(&data).ok_or(())?;
+    let parts = parse_stat_file(data).ok_or(())?;
     let start_time_raw = start_time_raw(&parts);
 
     // It's possible that a new process took this same PID when the "original one" terminated.
     // If the start time differs, then it means it's not the same process anymore and that we
     // need to get all its information, hence why we check it here.
-    if start_time_raw == entry.start_time_raw {
+    //
+    // If we have a pidfd and the process didn't exit, we know for sure it's the same one. If it
+    // exited, it can still be listed (as a zombie) so we fall back to the start time.
+    let same_process = match entry.pidfd {
+        Some(ref pidfd) if !pidfd_has_exited(pidfd, 0) => true,
+        _ => start_time_raw == entry.start_time_raw,
+    };
+    if same_process {
+        update_pidfd(entry, info);
         let mut proc_path = PathHandler::new(&entry.proc_path);
 
+        // `exec` changes the name of the process (the `comm` field of the `stat` file), so the
+        // information which can change with it is refreshed.
+        let exec_detected = parts.short_exe != entry.name.as_bytes();
+        if exec_detected {
+            entry.name = info.intern_name(OsStr::from_bytes(parts.short_exe));
+            // But `prctl(PR_SET_NAME)` changes it too, so it's only counted as an `exec` if the
+            // executable changed as well (or if we don't have the rights to check it).
+            let exe_id = entry.exe_id.and_then(|_| exe_id(&mut proc_path));
+            if exe_id.is_none() || exe_id != entry.exe_id {
+                entry.exe_id = exe_id;
+                entry.exec_count += 1;
+            }
+        }
         update_proc_info(
             entry,
             parent_pid,
@@ -622,9 +1346,8 @@ fn update_existing_process(
             &parts.str_parts,
             uptime,
             info,
+            exec_detected,
         );
-
-        refresh_user_group_ids(entry, &mut proc_path, refresh_kind);
         return Ok(None);
     }
     // If we're here, it means that the PID still exists but it's a different process.
@@ -638,6 +1361,7 @@ fn update_existing_process(
         uptime,
     );
     *proc = p;
+    update_pidfd(&mut proc.inner, info);
     // Since this PID is already in the HashMap, no need to add it again.
     Ok(None)
 }
@@ -652,19 +1376,44 @@ pub(crate) fn _get_process_data(
     info: &SystemInfo,
     refresh_kind: ProcessRefreshKind,
     tasks: Option<HashSet<Pid>>,
+    prefetched_stat: Option<&[u8]>,
 ) -> Result<Option<Process>, ()> {
     if let Some(ref mut entry) = proc_list.get_mut(&pid) {
-        return update_existing_process(entry, parent_pid, uptime, info, refresh_kind, tasks);
+        return update_existing_process(
+            entry,
+            parent_pid,
+            uptime,
+            info,
+            refresh_kind,
+            tasks,
+            prefetched_stat,
+        );
     }
-    let mut stat_file = None;
-    let data = _get_stat_data(path, &mut stat_file)?;
-    let parts = parse_stat_file(&data).ok_or(())?;
+    with_scratch_buffer(|data| {
+        // The pidfd is opened before reading the `stat` file so we're sure they both refer to the
+        // same process.
+        let pidfd = if info.use_pidfds && parent_pid.is_none() {
+            pidfd_open(pid)
+        } else {
+            None
+        };
+        let stat_file = _get_stat_data(path, data)?;
+        let parts = parse_stat_file(data).ok_or(())?;
 
-    let mut new_process =
-        retrieve_all_new_process_info(pid, parent_pid, &parts, path, info, refresh_kind, uptime);
-    new_process.inner.stat_file = stat_file;
-    new_process.inner.tasks = tasks;
-    Ok(Some(new_process))
+        let mut new_process = retrieve_all_new_process_info(
+            pid,
+            parent_pid,
+            &parts,
+            path,
+            info,
+            refresh_kind,
+            uptime,
+        );
+        new_process.inner.stat_file = FileCounter::new(stat_file, &info.kept_files);
+        new_process.inner.pidfd = pidfd;
+        new_process.inner.tasks = tasks;
+        Ok(Some(new_process))
+    })
 }
 
 fn old_get_memory(entry: &mut ProcessInner, str_parts: &[&str], info: &SystemInfo) {
@@ -697,15 +1446,19 @@ fn get_memory(path: &Path, entry: &mut ProcessInner, info: &SystemInfo) -> bool
             return false;
         }
     };
-    let mut buf = Vec::new();
-    if let Err(_e) = file.read_to_end(&mut buf) {
-        sysinfo_debug!(
-            "Using old memory information (failed to read {:?}: {_e:?})",
-            path
-        );
-        return false;
-    }
-    let mut parts = buf.split(|c| *c == b' ');
+    // `statm` contains 7 numbers so it always fits in this buffer, no need to allocate.
+    let mut buf = [0; 256];
+    let len = match file.read(&mut buf) {
+        Ok(len) => len,
+        Err(_e) => {
+            sysinfo_debug!(
+                "Using old memory information (failed to read {:?}: {_e:?})",
+                path
+            );
+            return false;
+        }
+    };
+    let mut parts = buf[..len].split(|c| *c == b' ');
     entry.virtual_memory = parts
         .next()
         .map(slice_to_nb)
@@ -770,6 +1523,48 @@ where
     val
 }
 
+/// Size of the buffers used to read the kept `stat` files with io_uring. A `stat` file is
+/// usually less than 400 bytes long.
+#[cfg(feature = "io-uring")]
+const PREFETCHED_STAT_SIZE: usize = 1_024;
+
+/// Reads the kept `stat` files of the processes to update with as few io_uring submissions as
+/// possible. Processes whose `stat` file couldn't be read entirely are not returned, so they're
+/// read as usual. An error is returned if the ring failed and must not be used anymore.
+#[cfg(feature = "io-uring")]
+pub(crate) fn prefetch_stat_files(
+    proc_list: &HashMap<Pid, Process>,
+    ring: &mut crate::sys::io_uring::IoUring,
+    processes_to_update: ProcessesToUpdate<'_>,
+) -> std::io::Result<HashMap<Pid, Vec<u8>>> {
+    use std::os::fd::AsRawFd;
+
+    let mut entries = proc_list
+        .iter()
+        .filter(|(pid, _)| match processes_to_update {
+            ProcessesToUpdate::All => true,
+            ProcessesToUpdate::Some(pids) => pids.contains(pid),
+        })
+        .filter_map(|(pid, proc)| {
+            let fd = proc.inner.stat_file.as_ref()?.as_raw_fd();
+            Some((*pid, fd, Vec::with_capacity(PREFETCHED_STAT_SIZE)))
+        })
+        .collect::<Vec<_>>();
+    let mut reads = entries
+        .iter_mut()
+        .map(|(_, fd, buf)| (*fd, buf))
+        .collect::<Vec<_>>();
+    let results = ring.read_files(&mut reads)?;
+    Ok(entries
+        .into_iter()
+        .zip(results)
+        .filter_map(|((pid, _, buf), result)| match result {
+            Ok(len) if len < PREFETCHED_STAT_SIZE => Some((pid, buf)),
+            _ => None,
+        })
+        .collect())
+}
+
 /// We're forced to read the whole `/proc` folder because if a process died and another took its
 /// place, we need to get the task parent (if it's a task).
 pub(crate) fn refresh_procs(
@@ -779,6 +1574,7 @@ pub(crate) fn refresh_procs(
     info: &SystemInfo,
     processes_to_update: ProcessesToUpdate<'_>,
     refresh_kind: ProcessRefreshKind,
+    prefetched_stats: &HashMap<Pid, Vec<u8>>,
 ) -> usize {
     #[cfg(feature = "multithread")]
     use rayon::iter::ParallelIterator;
@@ -826,6 +1622,7 @@ pub(crate) fn refresh_procs(
                     info,
                     refresh_kind,
                     e.tasks,
+                    prefetched_stats.get(&e.pid).map(Vec::as_slice),
                 )
                 .ok()?;
                 nb_updated.fetch_add(1, Ordering::Relaxed);
@@ -912,47 +1709,64 @@ fn get_proc_tasks(path: &Path, parent_pid: Pid) -> Vec<ProcAndTasks> {
         .unwrap_or_default()
 }
 
-fn split_content(mut data: &[u8]) -> Vec<OsString> {
-    let mut out = Vec::with_capacity(10);
-    while let Some(pos) = data.iter().position(|c| *c == 0) {
-        let s = &data[..pos].trim_ascii();
-        if !s.is_empty() {
-            out.push(OsStr::from_bytes(s).to_os_string());
+/// Splits the NUL separated `data` into `out`. The strings of `out` which are the same as in
+/// `data` are kept as is and the other ones are overwritten, reusing their allocation when
+/// possible.
+///
+/// Returns the number of reused and (re)written strings.
+fn split_content_into(data: &[u8], out: &mut Vec<OsString>) -> (u64, u64) {
+    let (mut reused, mut allocated) = (0, 0);
+    let mut nb = 0;
+    for s in data.split(|c| *c == 0) {
+        let s = s.trim_ascii();
+        if s.is_empty() {
+            continue;
         }
-        data = &data[pos + 1..];
-    }
-    if !data.is_empty() {
-        let s = data.trim_ascii();
-        if !s.is_empty() {
-            out.push(OsStr::from_bytes(s).to_os_string());
+        match out.get_mut(nb) {
+            Some(existing) if existing.as_bytes() == s => reused += 1,
+            Some(existing) => {
+                existing.clear();
+                existing.push(OsStr::from_bytes(s));
+                allocated += 1;
+            }
+            None => {
+                out.push(OsStr::from_bytes(s).to_os_string());
+                allocated += 1;
+            }
         }
+        nb += 1;
     }
-    out
+    out.truncate(nb);
+    (reused, allocated)
 }
 
-fn copy_from_file(entry: &Path) -> Vec<OsString> {
-    match File::open(entry) {
-        Ok(mut f) => {
-            let mut data = Vec::with_capacity(16_384);
+#[cfg(test)]
+fn split_content(data: &[u8]) -> Vec<OsString> {
+    let mut out = Vec::new();
+    split_content_into(data, &mut out);
+    out
+}
 
-            if let Err(_e) = f.read_to_end(&mut data) {
-                sysinfo_debug!("Failed to read file in `copy_from_file`: {:?}", _e);
-                Vec::new()
-            } else {
-                split_content(&data)
-            }
+fn update_from_file(entry: &Path, out: &mut Vec<OsString>, info: &SystemInfo) {
+    with_scratch_buffer(|data| {
+        if let Err(_e) = File::open(entry).and_then(|mut f| f.read_to_end(data)) {
+            sysinfo_debug!("Failed to read file in `update_from_file`: {:?}", _e);
+            out.clear();
+            return;
         }
-        Err(_e) => {
-            sysinfo_debug!("Failed to open file in `copy_from_file`: {:?}", _e);
-            Vec::new()
-        }
-    }
+        let (reused, allocated) = split_content_into(data, out);
+        info.reused_strings.fetch_add(reused, Ordering::Relaxed);
+        info.allocated_strings
+            .fetch_add(allocated, Ordering::Relaxed);
+    });
 }
 
 // Fetch tuples of real and effective UID and GID.
 fn get_uid_and_gid(file_path: &Path) -> Option<((uid_t, uid_t), (gid_t, gid_t))> {
-    let status_data = get_all_utf8_data(file_path, 16_385).ok()?;
+    with_scratch_buffer(|buf| parse_uid_and_gid(read_utf8_data(file_path, buf).ok()?))
+}
 
+fn parse_uid_and_gid(status_data: &str) -> Option<((uid_t, uid_t), (gid_t, gid_t))> {
     // We're only interested in the lines starting with Uid: and Gid:
     // here. From these lines, we're looking at the first and second entries to get
     // the real u/gid.
@@ -998,46 +1812,138 @@ fn get_uid_and_gid(file_path: &Path) -> Option<((uid_t, uid_t), (gid_t, gid_t))>
 
 fn get_tgid(file_path: &Path) -> Option<Pid> {
     const TGID_KEY: &str = "Tgid:";
-    let status_data = get_all_utf8_data(file_path, 16_385).ok()?;
-    let tgid_line = status_data
-        .lines()
-        .find(|line| line.starts_with(TGID_KEY))?;
-    tgid_line[TGID_KEY.len()..].trim_start().parse().ok()
-}
-
-struct Parts<'a> {
-    str_parts: Vec<&'a str>,
-    short_exe: &'a [u8],
-}
-
-fn parse_stat_file(data: &[u8]) -> Option<Parts<'_>> {
-    // The stat file is "interesting" to parse, because spaces cannot
-    // be used as delimiters. The second field stores the command name
-    // surrounded by parentheses. Unfortunately, whitespace and
-    // parentheses are legal parts of the command, so parsing has to
-    // proceed like this: The first field is delimited by the first
-    // whitespace, the second field is everything until the last ')'
-    // in the entire string. All other fields are delimited by
-    // whitespace.
-
-    let mut str_parts = Vec::with_capacity(51);
-    let mut data_it = data.splitn(2, |&b| b == b' ');
-    str_parts.push(str::from_utf8(data_it.next()?).ok()?);
-    let mut data_it = data_it.next()?.rsplitn(2, |&b| b == b')');
-    let data = str::from_utf8(data_it.next()?).ok()?;
-    let short_exe = data_it.next()?;
-    str_parts.extend(data.split_whitespace());
-    Some(Parts {
-        str_parts,
-        short_exe: short_exe.strip_prefix(b"(").unwrap_or(short_exe),
+    with_scratch_buffer(|buf| {
+        let status_data = read_utf8_data(file_path, buf).ok()?;
+        let tgid_line = status_data
+            .lines()
+            .find(|line| line.starts_with(TGID_KEY))?;
+        tgid_line[TGID_KEY.len()..].trim_start().parse().ok()
     })
 }
 
-/// Type used to correctly handle the `REMAINING_FILES` global.
-struct FileCounter(File);
+/// Returns the device and inode of the executable of the process at `proc_path`.
+///
+/// `statx` is called with a mask so the kernel only retrieves the inode (the device is always
+/// filled) instead of all the attributes of the file like `stat` does.
+#[cfg(any(target_env = "gnu", target_env = "musl", target_os = "android"))]
+fn exe_id(proc_path: &mut PathHandler) -> Option<(u64, u64)> {
+    let path = proc_path.replace_and_join("exe");
+    let c_path = std::ffi::CString::new(path.as_os_str().as_bytes()).ok()?;
+    let mut stx = MaybeUninit::<libc::statx>::uninit();
+    if unsafe {
+        libc::statx(
+            libc::AT_FDCWD,
+            c_path.as_ptr(),
+            0,
+            libc::STATX_INO,
+            stx.as_mut_ptr(),
+        )
+    } != 0
+    {
+        // `statx` was added in Linux 4.11.
+        if std::io::Error::last_os_error().raw_os_error() == Some(libc::ENOSYS) {
+            return exe_id_from_metadata(path);
+        }
+        return None;
+    }
+    let stx = unsafe { stx.assume_init() };
+    if stx.stx_mask & libc::STATX_INO == 0 {
+        return exe_id_from_metadata(path);
+    }
+    Some((
+        libc::makedev(stx.stx_dev_major, stx.stx_dev_minor) as _,
+        stx.stx_ino,
+    ))
+}
+
+#[cfg(not(any(target_env = "gnu", target_env = "musl", target_os = "android")))]
+fn exe_id(proc_path: &mut PathHandler) -> Option<(u64, u64)> {
+    exe_id_from_metadata(proc_path.replace_and_join("exe"))
+}
+
+fn exe_id_from_metadata(exe: &Path) -> Option<(u64, u64)> {
+    use std::os::unix::fs::MetadataExt;
+
+    let metadata = fs::metadata(exe).ok()?;
+    Some((metadata.dev(), metadata.ino()))
+}
+
+/// Opens a pidfd referring to `pid`. It requires Linux 5.3 or newer.
+fn pidfd_open(pid: Pid) -> Option<OwnedFd> {
+    let fd = unsafe { libc::syscall(libc::SYS_pidfd_open, pid.0, 0) };
+    if fd < 0 {
+        sysinfo_debug!("pidfd_open failed for {pid}");
+        None
+    } else {
+        Some(unsafe { OwnedFd::from_raw_fd(fd as _) })
+    }
+}
+
+/// Returns `true` if the process referred by `pidfd` exited. It waits up to `timeout`
+/// milliseconds (forever if negative) for it to happen.
+/// Converts the information filled by `waitid` into the status `waitpid` would have returned.
+fn exit_status_from_siginfo(info: &libc::siginfo_t) -> Option<ExitStatus> {
+    use std::os::unix::process::ExitStatusExt;
+
+    let status = unsafe { info.si_status() };
+    match info.si_code {
+        libc::CLD_EXITED => Some(ExitStatus::from_raw((status & 0xff) << 8)),
+        libc::CLD_KILLED => Some(ExitStatus::from_raw(status & 0x7f)),
+        libc::CLD_DUMPED => Some(ExitStatus::from_raw((status & 0x7f) | 0x80)),
+        _ => None,
+    }
+}
+
+fn pidfd_has_exited(pidfd: &OwnedFd, timeout: libc::c_int) -> bool {
+    let mut poll_fd = libc::pollfd {
+        fd: pidfd.as_raw_fd(),
+        events: libc::POLLIN,
+        revents: 0,
+    };
+    retry_eintr!(unsafe { libc::poll(&mut poll_fd, 1, timeout) }) > 0
+}
+
+/// Opens or closes the pidfd of `p` depending on the `System` settings. Threads are skipped.
+fn update_pidfd(p: &mut ProcessInner, info: &SystemInfo) {
+    if !info.use_pidfds {
+        p.pidfd = None;
+    } else if p.pidfd.is_none() && p.thread_kind.is_none() {
+        p.pidfd = pidfd_open(p.pid);
+    }
+}
+
+pub(crate) fn close_pidfds(proc_list: &mut HashMap<Pid, Process>) {
+    for proc_ in proc_list.values_mut() {
+        proc_.inner.pidfd = None;
+    }
+}
+
+/// Closes kept `stat` files until there are no more than the maximum allowed by `kept_files`.
+pub(crate) fn close_extra_stat_files(
+    proc_list: &mut HashMap<Pid, Process>,
+    kept_files: &KeptFiles,
+) {
+    for proc_ in proc_list.values_mut() {
+        if kept_files.used.load(Ordering::Relaxed) <= kept_files.max.load(Ordering::Relaxed) {
+            break;
+        }
+        proc_.inner.stat_file = None;
+    }
+}
+
+/// Type used to correctly handle the `REMAINING_FILES` global and the `System` limit.
+struct FileCounter(File, Arc<KeptFiles>);
 
 impl FileCounter {
-    fn new(f: File) -> Option<Self> {
+    fn new(f: File, kept_files: &Arc<KeptFiles>) -> Option<Self> {
+        let max = kept_files.max.load(Ordering::Relaxed);
+        kept_files
+            .used
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |used| {
+                // This `System` already keeps as many files as it's allowed to.
+                (used < max).then_some(used + 1)
+            })
+            .ok()?;
         let any_remaining =
             remaining_files().fetch_update(Ordering::SeqCst, Ordering::SeqCst, |remaining| {
                 if remaining > 0 {
@@ -1048,7 +1954,11 @@ impl FileCounter {
                 }
             });
 
-        any_remaining.ok().map(|_| Self(f))
+        if any_remaining.is_err() {
+            kept_files.used.fetch_sub(1, Ordering::Relaxed);
+            return None;
+        }
+        Some(Self(f, Arc::clone(kept_files)))
     }
 }
 
@@ -1068,14 +1978,30 @@ impl std::ops::DerefMut for FileCounter {
 impl Drop for FileCounter {
     fn drop(&mut self) {
         remaining_files().fetch_add(1, Ordering::Relaxed);
+        self.1.used.fetch_sub(1, Ordering::Relaxed);
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::split_content;
+    use super::{
+        count_sockets, get_known_tty_name, is_audio_capture_device, parse_drm_fdinfo,
+        parse_pactl_source_outputs, parse_tcp_listening_sockets, split_content, split_content_into,
+    };
+    use crate::Pid;
+    use std::collections::{HashMap, HashSet};
     use std::ffi::OsString;
 
+    #[test]
+    fn test_split_content_into_reuses_strings() {
+        let mut out = Vec::new();
+        assert_eq!(split_content_into(b"ls\0-l\0", &mut out), (0, 2));
+        assert_eq!(split_content_into(b"ls\0-la\0/tmp", &mut out), (1, 2));
+        assert_eq!(out, vec![OsString::from("ls"), "-la".into(), "/tmp".into()]);
+        assert_eq!(split_content_into(b"ls\0", &mut out), (1, 0));
+        assert_eq!(out, vec![OsString::from("ls")]);
+    }
+
     // This test ensures that all the parts of the data are split.
     #[test]
     fn test_copy_file() {
@@ -1090,4 +2016,128 @@ mod tests {
             vec![OsString::from("hello"), "b".into()]
         );
     }
+
+    #[test]
+    fn test_parse_drm_fdinfo() {
+        let mut clients = HashSet::new();
+        let mut engines = HashMap::new();
+
+        parse_drm_fdinfo("pos:\t0\nflags:\t02\n", &mut clients, &mut engines);
+        assert!(clients.is_empty());
+        assert!(engines.is_empty());
+
+        let fdinfo = "pos:\t0
+flags:\t02100002
+drm-driver:\ti915
+drm-pdev:\t0000:00:02.0
+drm-client-id:\t7
+drm-engine-render:\t9288864723 ns
+drm-engine-copy:\t2035071108 ns
+drm-engine-capacity-video:\t2
+";
+        parse_drm_fdinfo(fdinfo, &mut clients, &mut engines);
+        // The same client opened through another file descriptor must not be counted twice.
+        parse_drm_fdinfo(fdinfo, &mut clients, &mut engines);
+        parse_drm_fdinfo(
+            "drm-pdev:\t0000:00:02.0\ndrm-client-id:\t8\ndrm-engine-render:\t12 ns\n",
+            &mut clients,
+            &mut engines,
+        );
+        assert_eq!(clients.len(), 2);
+        assert_eq!(engines.len(), 2);
+        assert_eq!(engines["0000:00:02.0/render"], 9288864735);
+        assert_eq!(engines["0000:00:02.0/copy"], 2035071108);
+    }
+
+    #[test]
+    fn test_is_audio_capture_device() {
+        assert!(is_audio_capture_device("snd/pcmC0D0c"));
+        assert!(is_audio_capture_device("snd/pcmC1D12c"));
+        assert!(!is_audio_capture_device("snd/pcmC0D0p"));
+        assert!(!is_audio_capture_device("snd/controlC0"));
+        assert!(!is_audio_capture_device("video0"));
+    }
+
+    #[test]
+    fn test_parse_pactl_source_outputs() {
+        let output = "\
+Source Output #42
+\tDriver: protocol-native.c
+\tClient: 37
+\tCorked: no
+\tMute: no
+\tProperties:
+\t\tmedia.name = \"Record Stream\"
+\t\tapplication.process.id = \"1234\"
+
+Source Output #43
+\tDriver: protocol-native.c
+\tCorked: yes
+\tProperties:
+\t\tapplication.process.id = \"5678\"
+
+Source Output #44
+\tDriver: protocol-native.c
+\tCorked: no
+\tProperties:
+\t\tmedia.name = \"Peak detect\"
+
+Source Output #45
+\tCorked: no
+\tProperties:
+\t\tapplication.process.id = \"91\"
+";
+        assert_eq!(
+            parse_pactl_source_outputs(output),
+            vec![Pid::from(1234), Pid::from(91)],
+        );
+        assert!(parse_pactl_source_outputs("").is_empty());
+    }
+
+    #[test]
+    #[cfg(target_endian = "little")]
+    fn test_parse_tcp_listening_sockets() {
+        let tcp = "\
+  sl  local_address rem_address   st tx_queue rx_queue tr tm->when retrnsmt   uid  timeout inode
+   0: 0100007F:0277 00000000:0000 0A 00000000:00000000 00:00000000 00000000     0        0 1234 1 0000000000000000 100 0 0 10 0
+   1: 00000000:0016 00000000:0000 0A 00000000:00000000 00:00000000 00000000     0        0 5678 1 0000000000000000 100 0 0 10 0
+   2: 0100007F:A1B2 0100007F:0277 01 00000000:00000000 00:00000000 00000000  1000        0 1234 1 0000000000000000 20 4 30 10 -1
+";
+        let tcp6 = "\
+  sl  local_address                         remote_address                        st tx_queue rx_queue tr tm->when retrnsmt   uid  timeout inode
+   0: 00000000000000000000000001000000:1F90 00000000000000000000000000000000:0000 0A 00000000:00000000 00:00000000 00000000  1000        0 4321 1 0000000000000000 100 0 0 10 0
+";
+        let inodes = HashSet::from([1234, 4321]);
+        let mut ports = Vec::new();
+        parse_tcp_listening_sockets(tcp, &inodes, &mut ports);
+        parse_tcp_listening_sockets(tcp6, &inodes, &mut ports);
+        assert_eq!(
+            ports,
+            [
+                "127.0.0.1:631".parse().unwrap(),
+                "[::1]:8080".parse().unwrap(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_count_sockets() {
+        let udp = "\
+   sl  local_address rem_address   st tx_queue rx_queue tr tm->when retrnsmt   uid  timeout inode ref pointer drops
+  253: 00000000:0044 00000000:0000 07 00000000:00000000 00:00000000 00000000     0        0 1234 2 0000000000000000 0
+  610: 3500007F:0035 00000000:0000 07 00000000:00000000 00:00000000 00000000   101        0 5678 2 0000000000000000 0
+  611: 0100007F:A1B2 0100007F:0035 01 00000000:00000000 00:00000000 00000000  1000        0 4321 2 0000000000000000 0
+";
+        assert_eq!(count_sockets(udp, &HashSet::from([1234, 4321, 9999])), 2);
+        assert_eq!(count_sockets(udp, &HashSet::new()), 0);
+    }
+
+    #[test]
+    fn test_get_known_tty_name() {
+        assert_eq!(get_known_tty_name(136, 0).as_deref(), Some("pts/0"));
+        assert_eq!(get_known_tty_name(137, 2).as_deref(), Some("pts/258"));
+        assert_eq!(get_known_tty_name(4, 1).as_deref(), Some("tty1"));
+        assert_eq!(get_known_tty_name(4, 65).as_deref(), Some("ttyS1"));
+        assert_eq!(get_known_tty_name(5, 0), None);
+    }
 }
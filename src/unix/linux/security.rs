@@ -0,0 +1,220 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::FirewallProfile;
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const EFI_GLOBAL_VARIABLE_GUID: &str = "8be4df61-93ca-11d2-aa0d-00e098032b8c";
+
+/// Returns the device number (`major:minor`) and the source of the file system mounted on `/`
+/// from the content of `/proc/self/mountinfo`.
+fn parse_root_mount(mountinfo: &str) -> Option<(&str, &str)> {
+    // If multiple file systems are mounted on `/`, the last one is the visible one.
+    mountinfo.lines().rev().find_map(|line| {
+        let (fields, rest) = line.split_once(" - ")?;
+        let mut fields = fields.split(' ');
+        let device = fields.nth(2)?;
+        if fields.nth(1)? != "/" {
+            return None;
+        }
+        let source = rest.split(' ').nth(1)?;
+        Some((device, source))
+    })
+}
+
+/// Returns `true` if `device` (a directory of `/sys/class/block`) is a `dm-crypt` device or is
+/// built on top of one (like LVM on LUKS).
+fn is_encrypted_block_device(device: &Path, class_block: &Path, depth: usize) -> bool {
+    if fs::read_to_string(device.join("dm/uuid")).is_ok_and(|uuid| uuid.starts_with("CRYPT-")) {
+        return true;
+    }
+    if depth == 0 {
+        return false;
+    }
+    let Ok(slaves) = fs::read_dir(device.join("slaves")) else {
+        return false;
+    };
+    slaves.flatten().any(|slave| {
+        is_encrypted_block_device(&class_block.join(slave.file_name()), class_block, depth - 1)
+    })
+}
+
+fn get_root_block_device() -> Option<PathBuf> {
+    let mountinfo = fs::read_to_string("/proc/self/mountinfo").ok()?;
+    let (device, source) = parse_root_mount(&mountinfo)?;
+    let path = Path::new("/sys/dev/block").join(device);
+    if path.exists() {
+        return Some(path);
+    }
+    // Some file systems (like btrfs) use an anonymous device number so we use the mount source.
+    let source = fs::canonicalize(source).ok()?;
+    let path = Path::new("/sys/class/block").join(source.file_name()?);
+    path.exists().then_some(path)
+}
+
+/// Returns `true` if `ufw` is enabled in its configuration file (`/etc/ufw/ufw.conf`).
+fn parse_ufw_conf(conf: &str) -> bool {
+    conf.lines().any(|line| {
+        line.trim()
+            .strip_prefix("ENABLED=")
+            .is_some_and(|value| value.trim_matches(['"', '\'']).eq_ignore_ascii_case("yes"))
+    })
+}
+
+/// Returns `true` if the systemd `unit` is active. systemd keeps an `invocation:` link in
+/// `/run/systemd/units` for each active unit.
+fn is_systemd_unit_active(unit: &str) -> bool {
+    fs::symlink_metadata(Path::new("/run/systemd/units").join(format!("invocation:{unit}"))).is_ok()
+}
+
+pub(crate) struct SecurityInfoInner;
+
+impl SecurityInfoInner {
+    pub(crate) fn secure_boot_enabled() -> Option<bool> {
+        if !Path::new("/sys/firmware/efi").exists() {
+            // Not booted with UEFI.
+            return Some(false);
+        }
+        // With `efivarfs`, the first 4 bytes contain the variable attributes.
+        if let Ok(data) = fs::read(format!(
+            "/sys/firmware/efi/efivars/SecureBoot-{EFI_GLOBAL_VARIABLE_GUID}"
+        )) {
+            return data.get(4).map(|value| *value == 1);
+        }
+        // Legacy `sysfs-efivars` interface.
+        let data = fs::read(format!(
+            "/sys/firmware/efi/vars/SecureBoot-{EFI_GLOBAL_VARIABLE_GUID}/data"
+        ))
+        .ok()?;
+        data.first().map(|value| *value == 1)
+    }
+
+    pub(crate) fn tpm_present() -> Option<bool> {
+        match fs::read_dir("/sys/class/tpm") {
+            Ok(mut entries) => Some(entries.next().is_some()),
+            Err(_) => Path::new("/sys/class").exists().then_some(false),
+        }
+    }
+
+    pub(crate) fn root_disk_encrypted() -> Option<bool> {
+        let device = get_root_block_device()?;
+        // We limit the depth in case of broken `slaves` links.
+        Some(is_encrypted_block_device(
+            &device,
+            Path::new("/sys/class/block"),
+            8,
+        ))
+    }
+
+    pub(crate) fn firewall_status() -> Vec<FirewallProfile> {
+        let mut firewalls = Vec::new();
+        if let Ok(conf) = fs::read_to_string("/etc/ufw/ufw.conf") {
+            firewalls.push(FirewallProfile {
+                name: "ufw".to_owned(),
+                enabled: parse_ufw_conf(&conf),
+            });
+        }
+        for (name, unit, config_paths) in [
+            (
+                "firewalld",
+                "firewalld.service",
+                &["/etc/firewalld", "/usr/lib/firewalld"][..],
+            ),
+            (
+                "nftables",
+                "nftables.service",
+                &["/etc/nftables.conf", "/etc/sysconfig/nftables.conf"][..],
+            ),
+        ] {
+            let enabled = is_systemd_unit_active(unit);
+            if enabled || config_paths.iter().any(|path| Path::new(path).exists()) {
+                firewalls.push(FirewallProfile {
+                    name: name.to_owned(),
+                    enabled,
+                });
+            }
+        }
+        firewalls
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_encrypted_block_device, parse_root_mount, parse_ufw_conf};
+
+    use std::fs;
+
+    #[test]
+    fn test_parse_root_mount() {
+        let mountinfo = "\
+22 1 253:1 / / rw,relatime shared:1 - ext4 /dev/mapper/vg-root rw
+23 22 0:21 / /proc rw,nosuid,nodev,noexec,relatime shared:5 - proc proc rw
+24 22 8:1 / /boot rw,relatime shared:30 - ext4 /dev/sda1 rw
+";
+        assert_eq!(
+            parse_root_mount(mountinfo),
+            Some(("253:1", "/dev/mapper/vg-root"))
+        );
+        assert_eq!(
+            parse_root_mount("23 22 0:21 / /proc rw shared:5 - proc proc rw\n"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_is_encrypted_block_device() {
+        let class_block = tempfile::tempdir().unwrap();
+        let class_block = class_block.path();
+        // `dm-1` is an LVM volume on top of `dm-0`, which is a LUKS device on top of `sda2`.
+        for (device, uuid, slave) in [
+            ("sda2", None, None),
+            ("dm-0", Some("CRYPT-LUKS2-0123-root"), Some("sda2")),
+            ("dm-1", Some("LVM-abcdef"), Some("dm-0")),
+            ("dm-2", Some("LVM-ghijkl"), Some("sda2")),
+        ] {
+            let path = class_block.join(device);
+            fs::create_dir_all(path.join("slaves")).unwrap();
+            if let Some(uuid) = uuid {
+                fs::create_dir(path.join("dm")).unwrap();
+                fs::write(path.join("dm/uuid"), uuid).unwrap();
+            }
+            if let Some(slave) = slave {
+                fs::create_dir(path.join("slaves").join(slave)).unwrap();
+            }
+        }
+        assert!(!is_encrypted_block_device(
+            &class_block.join("sda2"),
+            class_block,
+            8
+        ));
+        assert!(is_encrypted_block_device(
+            &class_block.join("dm-0"),
+            class_block,
+            8
+        ));
+        assert!(is_encrypted_block_device(
+            &class_block.join("dm-1"),
+            class_block,
+            8
+        ));
+        assert!(!is_encrypted_block_device(
+            &class_block.join("dm-1"),
+            class_block,
+            0
+        ));
+        assert!(!is_encrypted_block_device(
+            &class_block.join("dm-2"),
+            class_block,
+            8
+        ));
+    }
+
+    #[test]
+    fn test_parse_ufw_conf() {
+        assert!(parse_ufw_conf("# comment\nENABLED=yes\nLOGLEVEL=low\n"));
+        assert!(parse_ufw_conf("ENABLED=\"yes\"\n"));
+        assert!(!parse_ufw_conf("ENABLED=no\nLOGLEVEL=low\n"));
+        assert!(!parse_ufw_conf("#ENABLED=yes\n"));
+    }
+}
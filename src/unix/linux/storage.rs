@@ -0,0 +1,336 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::{Volume, VolumeDevice, VolumeKind, VolumeState};
+
+use std::fs;
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+/// Places where `pvs` is installed, as it might not be in the `PATH` of services.
+const PVS_PATHS: &[&str] = &["/sbin/pvs", "/usr/sbin/pvs"];
+
+pub(crate) struct StorageInner;
+
+impl StorageInner {
+    pub(crate) fn volumes() -> Vec<Volume> {
+        let sys = Path::new("/sys");
+        let mut volumes = get_raid_volumes(sys);
+        if has_lvm_devices(sys) {
+            volumes.extend(get_lvm_volumes());
+        }
+        volumes.extend(get_btrfs_volumes(sys));
+        volumes
+    }
+}
+
+fn read_trimmed(path: &Path) -> Option<String> {
+    fs::read_to_string(path).ok().map(|s| s.trim().to_owned())
+}
+
+/// Lists the `md` devices from `/sys/block`.
+fn get_raid_volumes(sys: &Path) -> Vec<Volume> {
+    let Ok(entries) = fs::read_dir(sys.join("block")) else {
+        return Vec::new();
+    };
+    let mut volumes = entries
+        .flatten()
+        .filter_map(|entry| {
+            let name = entry.file_name().into_string().ok()?;
+            if !name.starts_with("md") {
+                return None;
+            }
+            let md = entry.path().join("md");
+            let level = read_trimmed(&md.join("level"))?;
+            let devices = fs::read_dir(&md)
+                .ok()?
+                .flatten()
+                .filter_map(|entry| {
+                    let name = entry.file_name().to_str()?.strip_prefix("dev-")?.to_owned();
+                    let state = read_trimmed(&entry.path().join("state")).unwrap_or_default();
+                    Some(VolumeDevice {
+                        name,
+                        is_faulty: state.split(',').any(|flag| flag == "faulty"),
+                        error_count: read_trimmed(&entry.path().join("errors"))
+                            .and_then(|errors| errors.parse().ok()),
+                    })
+                })
+                .collect::<Vec<_>>();
+            let state = match read_trimmed(&md.join("array_state")).as_deref() {
+                Some("inactive" | "clear") => VolumeState::Failed,
+                None => VolumeState::Unknown,
+                // Arrays without redundancy (like `raid0`) don't have a `degraded` file.
+                Some(_) => match read_trimmed(&md.join("degraded")) {
+                    Some(degraded) if degraded != "0" => {
+                        if read_trimmed(&md.join("sync_action")).as_deref() == Some("recover") {
+                            VolumeState::Rebuilding
+                        } else {
+                            VolumeState::Degraded
+                        }
+                    }
+                    Some(_) => VolumeState::Healthy,
+                    None if devices.iter().any(|device| device.is_faulty) => VolumeState::Failed,
+                    None => VolumeState::Healthy,
+                },
+            };
+            Some(Volume {
+                name,
+                kind: VolumeKind::Raid,
+                level: Some(level),
+                state,
+                devices,
+            })
+        })
+        .collect::<Vec<_>>();
+    volumes.sort_unstable_by(|a, b| a.name.cmp(&b.name));
+    volumes
+}
+
+/// Returns `true` if a device-mapper device belongs to LVM, to avoid running `pvs` for nothing.
+fn has_lvm_devices(sys: &Path) -> bool {
+    let Ok(entries) = fs::read_dir(sys.join("block")) else {
+        return false;
+    };
+    entries.flatten().any(|entry| {
+        read_trimmed(&entry.path().join("dm/uuid")).is_some_and(|uuid| uuid.starts_with("LVM-"))
+    })
+}
+
+fn get_lvm_volumes() -> Vec<Volume> {
+    let pvs = PVS_PATHS
+        .iter()
+        .find(|path| Path::new(path).exists())
+        .copied()
+        .unwrap_or("pvs");
+    let Ok(output) = Command::new(pvs)
+        .args([
+            "--noheadings",
+            "--separator",
+            ";",
+            "-o",
+            "vg_name,vg_attr,pv_name,pv_attr",
+        ])
+        .stdin(Stdio::null())
+        .stderr(Stdio::null())
+        .output()
+    else {
+        return Vec::new();
+    };
+    if !output.status.success() {
+        return Vec::new();
+    }
+    parse_pvs_output(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// Parses the output of `pvs` (with the `vg_name,vg_attr,pv_name,pv_attr` fields) and groups
+/// the physical volumes by volume group.
+fn parse_pvs_output(output: &str) -> Vec<Volume> {
+    let mut volumes: Vec<Volume> = Vec::new();
+    for line in output.lines() {
+        let mut fields = line.trim().split(';');
+        let (Some(vg_name), Some(vg_attr), Some(pv_name), Some(pv_attr)) =
+            (fields.next(), fields.next(), fields.next(), fields.next())
+        else {
+            continue;
+        };
+        // Physical volumes which don't belong to a volume group.
+        if vg_name.is_empty() {
+            continue;
+        }
+        let device = VolumeDevice {
+            name: pv_name.to_owned(),
+            // The third character is `m` if the physical volume is missing.
+            is_faulty: pv_attr.chars().nth(2) == Some('m'),
+            error_count: None,
+        };
+        if let Some(volume) = volumes.iter_mut().find(|volume| volume.name == vg_name) {
+            volume.devices.push(device);
+            continue;
+        }
+        // The fourth character is `p` if some physical volumes of the group are missing.
+        let state = match vg_attr.chars().nth(3) {
+            Some('p') => VolumeState::Degraded,
+            Some(_) => VolumeState::Healthy,
+            None => VolumeState::Unknown,
+        };
+        volumes.push(Volume {
+            name: vg_name.to_owned(),
+            kind: VolumeKind::LvmVolumeGroup,
+            level: None,
+            state,
+            devices: vec![device],
+        });
+    }
+    volumes
+}
+
+/// Lists the btrfs file systems from `/sys/fs/btrfs`.
+fn get_btrfs_volumes(sys: &Path) -> Vec<Volume> {
+    let Ok(entries) = fs::read_dir(sys.join("fs/btrfs")) else {
+        return Vec::new();
+    };
+    let mut volumes = entries
+        .flatten()
+        .filter_map(|entry| {
+            let path = entry.path();
+            // Other entries are features, not file systems.
+            let devinfo = fs::read_dir(path.join("devinfo")).ok()?;
+            let mut devices = devinfo
+                .flatten()
+                .filter_map(|entry| {
+                    let name = entry.file_name().into_string().ok()?;
+                    let path = entry.path();
+                    // Available since Linux 5.14.
+                    let error_count = read_trimmed(&path.join("error_stats")).map(|stats| {
+                        stats
+                            .lines()
+                            .filter_map(|line| line.split_ascii_whitespace().nth(1)?.parse().ok())
+                            .fold(0u64, u64::saturating_add)
+                    });
+                    Some(VolumeDevice {
+                        name,
+                        is_faulty: read_trimmed(&path.join("missing")).as_deref() == Some("1"),
+                        error_count,
+                    })
+                })
+                .collect::<Vec<_>>();
+            devices.sort_unstable_by_key(|device| device.name.parse::<u64>().unwrap_or(u64::MAX));
+            let name = read_trimmed(&path.join("label"))
+                .filter(|label| !label.is_empty())
+                .or_else(|| entry.file_name().into_string().ok())?;
+            let state = if devices.iter().any(|device| device.is_faulty) {
+                VolumeState::Degraded
+            } else {
+                VolumeState::Healthy
+            };
+            Some(Volume {
+                name,
+                kind: VolumeKind::Btrfs,
+                level: None,
+                state,
+                devices,
+            })
+        })
+        .collect::<Vec<_>>();
+    volumes.sort_unstable_by(|a, b| a.name.cmp(&b.name));
+    volumes
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn write(path: &Path, content: &str) {
+        fs::create_dir_all(path.parent().unwrap()).expect("failed to create directory");
+        fs::write(path, content).expect("failed to write file");
+    }
+
+    #[test]
+    fn test_raid_volumes() {
+        let temp_dir = tempfile::tempdir().expect("failed to create temporary directory");
+        let block = temp_dir.path().join("block");
+        // A degraded `raid1` being rebuilt.
+        write(&block.join("md0/md/level"), "raid1\n");
+        write(&block.join("md0/md/array_state"), "clean\n");
+        write(&block.join("md0/md/degraded"), "1\n");
+        write(&block.join("md0/md/sync_action"), "recover\n");
+        write(&block.join("md0/md/dev-sda1/state"), "faulty\n");
+        write(&block.join("md0/md/dev-sda1/errors"), "12\n");
+        write(&block.join("md0/md/dev-sdb1/state"), "in_sync\n");
+        write(&block.join("md0/md/dev-sdb1/errors"), "0\n");
+        // A `raid0` with a faulty device.
+        write(&block.join("md1/md/level"), "raid0\n");
+        write(&block.join("md1/md/array_state"), "active\n");
+        write(&block.join("md1/md/dev-sdc1/state"), "faulty\n");
+        // Not a RAID array.
+        write(&block.join("sda/size"), "1024\n");
+
+        let mut volumes = get_raid_volumes(temp_dir.path());
+        assert_eq!(volumes.len(), 2);
+        volumes[0]
+            .devices
+            .sort_unstable_by(|a, b| a.name.cmp(&b.name));
+        assert_eq!(
+            volumes[0],
+            Volume {
+                name: "md0".to_owned(),
+                kind: VolumeKind::Raid,
+                level: Some("raid1".to_owned()),
+                state: VolumeState::Rebuilding,
+                devices: vec![
+                    VolumeDevice {
+                        name: "sda1".to_owned(),
+                        is_faulty: true,
+                        error_count: Some(12),
+                    },
+                    VolumeDevice {
+                        name: "sdb1".to_owned(),
+                        is_faulty: false,
+                        error_count: Some(0),
+                    },
+                ],
+            }
+        );
+        assert_eq!(volumes[1].state, VolumeState::Failed);
+    }
+
+    #[test]
+    fn test_btrfs_volumes() {
+        let temp_dir = tempfile::tempdir().expect("failed to create temporary directory");
+        let fs_dir = temp_dir.path().join("fs/btrfs");
+        let uuid = fs_dir.join("5f3e1b2c-0c1a-4d6e-9b8f-1a2b3c4d5e6f");
+        write(&uuid.join("label"), "data\n");
+        write(
+            &uuid.join("devinfo/1/error_stats"),
+            "write_errs 1\nread_errs 2\nflush_errs 0\ncorruption_errs 3\ngeneration_errs 0\n",
+        );
+        write(&uuid.join("devinfo/1/missing"), "0\n");
+        write(&uuid.join("devinfo/2/missing"), "1\n");
+        // Not a file system.
+        write(&fs_dir.join("features/zoned"), "0\n");
+
+        assert_eq!(
+            get_btrfs_volumes(temp_dir.path()),
+            [Volume {
+                name: "data".to_owned(),
+                kind: VolumeKind::Btrfs,
+                level: None,
+                state: VolumeState::Degraded,
+                devices: vec![
+                    VolumeDevice {
+                        name: "1".to_owned(),
+                        is_faulty: false,
+                        error_count: Some(6),
+                    },
+                    VolumeDevice {
+                        name: "2".to_owned(),
+                        is_faulty: true,
+                        error_count: None,
+                    },
+                ],
+            }]
+        );
+    }
+
+    #[test]
+    fn test_parse_pvs_output() {
+        let output = "  vg0;wz--n-;/dev/sda2;a--\n  \
+            vg1;wz-pn-;/dev/sdb1;a--\n  \
+            vg1;wz-pn-;[unknown];a-m\n  \
+            ;;/dev/sdc1;---\n";
+        let volumes = parse_pvs_output(output);
+        assert_eq!(volumes.len(), 2);
+        assert_eq!(volumes[0].name, "vg0");
+        assert_eq!(volumes[0].state, VolumeState::Healthy);
+        assert_eq!(volumes[0].devices.len(), 1);
+        assert_eq!(volumes[1].name, "vg1");
+        assert_eq!(volumes[1].state, VolumeState::Degraded);
+        assert_eq!(
+            volumes[1]
+                .devices
+                .iter()
+                .map(|device| (device.name.as_str(), device.is_faulty))
+                .collect::<Vec<_>>(),
+            [("/dev/sdb1", false), ("[unknown]", true)]
+        );
+    }
+}
@@ -1,25 +1,35 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
-use crate::sys::cpu::{CpusWrapper, get_physical_core_count};
-use crate::sys::process::{compute_cpu_usage, refresh_procs};
+use crate::parsing::parse_swaps_line;
+use crate::sys::cpu::{
+    CpusWrapper, get_cpu_boost, get_global_boost, get_physical_core_count, get_vendor_id_and_brand,
+};
+use crate::sys::process::{
+    close_extra_stat_files, close_pidfds, compute_cpu_usage, refresh_procs,
+    update_sound_server_media_usage,
+};
 use crate::sys::utils::{get_all_utf8_data, to_u64};
+use crate::unix::utils::check_source;
 use crate::{
-    Cpu, CpuRefreshKind, LoadAvg, MemoryRefreshKind, Pid, Process, ProcessRefreshKind,
-    ProcessesToUpdate,
+    CompressedMemory, CoreDumpConfig, Cpu, CpuRefreshKind, CpuVulnerability,
+    CpuVulnerabilityStatus, InotifyUsage, LoadAvg, MemoryRefreshKind, Pid, Process,
+    ProcessRefreshKind, ProcessStringStats, ProcessesToUpdate, RefreshError, SwapDevice,
 };
 
 use libc::{self, _SC_CLK_TCK, _SC_HOST_NAME_MAX, _SC_PAGESIZE, c_char, sysconf};
 
 use std::cmp::min;
-use std::collections::HashMap;
-use std::ffi::CStr;
+use std::collections::{HashMap, HashSet};
+use std::ffi::{CStr, OsStr};
 use std::fs::File;
+use std::hash::Hash;
 use std::io::Read;
 use std::mem::MaybeUninit;
 use std::path::Path;
 use std::str::FromStr;
-use std::sync::{OnceLock, atomic::AtomicIsize};
-use std::time::Duration;
+use std::sync::atomic::{AtomicIsize, AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, Instant};
 
 unsafe fn getrlimit() -> Option<libc::rlimit> {
     let mut limits = libc::rlimit {
@@ -143,10 +153,26 @@ fn boot_time() -> u64 {
     }
 }
 
+/// Number of `stat` files kept open by the processes of a `System`.
+pub(crate) struct KeptFiles {
+    pub(crate) max: AtomicUsize,
+    pub(crate) used: AtomicUsize,
+}
+
 pub(crate) struct SystemInfo {
     pub(crate) page_size_b: u64,
     pub(crate) clock_cycle: u64,
     pub(crate) boot_time: u64,
+    /// Number of process strings kept as is during process refreshes.
+    pub(crate) reused_strings: AtomicU64,
+    /// Number of process strings (re)written during process refreshes.
+    pub(crate) allocated_strings: AtomicU64,
+    /// Process names shared between the processes.
+    names: Mutex<HashSet<Arc<OsStr>>>,
+    /// Executable paths shared between the processes.
+    exes: Mutex<HashSet<Arc<Path>>>,
+    pub(crate) kept_files: Arc<KeptFiles>,
+    pub(crate) use_pidfds: bool,
 }
 
 impl SystemInfo {
@@ -156,9 +182,57 @@ impl SystemInfo {
                 page_size_b: sysconf(_SC_PAGESIZE) as _,
                 clock_cycle: sysconf(_SC_CLK_TCK) as _,
                 boot_time: boot_time(),
+                reused_strings: AtomicU64::new(0),
+                allocated_strings: AtomicU64::new(0),
+                names: Mutex::new(HashSet::new()),
+                exes: Mutex::new(HashSet::new()),
+                kept_files: Arc::new(KeptFiles {
+                    max: AtomicUsize::new(usize::MAX),
+                    used: AtomicUsize::new(0),
+                }),
+                use_pidfds: false,
             }
         }
     }
+
+    /// Returns the process name `name`, shared with the other processes which have the same
+    /// one instead of being allocated again.
+    pub(crate) fn intern_name(&self, name: &OsStr) -> Arc<OsStr> {
+        self.intern(&self.names, name)
+    }
+
+    /// Returns the executable path `exe`, shared with the other processes which have the same
+    /// one instead of being allocated again.
+    pub(crate) fn intern_exe(&self, exe: &Path) -> Arc<Path> {
+        self.intern(&self.exes, exe)
+    }
+
+    fn intern<T: ?Sized + Eq + Hash>(&self, set: &Mutex<HashSet<Arc<T>>>, value: &T) -> Arc<T>
+    where
+        for<'a> Arc<T>: From<&'a T>,
+    {
+        let mut set = set.lock().unwrap_or_else(|error| error.into_inner());
+        if let Some(value) = set.get(value) {
+            self.reused_strings.fetch_add(1, Ordering::Relaxed);
+            return Arc::clone(value);
+        }
+        self.allocated_strings.fetch_add(1, Ordering::Relaxed);
+        let value = Arc::from(value);
+        set.insert(Arc::clone(&value));
+        value
+    }
+
+    /// Removes the names and executable paths which aren't used by any process anymore.
+    fn remove_unused_interned(&mut self) {
+        self.names
+            .get_mut()
+            .unwrap_or_else(|error| error.into_inner())
+            .retain(|name| Arc::strong_count(name) > 1);
+        self.exes
+            .get_mut()
+            .unwrap_or_else(|error| error.into_inner())
+            .retain(|exe| Arc::strong_count(exe) > 1);
+    }
 }
 
 pub(crate) struct SystemInner {
@@ -174,6 +248,9 @@ pub(crate) struct SystemInner {
     swap_free: u64,
     info: SystemInfo,
     cpus: CpusWrapper,
+    /// `None` until the first processes refresh, then `Some(None)` if io_uring isn't available.
+    #[cfg(feature = "io-uring")]
+    io_uring: Option<Option<crate::sys::io_uring::IoUring>>,
 }
 
 impl SystemInner {
@@ -208,7 +285,9 @@ impl SystemInner {
     }
 
     fn refresh_cpus(&mut self, only_update_global_cpu: bool, refresh_kind: CpuRefreshKind) {
-        self.cpus.refresh(only_update_global_cpu, refresh_kind);
+        if let Err(_e) = self.cpus.refresh(only_update_global_cpu, refresh_kind) {
+            sysinfo_debug!("failed to refresh CPUs: {_e:?}");
+        }
     }
 }
 
@@ -227,15 +306,34 @@ impl SystemInner {
             swap_free: 0,
             cpus: CpusWrapper::new(),
             info: SystemInfo::new(),
+            #[cfg(feature = "io-uring")]
+            io_uring: None,
         }
     }
 
     pub(crate) fn refresh_memory_specifics(&mut self, refresh_kind: MemoryRefreshKind) {
+        if let Err(_e) = self.try_refresh_memory_specifics(refresh_kind) {
+            sysinfo_debug!("failed to refresh memory: {_e:?}");
+        }
+    }
+
+    pub(crate) fn try_refresh_memory_specifics(
+        &mut self,
+        refresh_kind: MemoryRefreshKind,
+    ) -> Result<(), RefreshError> {
         if !refresh_kind.ram() && !refresh_kind.swap() {
-            return;
+            return Ok(());
+        }
+        let content = get_all_utf8_data("/proc/meminfo", 16_635)
+            .map_err(|e| RefreshError::from_io("/proc/meminfo", e))?;
+        // Without `MemTotal`, the content isn't the one we expect so we keep the previous values.
+        let mut mem_total_found = false;
+        parse_table(&content, ':', |key, _| mem_total_found |= key == "MemTotal");
+        if !mem_total_found {
+            return Err(RefreshError::Parse("/proc/meminfo".into()));
         }
         let mut mem_available_found = false;
-        read_table("/proc/meminfo", ':', |key, value_kib| {
+        parse_table(&content, ':', |key, value_kib| {
             let field = match key {
                 "MemTotal" => &mut self.mem_total,
                 "MemFree" => &mut self.mem_free,
@@ -266,6 +364,7 @@ impl SystemInner {
                 .saturating_add(self.mem_slab_reclaimable)
                 .saturating_sub(self.mem_shmem);
         }
+        Ok(())
     }
 
     pub(crate) fn cgroup_limits(&self) -> Option<crate::CGroupLimits> {
@@ -276,12 +375,44 @@ impl SystemInner {
         self.refresh_cpus(false, refresh_kind);
     }
 
+    pub(crate) fn try_refresh_cpu_specifics(
+        &mut self,
+        refresh_kind: CpuRefreshKind,
+    ) -> Result<(), RefreshError> {
+        self.cpus.refresh(false, refresh_kind)
+    }
+
+    pub(crate) fn check_processes_source() -> Result<(), RefreshError> {
+        check_source("/proc")
+    }
+
     pub(crate) fn refresh_processes_specifics(
         &mut self,
         processes_to_update: ProcessesToUpdate<'_>,
         refresh_kind: ProcessRefreshKind,
     ) -> usize {
         let uptime = Self::uptime();
+        // The processes removed since the previous refresh don't use them anymore.
+        self.info.remove_unused_interned();
+        #[cfg(feature = "io-uring")]
+        let prefetched_stats = match self
+            .io_uring
+            .get_or_insert_with(|| crate::sys::io_uring::IoUring::new(256).ok())
+        {
+            Some(ring) => crate::sys::process::prefetch_stat_files(
+                &self.process_list,
+                ring,
+                processes_to_update,
+            )
+            .unwrap_or_else(|_| {
+                // The ring is broken, so files are read as usual from now on.
+                self.io_uring = Some(None);
+                HashMap::new()
+            }),
+            None => HashMap::new(),
+        };
+        #[cfg(not(feature = "io-uring"))]
+        let prefetched_stats = HashMap::new();
         let nb_updated = refresh_procs(
             &mut self.process_list,
             Path::new("/proc"),
@@ -289,7 +420,11 @@ impl SystemInner {
             &self.info,
             processes_to_update,
             refresh_kind,
+            &prefetched_stats,
         );
+        if refresh_kind.media_usage() {
+            update_sound_server_media_usage(&mut self.process_list, processes_to_update);
+        }
         self.update_procs_cpu(refresh_kind);
         nb_updated
     }
@@ -302,6 +437,29 @@ impl SystemInner {
         &self.process_list
     }
 
+    pub(crate) fn process_string_stats(&self) -> ProcessStringStats {
+        ProcessStringStats {
+            reused: self.info.reused_strings.load(Ordering::Relaxed),
+            allocated: self.info.allocated_strings.load(Ordering::Relaxed),
+        }
+    }
+
+    pub(crate) fn set_max_kept_file_handles(&mut self, max: usize) {
+        self.info.kept_files.max.store(max, Ordering::Relaxed);
+        close_extra_stat_files(&mut self.process_list, &self.info.kept_files);
+    }
+
+    pub(crate) fn kept_file_handles(&self) -> usize {
+        self.info.kept_files.used.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn set_use_pidfds(&mut self, use_pidfds: bool) {
+        self.info.use_pidfds = use_pidfds;
+        if !use_pidfds {
+            close_pidfds(&mut self.process_list);
+        }
+    }
+
     pub(crate) fn processes_mut(&mut self) -> &mut HashMap<Pid, Process> {
         &mut self.process_list
     }
@@ -314,10 +472,18 @@ impl SystemInner {
         self.cpus.global_cpu.usage()
     }
 
+    pub(crate) fn last_cpu_usage_update(&self) -> Option<Instant> {
+        self.cpus.last_cpus_update
+    }
+
     pub(crate) fn cpus(&self) -> &[Cpu] {
         &self.cpus.cpus
     }
 
+    pub(crate) fn cpus_mut(&mut self) -> &mut [Cpu] {
+        &mut self.cpus.cpus
+    }
+
     pub(crate) fn total_memory(&self) -> u64 {
         self.mem_total
     }
@@ -347,6 +513,114 @@ impl SystemInner {
         self.swap_total - self.swap_free
     }
 
+    pub(crate) fn swap_devices(&self) -> Vec<SwapDevice> {
+        let Ok(data) = std::fs::read_to_string("/proc/swaps") else {
+            return Vec::new();
+        };
+        data.lines()
+            .filter_map(parse_swaps_line)
+            .map(|line| SwapDevice {
+                path: line.path.into(),
+                total: line.size.saturating_mul(1024),
+                used: line.used.saturating_mul(1024),
+                priority: Some(line.priority),
+            })
+            .collect()
+    }
+
+    pub(crate) fn compressed_memory(&self) -> Option<CompressedMemory> {
+        let mut compressed = None;
+        if let Ok(entries) = std::fs::read_dir("/sys/block") {
+            for entry in entries.flatten() {
+                if !entry.file_name().as_encoded_bytes().starts_with(b"zram") {
+                    continue;
+                }
+                // The first two fields are the original and the compressed data sizes in bytes.
+                let Ok(mm_stat) = std::fs::read_to_string(entry.path().join("mm_stat")) else {
+                    continue;
+                };
+                let mut fields = mm_stat
+                    .split_ascii_whitespace()
+                    .map(|field| field.parse::<u64>().ok());
+                if let (Some(Some(original_size)), Some(Some(compressed_size))) =
+                    (fields.next(), fields.next())
+                {
+                    add_compressed_memory(&mut compressed, original_size, compressed_size);
+                }
+            }
+        }
+        // `zswap` is only reported in `/proc/meminfo` since Linux 5.19.
+        if std::fs::read_to_string("/sys/module/zswap/parameters/enabled")
+            .is_ok_and(|enabled| enabled.trim() == "Y")
+        {
+            let (mut original_kib, mut compressed_kib) = (None, None);
+            read_table("/proc/meminfo", ':', |key, value_kib| match key {
+                "Zswapped" => original_kib = Some(value_kib),
+                "Zswap" => compressed_kib = Some(value_kib),
+                _ => {}
+            });
+            if let (Some(original_kib), Some(compressed_kib)) = (original_kib, compressed_kib) {
+                add_compressed_memory(
+                    &mut compressed,
+                    original_kib.saturating_mul(1_024),
+                    compressed_kib.saturating_mul(1_024),
+                );
+            }
+        }
+        compressed
+    }
+
+    pub(crate) fn inotify_usage(&self) -> Option<InotifyUsage> {
+        use std::os::unix::fs::MetadataExt;
+
+        let max_instances = read_u64("/proc/sys/fs/inotify/max_user_instances")?;
+        let max_watches = read_u64("/proc/sys/fs/inotify/max_user_watches")?;
+        // The limits are per user, so only the processes of the current user are counted.
+        let uid = unsafe { libc::getuid() };
+        let mut usage = InotifyUsage {
+            instances: 0,
+            max_instances,
+            watches: 0,
+            max_watches,
+        };
+        let Ok(entries) = std::fs::read_dir("/proc") else {
+            return Some(usage);
+        };
+        for entry in entries.flatten() {
+            if !entry
+                .file_name()
+                .as_encoded_bytes()
+                .iter()
+                .all(u8::is_ascii_digit)
+                || !entry.metadata().is_ok_and(|metadata| metadata.uid() == uid)
+            {
+                continue;
+            }
+            let path = entry.path();
+            let Ok(fds) = std::fs::read_dir(path.join("fd")) else {
+                continue;
+            };
+            for fd in fds.flatten() {
+                if !std::fs::read_link(fd.path())
+                    .is_ok_and(|target| target.as_os_str() == "anon_inode:inotify")
+                {
+                    continue;
+                }
+                usage.instances += 1;
+                // Each watch is listed on a `inotify wd:...` line.
+                if let Ok(fdinfo) =
+                    std::fs::read_to_string(path.join("fdinfo").join(fd.file_name()))
+                {
+                    usage.watches += fdinfo
+                        .lines()
+                        .filter(|line| line.starts_with("inotify wd:"))
+                        .count() as u64;
+                }
+            }
+        }
+        Some(usage)
+    }
+
     pub(crate) fn uptime() -> u64 {
         if cfg!(not(target_os = "android"))
             && let Ok(content) = get_all_utf8_data("/proc/uptime", 50)
@@ -393,6 +667,31 @@ impl SystemInner {
         }
     }
 
+    pub(crate) fn run_queue_length() -> Option<usize> {
+        let mut s = String::new();
+        if File::open("/proc/stat")
+            .and_then(|mut f| f.read_to_string(&mut s))
+            .is_ok()
+            && let Some(running) = s
+                .lines()
+                .find_map(|line| line.strip_prefix("procs_running "))
+                .and_then(|value| value.trim().parse().ok())
+        {
+            return Some(running);
+        }
+        // The fourth field of `/proc/loadavg` is "runnable/total".
+        s.clear();
+        File::open("/proc/loadavg")
+            .and_then(|mut f| f.read_to_string(&mut s))
+            .ok()?;
+        s.split_ascii_whitespace()
+            .nth(3)?
+            .split_once('/')?
+            .0
+            .parse()
+            .ok()
+    }
+
     #[cfg(not(target_os = "android"))]
     pub(crate) fn name() -> Option<String> {
         get_system_info_linux(
@@ -549,6 +848,13 @@ impl SystemInner {
     }
 
     pub(crate) fn cpu_arch() -> Option<String> {
+        // Contrary to `uname`, it isn't affected by the `linux32` personality.
+        if let Ok(arch) = std::fs::read_to_string("/proc/sys/kernel/arch") {
+            let arch = arch.trim();
+            if !arch.is_empty() {
+                return Some(arch.to_owned());
+            }
+        }
         let mut raw = MaybeUninit::<libc::utsname>::uninit();
 
         unsafe {
@@ -569,6 +875,34 @@ impl SystemInner {
         }
     }
 
+    pub(crate) fn is_compat_mode() -> Option<bool> {
+        // Not provided by the `libc` crate.
+        const PER_LINUX32: libc::c_int = 0x0008;
+
+        let persona = unsafe { libc::personality(0xffff_ffff) };
+        if persona != -1 && persona & PER_LINUX32 != 0 {
+            return Some(true);
+        }
+        let arch = Self::cpu_arch()?;
+        Some(cfg!(target_pointer_width = "32") && is_64_bit_arch(&arch))
+    }
+
+    pub(crate) fn core_dump_config() -> Option<CoreDumpConfig> {
+        let pattern = std::fs::read_to_string("/proc/sys/kernel/core_pattern").ok()?;
+        let pattern = pattern.trim_end_matches('\n').to_owned();
+        let handler = pattern
+            .strip_prefix('|')
+            .and_then(|command| command.split_ascii_whitespace().next())
+            .map(str::to_owned);
+        let (soft_limit, hard_limit) = crate::unix::utils::core_size_limits()?;
+        Some(CoreDumpConfig {
+            pattern,
+            handler,
+            soft_limit,
+            hard_limit,
+        })
+    }
+
     pub(crate) fn physical_core_count() -> Option<usize> {
         get_physical_core_count()
     }
@@ -578,6 +912,14 @@ impl SystemInner {
         self.refresh_cpu_specifics(refresh_kind);
     }
 
+    pub(crate) fn clock_ticks_per_second() -> Option<u64> {
+        crate::unix::utils::sysconf(libc::_SC_CLK_TCK)
+    }
+
+    pub(crate) fn page_size() -> Option<u64> {
+        crate::unix::utils::sysconf(libc::_SC_PAGESIZE)
+    }
+
     pub(crate) fn open_files_limit() -> Option<usize> {
         unsafe {
             match getrlimit() {
@@ -589,6 +931,215 @@ impl SystemInner {
             }
         }
     }
+
+    pub(crate) fn timezone() -> Option<String> {
+        crate::unix::utils::get_timezone().or_else(|| {
+            // Debian-based distributions also store it in `/etc/timezone`.
+            let tz = std::fs::read_to_string("/etc/timezone").ok()?;
+            let tz = tz.trim();
+            (!tz.is_empty()).then(|| tz.to_owned())
+        })
+    }
+
+    pub(crate) fn locale() -> Option<String> {
+        crate::unix::utils::get_locale_from_env().or_else(|| {
+            // When not set in the environment (like for services), we fallback to the system
+            // configuration.
+            ["/etc/locale.conf", "/etc/default/locale"]
+                .into_iter()
+                .filter_map(|path| std::fs::read_to_string(path).ok())
+                .find_map(|data| get_lang_from_locale_conf(&data))
+        })
+    }
+
+    pub(crate) fn cpu_vulnerabilities() -> Vec<CpuVulnerability> {
+        let Ok(entries) = std::fs::read_dir("/sys/devices/system/cpu/vulnerabilities") else {
+            return Vec::new();
+        };
+        let mut vulnerabilities = entries
+            .flatten()
+            .filter_map(|entry| {
+                let name = entry.file_name().into_string().ok()?;
+                let details = std::fs::read_to_string(entry.path()).ok()?;
+                let details = details.trim();
+                Some(CpuVulnerability {
+                    name,
+                    status: parse_vulnerability_status(details),
+                    details: details.to_owned(),
+                })
+            })
+            .collect::<Vec<_>>();
+        vulnerabilities.sort_unstable_by(|a, b| a.name.cmp(&b.name));
+        vulnerabilities
+    }
+
+    pub(crate) fn memory_hardware_info() -> Option<crate::MemoryHardwareInfo> {
+        let table = std::fs::read("/sys/firmware/dmi/tables/DMI").ok()?;
+        crate::MemoryHardwareInfo::from_smbios(&table)
+    }
+
+    pub(crate) fn virtualization() -> Option<String> {
+        if let Ok(kind) = std::fs::read_to_string("/sys/hypervisor/type") {
+            let kind = kind.trim();
+            if !kind.is_empty() {
+                return Some(match kind {
+                    "xen" => "Xen".to_owned(),
+                    kind => kind.to_owned(),
+                });
+            }
+        }
+        crate::common::system::virtualization_from_product()
+    }
+
+    pub(crate) fn board_model() -> Option<String> {
+        std::fs::read_to_string("/sys/firmware/devicetree/base/model")
+            .or_else(|_| std::fs::read_to_string("/proc/device-tree/model"))
+            .ok()
+            .map(|model| model.trim_end_matches('\0').trim().to_owned())
+            .filter(|model| !model.is_empty())
+    }
+
+    pub(crate) fn turbo_enabled() -> Option<bool> {
+        get_global_boost().or_else(|| {
+            let vendor_id = get_vendor_id_and_brand()
+                .remove(&0)
+                .map(|(vendor_id, _)| vendor_id)
+                .unwrap_or_default();
+            get_cpu_boost(0, &vendor_id, None)
+        })
+    }
+
+    pub(crate) fn throttling_status() -> Option<crate::ThrottlingStatus> {
+        // The firmware driver of the Raspberry Pi boards is a child of the `soc` node, whose
+        // name depends on the board (like `soc` or `soc@107c000000`).
+        std::fs::read_dir("/sys/devices/platform")
+            .ok()?
+            .flatten()
+            .filter(|soc| soc.file_name().as_encoded_bytes().starts_with(b"soc"))
+            .filter_map(|soc| std::fs::read_dir(soc.path()).ok())
+            .flat_map(|entries| entries.flatten())
+            .filter(|entry| entry.file_name().as_encoded_bytes().ends_with(b":firmware"))
+            .find_map(|firmware| {
+                std::fs::read_to_string(firmware.path().join("get_throttled")).ok()
+            })
+            .and_then(|content| crate::parsing::parse_rpi_throttled(&content))
+            .map(|flags| crate::ThrottlingStatus { flags })
+    }
+
+    pub(crate) fn socket_summary() -> Option<crate::SocketSummary> {
+        let read = |path: &str| std::fs::read_to_string(path).unwrap_or_default();
+        let sockstat = read("/proc/net/sockstat");
+        if sockstat.is_empty() {
+            return None;
+        }
+        let sockstat6 = read("/proc/net/sockstat6");
+        let snmp = read("/proc/net/snmp");
+        let netstat = read("/proc/net/netstat");
+
+        let sockstat = crate::parsing::parse_sockstat(&sockstat)
+            .into_iter()
+            .chain(crate::parsing::parse_sockstat(&sockstat6))
+            .collect::<Vec<_>>();
+        let snmp = crate::parsing::parse_snmp(&snmp)
+            .into_iter()
+            .chain(crate::parsing::parse_snmp(&netstat))
+            .collect::<Vec<_>>();
+        // The IPv6 counters are in `sockstat6`, with the `6` suffix.
+        let sockstat_sum = |protocols: &[&str], counter: &str| {
+            sockstat
+                .iter()
+                .filter(|(protocol, name, _)| protocols.contains(protocol) && *name == counter)
+                .map(|(_, _, value)| *value)
+                .reduce(|a, b| a + b)
+        };
+        let snmp_get = |protocol: &str, counter: &str| {
+            snmp.iter()
+                .find(|(p, name, _)| *p == protocol && *name == counter)
+                .map(|(_, _, value)| *value)
+        };
+
+        Some(crate::SocketSummary {
+            tcp_sockets: sockstat_sum(&["TCP", "TCP6"], "inuse"),
+            tcp_established: snmp_get("Tcp", "CurrEstab"),
+            tcp_syn_received: None,
+            tcp_time_wait: sockstat_sum(&["TCP"], "tw"),
+            tcp_orphaned: sockstat_sum(&["TCP"], "orphan"),
+            tcp_listen_overflows: snmp_get("TcpExt", "ListenOverflows"),
+            tcp_syn_cookies_sent: snmp_get("TcpExt", "SyncookiesSent"),
+            udp_sockets: sockstat_sum(&["UDP", "UDP6"], "inuse"),
+        })
+    }
+
+    pub(crate) fn kernel_log(
+        since: Option<Instant>,
+    ) -> std::io::Result<Vec<crate::KernelLogEntry>> {
+        use std::io::ErrorKind;
+        use std::os::unix::fs::OpenOptionsExt;
+
+        // The timestamps of the records use the same clock as `Instant`.
+        let since = match since {
+            Some(since) => {
+                let mut now = unsafe { std::mem::zeroed::<libc::timespec>() };
+                if unsafe { libc::clock_gettime(libc::CLOCK_MONOTONIC, &mut now) } != 0 {
+                    return Err(std::io::Error::last_os_error());
+                }
+                let now = Duration::new(now.tv_sec as _, now.tv_nsec as _);
+                now.saturating_sub(since.elapsed())
+            }
+            None => Duration::ZERO,
+        };
+        // Each read returns one record. It fails with `EAGAIN` once all records have been
+        // read and with `EPIPE` if the next record was overwritten in the meantime.
+        let mut kmsg = File::options()
+            .read(true)
+            .custom_flags(libc::O_NONBLOCK)
+            .open("/dev/kmsg")?;
+        let mut buffer = vec![0; 8_192];
+        let mut entries = Vec::new();
+        loop {
+            match kmsg.read(&mut buffer) {
+                Ok(0) => break,
+                Ok(read) => {
+                    let Some(record) = crate::parsing::parse_kmsg_record(&buffer[..read]) else {
+                        continue;
+                    };
+                    let time_since_boot = Duration::from_micros(record.timestamp);
+                    if time_since_boot >= since {
+                        entries.push(crate::KernelLogEntry {
+                            level: crate::KernelLogLevel::from_syslog(record.level),
+                            sequence: record.sequence,
+                            time_since_boot: Some(time_since_boot),
+                            message: record.message,
+                        });
+                    }
+                }
+                Err(error) if error.kind() == ErrorKind::WouldBlock => break,
+                Err(error)
+                    if error.kind() == ErrorKind::Interrupted
+                        || error.raw_os_error() == Some(libc::EPIPE) => {}
+                Err(error) => return Err(error),
+            }
+        }
+        Ok(entries)
+    }
+}
+
+fn is_64_bit_arch(arch: &str) -> bool {
+    matches!(
+        arch,
+        "x86_64"
+            | "aarch64"
+            | "aarch64_be"
+            | "ppc64"
+            | "ppc64le"
+            | "s390x"
+            | "mips64"
+            | "riscv64"
+            | "loongarch64"
+            | "sparc64"
+            | "alpha"
+            | "ia64"
+    )
 }
 
 fn read_u64(filename: &str) -> Option<u64> {
@@ -603,25 +1154,42 @@ fn read_u64(filename: &str) -> Option<u64> {
     result
 }
 
-fn read_table<F>(filename: &str, colsep: char, mut f: F)
+fn add_compressed_memory(
+    compressed: &mut Option<CompressedMemory>,
+    original_size: u64,
+    compressed_size: u64,
+) {
+    let compressed = compressed.get_or_insert_default();
+    compressed.original_size = compressed.original_size.saturating_add(original_size);
+    compressed.compressed_size = compressed.compressed_size.saturating_add(compressed_size);
+}
+
+fn read_table<F>(filename: &str, colsep: char, f: F)
 where
     F: FnMut(&str, u64),
 {
     if let Ok(content) = get_all_utf8_data(filename, 16_635) {
-        content
-            .split('\n')
-            .flat_map(|line| {
-                let mut split = line.split(colsep);
-                let key = split.next()?;
-                let value = split.next()?;
-                let value0 = value.trim_start().split(' ').next()?;
-                let value0_u64 = u64::from_str(value0).ok()?;
-                Some((key, value0_u64))
-            })
-            .for_each(|(k, v)| f(k, v));
+        parse_table(&content, colsep, f);
     }
 }
 
+fn parse_table<F>(content: &str, colsep: char, mut f: F)
+where
+    F: FnMut(&str, u64),
+{
+    content
+        .split('\n')
+        .flat_map(|line| {
+            let mut split = line.split(colsep);
+            let key = split.next()?;
+            let value = split.next()?;
+            let value0 = value.trim_start().split(' ').next()?;
+            let value0_u64 = u64::from_str(value0).ok()?;
+            Some((key, value0_u64))
+        })
+        .for_each(|(k, v)| f(k, v));
+}
+
 fn read_table_key(filename: &str, target_key: &str, colsep: char) -> Option<u64> {
     if let Ok(content) = get_all_utf8_data(filename, 16_635) {
         return content.split('\n').find_map(|line| {
@@ -769,6 +1337,30 @@ fn system_info_as_list(sysinfo: Option<String>) -> Vec<String> {
     }
 }
 
+/// Returns the value of `LANG` from a `locale.conf` file (which uses the shell syntax).
+fn get_lang_from_locale_conf(data: &str) -> Option<String> {
+    data.lines().find_map(|line| {
+        let value = line.trim().strip_prefix("LANG=")?;
+        let value = value.trim_matches(|c| c == '"' || c == '\'');
+        (!value.is_empty()).then(|| value.to_owned())
+    })
+}
+
+/// Parses the content of a file from `/sys/devices/system/cpu/vulnerabilities`.
+fn parse_vulnerability_status(details: &str) -> CpuVulnerabilityStatus {
+    // Some vulnerabilities (like `itlb_multihit`) are prefixed with the affected component.
+    let details = details.strip_prefix("KVM: ").unwrap_or(details);
+    if details.starts_with("Not affected") {
+        CpuVulnerabilityStatus::NotAffected
+    } else if details.starts_with("Mitigation") {
+        CpuVulnerabilityStatus::Mitigated
+    } else if details.starts_with("Vulnerable") || details.starts_with("Processor vulnerable") {
+        CpuVulnerabilityStatus::Vulnerable
+    } else {
+        CpuVulnerabilityStatus::Unknown
+    }
+}
+
 #[cfg(target_os = "android")]
 fn get_system_info_android(info: InfoType) -> Option<String> {
     // https://android.googlesource.com/platform/frameworks/base/+/refs/heads/master/core/java/android/os/Build.java#58
@@ -806,17 +1398,51 @@ fn get_system_info_android(info: InfoType) -> Option<String> {
 #[cfg(test)]
 mod test {
     use super::InfoType;
+    use super::SystemInfo;
+    use super::get_lang_from_locale_conf;
     #[cfg(target_os = "android")]
     use super::get_system_info_android;
     #[cfg(not(target_os = "android"))]
     use super::get_system_info_linux;
+    use super::is_64_bit_arch;
+    use super::parse_vulnerability_status;
     use super::read_table;
     use super::read_table_key;
     use super::system_info_as_list;
     use std::collections::HashMap;
     use std::io::Write;
+    use std::path::Path;
+    use std::sync::Arc;
+    use std::sync::atomic::Ordering;
     use tempfile::NamedTempFile;
 
+    #[test]
+    fn test_intern() {
+        let mut info = SystemInfo::new();
+        let a = info.intern_name("bash".as_ref());
+        let b = info.intern_name("bash".as_ref());
+        let c = info.intern_name("sh".as_ref());
+        assert!(Arc::ptr_eq(&a, &b));
+        assert_eq!(&*c, "sh");
+        assert_eq!(info.reused_strings.load(Ordering::Relaxed), 1);
+        assert_eq!(info.allocated_strings.load(Ordering::Relaxed), 2);
+
+        let exe = info.intern_exe(Path::new("/usr/bin/bash"));
+        assert!(Arc::ptr_eq(
+            &exe,
+            &info.intern_exe(Path::new("/usr/bin/bash"))
+        ));
+
+        // Only the values still used by a process are kept.
+        drop((b, c));
+        info.remove_unused_interned();
+        assert!(Arc::ptr_eq(&a, &info.intern_name("bash".as_ref())));
+        assert_eq!(info.names.get_mut().unwrap().len(), 1);
+        drop(exe);
+        info.remove_unused_interned();
+        assert!(info.exes.get_mut().unwrap().is_empty());
+    }
+
     #[test]
     fn test_read_table() {
         // Create a temporary file with test content
@@ -1019,4 +1645,56 @@ DISTRIB_DESCRIPTION="Ubuntu 20.10"
             vec!["rhel".to_string(), "fedora".to_string()],
         );
     }
+
+    #[test]
+    fn test_get_lang_from_locale_conf() {
+        assert_eq!(
+            get_lang_from_locale_conf("LANG=en_US.UTF-8\n").as_deref(),
+            Some("en_US.UTF-8"),
+        );
+        assert_eq!(
+            get_lang_from_locale_conf("# comment\nLC_TIME=C\nLANG=\"fr_FR.UTF-8\"\n").as_deref(),
+            Some("fr_FR.UTF-8"),
+        );
+        assert_eq!(get_lang_from_locale_conf("LANG=\n"), None);
+        assert_eq!(get_lang_from_locale_conf(""), None);
+    }
+
+    #[test]
+    fn test_parse_vulnerability_status() {
+        use crate::CpuVulnerabilityStatus;
+
+        assert_eq!(
+            parse_vulnerability_status("Not affected"),
+            CpuVulnerabilityStatus::NotAffected,
+        );
+        assert_eq!(
+            parse_vulnerability_status("Mitigation: PTI"),
+            CpuVulnerabilityStatus::Mitigated,
+        );
+        assert_eq!(
+            parse_vulnerability_status("KVM: Mitigation: VMX disabled"),
+            CpuVulnerabilityStatus::Mitigated,
+        );
+        assert_eq!(
+            parse_vulnerability_status("Vulnerable: Clear CPU buffers attempted, no microcode"),
+            CpuVulnerabilityStatus::Vulnerable,
+        );
+        assert_eq!(
+            parse_vulnerability_status("Processor vulnerable"),
+            CpuVulnerabilityStatus::Vulnerable,
+        );
+        assert_eq!(
+            parse_vulnerability_status("Unknown: Dependent on hypervisor status"),
+            CpuVulnerabilityStatus::Unknown,
+        );
+    }
+
+    #[test]
+    fn test_is_64_bit_arch() {
+        assert!(is_64_bit_arch("x86_64"));
+        assert!(is_64_bit_arch("aarch64"));
+        assert!(!is_64_bit_arch("i686"));
+        assert!(!is_64_bit_arch("armv7l"));
+    }
 }
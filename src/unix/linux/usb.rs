@@ -0,0 +1,83 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::{UsbDevice, UsbSpeed};
+
+use std::fs::{self, read_dir};
+use std::path::Path;
+
+fn read_attribute(device: &Path, attribute: &str) -> Option<String> {
+    let value = fs::read_to_string(device.join(attribute)).ok()?;
+    let value = value.trim();
+    if value.is_empty() {
+        None
+    } else {
+        Some(value.to_owned())
+    }
+}
+
+fn read_hex_attribute(device: &Path, attribute: &str) -> Option<u16> {
+    u16::from_str_radix(&read_attribute(device, attribute)?, 16).ok()
+}
+
+/// Converts the `devpath` attribute (like `1.4.2`) into the list of ports. Root hubs have `0` as
+/// `devpath`.
+fn parse_devpath(devpath: &str) -> Vec<u8> {
+    if devpath == "0" {
+        return Vec::new();
+    }
+    devpath
+        .split('.')
+        .filter_map(|port| port.parse().ok())
+        .collect()
+}
+
+pub(crate) fn get_usb_devices(devices: &mut Vec<UsbDevice>) {
+    devices.clear();
+
+    let Ok(entries) = read_dir("/sys/bus/usb/devices") else {
+        return;
+    };
+    for entry in entries.flatten() {
+        // Interfaces are listed as `[bus]-[ports]:[config].[interface]`, we only want devices.
+        if entry.file_name().as_encoded_bytes().contains(&b':') {
+            continue;
+        }
+        let path = entry.path();
+        let (Some(vendor_id), Some(product_id)) = (
+            read_hex_attribute(&path, "idVendor"),
+            read_hex_attribute(&path, "idProduct"),
+        ) else {
+            continue;
+        };
+        devices.push(UsbDevice {
+            vendor_id,
+            product_id,
+            manufacturer: read_attribute(&path, "manufacturer"),
+            product: read_attribute(&path, "product"),
+            serial_number: read_attribute(&path, "serial"),
+            bus_number: read_attribute(&path, "busnum").and_then(|b| b.parse().ok()),
+            port_numbers: read_attribute(&path, "devpath")
+                .map(|d| parse_devpath(&d))
+                .unwrap_or_default(),
+            speed: read_attribute(&path, "speed")
+                .and_then(|s| s.parse().ok())
+                .map(UsbSpeed::from_mbps)
+                .unwrap_or(UsbSpeed::Unknown),
+        });
+    }
+    devices.sort_unstable_by(|a, b| {
+        (a.bus_number, &a.port_numbers).cmp(&(b.bus_number, &b.port_numbers))
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_devpath;
+
+    #[test]
+    fn test_parse_devpath() {
+        assert!(parse_devpath("0").is_empty());
+        assert_eq!(parse_devpath("3"), vec![3]);
+        assert_eq!(parse_devpath("1.4.2"), vec![1, 4, 2]);
+    }
+}
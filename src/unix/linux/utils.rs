@@ -8,11 +8,66 @@ use std::io::{self, Read, Seek};
 use std::path::Path;
 
 #[cfg(feature = "system")]
-pub(crate) fn get_all_data_from_file(file: &mut File, size: usize) -> io::Result<Vec<u8>> {
-    let mut buf = Vec::with_capacity(size);
-    file.rewind()?;
-    file.read_to_end(&mut buf)?;
-    Ok(buf)
+thread_local! {
+    // Buffers used to read files, reused across reads to avoid allocating new ones for each
+    // process. There is one set of buffers per thread since processes can be refreshed in
+    // parallel.
+    static SCRATCH_BUFFERS: std::cell::RefCell<Vec<Vec<u8>>> =
+        const { std::cell::RefCell::new(Vec::new()) };
+}
+
+/// Calls `f` with an empty buffer which is reused across calls on the current thread. It can be
+/// called recursively, each call getting its own buffer.
+#[cfg(feature = "system")]
+pub(crate) fn with_scratch_buffer<R>(f: impl FnOnce(&mut Vec<u8>) -> R) -> R {
+    let mut buf = SCRATCH_BUFFERS
+        .with_borrow_mut(|buffers| buffers.pop())
+        .unwrap_or_default();
+    let ret = f(&mut buf);
+    buf.clear();
+    // We don't want to keep a big buffer around because of a single huge file.
+    buf.shrink_to(65_536);
+    SCRATCH_BUFFERS.with_borrow_mut(|buffers| buffers.push(buf));
+    ret
+}
+
+/// Reads the whole content of `file` (from its start) into `buf`.
+///
+/// It uses `pread` so the file doesn't need to be rewound first. Reads are done until one
+/// returns `0`: the files of `/proc` can return less than requested before their end (like
+/// `cmdline` or `environ`, read one page at a time).
+#[cfg(feature = "system")]
+pub(crate) fn read_all_data_from_file(file: &File, buf: &mut Vec<u8>) -> io::Result<()> {
+    use std::os::unix::fs::FileExt;
+
+    buf.clear();
+    loop {
+        if buf.capacity() - buf.len() < 1_024 {
+            buf.reserve(4_096);
+        }
+        let len = buf.len();
+        buf.resize(buf.capacity(), 0);
+        match file.read_at(&mut buf[len..], len as u64) {
+            Ok(read) => {
+                buf.truncate(len + read);
+                if read == 0 {
+                    return Ok(());
+                }
+            }
+            Err(error) if error.kind() == io::ErrorKind::Interrupted => buf.truncate(len),
+            Err(error) => {
+                buf.truncate(len);
+                return Err(error);
+            }
+        }
+    }
+}
+
+/// Reads the content of `file_path` into `buf` and returns it as a `str`.
+#[cfg(feature = "system")]
+pub(crate) fn read_utf8_data<P: AsRef<Path>>(file_path: P, buf: &mut Vec<u8>) -> io::Result<&str> {
+    read_all_data_from_file(&File::open(file_path.as_ref())?, buf)?;
+    std::str::from_utf8(buf).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
 }
 
 #[cfg(any(feature = "disk", feature = "system"))]
@@ -104,3 +159,31 @@ pub(crate) fn to_cpath(path: &std::path::Path) -> Vec<u8> {
     cpath.push(0);
     cpath
 }
+
+#[cfg(all(test, feature = "system"))]
+mod tests {
+    use super::read_all_data_from_file;
+    use std::fs::File;
+    use std::io::Write;
+
+    #[test]
+    fn test_read_all_data_from_file() {
+        let mut file = tempfile::tempfile().unwrap();
+        let content = (0..10_000).map(|i| (i % 251) as u8).collect::<Vec<_>>();
+        file.write_all(&content).unwrap();
+
+        // The file position is at its end, which must not matter.
+        let mut buf = b"previous content".to_vec();
+        read_all_data_from_file(&file, &mut buf).unwrap();
+        assert_eq!(buf, content);
+
+        let stat = File::open("/proc/self/stat").unwrap();
+        read_all_data_from_file(&stat, &mut buf).unwrap();
+        assert!(buf.ends_with(b"\n"));
+
+        // `environ` is read one page at a time, so short reads happen before its end.
+        let environ = File::open("/proc/self/environ").unwrap();
+        read_all_data_from_file(&environ, &mut buf).unwrap();
+        assert_eq!(buf, std::fs::read("/proc/self/environ").unwrap());
+    }
+}
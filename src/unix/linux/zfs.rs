@@ -0,0 +1,33 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::parsing::parse_kstat_named;
+use crate::{ZfsArc, ZfsPool};
+
+use std::path::Path;
+
+pub(crate) fn get_zfs_pools(pools: &mut Vec<ZfsPool>) {
+    // No need to run `zpool` if the ZFS module isn't loaded.
+    if !Path::new("/sys/module/zfs").exists() {
+        pools.clear();
+        return;
+    }
+    crate::unix::zfs::get_zfs_pools(pools);
+}
+
+pub(crate) fn get_zfs_arc() -> Option<ZfsArc> {
+    let data = std::fs::read_to_string("/proc/spl/kstat/zfs/arcstats").ok()?;
+    let mut arc = ZfsArc::default();
+    for (name, value) in parse_kstat_named(&data) {
+        let field = match name {
+            "size" => &mut arc.size,
+            "c" => &mut arc.target_size,
+            "c_min" => &mut arc.min_size,
+            "c_max" => &mut arc.max_size,
+            "hits" => &mut arc.hits,
+            "misses" => &mut arc.misses,
+            _ => continue,
+        };
+        *field = value;
+    }
+    Some(arc)
+}
@@ -49,14 +49,151 @@ cfg_if! {
         }
     }
 
+    if #[cfg(all(feature = "disk", not(any(target_os = "linux", target_os = "android"))))] {
+        pub(crate) struct StorageInner;
+
+        impl StorageInner {
+            pub(crate) fn volumes() -> Vec<crate::Volume> {
+                Vec::new()
+            }
+        }
+    }
+
+    if #[cfg(all(feature = "system", not(any(target_os = "linux", target_os = "android"))))] {
+        use crate::common::exited_process::ExitedProcess;
+
+        use std::io;
+
+        pub(crate) struct ExitedProcessesInner;
+
+        impl ExitedProcessesInner {
+            pub(crate) fn start() -> io::Result<Self> {
+                Err(io::Error::new(
+                    io::ErrorKind::Unsupported,
+                    "capturing exited processes is not supported on this system",
+                ))
+            }
+
+            pub(crate) fn take(&mut self, _processes: &mut Vec<ExitedProcess>) -> bool {
+                false
+            }
+        }
+    }
+
+    if #[cfg(all(feature = "ebpf", not(any(target_os = "linux", target_os = "android"))))] {
+        use crate::common::ebpf::LatencyStats;
+        use crate::Pid;
+
+        use std::collections::HashMap;
+
+        pub(crate) struct EbpfCollectorsInner;
+
+        impl EbpfCollectorsInner {
+            pub(crate) fn start() -> io::Result<Self> {
+                Err(io::Error::new(
+                    io::ErrorKind::Unsupported,
+                    "eBPF collectors are not supported on this system",
+                ))
+            }
+
+            pub(crate) fn refresh(
+                &mut self,
+                _io_latency: &mut HashMap<Pid, LatencyStats>,
+                _tcp_retransmits: &mut HashMap<Pid, u64>,
+                _run_queue_latency: &mut HashMap<Pid, LatencyStats>,
+            ) {
+            }
+        }
+    }
+
+    if #[cfg(all(
+        feature = "apple-silicon",
+        not(all(target_os = "macos", not(feature = "apple-sandbox"))),
+    ))] {
+        use crate::common::apple_silicon::PowerUsage;
+        use crate::CpuCluster;
+
+        pub(crate) struct AppleSiliconMetricsInner;
+
+        impl AppleSiliconMetricsInner {
+            // `std::io` is only imported by the `system` stubs, which aren't built on Linux.
+            pub(crate) fn start() -> std::io::Result<Self> {
+                Err(std::io::Error::new(
+                    std::io::ErrorKind::Unsupported,
+                    "Apple Silicon metrics are not supported on this system",
+                ))
+            }
+
+            pub(crate) fn refresh(
+                &mut self,
+                _clusters: &mut Vec<CpuCluster>,
+                _power: &mut PowerUsage,
+            ) {
+            }
+        }
+    }
+
+    if #[cfg(all(
+        feature = "disk",
+        any(target_os = "macos", target_os = "ios", target_os = "freebsd")
+    ))] {
+        pub(crate) mod disk_watcher;
+    }
+
     if #[cfg(feature = "network")] {
         pub(crate) mod network_helper;
     }
 
+    if #[cfg(all(feature = "network", not(target_os = "redox")))] {
+        pub(crate) mod network_watcher;
+
+        pub(crate) use self::network_watcher::NetworkWatcherInner;
+    } else if #[cfg(feature = "network")] {
+        use crate::common::network_watcher::InterfaceState;
+
+        use std::time::Duration;
+
+        pub(crate) struct NetworkWatcherInner;
+
+        impl NetworkWatcherInner {
+            pub(crate) fn open() -> std::io::Result<Self> {
+                Err(std::io::Error::new(
+                    std::io::ErrorKind::Unsupported,
+                    "watching network interfaces is not supported on this system",
+                ))
+            }
+
+            pub(crate) fn wait(&mut self, _timeout: Duration) -> bool {
+                false
+            }
+
+            pub(crate) fn interfaces(&mut self) -> std::io::Result<Vec<InterfaceState>> {
+                Ok(Vec::new())
+            }
+        }
+    }
+
     if #[cfg(feature = "user")] {
         pub(crate) mod users;
         pub(crate) mod groups;
     }
+
+    if #[cfg(all(
+        feature = "zfs",
+        any(target_os = "linux", target_os = "android", target_os = "freebsd")
+    ))] {
+        pub(crate) mod zfs;
+    } else if #[cfg(feature = "zfs")] {
+        use crate::{ZfsArc, ZfsPool};
+
+        pub(crate) fn get_zfs_pools(pools: &mut Vec<ZfsPool>) {
+            pools.clear();
+        }
+
+        pub(crate) fn get_zfs_arc() -> Option<ZfsArc> {
+            None
+        }
+    }
 }
 
 pub(crate) mod utils;
@@ -65,6 +202,8 @@ pub(crate) mod utils;
 #[cfg(any())]
 mod apple;
 #[cfg(any())]
+mod disk_watcher;
+#[cfg(any())]
 mod freebsd;
 #[cfg(any())]
 mod groups;
@@ -73,4 +212,8 @@ mod linux;
 #[cfg(any())]
 mod network_helper;
 #[cfg(any())]
+mod network_watcher;
+#[cfg(any())]
 mod users;
+#[cfg(any())]
+mod zfs;
@@ -294,6 +294,174 @@ fn sockaddr_to_network_addr(sa: *const libc::sockaddr) -> Option<IpAddr> {
     }
 }
 
+// FIXME: To be removed once `rt_msghdr` is available for FreeBSD in the minimum supported `libc`
+// version.
+#[cfg(target_os = "freebsd")]
+#[allow(non_camel_case_types, dead_code)]
+#[repr(C)]
+struct rt_msghdr {
+    rtm_msglen: libc::c_ushort,
+    rtm_version: libc::c_uchar,
+    rtm_type: libc::c_uchar,
+    rtm_index: libc::c_ushort,
+    _rtm_spare1: libc::c_ushort,
+    rtm_flags: libc::c_int,
+    rtm_addrs: libc::c_int,
+    rtm_pid: libc::pid_t,
+    rtm_seq: libc::c_int,
+    rtm_errno: libc::c_int,
+    rtm_fmask: libc::c_int,
+    rtm_inits: libc::c_ulong,
+    // `struct rt_metrics`.
+    rtm_rmx: [libc::c_ulong; 14],
+}
+#[cfg(target_os = "macos")]
+use libc::rt_msghdr;
+
+/// Converts a socket address from a routing message into an IP address.
+#[cfg(any(target_os = "macos", target_os = "freebsd"))]
+fn route_sockaddr_to_ip(sa: &[u8]) -> Option<IpAddr> {
+    // On BSD systems, the first two bytes are `sa_len` and `sa_family`.
+    match *sa.get(1)? as libc::c_int {
+        libc::AF_INET => {
+            let addr: [u8; 4] = sa.get(4..8)?.try_into().ok()?;
+            Some(IpAddr::from(addr))
+        }
+        libc::AF_INET6 => {
+            let mut addr: [u8; 16] = sa.get(8..24)?.try_into().ok()?;
+            // The kernel embeds the scope of link-local addresses in the second 16-bit word.
+            if addr[0] == 0xfe && addr[1] & 0xc0 == 0x80 {
+                addr[2] = 0;
+                addr[3] = 0;
+            }
+            Some(IpAddr::from(addr))
+        }
+        _ => None,
+    }
+}
+
+/// Returns the gateway and the interface of the default route of `family` from the routing
+/// table.
+#[cfg(any(target_os = "macos", target_os = "freebsd"))]
+unsafe fn get_default_route_for(family: libc::c_int) -> Option<(IpAddr, Option<String>)> {
+    // Socket addresses in routing messages are aligned on 32 bits on macOS and on
+    // `long` on FreeBSD.
+    #[cfg(target_os = "macos")]
+    const SA_ALIGN: usize = mem::size_of::<u32>();
+    #[cfg(target_os = "freebsd")]
+    const SA_ALIGN: usize = mem::size_of::<libc::c_long>();
+
+    let mut mib = [
+        libc::CTL_NET,
+        libc::PF_ROUTE,
+        0,
+        family,
+        libc::NET_RT_FLAGS,
+        libc::RTF_GATEWAY,
+    ];
+    let mut len = 0;
+    unsafe {
+        if libc::sysctl(
+            mib.as_mut_ptr(),
+            mib.len() as _,
+            null_mut(),
+            &mut len,
+            null_mut(),
+            0,
+        ) != 0
+        {
+            sysinfo_debug!("Failed to get routing table size");
+            return None;
+        }
+        let mut buf = vec![0u8; len];
+        if libc::sysctl(
+            mib.as_mut_ptr(),
+            mib.len() as _,
+            buf.as_mut_ptr() as _,
+            &mut len,
+            null_mut(),
+            0,
+        ) != 0
+        {
+            sysinfo_debug!("Failed to get routing table");
+            return None;
+        }
+        buf.truncate(len);
+
+        let mut messages = &buf[..];
+        while messages.len() >= mem::size_of::<rt_msghdr>() {
+            let header = (messages.as_ptr() as *const rt_msghdr).read_unaligned();
+            let msg_len = header.rtm_msglen as usize;
+            if msg_len < mem::size_of::<rt_msghdr>() || msg_len > messages.len() {
+                break;
+            }
+            let mut addrs = &messages[mem::size_of::<rt_msghdr>()..msg_len];
+            messages = &messages[msg_len..];
+
+            if header.rtm_flags & (libc::RTF_UP | libc::RTF_GATEWAY)
+                != libc::RTF_UP | libc::RTF_GATEWAY
+            {
+                continue;
+            }
+            // Scoped routes are only used for traffic bound to their interface.
+            #[cfg(target_os = "macos")]
+            if header.rtm_flags & libc::RTF_IFSCOPE != 0 {
+                continue;
+            }
+
+            let mut destination = None;
+            let mut gateway = None;
+            let mut is_default_mask = true;
+            for index in 0..libc::RTAX_MAX {
+                if header.rtm_addrs & (1 << index) == 0 {
+                    continue;
+                }
+                let Some(&sa_len) = addrs.first() else {
+                    break;
+                };
+                let sa_len = sa_len as usize;
+                let sa = addrs.get(..sa_len).unwrap_or(addrs);
+                match index {
+                    libc::RTAX_DST => destination = route_sockaddr_to_ip(sa),
+                    libc::RTAX_GATEWAY => gateway = route_sockaddr_to_ip(sa),
+                    // The netmask can be truncated after its last non-zero byte.
+                    libc::RTAX_NETMASK => is_default_mask = sa.iter().skip(2).all(|b| *b == 0),
+                    _ => {}
+                }
+                let aligned_len = if sa_len == 0 {
+                    SA_ALIGN
+                } else {
+                    1 + ((sa_len - 1) | (SA_ALIGN - 1))
+                };
+                addrs = addrs.get(aligned_len..).unwrap_or_default();
+            }
+
+            if let (Some(destination), Some(gateway)) = (destination, gateway)
+                && destination.is_unspecified()
+                && is_default_mask
+            {
+                let mut name = [0 as c_char; libc::IFNAMSIZ];
+                let interface =
+                    if libc::if_indextoname(header.rtm_index as _, name.as_mut_ptr()).is_null() {
+                        None
+                    } else {
+                        Some(CStr::from_ptr(name.as_ptr()).to_string_lossy().into_owned())
+                    };
+                return Some((gateway, interface));
+            }
+        }
+    }
+    None
+}
+
+/// Returns the gateway and the interface of the default route, preferring IPv4 over IPv6.
+#[cfg(any(target_os = "macos", target_os = "freebsd"))]
+pub(crate) fn get_default_route() -> Option<(IpAddr, Option<String>)> {
+    [libc::AF_INET, libc::AF_INET6]
+        .into_iter()
+        .find_map(|family| unsafe { get_default_route_for(family) })
+}
+
 pub(crate) fn ip_mask_to_prefix(mask: IpAddr) -> Result<u8, &'static str> {
     match mask {
         IpAddr::V4(mask) => ipv4_mask_to_prefix(mask),
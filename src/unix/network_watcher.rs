@@ -0,0 +1,141 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::common::network_watcher::InterfaceState;
+
+use std::ffi::CStr;
+use std::io;
+use std::os::fd::{AsRawFd, FromRawFd, OwnedFd};
+use std::ptr::null_mut;
+use std::time::Duration;
+
+pub(crate) struct NetworkWatcherInner {
+    socket: OwnedFd,
+}
+
+impl NetworkWatcherInner {
+    pub(crate) fn open() -> io::Result<Self> {
+        Ok(Self {
+            socket: open_socket()?,
+        })
+    }
+
+    pub(crate) fn wait(&mut self, timeout: Duration) -> bool {
+        let mut fd = libc::pollfd {
+            fd: self.socket.as_raw_fd(),
+            events: libc::POLLIN,
+            revents: 0,
+        };
+        if unsafe { libc::poll(&mut fd, 1, timeout.as_millis() as _) } <= 0 {
+            return false;
+        }
+        // The content of the messages doesn't matter since the interfaces are listed again, so
+        // they're only drained. It also handles the case where some messages were dropped by the
+        // kernel.
+        let mut buffer = [0u8; 8192];
+        while unsafe {
+            libc::recv(
+                self.socket.as_raw_fd(),
+                buffer.as_mut_ptr().cast(),
+                buffer.len(),
+                libc::MSG_DONTWAIT,
+            )
+        } > 0
+        {}
+        true
+    }
+
+    pub(crate) fn interfaces(&mut self) -> io::Result<Vec<InterfaceState>> {
+        get_interfaces_state()
+    }
+}
+
+/// Opens a `rtnetlink` socket receiving the changes of the network interfaces.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn open_socket() -> io::Result<OwnedFd> {
+    let fd = unsafe {
+        libc::socket(
+            libc::AF_NETLINK,
+            libc::SOCK_RAW | libc::SOCK_CLOEXEC,
+            libc::NETLINK_ROUTE,
+        )
+    };
+    if fd < 0 {
+        return Err(io::Error::last_os_error());
+    }
+    let socket = unsafe { OwnedFd::from_raw_fd(fd) };
+
+    let mut addr: libc::sockaddr_nl = unsafe { std::mem::zeroed() };
+    addr.nl_family = libc::AF_NETLINK as _;
+    addr.nl_groups = libc::RTMGRP_LINK as _;
+    if unsafe {
+        libc::bind(
+            socket.as_raw_fd(),
+            (&addr as *const libc::sockaddr_nl).cast(),
+            std::mem::size_of::<libc::sockaddr_nl>() as _,
+        )
+    } != 0
+    {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(socket)
+}
+
+/// Opens a routing socket, which receives (among other things) the changes of the network
+/// interfaces.
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+fn open_socket() -> io::Result<OwnedFd> {
+    // `SOCK_CLOEXEC` is not available on Apple targets, `FD_CLOEXEC` is set afterwards instead.
+    #[cfg(not(any(target_os = "macos", target_os = "ios")))]
+    const SOCK_TYPE: libc::c_int = libc::SOCK_RAW | libc::SOCK_CLOEXEC;
+    #[cfg(any(target_os = "macos", target_os = "ios"))]
+    const SOCK_TYPE: libc::c_int = libc::SOCK_RAW;
+
+    let fd = unsafe { libc::socket(libc::PF_ROUTE, SOCK_TYPE, libc::AF_UNSPEC) };
+    if fd < 0 {
+        return Err(io::Error::last_os_error());
+    }
+    let socket = unsafe { OwnedFd::from_raw_fd(fd) };
+    #[cfg(any(target_os = "macos", target_os = "ios"))]
+    if unsafe { libc::fcntl(socket.as_raw_fd(), libc::F_SETFD, libc::FD_CLOEXEC) } < 0 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(socket)
+}
+
+fn get_interfaces_state() -> io::Result<Vec<InterfaceState>> {
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    const LINK_FAMILY: libc::c_int = libc::AF_PACKET;
+    #[cfg(not(any(target_os = "linux", target_os = "android")))]
+    const LINK_FAMILY: libc::c_int = libc::AF_LINK;
+
+    let mut interfaces: Vec<InterfaceState> = Vec::new();
+    let mut ifap = null_mut();
+    unsafe {
+        if retry_eintr!(libc::getifaddrs(&mut ifap)) != 0 {
+            return Err(io::Error::last_os_error());
+        }
+        let mut current = ifap;
+        while let Some(ifa) = current.as_ref() {
+            current = ifa.ifa_next;
+            // Each interface has exactly one link-layer address entry, even when it has no IP
+            // address.
+            if ifa.ifa_name.is_null()
+                || ifa.ifa_addr.is_null()
+                || (*ifa.ifa_addr).sa_family as libc::c_int != LINK_FAMILY
+            {
+                continue;
+            }
+            let name = CStr::from_ptr(ifa.ifa_name).to_string_lossy().into_owned();
+            if interfaces.iter().any(|interface| interface.name == name) {
+                continue;
+            }
+            let flags = ifa.ifa_flags as libc::c_int;
+            interfaces.push(InterfaceState {
+                name,
+                is_up: flags & libc::IFF_UP != 0 && flags & libc::IFF_RUNNING != 0,
+            });
+        }
+        libc::freeifaddrs(ifap);
+    }
+    Ok(interfaces)
+}
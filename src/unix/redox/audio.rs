@@ -0,0 +1,20 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::{AudioDevice, AudioDeviceKind};
+
+use std::path::Path;
+
+pub(crate) fn get_audio_devices(devices: &mut Vec<AudioDevice>) {
+    devices.clear();
+
+    // The `audio` scheme is provided by `audiod`, which mixes the streams of all clients and sends
+    // them to the hardware driver. It only supports playback.
+    if Path::new("/scheme/audio").exists() {
+        devices.push(AudioDevice {
+            id: "audio".to_owned(),
+            name: "audio".to_owned(),
+            kind: AudioDeviceKind::Output,
+            is_default: true,
+        });
+    }
+}
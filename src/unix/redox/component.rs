@@ -4,7 +4,7 @@
 //
 // Values in /sys/class/hwmonN are `c_long` or `c_ulong`
 // transposed to rust we only read `u32` or `i32` values.
-use crate::Component;
+use crate::{Component, ValueSource};
 
 use std::collections::HashMap;
 use std::ffi::OsStr;
@@ -95,6 +95,10 @@ pub(crate) struct ComponentInner {
     input_file: Option<PathBuf>,
     /// `temp[1-*]_highest file` to read if available highest value.
     highest_file: Option<PathBuf>,
+    /// Whether `max` was read from `highest_file` or computed by `sysinfo`.
+    max_from_hardware: bool,
+    /// Content of `temp[1-*]_label`, before `label` is computed.
+    raw_label: Option<String>,
     pub(crate) updated: bool,
 }
 
@@ -108,16 +112,21 @@ impl ComponentInner {
                     max,
                     input_file,
                     highest_file,
+                    max_from_hardware,
                     ..
                 },
+            ..
         }: Component,
     ) {
         if let Some(temp) = temperature {
             self.temperature = Some(temp);
         }
         match (max, self.max) {
-            (Some(new_max), Some(old_max)) => self.max = Some(new_max.max(old_max)),
-            (Some(max), None) => self.max = Some(max),
+            (Some(new_max), Some(old_max)) if new_max <= old_max => {}
+            (Some(max), _) => {
+                self.max = Some(max);
+                self.max_from_hardware = max_from_hardware;
+            }
             _ => {}
         }
         if input_file.is_some() && input_file != self.input_file {
@@ -230,7 +239,9 @@ fn fill_component(component: &mut ComponentInner, item: &str, folder: &Path, fil
         }
         "label" => component.label = get_file_line(&hwmon_file, 10).unwrap_or_default(),
         "highest" => {
-            component.max = get_temperature_from_file(&hwmon_file).or(component.temperature);
+            let highest = get_temperature_from_file(&hwmon_file);
+            component.max_from_hardware = highest.is_some();
+            component.max = highest.or(component.temperature);
             component.highest_file = Some(hwmon_file);
         }
         // "max" => component.threshold_max = get_temperature_from_file(&hwmon_file),
@@ -292,6 +303,7 @@ impl ComponentInner {
 
             let component = matchings.entry(id).or_insert_with(|| Component {
                 inner: ComponentInner::default(),
+                max_observed: None,
             });
             let component = &mut component.inner;
             let name = get_file_line(&folder.join("name"), 16);
@@ -312,6 +324,9 @@ impl ComponentInner {
             .filter(|(_, c)| c.inner.input_file.is_some())
         {
             // compute label from known data
+            if !new_comp.inner.label.is_empty() {
+                new_comp.inner.raw_label = Some(new_comp.inner.label.clone());
+            }
             new_comp.inner.label = new_comp.inner.format_label("temp", id);
             if let Some(comp) = components
                 .iter_mut()
@@ -359,10 +374,32 @@ impl ComponentInner {
         self.threshold_critical
     }
 
+    pub(crate) fn max_source(&self) -> Option<ValueSource> {
+        self.max.map(|_| {
+            if self.max_from_hardware {
+                ValueSource::Hardware
+            } else {
+                ValueSource::Inferred
+            }
+        })
+    }
+
+    pub(crate) fn critical_source(&self) -> Option<ValueSource> {
+        self.threshold_critical.map(|_| ValueSource::Hardware)
+    }
+
     pub(crate) fn label(&self) -> &str {
         &self.label
     }
 
+    pub(crate) fn raw_label(&self) -> Option<&str> {
+        self.raw_label.as_deref()
+    }
+
+    pub(crate) fn chip(&self) -> Option<&str> {
+        Some(self.name.as_str()).filter(|name| !name.is_empty())
+    }
+
     pub(crate) fn id(&self) -> Option<&str> {
         self.id.as_deref()
     }
@@ -373,15 +410,16 @@ impl ComponentInner {
             .as_ref()
             .and_then(|file| get_temperature_from_file(file.as_path()));
         // tries to read out kernel highest if not compute something from temperature.
-        let max = self
+        let highest = self
             .highest_file
             .as_ref()
-            .and_then(|file| get_temperature_from_file(file.as_path()))
-            .or_else(|| {
-                let last = self.temperature?;
-                let current = current?;
-                Some(last.max(current))
-            });
+            .and_then(|file| get_temperature_from_file(file.as_path()));
+        self.max_from_hardware = highest.is_some();
+        let max = highest.or_else(|| {
+            let last = self.temperature?;
+            let current = current?;
+            Some(last.max(current))
+        });
         self.max = max;
         self.temperature = current;
     }
@@ -456,7 +494,10 @@ impl ComponentsInner {
                         ..Default::default()
                     };
                     fill_component(&mut component, "input", &path, "temp");
-                    self.components.push(Component { inner: component });
+                    self.components.push(Component {
+                        inner: component,
+                        max_observed: None,
+                    });
                 }
             });
         }
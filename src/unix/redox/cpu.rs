@@ -8,7 +8,7 @@ use std::io::{BufRead, BufReader, Read};
 use std::time::Instant;
 
 use crate::sys::utils::to_u64;
-use crate::{Cpu, CpuRefreshKind};
+use crate::{Cpu, CpuRefreshKind, RefreshError};
 
 macro_rules! to_str {
     ($e:expr) => {
@@ -21,7 +21,10 @@ pub(crate) struct CpusWrapper {
     pub(crate) cpus: Vec<Cpu>,
     got_cpu_frequency: bool,
     /// This field is needed to prevent updating when not enough time passed since last update.
-    last_update: Option<Instant>,
+    pub(crate) last_update: Option<Instant>,
+    /// When the per-CPU times were last updated. It differs from `last_update` if only the
+    /// global CPU times were updated (when computing the processes CPU usage).
+    pub(crate) last_cpus_update: Option<Instant>,
 }
 
 impl CpusWrapper {
@@ -31,6 +34,7 @@ impl CpusWrapper {
             cpus: Vec::with_capacity(4),
             got_cpu_frequency: false,
             last_update: None,
+            last_cpus_update: None,
         }
     }
 
@@ -39,10 +43,16 @@ impl CpusWrapper {
         only_update_global_cpu: bool,
         refresh_kind: CpuRefreshKind,
     ) {
-        self.refresh(only_update_global_cpu, refresh_kind);
+        if let Err(_e) = self.refresh(only_update_global_cpu, refresh_kind) {
+            sysinfo_debug!("failed to retrieve CPU information: {:?}", _e);
+        }
     }
 
-    pub(crate) fn refresh(&mut self, only_update_global_cpu: bool, refresh_kind: CpuRefreshKind) {
+    pub(crate) fn refresh(
+        &mut self,
+        only_update_global_cpu: bool,
+        refresh_kind: CpuRefreshKind,
+    ) -> Result<(), RefreshError> {
         let need_cpu_usage_update = self
             .last_update
             .map(|last_update| last_update.elapsed() >= crate::MINIMUM_CPU_UPDATE_INTERVAL)
@@ -58,31 +68,58 @@ impl CpusWrapper {
         // If the last CPU usage update is too close (less than `MINIMUM_CPU_UPDATE_INTERVAL`),
         // we don't want to update CPUs times.
         if need_cpu_usage_update && (first || refresh_kind.cpu_usage()) {
-/* Example /scheme/sys/stat output:
-cpu  3655 0 10896 965406 37003
-cpu0 344 0 626 29683 37003
-cpu1 319 0 1632 28676 0
-cpu2 227 0 1478 28920 0
-cpu3 169 0 1125 29333 0
-cpu4 139 0 740 29753 0
-name user nice kernel idle irq
-Description of fields above
-*/
-
-            let mut sys_stat = fs::read_to_string("/scheme/sys/stat").unwrap_or_default();
+            /* Example /scheme/sys/stat output:
+            cpu  3655 0 10896 965406 37003
+            cpu0 344 0 626 29683 37003
+            cpu1 319 0 1632 28676 0
+            cpu2 227 0 1478 28920 0
+            cpu3 169 0 1125 29333 0
+            cpu4 139 0 740 29753 0
+            name user nice kernel idle irq
+            Description of fields above
+            */
+
+            let sys_stat = fs::read_to_string("/scheme/sys/stat")
+                .map_err(|e| RefreshError::from_io("/scheme/sys/stat", e))?;
+            if !sys_stat.lines().any(|line| line.starts_with("cpu ")) {
+                return Err(RefreshError::Parse("/scheme/sys/stat".into()));
+            }
             self.last_update = Some(Instant::now());
+            if first || !only_update_global_cpu {
+                self.last_cpus_update = self.last_update;
+            }
             for line in sys_stat.lines() {
                 let mut parts = line.split(' ').filter(|s| !s.is_empty());
                 let name = parts.next().unwrap_or_default();
                 if !name.starts_with("cpu") {
                     continue;
                 }
-                let user = parts.next().unwrap_or_default().parse::<u64>().unwrap_or_default();
-                let nice = parts.next().unwrap_or_default().parse::<u64>().unwrap_or_default();
-                let system = parts.next().unwrap_or_default().parse::<u64>().unwrap_or_default();
-                let idle = parts.next().unwrap_or_default().parse::<u64>().unwrap_or_default();
+                let user = parts
+                    .next()
+                    .unwrap_or_default()
+                    .parse::<u64>()
+                    .unwrap_or_default();
+                let nice = parts
+                    .next()
+                    .unwrap_or_default()
+                    .parse::<u64>()
+                    .unwrap_or_default();
+                let system = parts
+                    .next()
+                    .unwrap_or_default()
+                    .parse::<u64>()
+                    .unwrap_or_default();
+                let idle = parts
+                    .next()
+                    .unwrap_or_default()
+                    .parse::<u64>()
+                    .unwrap_or_default();
                 let iowait = 0;
-                let irq = parts.next().unwrap_or_default().parse::<u64>().unwrap_or_default();
+                let irq = parts
+                    .next()
+                    .unwrap_or_default()
+                    .parse::<u64>()
+                    .unwrap_or_default();
                 let softirq = 0;
                 let steal = 0;
                 let guest = 0;
@@ -91,22 +128,20 @@ Description of fields above
                 // Global stats
                 if name == "cpu" {
                     self.global_cpu.set(
-                        user,
-                        nice,
-                        system,
-                        idle,
-                        iowait,
-                        irq,
-                        softirq,
-                        steal,
-                        guest,
-                        guest_nice,
+                        user, nice, system, idle, iowait, irq, softirq, steal, guest, guest_nice,
                     );
+                    // The aggregate line comes first, so no need to go through the per-cpu
+                    // lines if they're not needed.
+                    if only_update_global_cpu && !first {
+                        break;
+                    }
                     continue;
                 }
 
                 // Per-cpu stats
-                let Ok(i) = name[3..].parse::<usize>() else { continue };
+                let Ok(i) = name[3..].parse::<usize>() else {
+                    continue;
+                };
                 if first {
                     let (vendor_id, brand) = match vendors_brands.remove(&i) {
                         Some((vendor_id, brand)) => (vendor_id, brand),
@@ -114,34 +149,15 @@ Description of fields above
                     };
                     self.cpus.push(Cpu {
                         inner: CpuInner::new_with_values(
-                            name,
-                            user,
-                            nice,
-                            system,
-                            idle,
-                            iowait,
-                            irq,
-                            softirq,
-                            steal,
-                            guest,
-                            guest_nice,
-                            0,
-                            vendor_id,
-                            brand,
+                            name, user, nice, system, idle, iowait, irq, softirq, steal, guest,
+                            guest_nice, 0, vendor_id, brand,
                         ),
+                        peak_usage: 0.,
+                        measurement_interval: None,
                     });
                 } else if let Some(cpu) = self.cpus.get_mut(i) {
                     cpu.inner.set(
-                        user,
-                        nice,
-                        system,
-                        idle,
-                        iowait,
-                        irq,
-                        softirq,
-                        steal,
-                        guest,
-                        guest_nice,
+                        user, nice, system, idle, iowait, irq, softirq, steal, guest, guest_nice,
                     );
                 }
             }
@@ -150,6 +166,7 @@ Description of fields above
         if refresh_kind.frequency() {
             //TODO: cpu frequency
         }
+        Ok(())
     }
 
     pub(crate) fn get_global_raw_times(&self) -> (u64, u64) {
@@ -406,6 +423,22 @@ impl CpuInner {
     pub(crate) fn brand(&self) -> &str {
         &self.brand
     }
+
+    pub(crate) fn run_queue_length(&self) -> Option<usize> {
+        None
+    }
+
+    pub(crate) fn run_queue_wait_time(&self) -> Option<u64> {
+        None
+    }
+
+    pub(crate) fn boost_active(&self) -> Option<bool> {
+        None
+    }
+
+    pub(crate) fn idle_states(&self) -> &[crate::CpuIdleState] {
+        &[]
+    }
 }
 
 /// Returns the brand/vendor string for the first CPU (which should be the same for all CPUs).
@@ -426,10 +459,10 @@ pub(crate) fn get_vendor_id_and_brand() -> HashMap<usize, (String, String)> {
         match key {
             "CPUs" => {
                 value.parse::<usize>().map(|x| count = x);
-            },
+            }
             "Vendor" => {
                 vendor = value.to_string();
-            },
+            }
             "Model" => {
                 model = value.to_string();
             }
@@ -440,4 +473,4 @@ pub(crate) fn get_vendor_id_and_brand() -> HashMap<usize, (String, String)> {
         cpus.insert(id, (vendor.clone(), model.clone()));
     }
     cpus
-}
\ No newline at end of file
+}
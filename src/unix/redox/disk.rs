@@ -1,7 +1,7 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
 use crate::sys::utils::{get_all_utf8_data, to_cpath};
-use crate::{Disk, DiskKind, DiskRefreshKind, DiskUsage};
+use crate::{Disk, DiskKind, DiskRefreshKind, DiskSpace, DiskUsage, RefreshError};
 
 use libc::statvfs;
 use std::collections::HashMap;
@@ -138,6 +138,14 @@ impl DiskInner {
             total_written_bytes: self.written_bytes,
         }
     }
+
+    pub(crate) fn network_fs_stats(&self) -> Option<crate::NetworkFsStats> {
+        None
+    }
+
+    pub(crate) fn io_stats(&self) -> Option<crate::DiskIoStats> {
+        None
+    }
 }
 
 impl crate::DisksInner {
@@ -147,16 +155,14 @@ impl crate::DisksInner {
         }
     }
 
-    pub(crate) fn refresh_specifics(
+    pub(crate) fn try_refresh_specifics(
         &mut self,
         remove_not_listed_disks: bool,
         refresh_kind: DiskRefreshKind,
-    ) {
-        get_all_list(
-            &mut self.disks,
-            &get_all_utf8_data("/proc/mounts", 16_385).unwrap_or_default(),
-            refresh_kind,
-        );
+    ) -> Result<(), RefreshError> {
+        let mounts = get_all_utf8_data("/proc/mounts", 16_385)
+            .map_err(|e| RefreshError::from_io("/proc/mounts", e))?;
+        get_all_list(&mut self.disks, &mounts, refresh_kind);
 
         if remove_not_listed_disks {
             self.disks.retain_mut(|disk| {
@@ -171,6 +177,17 @@ impl crate::DisksInner {
                 c.inner.updated = false;
             }
         }
+        Ok(())
+    }
+
+    pub(crate) fn refresh_specifics(
+        &mut self,
+        remove_not_listed_disks: bool,
+        refresh_kind: DiskRefreshKind,
+    ) {
+        if let Err(_e) = self.try_refresh_specifics(remove_not_listed_disks, refresh_kind) {
+            sysinfo_debug!("failed to refresh disks: {_e:?}");
+        }
     }
 
     pub(crate) fn list(&self) -> &[Disk] {
@@ -180,6 +197,47 @@ impl crate::DisksInner {
     pub(crate) fn list_mut(&mut self) -> &mut [Disk] {
         &mut self.disks
     }
+
+    pub(crate) fn usage_for_path(path: &Path) -> Option<DiskSpace> {
+        let path = fs::canonicalize(path).ok()?;
+        let mounts = get_all_utf8_data("/proc/mounts", 16_385).ok()?;
+        // Mount points are listed in the order they were mounted. If the same mount point is
+        // used more than once, only the last one is visible, and `max_by_key` returns the last
+        // maximum element.
+        let mount_point = mounts
+            .lines()
+            .filter_map(|line| {
+                Some(PathBuf::from(unescape_mount_point(
+                    line.split_whitespace().nth(1)?,
+                )))
+            })
+            .filter(|mount_point| path.starts_with(mount_point))
+            .max_by_key(|mount_point| mount_point.components().count())?;
+
+        let path = to_cpath(&path);
+        let mut stat: MaybeUninit<statvfs> = MaybeUninit::uninit();
+        if unsafe { retry_eintr!(statvfs(path.as_ptr() as *const _, stat.as_mut_ptr())) } != 0 {
+            return None;
+        }
+        let stat = unsafe { stat.assume_init() };
+        let bsize = cast!(stat.f_bsize);
+        Some(DiskSpace {
+            mount_point,
+            total_space: bsize.saturating_mul(cast!(stat.f_blocks)),
+            available_space: bsize.saturating_mul(cast!(stat.f_bavail)),
+            used_space: bsize
+                .saturating_mul(cast!(stat.f_blocks).saturating_sub(cast!(stat.f_bfree))),
+        })
+    }
+}
+
+/// Replaces the characters escaped in the mount points of `/proc/mounts`.
+fn unescape_mount_point(mount_point: &str) -> String {
+    mount_point
+        .replace("\\134", "\\")
+        .replace("\\040", " ")
+        .replace("\\011", "\t")
+        .replace("\\012", "\n")
 }
 
 /// Resolves the actual device name for a specified `device` from `/proc/mounts`
@@ -366,13 +424,7 @@ fn get_all_list(container: &mut Vec<Disk>, content: &str, refresh_kind: DiskRefr
             // fs_spec<tab>fs_file<tab>fs_vfstype<tab>other fields
             let mut fields = line.split_whitespace();
             let fs_spec = fields.next().unwrap_or("");
-            let fs_file = fields
-                .next()
-                .unwrap_or("")
-                .replace("\\134", "\\")
-                .replace("\\040", " ")
-                .replace("\\011", "\t")
-                .replace("\\012", "\n");
+            let fs_file = unescape_mount_point(fields.next().unwrap_or(""));
             let fs_vfstype = fields.next().unwrap_or("");
             (fs_spec, fs_file, fs_vfstype)
         })
@@ -0,0 +1,54 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::Display;
+
+use std::env;
+use std::fs;
+
+/// Parses the path of the orbital display (like `/scheme/orbital/0/1920/1080`), which ends with
+/// its width and height.
+fn parse_display_path(path: &str) -> Option<(u32, u32)> {
+    let mut parts = path.trim_end_matches('/').rsplit('/');
+    let height = parts.next()?.parse().ok()?;
+    let width = parts.next()?.parse().ok()?;
+    Some((width, height))
+}
+
+pub(crate) fn get_displays(displays: &mut Vec<Display>) {
+    displays.clear();
+
+    // Like `orbclient`, we retrieve the display size from the path of the display scheme.
+    let Ok(display) = env::var("DISPLAY") else {
+        return;
+    };
+    let Some((width, height)) = fs::canonicalize(&display)
+        .ok()
+        .and_then(|path| parse_display_path(path.to_str()?))
+    else {
+        return;
+    };
+    displays.push(Display {
+        id: display,
+        name: None,
+        width,
+        height,
+        refresh_rate: None,
+        gpu: None,
+        is_primary: true,
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_display_path;
+
+    #[test]
+    fn test_parse_display_path() {
+        assert_eq!(
+            parse_display_path("/scheme/orbital/0/1920/1080"),
+            Some((1920, 1080))
+        );
+        assert_eq!(parse_display_path("orbital:0/800/600/"), Some((800, 600)));
+        assert_eq!(parse_display_path("/scheme/orbital"), None);
+    }
+}
@@ -3,10 +3,11 @@
 use std::collections::{HashMap, hash_map};
 use std::fs::File;
 use std::io::Read;
+use std::net::IpAddr;
 use std::path::Path;
 
 use crate::network::refresh_networks_addresses;
-use crate::{IpNetwork, MacAddr, NetworkData};
+use crate::{DnsConfiguration, IpNetwork, MacAddr, NetworkData, RefreshError};
 
 macro_rules! old_and_new {
     ($ty_:expr, $name:ident, $old:ident) => {{
@@ -42,77 +43,104 @@ fn refresh_networks_list_from_sysfs(
     interfaces: &mut HashMap<String, NetworkData>,
     remove_not_listed_interfaces: bool,
     sysfs_net: &Path,
-) {
-    if let Ok(dir) = std::fs::read_dir(sysfs_net) {
-        let mut data = vec![0; 30];
+) -> std::io::Result<()> {
+    let dir = std::fs::read_dir(sysfs_net)?;
+    let mut data = vec![0; 30];
 
-        for stats in interfaces.values_mut() {
-            stats.inner.updated = false;
-        }
+    for stats in interfaces.values_mut() {
+        stats.inner.updated = false;
+    }
 
-        for entry in dir.flatten() {
-            let parent = &entry.path().join("statistics");
-            let entry_path = &entry.path();
-            let entry = match entry.file_name().into_string() {
-                Ok(entry) => entry,
-                Err(_) => continue,
-            };
-            let rx_bytes = read(parent, "rx_bytes", &mut data);
-            let tx_bytes = read(parent, "tx_bytes", &mut data);
-            let rx_packets = read(parent, "rx_packets", &mut data);
-            let tx_packets = read(parent, "tx_packets", &mut data);
-            let rx_errors = read(parent, "rx_errors", &mut data);
-            let tx_errors = read(parent, "tx_errors", &mut data);
-            // let rx_compressed = read(parent, "rx_compressed", &mut data);
-            // let tx_compressed = read(parent, "tx_compressed", &mut data);
-            let mtu = read(entry_path, "mtu", &mut data);
-
-            match interfaces.entry(entry) {
-                hash_map::Entry::Occupied(mut e) => {
-                    let interface = e.get_mut();
-                    let interface = &mut interface.inner;
-
-                    old_and_new!(interface, rx_bytes, old_rx_bytes);
-                    old_and_new!(interface, tx_bytes, old_tx_bytes);
-                    old_and_new!(interface, rx_packets, old_rx_packets);
-                    old_and_new!(interface, tx_packets, old_tx_packets);
-                    old_and_new!(interface, rx_errors, old_rx_errors);
-                    old_and_new!(interface, tx_errors, old_tx_errors);
-                    // old_and_new!(e, rx_compressed, old_rx_compressed);
-                    // old_and_new!(e, tx_compressed, old_tx_compressed);
-                    if interface.mtu != mtu {
-                        interface.mtu = mtu;
-                    }
-                    interface.updated = true;
-                }
-                hash_map::Entry::Vacant(e) => {
-                    e.insert(NetworkData {
-                        inner: NetworkDataInner {
-                            rx_bytes,
-                            old_rx_bytes: rx_bytes,
-                            tx_bytes,
-                            old_tx_bytes: tx_bytes,
-                            rx_packets,
-                            old_rx_packets: rx_packets,
-                            tx_packets,
-                            old_tx_packets: tx_packets,
-                            rx_errors,
-                            old_rx_errors: rx_errors,
-                            tx_errors,
-                            old_tx_errors: tx_errors,
-                            mac_addr: MacAddr::UNSPECIFIED,
-                            ip_networks: vec![],
-                            // rx_compressed,
-                            // old_rx_compressed: rx_compressed,
-                            // tx_compressed,
-                            // old_tx_compressed: tx_compressed,
-                            mtu,
-                            updated: true,
-                        },
-                    });
+    for entry in dir.flatten() {
+        let parent = &entry.path().join("statistics");
+        let entry_path = &entry.path();
+        let entry = match entry.file_name().into_string() {
+            Ok(entry) => entry,
+            Err(_) => continue,
+        };
+        let rx_bytes = read(parent, "rx_bytes", &mut data);
+        let tx_bytes = read(parent, "tx_bytes", &mut data);
+        let rx_packets = read(parent, "rx_packets", &mut data);
+        let tx_packets = read(parent, "tx_packets", &mut data);
+        let rx_errors = read(parent, "rx_errors", &mut data);
+        let tx_errors = read(parent, "tx_errors", &mut data);
+        let rx_dropped = read(parent, "rx_dropped", &mut data);
+        let tx_dropped = read(parent, "tx_dropped", &mut data);
+        let rx_fifo_errors = read(parent, "rx_fifo_errors", &mut data);
+        let tx_fifo_errors = read(parent, "tx_fifo_errors", &mut data);
+        let collisions = read(parent, "collisions", &mut data);
+        let tx_carrier_errors = read(parent, "tx_carrier_errors", &mut data);
+        let multicast = read(parent, "multicast", &mut data);
+        // let rx_compressed = read(parent, "rx_compressed", &mut data);
+        // let tx_compressed = read(parent, "tx_compressed", &mut data);
+        let mtu = read(entry_path, "mtu", &mut data);
+
+        match interfaces.entry(entry) {
+            hash_map::Entry::Occupied(mut e) => {
+                let interface = e.get_mut();
+                let interface = &mut interface.inner;
+
+                old_and_new!(interface, rx_bytes, old_rx_bytes);
+                old_and_new!(interface, tx_bytes, old_tx_bytes);
+                old_and_new!(interface, rx_packets, old_rx_packets);
+                old_and_new!(interface, tx_packets, old_tx_packets);
+                old_and_new!(interface, rx_errors, old_rx_errors);
+                old_and_new!(interface, tx_errors, old_tx_errors);
+                old_and_new!(interface, rx_dropped, old_rx_dropped);
+                old_and_new!(interface, tx_dropped, old_tx_dropped);
+                old_and_new!(interface, rx_fifo_errors, old_rx_fifo_errors);
+                old_and_new!(interface, tx_fifo_errors, old_tx_fifo_errors);
+                old_and_new!(interface, collisions, old_collisions);
+                old_and_new!(interface, tx_carrier_errors, old_tx_carrier_errors);
+                old_and_new!(interface, multicast, old_multicast);
+                // old_and_new!(e, rx_compressed, old_rx_compressed);
+                // old_and_new!(e, tx_compressed, old_tx_compressed);
+                if interface.mtu != mtu {
+                    interface.mtu = mtu;
                 }
-            };
-        }
+                interface.updated = true;
+            }
+            hash_map::Entry::Vacant(e) => {
+                e.insert(NetworkData {
+                    inner: NetworkDataInner {
+                        rx_bytes,
+                        old_rx_bytes: rx_bytes,
+                        tx_bytes,
+                        old_tx_bytes: tx_bytes,
+                        rx_packets,
+                        old_rx_packets: rx_packets,
+                        tx_packets,
+                        old_tx_packets: tx_packets,
+                        rx_errors,
+                        old_rx_errors: rx_errors,
+                        tx_errors,
+                        old_tx_errors: tx_errors,
+                        rx_dropped,
+                        old_rx_dropped: rx_dropped,
+                        tx_dropped,
+                        old_tx_dropped: tx_dropped,
+                        rx_fifo_errors,
+                        old_rx_fifo_errors: rx_fifo_errors,
+                        tx_fifo_errors,
+                        old_tx_fifo_errors: tx_fifo_errors,
+                        collisions,
+                        old_collisions: collisions,
+                        tx_carrier_errors,
+                        old_tx_carrier_errors: tx_carrier_errors,
+                        multicast,
+                        old_multicast: multicast,
+                        mac_addr: MacAddr::UNSPECIFIED,
+                        ip_networks: vec![],
+                        // rx_compressed,
+                        // old_rx_compressed: rx_compressed,
+                        // tx_compressed,
+                        // old_tx_compressed: tx_compressed,
+                        mtu,
+                        updated: true,
+                    },
+                });
+            }
+        };
     }
     // We do this here because `refresh_networks_list_remove_interface` test is checking that
     // this is working as expected.
@@ -126,6 +154,7 @@ fn refresh_networks_list_from_sysfs(
             true
         });
     }
+    Ok(())
 }
 
 pub(crate) struct NetworksInner {
@@ -143,13 +172,57 @@ impl NetworksInner {
         &self.interfaces
     }
 
-    pub(crate) fn refresh(&mut self, remove_not_listed_interfaces: bool) {
-        refresh_networks_list_from_sysfs(
+    pub(crate) fn try_refresh(
+        &mut self,
+        remove_not_listed_interfaces: bool,
+    ) -> Result<(), RefreshError> {
+        let path = Path::new("/sys/class/net/");
+        let ret = refresh_networks_list_from_sysfs(
             &mut self.interfaces,
             remove_not_listed_interfaces,
-            Path::new("/sys/class/net/"),
-        );
+            path,
+        )
+        .map_err(|e| RefreshError::from_io(path, e));
         refresh_networks_addresses(&mut self.interfaces);
+        ret
+    }
+
+    pub(crate) fn refresh(&mut self, remove_not_listed_interfaces: bool) {
+        if let Err(_e) = self.try_refresh(remove_not_listed_interfaces) {
+            sysinfo_debug!("failed to refresh network interfaces: {_e:?}");
+        }
+    }
+
+    pub(crate) fn dns_configuration() -> DnsConfiguration {
+        // The nameserver used by the network stack is stored in `/etc/net/dns`.
+        let nameservers = std::fs::read_to_string("/etc/net/dns")
+            .map(|data| {
+                data.lines()
+                    .filter_map(|line| line.trim().parse().ok())
+                    .collect()
+            })
+            .unwrap_or_default();
+        DnsConfiguration {
+            nameservers,
+            search_domains: Vec::new(),
+        }
+    }
+
+    pub(crate) fn default_gateway() -> Option<IpAddr> {
+        // The gateway used by the network stack is stored in `/etc/net/ip_router`.
+        std::fs::read_to_string("/etc/net/ip_router")
+            .ok()?
+            .trim()
+            .parse()
+            .ok()
+    }
+
+    pub(crate) fn primary_interface() -> Option<String> {
+        None
+    }
+
+    pub(crate) fn protocol_stats() -> Option<crate::ProtocolStats> {
+        None
     }
 }
 
@@ -174,6 +247,27 @@ pub(crate) struct NetworkDataInner {
     /// similar to `rx_errors`
     tx_errors: u64,
     old_tx_errors: u64,
+    /// Number of packets received but dropped by the kernel (like when running out of buffers).
+    rx_dropped: u64,
+    old_rx_dropped: u64,
+    /// Number of packets dropped on their way to transmission.
+    tx_dropped: u64,
+    old_tx_dropped: u64,
+    /// Number of receiver FIFO overruns.
+    rx_fifo_errors: u64,
+    old_rx_fifo_errors: u64,
+    /// Number of transmitter FIFO underruns.
+    tx_fifo_errors: u64,
+    old_tx_fifo_errors: u64,
+    /// Number of collisions during packet transmissions.
+    collisions: u64,
+    old_collisions: u64,
+    /// Number of frames transmission errors due to loss of carrier.
+    tx_carrier_errors: u64,
+    old_tx_carrier_errors: u64,
+    /// Number of multicast packets received.
+    multicast: u64,
+    old_multicast: u64,
     /// MAC address
     pub(crate) mac_addr: MacAddr,
     pub(crate) ip_networks: Vec<IpNetwork>,
@@ -242,6 +336,63 @@ impl NetworkDataInner {
         self.tx_errors
     }
 
+    pub(crate) fn drops_on_received(&self) -> u64 {
+        self.rx_dropped.saturating_sub(self.old_rx_dropped)
+    }
+
+    pub(crate) fn total_drops_on_received(&self) -> u64 {
+        self.rx_dropped
+    }
+
+    pub(crate) fn drops_on_transmitted(&self) -> u64 {
+        self.tx_dropped.saturating_sub(self.old_tx_dropped)
+    }
+
+    pub(crate) fn total_drops_on_transmitted(&self) -> u64 {
+        self.tx_dropped
+    }
+
+    pub(crate) fn fifo_errors_on_received(&self) -> u64 {
+        self.rx_fifo_errors.saturating_sub(self.old_rx_fifo_errors)
+    }
+
+    pub(crate) fn total_fifo_errors_on_received(&self) -> u64 {
+        self.rx_fifo_errors
+    }
+
+    pub(crate) fn fifo_errors_on_transmitted(&self) -> u64 {
+        self.tx_fifo_errors.saturating_sub(self.old_tx_fifo_errors)
+    }
+
+    pub(crate) fn total_fifo_errors_on_transmitted(&self) -> u64 {
+        self.tx_fifo_errors
+    }
+
+    pub(crate) fn collisions(&self) -> u64 {
+        self.collisions.saturating_sub(self.old_collisions)
+    }
+
+    pub(crate) fn total_collisions(&self) -> u64 {
+        self.collisions
+    }
+
+    pub(crate) fn carrier_errors(&self) -> u64 {
+        self.tx_carrier_errors
+            .saturating_sub(self.old_tx_carrier_errors)
+    }
+
+    pub(crate) fn total_carrier_errors(&self) -> u64 {
+        self.tx_carrier_errors
+    }
+
+    pub(crate) fn multicast_packets_received(&self) -> u64 {
+        self.multicast.saturating_sub(self.old_multicast)
+    }
+
+    pub(crate) fn total_multicast_packets_received(&self) -> u64 {
+        self.multicast
+    }
+
     pub(crate) fn mac_address(&self) -> MacAddr {
         self.mac_addr
     }
@@ -269,12 +420,12 @@ mod test {
 
         let mut interfaces = HashMap::new();
 
-        refresh_networks_list_from_sysfs(&mut interfaces, false, sys_net_dir.path());
+        refresh_networks_list_from_sysfs(&mut interfaces, false, sys_net_dir.path()).unwrap();
         assert_eq!(interfaces.keys().collect::<Vec<_>>(), ["itf1"]);
 
         fs::create_dir(sys_net_dir.path().join("itf2")).expect("failed to create subdirectory");
 
-        refresh_networks_list_from_sysfs(&mut interfaces, false, sys_net_dir.path());
+        refresh_networks_list_from_sysfs(&mut interfaces, false, sys_net_dir.path()).unwrap();
         let mut itf_names: Vec<String> = interfaces.keys().map(|n| n.to_owned()).collect();
         itf_names.sort();
         assert_eq!(itf_names, ["itf1", "itf2"]);
@@ -291,14 +442,14 @@ mod test {
 
         let mut interfaces = HashMap::new();
 
-        refresh_networks_list_from_sysfs(&mut interfaces, false, sys_net_dir.path());
+        refresh_networks_list_from_sysfs(&mut interfaces, false, sys_net_dir.path()).unwrap();
         let mut itf_names: Vec<String> = interfaces.keys().map(|n| n.to_owned()).collect();
         itf_names.sort();
         assert_eq!(itf_names, ["itf1", "itf2"]);
 
         fs::remove_dir(&itf1_dir).expect("failed to remove subdirectory");
 
-        refresh_networks_list_from_sysfs(&mut interfaces, true, sys_net_dir.path());
+        refresh_networks_list_from_sysfs(&mut interfaces, true, sys_net_dir.path()).unwrap();
         assert_eq!(interfaces.keys().collect::<Vec<_>>(), ["itf2"]);
     }
 }
@@ -4,23 +4,30 @@ use std::cell::UnsafeCell;
 use std::collections::{HashMap, HashSet};
 use std::ffi::{OsStr, OsString};
 use std::fmt;
-use std::fs::{self, DirEntry, File, read_dir};
+use std::fs::{DirEntry, File, read_dir};
 use std::io::Read;
+use std::net::SocketAddr;
 use std::os::unix::ffi::OsStrExt;
 use std::path::{Path, PathBuf};
 use std::process::ExitStatus;
 use std::str::{self, FromStr};
+use std::sync::OnceLock;
 use std::sync::atomic::{AtomicUsize, Ordering};
 
 use libc::{c_ulong, gid_t, uid_t};
 
+use crate::parsing::{
+    RedoxContextLine, RedoxPsLine, parse_redox_context_line, parse_redox_context_memory,
+    parse_redox_context_time, parse_redox_ps_line,
+};
 use crate::sys::system::SystemInfo;
 use crate::sys::utils::{
-    PathHandler, PathPush, get_all_data_from_file, get_all_utf8_data, realpath,
+    PathHandler, PathPush, get_all_data_from_file, get_all_utf8_data, read_utf8_data, realpath,
+    with_scratch_buffer,
 };
 use crate::{
-    DiskUsage, Gid, Pid, Process, ProcessRefreshKind, ProcessStatus, ProcessesToUpdate, Signal,
-    ThreadKind, Uid,
+    CurrentProcessUsage, DiskUsage, EnergyImpact, ExeMetadata, ExeSignature, Gid, MediaUsage, Pid,
+    Process, ProcessRefreshKind, ProcessStatus, ProcessesToUpdate, Signal, ThreadKind, Uid,
 };
 
 use crate::sys::system::remaining_files;
@@ -64,41 +71,15 @@ impl fmt::Display for ProcessStatus {
     }
 }
 
-#[allow(dead_code)]
-#[repr(usize)]
-enum ProcIndex {
-    Pid = 0,
-    State,
-    ParentPid,
-    GroupId,
-    SessionId,
-    Tty,
-    ForegroundProcessGroupId,
-    Flags,
-    MinorFaults,
-    ChildrenMinorFaults,
-    MajorFaults,
-    ChildrenMajorFaults,
-    UserTime,
-    SystemTime,
-    ChildrenUserTime,
-    ChildrenKernelTime,
-    Priority,
-    Nice,
-    NumberOfThreads,
-    IntervalTimerSigalarm,
-    StartTime,
-    VirtualSize,
-    ResidentSetSize,
-    // More exist but we only use the listed ones. For more, take a look at `man proc`.
-}
-
 pub(crate) struct ProcessInner {
     pub(crate) name: OsString,
     pub(crate) cmd: Vec<OsString>,
     pub(crate) exe: Option<PathBuf>,
     pub(crate) pid: Pid,
     parent: Option<Pid>,
+    thread_count: Option<usize>,
+    session_id: Option<Pid>,
+    process_group_id: Option<Pid>,
     pub(crate) environ: Vec<OsString>,
     pub(crate) cwd: Option<PathBuf>,
     pub(crate) root: Option<PathBuf>,
@@ -127,6 +108,8 @@ pub(crate) struct ProcessInner {
     written_bytes: u64,
     thread_kind: Option<ThreadKind>,
     accumulated_cpu_time: u64,
+    exe_signature: OnceLock<Option<ExeSignature>>,
+    exe_metadata: OnceLock<Option<ExeMetadata>>,
     exists: bool,
 }
 
@@ -136,6 +119,9 @@ impl ProcessInner {
             name: OsString::new(),
             pid,
             parent: None,
+            thread_count: None,
+            session_id: None,
+            process_group_id: None,
             cmd: Vec::new(),
             environ: Vec::new(),
             exe: None,
@@ -166,6 +152,8 @@ impl ProcessInner {
             written_bytes: 0,
             thread_kind: None,
             accumulated_cpu_time: 0,
+            exe_signature: OnceLock::new(),
+            exe_metadata: OnceLock::new(),
             exists: true,
         }
     }
@@ -215,6 +203,22 @@ impl ProcessInner {
         self.parent
     }
 
+    pub(crate) fn thread_count(&self) -> Option<usize> {
+        self.thread_count
+    }
+
+    pub(crate) fn tty(&self) -> Option<String> {
+        None
+    }
+
+    pub(crate) fn reparented_to_init(&self) -> bool {
+        false
+    }
+
+    pub(crate) fn exec_count(&self) -> u64 {
+        0
+    }
+
     pub(crate) fn status(&self) -> ProcessStatus {
         self.status
     }
@@ -223,6 +227,10 @@ impl ProcessInner {
         self.start_time
     }
 
+    pub(crate) fn start_time_raw(&self) -> u64 {
+        self.start_time_raw
+    }
+
     pub(crate) fn run_time(&self) -> u64 {
         self.run_time
     }
@@ -235,6 +243,10 @@ impl ProcessInner {
         self.accumulated_cpu_time
     }
 
+    pub(crate) fn children_cpu_time(&self) -> u64 {
+        0
+    }
+
     pub(crate) fn disk_usage(&self) -> DiskUsage {
         DiskUsage {
             written_bytes: self.written_bytes.saturating_sub(self.old_written_bytes),
@@ -265,18 +277,37 @@ impl ProcessInner {
     }
 
     pub(crate) fn session_id(&self) -> Option<Pid> {
-        unsafe {
-            unsafe extern "C" {
-                //TODO: expose getsid in libc crate
-                fn getsid(pid: libc::pid_t) -> libc::pid_t;
-            }
-            let session_id = getsid(self.pid.0);
-            if session_id < 0 {
-                None
-            } else {
-                Some(Pid(session_id))
+        self.session_id
+    }
+
+    pub(crate) fn process_group_id(&self) -> Option<Pid> {
+        self.process_group_id
+    }
+
+    pub(crate) fn current_usage() -> Option<CurrentProcessUsage> {
+        let pid = Pid(unsafe { libc::getpid() });
+        with_scratch_buffer(|buf| {
+            let sys_context = read_utf8_data("/scheme/sys/context", buf).ok()?;
+            let mut usage = CurrentProcessUsage::default();
+            for line in sys_context.lines().skip(1) {
+                if line
+                    .get(0..6)
+                    .and_then(|pid| pid.trim().parse::<Pid>().ok())
+                    != Some(pid)
+                {
+                    continue;
+                }
+                let mem = parse_redox_context_memory(line);
+                usage.memory += mem;
+                usage.virtual_memory += mem;
+                usage.accumulated_cpu_time += parse_redox_context_time(line);
             }
-        }
+            Some(usage)
+        })
+    }
+
+    pub(crate) fn children_usage() -> Option<crate::ChildrenUsage> {
+        None
     }
 
     pub(crate) fn thread_kind(&self) -> Option<ThreadKind> {
@@ -299,9 +330,99 @@ impl ProcessInner {
         None
     }
 
+    pub(crate) fn path_usage(&self, path: &Path) -> crate::PathUsage {
+        crate::PathUsage::from_process_paths(path, self.cwd(), self.root(), self.exe())
+    }
+
+    pub(crate) fn deleted_mappings(&self) -> Vec<PathBuf> {
+        Vec::new()
+    }
+
     pub(crate) fn open_files_limit(&self) -> Option<usize> {
         None
     }
+
+    pub(crate) fn stack_size(&self) -> Option<u64> {
+        None
+    }
+
+    pub(crate) fn heap_size(&self) -> Option<u64> {
+        None
+    }
+
+    pub(crate) fn cgroup(&self) -> Option<String> {
+        None
+    }
+
+    pub(crate) fn container_id(&self) -> Option<String> {
+        None
+    }
+
+    pub(crate) fn sched_stats(&self) -> Option<crate::SchedStats> {
+        None
+    }
+
+    pub(crate) fn io_counters(&self) -> Option<crate::IoCounters> {
+        None
+    }
+
+    pub(crate) fn wait_channel(&self) -> Option<String> {
+        None
+    }
+
+    pub(crate) fn kernel_stack(&self) -> Result<Vec<String>, crate::RefreshError> {
+        Err(crate::RefreshError::Unsupported)
+    }
+
+    pub(crate) fn connection_count(&self) -> Option<crate::ConnectionCount> {
+        None
+    }
+
+    pub(crate) fn is_64_bit(&self) -> Option<bool> {
+        None
+    }
+
+    pub(crate) fn abi(&self) -> Option<&'static str> {
+        None
+    }
+
+    pub(crate) fn listening_ports(&self) -> Option<Vec<SocketAddr>> {
+        None
+    }
+
+    pub(crate) fn exe_signature(&self) -> Option<&ExeSignature> {
+        let exe = self.exe.as_deref()?;
+        self.exe_signature
+            .get_or_init(|| crate::sha256::sha256_file(exe).map(ExeSignature::Sha256))
+            .as_ref()
+    }
+
+    pub(crate) fn exe_metadata(&self) -> Option<&ExeMetadata> {
+        let exe = self.exe.as_deref()?;
+        self.exe_metadata
+            .get_or_init(|| crate::exe_metadata::read_exe_metadata(exe))
+            .as_ref()
+    }
+
+    pub(crate) fn bundle_identifier(&self) -> Option<&str> {
+        None
+    }
+
+    pub(crate) fn bundle_name(&self) -> Option<&str> {
+        None
+    }
+
+    pub(crate) fn energy_impact(&self) -> Option<EnergyImpact> {
+        None
+    }
+
+    pub(crate) fn gpu_usage(&self) -> Option<f32> {
+        None
+    }
+
+    pub(crate) fn media_usage(&self) -> Option<MediaUsage> {
+        None
+    }
 }
 
 pub(crate) fn compute_cpu_usage(p: &mut ProcessInner, total_time: f32, max_value: f32) {
@@ -321,11 +442,6 @@ pub(crate) fn compute_cpu_usage(p: &mut ProcessInner, total_time: f32, max_value
         .min(max_value);
 }
 
-#[inline(always)]
-fn start_time_raw(parts: &Parts<'_>) -> u64 {
-    u64::from_str(parts.str_parts[ProcIndex::StartTime as usize]).unwrap_or(0)
-}
-
 fn _get_stat_data_and_file(path: &Path) -> Result<(Vec<u8>, File), ()> {
     let mut file = File::open(path.join("stat")).map_err(|_| ())?;
     let data = get_all_data_from_file(&mut file, 1024).map_err(|_| ())?;
@@ -355,17 +471,46 @@ pub(crate) fn refresh_procs(
     info: &SystemInfo,
     processes_to_update: ProcessesToUpdate<'_>,
     refresh_kind: ProcessRefreshKind,
+) -> usize {
+    // The buffers are reused across refreshes to avoid allocating new ones every time.
+    with_scratch_buffer(|proc_ps| {
+        with_scratch_buffer(|sys_context| {
+            //TODO: these could be out of sync
+            let proc_ps = read_utf8_data("/scheme/proc/ps", proc_ps).unwrap_or_default();
+            let sys_context =
+                read_utf8_data("/scheme/sys/context", sys_context).unwrap_or_default();
+            update_procs(
+                proc_list,
+                proc_ps,
+                sys_context,
+                uptime,
+                info,
+                processes_to_update,
+                refresh_kind,
+            )
+        })
+    })
+}
+
+fn update_procs(
+    proc_list: &mut HashMap<Pid, Process>,
+    proc_ps: &str,
+    sys_context: &str,
+    uptime: u64,
+    info: &SystemInfo,
+    processes_to_update: ProcessesToUpdate<'_>,
+    refresh_kind: ProcessRefreshKind,
 ) -> usize {
     let mut nb_updated = 0;
-    //TODO: these could be out of sync
-    let proc_ps = fs::read_to_string("/scheme/proc/ps").unwrap_or_default();
-    let sys_context = fs::read_to_string("/scheme/sys/context").unwrap_or_default();
 
     // Reset current processes
     for (pid, proc) in proc_list.iter_mut() {
         let mut p = &mut proc.inner;
         p.name.clear();
         p.parent = None;
+        p.thread_count = None;
+        p.session_id = None;
+        p.process_group_id = None;
         p.memory = 0;
         p.virtual_memory = 0;
         p.old_utime = p.utime;
@@ -383,28 +528,38 @@ pub(crate) fn refresh_procs(
         p.exists = false;
     }
 
-/* Example data from /scheme/proc/ps:
-PID   PGID  PPID  SID   RUID  RGID  RNS   EUID  EGID  ENS   NTHRD STATUS  NAME
-1     1     1     1     0     0     1     0     0     1     1     R       /scheme/initfs/bin/init
-4     1     1     1     0     0     0     0     0     0     1     R       /bin/nulld
-0     6     12    18    24    30    36    42    48    54    60    66      74
-Indexes listed above
-*/
     for line in proc_ps.lines().skip(1) {
-        let Ok(pid) = line[0..6].trim().parse::<usize>().map(Pid::from) else { continue };
-        let ppid = line[12..18].trim().parse::<Pid>().ok();
-        let ruid = line[24..30].trim().parse::<libc::uid_t>().map(Uid).ok();
-        let rgid = line[30..36].trim().parse::<libc::gid_t>().map(Gid).ok();
-        let euid = line[42..48].trim().parse::<libc::uid_t>().map(Uid).ok();
-        let egid = line[48..54].trim().parse::<libc::gid_t>().map(Gid).ok();
-        let status = line[66..74].trim().chars().next().unwrap_or_default();
-        let name = &line[74..];
+        let Some(RedoxPsLine {
+            pid,
+            pgid,
+            ppid,
+            sid,
+            ruid,
+            rgid,
+            euid,
+            egid,
+            nthrd,
+            status,
+            name,
+        }) = parse_redox_ps_line(line)
+        else {
+            continue;
+        };
+        let pid = Pid::from(pid);
+        let pgid = pgid.map(Pid::from);
+        let ppid = ppid.map(Pid::from);
+        let sid = sid.map(Pid::from);
+        let ruid = ruid.map(Uid);
+        let rgid = rgid.map(Gid);
+        let euid = euid.map(Uid);
+        let egid = egid.map(Gid);
 
         //TODO: use TID or fill in tasks?
         //TODO: /proc not implemented so this path is not useful
         //TODO: fill in more fields
         let mut proc = proc_list.entry(pid).or_insert_with(|| Process {
-            inner: ProcessInner::new(pid)
+            inner: ProcessInner::new(pid),
+            last_refreshed_at: None,
         });
         let mut p = &mut proc.inner;
         if p.name.is_empty() {
@@ -413,6 +568,15 @@ Indexes listed above
         if p.parent.is_none() {
             p.parent = ppid;
         }
+        if p.thread_count.is_none() {
+            p.thread_count = nthrd;
+        }
+        if p.session_id.is_none() {
+            p.session_id = sid;
+        }
+        if p.process_group_id.is_none() {
+            p.process_group_id = pgid;
+        }
         if p.user_id.is_none() {
             p.user_id = ruid;
         }
@@ -436,54 +600,31 @@ Indexes listed above
         }
     }
 
-/* Example data from /scheme/sys/context:
-PID   EUID  EGID  ENS   STAT  CPU   AFFINITY   TIME        MEM     NAME
-0     0     0     0     RR+   #3               00:00:01.36 1 KB    [kmain]
-0     0     0     0     RR+   #2               00:00:01.35 1 KB    [kmain]
-0     0     0     0     RR    #1               00:00:01.34 1 KB    [kmain]
-0     0     0     0     RR+   #0               00:00:01.31 1 KB    [kmain]
-0     0     0     1     UB    #3               00:00:00.00 23 MB   [init]
-0     0     0     1     UB    #1               00:00:00.00 23 MB   [init]
-1     0     0     1     UB    #3               00:00:00.01 1 MB    /scheme/initfs/bin/init
-0     6     12    18    24    30    36         47 50 53 56 59      67
-Indexes listed above
-*/
     for line in sys_context.lines().skip(1) {
-        let Ok(pid) = line[0..6].trim().parse::<usize>().map(Pid::from) else { continue };
-        let euid = line[6..12].trim().parse::<libc::uid_t>().map(Uid).ok();
-        let egid = line[12..18].trim().parse::<libc::gid_t>().map(Gid).ok();
-        let mut stat = line[24..30].trim().chars();
-        let kind = stat.next().unwrap_or_default();
-        let status = stat.next().unwrap_or_default();
-        //TODO: this ID may not map to the CPUs detected from /scheme/sys/cpu
-        let cpu = line[31..36].trim().parse::<usize>().unwrap_or_default();
-        let time =
-            // Hours
-            line[47..49].parse::<u64>().unwrap_or_default() * 3600 * 1000 + 
-            // Minutes
-            line[50..52].parse::<u64>().unwrap_or_default() * 60 * 1000 +
-            // Seconds
-            line[53..55].parse::<u64>().unwrap_or_default() * 1000 +
-            // Centiseconds
-            line[56..58].parse::<u64>().unwrap_or_default() * 10;
-        let mut parts = line[59..67].trim().split(' ');
-        let mut mem = parts.next().unwrap_or_default().parse::<u64>().unwrap_or_default();
-        match parts.next().unwrap_or_default() {
-            "B" => {},
-            "KB" => mem *= 1024,
-            "MB" => mem *= 1024 * 1024,
-            "GB" => mem *= 1024 * 1024 * 1024,
-            suffix => {
-                sysinfo_debug!("unknown memory suffix {:?}", suffix);
-            }
-        }
-        let name = &line[67..];
+        let Some(RedoxContextLine {
+            pid,
+            euid,
+            egid,
+            kind,
+            status,
+            time,
+            memory: mem,
+            name,
+            ..
+        }) = parse_redox_context_line(line)
+        else {
+            continue;
+        };
+        let pid = Pid::from(pid);
+        let euid = euid.map(Uid);
+        let egid = egid.map(Gid);
 
         //TODO: use TID or fill in tasks?
         //TODO: /proc not implemented so this path is not useful
         //TODO: fill in more fields
         let mut proc = proc_list.entry(pid).or_insert_with(|| Process {
-            inner: ProcessInner::new(pid)
+            inner: ProcessInner::new(pid),
+            last_refreshed_at: None,
         });
         let mut p = &mut proc.inner;
         if p.name.is_empty() {
@@ -522,34 +663,6 @@ Indexes listed above
     nb_updated
 }
 
-struct Parts<'a> {
-    str_parts: Vec<&'a str>,
-    short_exe: &'a [u8],
-}
-
-fn parse_stat_file(data: &[u8]) -> Option<Parts<'_>> {
-    // The stat file is "interesting" to parse, because spaces cannot
-    // be used as delimiters. The second field stores the command name
-    // surrounded by parentheses. Unfortunately, whitespace and
-    // parentheses are legal parts of the command, so parsing has to
-    // proceed like this: The first field is delimited by the first
-    // whitespace, the second field is everything until the last ')'
-    // in the entire string. All other fields are delimited by
-    // whitespace.
-
-    let mut str_parts = Vec::with_capacity(51);
-    let mut data_it = data.splitn(2, |&b| b == b' ');
-    str_parts.push(str::from_utf8(data_it.next()?).ok()?);
-    let mut data_it = data_it.next()?.rsplitn(2, |&b| b == b')');
-    let data = str::from_utf8(data_it.next()?).ok()?;
-    let short_exe = data_it.next()?;
-    str_parts.extend(data.split_whitespace());
-    Some(Parts {
-        str_parts,
-        short_exe: short_exe.strip_prefix(b"(").unwrap_or(short_exe),
-    })
-}
-
 /// Type used to correctly handle the `REMAINING_FILES` global.
 struct FileCounter(File);
 
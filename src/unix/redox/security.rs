@@ -0,0 +1,23 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::FirewallProfile;
+
+pub(crate) struct SecurityInfoInner;
+
+impl SecurityInfoInner {
+    pub(crate) fn secure_boot_enabled() -> Option<bool> {
+        None
+    }
+
+    pub(crate) fn tpm_present() -> Option<bool> {
+        None
+    }
+
+    pub(crate) fn root_disk_encrypted() -> Option<bool> {
+        None
+    }
+
+    pub(crate) fn firewall_status() -> Vec<FirewallProfile> {
+        Vec::new()
+    }
+}
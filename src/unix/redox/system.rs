@@ -3,9 +3,10 @@
 use crate::sys::cpu::CpusWrapper;
 use crate::sys::process::{compute_cpu_usage, refresh_procs};
 use crate::sys::utils::{get_all_utf8_data, to_u64};
+use crate::unix::utils::check_source;
 use crate::{
     Cpu, CpuRefreshKind, LoadAvg, MemoryRefreshKind, Pid, Process, ProcessRefreshKind,
-    ProcessesToUpdate,
+    ProcessStringStats, ProcessesToUpdate, RefreshError,
 };
 
 use libc::{self, _SC_CLK_TCK, _SC_HOST_NAME_MAX, _SC_PAGESIZE, c_char, sysconf};
@@ -19,7 +20,7 @@ use std::mem::MaybeUninit;
 use std::path::Path;
 use std::str::FromStr;
 use std::sync::{OnceLock, atomic::AtomicIsize};
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 unsafe fn getrlimit() -> Option<libc::rlimit> {
     let mut limits = libc::rlimit {
@@ -185,7 +186,9 @@ impl SystemInner {
     }
 
     fn refresh_cpus(&mut self, only_update_global_cpu: bool, refresh_kind: CpuRefreshKind) {
-        self.cpus.refresh(only_update_global_cpu, refresh_kind);
+        if let Err(_e) = self.cpus.refresh(only_update_global_cpu, refresh_kind) {
+            sysinfo_debug!("failed to refresh CPUs: {_e:?}");
+        }
     }
 }
 
@@ -208,18 +211,37 @@ impl SystemInner {
     }
 
     pub(crate) fn refresh_memory_specifics(&mut self, refresh_kind: MemoryRefreshKind) {
+        if let Err(_e) = self.try_refresh_memory_specifics(refresh_kind) {
+            sysinfo_debug!("failed to refresh memory: {_e:?}");
+        }
+    }
+
+    pub(crate) fn try_refresh_memory_specifics(
+        &mut self,
+        refresh_kind: MemoryRefreshKind,
+    ) -> Result<(), RefreshError> {
         if !refresh_kind.ram() && !refresh_kind.swap() {
-            return;
+            return Ok(());
         }
         let mut stat: MaybeUninit<libc::statvfs> = MaybeUninit::uninit();
-        if unsafe { libc::statvfs(CString::new("/scheme/memory").unwrap().as_ptr(), stat.as_mut_ptr()) } == 0 {
-            let stat = unsafe { stat.assume_init() };
-            self.mem_total = stat.f_blocks as u64 * stat.f_bsize as u64;
-            self.mem_free = stat.f_bfree as u64 * stat.f_bsize as u64;
-            self.mem_available = stat.f_bavail as u64 * stat.f_bsize as u64;
-            //TODO: other memory numbers
+        if unsafe {
+            libc::statvfs(
+                CString::new("/scheme/memory").unwrap().as_ptr(),
+                stat.as_mut_ptr(),
+            )
+        } != 0
+        {
+            return Err(RefreshError::from_io(
+                "/scheme/memory",
+                std::io::Error::last_os_error(),
+            ));
         }
-
+        let stat = unsafe { stat.assume_init() };
+        self.mem_total = stat.f_blocks as u64 * stat.f_bsize as u64;
+        self.mem_free = stat.f_bfree as u64 * stat.f_bsize as u64;
+        self.mem_available = stat.f_bavail as u64 * stat.f_bsize as u64;
+        //TODO: other memory numbers
+        Ok(())
     }
 
     pub(crate) fn cgroup_limits(&self) -> Option<crate::CGroupLimits> {
@@ -230,6 +252,17 @@ impl SystemInner {
         self.refresh_cpus(false, refresh_kind);
     }
 
+    pub(crate) fn try_refresh_cpu_specifics(
+        &mut self,
+        refresh_kind: CpuRefreshKind,
+    ) -> Result<(), RefreshError> {
+        self.cpus.refresh(false, refresh_kind)
+    }
+
+    pub(crate) fn check_processes_source() -> Result<(), RefreshError> {
+        check_source("/scheme/sys/context")
+    }
+
     pub(crate) fn refresh_processes_specifics(
         &mut self,
         processes_to_update: ProcessesToUpdate<'_>,
@@ -255,6 +288,18 @@ impl SystemInner {
         &self.process_list
     }
 
+    pub(crate) fn process_string_stats(&self) -> ProcessStringStats {
+        ProcessStringStats::default()
+    }
+
+    pub(crate) fn set_max_kept_file_handles(&mut self, _max: usize) {}
+
+    pub(crate) fn kept_file_handles(&self) -> usize {
+        0
+    }
+
+    pub(crate) fn set_use_pidfds(&mut self, _use_pidfds: bool) {}
+
     pub(crate) fn processes_mut(&mut self) -> &mut HashMap<Pid, Process> {
         &mut self.process_list
     }
@@ -267,10 +312,18 @@ impl SystemInner {
         self.cpus.global_cpu.usage()
     }
 
+    pub(crate) fn last_cpu_usage_update(&self) -> Option<Instant> {
+        self.cpus.last_cpus_update
+    }
+
     pub(crate) fn cpus(&self) -> &[Cpu] {
         &self.cpus.cpus
     }
 
+    pub(crate) fn cpus_mut(&mut self) -> &mut [Cpu] {
+        &mut self.cpus.cpus
+    }
+
     pub(crate) fn total_memory(&self) -> u64 {
         self.mem_total
     }
@@ -300,11 +353,22 @@ impl SystemInner {
         self.swap_total - self.swap_free
     }
 
+    pub(crate) fn swap_devices(&self) -> Vec<crate::SwapDevice> {
+        Vec::new()
+    }
+
+    pub(crate) fn compressed_memory(&self) -> Option<crate::CompressedMemory> {
+        None
+    }
+
+    pub(crate) fn inotify_usage(&self) -> Option<crate::InotifyUsage> {
+        None
+    }
+
     pub(crate) fn uptime() -> u64 {
         uptime()
     }
 
-
     pub(crate) fn boot_time() -> u64 {
         boot_time()
     }
@@ -330,6 +394,10 @@ impl SystemInner {
         }
     }
 
+    pub(crate) fn run_queue_length() -> Option<usize> {
+        None
+    }
+
     #[cfg(not(target_os = "android"))]
     pub(crate) fn name() -> Option<String> {
         get_system_info_linux(
@@ -506,6 +574,14 @@ impl SystemInner {
         }
     }
 
+    pub(crate) fn is_compat_mode() -> Option<bool> {
+        None
+    }
+
+    pub(crate) fn core_dump_config() -> Option<crate::CoreDumpConfig> {
+        None
+    }
+
     pub(crate) fn physical_core_count() -> Option<usize> {
         Some(unsafe { sysconf(libc::_SC_NPROCESSORS_ONLN) as _ })
     }
@@ -515,6 +591,14 @@ impl SystemInner {
         self.refresh_cpu_specifics(refresh_kind);
     }
 
+    pub(crate) fn clock_ticks_per_second() -> Option<u64> {
+        crate::unix::utils::sysconf(libc::_SC_CLK_TCK)
+    }
+
+    pub(crate) fn page_size() -> Option<u64> {
+        crate::unix::utils::sysconf(libc::_SC_PAGESIZE)
+    }
+
     pub(crate) fn open_files_limit() -> Option<usize> {
         unsafe {
             match getrlimit() {
@@ -526,6 +610,62 @@ impl SystemInner {
             }
         }
     }
+
+    pub(crate) fn timezone() -> Option<String> {
+        crate::unix::utils::get_timezone()
+    }
+
+    pub(crate) fn locale() -> Option<String> {
+        crate::unix::utils::get_locale_from_env()
+    }
+
+    pub(crate) fn cpu_vulnerabilities() -> Vec<crate::CpuVulnerability> {
+        Vec::new()
+    }
+
+    pub(crate) fn memory_hardware_info() -> Option<crate::MemoryHardwareInfo> {
+        None
+    }
+
+    pub(crate) fn virtualization() -> Option<String> {
+        crate::common::system::virtualization_from_product()
+    }
+
+    pub(crate) fn board_model() -> Option<String> {
+        None
+    }
+
+    pub(crate) fn turbo_enabled() -> Option<bool> {
+        None
+    }
+
+    pub(crate) fn throttling_status() -> Option<crate::ThrottlingStatus> {
+        None
+    }
+
+    pub(crate) fn socket_summary() -> Option<crate::SocketSummary> {
+        None
+    }
+
+    pub(crate) fn kernel_log(
+        _since: Option<Instant>,
+    ) -> std::io::Result<Vec<crate::KernelLogEntry>> {
+        let log = std::fs::read("/scheme/sys/log")?;
+        Ok(String::from_utf8_lossy(&log)
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .enumerate()
+            .map(|(sequence, line)| {
+                let (level, message) = crate::parsing::parse_redox_log_line(line);
+                crate::KernelLogEntry {
+                    level: crate::KernelLogLevel::from_syslog(level),
+                    sequence: sequence as u64,
+                    time_since_boot: None,
+                    message: message.to_owned(),
+                }
+            })
+            .collect())
+    }
 }
 
 fn read_u64(filename: &str) -> Option<u64> {
@@ -0,0 +1,157 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::{UsbDevice, UsbSpeed};
+
+use std::fs::{self, read_dir};
+
+/// Parses the port name used by `xhcid` (like `port1` or `port1.4` for a device behind a hub)
+/// into the list of ports.
+fn parse_port(name: &str) -> Option<Vec<u8>> {
+    name.strip_prefix("port")?
+        .split('.')
+        .map(|port| port.parse().ok())
+        .collect()
+}
+
+/// Returns the value of the `key` field of the JSON object `json`, as written by `serde_json`
+/// (without whitespace).
+///
+/// Only the first occurrence of the field is returned, so the fields of the nested objects
+/// (which are written after the ones of the device descriptor) are ignored.
+fn json_field<'a>(json: &'a str, key: &str) -> Option<&'a str> {
+    let start = json.find(&format!("\"{key}\":"))? + key.len() + 3;
+    let value = &json[start..];
+    if value.starts_with('"') {
+        let mut escaped = false;
+        for (pos, c) in value.char_indices().skip(1) {
+            match c {
+                '\\' => escaped = !escaped,
+                '"' if !escaped => return Some(&value[..=pos]),
+                _ => escaped = false,
+            }
+        }
+        None
+    } else {
+        let end = value.find([',', '}', ']']).unwrap_or(value.len());
+        Some(&value[..end])
+    }
+}
+
+fn json_u16(json: &str, key: &str) -> Option<u16> {
+    json_field(json, key)?.parse().ok()
+}
+
+fn json_string(json: &str, key: &str) -> Option<String> {
+    let value = json_field(json, key)?
+        .strip_prefix('"')?
+        .strip_suffix('"')?;
+    let mut out = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next()? {
+            'n' => out.push('\n'),
+            't' => out.push('\t'),
+            'r' => out.push('\r'),
+            'u' => {
+                let code: String = chars.by_ref().take(4).collect();
+                out.push(char::from_u32(u32::from_str_radix(&code, 16).ok()?)?);
+            }
+            c => out.push(c),
+        }
+    }
+    let out = out.trim();
+    if out.is_empty() {
+        None
+    } else {
+        Some(out.to_owned())
+    }
+}
+
+pub(crate) fn get_usb_devices(devices: &mut Vec<UsbDevice>) {
+    devices.clear();
+
+    // Each xHCI controller is handled by an `xhcid` instance providing a `usb.[controller]`
+    // scheme, in which each port with a device has a `descriptors` file containing the device
+    // descriptor in JSON.
+    let Ok(entries) = read_dir("/scheme") else {
+        return;
+    };
+    let mut controllers = entries
+        .flatten()
+        .filter(|entry| entry.file_name().as_encoded_bytes().starts_with(b"usb."))
+        .map(|entry| entry.path())
+        .collect::<Vec<_>>();
+    controllers.sort_unstable();
+
+    for (bus_number, controller) in controllers.iter().enumerate() {
+        let Ok(ports) = read_dir(controller) else {
+            continue;
+        };
+        for port in ports.flatten() {
+            let Some(port_numbers) = port.file_name().to_str().and_then(parse_port) else {
+                continue;
+            };
+            let Ok(descriptor) = fs::read_to_string(port.path().join("descriptors")) else {
+                continue;
+            };
+            let (Some(vendor_id), Some(product_id)) = (
+                json_u16(&descriptor, "vendor"),
+                json_u16(&descriptor, "product"),
+            ) else {
+                continue;
+            };
+            devices.push(UsbDevice {
+                vendor_id,
+                product_id,
+                manufacturer: json_string(&descriptor, "manufacturer_str"),
+                product: json_string(&descriptor, "product_str"),
+                serial_number: json_string(&descriptor, "serial_str"),
+                bus_number: u8::try_from(bus_number + 1).ok(),
+                port_numbers,
+                speed: UsbSpeed::Unknown,
+            });
+        }
+    }
+    devices.sort_unstable_by(|a, b| {
+        (a.bus_number, &a.port_numbers).cmp(&(b.bus_number, &b.port_numbers))
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{json_string, json_u16, parse_port};
+
+    #[test]
+    fn test_parse_port() {
+        assert_eq!(parse_port("port3"), Some(vec![3]));
+        assert_eq!(parse_port("port1.4"), Some(vec![1, 4]));
+        assert_eq!(parse_port("port"), None);
+        assert_eq!(parse_port("configure"), None);
+    }
+
+    #[test]
+    fn test_parse_descriptor() {
+        let descriptor = concat!(
+            r#"{"kind":1,"usb":512,"class":0,"sub_class":0,"protocol":0,"packet_size":64,"#,
+            r#""vendor":1133,"product":49271,"release":4352,"#,
+            r#""manufacturer_str":"Logitech \"Inc\"","product_str":"USBé Mouse","#,
+            r#""serial_str":null,"config_descs":[{"kind":2,"vendor":1}]}"#,
+        );
+        assert_eq!(json_u16(descriptor, "vendor"), Some(1133));
+        assert_eq!(json_u16(descriptor, "product"), Some(49271));
+        assert_eq!(
+            json_string(descriptor, "manufacturer_str").as_deref(),
+            Some("Logitech \"Inc\""),
+        );
+        assert_eq!(
+            json_string(descriptor, "product_str").as_deref(),
+            Some("USBé Mouse"),
+        );
+        assert_eq!(json_string(descriptor, "serial_str"), None);
+        assert_eq!(json_u16(descriptor, "missing"), None);
+    }
+}
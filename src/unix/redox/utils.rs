@@ -7,6 +7,38 @@ use std::io::{self, Read, Seek};
 #[cfg(any(feature = "disk", feature = "system"))]
 use std::path::Path;
 
+#[cfg(feature = "system")]
+thread_local! {
+    // Buffers used to read files, reused across reads to avoid allocating new ones for each
+    // process. There is one set of buffers per thread since processes can be refreshed in
+    // parallel.
+    static SCRATCH_BUFFERS: std::cell::RefCell<Vec<Vec<u8>>> =
+        const { std::cell::RefCell::new(Vec::new()) };
+}
+
+/// Calls `f` with an empty buffer which is reused across calls on the current thread. It can be
+/// called recursively, each call getting its own buffer.
+#[cfg(feature = "system")]
+pub(crate) fn with_scratch_buffer<R>(f: impl FnOnce(&mut Vec<u8>) -> R) -> R {
+    let mut buf = SCRATCH_BUFFERS
+        .with_borrow_mut(|buffers| buffers.pop())
+        .unwrap_or_default();
+    let ret = f(&mut buf);
+    buf.clear();
+    // We don't want to keep a big buffer around because of a single huge file.
+    buf.shrink_to(65_536);
+    SCRATCH_BUFFERS.with_borrow_mut(|buffers| buffers.push(buf));
+    ret
+}
+
+/// Reads the content of `file_path` into `buf` and returns it as a `str`.
+#[cfg(feature = "system")]
+pub(crate) fn read_utf8_data<P: AsRef<Path>>(file_path: P, buf: &mut Vec<u8>) -> io::Result<&str> {
+    buf.clear();
+    File::open(file_path.as_ref())?.read_to_end(buf)?;
+    std::str::from_utf8(buf).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
 #[cfg(feature = "system")]
 pub(crate) fn get_all_data_from_file(file: &mut File, size: usize) -> io::Result<Vec<u8>> {
     let mut buf = Vec::with_capacity(size);
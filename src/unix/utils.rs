@@ -1,5 +1,107 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
+/// Checks that `path`, from which some information is read, can be opened.
+#[cfg(all(
+    any(feature = "system", feature = "disk", feature = "network"),
+    any(target_os = "linux", target_os = "android", target_os = "redox")
+))]
+pub(crate) fn check_source(path: &str) -> Result<(), crate::RefreshError> {
+    std::fs::File::open(path)
+        .map(|_| ())
+        .map_err(|error| crate::RefreshError::from_io(path, error))
+}
+
+/// Returns the value of the `name` system configuration variable, or `None` if it isn't
+/// available.
+#[cfg(feature = "system")]
+pub(crate) fn sysconf(name: libc::c_int) -> Option<u64> {
+    match unsafe { libc::sysconf(name) } {
+        value if value > 0 => Some(value as u64),
+        _ => None,
+    }
+}
+
+/// Returns the soft and hard limits of the size of core dumps of the current process, `None`
+/// meaning unlimited.
+#[cfg(all(
+    feature = "system",
+    any(
+        target_os = "linux",
+        target_os = "android",
+        all(target_os = "macos", not(feature = "apple-sandbox")),
+        target_os = "freebsd"
+    )
+))]
+// `rlim_t` is signed on FreeBSD.
+#[allow(clippy::unnecessary_cast)]
+pub(crate) fn core_size_limits() -> Option<(Option<u64>, Option<u64>)> {
+    let mut limits = std::mem::MaybeUninit::<libc::rlimit>::uninit();
+    if unsafe { libc::getrlimit(libc::RLIMIT_CORE, limits.as_mut_ptr()) } != 0 {
+        return None;
+    }
+    let limits = unsafe { limits.assume_init() };
+    let limit = |value: libc::rlim_t| (value != libc::RLIM_INFINITY).then_some(value as u64);
+    Some((limit(limits.rlim_cur), limit(limits.rlim_max)))
+}
+
+/// Returns the resources used by the exited and waited for children of the current process.
+#[cfg(all(
+    feature = "system",
+    any(
+        target_os = "linux",
+        target_os = "android",
+        all(target_os = "macos", not(feature = "apple-sandbox")),
+        target_os = "freebsd"
+    )
+))]
+pub(crate) fn children_usage() -> Option<crate::ChildrenUsage> {
+    let mut usage = std::mem::MaybeUninit::<libc::rusage>::uninit();
+    if unsafe { libc::getrusage(libc::RUSAGE_CHILDREN, usage.as_mut_ptr()) } != 0 {
+        return None;
+    }
+    let usage = unsafe { usage.assume_init() };
+    let to_ms = |time: libc::timeval| time.tv_sec as u64 * 1_000 + time.tv_usec as u64 / 1_000;
+    // `ru_maxrss` is in bytes on macOS and in kilobytes on the other systems.
+    let max_memory = if cfg!(target_os = "macos") {
+        usage.ru_maxrss as u64
+    } else {
+        (usage.ru_maxrss as u64).saturating_mul(1_024)
+    };
+    Some(crate::ChildrenUsage {
+        accumulated_cpu_time: to_ms(usage.ru_utime).saturating_add(to_ms(usage.ru_stime)),
+        max_memory,
+    })
+}
+
+/// Returns the space of the file system containing `path` using `statfs`, which provides the
+/// mount point as well.
+#[cfg(all(
+    feature = "disk",
+    any(target_os = "macos", target_os = "ios", target_os = "freebsd")
+))]
+pub(crate) fn statfs_space(path: &std::path::Path) -> Option<crate::DiskSpace> {
+    use std::ffi::{CStr, CString, OsStr};
+    use std::os::unix::ffi::OsStrExt;
+
+    let path = CString::new(path.as_os_str().as_bytes()).ok()?;
+    let mut stat: libc::statfs = unsafe { std::mem::zeroed() };
+    if unsafe { libc::statfs(path.as_ptr(), &mut stat) } != 0 {
+        return None;
+    }
+    let mount_point = unsafe { CStr::from_ptr(stat.f_mntonname.as_ptr()) };
+    let bsize = stat.f_bsize as u64;
+    // `f_bavail` is signed on FreeBSD.
+    #[allow(clippy::unnecessary_min_or_max)]
+    let available = stat.f_bavail.max(0) as u64;
+    Some(crate::DiskSpace {
+        mount_point: OsStr::from_bytes(mount_point.to_bytes()).into(),
+        total_space: bsize.saturating_mul(stat.f_blocks as u64),
+        available_space: bsize.saturating_mul(available),
+        used_space: bsize
+            .saturating_mul((stat.f_blocks as u64).saturating_sub(stat.f_bfree as u64)),
+    })
+}
+
 #[cfg(feature = "user")]
 pub(crate) fn cstr_to_rust(c: *const libc::c_char) -> Option<String> {
     cstr_to_rust_with_size(c, None)
@@ -58,3 +160,151 @@ pub(crate) fn wait_process(pid: crate::Pid) -> Option<std::process::ExitStatus>
         Some(std::process::ExitStatus::from_raw(status))
     }
 }
+
+/// Extracts the time zone name from a path inside a `zoneinfo` directory (like
+/// `/usr/share/zoneinfo/Europe/Paris`).
+#[cfg(all(feature = "system", not(any(target_os = "macos", target_os = "ios"))))]
+pub(crate) fn timezone_from_path(path: &str) -> Option<String> {
+    let (_, name) = path.rsplit_once("zoneinfo/")?;
+    let name = name
+        .strip_prefix("posix/")
+        .or_else(|| name.strip_prefix("right/"))
+        .unwrap_or(name);
+    if name.is_empty() {
+        None
+    } else {
+        Some(name.to_owned())
+    }
+}
+
+/// Returns the local time zone based on the `TZ` environment variable, then on the
+/// `/etc/localtime` symlink.
+#[cfg(all(feature = "system", not(any(target_os = "macos", target_os = "ios"))))]
+pub(crate) fn get_timezone() -> Option<String> {
+    if let Ok(tz) = std::env::var("TZ") {
+        let tz = tz.strip_prefix(':').unwrap_or(&tz);
+        if tz.starts_with('/') {
+            if let Some(name) = timezone_from_path(tz) {
+                return Some(name);
+            }
+        } else if !tz.is_empty() {
+            return Some(tz.to_owned());
+        }
+    }
+    let path = std::fs::read_link("/etc/localtime").ok()?;
+    timezone_from_path(path.to_str()?)
+}
+
+/// Returns the locale used for messages, following the POSIX precedence of the `LC_ALL`,
+/// `LC_MESSAGES` and `LANG` environment variables.
+#[cfg(all(feature = "system", not(any(target_os = "macos", target_os = "ios"))))]
+pub(crate) fn get_locale_from_env() -> Option<String> {
+    ["LC_ALL", "LC_MESSAGES", "LANG"]
+        .into_iter()
+        .filter_map(|var| std::env::var(var).ok())
+        .find(|value| !value.is_empty())
+}
+
+/// Parses the nameservers and the search domains of a `resolv.conf` file.
+#[cfg(all(
+    feature = "network",
+    any(target_os = "linux", target_os = "android", target_os = "freebsd")
+))]
+pub(crate) fn parse_resolv_conf(data: &str) -> crate::DnsConfiguration {
+    let mut config = crate::DnsConfiguration::default();
+    for line in data.lines() {
+        let mut parts = line.split_whitespace();
+        match parts.next() {
+            Some("nameserver") => {
+                // IPv6 link-local addresses can have a zone index (like `fe80::1%eth0`).
+                if let Some(addr) = parts
+                    .next()
+                    .and_then(|addr| addr.split('%').next()?.parse().ok())
+                {
+                    config.nameservers.push(addr);
+                }
+            }
+            // If there are multiple `domain` and `search` lines, the last one wins.
+            Some("domain" | "search") => {
+                config.search_domains = parts.map(str::to_owned).collect();
+            }
+            _ => {}
+        }
+    }
+    config
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    #[cfg(all(feature = "system", not(any(target_os = "macos", target_os = "ios"))))]
+    fn test_timezone_from_path() {
+        use super::timezone_from_path;
+
+        assert_eq!(
+            timezone_from_path("/usr/share/zoneinfo/Europe/Paris").as_deref(),
+            Some("Europe/Paris"),
+        );
+        assert_eq!(
+            timezone_from_path("../usr/share/zoneinfo/posix/America/New_York").as_deref(),
+            Some("America/New_York"),
+        );
+        assert_eq!(
+            timezone_from_path("/usr/share/zoneinfo/UTC").as_deref(),
+            Some("UTC")
+        );
+        assert_eq!(timezone_from_path("/usr/share/zoneinfo/"), None);
+        assert_eq!(timezone_from_path("/etc/foo"), None);
+    }
+
+    #[test]
+    #[cfg(all(feature = "system", any(target_os = "linux", target_os = "android")))]
+    fn test_check_source() {
+        use super::check_source;
+        use crate::RefreshError;
+        use std::path::PathBuf;
+
+        assert_eq!(check_source("/proc/self/stat"), Ok(()));
+        assert_eq!(
+            check_source("/proc/does-not-exist"),
+            Err(RefreshError::NotFound(PathBuf::from(
+                "/proc/does-not-exist"
+            ))),
+        );
+        assert_eq!(
+            RefreshError::NotFound(PathBuf::from("/proc/meminfo")).to_string(),
+            "`/proc/meminfo` not found",
+        );
+    }
+
+    #[test]
+    #[cfg(all(
+        feature = "network",
+        any(target_os = "linux", target_os = "android", target_os = "freebsd")
+    ))]
+    fn test_parse_resolv_conf() {
+        use super::parse_resolv_conf;
+        use std::net::IpAddr;
+
+        let config = parse_resolv_conf(
+            "\
+# Generated by NetworkManager
+domain example.org
+search lan example.com
+nameserver 192.168.1.1
+nameserver fe80::1%eth0
+nameserver invalid
+options edns0
+",
+        );
+        assert_eq!(
+            config.nameservers(),
+            &[
+                "192.168.1.1".parse::<IpAddr>().unwrap(),
+                "fe80::1".parse::<IpAddr>().unwrap(),
+            ],
+        );
+        assert_eq!(config.search_domains(), &["lan", "example.com"]);
+        assert_eq!(parse_resolv_conf(""), Default::default());
+    }
+}
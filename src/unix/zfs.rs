@@ -0,0 +1,71 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::{ZfsPool, ZfsPoolHealth};
+
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+/// Places where `zpool` is installed, as it might not be in the `PATH` of services.
+const ZPOOL_PATHS: &[&str] = &["/sbin/zpool", "/usr/sbin/zpool", "/usr/local/sbin/zpool"];
+
+pub(crate) fn get_zfs_pools(pools: &mut Vec<ZfsPool>) {
+    pools.clear();
+    let zpool = ZPOOL_PATHS
+        .iter()
+        .find(|path| Path::new(path).exists())
+        .copied()
+        .unwrap_or("zpool");
+    // `-H` removes the headers and separates the fields with tabs, `-p` displays exact values.
+    let Ok(output) = Command::new(zpool)
+        .args(["list", "-H", "-p", "-o", "name,size,allocated,free,health"])
+        .stdin(Stdio::null())
+        .stderr(Stdio::null())
+        .output()
+    else {
+        return;
+    };
+    if output.status.success() {
+        pools.extend(
+            String::from_utf8_lossy(&output.stdout)
+                .lines()
+                .filter_map(parse_zpool_list_line),
+        );
+    }
+}
+
+fn parse_zpool_list_line(line: &str) -> Option<ZfsPool> {
+    let mut fields = line.split('\t');
+    let name = fields.next()?.to_owned();
+    let size = fields.next()?.parse().ok()?;
+    let allocated = fields.next()?.parse().ok()?;
+    let free = fields.next()?.parse().ok()?;
+    let health = ZfsPoolHealth::from_zpool(fields.next()?);
+    Some(ZfsPool {
+        name,
+        health,
+        size,
+        allocated,
+        free,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn check_parse_zpool_list_line() {
+        assert_eq!(
+            parse_zpool_list_line("tank\t3985729650688\t1202590842880\t2783138807808\tDEGRADED"),
+            Some(ZfsPool {
+                name: "tank".to_owned(),
+                health: ZfsPoolHealth::Degraded,
+                size: 3_985_729_650_688,
+                allocated: 1_202_590_842_880,
+                free: 2_783_138_807_808,
+            })
+        );
+        assert!(parse_zpool_list_line("tank\t3.62T\t1.09T\t2.53T\tONLINE").is_none());
+        assert!(parse_zpool_list_line("").is_none());
+    }
+}
@@ -0,0 +1,19 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::CpuCluster;
+use crate::common::apple_silicon::PowerUsage;
+
+use std::io;
+
+pub(crate) struct AppleSiliconMetricsInner;
+
+impl AppleSiliconMetricsInner {
+    pub(crate) fn start() -> io::Result<Self> {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "Apple Silicon metrics are not supported on this system",
+        ))
+    }
+
+    pub(crate) fn refresh(&mut self, _clusters: &mut Vec<CpuCluster>, _power: &mut PowerUsage) {}
+}
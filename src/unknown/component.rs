@@ -1,6 +1,6 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
-use crate::Component;
+use crate::{Component, ValueSource};
 
 pub(crate) struct ComponentInner {
     pub(crate) updated: bool,
@@ -19,10 +19,26 @@ impl ComponentInner {
         None
     }
 
+    pub(crate) fn max_source(&self) -> Option<ValueSource> {
+        None
+    }
+
+    pub(crate) fn critical_source(&self) -> Option<ValueSource> {
+        None
+    }
+
     pub(crate) fn label(&self) -> &str {
         ""
     }
 
+    pub(crate) fn raw_label(&self) -> Option<&str> {
+        None
+    }
+
+    pub(crate) fn chip(&self) -> Option<&str> {
+        None
+    }
+
     pub(crate) fn id(&self) -> Option<&str> {
         None
     }
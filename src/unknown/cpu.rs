@@ -22,4 +22,20 @@ impl CpuInner {
     pub(crate) fn brand(&self) -> &str {
         ""
     }
+
+    pub(crate) fn run_queue_length(&self) -> Option<usize> {
+        None
+    }
+
+    pub(crate) fn run_queue_wait_time(&self) -> Option<u64> {
+        None
+    }
+
+    pub(crate) fn boost_active(&self) -> Option<bool> {
+        None
+    }
+
+    pub(crate) fn idle_states(&self) -> &[crate::CpuIdleState] {
+        &[]
+    }
 }
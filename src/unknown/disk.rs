@@ -1,6 +1,6 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
-use crate::{Disk, DiskKind, DiskRefreshKind, DiskUsage};
+use crate::{Disk, DiskKind, DiskRefreshKind, DiskUsage, RefreshError};
 
 use std::{ffi::OsStr, path::Path};
 
@@ -46,6 +46,14 @@ impl DiskInner {
     pub(crate) fn usage(&self) -> DiskUsage {
         DiskUsage::default()
     }
+
+    pub(crate) fn network_fs_stats(&self) -> Option<crate::NetworkFsStats> {
+        None
+    }
+
+    pub(crate) fn io_stats(&self) -> Option<crate::DiskIoStats> {
+        None
+    }
 }
 
 pub(crate) struct DisksInner {
@@ -65,6 +73,14 @@ impl DisksInner {
         self.disks
     }
 
+    pub(crate) fn try_refresh_specifics(
+        &mut self,
+        _remove_not_listed_disks: bool,
+        _refreshes: DiskRefreshKind,
+    ) -> Result<(), RefreshError> {
+        Err(RefreshError::Unsupported)
+    }
+
     pub(crate) fn refresh_specifics(
         &mut self,
         _remove_not_listed_disks: bool,
@@ -80,4 +96,8 @@ impl DisksInner {
     pub(crate) fn list_mut(&mut self) -> &mut [Disk] {
         &mut self.disks
     }
+
+    pub(crate) fn usage_for_path(_path: &Path) -> Option<crate::DiskSpace> {
+        None
+    }
 }
@@ -0,0 +1,25 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::common::disk_watcher::MountEntry;
+
+use std::io;
+use std::time::Duration;
+
+pub(crate) struct DiskWatcherInner;
+
+impl DiskWatcherInner {
+    pub(crate) fn open() -> io::Result<Self> {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "watching disks is not supported on this system",
+        ))
+    }
+
+    pub(crate) fn wait(&mut self, _timeout: Duration) -> bool {
+        false
+    }
+
+    pub(crate) fn mounts(&mut self) -> io::Result<Vec<MountEntry>> {
+        Ok(Vec::new())
+    }
+}
@@ -3,23 +3,41 @@
 cfg_if! {
     if #[cfg(feature = "system")] {
         pub mod cpu;
+        pub mod exited_process;
         pub mod motherboard;
         pub mod process;
         pub mod product;
+        pub mod security;
         pub mod system;
 
         pub(crate) use self::cpu::CpuInner;
+        pub(crate) use self::exited_process::ExitedProcessesInner;
         pub(crate) use self::motherboard::MotherboardInner;
         pub(crate) use self::process::ProcessInner;
         pub(crate) use self::product::ProductInner;
+        pub(crate) use self::security::SecurityInfoInner;
         pub(crate) use self::system::SystemInner;
         pub use self::system::{MINIMUM_CPU_UPDATE_INTERVAL, SUPPORTED_SIGNALS};
     }
 
+    if #[cfg(feature = "apple-silicon")] {
+        pub mod apple_silicon;
+
+        pub(crate) use self::apple_silicon::AppleSiliconMetricsInner;
+    }
+    if #[cfg(feature = "ebpf")] {
+        pub mod ebpf;
+
+        pub(crate) use self::ebpf::EbpfCollectorsInner;
+    }
     if #[cfg(feature = "disk")] {
         pub mod disk;
+        pub mod disk_watcher;
+        pub mod storage;
 
         pub(crate) use self::disk::{DiskInner, DisksInner};
+        pub(crate) use self::disk_watcher::DiskWatcherInner;
+        pub(crate) use self::storage::StorageInner;
     }
 
     if #[cfg(feature = "component")] {
@@ -30,8 +48,28 @@ cfg_if! {
 
     if #[cfg(feature = "network")] {
         pub mod network;
+        pub mod network_watcher;
 
         pub(crate) use self::network::{NetworkDataInner, NetworksInner};
+        pub(crate) use self::network_watcher::NetworkWatcherInner;
+    }
+
+    if #[cfg(feature = "audio")] {
+        pub mod audio;
+
+        pub(crate) use self::audio::get_audio_devices;
+    }
+
+    if #[cfg(feature = "display")] {
+        pub mod display;
+
+        pub(crate) use self::display::get_displays;
+    }
+
+    if #[cfg(feature = "usb")] {
+        pub mod usb;
+
+        pub(crate) use self::usb::get_usb_devices;
     }
 
     if #[cfg(feature = "user")] {
@@ -41,6 +79,12 @@ cfg_if! {
         pub(crate) use self::groups::get_groups;
         pub(crate) use self::users::{get_users, UserInner};
     }
+
+    if #[cfg(feature = "zfs")] {
+        pub mod zfs;
+
+        pub(crate) use self::zfs::{get_zfs_arc, get_zfs_pools};
+    }
 }
 
 #[doc = include_str!("../../md_doc/is_supported.md")]
@@ -48,22 +92,40 @@ pub const IS_SUPPORTED_SYSTEM: bool = false;
 
 // Make formattable by rustfmt.
 #[cfg(any())]
+mod apple_silicon;
+#[cfg(any())]
+mod audio;
+#[cfg(any())]
 mod component;
 #[cfg(any())]
 mod cpu;
 #[cfg(any())]
 mod disk;
 #[cfg(any())]
+mod disk_watcher;
+#[cfg(any())]
+mod display;
+#[cfg(any())]
 mod groups;
 #[cfg(any())]
 mod motherboard;
 #[cfg(any())]
 mod network;
 #[cfg(any())]
+mod network_watcher;
+#[cfg(any())]
 mod process;
 #[cfg(any())]
 mod product;
 #[cfg(any())]
+mod security;
+#[cfg(any())]
+mod storage;
+#[cfg(any())]
 mod system;
 #[cfg(any())]
+mod usb;
+#[cfg(any())]
 mod users;
+#[cfg(any())]
+mod zfs;
@@ -1,8 +1,9 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
-use crate::{IpNetwork, MacAddr, NetworkData};
+use crate::{DnsConfiguration, IpNetwork, MacAddr, NetworkData, RefreshError};
 
 use std::collections::HashMap;
+use std::net::IpAddr;
 
 pub(crate) struct NetworksInner {
     pub(crate) interfaces: HashMap<String, NetworkData>,
@@ -19,7 +20,30 @@ impl NetworksInner {
         &self.interfaces
     }
 
+    pub(crate) fn try_refresh(
+        &mut self,
+        _remove_not_listed_interfaces: bool,
+    ) -> Result<(), RefreshError> {
+        Err(RefreshError::Unsupported)
+    }
+
     pub(crate) fn refresh(&mut self, _remove_not_listed_interfaces: bool) {}
+
+    pub(crate) fn dns_configuration() -> DnsConfiguration {
+        DnsConfiguration::default()
+    }
+
+    pub(crate) fn default_gateway() -> Option<IpAddr> {
+        None
+    }
+
+    pub(crate) fn primary_interface() -> Option<String> {
+        None
+    }
+
+    pub(crate) fn protocol_stats() -> Option<crate::ProtocolStats> {
+        None
+    }
 }
 
 pub(crate) struct NetworkDataInner;
@@ -73,6 +97,62 @@ impl NetworkDataInner {
         0
     }
 
+    pub(crate) fn drops_on_received(&self) -> u64 {
+        0
+    }
+
+    pub(crate) fn total_drops_on_received(&self) -> u64 {
+        0
+    }
+
+    pub(crate) fn drops_on_transmitted(&self) -> u64 {
+        0
+    }
+
+    pub(crate) fn total_drops_on_transmitted(&self) -> u64 {
+        0
+    }
+
+    pub(crate) fn fifo_errors_on_received(&self) -> u64 {
+        0
+    }
+
+    pub(crate) fn total_fifo_errors_on_received(&self) -> u64 {
+        0
+    }
+
+    pub(crate) fn fifo_errors_on_transmitted(&self) -> u64 {
+        0
+    }
+
+    pub(crate) fn total_fifo_errors_on_transmitted(&self) -> u64 {
+        0
+    }
+
+    pub(crate) fn collisions(&self) -> u64 {
+        0
+    }
+
+    pub(crate) fn total_collisions(&self) -> u64 {
+        0
+    }
+
+    pub(crate) fn carrier_errors(&self) -> u64 {
+        0
+    }
+
+    pub(crate) fn total_carrier_errors(&self) -> u64 {
+        0
+    }
+
+    pub(crate) fn multicast_packets_received(&self) -> u64 {
+        0
+    }
+
+    pub(crate) fn total_multicast_packets_received(&self) -> u64 {
+        0
+    }
+
     pub(crate) fn mac_address(&self) -> MacAddr {
         MacAddr::UNSPECIFIED
     }
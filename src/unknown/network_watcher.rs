@@ -0,0 +1,25 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::common::network_watcher::InterfaceState;
+
+use std::io;
+use std::time::Duration;
+
+pub(crate) struct NetworkWatcherInner;
+
+impl NetworkWatcherInner {
+    pub(crate) fn open() -> io::Result<Self> {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "watching network interfaces is not supported on this system",
+        ))
+    }
+
+    pub(crate) fn wait(&mut self, _timeout: Duration) -> bool {
+        false
+    }
+
+    pub(crate) fn interfaces(&mut self) -> std::io::Result<Vec<InterfaceState>> {
+        Ok(Vec::new())
+    }
+}
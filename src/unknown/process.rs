@@ -1,10 +1,14 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
-use crate::{DiskUsage, Gid, Pid, ProcessStatus, Signal, Uid};
+use crate::{
+    CurrentProcessUsage, DiskUsage, EnergyImpact, ExeMetadata, ExeSignature, Gid, MediaUsage, Pid,
+    ProcessStatus, Signal, Uid,
+};
 
 use std::ffi::{OsStr, OsString};
 use std::fmt;
-use std::path::Path;
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
 use std::process::ExitStatus;
 
 impl fmt::Display for ProcessStatus {
@@ -63,6 +67,22 @@ impl ProcessInner {
         self.parent
     }
 
+    pub(crate) fn thread_count(&self) -> Option<usize> {
+        None
+    }
+
+    pub(crate) fn tty(&self) -> Option<String> {
+        None
+    }
+
+    pub(crate) fn reparented_to_init(&self) -> bool {
+        false
+    }
+
+    pub(crate) fn exec_count(&self) -> u64 {
+        0
+    }
+
     pub(crate) fn status(&self) -> ProcessStatus {
         ProcessStatus::Unknown(0)
     }
@@ -71,6 +91,10 @@ impl ProcessInner {
         0
     }
 
+    pub(crate) fn start_time_raw(&self) -> u64 {
+        0
+    }
+
     pub(crate) fn run_time(&self) -> u64 {
         0
     }
@@ -83,6 +107,10 @@ impl ProcessInner {
         0
     }
 
+    pub(crate) fn children_cpu_time(&self) -> u64 {
+        0
+    }
+
     pub(crate) fn disk_usage(&self) -> DiskUsage {
         DiskUsage::default()
     }
@@ -111,6 +139,18 @@ impl ProcessInner {
         None
     }
 
+    pub(crate) fn process_group_id(&self) -> Option<Pid> {
+        None
+    }
+
+    pub(crate) fn current_usage() -> Option<CurrentProcessUsage> {
+        None
+    }
+
+    pub(crate) fn children_usage() -> Option<crate::ChildrenUsage> {
+        None
+    }
+
     pub(crate) fn switch_updated(&mut self) -> bool {
         false
     }
@@ -125,7 +165,91 @@ impl ProcessInner {
         None
     }
 
+    pub(crate) fn path_usage(&self, path: &Path) -> crate::PathUsage {
+        crate::PathUsage::from_process_paths(path, self.cwd(), self.root(), self.exe())
+    }
+
+    pub(crate) fn deleted_mappings(&self) -> Vec<PathBuf> {
+        Vec::new()
+    }
+
     pub(crate) fn open_files_limit(&self) -> Option<usize> {
         None
     }
+
+    pub(crate) fn stack_size(&self) -> Option<u64> {
+        None
+    }
+
+    pub(crate) fn heap_size(&self) -> Option<u64> {
+        None
+    }
+
+    pub(crate) fn cgroup(&self) -> Option<String> {
+        None
+    }
+
+    pub(crate) fn container_id(&self) -> Option<String> {
+        None
+    }
+
+    pub(crate) fn sched_stats(&self) -> Option<crate::SchedStats> {
+        None
+    }
+
+    pub(crate) fn io_counters(&self) -> Option<crate::IoCounters> {
+        None
+    }
+
+    pub(crate) fn wait_channel(&self) -> Option<String> {
+        None
+    }
+
+    pub(crate) fn kernel_stack(&self) -> Result<Vec<String>, crate::RefreshError> {
+        Err(crate::RefreshError::Unsupported)
+    }
+
+    pub(crate) fn connection_count(&self) -> Option<crate::ConnectionCount> {
+        None
+    }
+
+    pub(crate) fn is_64_bit(&self) -> Option<bool> {
+        None
+    }
+
+    pub(crate) fn abi(&self) -> Option<&'static str> {
+        None
+    }
+
+    pub(crate) fn listening_ports(&self) -> Option<Vec<SocketAddr>> {
+        None
+    }
+
+    pub(crate) fn exe_signature(&self) -> Option<&ExeSignature> {
+        None
+    }
+
+    pub(crate) fn exe_metadata(&self) -> Option<&ExeMetadata> {
+        None
+    }
+
+    pub(crate) fn bundle_identifier(&self) -> Option<&str> {
+        None
+    }
+
+    pub(crate) fn bundle_name(&self) -> Option<&str> {
+        None
+    }
+
+    pub(crate) fn energy_impact(&self) -> Option<EnergyImpact> {
+        None
+    }
+
+    pub(crate) fn gpu_usage(&self) -> Option<f32> {
+        None
+    }
+
+    pub(crate) fn media_usage(&self) -> Option<MediaUsage> {
+        None
+    }
 }
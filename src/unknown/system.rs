@@ -2,11 +2,11 @@
 
 use crate::{
     Cpu, CpuRefreshKind, LoadAvg, MemoryRefreshKind, Pid, Process, ProcessRefreshKind,
-    ProcessesToUpdate,
+    ProcessStringStats, ProcessesToUpdate, RefreshError,
 };
 
 use std::collections::HashMap;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 declare_signals! {
     (),
@@ -37,6 +37,24 @@ impl SystemInner {
 
     pub(crate) fn refresh_cpu_specifics(&mut self, _refresh_kind: CpuRefreshKind) {}
 
+    pub(crate) fn try_refresh_memory_specifics(
+        &mut self,
+        _refresh_kind: MemoryRefreshKind,
+    ) -> Result<(), RefreshError> {
+        Err(RefreshError::Unsupported)
+    }
+
+    pub(crate) fn try_refresh_cpu_specifics(
+        &mut self,
+        _refresh_kind: CpuRefreshKind,
+    ) -> Result<(), RefreshError> {
+        Err(RefreshError::Unsupported)
+    }
+
+    pub(crate) fn check_processes_source() -> Result<(), RefreshError> {
+        Err(RefreshError::Unsupported)
+    }
+
     pub(crate) fn refresh_cpu_list(&mut self, _refresh_kind: CpuRefreshKind) {}
 
     pub(crate) fn refresh_processes_specifics(
@@ -55,6 +73,18 @@ impl SystemInner {
         &self.process_list
     }
 
+    pub(crate) fn process_string_stats(&self) -> ProcessStringStats {
+        ProcessStringStats::default()
+    }
+
+    pub(crate) fn set_max_kept_file_handles(&mut self, _max: usize) {}
+
+    pub(crate) fn kept_file_handles(&self) -> usize {
+        0
+    }
+
+    pub(crate) fn set_use_pidfds(&mut self, _use_pidfds: bool) {}
+
     pub(crate) fn processes_mut(&mut self) -> &mut HashMap<Pid, Process> {
         &mut self.process_list
     }
@@ -67,10 +97,18 @@ impl SystemInner {
         0.
     }
 
+    pub(crate) fn last_cpu_usage_update(&self) -> Option<Instant> {
+        None
+    }
+
     pub(crate) fn cpus(&self) -> &[Cpu] {
         &[]
     }
 
+    pub(crate) fn cpus_mut(&mut self) -> &mut [Cpu] {
+        &mut []
+    }
+
     pub(crate) fn total_memory(&self) -> u64 {
         0
     }
@@ -99,6 +137,18 @@ impl SystemInner {
         0
     }
 
+    pub(crate) fn swap_devices(&self) -> Vec<crate::SwapDevice> {
+        Vec::new()
+    }
+
+    pub(crate) fn compressed_memory(&self) -> Option<crate::CompressedMemory> {
+        None
+    }
+
+    pub(crate) fn inotify_usage(&self) -> Option<crate::InotifyUsage> {
+        None
+    }
+
     pub(crate) fn uptime() -> u64 {
         0
     }
@@ -115,6 +165,10 @@ impl SystemInner {
         }
     }
 
+    pub(crate) fn run_queue_length() -> Option<usize> {
+        None
+    }
+
     pub(crate) fn name() -> Option<String> {
         None
     }
@@ -150,11 +204,69 @@ impl SystemInner {
         None
     }
 
+    pub(crate) fn is_compat_mode() -> Option<bool> {
+        None
+    }
+
+    pub(crate) fn core_dump_config() -> Option<crate::CoreDumpConfig> {
+        None
+    }
+
     pub(crate) fn physical_core_count() -> Option<usize> {
         None
     }
 
+    pub(crate) fn clock_ticks_per_second() -> Option<u64> {
+        None
+    }
+
+    pub(crate) fn page_size() -> Option<u64> {
+        None
+    }
+
     pub(crate) fn open_files_limit() -> Option<usize> {
         None
     }
+
+    pub(crate) fn timezone() -> Option<String> {
+        None
+    }
+
+    pub(crate) fn locale() -> Option<String> {
+        None
+    }
+
+    pub(crate) fn cpu_vulnerabilities() -> Vec<crate::CpuVulnerability> {
+        Vec::new()
+    }
+
+    pub(crate) fn memory_hardware_info() -> Option<crate::MemoryHardwareInfo> {
+        None
+    }
+
+    pub(crate) fn virtualization() -> Option<String> {
+        crate::common::system::virtualization_from_product()
+    }
+
+    pub(crate) fn board_model() -> Option<String> {
+        None
+    }
+
+    pub(crate) fn turbo_enabled() -> Option<bool> {
+        None
+    }
+
+    pub(crate) fn throttling_status() -> Option<crate::ThrottlingStatus> {
+        None
+    }
+
+    pub(crate) fn socket_summary() -> Option<crate::SocketSummary> {
+        None
+    }
+
+    pub(crate) fn kernel_log(
+        _since: Option<Instant>,
+    ) -> std::io::Result<Vec<crate::KernelLogEntry>> {
+        Err(std::io::ErrorKind::Unsupported.into())
+    }
 }
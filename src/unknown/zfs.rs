@@ -0,0 +1,11 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::{ZfsArc, ZfsPool};
+
+pub(crate) fn get_zfs_pools(pools: &mut Vec<ZfsPool>) {
+    pools.clear();
+}
+
+pub(crate) fn get_zfs_arc() -> Option<ZfsArc> {
+    None
+}
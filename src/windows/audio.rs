@@ -0,0 +1,92 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::{AudioDevice, AudioDeviceKind};
+
+use windows::Win32::Devices::FunctionDiscovery::PKEY_Device_FriendlyName;
+use windows::Win32::Media::Audio::{
+    DEVICE_STATE_ACTIVE, EDataFlow, IMMDevice, IMMDeviceEnumerator, MMDeviceEnumerator, eCapture,
+    eConsole, eRender,
+};
+use windows::Win32::System::Com::StructuredStorage::{PropVariantClear, PropVariantToStringAlloc};
+use windows::Win32::System::Com::{
+    CLSCTX_ALL, CoCreateInstance, CoInitializeEx, CoTaskMemFree, STGM_READ,
+};
+use windows::core::PWSTR;
+
+/// Converts a `PWSTR` allocated by COM into a `String` and frees it.
+unsafe fn take_pwstr(s: PWSTR) -> Option<String> {
+    let ret = unsafe { s.to_string() }.ok();
+    unsafe { CoTaskMemFree(Some(s.0 as *const _)) };
+    ret
+}
+
+unsafe fn get_id(device: &IMMDevice) -> Option<String> {
+    unsafe { take_pwstr(device.GetId().ok()?) }
+}
+
+unsafe fn get_friendly_name(device: &IMMDevice) -> Option<String> {
+    unsafe {
+        let store = device.OpenPropertyStore(STGM_READ).ok()?;
+        let mut value = store.GetValue(&PKEY_Device_FriendlyName).ok()?;
+        let name = PropVariantToStringAlloc(&value)
+            .ok()
+            .and_then(|s| take_pwstr(s));
+        let _err = PropVariantClear(&mut value);
+        name
+    }
+}
+
+unsafe fn add_devices(
+    enumerator: &IMMDeviceEnumerator,
+    flow: EDataFlow,
+    kind: AudioDeviceKind,
+    devices: &mut Vec<AudioDevice>,
+) {
+    unsafe {
+        let default_id = enumerator
+            .GetDefaultAudioEndpoint(flow, eConsole)
+            .ok()
+            .and_then(|device| get_id(&device));
+        let collection = match enumerator.EnumAudioEndpoints(flow, DEVICE_STATE_ACTIVE) {
+            Ok(collection) => collection,
+            Err(_err) => {
+                sysinfo_debug!("EnumAudioEndpoints failed: {_err:?}");
+                return;
+            }
+        };
+        let count = collection.GetCount().unwrap_or(0);
+        for index in 0..count {
+            let Ok(device) = collection.Item(index) else {
+                continue;
+            };
+            let Some(id) = get_id(&device) else {
+                continue;
+            };
+            devices.push(AudioDevice {
+                name: get_friendly_name(&device).unwrap_or_else(|| id.clone()),
+                is_default: default_id.as_ref() == Some(&id),
+                id,
+                kind,
+            });
+        }
+    }
+}
+
+pub(crate) fn get_audio_devices(devices: &mut Vec<AudioDevice>) {
+    devices.clear();
+
+    unsafe {
+        // It fails if COM was already initialized with another concurrency model, which is fine.
+        let _err = CoInitializeEx(None, Default::default());
+        let enumerator: IMMDeviceEnumerator =
+            match CoCreateInstance(&MMDeviceEnumerator, None, CLSCTX_ALL) {
+                Ok(enumerator) => enumerator,
+                Err(_err) => {
+                    sysinfo_debug!("Failed to create the audio device enumerator: {_err:?}");
+                    return;
+                }
+            };
+        add_devices(&enumerator, eRender, AudioDeviceKind::Output, devices);
+        add_devices(&enumerator, eCapture, AudioDeviceKind::Input, devices);
+    }
+}
@@ -1,25 +1,13 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
-use crate::Component;
-
-use windows::Win32::Foundation::{SysAllocString, SysFreeString};
-use windows::Win32::Security::PSECURITY_DESCRIPTOR;
-use windows::Win32::System::Com::{
-    CLSCTX_INPROC_SERVER, CoCreateInstance, CoInitializeEx, CoInitializeSecurity,
-    CoSetProxyBlanket, EOAC_NONE, RPC_C_AUTHN_LEVEL_CALL, RPC_C_AUTHN_LEVEL_DEFAULT,
-    RPC_C_IMP_LEVEL_IMPERSONATE,
-};
-use windows::Win32::System::Rpc::{RPC_C_AUTHN_WINNT, RPC_C_AUTHZ_NONE};
+use crate::{Component, ValueSource};
+
+use crate::sys::wmi::WmiServices;
+
 use windows::Win32::System::Variant::{VARIANT, VariantClear};
-use windows::Win32::System::Wmi::{
-    IEnumWbemClassObject, IWbemLocator, IWbemServices, WBEM_FLAG_FORWARD_ONLY,
-    WBEM_FLAG_NONSYSTEM_ONLY, WBEM_FLAG_RETURN_IMMEDIATELY, WBEM_INFINITE, WbemLocator,
-};
+use windows::Win32::System::Wmi::{IEnumWbemClassObject, WBEM_FLAG_NONSYSTEM_ONLY, WBEM_INFINITE};
 use windows::core::w;
 
-use std::cell::OnceCell;
-use std::sync::OnceLock;
-
 pub(crate) struct ComponentInner {
     temperature: f32,
     max: f32,
@@ -32,11 +20,7 @@ pub(crate) struct ComponentInner {
 impl ComponentInner {
     /// Creates a new `ComponentInner` with the given information.
     fn new() -> Option<Self> {
-        let mut c = Connection::new()
-            .and_then(|x| x.create_instance())
-            .and_then(|x| x.connect_server())
-            .and_then(|x| x.set_proxy_blanket())
-            .and_then(|x| x.exec_query())?;
+        let mut c = Connection::new().and_then(|x| x.exec_query())?;
 
         c.temperature(true)
             .map(|(temperature, critical)| ComponentInner {
@@ -61,20 +45,33 @@ impl ComponentInner {
         self.critical
     }
 
+    pub(crate) fn max_source(&self) -> Option<ValueSource> {
+        Some(ValueSource::Inferred)
+    }
+
+    pub(crate) fn critical_source(&self) -> Option<ValueSource> {
+        self.critical.map(|_| ValueSource::Hardware)
+    }
+
     pub(crate) fn label(&self) -> &str {
         &self.label
     }
 
+    pub(crate) fn raw_label(&self) -> Option<&str> {
+        None
+    }
+
+    pub(crate) fn chip(&self) -> Option<&str> {
+        None
+    }
+
     pub(crate) fn id(&self) -> Option<&str> {
         Some(&self.label)
     }
 
     pub(crate) fn refresh(&mut self) {
         if self.connection.is_none() {
-            self.connection = Connection::new()
-                .and_then(|x| x.create_instance())
-                .and_then(|x| x.connect_server())
-                .and_then(|x| x.set_proxy_blanket());
+            self.connection = Connection::new();
         }
         self.connection = if let Some(x) = self.connection.take() {
             x.exec_query()
@@ -122,7 +119,10 @@ impl ComponentsInner {
     pub(crate) fn refresh(&mut self) {
         if self.components.is_empty() {
             self.components = match ComponentInner::new() {
-                Some(c) => vec![Component { inner: c }],
+                Some(c) => vec![Component {
+                    inner: c,
+                    max_observed: None,
+                }],
                 None => Vec::new(),
             };
         } else {
@@ -135,142 +135,28 @@ impl ComponentsInner {
     }
 }
 
-macro_rules! bstr {
-    ($x:literal) => {{ SysAllocString(w!($x)) }};
-}
-
 struct Connection {
-    instance: Option<IWbemLocator>,
-    server_connection: Option<IWbemServices>,
     enumerator: Option<IEnumWbemClassObject>,
+    services: WmiServices,
 }
 
 #[allow(clippy::non_send_fields_in_send_ty)]
 unsafe impl Send for Connection {}
 unsafe impl Sync for Connection {}
 
-static SECURITY: OnceLock<Result<(), ()>> = OnceLock::new();
-thread_local! {
-    pub static CONNECTION: OnceCell<Result<(), ()>> = const { OnceCell::new() };
-}
-
-unsafe fn initialize_connection() -> Result<(), ()> {
-    if unsafe { CoInitializeEx(None, Default::default()) }.is_err() {
-        sysinfo_debug!("Failed to initialize connection");
-        Err(())
-    } else {
-        Ok(())
-    }
-}
-
-unsafe fn initialize_security() -> Result<(), ()> {
-    if unsafe {
-        CoInitializeSecurity(
-            Some(PSECURITY_DESCRIPTOR::default()),
-            -1,
-            None,
-            None,
-            RPC_C_AUTHN_LEVEL_DEFAULT,
-            RPC_C_IMP_LEVEL_IMPERSONATE,
-            None,
-            EOAC_NONE,
-            None,
-        )
-    }
-    .is_err()
-    {
-        sysinfo_debug!("Failed to initialize security");
-        Err(())
-    } else {
-        Ok(())
-    }
-}
-
 impl Connection {
-    #[allow(clippy::unnecessary_wraps)]
     fn new() -> Option<Connection> {
-        if CONNECTION
-            .with(|x| *x.get_or_init(|| unsafe { initialize_connection() }))
-            .is_err()
-            || SECURITY
-                .get_or_init(|| unsafe { initialize_security() })
-                .is_err()
-        {
-            return None;
-        }
         Some(Connection {
-            instance: None,
-            server_connection: None,
             enumerator: None,
+            services: WmiServices::connect("root\\WMI")?,
         })
     }
 
-    fn create_instance(mut self) -> Option<Connection> {
-        let instance =
-            unsafe { CoCreateInstance(&WbemLocator, None, CLSCTX_INPROC_SERVER) }.ok()?;
-        self.instance = Some(instance);
-        Some(self)
-    }
-
-    fn connect_server(mut self) -> Option<Connection> {
-        let instance = self.instance.as_ref()?;
-        let svc = unsafe {
-            let s = bstr!("root\\WMI");
-            let res = instance.ConnectServer(
-                &s,
-                &Default::default(),
-                &Default::default(),
-                &Default::default(),
-                0,
-                &Default::default(),
-                None,
-            );
-            SysFreeString(&s);
-            res
-        }
-        .ok()?;
-
-        self.server_connection = Some(svc);
-        Some(self)
-    }
-
-    fn set_proxy_blanket(self) -> Option<Connection> {
-        unsafe {
-            CoSetProxyBlanket(
-                self.server_connection.as_ref()?,
-                RPC_C_AUTHN_WINNT,
-                RPC_C_AUTHZ_NONE,
-                None,
-                RPC_C_AUTHN_LEVEL_CALL,
-                RPC_C_IMP_LEVEL_IMPERSONATE,
-                None,
-                EOAC_NONE,
-            )
-        }
-        .ok()?;
-
-        Some(self)
-    }
-
     fn exec_query(mut self) -> Option<Connection> {
-        let server_connection = self.server_connection.as_ref()?;
-
-        let enumerator = unsafe {
-            let s = bstr!("WQL"); // query kind
-            let query = bstr!("SELECT * FROM MSAcpi_ThermalZoneTemperature");
-            let hres = server_connection.ExecQuery(
-                &s,
-                &query,
-                WBEM_FLAG_FORWARD_ONLY | WBEM_FLAG_RETURN_IMMEDIATELY,
-                None,
-            );
-            SysFreeString(&s);
-            SysFreeString(&query);
-            hres
-        }
-        .ok()?;
-
-        self.enumerator = Some(enumerator);
+        self.enumerator = Some(
+            self.services
+                .query("SELECT * FROM MSAcpi_ThermalZoneTemperature")?,
+        );
         Some(self)
     }
 
@@ -336,9 +222,7 @@ impl Connection {
 
 impl Drop for Connection {
     fn drop(&mut self) {
-        // Those three calls are here to enforce that they get dropped in the good order.
+        // The enumerator needs to be dropped before the connection.
         self.enumerator.take();
-        self.server_connection.take();
-        self.instance.take();
     }
 }
@@ -8,8 +8,11 @@ use std::io::Error;
 use std::mem;
 use std::ops::DerefMut;
 use std::sync::{Mutex, OnceLock};
+use std::time::Instant;
 
-use windows::Win32::Foundation::{CloseHandle, ERROR_INSUFFICIENT_BUFFER, ERROR_SUCCESS, HANDLE};
+use windows::Win32::Foundation::{
+    CloseHandle, ERROR_INSUFFICIENT_BUFFER, ERROR_SUCCESS, HANDLE, HLOCAL, LocalFree,
+};
 use windows::Win32::System::Performance::{
     PDH_FMT_COUNTERVALUE, PDH_FMT_DOUBLE, PDH_HCOUNTER, PDH_HQUERY, PERF_DETAIL_NOVICE,
     PdhAddEnglishCounterA, PdhAddEnglishCounterW, PdhCloseQuery, PdhCollectQueryData,
@@ -17,17 +20,22 @@ use windows::Win32::System::Performance::{
     PdhRemoveCounter,
 };
 use windows::Win32::System::Power::{
-    CallNtPowerInformation, PROCESSOR_POWER_INFORMATION, ProcessorInformation,
+    CallNtPowerInformation, GetSystemPowerStatus, PROCESSOR_POWER_INFORMATION,
+    PowerGetActiveScheme, PowerReadACValueIndex, PowerReadDCValueIndex, ProcessorInformation,
+    SYSTEM_POWER_STATUS,
 };
 use windows::Win32::System::SystemInformation::{self, GetSystemInfo};
 use windows::Win32::System::SystemInformation::{
     GetLogicalProcessorInformationEx, RelationAll, RelationProcessorCore, SYSTEM_INFO,
     SYSTEM_LOGICAL_PROCESSOR_INFORMATION_EX,
 };
+use windows::Win32::System::SystemServices::{
+    GUID_PROCESSOR_PERF_BOOST_MODE, GUID_PROCESSOR_SETTINGS_SUBGROUP,
+};
 use windows::Win32::System::Threading::{
     CreateEventA, INFINITE, RegisterWaitForSingleObject, WT_EXECUTEDEFAULT,
 };
-use windows::core::{PCSTR, PCWSTR, s};
+use windows::core::{GUID, PCSTR, PCWSTR, s};
 
 // This formula comes from Linux's include/linux/sched/loadavg.h
 // https://github.com/torvalds/linux/blob/345671ea0f9258f410eb057b9ced9cefbbe5dc78/include/linux/sched/loadavg.h#L20-L23
@@ -264,6 +272,7 @@ impl Query {
 pub(crate) struct CpusWrapper {
     pub(crate) global: CpuUsage,
     cpus: Vec<Cpu>,
+    pub(crate) last_update: Option<Instant>,
 }
 
 impl CpusWrapper {
@@ -274,6 +283,7 @@ impl CpusWrapper {
                 key_used: None,
             },
             cpus: Vec::new(),
+            last_update: None,
         }
     }
 
@@ -285,6 +295,10 @@ impl CpusWrapper {
         &self.cpus
     }
 
+    pub fn cpus_mut(&mut self) -> &mut [Cpu] {
+        &mut self.cpus
+    }
+
     fn init_if_needed(&mut self, refresh_kind: CpuRefreshKind) {
         if self.cpus.is_empty() {
             self.cpus = init_cpus(refresh_kind);
@@ -303,9 +317,11 @@ impl CpusWrapper {
 
     pub fn get_frequencies(&mut self) {
         let frequencies = get_frequencies(self.cpus.len());
+        let boost_active = get_boost_enabled();
 
         for (cpu, frequency) in self.cpus.iter_mut().zip(frequencies) {
             cpu.inner.set_frequency(frequency);
+            cpu.inner.boost_active = boost_active;
         }
     }
 }
@@ -327,6 +343,7 @@ pub(crate) struct CpuInner {
     usage: CpuUsage,
     brand: String,
     frequency: u64,
+    boost_active: Option<bool>,
 }
 
 impl CpuInner {
@@ -350,11 +367,28 @@ impl CpuInner {
         &self.brand
     }
 
+    pub(crate) fn run_queue_length(&self) -> Option<usize> {
+        None
+    }
+
+    pub(crate) fn run_queue_wait_time(&self) -> Option<u64> {
+        None
+    }
+
+    pub(crate) fn boost_active(&self) -> Option<bool> {
+        self.boost_active
+    }
+
+    pub(crate) fn idle_states(&self) -> &[crate::CpuIdleState] {
+        &[]
+    }
+
     pub(crate) fn new_with_values(
         name: String,
         vendor_id: String,
         brand: String,
         frequency: u64,
+        boost_active: Option<bool>,
     ) -> Self {
         Self {
             name,
@@ -365,6 +399,7 @@ impl CpuInner {
             vendor_id,
             brand,
             frequency,
+            boost_active,
         }
     }
 
@@ -515,6 +550,35 @@ pub(crate) fn get_frequencies(nb_cpus: usize) -> Vec<u64> {
     vec![0; nb_cpus]
 }
 
+/// Returns whether the "processor performance boost mode" of the active power scheme allows the
+/// CPUs to boost with the current power source.
+pub(crate) fn get_boost_enabled() -> Option<bool> {
+    unsafe {
+        let mut scheme: *mut GUID = std::ptr::null_mut();
+        if PowerGetActiveScheme(None, &mut scheme) != ERROR_SUCCESS || scheme.is_null() {
+            sysinfo_debug!("get_boost_enabled: PowerGetActiveScheme failed");
+            return None;
+        }
+        let mut status = SYSTEM_POWER_STATUS::default();
+        let on_battery = GetSystemPowerStatus(&mut status).is_ok() && status.ACLineStatus == 0;
+        let mut mode = 0;
+        let subgroup = Some(&GUID_PROCESSOR_SETTINGS_SUBGROUP as *const _);
+        let setting = Some(&GUID_PROCESSOR_PERF_BOOST_MODE as *const _);
+        let ret = if on_battery {
+            PowerReadDCValueIndex(None, Some(scheme as *const _), subgroup, setting, &mut mode)
+        } else {
+            PowerReadACValueIndex(None, Some(scheme as *const _), subgroup, setting, &mut mode).0
+        };
+        let _err = LocalFree(Some(HLOCAL(scheme as _)));
+        if ret != ERROR_SUCCESS.0 {
+            sysinfo_debug!("get_boost_enabled: failed to read the boost mode");
+            return None;
+        }
+        // `0` is the "Disabled" mode, all the others allow the boost.
+        Some(mode != 0)
+    }
+}
+
 pub(crate) fn get_physical_core_count() -> Option<usize> {
     // We cannot use the number of cpus here to pre calculate the buf size.
     // `GetLogicalCpuInformationEx` with `RelationProcessorCore` passed to it not only returns
@@ -598,10 +662,10 @@ fn init_cpus(refresh_kind: CpuRefreshKind) -> Vec<Cpu> {
         GetSystemInfo(&mut sys_info);
         let (vendor_id, brand) = get_vendor_id_and_brand(&sys_info);
         let nb_cpus = sys_info.dwNumberOfProcessors as usize;
-        let frequencies = if refresh_kind.frequency() {
-            get_frequencies(nb_cpus)
+        let (frequencies, boost_active) = if refresh_kind.frequency() {
+            (get_frequencies(nb_cpus), get_boost_enabled())
         } else {
-            vec![0; nb_cpus]
+            (vec![0; nb_cpus], None)
         };
         let mut ret = Vec::with_capacity(nb_cpus + 1);
         for (nb, frequency) in frequencies.iter().enumerate() {
@@ -611,7 +675,10 @@ fn init_cpus(refresh_kind: CpuRefreshKind) -> Vec<Cpu> {
                     vendor_id.clone(),
                     brand.clone(),
                     *frequency,
+                    boost_active,
                 ),
+                peak_usage: 0.,
+                measurement_interval: None,
             });
         }
         ret
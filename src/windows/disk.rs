@@ -1,17 +1,17 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
 use crate::sys::utils::HandleWrapper;
-use crate::{Disk, DiskKind, DiskRefreshKind, DiskUsage};
+use crate::{Disk, DiskKind, DiskRefreshKind, DiskUsage, RefreshError};
 
 use std::ffi::{OsStr, OsString};
 use std::mem::size_of;
-use std::os::windows::ffi::OsStringExt;
+use std::os::windows::ffi::{OsStrExt, OsStringExt};
 use std::path::Path;
 
 use windows::Win32::Foundation::MAX_PATH;
 use windows::Win32::Storage::FileSystem::{
     FindFirstVolumeW, FindNextVolumeW, FindVolumeClose, GetDiskFreeSpaceExW, GetDriveTypeW,
-    GetVolumeInformationW, GetVolumePathNamesForVolumeNameW,
+    GetVolumeInformationW, GetVolumePathNameW, GetVolumePathNamesForVolumeNameW,
 };
 use windows::Win32::System::IO::DeviceIoControl;
 use windows::Win32::System::Ioctl::{
@@ -213,6 +213,14 @@ impl DiskInner {
             total_written_bytes: self.written_bytes,
         }
     }
+
+    pub(crate) fn network_fs_stats(&self) -> Option<crate::NetworkFsStats> {
+        None
+    }
+
+    pub(crate) fn io_stats(&self) -> Option<crate::DiskIoStats> {
+        None
+    }
 }
 
 pub(crate) struct DisksInner {
@@ -234,6 +242,15 @@ impl DisksInner {
         self.disks
     }
 
+    pub(crate) fn try_refresh_specifics(
+        &mut self,
+        remove_not_listed_disks: bool,
+        refresh_kind: DiskRefreshKind,
+    ) -> Result<(), RefreshError> {
+        self.refresh_specifics(remove_not_listed_disks, refresh_kind);
+        Ok(())
+    }
+
     pub(crate) fn refresh_specifics(
         &mut self,
         remove_not_listed_disks: bool,
@@ -251,6 +268,39 @@ impl DisksInner {
     pub(crate) fn list_mut(&mut self) -> &mut [Disk] {
         &mut self.disks
     }
+
+    pub(crate) fn usage_for_path(path: &Path) -> Option<crate::DiskSpace> {
+        let path = path
+            .as_os_str()
+            .encode_wide()
+            .chain(Some(0))
+            .collect::<Vec<_>>();
+        let path = PCWSTR::from_raw(path.as_ptr());
+        let mut mount_point = [0u16; MAX_PATH as usize + 1];
+        let mut available_space = 0;
+        let mut total_space = 0;
+        let mut free_space = 0;
+        unsafe {
+            GetVolumePathNameW(path, &mut mount_point).ok()?;
+            GetDiskFreeSpaceExW(
+                path,
+                Some(&mut available_space),
+                Some(&mut total_space),
+                Some(&mut free_space),
+            )
+            .ok()?;
+        }
+        let len = mount_point
+            .iter()
+            .position(|&c| c == 0)
+            .unwrap_or(mount_point.len());
+        Some(crate::DiskSpace {
+            mount_point: OsString::from_wide(&mount_point[..len]).into(),
+            total_space,
+            available_space,
+            used_space: total_space.saturating_sub(free_space),
+        })
+    }
 }
 
 unsafe fn get_drive_size(mount_point: &[u16]) -> Option<(u64, u64)> {
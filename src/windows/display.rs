@@ -0,0 +1,76 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::Display;
+
+use std::mem;
+
+use windows::Win32::Graphics::Gdi::{
+    DEVMODEW, DISPLAY_DEVICE_ATTACHED_TO_DESKTOP, DISPLAY_DEVICE_PRIMARY_DEVICE, DISPLAY_DEVICEW,
+    ENUM_CURRENT_SETTINGS, EnumDisplayDevicesW, EnumDisplaySettingsW,
+};
+use windows::core::PCWSTR;
+
+fn wide_to_string(s: &[u16]) -> String {
+    let len = s.iter().position(|c| *c == 0).unwrap_or(s.len());
+    String::from_utf16_lossy(&s[..len])
+}
+
+/// Calls `EnumDisplayDevicesW`. If `adapter` is `None`, it enumerates the display adapters,
+/// otherwise the monitors attached to `adapter`.
+unsafe fn enum_display_device(adapter: Option<&[u16]>, index: u32) -> Option<DISPLAY_DEVICEW> {
+    let mut device = DISPLAY_DEVICEW {
+        cb: mem::size_of::<DISPLAY_DEVICEW>() as _,
+        ..Default::default()
+    };
+    let adapter = adapter.map_or(PCWSTR::null(), |name| PCWSTR(name.as_ptr()));
+    if unsafe { EnumDisplayDevicesW(adapter, index, &mut device, 0) }.as_bool() {
+        Some(device)
+    } else {
+        None
+    }
+}
+
+pub(crate) fn get_displays(displays: &mut Vec<Display>) {
+    displays.clear();
+
+    unsafe {
+        for index in 0.. {
+            let Some(adapter) = enum_display_device(None, index) else {
+                break;
+            };
+            if !adapter
+                .StateFlags
+                .contains(DISPLAY_DEVICE_ATTACHED_TO_DESKTOP)
+            {
+                continue;
+            }
+            let mut mode = DEVMODEW {
+                dmSize: mem::size_of::<DEVMODEW>() as _,
+                ..Default::default()
+            };
+            if !EnumDisplaySettingsW(
+                PCWSTR(adapter.DeviceName.as_ptr()),
+                ENUM_CURRENT_SETTINGS,
+                &mut mode,
+            )
+            .as_bool()
+            {
+                sysinfo_debug!("EnumDisplaySettingsW failed");
+                continue;
+            }
+            let monitor = enum_display_device(Some(&adapter.DeviceName), 0);
+            displays.push(Display {
+                id: wide_to_string(&adapter.DeviceName),
+                name: monitor.map(|monitor| wide_to_string(&monitor.DeviceString)),
+                width: mode.dmPelsWidth,
+                height: mode.dmPelsHeight,
+                // `0` and `1` mean "default hardware refresh rate".
+                refresh_rate: Some(mode.dmDisplayFrequency)
+                    .filter(|rate| *rate > 1)
+                    .map(|rate| rate as f64),
+                gpu: Some(wide_to_string(&adapter.DeviceString)).filter(|gpu| !gpu.is_empty()),
+                is_primary: adapter.StateFlags.contains(DISPLAY_DEVICE_PRIMARY_DEVICE),
+            });
+        }
+    }
+}
@@ -0,0 +1,26 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::Pid;
+use crate::common::ebpf::LatencyStats;
+
+use std::collections::HashMap;
+use std::io;
+
+pub(crate) struct EbpfCollectorsInner;
+
+impl EbpfCollectorsInner {
+    pub(crate) fn start() -> io::Result<Self> {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "eBPF collectors are not supported on this system",
+        ))
+    }
+
+    pub(crate) fn refresh(
+        &mut self,
+        _io_latency: &mut HashMap<Pid, LatencyStats>,
+        _tcp_retransmits: &mut HashMap<Pid, u64>,
+        _run_queue_latency: &mut HashMap<Pid, LatencyStats>,
+    ) {
+    }
+}
@@ -0,0 +1,20 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::common::exited_process::ExitedProcess;
+
+use std::io;
+
+pub(crate) struct ExitedProcessesInner;
+
+impl ExitedProcessesInner {
+    pub(crate) fn start() -> io::Result<Self> {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "capturing exited processes is not supported on this system",
+        ))
+    }
+
+    pub(crate) fn take(&mut self, _processes: &mut Vec<ExitedProcess>) -> bool {
+        false
+    }
+}
@@ -5,23 +5,40 @@ mod utils;
 cfg_if! {
     if #[cfg(feature = "system")] {
         mod cpu;
+        mod exited_process;
         mod ffi;
         mod motherboard;
         mod process;
         mod product;
+        mod security;
         mod system;
 
         pub(crate) use self::cpu::CpuInner;
+        pub(crate) use self::exited_process::ExitedProcessesInner;
         pub(crate) use self::motherboard::MotherboardInner;
         pub(crate) use self::process::ProcessInner;
         pub(crate) use self::product::ProductInner;
+        pub(crate) use self::security::SecurityInfoInner;
         pub(crate) use self::system::SystemInner;
         pub use self::system::{MINIMUM_CPU_UPDATE_INTERVAL, SUPPORTED_SIGNALS};
     }
+    if #[cfg(feature = "apple-silicon")] {
+        mod apple_silicon;
+
+        pub(crate) use self::apple_silicon::AppleSiliconMetricsInner;
+    }
+    if #[cfg(feature = "ebpf")] {
+        mod ebpf;
+
+        pub(crate) use self::ebpf::EbpfCollectorsInner;
+    }
     if #[cfg(feature = "disk")] {
         mod disk;
+        mod storage;
 
         pub(crate) use self::disk::{DiskInner, DisksInner};
+        pub(crate) use self::storage::StorageInner;
+        pub(crate) use crate::common::disk_watcher::PollingDiskWatcherInner as DiskWatcherInner;
     }
 
     if #[cfg(feature = "component")] {
@@ -33,8 +50,28 @@ cfg_if! {
     if #[cfg(feature = "network")] {
         mod network;
         pub(crate) mod network_helper;
+        mod network_watcher;
 
         pub(crate) use self::network::{NetworkDataInner, NetworksInner};
+        pub(crate) use self::network_watcher::NetworkWatcherInner;
+    }
+
+    if #[cfg(feature = "audio")] {
+        mod audio;
+
+        pub(crate) use self::audio::get_audio_devices;
+    }
+
+    if #[cfg(feature = "display")] {
+        mod display;
+
+        pub(crate) use self::display::get_displays;
+    }
+
+    if #[cfg(feature = "usb")] {
+        mod usb;
+
+        pub(crate) use self::usb::get_usb_devices;
     }
 
     if #[cfg(feature = "user")] {
@@ -46,11 +83,21 @@ cfg_if! {
         pub(crate) use self::users::UserInner;
     }
 
+    if #[cfg(feature = "zfs")] {
+        mod zfs;
+
+        pub(crate) use self::zfs::{get_zfs_arc, get_zfs_pools};
+    }
+
     if #[cfg(any(feature = "user", feature = "system"))] {
         mod sid;
 
         pub(crate) use self::sid::Sid;
     }
+
+    if #[cfg(any(feature = "component", feature = "system"))] {
+        pub(crate) mod wmi;
+    }
 }
 
 #[doc = include_str!("../../md_doc/is_supported.md")]
@@ -58,12 +105,18 @@ pub const IS_SUPPORTED_SYSTEM: bool = true;
 
 // Make formattable by rustfmt.
 #[cfg(any())]
+mod apple_silicon;
+#[cfg(any())]
+mod audio;
+#[cfg(any())]
 mod component;
 #[cfg(any())]
 mod cpu;
 #[cfg(any())]
 mod disk;
 #[cfg(any())]
+mod display;
+#[cfg(any())]
 mod ffi;
 #[cfg(any())]
 mod groups;
@@ -74,12 +127,24 @@ mod network;
 #[cfg(any())]
 mod network_helper;
 #[cfg(any())]
+mod network_watcher;
+#[cfg(any())]
 mod process;
 #[cfg(any())]
 mod product;
 #[cfg(any())]
+mod security;
+#[cfg(any())]
 mod sid;
 #[cfg(any())]
+mod storage;
+#[cfg(any())]
 mod system;
 #[cfg(any())]
+mod usb;
+#[cfg(any())]
 mod users;
+#[cfg(any())]
+mod wmi;
+#[cfg(any())]
+mod zfs;
@@ -1,12 +1,20 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
 use crate::network::refresh_networks_addresses;
-use crate::{IpNetwork, MacAddr, NetworkData};
+use crate::sys::network_helper::{get_default_route, get_dns_configuration};
+use crate::{DnsConfiguration, IpNetwork, MacAddr, NetworkData, RefreshError};
 
 use std::collections::{HashMap, hash_map};
+use std::net::IpAddr;
 
-use windows::Win32::NetworkManagement::IpHelper::{FreeMibTable, GetIfTable2, MIB_IF_TABLE2};
+use windows::Win32::Foundation::NO_ERROR;
+use windows::Win32::NetworkManagement::IpHelper::{
+    FreeMibTable, GetIcmpStatisticsEx, GetIfTable2, GetIpStatisticsEx, GetTcpStatisticsEx,
+    GetUdpStatisticsEx, MIB_ICMP_EX_XPSP1, MIB_IF_TABLE2, MIB_IPSTATS_LH, MIB_TCPSTATS_LH,
+    MIB_UDPSTATS,
+};
 use windows::Win32::NetworkManagement::Ndis::MediaConnectStateDisconnected;
+use windows::Win32::Networking::WinSock::{AF_INET, AF_INET6};
 
 macro_rules! old_and_new {
     ($ty_:expr, $name:ident, $old:ident, $new_val:expr) => {{
@@ -30,6 +38,14 @@ impl NetworksInner {
         &self.interfaces
     }
 
+    pub(crate) fn try_refresh(
+        &mut self,
+        remove_not_listed_interfaces: bool,
+    ) -> Result<(), RefreshError> {
+        self.refresh(remove_not_listed_interfaces);
+        Ok(())
+    }
+
     pub(crate) fn refresh(&mut self, remove_not_listed_interfaces: bool) {
         let mut table: *mut MIB_IF_TABLE2 = std::ptr::null_mut();
 
@@ -114,6 +130,8 @@ impl NetworksInner {
                         );
                         old_and_new!(interface, errors_in, old_errors_in, ptr.InErrors);
                         old_and_new!(interface, errors_out, old_errors_out, ptr.OutErrors);
+                        old_and_new!(interface, drops_in, old_drops_in, ptr.InDiscards);
+                        old_and_new!(interface, drops_out, old_drops_out, ptr.OutDiscards);
                         if interface.mtu != mtu {
                             interface.mtu = mtu;
                         }
@@ -137,6 +155,10 @@ impl NetworksInner {
                                 old_errors_in: ptr.InErrors,
                                 errors_out: ptr.OutErrors,
                                 old_errors_out: ptr.OutErrors,
+                                drops_in: ptr.InDiscards,
+                                old_drops_in: ptr.InDiscards,
+                                drops_out: ptr.OutDiscards,
+                                old_drops_out: ptr.OutDiscards,
                                 mac_addr: MacAddr::UNSPECIFIED,
                                 ip_networks: vec![],
                                 mtu,
@@ -161,6 +183,59 @@ impl NetworksInner {
         // Refresh all interfaces' addresses.
         refresh_networks_addresses(&mut self.interfaces);
     }
+
+    pub(crate) fn dns_configuration() -> DnsConfiguration {
+        unsafe { get_dns_configuration() }
+    }
+
+    pub(crate) fn default_gateway() -> Option<IpAddr> {
+        unsafe { get_default_route() }.map(|(gateway, _)| gateway)
+    }
+
+    pub(crate) fn primary_interface() -> Option<String> {
+        unsafe { get_default_route() }.and_then(|(_, interface)| interface)
+    }
+
+    pub(crate) fn protocol_stats() -> Option<crate::ProtocolStats> {
+        let mut stats = crate::ProtocolStats::default();
+        let mut found = false;
+        for family in [AF_INET, AF_INET6] {
+            let family = family.0 as u32;
+            let mut ip = MIB_IPSTATS_LH::default();
+            if unsafe { GetIpStatisticsEx(&mut ip, family) } == NO_ERROR.0 {
+                found = true;
+                stats.ip_header_errors += ip.dwInHdrErrors as u64;
+                stats.ip_address_errors += ip.dwInAddrErrors as u64;
+                stats.ip_discards += ip.dwInDiscards as u64;
+                stats.ip_reassembly_failures += ip.dwReasmFails as u64;
+                stats.ip_fragment_failures += ip.dwFragFails as u64;
+            }
+            let mut icmp = MIB_ICMP_EX_XPSP1::default();
+            if unsafe { GetIcmpStatisticsEx(&mut icmp, family) } == NO_ERROR.0 {
+                found = true;
+                stats.icmp_errors_received += icmp.icmpInStats.dwErrors as u64;
+                stats.icmp_errors_sent += icmp.icmpOutStats.dwErrors as u64;
+            }
+            let mut tcp = MIB_TCPSTATS_LH::default();
+            if unsafe { GetTcpStatisticsEx(&mut tcp, family) } == NO_ERROR.0 {
+                found = true;
+                stats.tcp_segments_received += tcp.dwInSegs as u64;
+                stats.tcp_segments_sent += tcp.dwOutSegs as u64;
+                stats.tcp_retransmitted_segments += tcp.dwRetransSegs as u64;
+                stats.tcp_errors_received += tcp.dwInErrs as u64;
+                stats.tcp_resets_sent += tcp.dwOutRsts as u64;
+                stats.tcp_connection_resets += tcp.dwEstabResets as u64;
+                stats.tcp_failed_connection_attempts += tcp.dwAttemptFails as u64;
+            }
+            let mut udp = MIB_UDPSTATS::default();
+            if unsafe { GetUdpStatisticsEx(&mut udp, family) } == NO_ERROR.0 {
+                found = true;
+                stats.udp_receive_errors += udp.dwInErrors as u64;
+                stats.udp_no_ports += udp.dwNoPorts as u64;
+            }
+        }
+        found.then_some(stats)
+    }
 }
 
 pub(crate) struct NetworkDataInner {
@@ -176,6 +251,10 @@ pub(crate) struct NetworkDataInner {
     old_errors_in: u64,
     errors_out: u64,
     old_errors_out: u64,
+    drops_in: u64,
+    old_drops_in: u64,
+    drops_out: u64,
+    old_drops_out: u64,
     updated: bool,
     pub(crate) mac_addr: MacAddr,
     pub(crate) ip_networks: Vec<IpNetwork>,
@@ -232,6 +311,62 @@ impl NetworkDataInner {
         self.errors_out
     }
 
+    pub(crate) fn drops_on_received(&self) -> u64 {
+        self.drops_in.saturating_sub(self.old_drops_in)
+    }
+
+    pub(crate) fn total_drops_on_received(&self) -> u64 {
+        self.drops_in
+    }
+
+    pub(crate) fn drops_on_transmitted(&self) -> u64 {
+        self.drops_out.saturating_sub(self.old_drops_out)
+    }
+
+    pub(crate) fn total_drops_on_transmitted(&self) -> u64 {
+        self.drops_out
+    }
+
+    pub(crate) fn fifo_errors_on_received(&self) -> u64 {
+        0
+    }
+
+    pub(crate) fn total_fifo_errors_on_received(&self) -> u64 {
+        0
+    }
+
+    pub(crate) fn fifo_errors_on_transmitted(&self) -> u64 {
+        0
+    }
+
+    pub(crate) fn total_fifo_errors_on_transmitted(&self) -> u64 {
+        0
+    }
+
+    pub(crate) fn collisions(&self) -> u64 {
+        0
+    }
+
+    pub(crate) fn total_collisions(&self) -> u64 {
+        0
+    }
+
+    pub(crate) fn carrier_errors(&self) -> u64 {
+        0
+    }
+
+    pub(crate) fn total_carrier_errors(&self) -> u64 {
+        0
+    }
+
+    pub(crate) fn multicast_packets_received(&self) -> u64 {
+        0
+    }
+
+    pub(crate) fn total_multicast_packets_received(&self) -> u64 {
+        0
+    }
+
     pub(crate) fn mac_address(&self) -> MacAddr {
         self.mac_addr
     }
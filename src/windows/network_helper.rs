@@ -6,14 +6,18 @@ use std::ptr::{NonNull, null_mut};
 
 use windows::Win32::Foundation::{ERROR_BUFFER_OVERFLOW, ERROR_SUCCESS};
 use windows::Win32::NetworkManagement::IpHelper::{
-    GAA_FLAG_SKIP_ANYCAST, GAA_FLAG_SKIP_DNS_SERVER, GAA_FLAG_SKIP_MULTICAST, GetAdaptersAddresses,
-    IP_ADAPTER_ADDRESSES_LH, IP_ADAPTER_UNICAST_ADDRESS_LH,
+    ConvertInterfaceLuidToAlias, FreeMibTable, GAA_FLAG_SKIP_ANYCAST, GAA_FLAG_SKIP_DNS_SERVER,
+    GAA_FLAG_SKIP_MULTICAST, GAA_FLAG_SKIP_UNICAST, GET_ADAPTERS_ADDRESSES_FLAGS,
+    GetAdaptersAddresses, GetIpForwardTable2, GetIpInterfaceEntry, IP_ADAPTER_ADDRESSES_LH,
+    IP_ADAPTER_UNICAST_ADDRESS_LH, InitializeIpInterfaceEntry, MIB_IPFORWARD_ROW2,
+    MIB_IPFORWARD_TABLE2, MIB_IPINTERFACE_ROW,
 };
+use windows::Win32::NetworkManagement::Ndis::{IfOperStatusUp, NET_LUID_LH};
 use windows::Win32::Networking::WinSock::{
     AF_INET, AF_INET6, AF_UNSPEC, SOCKADDR, SOCKADDR_IN, SOCKADDR_IN6,
 };
 
-use crate::{IpNetwork, MacAddr};
+use crate::{DnsConfiguration, IpNetwork, MacAddr};
 
 /// this iterator yields an interface name and address
 pub(crate) struct InterfaceAddressIterator {
@@ -102,6 +106,16 @@ impl Drop for InterfaceAddressIterator {
 }
 
 pub(crate) unsafe fn get_interface_address() -> Result<InterfaceAddressIterator, String> {
+    unsafe {
+        get_adapters_addresses(
+            GAA_FLAG_SKIP_MULTICAST | GAA_FLAG_SKIP_ANYCAST | GAA_FLAG_SKIP_DNS_SERVER,
+        )
+    }
+}
+
+unsafe fn get_adapters_addresses(
+    flags: GET_ADAPTERS_ADDRESSES_FLAGS,
+) -> Result<InterfaceAddressIterator, String> {
     // https://learn.microsoft.com/en-us/windows/win32/api/iphlpapi/nf-iphlpapi-getadaptersaddresses#remarks
     // A 15k buffer is recommended
     let mut size: u32 = 15 * 1024;
@@ -115,7 +129,7 @@ pub(crate) unsafe fn get_interface_address() -> Result<InterfaceAddressIterator,
             iterator = iterator.realloc(size as _)?;
             ret = GetAdaptersAddresses(
                 AF_UNSPEC.0.into(),
-                GAA_FLAG_SKIP_MULTICAST | GAA_FLAG_SKIP_ANYCAST | GAA_FLAG_SKIP_DNS_SERVER,
+                flags,
                 None,
                 Some(iterator.buf),
                 &mut size,
@@ -135,6 +149,124 @@ pub(crate) unsafe fn get_interface_address() -> Result<InterfaceAddressIterator,
     Err(format!("GetAdaptersAddresses() failed with code {ret}"))
 }
 
+/// Merges the DNS servers and suffixes of the network adapters which are up.
+pub(crate) unsafe fn get_dns_configuration() -> DnsConfiguration {
+    let mut config = DnsConfiguration::default();
+    let iterator = match unsafe {
+        get_adapters_addresses(
+            GAA_FLAG_SKIP_UNICAST | GAA_FLAG_SKIP_MULTICAST | GAA_FLAG_SKIP_ANYCAST,
+        )
+    } {
+        Ok(iterator) => iterator,
+        Err(_error) => {
+            sysinfo_debug!("{_error}");
+            return config;
+        }
+    };
+    let mut adapter = iterator.buf;
+    while !adapter.is_null() {
+        let current = unsafe { &*adapter };
+        adapter = current.Next;
+        if current.OperStatus != IfOperStatusUp {
+            continue;
+        }
+
+        let mut server = current.FirstDnsServerAddress;
+        while !server.is_null() {
+            let current_server = unsafe { server.read_unaligned() };
+            if let Some(socket_address) = NonNull::new(current_server.Address.lpSockaddr)
+                && let Some(addr) = get_ip_address_from_socket_address(socket_address)
+                && !config.nameservers.contains(&addr)
+            {
+                config.nameservers.push(addr);
+            }
+            server = current_server.Next;
+        }
+
+        let mut domains = Vec::new();
+        if let Ok(suffix) = unsafe { current.DnsSuffix.to_string() } {
+            domains.push(suffix);
+        }
+        let mut suffix = current.FirstDnsSuffix;
+        while !suffix.is_null() {
+            let current_suffix = unsafe { &*suffix };
+            let len = current_suffix
+                .String
+                .iter()
+                .position(|c| *c == 0)
+                .unwrap_or(current_suffix.String.len());
+            domains.push(String::from_utf16_lossy(&current_suffix.String[..len]));
+            suffix = current_suffix.Next;
+        }
+        for domain in domains {
+            if !domain.is_empty() && !config.search_domains.contains(&domain) {
+                config.search_domains.push(domain);
+            }
+        }
+    }
+    config
+}
+
+/// Returns the metric of a route, which is the sum of the route metric and of the metric of its
+/// interface.
+unsafe fn get_route_metric(row: &MIB_IPFORWARD_ROW2) -> u32 {
+    let mut interface = MIB_IPINTERFACE_ROW::default();
+    unsafe {
+        InitializeIpInterfaceEntry(&mut interface);
+        interface.Family = row.DestinationPrefix.Prefix.si_family;
+        interface.InterfaceLuid = row.InterfaceLuid;
+        if GetIpInterfaceEntry(&mut interface) != ERROR_SUCCESS {
+            return row.Metric;
+        }
+    }
+    row.Metric.saturating_add(interface.Metric)
+}
+
+/// Returns the gateway and the interface alias of the default route with the lowest metric,
+/// preferring IPv4 over IPv6.
+pub(crate) unsafe fn get_default_route() -> Option<(IpAddr, Option<String>)> {
+    let mut table: *mut MIB_IPFORWARD_TABLE2 = null_mut();
+    let mut best: Option<((bool, u32), IpAddr, NET_LUID_LH)> = None;
+    unsafe {
+        if GetIpForwardTable2(AF_UNSPEC, &mut table) != ERROR_SUCCESS || table.is_null() {
+            sysinfo_debug!("GetIpForwardTable2 failed");
+            return None;
+        }
+        let rows =
+            std::slice::from_raw_parts((*table).Table.as_ptr(), (*table).NumEntries as usize);
+        for row in rows {
+            if row.DestinationPrefix.PrefixLength != 0 {
+                continue;
+            }
+            let Some(gateway) =
+                get_ip_address_from_socket_address(NonNull::from(&row.NextHop).cast())
+            else {
+                continue;
+            };
+            // On-link routes don't have a gateway.
+            if gateway.is_unspecified() {
+                continue;
+            }
+            let key = (gateway.is_ipv6(), get_route_metric(row));
+            if best.as_ref().is_none_or(|(best_key, _, _)| key < *best_key) {
+                best = Some((key, gateway, row.InterfaceLuid));
+            }
+        }
+        FreeMibTable(table as _);
+    }
+
+    let (_, gateway, luid) = best?;
+    // `NDIS_IF_MAX_STRING_SIZE + 1`.
+    let mut alias = [0u16; 257];
+    let interface = if unsafe { ConvertInterfaceLuidToAlias(&luid, &mut alias) } == ERROR_SUCCESS {
+        let len = alias.iter().position(|c| *c == 0).unwrap_or(alias.len());
+        Some(String::from_utf16_lossy(&alias[..len]))
+    } else {
+        None
+    };
+    Some((gateway, interface))
+}
+
 fn get_ip_networks(mut prefixes_ptr: *mut IP_ADAPTER_UNICAST_ADDRESS_LH) -> HashSet<IpNetwork> {
     let mut ip_networks = HashSet::new();
     while !prefixes_ptr.is_null() {
@@ -0,0 +1,125 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::common::network_watcher::InterfaceState;
+
+use std::ffi::c_void;
+use std::io;
+use std::sync::{Arc, Condvar, Mutex};
+use std::time::Duration;
+
+use windows::Win32::Foundation::{HANDLE, NO_ERROR};
+use windows::Win32::NetworkManagement::IpHelper::{
+    CancelMibChangeNotify2, FreeMibTable, GetIfTable2, MIB_IF_TABLE2, MIB_IPINTERFACE_ROW,
+    MIB_NOTIFICATION_TYPE, NotifyIpInterfaceChange,
+};
+use windows::Win32::NetworkManagement::Ndis::IfOperStatusUp;
+use windows::Win32::Networking::WinSock::AF_UNSPEC;
+
+/// Set by the notification callback when something changed.
+#[derive(Default)]
+struct Changed {
+    changed: Mutex<bool>,
+    condvar: Condvar,
+}
+
+pub(crate) struct NetworkWatcherInner {
+    changed: Arc<Changed>,
+    handle: HANDLE,
+}
+
+// SAFETY: The notification handle is only used to cancel the notifications.
+unsafe impl Send for NetworkWatcherInner {}
+
+unsafe extern "system" fn on_interface_change(
+    context: *const c_void,
+    _row: *const MIB_IPINTERFACE_ROW,
+    _kind: MIB_NOTIFICATION_TYPE,
+) {
+    // SAFETY: `context` is the `Changed` kept alive by `NetworkWatcherInner` until the
+    // notifications are cancelled.
+    let changed = unsafe { &*(context as *const Changed) };
+    if let Ok(mut value) = changed.changed.lock() {
+        *value = true;
+        changed.condvar.notify_all();
+    }
+}
+
+impl NetworkWatcherInner {
+    pub(crate) fn open() -> io::Result<Self> {
+        let changed = Arc::new(Changed::default());
+        let mut handle = HANDLE::default();
+        let ret = unsafe {
+            NotifyIpInterfaceChange(
+                AF_UNSPEC,
+                Some(on_interface_change),
+                Some(Arc::as_ptr(&changed).cast()),
+                false,
+                &mut handle,
+            )
+        };
+        if ret != NO_ERROR {
+            return Err(io::Error::from_raw_os_error(ret.0 as _));
+        }
+        Ok(Self { changed, handle })
+    }
+
+    pub(crate) fn wait(&mut self, timeout: Duration) -> bool {
+        let Ok(changed) = self.changed.changed.lock() else {
+            return false;
+        };
+        let Ok((mut changed, _)) =
+            self.changed
+                .condvar
+                .wait_timeout_while(changed, timeout, |changed| !*changed)
+        else {
+            return false;
+        };
+        std::mem::replace(&mut *changed, false)
+    }
+
+    pub(crate) fn interfaces(&mut self) -> io::Result<Vec<InterfaceState>> {
+        // From the `InterfaceAndOperStatusFlags` bit field.
+        const FILTER_INTERFACE: u8 = 1 << 1;
+
+        let mut interfaces: Vec<InterfaceState> = Vec::new();
+        let mut table: *mut MIB_IF_TABLE2 = std::ptr::null_mut();
+        unsafe {
+            let ret = GetIfTable2(&mut table);
+            if ret.is_err() {
+                return Err(io::Error::from_raw_os_error(ret.0 as _));
+            }
+            let rows =
+                std::slice::from_raw_parts((*table).Table.as_ptr(), (*table).NumEntries as _);
+            for row in rows {
+                // Filter drivers are listed as interfaces too.
+                if row.InterfaceAndOperStatusFlags._bitfield & FILTER_INTERFACE != 0 {
+                    continue;
+                }
+                let len = row
+                    .Alias
+                    .iter()
+                    .position(|c| *c == 0)
+                    .unwrap_or(row.Alias.len());
+                let Ok(name) = String::from_utf16(&row.Alias[..len]) else {
+                    continue;
+                };
+                if name.is_empty() || interfaces.iter().any(|interface| interface.name == name) {
+                    continue;
+                }
+                interfaces.push(InterfaceState {
+                    name,
+                    is_up: row.OperStatus == IfOperStatusUp,
+                });
+            }
+            FreeMibTable(table as _);
+        }
+        Ok(interfaces)
+    }
+}
+
+impl Drop for NetworkWatcherInner {
+    fn drop(&mut self) {
+        // It waits for the running callbacks to return, so `changed` can be dropped afterwards.
+        let _ = unsafe { CancelMibChangeNotify2(self.handle) };
+    }
+}
@@ -1,21 +1,24 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
-use crate::sys::system::is_proc_running;
+use crate::sys::system::{get_reg_value_u64, is_proc_running};
 use crate::sys::utils::HandleWrapper;
 use crate::windows::Sid;
-use crate::{DiskUsage, Gid, Pid, ProcessRefreshKind, ProcessStatus, Signal, Uid};
+use crate::{
+    ConnectionCount, CurrentProcessUsage, DiskUsage, EnergyImpact, ExeMetadata, ExeSignature, Gid,
+    MediaUsage, Pid, ProcessRefreshKind, ProcessStatus, Signal, Uid,
+};
 
 use std::ffi::{OsStr, OsString};
 use std::fmt;
 #[cfg(feature = "debug")]
 use std::io;
 use std::mem::{MaybeUninit, size_of, zeroed};
+use std::net::{Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV6};
 use std::os::windows::ffi::OsStringExt;
 use std::os::windows::process::{CommandExt, ExitStatusExt};
 use std::path::{Path, PathBuf};
 use std::process::{self, ExitStatus};
 use std::ptr::null_mut;
-use std::str::{self, FromStr};
 use std::sync::{Arc, OnceLock};
 use std::time::Instant;
 
@@ -28,25 +31,36 @@ use windows::Wdk::System::Threading::{
     ProcessCommandLineInformation, ProcessWow64Information,
 };
 use windows::Win32::Foundation::{
-    ERROR_INSUFFICIENT_BUFFER, FILETIME, HANDLE, HLOCAL, HMODULE, LocalFree, MAX_PATH,
+    ERROR_INSUFFICIENT_BUFFER, FILETIME, HANDLE, HLOCAL, HMODULE, LocalFree, MAX_PATH, NO_ERROR,
     STATUS_BUFFER_OVERFLOW, STATUS_BUFFER_TOO_SMALL, STATUS_INFO_LENGTH_MISMATCH, UNICODE_STRING,
 };
+use windows::Win32::NetworkManagement::IpHelper::{
+    GetExtendedTcpTable, GetExtendedUdpTable, MIB_TCP_STATE_LISTEN, MIB_TCP6ROW_OWNER_PID,
+    MIB_TCP6TABLE_OWNER_PID, MIB_TCPROW_OWNER_PID, MIB_TCPTABLE_OWNER_PID, MIB_UDP6ROW_OWNER_PID,
+    MIB_UDPROW_OWNER_PID, TCP_TABLE_OWNER_PID_ALL, TCP_TABLE_OWNER_PID_LISTENER,
+    UDP_TABLE_OWNER_PID,
+};
+use windows::Win32::Networking::WinSock::{ADDRESS_FAMILY, AF_INET, AF_INET6};
 use windows::Win32::Security::{GetTokenInformation, TOKEN_QUERY, TOKEN_USER, TokenUser};
 use windows::Win32::System::Diagnostics::Debug::ReadProcessMemory;
-use windows::Win32::System::Diagnostics::ToolHelp::PROCESSENTRY32W;
 use windows::Win32::System::Memory::{
     GetProcessHeap, HEAP_ZERO_MEMORY, HeapAlloc, HeapFree, MEMORY_BASIC_INFORMATION, VirtualQueryEx,
 };
 use windows::Win32::System::ProcessStatus::{
     GetModuleFileNameExW, GetProcessMemoryInfo, PROCESS_MEMORY_COUNTERS_EX,
 };
+use windows::Win32::System::Registry::HKEY_CURRENT_USER;
 use windows::Win32::System::RemoteDesktop::ProcessIdToSessionId;
-use windows::Win32::System::SystemInformation::OSVERSIONINFOEXW;
+use windows::Win32::System::SystemInformation::{
+    IMAGE_FILE_MACHINE, IMAGE_FILE_MACHINE_AMD64, IMAGE_FILE_MACHINE_ARM, IMAGE_FILE_MACHINE_ARM64,
+    IMAGE_FILE_MACHINE_ARMNT, IMAGE_FILE_MACHINE_I386, IMAGE_FILE_MACHINE_IA64,
+    IMAGE_FILE_MACHINE_THUMB, IMAGE_FILE_MACHINE_UNKNOWN, OSVERSIONINFOEXW,
+};
 use windows::Win32::System::Threading::{
-    CREATE_NO_WINDOW, GetExitCodeProcess, GetProcessHandleCount, GetProcessIoCounters,
-    GetProcessTimes, GetSystemTimes, IO_COUNTERS, OpenProcess, OpenProcessToken, PEB,
-    PROCESS_BASIC_INFORMATION, PROCESS_QUERY_INFORMATION, PROCESS_QUERY_LIMITED_INFORMATION,
-    PROCESS_VM_READ,
+    CREATE_NO_WINDOW, GetCurrentProcess, GetExitCodeProcess, GetProcessHandleCount,
+    GetProcessIoCounters, GetProcessTimes, GetSystemTimes, IO_COUNTERS, IsWow64Process2,
+    OpenProcess, OpenProcessToken, PEB, PROCESS_BASIC_INFORMATION, PROCESS_QUERY_INFORMATION,
+    PROCESS_QUERY_LIMITED_INFORMATION, PROCESS_VM_READ,
 };
 use windows::Win32::UI::Shell::CommandLineToArgvW;
 use windows::core::PCWSTR;
@@ -74,7 +88,7 @@ fn get_process_handler(pid: Pid) -> Option<HandleWrapper> {
         .or_else(|| {
             sysinfo_debug!(
                 "OpenProcess failed, error: {:?}",
-                io::Error::last_os_error()
+                std::io::Error::last_os_error()
             );
             HandleWrapper::new(unsafe {
                 OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, false, pid.0 as u32)
@@ -84,7 +98,7 @@ fn get_process_handler(pid: Pid) -> Option<HandleWrapper> {
         .or_else(|| {
             sysinfo_debug!(
                 "OpenProcess limited failed, error: {:?}",
-                io::Error::last_os_error()
+                std::io::Error::last_os_error()
             );
             None
         })
@@ -164,7 +178,7 @@ unsafe fn get_process_user_id(process: &mut ProcessInner, refresh_kind: ProcessR
         {
             sysinfo_debug!(
                 "GetTokenInformation failed (returned {_err:?}), error: {:?}",
-                io::Error::last_os_error()
+                std::io::Error::last_os_error()
             );
             return;
         }
@@ -192,6 +206,7 @@ pub(crate) struct ProcessInner {
     pub(crate) memory: u64,
     pub(crate) virtual_memory: u64,
     pub(crate) parent: Option<Pid>,
+    pub(crate) thread_count: Option<usize>,
     status: ProcessStatus,
     handle: Option<Arc<HandleWrapper>>,
     cpu_calc_values: CPUsageCalculationValues,
@@ -204,6 +219,9 @@ pub(crate) struct ProcessInner {
     read_bytes: u64,
     written_bytes: u64,
     accumulated_cpu_time: u64,
+    exe_signature: OnceLock<Option<ExeSignature>>,
+    exe_metadata: OnceLock<Option<ExeMetadata>>,
+    media_usage: Option<MediaUsage>,
     exists: bool,
 }
 
@@ -215,6 +233,21 @@ struct CPUsageCalculationValues {
     last_update: Instant,
 }
 
+/// Information returned for all processes at once by `NtQuerySystemInformation`, used instead
+/// of querying each process through its handle.
+pub(crate) struct ProcessStats {
+    /// In 100 nanosecond units since 1601-01-01, like a `FILETIME`.
+    pub(crate) create_time: u64,
+    /// In 100 nanosecond units.
+    pub(crate) kernel_time: u64,
+    /// In 100 nanosecond units.
+    pub(crate) user_time: u64,
+    pub(crate) memory: u64,
+    pub(crate) virtual_memory: u64,
+    pub(crate) read_bytes: u64,
+    pub(crate) written_bytes: u64,
+}
+
 impl CPUsageCalculationValues {
     fn new() -> Self {
         CPUsageCalculationValues {
@@ -257,18 +290,29 @@ unsafe fn get_exe(process_handler: &HandleWrapper) -> Option<PathBuf> {
 }
 
 impl ProcessInner {
-    pub(crate) fn new(pid: Pid, parent: Option<Pid>, now: u64, name: OsString) -> Self {
-        let (handle, start_time, run_time) = if let Some(handle) = get_process_handler(pid) {
-            let (start_time, run_time) = get_start_and_run_time(*handle, now);
-            (Some(Arc::new(handle)), start_time, run_time)
-        } else {
-            (None, 0, 0)
+    pub(crate) fn new(
+        pid: Pid,
+        parent: Option<Pid>,
+        now: u64,
+        name: OsString,
+        stats: Option<&ProcessStats>,
+    ) -> Self {
+        let handle = get_process_handler(pid);
+        let (start_time, run_time) = match (stats, &handle) {
+            (Some(stats), _) => {
+                let start_time = compute_start(stats.create_time);
+                (start_time, check_sub(now, start_time))
+            }
+            (None, Some(handle)) => get_start_and_run_time(**handle, now),
+            (None, None) => (0, 0),
         };
+        let handle = handle.map(Arc::new);
         Self {
             handle,
             name,
             pid,
             parent,
+            thread_count: None,
             user_id: None,
             cmd: Vec::new(),
             environ: Vec::new(),
@@ -288,6 +332,9 @@ impl ProcessInner {
             read_bytes: 0,
             written_bytes: 0,
             accumulated_cpu_time: 0,
+            exe_signature: OnceLock::new(),
+            exe_metadata: OnceLock::new(),
+            media_usage: None,
             exists: true,
         }
     }
@@ -298,16 +345,20 @@ impl ProcessInner {
         nb_cpus: u64,
         now: u64,
         refresh_parent: bool,
+        stats: Option<&ProcessStats>,
     ) {
         if refresh_kind.cpu() {
-            compute_cpu_usage(self, nb_cpus);
+            compute_cpu_usage(self, nb_cpus, stats);
         }
         if refresh_kind.disk_usage() {
-            update_disk_usage(self);
+            update_disk_usage(self, stats);
         }
         if refresh_kind.memory() {
             let mut mem_info = PROCESS_MEMORY_COUNTERS_EX::default();
-            if let Some(handle) = self.get_handle() {
+            if let Some(stats) = stats {
+                self.memory = stats.memory;
+                self.virtual_memory = stats.virtual_memory;
+            } else if let Some(handle) = self.get_handle() {
                 if let Err(_error) = unsafe {
                     GetProcessMemoryInfo(
                         handle,
@@ -327,37 +378,26 @@ impl ProcessInner {
             get_process_params(self, refresh_kind, refresh_parent);
         }
         if refresh_kind.exe().needs_update(|| self.exe.is_none()) {
-            unsafe {
-                self.exe = match self.handle.as_ref() {
+            let exe = unsafe {
+                match self.handle.as_ref() {
                     Some(handle) => get_exe(handle),
                     None => get_executable_path(self.pid),
-                };
+                }
+            };
+            if exe != self.exe {
+                // The executable changed, so what was computed from `exe` is outdated.
+                self.exe_signature = OnceLock::new();
+                self.exe_metadata = OnceLock::new();
+                self.exe = exe;
             }
         }
+        if refresh_kind.media_usage() {
+            self.media_usage = self.exe.as_deref().map(get_media_usage);
+        }
         self.run_time = now.saturating_sub(self.start_time());
         self.updated = true;
     }
 
-    pub(crate) fn from_process_entry(entry: &PROCESSENTRY32W, now: u64) -> Self {
-        let pid = Pid::from_u32(entry.th32ProcessID);
-        let name = match OsString::from_str(
-            String::from_utf16_lossy(&entry.szExeFile).trim_end_matches('\0'),
-        ) {
-            Ok(name) => name,
-            Err(_) => format!("<no name> Process {pid}").into(),
-        };
-        let ppid = {
-            if entry.th32ParentProcessID == 0 {
-                // no parent pid
-                None
-            } else {
-                Some(Pid::from_u32(entry.th32ParentProcessID))
-            }
-        };
-
-        Self::new(pid, ppid, now, name)
-    }
-
     pub(crate) fn get_handle(&self) -> Option<HANDLE> {
         self.handle.as_ref().map(|h| ***h)
     }
@@ -413,6 +453,22 @@ impl ProcessInner {
         self.parent
     }
 
+    pub(crate) fn thread_count(&self) -> Option<usize> {
+        self.thread_count
+    }
+
+    pub(crate) fn tty(&self) -> Option<String> {
+        None
+    }
+
+    pub(crate) fn reparented_to_init(&self) -> bool {
+        false
+    }
+
+    pub(crate) fn exec_count(&self) -> u64 {
+        0
+    }
+
     pub(crate) fn status(&self) -> ProcessStatus {
         self.status
     }
@@ -421,6 +477,10 @@ impl ProcessInner {
         self.start_time
     }
 
+    pub(crate) fn start_time_raw(&self) -> u64 {
+        self.start_time
+    }
+
     pub(crate) fn run_time(&self) -> u64 {
         self.run_time
     }
@@ -433,6 +493,10 @@ impl ProcessInner {
         self.accumulated_cpu_time
     }
 
+    pub(crate) fn children_cpu_time(&self) -> u64 {
+        0
+    }
+
     pub(crate) fn disk_usage(&self) -> DiskUsage {
         DiskUsage {
             written_bytes: self.written_bytes.saturating_sub(self.old_written_bytes),
@@ -493,12 +557,50 @@ impl ProcessInner {
             }
             sysinfo_debug!(
                 "ProcessIdToSessionId failed, error: {:?}",
-                io::Error::last_os_error()
+                std::io::Error::last_os_error()
             );
             None
         }
     }
 
+    pub(crate) fn process_group_id(&self) -> Option<Pid> {
+        None
+    }
+
+    pub(crate) fn current_usage() -> Option<CurrentProcessUsage> {
+        unsafe {
+            let handle = GetCurrentProcess();
+            let mut mem_info = PROCESS_MEMORY_COUNTERS_EX::default();
+            if let Err(_error) = GetProcessMemoryInfo(
+                handle,
+                &mut mem_info as *mut _ as *mut _,
+                std::mem::size_of_val::<PROCESS_MEMORY_COUNTERS_EX>(&mem_info) as _,
+            ) {
+                sysinfo_debug!("GetProcessMemoryInfo failed: {_error:?}");
+                return None;
+            }
+            let mut ftime: FILETIME = zeroed();
+            let mut fsys: FILETIME = zeroed();
+            let mut fuser: FILETIME = zeroed();
+            if let Err(_error) =
+                GetProcessTimes(handle, &mut ftime, &mut ftime, &mut fsys, &mut fuser)
+            {
+                sysinfo_debug!("GetProcessTimes failed: {_error:?}");
+                return None;
+            }
+            Some(CurrentProcessUsage {
+                memory: mem_info.WorkingSetSize as _,
+                virtual_memory: mem_info.PrivateUsage as _,
+                accumulated_cpu_time: filetime_to_u64(fuser).saturating_add(filetime_to_u64(fsys))
+                    / FILETIMES_PER_MILLISECONDS,
+            })
+        }
+    }
+
+    pub(crate) fn children_usage() -> Option<crate::ChildrenUsage> {
+        None
+    }
+
     pub(crate) fn switch_updated(&mut self) -> bool {
         std::mem::replace(&mut self.updated, false)
     }
@@ -527,9 +629,130 @@ impl ProcessInner {
         }
     }
 
+    pub(crate) fn path_usage(&self, path: &Path) -> crate::PathUsage {
+        crate::PathUsage::from_process_paths(path, self.cwd(), self.root(), self.exe())
+    }
+
+    pub(crate) fn deleted_mappings(&self) -> Vec<PathBuf> {
+        Vec::new()
+    }
+
     pub(crate) fn open_files_limit(&self) -> Option<usize> {
         crate::System::open_files_limit()
     }
+
+    pub(crate) fn stack_size(&self) -> Option<u64> {
+        None
+    }
+
+    pub(crate) fn heap_size(&self) -> Option<u64> {
+        None
+    }
+
+    pub(crate) fn cgroup(&self) -> Option<String> {
+        None
+    }
+
+    pub(crate) fn container_id(&self) -> Option<String> {
+        None
+    }
+
+    pub(crate) fn sched_stats(&self) -> Option<crate::SchedStats> {
+        None
+    }
+
+    pub(crate) fn io_counters(&self) -> Option<crate::IoCounters> {
+        None
+    }
+
+    pub(crate) fn wait_channel(&self) -> Option<String> {
+        None
+    }
+
+    pub(crate) fn kernel_stack(&self) -> Result<Vec<String>, crate::RefreshError> {
+        Err(crate::RefreshError::Unsupported)
+    }
+
+    pub(crate) fn connection_count(&self) -> Option<ConnectionCount> {
+        get_connection_count(self.pid.0 as _)
+    }
+
+    /// Returns the machine of the process and the native machine.
+    fn wow64_machines(&self) -> Option<(IMAGE_FILE_MACHINE, IMAGE_FILE_MACHINE)> {
+        let handle = self.get_handle()?;
+        let mut process_machine = IMAGE_FILE_MACHINE_UNKNOWN;
+        let mut native_machine = IMAGE_FILE_MACHINE_UNKNOWN;
+        unsafe { IsWow64Process2(handle, &mut process_machine, Some(&mut native_machine)).ok()? };
+        // `IMAGE_FILE_MACHINE_UNKNOWN` means that the process isn't running under WOW64.
+        if process_machine == IMAGE_FILE_MACHINE_UNKNOWN {
+            process_machine = native_machine;
+        }
+        Some((process_machine, native_machine))
+    }
+
+    pub(crate) fn is_64_bit(&self) -> Option<bool> {
+        let (machine, _) = self.wow64_machines()?;
+        Some(matches!(
+            machine,
+            IMAGE_FILE_MACHINE_AMD64 | IMAGE_FILE_MACHINE_ARM64 | IMAGE_FILE_MACHINE_IA64
+        ))
+    }
+
+    pub(crate) fn abi(&self) -> Option<&'static str> {
+        let (machine, _) = self.wow64_machines()?;
+        match machine {
+            IMAGE_FILE_MACHINE_I386 => Some("x86"),
+            IMAGE_FILE_MACHINE_AMD64 => Some("x86_64"),
+            IMAGE_FILE_MACHINE_ARM | IMAGE_FILE_MACHINE_ARMNT | IMAGE_FILE_MACHINE_THUMB => {
+                Some("arm")
+            }
+            IMAGE_FILE_MACHINE_ARM64 => Some("arm64"),
+            IMAGE_FILE_MACHINE_IA64 => Some("ia64"),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn listening_ports(&self) -> Option<Vec<SocketAddr>> {
+        get_listening_ports(self.pid.0 as _)
+    }
+
+    pub(crate) fn exe_signature(&self) -> Option<&ExeSignature> {
+        let exe = self.exe.as_deref()?;
+        self.exe_signature
+            .get_or_init(|| {
+                get_authenticode_signer(exe)
+                    .map(ExeSignature::Authenticode)
+                    .or_else(|| crate::sha256::sha256_file(exe).map(ExeSignature::Sha256))
+            })
+            .as_ref()
+    }
+
+    pub(crate) fn exe_metadata(&self) -> Option<&ExeMetadata> {
+        let exe = self.exe.as_deref()?;
+        self.exe_metadata
+            .get_or_init(|| crate::exe_metadata::read_exe_metadata(exe))
+            .as_ref()
+    }
+
+    pub(crate) fn bundle_identifier(&self) -> Option<&str> {
+        None
+    }
+
+    pub(crate) fn bundle_name(&self) -> Option<&str> {
+        None
+    }
+
+    pub(crate) fn energy_impact(&self) -> Option<EnergyImpact> {
+        None
+    }
+
+    pub(crate) fn gpu_usage(&self) -> Option<f32> {
+        None
+    }
+
+    pub(crate) fn media_usage(&self) -> Option<MediaUsage> {
+        self.media_usage
+    }
 }
 
 #[inline]
@@ -571,7 +794,7 @@ fn update_root(refresh_kind: ProcessRefreshKind, cwd: &Path, root: &mut Option<P
 fn compute_start(process_times: u64) -> u64 {
     // 11_644_473_600 is the number of seconds between the Windows epoch (1601-01-01) and
     // the Linux epoch (1970-01-01).
-    process_times / 10_000_000 - 11_644_473_600
+    (process_times / 10_000_000).saturating_sub(11_644_473_600)
 }
 
 fn get_start_and_run_time(handle: HANDLE, now: u64) -> (u64, u64) {
@@ -1039,7 +1262,7 @@ fn check_sub(a: u64, b: u64) -> u64 {
 
 /// Before changing this function, you must consider the following:
 /// <https://github.com/GuillaumeGomez/sysinfo/issues/459>
-pub(crate) fn compute_cpu_usage(p: &mut ProcessInner, nb_cpus: u64) {
+pub(crate) fn compute_cpu_usage(p: &mut ProcessInner, nb_cpus: u64, stats: Option<&ProcessStats>) {
     let need_update = p.cpu_calc_values.last_update.elapsed() >= MINIMUM_CPU_UPDATE_INTERVAL;
 
     unsafe {
@@ -1050,7 +1273,9 @@ pub(crate) fn compute_cpu_usage(p: &mut ProcessInner, nb_cpus: u64) {
         let mut fglobal_kernel_time: FILETIME = zeroed(); // notice that it includes idle time
         let mut fglobal_user_time: FILETIME = zeroed();
 
-        if let Some(handle) = p.get_handle() {
+        if stats.is_none()
+            && let Some(handle) = p.get_handle()
+        {
             let _err = GetProcessTimes(handle, &mut ftime, &mut ftime, &mut fsys, &mut fuser);
         }
 
@@ -1064,8 +1289,10 @@ pub(crate) fn compute_cpu_usage(p: &mut ProcessInner, nb_cpus: u64) {
 
         p.cpu_calc_values.last_update = Instant::now();
 
-        let sys = filetime_to_u64(fsys);
-        let user = filetime_to_u64(fuser);
+        let (sys, user) = match stats {
+            Some(stats) => (stats.kernel_time, stats.user_time),
+            None => (filetime_to_u64(fsys), filetime_to_u64(fuser)),
+        };
         let global_kernel_time = filetime_to_u64(fglobal_kernel_time);
         let global_user_time = filetime_to_u64(fglobal_user_time);
 
@@ -1098,10 +1325,15 @@ pub(crate) fn compute_cpu_usage(p: &mut ProcessInner, nb_cpus: u64) {
     }
 }
 
-pub(crate) fn update_disk_usage(p: &mut ProcessInner) {
+pub(crate) fn update_disk_usage(p: &mut ProcessInner, stats: Option<&ProcessStats>) {
     let mut counters = MaybeUninit::<IO_COUNTERS>::uninit();
 
-    if let Some(handle) = p.get_handle() {
+    if let Some(stats) = stats {
+        p.old_read_bytes = p.read_bytes;
+        p.old_written_bytes = p.written_bytes;
+        p.read_bytes = stats.read_bytes;
+        p.written_bytes = stats.written_bytes;
+    } else if let Some(handle) = p.get_handle() {
         unsafe {
             if GetProcessIoCounters(handle, counters.as_mut_ptr()).is_err() {
                 sysinfo_debug!("GetProcessIoCounters call failed on process {}", p.pid());
@@ -1120,3 +1352,279 @@ pub(crate) fn update_disk_usage(p: &mut ProcessInner) {
 const fn filetime_to_u64(ft: FILETIME) -> u64 {
     ((ft.dwHighDateTime as u64) << 32) | (ft.dwLowDateTime as u64)
 }
+
+/// Returns the name of the signer of the Authenticode signature of `exe` if the signature is
+/// valid.
+pub(crate) fn get_authenticode_signer(exe: &Path) -> Option<String> {
+    use std::os::windows::ffi::OsStrExt;
+    use windows::Win32::Foundation::HWND;
+    use windows::Win32::Security::Cryptography::{
+        CERT_CONTEXT, CERT_FIND_SUBJECT_CERT, CERT_INFO, CERT_NAME_SIMPLE_DISPLAY_TYPE,
+        CERT_QUERY_CONTENT_FLAG_PKCS7_SIGNED_EMBED, CERT_QUERY_ENCODING_TYPE,
+        CERT_QUERY_FORMAT_FLAG_BINARY, CERT_QUERY_OBJECT_FILE, CMSG_SIGNER_INFO,
+        CMSG_SIGNER_INFO_PARAM, CertCloseStore, CertFindCertificateInStore,
+        CertFreeCertificateContext, CertGetNameStringW, CryptMsgClose, CryptMsgGetParam,
+        CryptQueryObject, HCERTSTORE, PKCS_7_ASN_ENCODING, X509_ASN_ENCODING,
+    };
+    use windows::Win32::Security::WinTrust::{
+        WINTRUST_ACTION_GENERIC_VERIFY_V2, WINTRUST_DATA, WINTRUST_DATA_0, WINTRUST_FILE_INFO,
+        WTD_CHOICE_FILE, WTD_REVOKE_NONE, WTD_STATEACTION_CLOSE, WTD_STATEACTION_VERIFY,
+        WTD_UI_NONE, WinVerifyTrust,
+    };
+
+    let wide_path: Vec<u16> = exe.as_os_str().encode_wide().chain(Some(0)).collect();
+
+    unsafe {
+        // First we check that the signature is valid.
+        let mut file_info = WINTRUST_FILE_INFO {
+            cbStruct: size_of::<WINTRUST_FILE_INFO>() as _,
+            pcwszFilePath: PCWSTR::from_raw(wide_path.as_ptr()),
+            ..Default::default()
+        };
+        let mut trust_data = WINTRUST_DATA {
+            cbStruct: size_of::<WINTRUST_DATA>() as _,
+            dwUIChoice: WTD_UI_NONE,
+            fdwRevocationChecks: WTD_REVOKE_NONE,
+            dwUnionChoice: WTD_CHOICE_FILE,
+            Anonymous: WINTRUST_DATA_0 {
+                pFile: &mut file_info,
+            },
+            dwStateAction: WTD_STATEACTION_VERIFY,
+            ..Default::default()
+        };
+        let mut action = WINTRUST_ACTION_GENERIC_VERIFY_V2;
+        let status = WinVerifyTrust(
+            HWND::default(),
+            &mut action,
+            &mut trust_data as *mut _ as *mut c_void,
+        );
+        trust_data.dwStateAction = WTD_STATEACTION_CLOSE;
+        WinVerifyTrust(
+            HWND::default(),
+            &mut action,
+            &mut trust_data as *mut _ as *mut c_void,
+        );
+        if status != 0 {
+            sysinfo_debug!("no valid Authenticode signature for {exe:?}: {status:#x}");
+            return None;
+        }
+
+        // Then we retrieve the signer certificate to get its name.
+        let mut store = HCERTSTORE::default();
+        let mut msg: *mut c_void = null_mut();
+        if CryptQueryObject(
+            CERT_QUERY_OBJECT_FILE,
+            wide_path.as_ptr() as *const c_void,
+            CERT_QUERY_CONTENT_FLAG_PKCS7_SIGNED_EMBED,
+            CERT_QUERY_FORMAT_FLAG_BINARY,
+            0,
+            None,
+            None,
+            None,
+            Some(&mut store),
+            Some(&mut msg),
+            None,
+        )
+        .is_err()
+        {
+            sysinfo_debug!("CryptQueryObject failed for {exe:?}");
+            return None;
+        }
+
+        let mut signer = None;
+        let mut size = 0;
+        if CryptMsgGetParam(msg, CMSG_SIGNER_INFO_PARAM, 0, None, &mut size).is_ok() {
+            // `u64` to get a buffer correctly aligned for `CMSG_SIGNER_INFO`.
+            let mut buffer = vec![0u64; (size as usize).div_ceil(size_of::<u64>())];
+            if CryptMsgGetParam(
+                msg,
+                CMSG_SIGNER_INFO_PARAM,
+                0,
+                Some(buffer.as_mut_ptr().cast()),
+                &mut size,
+            )
+            .is_ok()
+            {
+                let signer_info = &*(buffer.as_ptr() as *const CMSG_SIGNER_INFO);
+                let cert_info = CERT_INFO {
+                    Issuer: signer_info.Issuer,
+                    SerialNumber: signer_info.SerialNumber,
+                    ..Default::default()
+                };
+                let cert: *const CERT_CONTEXT = CertFindCertificateInStore(
+                    store,
+                    CERT_QUERY_ENCODING_TYPE(X509_ASN_ENCODING.0 | PKCS_7_ASN_ENCODING.0),
+                    0,
+                    CERT_FIND_SUBJECT_CERT,
+                    Some(&cert_info as *const CERT_INFO as *const c_void),
+                    None,
+                );
+                if !cert.is_null() {
+                    let mut name = [0u16; 256];
+                    let len = CertGetNameStringW(
+                        cert,
+                        CERT_NAME_SIMPLE_DISPLAY_TYPE,
+                        0,
+                        None,
+                        Some(&mut name),
+                    );
+                    if len > 1 {
+                        signer = Some(String::from_utf16_lossy(&name[..len as usize - 1]));
+                    }
+                    let _ = CertFreeCertificateContext(Some(cert));
+                }
+            }
+        }
+        let _ = CryptMsgClose(Some(msg));
+        let _ = CertCloseStore(Some(store), 0);
+        signer
+    }
+}
+
+/// Uses the capabilities usage tracked by Windows for its privacy settings. For each capability,
+/// there is a key per executable (where `\` is replaced with `#`) containing the last time it
+/// started and stopped using it. If it didn't stop yet, then it's still using it.
+fn get_media_usage(exe: &Path) -> MediaUsage {
+    let exe = exe.to_string_lossy().replace('\\', "#");
+    let is_used = |capability: &str| {
+        let path = format!(
+            "Software\\Microsoft\\Windows\\CurrentVersion\\CapabilityAccessManager\\ConsentStore\\\
+             {capability}\\NonPackaged\\{exe}"
+        );
+        let start = get_reg_value_u64(HKEY_CURRENT_USER, &path, "LastUsedTimeStart");
+        let stop = get_reg_value_u64(HKEY_CURRENT_USER, &path, "LastUsedTimeStop");
+        Some(matches!((start, stop), (Some(start), Some(0)) if start != 0))
+    };
+    MediaUsage {
+        audio_capture: is_used("microphone"),
+        camera: is_used("webcam"),
+        screen_recording: is_used("graphicsCaptureProgrammatic"),
+    }
+}
+
+/// Returns the content of the table filled by `get_table` (a call to `GetExtendedTcpTable` or
+/// `GetExtendedUdpTable`). The returned buffer uses `u32` to ensure the table is correctly
+/// aligned.
+unsafe fn get_socket_table(
+    get_table: impl Fn(Option<*mut std::ffi::c_void>, &mut u32) -> u32,
+) -> Option<Vec<u32>> {
+    let mut size = 0;
+    let mut buffer = Vec::new();
+    // The table can grow between two calls so we try a few times.
+    for _ in 0..3 {
+        let ret = get_table(Some(buffer.as_mut_ptr() as *mut _), &mut size);
+        if ret == NO_ERROR.0 {
+            return Some(buffer);
+        } else if ret != ERROR_INSUFFICIENT_BUFFER.0 {
+            sysinfo_debug!("GetExtendedTcpTable/GetExtendedUdpTable failed: {ret}");
+            return None;
+        }
+        buffer = vec![0; (size as usize).div_ceil(size_of::<u32>())];
+    }
+    None
+}
+
+/// Returns the content of the table of the TCP listening sockets for `family`.
+unsafe fn get_tcp_listener_table(family: ADDRESS_FAMILY) -> Option<Vec<u32>> {
+    unsafe {
+        get_socket_table(|table, size| {
+            GetExtendedTcpTable(
+                table,
+                size,
+                false,
+                family.0 as _,
+                TCP_TABLE_OWNER_PID_LISTENER,
+                0,
+            )
+        })
+    }
+}
+
+/// Returns the rows of a `MIB_*TABLE_OWNER_PID` table returned by [`get_socket_table`]. These
+/// tables all start with the number of rows, followed by the rows.
+unsafe fn socket_table_rows<Row>(buffer: &[u32]) -> &[Row] {
+    let Some((len, rows)) = buffer.split_first() else {
+        return &[];
+    };
+    // SAFETY: the rows are made of `u32` and `u8` fields, so they are aligned like `u32`.
+    unsafe { std::slice::from_raw_parts(rows.as_ptr() as *const Row, *len as usize) }
+}
+
+fn get_connection_count(pid: u32) -> Option<ConnectionCount> {
+    let get_tcp_table = |family: ADDRESS_FAMILY| unsafe {
+        get_socket_table(|table, size| {
+            GetExtendedTcpTable(
+                table,
+                size,
+                false,
+                family.0 as _,
+                TCP_TABLE_OWNER_PID_ALL,
+                0,
+            )
+        })
+    };
+    let get_udp_table = |family: ADDRESS_FAMILY| unsafe {
+        get_socket_table(|table, size| {
+            GetExtendedUdpTable(table, size, false, family.0 as _, UDP_TABLE_OWNER_PID, 0)
+        })
+    };
+    let (tcp, tcp6) = (get_tcp_table(AF_INET)?, get_tcp_table(AF_INET6)?);
+    let (udp, udp6) = (get_udp_table(AF_INET)?, get_udp_table(AF_INET6)?);
+    unsafe {
+        Some(ConnectionCount {
+            tcp: socket_table_rows::<MIB_TCPROW_OWNER_PID>(&tcp)
+                .iter()
+                .filter(|row| row.dwOwningPid == pid)
+                .count()
+                + socket_table_rows::<MIB_TCP6ROW_OWNER_PID>(&tcp6)
+                    .iter()
+                    .filter(|row| row.dwOwningPid == pid)
+                    .count(),
+            udp: socket_table_rows::<MIB_UDPROW_OWNER_PID>(&udp)
+                .iter()
+                .filter(|row| row.dwOwningPid == pid)
+                .count()
+                + socket_table_rows::<MIB_UDP6ROW_OWNER_PID>(&udp6)
+                    .iter()
+                    .filter(|row| row.dwOwningPid == pid)
+                    .count(),
+        })
+    }
+}
+
+fn get_listening_ports(pid: u32) -> Option<Vec<SocketAddr>> {
+    let mut ports = Vec::new();
+    unsafe {
+        let buffer = get_tcp_listener_table(AF_INET)?;
+        let table = &*(buffer.as_ptr() as *const MIB_TCPTABLE_OWNER_PID);
+        let rows: &[MIB_TCPROW_OWNER_PID] =
+            std::slice::from_raw_parts(table.table.as_ptr(), table.dwNumEntries as _);
+        for row in rows {
+            if row.dwOwningPid == pid && row.dwState == MIB_TCP_STATE_LISTEN.0 as u32 {
+                // The address and the port are in network byte order.
+                ports.push(SocketAddr::new(
+                    Ipv4Addr::from(row.dwLocalAddr.to_ne_bytes()).into(),
+                    u16::from_be(row.dwLocalPort as u16),
+                ));
+            }
+        }
+
+        let buffer = get_tcp_listener_table(AF_INET6)?;
+        let table = &*(buffer.as_ptr() as *const MIB_TCP6TABLE_OWNER_PID);
+        let rows: &[MIB_TCP6ROW_OWNER_PID] =
+            std::slice::from_raw_parts(table.table.as_ptr(), table.dwNumEntries as _);
+        for row in rows {
+            if row.dwOwningPid == pid && row.dwState == MIB_TCP_STATE_LISTEN.0 as u32 {
+                ports.push(SocketAddr::V6(SocketAddrV6::new(
+                    Ipv6Addr::from(row.ucLocalAddr),
+                    u16::from_be(row.dwLocalPort as u16),
+                    0,
+                    row.dwLocalScopeId,
+                )));
+            }
+        }
+    }
+    ports.sort_unstable();
+    ports.dedup();
+    Some(ports)
+}
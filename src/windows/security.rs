@@ -0,0 +1,93 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::FirewallProfile;
+use crate::sys::system::get_reg_value_u32;
+use crate::sys::wmi::{WmiServices, next_u32};
+
+use std::mem;
+
+use windows::Win32::Foundation::TBS_E_TPM_NOT_FOUND;
+use windows::Win32::System::Registry::HKEY_LOCAL_MACHINE;
+use windows::Win32::System::TpmBaseServices::{TBS_SUCCESS, TPM_DEVICE_INFO, Tbsi_GetDeviceInfo};
+use windows::core::w;
+
+/// Name of the Windows Firewall profiles and of their registry keys, in the local configuration
+/// and in the group policies.
+const FIREWALL_PROFILES: &[(&str, &str, &str)] = &[
+    ("Domain", "DomainProfile", "DomainProfile"),
+    ("Private", "StandardProfile", "PrivateProfile"),
+    ("Public", "PublicProfile", "PublicProfile"),
+];
+
+pub(crate) struct SecurityInfoInner;
+
+impl SecurityInfoInner {
+    pub(crate) fn secure_boot_enabled() -> Option<bool> {
+        get_reg_value_u32(
+            HKEY_LOCAL_MACHINE,
+            "SYSTEM\\CurrentControlSet\\Control\\SecureBoot\\State",
+            "UEFISecureBootEnabled",
+        )
+        .map(|value| u32::from_le_bytes(value) != 0)
+    }
+
+    pub(crate) fn tpm_present() -> Option<bool> {
+        let mut info = TPM_DEVICE_INFO::default();
+        let ret = unsafe {
+            Tbsi_GetDeviceInfo(
+                mem::size_of::<TPM_DEVICE_INFO>() as _,
+                (&mut info as *mut TPM_DEVICE_INFO).cast(),
+            )
+        };
+        if ret == TBS_SUCCESS {
+            Some(true)
+        } else if ret == TBS_E_TPM_NOT_FOUND.0 as u32 {
+            Some(false)
+        } else {
+            sysinfo_debug!("Tbsi_GetDeviceInfo failed: {ret:#x}");
+            None
+        }
+    }
+
+    pub(crate) fn root_disk_encrypted() -> Option<bool> {
+        let drive = std::env::var("SystemDrive").unwrap_or_else(|_| "C:".to_owned());
+        // Querying the BitLocker status requires administrator privileges, the connection fails
+        // otherwise.
+        let services = WmiServices::connect("root\\CIMV2\\Security\\MicrosoftVolumeEncryption")?;
+        let volumes = services.query(&format!(
+            "SELECT ProtectionStatus FROM Win32_EncryptableVolume WHERE DriveLetter = '{drive}'"
+        ))?;
+        // `2` means that the status is unknown (because the volume is locked).
+        match next_u32(&volumes, w!("ProtectionStatus"))? {
+            0 => Some(false),
+            1 => Some(true),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn firewall_status() -> Vec<FirewallProfile> {
+        let get_enabled = |path: String| {
+            get_reg_value_u32(HKEY_LOCAL_MACHINE, &path, "EnableFirewall")
+                .map(|value| u32::from_le_bytes(value) != 0)
+        };
+        FIREWALL_PROFILES
+            .iter()
+            .filter_map(|(name, local_key, policy_key)| {
+                // Group policies take precedence over the local configuration.
+                let enabled = get_enabled(format!(
+                    "SOFTWARE\\Policies\\Microsoft\\WindowsFirewall\\{policy_key}"
+                ))
+                .or_else(|| {
+                    get_enabled(format!(
+                        "SYSTEM\\CurrentControlSet\\Services\\SharedAccess\\Parameters\\\
+                         FirewallPolicy\\{local_key}"
+                    ))
+                })?;
+                Some(FirewallProfile {
+                    name: (*name).to_owned(),
+                    enabled,
+                })
+            })
+            .collect()
+    }
+}
@@ -1,33 +1,45 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
 use crate::{
-    Cpu, CpuRefreshKind, LoadAvg, MemoryRefreshKind, Pid, ProcessRefreshKind, ProcessesToUpdate,
+    Cpu, CpuRefreshKind, LoadAvg, MemoryRefreshKind, Pid, ProcessRefreshKind, ProcessStringStats,
+    ProcessesToUpdate, RefreshError,
 };
 
 use crate::sys::cpu::*;
+use crate::sys::process::ProcessStats;
 use crate::{Process, ProcessInner};
 
 use std::collections::HashMap;
-use std::ffi::OsStr;
+use std::ffi::{OsStr, OsString, c_void};
 use std::mem::{size_of, zeroed};
-use std::os::windows::ffi::OsStrExt;
-use std::time::{Duration, SystemTime};
-
-use windows::Win32::Foundation::{self, HANDLE, STILL_ACTIVE};
+use std::os::windows::ffi::{OsStrExt, OsStringExt};
+use std::str::FromStr;
+use std::time::{Duration, Instant, SystemTime};
+
+use ntapi::ntexapi::SYSTEM_PROCESS_INFORMATION;
+use windows::Wdk::System::SystemInformation::{NtQuerySystemInformation, SystemProcessInformation};
+use windows::Win32::Foundation::{self, HANDLE, STATUS_INFO_LENGTH_MISMATCH, STILL_ACTIVE};
+use windows::Win32::Globalization::GetUserDefaultLocaleName;
 use windows::Win32::System::Diagnostics::ToolHelp::{
     CreateToolhelp32Snapshot, PROCESSENTRY32W, Process32FirstW, Process32NextW, TH32CS_SNAPPROCESS,
 };
-use windows::Win32::System::ProcessStatus::{K32GetPerformanceInfo, PERFORMANCE_INFORMATION};
+use windows::Win32::System::ProcessStatus::{
+    ENUM_PAGE_FILE_INFORMATION, K32EnumPageFilesW, K32GetPerformanceInfo, PERFORMANCE_INFORMATION,
+};
 use windows::Win32::System::Registry::{
     HKEY, HKEY_LOCAL_MACHINE, KEY_READ, REG_NONE, RegCloseKey, RegOpenKeyExW, RegQueryValueExW,
 };
-use windows::Win32::System::SystemInformation::{self, GetSystemInfo};
+use windows::Win32::System::SystemInformation::{self, GetNativeSystemInfo, GetSystemInfo};
 use windows::Win32::System::SystemInformation::{
     ComputerNamePhysicalDnsHostname, GetComputerNameExW, GetTickCount64, GlobalMemoryStatusEx,
     MEMORYSTATUSEX, SYSTEM_INFO,
 };
-use windows::Win32::System::Threading::GetExitCodeProcess;
-use windows::core::{Owned, PCWSTR, PWSTR};
+use windows::Win32::System::SystemServices::LOCALE_NAME_MAX_LENGTH;
+use windows::Win32::System::Threading::{GetCurrentProcess, GetExitCodeProcess, IsWow64Process};
+use windows::Win32::System::Time::{
+    DYNAMIC_TIME_ZONE_INFORMATION, GetDynamicTimeZoneInformation, TIME_ZONE_ID_INVALID,
+};
+use windows::core::{BOOL, Owned, PCWSTR, PWSTR};
 
 declare_signals! {
     (),
@@ -123,8 +135,18 @@ impl SystemInner {
             self.query = Query::new(true);
             self.initialize_cpu_counters(refresh_kind);
         }
-        if let Some(ref mut query) = self.query {
+        // If the last CPU usage update is too close (less than `MINIMUM_CPU_UPDATE_INTERVAL`),
+        // we don't want to update CPUs usage.
+        let need_cpu_usage_update = refresh_kind.cpu_usage()
+            && self
+                .cpus
+                .last_update
+                .is_none_or(|last_update| last_update.elapsed() >= MINIMUM_CPU_UPDATE_INTERVAL);
+        if let Some(ref mut query) = self.query
+            && need_cpu_usage_update
+        {
             query.refresh();
+            self.cpus.last_update = Some(Instant::now());
             let mut total_idle_time = None;
             if let Some(ref key_used) = self.cpus.global.key_used {
                 total_idle_time = Some(
@@ -149,10 +171,30 @@ impl SystemInner {
                     cpu.inner.set_cpu_usage(100.0 - idle_time);
                 }
             }
-            if refresh_kind.frequency() {
-                self.cpus.get_frequencies();
-            }
         }
+        if self.query.is_some() && refresh_kind.frequency() {
+            self.cpus.get_frequencies();
+        }
+    }
+
+    pub(crate) fn try_refresh_memory_specifics(
+        &mut self,
+        refresh_kind: MemoryRefreshKind,
+    ) -> Result<(), RefreshError> {
+        self.refresh_memory_specifics(refresh_kind);
+        Ok(())
+    }
+
+    pub(crate) fn try_refresh_cpu_specifics(
+        &mut self,
+        refresh_kind: CpuRefreshKind,
+    ) -> Result<(), RefreshError> {
+        self.refresh_cpu_specifics(refresh_kind);
+        Ok(())
+    }
+
+    pub(crate) fn check_processes_source() -> Result<(), RefreshError> {
+        Ok(())
     }
 
     pub(crate) fn refresh_cpu_list(&mut self, refresh_kind: CpuRefreshKind) {
@@ -229,75 +271,42 @@ impl SystemInner {
             0
         };
 
-        // Use the amazing and cool CreateToolhelp32Snapshot function.
-        // Take a snapshot of all running processes. Match the result to an error
-        let snapshot = match unsafe { CreateToolhelp32Snapshot(TH32CS_SNAPPROCESS, 0) } {
-            Ok(handle) => handle,
-            Err(_err) => {
-                sysinfo_debug!(
-                    "Error capturing process snapshot: CreateToolhelp32Snapshot returned {}",
-                    _err
-                );
-                return 0;
-            }
-        };
-
-        // This owns the above handle and makes sure that close will be called when dropped.
-        let snapshot = unsafe { Owned::new(snapshot) };
-
-        // https://learn.microsoft.com/en-us/windows/win32/api/tlhelp32/ns-tlhelp32-processentry32w
-        // Microsoft documentation states that for PROCESSENTRY32W, before calling Process32FirstW,
-        // the 'dwSize' field MUST be set to the size of the PROCESSENTRY32W. Otherwise, Process32FirstW fails.
-        let mut process_entry = PROCESSENTRY32W {
-            dwSize: size_of::<PROCESSENTRY32W>() as u32,
-            ..Default::default()
+        // `NtQuerySystemInformation` returns all processes with their CPU times, memory and disk
+        // usage in one call. If it fails, we list them with `CreateToolhelp32Snapshot` and get
+        // this information through the process handles instead.
+        let Some(entries) = list_processes().or_else(list_processes_with_toolhelp) else {
+            return 0;
         };
 
         let mut num_procs = 0; // keep track of the number of updated processes
         let process_list = &mut self.process_list;
 
-        // process the first process
-        unsafe {
-            if let Err(_error) = Process32FirstW(*snapshot, &mut process_entry) {
-                sysinfo_debug!("Process32FirstW has failed: {_error:?}");
-                return 0;
+        for entry in entries {
+            if !filter_callback(entry.pid, filter_array) {
+                continue;
             }
-        }
-
-        // Iterate over processes in the snapshot.
-        // Use Process32NextW to process the next PROCESSENTRY32W in the snapshot
-        loop {
-            let proc_id = Pid::from_u32(process_entry.th32ProcessID);
-
-            if filter_callback(proc_id, filter_array) {
-                // exists already
-                if let Some(p) = process_list.get_mut(&proc_id) {
-                    // Update with the most recent information
-                    let p = &mut p.inner;
-                    p.update(refresh_kind, nb_cpus, now, false);
-
-                    // Update parent process
-                    let parent = if process_entry.th32ParentProcessID == 0 {
-                        None
-                    } else {
-                        Some(Pid::from_u32(process_entry.th32ParentProcessID))
-                    };
-
-                    p.parent = parent;
-                } else {
-                    // Make a new 'ProcessInner' using the Windows PROCESSENTRY32W struct.
-                    let mut p = ProcessInner::from_process_entry(&process_entry, now);
-                    p.update(refresh_kind, nb_cpus, now, false);
-                    process_list.insert(proc_id, Process { inner: p });
-                }
-
-                num_procs += 1;
+            let stats = entry.stats.as_ref();
+            // exists already
+            if let Some(p) = process_list.get_mut(&entry.pid) {
+                // Update with the most recent information
+                let p = &mut p.inner;
+                p.update(refresh_kind, nb_cpus, now, false, stats);
+                p.parent = entry.parent;
+                p.thread_count = Some(entry.thread_count);
+            } else {
+                let mut p = ProcessInner::new(entry.pid, entry.parent, now, entry.name, stats);
+                p.thread_count = Some(entry.thread_count);
+                p.update(refresh_kind, nb_cpus, now, false, stats);
+                process_list.insert(
+                    entry.pid,
+                    Process {
+                        inner: p,
+                        last_refreshed_at: None,
+                    },
+                );
             }
 
-            // nothing else to process
-            if unsafe { Process32NextW(*snapshot, &mut process_entry).is_err() } {
-                break;
-            }
+            num_procs += 1;
         }
 
         num_procs
@@ -307,6 +316,18 @@ impl SystemInner {
         &self.process_list
     }
 
+    pub(crate) fn process_string_stats(&self) -> ProcessStringStats {
+        ProcessStringStats::default()
+    }
+
+    pub(crate) fn set_max_kept_file_handles(&mut self, _max: usize) {}
+
+    pub(crate) fn kept_file_handles(&self) -> usize {
+        0
+    }
+
+    pub(crate) fn set_use_pidfds(&mut self, _use_pidfds: bool) {}
+
     pub(crate) fn processes_mut(&mut self) -> &mut HashMap<Pid, Process> {
         &mut self.process_list
     }
@@ -319,10 +340,18 @@ impl SystemInner {
         self.cpus.global_cpu_usage()
     }
 
+    pub(crate) fn last_cpu_usage_update(&self) -> Option<Instant> {
+        self.cpus.last_update
+    }
+
     pub(crate) fn cpus(&self) -> &[Cpu] {
         self.cpus.cpus()
     }
 
+    pub(crate) fn cpus_mut(&mut self) -> &mut [Cpu] {
+        self.cpus.cpus_mut()
+    }
+
     pub(crate) fn total_memory(&self) -> u64 {
         self.mem_total
     }
@@ -352,6 +381,45 @@ impl SystemInner {
         self.swap_used
     }
 
+    pub(crate) fn swap_devices(&self) -> Vec<crate::SwapDevice> {
+        unsafe extern "system" fn add_page_file(
+            context: *mut c_void,
+            info: *mut ENUM_PAGE_FILE_INFORMATION,
+            file_name: PCWSTR,
+        ) -> BOOL {
+            // SAFETY: `context` is the `Vec` given to `K32EnumPageFilesW` below.
+            let devices = unsafe { &mut *(context as *mut Vec<crate::SwapDevice>) };
+            let (Some(info), Ok(file_name)) =
+                (unsafe { info.as_ref() }, unsafe { file_name.to_string() })
+            else {
+                return true.into();
+            };
+            let page_size = SystemInner::page_size().unwrap_or(4096);
+            devices.push(crate::SwapDevice {
+                // Page files are listed with their NT path (like `\??\C:\pagefile.sys`).
+                path: file_name.strip_prefix(r"\??\").unwrap_or(&file_name).into(),
+                total: page_size.saturating_mul(info.TotalSize as _),
+                used: page_size.saturating_mul(info.TotalInUse as _),
+                priority: None,
+            });
+            true.into()
+        }
+
+        let mut devices: Vec<crate::SwapDevice> = Vec::new();
+        unsafe {
+            let _ = K32EnumPageFilesW(Some(add_page_file), (&mut devices as *mut Vec<_>).cast());
+        }
+        devices
+    }
+
+    pub(crate) fn compressed_memory(&self) -> Option<crate::CompressedMemory> {
+        None
+    }
+
+    pub(crate) fn inotify_usage(&self) -> Option<crate::InotifyUsage> {
+        None
+    }
+
     pub(crate) fn uptime() -> u64 {
         unsafe { GetTickCount64() / 1_000 }
     }
@@ -364,6 +432,10 @@ impl SystemInner {
         get_load_average()
     }
 
+    pub(crate) fn run_queue_length() -> Option<usize> {
+        None
+    }
+
     pub(crate) fn name() -> Option<String> {
         Some("Windows".to_owned())
     }
@@ -434,7 +506,8 @@ impl SystemInner {
         unsafe {
             // https://docs.microsoft.com/fr-fr/windows/win32/api/sysinfoapi/ns-sysinfoapi-system_info
             let mut info = SYSTEM_INFO::default();
-            GetSystemInfo(&mut info);
+            // Contrary to `GetSystemInfo`, it returns the real architecture in WOW64 processes.
+            GetNativeSystemInfo(&mut info);
             match info.Anonymous.Anonymous.wProcessorArchitecture {
                 SystemInformation::PROCESSOR_ARCHITECTURE_ALPHA => Some("alpha".to_string()),
                 SystemInformation::PROCESSOR_ARCHITECTURE_ALPHA64 => Some("alpha64".to_string()),
@@ -455,10 +528,32 @@ impl SystemInner {
         }
     }
 
+    pub(crate) fn is_compat_mode() -> Option<bool> {
+        let mut is_wow64 = BOOL(0);
+        unsafe { IsWow64Process(GetCurrentProcess(), &mut is_wow64).ok()? };
+        Some(is_wow64.as_bool())
+    }
+
+    pub(crate) fn core_dump_config() -> Option<crate::CoreDumpConfig> {
+        None
+    }
+
     pub(crate) fn physical_core_count() -> Option<usize> {
         get_physical_core_count()
     }
 
+    pub(crate) fn clock_ticks_per_second() -> Option<u64> {
+        None
+    }
+
+    pub(crate) fn page_size() -> Option<u64> {
+        unsafe {
+            let mut info = SYSTEM_INFO::default();
+            GetSystemInfo(&mut info);
+            Some(info.dwPageSize as u64)
+        }
+    }
+
     pub(crate) fn open_files_limit() -> Option<usize> {
         // Apparently when using C run-time libraries, it's limited by _NHANDLE_.
         // It's a define:
@@ -473,6 +568,277 @@ impl SystemInner {
         // So 128 * (1 << 6) = 8192
         Some(8192)
     }
+
+    pub(crate) fn timezone() -> Option<String> {
+        let mut info = DYNAMIC_TIME_ZONE_INFORMATION::default();
+        if unsafe { GetDynamicTimeZoneInformation(&mut info) } == TIME_ZONE_ID_INVALID {
+            sysinfo_debug!("GetDynamicTimeZoneInformation failed");
+            return None;
+        }
+        let len = info
+            .TimeZoneKeyName
+            .iter()
+            .position(|c| *c == 0)
+            .unwrap_or(info.TimeZoneKeyName.len());
+        String::from_utf16(&info.TimeZoneKeyName[..len])
+            .ok()
+            .filter(|tz| !tz.is_empty())
+    }
+
+    pub(crate) fn locale() -> Option<String> {
+        let mut buffer = [0u16; LOCALE_NAME_MAX_LENGTH as usize];
+        let len = unsafe { GetUserDefaultLocaleName(&mut buffer) };
+        if len <= 1 {
+            sysinfo_debug!("GetUserDefaultLocaleName failed");
+            return None;
+        }
+        // The returned length includes the null terminator.
+        String::from_utf16(&buffer[..len as usize - 1]).ok()
+    }
+
+    pub(crate) fn cpu_vulnerabilities() -> Vec<crate::CpuVulnerability> {
+        Vec::new()
+    }
+
+    pub(crate) fn memory_hardware_info() -> Option<crate::MemoryHardwareInfo> {
+        let table = crate::sys::utils::get_smbios_table()?;
+        // The table is preceded by the header of the `RawSMBIOSData` structure.
+        crate::MemoryHardwareInfo::from_smbios(table.get(8..)?)
+    }
+
+    pub(crate) fn virtualization() -> Option<String> {
+        crate::common::system::virtualization_from_product()
+    }
+
+    pub(crate) fn board_model() -> Option<String> {
+        None
+    }
+
+    pub(crate) fn turbo_enabled() -> Option<bool> {
+        get_boost_enabled()
+    }
+
+    pub(crate) fn throttling_status() -> Option<crate::ThrottlingStatus> {
+        None
+    }
+
+    pub(crate) fn socket_summary() -> Option<crate::SocketSummary> {
+        use windows::Win32::Foundation::NO_ERROR;
+        use windows::Win32::NetworkManagement::IpHelper::{
+            GetTcpStatisticsEx, GetUdpStatisticsEx, MIB_TCPSTATS_LH, MIB_UDPSTATS,
+        };
+        use windows::Win32::Networking::WinSock::{AF_INET, AF_INET6};
+
+        let mut summary = crate::SocketSummary::default();
+        for family in [AF_INET, AF_INET6] {
+            let mut tcp = MIB_TCPSTATS_LH::default();
+            if unsafe { GetTcpStatisticsEx(&mut tcp, family.0 as _) } == NO_ERROR.0 {
+                *summary.tcp_sockets.get_or_insert(0) += tcp.dwNumConns as u64;
+                *summary.tcp_established.get_or_insert(0) += tcp.dwCurrEstab as u64;
+            }
+            let mut udp = MIB_UDPSTATS::default();
+            if unsafe { GetUdpStatisticsEx(&mut udp, family.0 as _) } == NO_ERROR.0 {
+                *summary.udp_sockets.get_or_insert(0) += udp.dwNumAddrs as u64;
+            }
+        }
+        (summary != crate::SocketSummary::default()).then_some(summary)
+    }
+
+    pub(crate) fn kernel_log(
+        since: Option<Instant>,
+    ) -> std::io::Result<Vec<crate::KernelLogEntry>> {
+        use windows::Win32::System::EventLog::{
+            EVT_HANDLE, EvtCreateRenderContext, EvtNext, EvtQuery, EvtQueryChannelPath,
+            EvtQueryForwardDirection, EvtRenderContextSystem,
+        };
+        use windows::core::w;
+
+        // Like the kernel ring buffer of the other systems, only the events logged since boot
+        // are returned.
+        let uptime = Duration::from_millis(unsafe { GetTickCount64() });
+        let elapsed = since.map_or(uptime, |since| since.elapsed().min(uptime));
+        let query = format!(
+            "*[System[TimeCreated[timediff(@SystemTime) <= {}]]]",
+            elapsed.as_millis()
+        );
+        let query = query.encode_utf16().chain(Some(0)).collect::<Vec<_>>();
+        let boot_time = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap_or_default()
+            .saturating_sub(uptime);
+        // In 100 nanoseconds since 1601, like the times of the events.
+        let boot_time = (boot_time.as_nanos() / 100) as u64 + 116_444_736_000_000_000;
+
+        unsafe {
+            let results = Owned::new(
+                EvtQuery(
+                    None,
+                    w!("System"),
+                    PCWSTR::from_raw(query.as_ptr()),
+                    EvtQueryChannelPath.0 | EvtQueryForwardDirection.0,
+                )
+                .map_err(to_io_error)?,
+            );
+            let context = Owned::new(
+                EvtCreateRenderContext(None, EvtRenderContextSystem.0).map_err(to_io_error)?,
+            );
+            let mut publishers = HashMap::new();
+            let mut entries = Vec::new();
+            let mut events = [0; 64];
+            loop {
+                let mut returned = 0;
+                // It fails with `ERROR_NO_MORE_ITEMS` once all events have been read.
+                if EvtNext(*results, &mut events, u32::MAX, 0, &mut returned).is_err() {
+                    break;
+                }
+                for &event in events.iter().take(returned as usize) {
+                    let event = Owned::new(EVT_HANDLE(event));
+                    entries.extend(read_event(*context, *event, boot_time, &mut publishers));
+                }
+            }
+            Ok(entries)
+        }
+    }
+}
+
+/// Converts a Windows error to an `io::Error`. The `From` implementation keeps the `HRESULT`,
+/// so the `ErrorKind` of the Win32 errors (like `PermissionDenied`) would be lost.
+fn to_io_error(error: windows::core::Error) -> std::io::Error {
+    let code = error.code().0 as u32;
+    if code >> 16 == 0x8007 {
+        std::io::Error::from_raw_os_error((code & 0xffff) as _)
+    } else {
+        error.into()
+    }
+}
+
+/// Returns the kernel log entry of an event of the event log. `boot_time` is in 100
+/// nanoseconds since 1601 and `publishers` caches the metadata of the event providers, which is
+/// needed to format the messages.
+unsafe fn read_event(
+    context: windows::Win32::System::EventLog::EVT_HANDLE,
+    event: windows::Win32::System::EventLog::EVT_HANDLE,
+    boot_time: u64,
+    publishers: &mut HashMap<Vec<u16>, Option<Owned<windows::Win32::System::EventLog::EVT_HANDLE>>>,
+) -> Option<crate::KernelLogEntry> {
+    use crate::KernelLogLevel;
+    use windows::Win32::System::EventLog::{
+        EVT_SYSTEM_PROPERTY_ID, EVT_VARIANT, EvtOpenPublisherMetadata, EvtRender,
+        EvtRenderEventValues, EvtSystemEventRecordId, EvtSystemLevel, EvtSystemProviderName,
+        EvtSystemTimeCreated, EvtVarTypeNull,
+    };
+
+    unsafe {
+        let mut used = 0;
+        let mut count = 0;
+        // The first call fails and returns the needed size.
+        let _ = EvtRender(
+            Some(context),
+            event,
+            EvtRenderEventValues.0,
+            0,
+            None,
+            &mut used,
+            &mut count,
+        );
+        // `u64` to have the alignment of `EVT_VARIANT`.
+        let mut buffer = vec![0_u64; (used as usize).div_ceil(size_of::<u64>())];
+        EvtRender(
+            Some(context),
+            event,
+            EvtRenderEventValues.0,
+            (buffer.len() * size_of::<u64>()) as _,
+            Some(buffer.as_mut_ptr().cast()),
+            &mut used,
+            &mut count,
+        )
+        .ok()?;
+        let values =
+            std::slice::from_raw_parts(buffer.as_ptr().cast::<EVT_VARIANT>(), count as usize);
+        let value = |id: EVT_SYSTEM_PROPERTY_ID| {
+            values
+                .get(id.0 as usize)
+                .filter(|value| value.Type != EvtVarTypeNull.0 as u32)
+        };
+
+        let level = match value(EvtSystemLevel).map(|value| value.Anonymous.ByteVal) {
+            Some(1) => KernelLogLevel::Critical,
+            Some(2) => KernelLogLevel::Error,
+            Some(3) => KernelLogLevel::Warning,
+            Some(5) => KernelLogLevel::Debug,
+            _ => KernelLogLevel::Info,
+        };
+        let sequence = value(EvtSystemEventRecordId).map_or(0, |value| value.Anonymous.UInt64Val);
+        let time_since_boot = value(EvtSystemTimeCreated).map(|value| {
+            let time = value.Anonymous.FileTimeVal.saturating_sub(boot_time);
+            Duration::from_nanos(time.saturating_mul(100))
+        });
+        let provider = value(EvtSystemProviderName)?.Anonymous.StringVal;
+        if provider.is_null() {
+            return None;
+        }
+        let metadata = publishers
+            .entry(provider.as_wide().to_vec())
+            .or_insert_with(|| {
+                EvtOpenPublisherMetadata(None, provider, PCWSTR::null(), 0, 0)
+                    .ok()
+                    .map(|metadata| Owned::new(metadata))
+            });
+        // Without the metadata of the provider, only its name is known.
+        let message = metadata
+            .as_deref()
+            .and_then(|metadata| format_event_message(*metadata, event))
+            .unwrap_or_else(|| String::from_utf16_lossy(provider.as_wide()));
+
+        Some(crate::KernelLogEntry {
+            level,
+            sequence,
+            time_since_boot,
+            message,
+        })
+    }
+}
+
+unsafe fn format_event_message(
+    metadata: windows::Win32::System::EventLog::EVT_HANDLE,
+    event: windows::Win32::System::EventLog::EVT_HANDLE,
+) -> Option<String> {
+    use windows::Win32::System::EventLog::{EvtFormatMessage, EvtFormatMessageEvent};
+
+    let mut used = 0;
+    let mut buffer;
+    unsafe {
+        // The first call fails and returns the needed size.
+        let _ = EvtFormatMessage(
+            Some(metadata),
+            Some(event),
+            0,
+            None,
+            EvtFormatMessageEvent.0,
+            None,
+            &mut used,
+        );
+        if used == 0 {
+            return None;
+        }
+        buffer = vec![0_u16; used as usize];
+        EvtFormatMessage(
+            Some(metadata),
+            Some(event),
+            0,
+            None,
+            EvtFormatMessageEvent.0,
+            Some(&mut buffer),
+            &mut used,
+        )
+        .ok()?;
+    }
+    let len = buffer.iter().position(|c| *c == 0).unwrap_or(buffer.len());
+    Some(
+        String::from_utf16_lossy(&buffer[..len])
+            .trim_end()
+            .to_owned(),
+    )
 }
 
 pub(crate) fn is_proc_running(handle: HANDLE) -> bool {
@@ -525,6 +891,157 @@ fn add_english_counter(
     }
 }
 
+/// A process listed by [`list_processes`] or [`list_processes_with_toolhelp`].
+struct ProcessEntry {
+    pid: Pid,
+    parent: Option<Pid>,
+    name: OsString,
+    thread_count: usize,
+    /// Only provided by [`list_processes`].
+    stats: Option<ProcessStats>,
+}
+
+fn parent_pid(ppid: u32) -> Option<Pid> {
+    if ppid == 0 {
+        // no parent pid
+        None
+    } else {
+        Some(Pid::from_u32(ppid))
+    }
+}
+
+/// Lists all processes with a single `NtQuerySystemInformation(SystemProcessInformation)` call.
+#[allow(clippy::cast_ptr_alignment)]
+fn list_processes() -> Option<Vec<ProcessEntry>> {
+    // `u64` to have the alignment expected for `SYSTEM_PROCESS_INFORMATION`.
+    let mut buffer: Vec<u64> = Vec::new();
+    let mut buffer_size: u32 = 512 * 1024;
+    loop {
+        buffer.resize(buffer_size as usize / size_of::<u64>(), 0);
+        let mut return_length = 0;
+        let status = unsafe {
+            NtQuerySystemInformation(
+                SystemProcessInformation,
+                buffer.as_mut_ptr().cast(),
+                (buffer.len() * size_of::<u64>()) as _,
+                &mut return_length,
+            )
+        };
+        if status == STATUS_INFO_LENGTH_MISMATCH {
+            // New processes can be created before the next call, so we take some margin.
+            buffer_size = return_length.max(buffer_size).saturating_add(64 * 1024);
+            continue;
+        }
+        if status.is_err() {
+            sysinfo_debug!("NtQuerySystemInformation failed: {status:?}");
+            return None;
+        }
+        break;
+    }
+
+    let mut entries = Vec::new();
+    let mut offset = 0;
+    loop {
+        unsafe {
+            let info = &*buffer
+                .as_ptr()
+                .cast::<u8>()
+                .add(offset)
+                .cast::<SYSTEM_PROCESS_INFORMATION>();
+            let pid = Pid::from(info.UniqueProcessId as usize);
+            let name = if info.ImageName.Buffer.is_null() {
+                // Only the idle process has no name.
+                OsString::from("[System Process]")
+            } else {
+                OsString::from_wide(std::slice::from_raw_parts(
+                    info.ImageName.Buffer,
+                    info.ImageName.Length as usize / size_of::<u16>(),
+                ))
+            };
+            entries.push(ProcessEntry {
+                pid,
+                parent: parent_pid(info.InheritedFromUniqueProcessId as usize as _),
+                name,
+                thread_count: info.NumberOfThreads as _,
+                stats: Some(ProcessStats {
+                    create_time: *info.CreateTime.QuadPart() as _,
+                    kernel_time: *info.KernelTime.QuadPart() as _,
+                    user_time: *info.UserTime.QuadPart() as _,
+                    memory: info.WorkingSetSize as _,
+                    virtual_memory: info.PagefileUsage as _,
+                    read_bytes: *info.ReadTransferCount.QuadPart() as _,
+                    written_bytes: *info.WriteTransferCount.QuadPart() as _,
+                }),
+            });
+            if info.NextEntryOffset == 0 {
+                break;
+            }
+            offset += info.NextEntryOffset as usize;
+        }
+    }
+    Some(entries)
+}
+
+/// Lists all processes with `CreateToolhelp32Snapshot`.
+fn list_processes_with_toolhelp() -> Option<Vec<ProcessEntry>> {
+    // Take a snapshot of all running processes. Match the result to an error
+    let snapshot = match unsafe { CreateToolhelp32Snapshot(TH32CS_SNAPPROCESS, 0) } {
+        Ok(handle) => handle,
+        Err(_err) => {
+            sysinfo_debug!(
+                "Error capturing process snapshot: CreateToolhelp32Snapshot returned {}",
+                _err
+            );
+            return None;
+        }
+    };
+
+    // This owns the above handle and makes sure that close will be called when dropped.
+    let snapshot = unsafe { Owned::new(snapshot) };
+
+    // https://learn.microsoft.com/en-us/windows/win32/api/tlhelp32/ns-tlhelp32-processentry32w
+    // Microsoft documentation states that for PROCESSENTRY32W, before calling Process32FirstW,
+    // the 'dwSize' field MUST be set to the size of the PROCESSENTRY32W. Otherwise, Process32FirstW fails.
+    let mut process_entry = PROCESSENTRY32W {
+        dwSize: size_of::<PROCESSENTRY32W>() as u32,
+        ..Default::default()
+    };
+
+    // process the first process
+    unsafe {
+        if let Err(_error) = Process32FirstW(*snapshot, &mut process_entry) {
+            sysinfo_debug!("Process32FirstW has failed: {_error:?}");
+            return None;
+        }
+    }
+
+    let mut entries = Vec::new();
+    // Iterate over processes in the snapshot.
+    // Use Process32NextW to process the next PROCESSENTRY32W in the snapshot
+    loop {
+        let pid = Pid::from_u32(process_entry.th32ProcessID);
+        let name = match OsString::from_str(
+            String::from_utf16_lossy(&process_entry.szExeFile).trim_end_matches('\0'),
+        ) {
+            Ok(name) => name,
+            Err(_) => format!("<no name> Process {pid}").into(),
+        };
+        entries.push(ProcessEntry {
+            pid,
+            parent: parent_pid(process_entry.th32ParentProcessID),
+            name,
+            thread_count: process_entry.cntThreads as _,
+            stats: None,
+        });
+
+        // nothing else to process
+        if unsafe { Process32NextW(*snapshot, &mut process_entry).is_err() } {
+            break;
+        }
+    }
+    Some(entries)
+}
+
 fn get_now() -> u64 {
     SystemTime::now()
         .duration_since(SystemTime::UNIX_EPOCH)
@@ -635,3 +1152,19 @@ pub(crate) fn get_reg_value_u32(hkey: HKEY, path: &str, field_name: &str) -> Opt
             .ok()
     }
 }
+
+pub(crate) fn get_reg_value_u64(hkey: HKEY, path: &str, field_name: &str) -> Option<u64> {
+    let c_path = utf16_str(path);
+    let c_field_name = utf16_str(field_name);
+
+    unsafe {
+        let new_key = RegKey::open(hkey, &c_path)?;
+        let mut buf_len: u32 = 8;
+        let mut buf = [0u8; 8];
+
+        new_key
+            .get_value(&c_field_name, &mut buf, &mut buf_len)
+            .map(|_| u64::from_le_bytes(buf))
+            .ok()
+    }
+}
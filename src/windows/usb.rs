@@ -0,0 +1,164 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::{UsbDevice, UsbSpeed};
+
+use windows::Win32::Devices::DeviceAndDriverInstallation::{
+    DIGCF_DEVICEINTERFACE, DIGCF_PRESENT, HDEVINFO, SETUP_DI_REGISTRY_PROPERTY, SP_DEVINFO_DATA,
+    SPDRP_DEVICEDESC, SPDRP_LOCATION_PATHS, SPDRP_MFG, SetupDiDestroyDeviceInfoList,
+    SetupDiEnumDeviceInfo, SetupDiGetClassDevsW, SetupDiGetDeviceInstanceIdW,
+    SetupDiGetDeviceRegistryPropertyW,
+};
+use windows::Win32::Devices::Usb::GUID_DEVINTERFACE_USB_DEVICE;
+
+struct DeviceInfoList(HDEVINFO);
+
+impl Drop for DeviceInfoList {
+    fn drop(&mut self) {
+        let _err = unsafe { SetupDiDestroyDeviceInfoList(self.0) };
+    }
+}
+
+/// Returns the string (or the first string if it's a `REG_MULTI_SZ`) of the given property.
+unsafe fn get_property(
+    list: &DeviceInfoList,
+    info: &SP_DEVINFO_DATA,
+    property: SETUP_DI_REGISTRY_PROPERTY,
+) -> Option<String> {
+    let mut buf = [0u16; 512];
+    let ret = unsafe {
+        SetupDiGetDeviceRegistryPropertyW(
+            list.0,
+            info,
+            property,
+            None,
+            Some(std::slice::from_raw_parts_mut(
+                buf.as_mut_ptr().cast(),
+                buf.len() * 2,
+            )),
+            None,
+        )
+    };
+    if let Err(_err) = ret {
+        sysinfo_debug!("SetupDiGetDeviceRegistryPropertyW failed: {_err:?}");
+        return None;
+    }
+    let len = buf.iter().position(|c| *c == 0).unwrap_or(buf.len());
+    let value = String::from_utf16_lossy(&buf[..len]);
+    if value.is_empty() { None } else { Some(value) }
+}
+
+/// Parses a device instance ID like `USB\VID_046D&PID_C52B\1234ABCD`.
+///
+/// The last part is the serial number of the device, unless it contains a `&`, which means that
+/// Windows generated it because the device has no serial number.
+fn parse_instance_id(instance_id: &str) -> Option<(u16, u16, Option<String>)> {
+    let mut parts = instance_id.split('\\');
+    if !parts.next()?.eq_ignore_ascii_case("USB") {
+        return None;
+    }
+    let mut vendor_id = None;
+    let mut product_id = None;
+    for id in parts.next()?.split('&') {
+        if let Some(vid) = id.strip_prefix("VID_") {
+            vendor_id = u16::from_str_radix(vid, 16).ok();
+        } else if let Some(pid) = id.strip_prefix("PID_") {
+            product_id = u16::from_str_radix(pid, 16).ok();
+        }
+    }
+    let serial_number = parts
+        .next()
+        .filter(|serial| !serial.is_empty() && !serial.contains('&'))
+        .map(str::to_owned);
+    Some((vendor_id?, product_id?, serial_number))
+}
+
+/// Extracts the ports from a location path like `PCIROOT(0)#PCI(1400)#USBROOT(0)#USB(2)#USB(1)`.
+fn parse_location_path(location: &str) -> Vec<u8> {
+    location
+        .split('#')
+        .filter_map(|part| part.strip_prefix("USB(")?.strip_suffix(')')?.parse().ok())
+        .collect()
+}
+
+pub(crate) fn get_usb_devices(devices: &mut Vec<UsbDevice>) {
+    devices.clear();
+
+    let list = match unsafe {
+        SetupDiGetClassDevsW(
+            Some(&GUID_DEVINTERFACE_USB_DEVICE),
+            None,
+            None,
+            DIGCF_PRESENT | DIGCF_DEVICEINTERFACE,
+        )
+    } {
+        Ok(list) => DeviceInfoList(list),
+        Err(_err) => {
+            sysinfo_debug!("SetupDiGetClassDevsW failed: {_err:?}");
+            return;
+        }
+    };
+
+    let mut info = SP_DEVINFO_DATA {
+        cbSize: std::mem::size_of::<SP_DEVINFO_DATA>() as _,
+        ..Default::default()
+    };
+    let mut index = 0;
+    while unsafe { SetupDiEnumDeviceInfo(list.0, index, &mut info) }.is_ok() {
+        index += 1;
+
+        let mut buf = [0u16; 512];
+        if unsafe { SetupDiGetDeviceInstanceIdW(list.0, &info, Some(&mut buf), None) }.is_err() {
+            continue;
+        }
+        let len = buf.iter().position(|c| *c == 0).unwrap_or(buf.len());
+        let Some((vendor_id, product_id, serial_number)) =
+            parse_instance_id(&String::from_utf16_lossy(&buf[..len]))
+        else {
+            continue;
+        };
+        unsafe {
+            devices.push(UsbDevice {
+                vendor_id,
+                product_id,
+                manufacturer: get_property(&list, &info, SPDRP_MFG),
+                product: get_property(&list, &info, SPDRP_DEVICEDESC),
+                serial_number,
+                bus_number: None,
+                port_numbers: get_property(&list, &info, SPDRP_LOCATION_PATHS)
+                    .map(|location| parse_location_path(&location))
+                    .unwrap_or_default(),
+                speed: UsbSpeed::Unknown,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_instance_id, parse_location_path};
+
+    #[test]
+    fn test_parse_instance_id() {
+        assert_eq!(
+            parse_instance_id(r"USB\VID_046D&PID_C52B\1234ABCD"),
+            Some((0x046d, 0xc52b, Some("1234ABCD".to_owned())))
+        );
+        assert_eq!(
+            parse_instance_id(r"USB\VID_8087&PID_0026\5&1A2B3C4D&0&10"),
+            Some((0x8087, 0x0026, None))
+        );
+        assert_eq!(
+            parse_instance_id(r"PCI\VEN_8086&DEV_A0ED\3&11583659&0&A0"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_parse_location_path() {
+        assert_eq!(
+            parse_location_path("PCIROOT(0)#PCI(1400)#USBROOT(0)#USB(2)#USB(1)"),
+            vec![2, 1]
+        );
+        assert!(parse_location_path("PCIROOT(0)#PCI(1400)#USBROOT(0)").is_empty());
+    }
+}
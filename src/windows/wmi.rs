@@ -0,0 +1,157 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use windows::Win32::Security::PSECURITY_DESCRIPTOR;
+use windows::Win32::System::Com::{
+    CLSCTX_INPROC_SERVER, CoCreateInstance, CoInitializeEx, CoInitializeSecurity,
+    CoSetProxyBlanket, EOAC_NONE, RPC_C_AUTHN_LEVEL_CALL, RPC_C_AUTHN_LEVEL_DEFAULT,
+    RPC_C_IMP_LEVEL_IMPERSONATE,
+};
+use windows::Win32::System::Rpc::{RPC_C_AUTHN_WINNT, RPC_C_AUTHZ_NONE};
+#[cfg(feature = "system")]
+use windows::Win32::System::Variant::{VARIANT, VariantClear, VariantToUInt32};
+#[cfg(feature = "system")]
+use windows::Win32::System::Wmi::WBEM_INFINITE;
+use windows::Win32::System::Wmi::{
+    IEnumWbemClassObject, IWbemLocator, IWbemServices, WBEM_FLAG_FORWARD_ONLY,
+    WBEM_FLAG_RETURN_IMMEDIATELY, WbemLocator,
+};
+use windows::core::BSTR;
+#[cfg(feature = "system")]
+use windows::core::PCWSTR;
+
+use std::cell::OnceCell;
+#[cfg(feature = "system")]
+use std::mem::MaybeUninit;
+use std::sync::OnceLock;
+
+static SECURITY: OnceLock<Result<(), ()>> = OnceLock::new();
+thread_local! {
+    static CONNECTION: OnceCell<Result<(), ()>> = const { OnceCell::new() };
+}
+
+unsafe fn initialize_connection() -> Result<(), ()> {
+    if unsafe { CoInitializeEx(None, Default::default()) }.is_err() {
+        sysinfo_debug!("Failed to initialize connection");
+        Err(())
+    } else {
+        Ok(())
+    }
+}
+
+unsafe fn initialize_security() -> Result<(), ()> {
+    if unsafe {
+        CoInitializeSecurity(
+            Some(PSECURITY_DESCRIPTOR::default()),
+            -1,
+            None,
+            None,
+            RPC_C_AUTHN_LEVEL_DEFAULT,
+            RPC_C_IMP_LEVEL_IMPERSONATE,
+            None,
+            EOAC_NONE,
+            None,
+        )
+    }
+    .is_err()
+    {
+        sysinfo_debug!("Failed to initialize security");
+        Err(())
+    } else {
+        Ok(())
+    }
+}
+
+/// Connection to a WMI namespace.
+pub(crate) struct WmiServices {
+    services: IWbemServices,
+    // Needs to be dropped after `services`.
+    _locator: IWbemLocator,
+}
+
+impl WmiServices {
+    /// Connects to the WMI `namespace` (like `root\WMI`).
+    ///
+    /// COM is initialized for the current thread if it wasn't already, and its security only
+    /// once for the whole process (as it can only be done once).
+    pub(crate) fn connect(namespace: &str) -> Option<Self> {
+        if CONNECTION
+            .with(|x| *x.get_or_init(|| unsafe { initialize_connection() }))
+            .is_err()
+            || SECURITY
+                .get_or_init(|| unsafe { initialize_security() })
+                .is_err()
+        {
+            return None;
+        }
+        let locator: IWbemLocator =
+            unsafe { CoCreateInstance(&WbemLocator, None, CLSCTX_INPROC_SERVER) }.ok()?;
+        let services = unsafe {
+            locator.ConnectServer(
+                &BSTR::from(namespace),
+                &BSTR::new(),
+                &BSTR::new(),
+                &BSTR::new(),
+                0,
+                &BSTR::new(),
+                None,
+            )
+        }
+        .map_err(|_e| sysinfo_debug!("Failed to connect to WMI namespace {namespace}: {_e:?}"))
+        .ok()?;
+        unsafe {
+            CoSetProxyBlanket(
+                &services,
+                RPC_C_AUTHN_WINNT,
+                RPC_C_AUTHZ_NONE,
+                None,
+                RPC_C_AUTHN_LEVEL_CALL,
+                RPC_C_IMP_LEVEL_IMPERSONATE,
+                None,
+                EOAC_NONE,
+            )
+        }
+        .ok()?;
+        Some(Self {
+            services,
+            _locator: locator,
+        })
+    }
+
+    /// Runs the WQL `query`.
+    pub(crate) fn query(&self, query: &str) -> Option<IEnumWbemClassObject> {
+        unsafe {
+            self.services.ExecQuery(
+                &BSTR::from("WQL"),
+                &BSTR::from(query),
+                WBEM_FLAG_FORWARD_ONLY | WBEM_FLAG_RETURN_IMMEDIATELY,
+                None,
+            )
+        }
+        .map_err(|_e| sysinfo_debug!("WMI query `{query}` failed: {_e:?}"))
+        .ok()
+    }
+}
+
+/// Returns the `property` of the next object of `enumerator` as a `u32`.
+#[cfg(feature = "system")]
+pub(crate) fn next_u32(enumerator: &IEnumWbemClassObject, property: PCWSTR) -> Option<u32> {
+    let mut objects = [None; 1];
+    let mut nb_returned = 0;
+    unsafe { enumerator.Next(WBEM_INFINITE, &mut objects, &mut nb_returned) }
+        .ok()
+        .ok()?;
+    let [Some(object)] = &objects else {
+        return None;
+    };
+    let mut variant = MaybeUninit::<VARIANT>::uninit();
+    unsafe {
+        // `Get` only initializes the variant if it succeeds.
+        object
+            .Get(property, 0, variant.as_mut_ptr(), None, None)
+            .ok()?;
+        let mut variant = variant.assume_init();
+        let value = VariantToUInt32(&variant).ok();
+        let _r = VariantClear(&mut variant);
+        value
+    }
+}
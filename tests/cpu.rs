@@ -50,3 +50,36 @@ fn test_too_rapid_cpu_refresh() {
 
     assert!(s.cpus().iter().any(|c| !c.cpu_usage().is_nan()));
 }
+
+#[test]
+fn test_run_queue_length() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || !cfg!(any(target_os = "linux", target_os = "android")) {
+        return;
+    }
+    // At least the thread running this test is runnable.
+    assert!(sysinfo::System::run_queue_length().unwrap() > 0);
+
+    let mut s = sysinfo::System::new();
+    s.refresh_cpu_specifics(sysinfo::CpuRefreshKind::nothing().with_run_queue());
+    assert!(!s.cpus().is_empty());
+    if std::path::Path::new("/proc/schedstat").exists() {
+        assert!(s.cpus().iter().all(|c| c.run_queue_wait_time().is_some()));
+    }
+}
+
+#[test]
+fn test_cpu_peak_usage() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM {
+        return;
+    }
+    let mut s = sysinfo::System::new();
+    s.refresh_cpu_usage();
+    // The first sample is ignored.
+    assert!(s.cpus().iter().all(|c| c.peak_usage() == 0.));
+    std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+    s.refresh_cpu_usage();
+    assert!(s.cpus().iter().all(|c| c.peak_usage() >= c.cpu_usage()));
+
+    s.reset_cpu_peak_usage();
+    assert!(s.cpus().iter().all(|c| c.peak_usage() == c.cpu_usage()));
+}
@@ -74,30 +74,22 @@ fn test_disk_refresh_kind() {
                 // These would ideally assert that *all* are refreshed, but we settle for a weaker
                 // assertion because failures can't be distinguished from "not refreshed" values.
                 assert!(
-                    disks
-                        .iter()
-                        .any(|disk| disk.available_space() != Default::default()),
+                    disks.iter().any(|disk| disk.available_space() != 0),
                     "{name}: disk.available_space should be refreshed"
                 );
                 assert!(
-                    disks
-                        .iter()
-                        .any(|disk| disk.total_space() != Default::default()),
+                    disks.iter().any(|disk| disk.total_space() != 0),
                     "{name}: disk.total_space should be refreshed"
                 );
                 // We can't assert anything about booleans, since false is indistinguishable from
                 // not-refreshed
             } else {
                 assert!(
-                    disks
-                        .iter()
-                        .all(|disk| disk.available_space() == Default::default()),
+                    disks.iter().all(|disk| disk.available_space() == 0),
                     "{name}: disk.available_space should not be refreshed"
                 );
                 assert!(
-                    disks
-                        .iter()
-                        .all(|disk| disk.total_space() == Default::default()),
+                    disks.iter().all(|disk| disk.total_space() == 0),
                     "{name}: disk.total_space should not be refreshed"
                 );
             }
@@ -184,3 +176,91 @@ fn test_disks_usage() {
     // just verify the number is non-zero.
     assert!(written_bytes > 0);
 }
+
+#[test]
+#[cfg(all(feature = "system", feature = "disk"))]
+fn test_usage_for_path() {
+    use sysinfo::Disks;
+
+    let dir = std::env::current_dir().unwrap();
+    let space = Disks::usage_for_path(&dir);
+    if !sysinfo::IS_SUPPORTED_SYSTEM {
+        assert_eq!(space, None);
+        return;
+    }
+    let space = space.expect("failed to get disk space");
+    #[cfg(unix)]
+    assert!(dir.canonicalize().unwrap().starts_with(space.mount_point()));
+    assert!(space.total_space() >= space.available_space());
+    assert!(space.total_space() >= space.used_space());
+
+    assert_eq!(Disks::usage_for_path("/does/not/exist".as_ref()), None);
+}
+
+#[test]
+#[cfg(feature = "disk")]
+fn test_disk_watcher() {
+    use std::time::{Duration, Instant};
+    use sysinfo::DiskWatcher;
+
+    let watcher = DiskWatcher::start();
+    if !sysinfo::IS_SUPPORTED_SYSTEM {
+        assert_eq!(
+            watcher.err().map(|error| error.kind()),
+            Some(std::io::ErrorKind::Unsupported)
+        );
+        return;
+    }
+    let mut watcher = watcher.expect("failed to watch disks");
+    let start = Instant::now();
+    watcher.wait(Duration::from_millis(100));
+    // Unless something was mounted in the meantime, it waited for the whole timeout.
+    assert!(!watcher.events().is_empty() || start.elapsed() >= Duration::from_millis(100));
+    watcher.refresh();
+}
+
+#[test]
+#[cfg(feature = "disk")]
+fn test_storage_volumes() {
+    let volumes = sysinfo::Storage::volumes();
+    if !cfg!(target_os = "linux") {
+        assert!(volumes.is_empty());
+    }
+    for volume in &volumes {
+        assert!(!volume.name().is_empty());
+    }
+}
+
+#[test]
+#[cfg(feature = "disk")]
+fn test_disks_network_fs_stats() {
+    let disks = sysinfo::Disks::new_with_refreshed_list();
+    for disk in disks.list() {
+        let Some(stats) = disk.network_fs_stats() else {
+            continue;
+        };
+        assert!(disk.file_system().to_string_lossy().starts_with("nfs"));
+        // The execution time includes the round-trip time.
+        assert!(stats.average_rtt() <= stats.average_execution_time());
+    }
+}
+
+#[test]
+#[cfg(all(feature = "system", feature = "disk"))]
+fn test_disks_io_stats() {
+    if should_skip() {
+        return;
+    }
+    let mut disks = sysinfo::Disks::new_with_refreshed_list();
+    // It needs two refreshes.
+    assert!(disks.list().iter().all(|disk| disk.io_stats().is_none()));
+    std::thread::sleep(std::time::Duration::from_millis(100));
+    disks.refresh_specifics(true, sysinfo::DiskRefreshKind::nothing().with_io_usage());
+    for disk in disks.list() {
+        let Some(stats) = disk.io_stats() else {
+            continue;
+        };
+        assert!((0. ..=100.).contains(&stats.utilization()));
+        assert!(stats.average_queue_depth() >= 0.);
+    }
+}
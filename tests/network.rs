@@ -10,7 +10,76 @@ fn test_networks() {
     if sysinfo::IS_SUPPORTED_SYSTEM {
         let mut n = Networks::new();
         assert_eq!(n.iter().count(), 0);
+        assert!(n.last_refreshed_at().is_none());
         n.refresh(false);
         assert!(n.iter().count() > 0);
+        assert!(n.last_refreshed_at().is_some());
     }
 }
+
+#[cfg(feature = "network")]
+#[test]
+fn test_dns_configuration() {
+    use sysinfo::Networks;
+
+    let dns = Networks::dns_configuration();
+    if !sysinfo::IS_SUPPORTED_SYSTEM {
+        assert_eq!(dns, Default::default());
+    }
+    for domain in dns.search_domains() {
+        assert!(!domain.is_empty());
+    }
+}
+
+#[cfg(feature = "network")]
+#[test]
+fn test_primary_interface() {
+    use sysinfo::Networks;
+
+    if !sysinfo::IS_SUPPORTED_SYSTEM {
+        assert_eq!(Networks::default_gateway(), None);
+        assert_eq!(Networks::primary_interface(), None);
+    } else if cfg!(target_os = "linux")
+        && let Some(interface) = Networks::primary_interface()
+    {
+        let networks = Networks::new_with_refreshed_list();
+        assert!(networks.contains_key(&interface));
+        assert!(Networks::default_gateway().is_some());
+    }
+}
+
+#[cfg(feature = "network")]
+#[test]
+fn test_protocol_stats() {
+    use sysinfo::Networks;
+
+    let stats = Networks::protocol_stats();
+    if !cfg!(any(target_os = "linux", windows)) {
+        assert_eq!(stats, None);
+    } else if let Some(stats) = stats {
+        let new_stats = Networks::protocol_stats().expect("protocol stats disappeared");
+        assert!(new_stats.tcp_segments_sent() >= stats.tcp_segments_sent());
+    }
+}
+
+#[cfg(feature = "network")]
+#[test]
+fn test_network_watcher() {
+    use std::time::Duration;
+    use sysinfo::NetworkWatcher;
+
+    let watcher = NetworkWatcher::start();
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(target_os = "redox") {
+        assert_eq!(
+            watcher.err().map(|error| error.kind()),
+            Some(std::io::ErrorKind::Unsupported)
+        );
+        return;
+    }
+    let mut watcher = watcher.expect("failed to watch network interfaces");
+    watcher.wait(Duration::from_millis(100));
+    for event in watcher.events() {
+        assert!(!event.interface_name().is_empty());
+    }
+    watcher.refresh();
+}
@@ -3,7 +3,9 @@
 #![cfg(feature = "system")]
 
 use bstr::ByteSlice;
-use sysinfo::{Pid, ProcessRefreshKind, ProcessesToUpdate, RefreshKind, System, UpdateKind};
+use sysinfo::{
+    PathUsage, Pid, ProcessRefreshKind, ProcessesToUpdate, RefreshKind, System, UpdateKind,
+};
 
 macro_rules! start_proc {
     ($time:literal, $name:literal) => {
@@ -332,6 +334,128 @@ fn test_process_session_id() {
     assert!(s.processes().values().any(|p| p.session_id().is_some()));
 }
 
+// Checks that `process_group_id` and `session_id` match what the system returns.
+#[test]
+#[cfg(target_os = "linux")]
+fn test_process_group_id() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM {
+        return;
+    }
+    let mut s = System::new();
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    s.refresh_processes(ProcessesToUpdate::Some(&[pid]), false);
+    let p = s.process(pid).expect("current process not found");
+    unsafe {
+        assert_eq!(
+            p.process_group_id(),
+            Some(Pid::from(libc::getpgid(0) as usize))
+        );
+        assert_eq!(p.session_id(), Some(Pid::from(libc::getsid(0) as usize)));
+    }
+}
+
+#[test]
+fn test_track_child() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let mut child = start_proc!("300", "TrackChild");
+    let pid = Pid::from_u32(child.id());
+
+    let mut s = System::new();
+    let process = s.track_child(&child).expect("child not found");
+    assert_eq!(process.pid(), pid);
+    assert_eq!(s.processes().len(), 1);
+
+    child.kill().unwrap();
+    child.wait().unwrap();
+    assert!(s.track_child(&child).is_none());
+    assert!(s.process(pid).is_none());
+}
+
+#[test]
+fn test_current_process() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let usage = sysinfo::current_process().expect("failed to get current process usage");
+    assert!(usage.memory > 0);
+    assert!(usage.virtual_memory > 0);
+
+    let mut s = System::new();
+    let process = s.refresh_self().expect("current process not found");
+    assert_eq!(Some(process.pid()), sysinfo::get_current_pid().ok());
+    assert!(process.memory() > 0);
+    assert_eq!(s.processes().len(), 1);
+}
+
+#[test]
+fn test_self_children_usage() {
+    let usage = sysinfo::self_children_usage();
+    if !sysinfo::IS_SUPPORTED_SYSTEM
+        || !cfg!(any(
+            target_os = "linux",
+            target_os = "macos",
+            target_os = "freebsd"
+        ))
+        || cfg!(feature = "apple-sandbox")
+    {
+        assert!(usage.is_none());
+        return;
+    }
+    let before = usage.expect("failed to get children usage");
+    let status = std::process::Command::new("sh")
+        .arg("-c")
+        .arg("i=0; while [ $i -lt 20000 ]; do i=$((i+1)); done")
+        .status()
+        .unwrap();
+    assert!(status.success());
+    let after = sysinfo::self_children_usage().expect("failed to get children usage");
+    assert!(after.max_memory > 0);
+    assert!(after.max_memory >= before.max_memory);
+    assert!(after.accumulated_cpu_time >= before.accumulated_cpu_time);
+}
+
+#[test]
+fn test_minimal_refresh_kind() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    let mut s = System::new();
+    for _ in 0..2 {
+        s.refresh_processes_specifics(
+            ProcessesToUpdate::Some(&[pid]),
+            false,
+            ProcessRefreshKind::minimal(),
+        );
+    }
+    let p = s.process(pid).expect("current process not found");
+    assert!(p.memory() > 0);
+    assert!(p.cmd().is_empty());
+    assert!(p.environ().is_empty());
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn test_max_kept_file_handles() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM {
+        return;
+    }
+    let mut s = System::new();
+    s.set_max_kept_file_handles(0);
+    s.refresh_processes(ProcessesToUpdate::All, false);
+    assert_eq!(s.kept_file_handles(), 0);
+
+    s.set_max_kept_file_handles(3);
+    s.refresh_processes(ProcessesToUpdate::All, false);
+    assert!(s.kept_file_handles() <= 3);
+
+    // Lowering the limit closes the extra files.
+    s.set_max_kept_file_handles(1);
+    assert!(s.kept_file_handles() <= 1);
+}
+
 // Checks that `refresh_processes` is removing dead processes.
 #[test]
 fn test_refresh_processes() {
@@ -620,6 +744,67 @@ fn test_wait_child() {
     assert!(before.elapsed() < std::time::Duration::from_millis(1000));
 }
 
+#[test]
+#[cfg(target_os = "linux")]
+fn test_wait_child_with_pidfd() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM {
+        return;
+    }
+    let p = start_proc!("300", "WaitChildPidfd");
+
+    let before = std::time::Instant::now();
+    let pid = Pid::from_u32(p.id() as _);
+
+    let mut s = System::new();
+    s.set_use_pidfds(true);
+    s.refresh_processes(ProcessesToUpdate::Some(&[pid]), false);
+    // The process is still the same one, so it should still be there.
+    assert_eq!(
+        s.refresh_processes(ProcessesToUpdate::Some(&[pid]), true),
+        1
+    );
+    let process = s.process(pid).unwrap();
+
+    assert_eq!(process.kill_with(sysinfo::Signal::Kill), Some(true));
+    let status = process.wait().expect("failed to wait for the process");
+    assert!(!status.success());
+    {
+        use std::os::unix::process::ExitStatusExt;
+        assert_eq!(status.signal(), Some(libc::SIGKILL));
+    }
+
+    assert_eq!(
+        s.refresh_processes(ProcessesToUpdate::Some(&[pid]), true),
+        0
+    );
+    assert!(before.elapsed() < std::time::Duration::from_millis(1000));
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+#[allow(clippy::zombie_processes)]
+fn test_wait_child_exit_code_with_pidfd() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM {
+        return;
+    }
+    let p = std::process::Command::new("sh")
+        .arg("-c")
+        .arg("sleep 0.5; exit 3")
+        .spawn()
+        .unwrap();
+    let pid = Pid::from_u32(p.id() as _);
+
+    let mut s = System::new();
+    s.set_use_pidfds(true);
+    s.refresh_processes(ProcessesToUpdate::Some(&[pid]), false);
+    let status = s
+        .process(pid)
+        .unwrap()
+        .wait()
+        .expect("failed to wait for the process");
+    assert_eq!(status.code(), Some(3));
+}
+
 #[test]
 fn test_wait_non_child() {
     if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
@@ -1058,6 +1243,42 @@ fn accumulated_cpu_time() {
     );
 }
 
+#[test]
+fn children_cpu_time() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM
+        || cfg!(feature = "apple-sandbox")
+        || !cfg!(any(
+            target_os = "linux",
+            target_os = "android",
+            target_os = "macos"
+        ))
+    {
+        return;
+    }
+
+    // The children CPU time is only updated once they've been waited for.
+    let status = std::process::Command::new("sh")
+        .arg("-c")
+        .arg("i=0; while [ $i -lt 100000 ]; do i=$((i+1)); done")
+        .status()
+        .expect("failed to run sh");
+    assert!(status.success());
+
+    let mut s = System::new();
+    let current_pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    s.refresh_processes_specifics(
+        ProcessesToUpdate::Some(&[current_pid]),
+        false,
+        ProcessRefreshKind::nothing().with_cpu(),
+    );
+    assert_ne!(
+        s.process(current_pid)
+            .expect("no process found")
+            .children_cpu_time(),
+        0
+    );
+}
+
 #[test]
 fn test_exists() {
     if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
@@ -1156,6 +1377,358 @@ fn open_files() {
     );
 }
 
+#[test]
+#[cfg(any(target_os = "linux", windows))]
+fn listening_ports() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM {
+        return;
+    }
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let mut s = System::new();
+    s.refresh_processes(ProcessesToUpdate::Some(&[pid]), false);
+    let ports = s.process(pid).unwrap().listening_ports().unwrap();
+    assert!(ports.contains(&listener.local_addr().unwrap()));
+}
+
+#[test]
+fn connection_count() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM {
+        return;
+    }
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    let _listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let _socket = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+    let mut s = System::new();
+    s.refresh_processes(ProcessesToUpdate::Some(&[pid]), false);
+    let count = s.process(pid).unwrap().connection_count();
+    if !cfg!(any(target_os = "linux", windows)) {
+        assert!(count.is_none());
+        return;
+    }
+    let count = count.unwrap();
+    assert!(count.tcp >= 1);
+    assert!(count.udp >= 1);
+}
+
+#[test]
+fn process_bitness() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM {
+        return;
+    }
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    let mut s = System::new();
+    s.refresh_processes(ProcessesToUpdate::Some(&[pid]), false);
+    let process = s.process(pid).unwrap();
+    if !cfg!(any(target_os = "linux", windows)) {
+        assert!(process.is_64_bit().is_none());
+        assert!(process.abi().is_none());
+        return;
+    }
+    assert_eq!(process.is_64_bit(), Some(cfg!(target_pointer_width = "64")));
+    assert!(process.abi().is_some());
+}
+
+#[test]
+fn stack_and_heap_size() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM {
+        return;
+    }
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    let mut s = System::new();
+    s.refresh_processes(ProcessesToUpdate::Some(&[pid]), false);
+    let process = s.process(pid).unwrap();
+    if !cfg!(target_os = "linux") {
+        assert!(process.stack_size().is_none());
+        assert!(process.heap_size().is_none());
+        return;
+    }
+    assert!(process.stack_size().is_some_and(|size| size > 0));
+    assert!(process.heap_size().is_some());
+}
+
+#[test]
+fn sched_stats() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM {
+        return;
+    }
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    let mut s = System::new();
+    s.refresh_processes(ProcessesToUpdate::Some(&[pid]), false);
+    let stats = s.process(pid).unwrap().sched_stats();
+    if !cfg!(target_os = "linux") {
+        assert!(stats.is_none());
+        return;
+    }
+    // `/proc/<pid>/schedstat` doesn't exist without `CONFIG_SCHED_INFO`.
+    if let Some(stats) = stats {
+        assert!(stats.run_time > std::time::Duration::ZERO);
+        assert!(stats.timeslices > 0);
+    }
+}
+
+#[test]
+fn io_counters() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM {
+        return;
+    }
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    let mut s = System::new();
+    s.refresh_processes(ProcessesToUpdate::Some(&[pid]), false);
+    let counters = s.process(pid).unwrap().io_counters();
+    if !cfg!(target_os = "linux") {
+        assert!(counters.is_none());
+        return;
+    }
+    // `/proc/<pid>/io` doesn't exist without `CONFIG_TASK_IO_ACCOUNTING`.
+    if let Some(counters) = counters {
+        // Refreshing the process read files under `/proc`.
+        assert!(counters.read_syscalls > 0);
+    }
+}
+
+#[test]
+fn wait_channel() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(windows) {
+        return;
+    }
+    // The current process can be blocked anywhere (like waiting for the `rayon` threads), so we
+    // check a process known to be sleeping instead.
+    let mut p = start_proc!("10", "WaitChannelSignal");
+    let pid = Pid::from_u32(p.id() as _);
+    std::thread::sleep(std::time::Duration::from_millis(500));
+    let mut s = System::new();
+    s.refresh_processes(ProcessesToUpdate::Some(&[pid]), false);
+    let wait_channel = s.process(pid).unwrap().wait_channel();
+    p.kill().expect("Unable to kill process.");
+    let _ = p.wait();
+    if !cfg!(target_os = "linux") {
+        assert_eq!(wait_channel, None);
+    } else if let Some(wait_channel) = wait_channel {
+        // It's only reported to the users allowed to read the kernel stack of the process.
+        assert!(!wait_channel.is_empty());
+    }
+}
+
+#[test]
+fn kernel_stack() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM {
+        return;
+    }
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    let mut s = System::new();
+    s.refresh_processes(ProcessesToUpdate::Some(&[pid]), false);
+    let stack = s.process(pid).unwrap().kernel_stack();
+    if !cfg!(target_os = "linux") {
+        assert_eq!(stack, Err(sysinfo::RefreshError::Unsupported));
+        return;
+    }
+    match stack {
+        Ok(stack) => assert!(stack.iter().all(|frame| !frame.is_empty())),
+        // Only root can read `/proc/<pid>/stack`, and it doesn't exist without
+        // `CONFIG_STACKTRACE`.
+        Err(error) => assert!(
+            matches!(
+                error,
+                sysinfo::RefreshError::PermissionDenied(_) | sysinfo::RefreshError::NotFound(_)
+            ),
+            "{error:?}"
+        ),
+    }
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn zombie_and_orphaned_processes() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM {
+        return;
+    }
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    // The child isn't waited on until the end of the test, so it becomes a zombie.
+    let mut child = std::process::Command::new("true").spawn().unwrap();
+    let child_pid = Pid::from_u32(child.id());
+    std::thread::sleep(std::time::Duration::from_millis(250));
+
+    let s = System::new_with_specifics(
+        RefreshKind::nothing().with_processes(ProcessRefreshKind::nothing()),
+    );
+    assert!(s.zombie_processes().any(|p| p.pid() == child_pid));
+    assert!(s.zombie_processes().all(|p| p.pid() != pid));
+    assert!(
+        s.orphaned_processes()
+            .all(|p| p.pid() != pid && p.pid() != child_pid)
+    );
+    assert!(!s.process(pid).unwrap().reparented_to_init());
+    child.wait().unwrap();
+}
+
+#[test]
+fn processes_started_since() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    let s = System::new_with_specifics(
+        RefreshKind::nothing().with_processes(ProcessRefreshKind::nothing()),
+    );
+    let process = s.process(pid).unwrap();
+    let since = std::time::UNIX_EPOCH + std::time::Duration::from_secs(process.start_time());
+
+    let processes = s.processes_started_since(since).collect::<Vec<_>>();
+    assert!(processes.iter().any(|p| p.pid() == pid));
+    assert!(processes.iter().all(|p| !process.started_after(p)));
+    assert!(
+        processes
+            .windows(2)
+            .all(|pair| !pair[0].started_after(pair[1]))
+    );
+}
+
+#[test]
+fn thread_count() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(target_os = "ios") {
+        return;
+    }
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    let (sender, receiver) = std::sync::mpsc::channel::<()>();
+    let thread = std::thread::spawn(move || receiver.recv());
+    let s = System::new_with_specifics(
+        RefreshKind::nothing().with_processes(ProcessRefreshKind::nothing().with_cpu()),
+    );
+    let thread_count = s.process(pid).unwrap().thread_count().unwrap();
+    assert!(thread_count >= 2);
+    assert!(s.total_threads() >= thread_count);
+    sender.send(()).unwrap();
+    thread.join().unwrap().unwrap();
+}
+
+#[test]
+fn usage_by_user() {
+    let s = System::new_with_specifics(
+        RefreshKind::nothing().with_processes(
+            ProcessRefreshKind::nothing()
+                .with_memory()
+                .with_user(UpdateKind::Always),
+        ),
+    );
+    let usage = s.usage_by_user();
+    if !sysinfo::IS_SUPPORTED_SYSTEM {
+        assert!(usage.is_empty());
+        return;
+    }
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    let process = s.process(pid).unwrap();
+    let user = &usage[process.user_id().unwrap()];
+    assert!(user.process_count >= 1);
+    assert!(user.memory >= process.memory());
+    assert!(usage.values().map(|u| u.process_count).sum::<usize>() <= s.processes().len());
+}
+
+#[test]
+fn usage_by_cgroup() {
+    let s = System::new_with_specifics(
+        RefreshKind::nothing().with_processes(ProcessRefreshKind::nothing().with_memory()),
+    );
+    let usage = s.usage_by_cgroup();
+    if !sysinfo::IS_SUPPORTED_SYSTEM || !cfg!(target_os = "linux") {
+        assert!(usage.is_empty());
+        return;
+    }
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    let process = s.process(pid).unwrap();
+    // `/proc/[pid]/cgroup` might not exist if the kernel was built without cgroups.
+    let Some(cgroup) = process.cgroup() else {
+        return;
+    };
+    let group = &usage[&cgroup];
+    assert!(group.process_count >= 1);
+    assert_eq!(group.container_id, process.container_id());
+    assert!(group.memory >= process.memory());
+    assert!(usage.values().map(|u| u.process_count).sum::<usize>() <= s.processes().len());
+}
+
+#[test]
+fn processes_by_exe() {
+    let s = System::new_with_specifics(
+        RefreshKind::nothing().with_processes(
+            ProcessRefreshKind::nothing()
+                .with_memory()
+                .with_exe(UpdateKind::Always),
+        ),
+    );
+    let usage = s.processes_by_exe();
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        assert!(usage.is_empty());
+        return;
+    }
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    let exe = std::env::current_exe().unwrap().canonicalize().unwrap();
+    let group = &usage[&exe];
+    assert!(group.pids.contains(&pid));
+    assert!(group.memory >= s.process(pid).unwrap().memory());
+    assert!(usage.values().map(|u| u.pids.len()).sum::<usize>() <= s.processes().len());
+}
+
+#[test]
+fn processes_using_path() {
+    let dir = tempfile::tempdir().unwrap();
+    let file_path = dir.path().join("file");
+    let _file = std::fs::File::create(&file_path).unwrap();
+    let s = System::new_with_specifics(
+        RefreshKind::nothing().with_processes(
+            ProcessRefreshKind::nothing()
+                .with_cwd(UpdateKind::Always)
+                .with_exe(UpdateKind::Always),
+        ),
+    );
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        assert!(s.processes_using_path(dir.path()).is_empty());
+        return;
+    }
+    let exe = std::env::current_exe().unwrap();
+    let usage = s.processes_using_path(&exe);
+    assert!(usage[&pid].exe);
+    assert!(usage.values().all(|usage| usage.is_used()));
+    if cfg!(any(target_os = "linux", target_os = "macos")) {
+        let usage = s.processes_using_path(dir.path());
+        assert_eq!(
+            usage.get(&pid).copied(),
+            Some(PathUsage {
+                open_file: true,
+                ..PathUsage::default()
+            })
+        );
+    }
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn processes_with_deleted_mappings() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM {
+        return;
+    }
+    let dir = tempfile::tempdir().unwrap();
+    let exe = dir.path().canonicalize().unwrap().join("deleted-sleep");
+    std::fs::copy("/bin/sleep", &exe).unwrap();
+    let mut p = std::process::Command::new(&exe)
+        .arg("3")
+        .stdout(std::process::Stdio::null())
+        .spawn()
+        .unwrap();
+    let pid = Pid::from_u32(p.id() as _);
+    // Wait for the `exec` to be done before removing the file.
+    std::thread::sleep(std::time::Duration::from_millis(250));
+    std::fs::remove_file(&exe).unwrap();
+
+    let mut s = System::new();
+    s.refresh_processes(ProcessesToUpdate::Some(&[pid]), false);
+    let deleted = s.processes_with_deleted_mappings();
+    p.kill().expect("Unable to kill process.");
+    let _ = p.wait();
+    assert_eq!(deleted.get(&pid), Some(&vec![exe]));
+}
+
 #[test]
 fn test_wait() {
     if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
@@ -1207,3 +1780,299 @@ fn test_cpu_processes_usage() {
 
     assert!(sys.processes().iter().any(|(_, p)| p.cpu_usage() > 0.));
 }
+
+#[test]
+fn test_exe_signature() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let pid = sysinfo::get_current_pid().unwrap();
+    let mut s = System::new();
+    s.refresh_processes_specifics(
+        ProcessesToUpdate::Some(&[pid]),
+        false,
+        ProcessRefreshKind::nothing(),
+    );
+    // No executable path, so no signature.
+    assert_eq!(s.process(pid).unwrap().exe_signature(), None);
+
+    s.refresh_processes_specifics(
+        ProcessesToUpdate::Some(&[pid]),
+        false,
+        ProcessRefreshKind::nothing().with_exe(UpdateKind::Always),
+    );
+    let process = s.process(pid).unwrap();
+    let signature = process.exe_signature().cloned();
+    assert!(signature.is_some());
+    // The value is cached.
+    assert_eq!(process.exe_signature(), signature.as_ref());
+    if cfg!(any(target_os = "linux", target_os = "freebsd")) {
+        assert!(matches!(signature, Some(sysinfo::ExeSignature::Sha256(_))));
+    }
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn test_exe_signature_of_deleted_exe() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM {
+        return;
+    }
+    let dir = tempfile::tempdir().unwrap();
+    let exe = dir.path().join("deleted-sleep");
+    std::fs::copy("/bin/sleep", &exe).unwrap();
+    let mut p = std::process::Command::new(&exe)
+        .arg("3")
+        .stdout(std::process::Stdio::null())
+        .spawn()
+        .unwrap();
+    let pid = Pid::from_u32(p.id() as _);
+    // Wait for the `exec` to be done before removing the file.
+    std::thread::sleep(std::time::Duration::from_millis(250));
+    std::fs::remove_file(&exe).unwrap();
+
+    let mut s = System::new();
+    s.refresh_processes_specifics(
+        ProcessesToUpdate::Some(&[pid]),
+        false,
+        ProcessRefreshKind::nothing().with_exe(UpdateKind::Always),
+    );
+    let signature = s.process(pid).and_then(|p| p.exe_signature().cloned());
+    p.kill().expect("Unable to kill process.");
+    let _ = p.wait();
+    assert!(matches!(signature, Some(sysinfo::ExeSignature::Sha256(_))));
+}
+
+#[test]
+fn test_exe_metadata() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let pid = sysinfo::get_current_pid().unwrap();
+    let mut s = System::new();
+    s.refresh_processes_specifics(
+        ProcessesToUpdate::Some(&[pid]),
+        false,
+        ProcessRefreshKind::nothing().with_exe(UpdateKind::Always),
+    );
+    let process = s.process(pid).unwrap();
+    let metadata = process.exe_metadata().cloned();
+    assert!(metadata.is_some());
+    // The value is cached.
+    assert_eq!(process.exe_metadata(), metadata.as_ref());
+    let metadata = metadata.unwrap();
+    if cfg!(any(target_os = "linux", target_os = "freebsd")) {
+        assert!(metadata.is_stripped().is_some());
+    }
+    if cfg!(windows) {
+        assert!(metadata.link_time().is_some());
+    }
+}
+
+#[test]
+fn test_update_kind_only_if_changed() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM
+        || !cfg!(any(target_os = "linux", target_os = "macos"))
+        || cfg!(feature = "apple-sandbox")
+    {
+        return;
+    }
+    let mut child = std::process::Command::new("sh")
+        .arg("-c")
+        .arg("sleep 0.2; exec sleep 10")
+        .spawn()
+        .expect("failed to run sh");
+    let pid = Pid::from_u32(child.id());
+    let refresh_kind = ProcessRefreshKind::nothing().with_exe(UpdateKind::OnlyIfChanged);
+    let mut s = System::new();
+    s.refresh_processes_specifics(ProcessesToUpdate::Some(&[pid]), false, refresh_kind);
+    assert!(s.process(pid).unwrap().exe().is_some());
+
+    let mut exe = None;
+    for _ in 0..50 {
+        std::thread::sleep(std::time::Duration::from_millis(100));
+        s.refresh_processes_specifics(ProcessesToUpdate::Some(&[pid]), false, refresh_kind);
+        let process = s.process(pid).unwrap();
+        if process.name() == "sleep" {
+            exe = process.exe().map(|exe| exe.to_owned());
+            break;
+        }
+    }
+    child.kill().unwrap();
+    child.wait().unwrap();
+    // The executable was retrieved again after `exec`.
+    assert!(exe.unwrap().ends_with("sleep"));
+}
+
+#[test]
+fn test_exec_count() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM
+        || !cfg!(any(target_os = "linux", target_os = "macos"))
+        || cfg!(feature = "apple-sandbox")
+    {
+        return;
+    }
+    let mut child = std::process::Command::new("sh")
+        .arg("-c")
+        .arg("sleep 0.2; exec sleep 10")
+        .spawn()
+        .expect("failed to run sh");
+    let pid = Pid::from_u32(child.id());
+    let mut s = System::new();
+    s.refresh_processes(ProcessesToUpdate::Some(&[pid]), false);
+    let process = s.process(pid).unwrap();
+    assert_eq!(process.exec_count(), 0);
+    // If the first refresh happened after `exec`, it cannot be detected.
+    let expected = if process.name() == "sleep" { 0 } else { 1 };
+
+    let mut exec_count = None;
+    for _ in 0..50 {
+        std::thread::sleep(std::time::Duration::from_millis(100));
+        s.refresh_processes(ProcessesToUpdate::Some(&[pid]), false);
+        let process = s.process(pid).unwrap();
+        if process.name() == "sleep" {
+            exec_count = Some(process.exec_count());
+            break;
+        }
+    }
+    child.kill().unwrap();
+    child.wait().unwrap();
+    assert_eq!(exec_count, Some(expected));
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn test_exec_count_name_change() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM {
+        return;
+    }
+    let (tid_sender, tid_receiver) = std::sync::mpsc::channel();
+    let (rename_sender, rename_receiver) = std::sync::mpsc::channel::<()>();
+    let (renamed_sender, renamed_receiver) = std::sync::mpsc::channel();
+    let thread = std::thread::spawn(move || {
+        tid_sender.send(unsafe { libc::gettid() }).unwrap();
+        rename_receiver.recv().unwrap();
+        unsafe { libc::prctl(libc::PR_SET_NAME, c"renamed".as_ptr()) };
+        renamed_sender.send(()).unwrap();
+        rename_receiver.recv().ok();
+    });
+    let tid = Pid::from_u32(tid_receiver.recv().unwrap() as _);
+
+    let mut s = System::new();
+    s.refresh_processes(ProcessesToUpdate::All, false);
+    assert_eq!(s.process(tid).unwrap().exec_count(), 0);
+    rename_sender.send(()).unwrap();
+    renamed_receiver.recv().unwrap();
+    s.refresh_processes(ProcessesToUpdate::All, false);
+    let process = s.process(tid).unwrap();
+    assert_eq!(process.name(), "renamed");
+    // The executable didn't change, so it's not an `exec`.
+    assert_eq!(process.exec_count(), 0);
+
+    drop(rename_sender);
+    thread.join().unwrap();
+}
+
+#[test]
+fn test_exited_processes() {
+    // It requires the `CAP_NET_ADMIN` capability on Linux and isn't supported elsewhere.
+    let Ok(mut exited) = sysinfo::ExitedProcesses::start() else {
+        return;
+    };
+    let child = std::process::Command::new("sh")
+        .arg("-c")
+        .arg("exit 3")
+        .spawn()
+        .expect("failed to run sh");
+    let pid = Pid::from_u32(child.id());
+    let output = child.wait_with_output().expect("failed to wait for sh");
+    assert_eq!(output.status.code(), Some(3));
+
+    let mut found = None;
+    for _ in 0..20 {
+        std::thread::sleep(std::time::Duration::from_millis(50));
+        exited.refresh();
+        found = exited.list().iter().find(|p| p.pid() == pid).cloned();
+        if found.is_some() {
+            break;
+        }
+    }
+    let process = found.expect("exit of the child wasn't captured");
+    assert_eq!(process.exit_status().and_then(|s| s.code()), Some(3));
+    assert_eq!(process.parent(), sysinfo::get_current_pid().ok());
+    // The information might not be available if the process was reaped before it was read and
+    // taskstats isn't available.
+    if !process.name().is_empty() {
+        assert_eq!(process.name(), "sh");
+        assert!(process.start_time() > 0);
+    }
+}
+
+#[cfg(feature = "ebpf")]
+#[test]
+fn test_ebpf_collectors() {
+    // It requires the `CAP_BPF` and `CAP_PERFMON` capabilities on Linux and isn't supported
+    // elsewhere.
+    let Ok(mut collectors) = sysinfo::EbpfCollectors::start() else {
+        return;
+    };
+    // Each sleep makes the current thread go through the run queue when it's woken up.
+    for _ in 0..10 {
+        std::thread::sleep(std::time::Duration::from_millis(5));
+    }
+    collectors.refresh();
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    let latency = collectors
+        .run_queue_latency(pid)
+        .expect("no run queue latency");
+    assert_ne!(latency.count(), 0);
+    assert!(latency.max() <= latency.total());
+}
+
+#[test]
+fn test_processes_sorted() {
+    let mut s = System::new();
+    s.refresh_processes(ProcessesToUpdate::All, false);
+    let sorted = s.processes_sorted();
+    assert_eq!(sorted.len(), s.processes().len());
+    assert!(sorted.windows(2).all(|w| w[0].pid() < w[1].pid()));
+
+    let index = s.processes_by_name_index();
+    assert_eq!(index.values().map(Vec::len).sum::<usize>(), sorted.len());
+    for (name, processes) in &index {
+        assert!(processes.iter().all(|process| process.name() == *name));
+        assert!(processes.windows(2).all(|w| w[0].pid() < w[1].pid()));
+    }
+}
+
+#[test]
+fn test_pid_set_tracker() {
+    let pid = sysinfo::get_current_pid().unwrap();
+    let mut tracker = sysinfo::PidSetTracker::new([pid]);
+    assert!(!tracker.add(pid));
+    assert_eq!(tracker.pids(), &[pid]);
+
+    tracker.refresh();
+    if !sysinfo::IS_SUPPORTED_SYSTEM {
+        assert!(tracker.delta(pid).is_none());
+        return;
+    }
+    let delta = tracker.delta(pid).unwrap();
+    assert!(delta.interval.is_none());
+    assert!(!delta.pid_reused);
+    assert!(tracker.process(pid).is_some());
+
+    std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+    tracker.refresh();
+    let delta = tracker.delta(pid).unwrap();
+    assert!(delta.interval.unwrap() >= sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+    assert!(!delta.pid_reused);
+    assert_eq!(tracker.deltas().count(), 1);
+
+    assert!(tracker.remove(pid));
+    assert!(!tracker.remove(pid));
+    assert!(tracker.delta(pid).is_none());
+    assert!(tracker.process(pid).is_none());
+    // The process information was dropped when it stopped being tracked.
+    assert!(tracker.add(pid));
+    assert!(tracker.process(pid).is_none());
+}
@@ -3,7 +3,7 @@
 #![cfg(feature = "system")]
 #![allow(clippy::assertions_on_constants)]
 
-use sysinfo::{ProcessesToUpdate, System};
+use sysinfo::{CpuRefreshKind, ProcessesToUpdate, RefreshKind, System};
 
 #[test]
 fn test_refresh_system() {
@@ -19,6 +19,148 @@ fn test_refresh_system() {
     assert!(sys.total_swap() >= sys.free_swap());
 }
 
+#[test]
+fn test_refresh_observers() {
+    use std::sync::{Arc, Mutex};
+    use sysinfo::RefreshSubsystem;
+
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let mut sys = System::new();
+    let observer_events = Arc::clone(&events);
+    sys.add_refresh_observer(move |event| observer_events.lock().unwrap().push(*event));
+    sys.refresh_all();
+    sys.refresh_cpu_list(CpuRefreshKind::nothing());
+
+    let events = events.lock().unwrap();
+    assert_eq!(
+        events.iter().map(|e| e.subsystem).collect::<Vec<_>>(),
+        [
+            RefreshSubsystem::Memory,
+            RefreshSubsystem::Cpu,
+            RefreshSubsystem::Processes,
+            RefreshSubsystem::CpuList,
+        ],
+    );
+    assert_eq!(
+        events.last().map(|e| e.refreshed_at),
+        sys.last_refreshed_at()
+    );
+}
+
+#[test]
+fn test_last_refresh_durations() {
+    let mut sys = System::new();
+    assert_eq!(sys.last_refresh_durations(), Default::default());
+    sys.refresh_memory();
+    let durations = sys.last_refresh_durations();
+    assert!(durations.memory.is_some());
+    assert!(durations.cpu.is_none());
+    assert!(durations.processes.is_none());
+    sys.refresh_all();
+    let durations = sys.last_refresh_durations();
+    assert!(durations.cpu.is_some());
+    assert!(durations.processes.is_some());
+    assert!(durations.cpu_list.is_none());
+}
+
+#[test]
+fn test_shared_system() {
+    use sysinfo::{MemoryRefreshKind, SharedSystem};
+
+    let shared = SharedSystem::new(System::new());
+    let refresher = shared.clone();
+    let thread = std::thread::spawn(move || {
+        for _ in 0..5 {
+            refresher.refresh_specifics(
+                RefreshKind::nothing().with_memory(MemoryRefreshKind::everything()),
+            );
+        }
+    });
+    for _ in 0..5 {
+        let s = shared.snapshot_ref();
+        assert!(s.total_memory() >= s.free_memory());
+    }
+    thread.join().unwrap();
+
+    let s = shared.snapshot_ref();
+    assert!(s.last_refresh_durations().memory.is_some());
+    if sysinfo::IS_SUPPORTED_SYSTEM {
+        assert_ne!(s.total_memory(), 0);
+    }
+}
+
+#[cfg(feature = "record")]
+#[test]
+fn test_shared_system_snapshot() {
+    use sysinfo::{MemoryRefreshKind, SharedSystem};
+
+    let shared = SharedSystem::new(System::new());
+    shared.refresh_specifics(RefreshKind::nothing().with_memory(MemoryRefreshKind::everything()));
+    let snapshot = shared.snapshot();
+    let s = shared.snapshot_ref();
+    assert_eq!(snapshot.total_memory(), s.total_memory());
+    assert_eq!(snapshot.processes().len(), s.processes().len());
+}
+
+#[test]
+fn test_snapshot_ref() {
+    use std::sync::Arc;
+    use sysinfo::{CpuRefreshKind, MemoryRefreshKind, SharedSystem};
+
+    let shared = SharedSystem::new(System::new());
+    let first = shared.snapshot_ref();
+    // Nothing was refreshed, so the same `System` is returned.
+    assert!(Arc::ptr_eq(&first, &shared.snapshot_ref()));
+    assert_eq!(first.total_memory(), 0);
+
+    shared.refresh_specifics(RefreshKind::nothing().with_memory(MemoryRefreshKind::everything()));
+    let second = shared.snapshot_ref();
+    assert!(!Arc::ptr_eq(&first, &second));
+    // `first` is still held, so the refresh was done in a new `System`.
+    assert_eq!(first.total_memory(), 0);
+    if sysinfo::IS_SUPPORTED_SYSTEM {
+        assert_ne!(second.total_memory(), 0);
+    }
+    let second_ptr = Arc::as_ptr(&second);
+    drop((first, second));
+
+    shared.refresh_specifics(RefreshKind::nothing().with_cpu(CpuRefreshKind::everything()));
+    let third = shared.snapshot_ref();
+    // The memory was only refreshed in the other `System`, it must have been caught up.
+    if sysinfo::IS_SUPPORTED_SYSTEM {
+        assert_ne!(third.total_memory(), 0);
+    }
+    assert!(!third.cpus().is_empty() || !sysinfo::IS_SUPPORTED_SYSTEM);
+    drop(third);
+
+    // The `System` which was released before the previous refresh is reused.
+    shared.refresh_specifics(RefreshKind::nothing().with_memory(MemoryRefreshKind::everything()));
+    assert_eq!(Arc::as_ptr(&shared.snapshot_ref()), second_ptr);
+}
+
+#[test]
+fn test_try_refresh_system() {
+    use sysinfo::{CpuRefreshKind, MemoryRefreshKind, ProcessRefreshKind, RefreshError};
+
+    let mut sys = System::new();
+    let memory = sys.try_refresh_memory_specifics(MemoryRefreshKind::everything());
+    let cpu = sys.try_refresh_cpu_specifics(CpuRefreshKind::everything());
+    let processes = sys.try_refresh_processes_specifics(
+        ProcessesToUpdate::All,
+        true,
+        ProcessRefreshKind::nothing(),
+    );
+    if sysinfo::IS_SUPPORTED_SYSTEM {
+        assert_eq!(memory, Ok(()));
+        assert_eq!(cpu, Ok(()));
+        assert!(processes.is_ok());
+        assert!(sys.total_memory() != 0);
+    } else if cfg!(not(sysinfo_test_backend)) {
+        assert_eq!(memory, Err(RefreshError::Unsupported));
+        assert_eq!(processes, Err(RefreshError::Unsupported));
+    }
+}
+
 #[test]
 fn test_refresh_process() {
     let mut sys = System::new();
@@ -118,6 +260,16 @@ fn check_uptime() {
     }
 }
 
+#[test]
+fn check_compat_mode() {
+    let compat = System::is_compat_mode();
+    if sysinfo::IS_SUPPORTED_SYSTEM && cfg!(any(target_os = "linux", windows)) {
+        assert!(compat.is_some());
+    } else if !sysinfo::IS_SUPPORTED_SYSTEM {
+        assert!(compat.is_none());
+    }
+}
+
 #[test]
 fn check_boot_time() {
     if sysinfo::IS_SUPPORTED_SYSTEM {
@@ -125,6 +277,290 @@ fn check_boot_time() {
     }
 }
 
+#[test]
+fn check_clock_ticks_and_page_size() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM {
+        return;
+    }
+    if cfg!(unix) {
+        assert!(System::clock_ticks_per_second().is_some_and(|ticks| ticks > 0));
+    }
+    assert!(System::page_size().is_some_and(|size| size.is_power_of_two()));
+}
+
+#[test]
+fn check_time_skew() {
+    if sysinfo::IS_SUPPORTED_SYSTEM {
+        assert!(System::time_skew().is_some());
+        std::thread::sleep(std::time::Duration::from_millis(1100));
+        // Unless the wall clock is changed during the test, there should be no skew.
+        assert!(System::time_skew().is_some_and(|skew| skew.abs() <= 1));
+    } else {
+        assert!(System::time_skew().is_none());
+    }
+}
+
+#[test]
+fn check_timezone_and_locale() {
+    for value in [System::timezone(), System::locale()].into_iter().flatten() {
+        assert!(!value.is_empty());
+        assert!(!value.contains('\u{0}'));
+    }
+}
+
+#[test]
+fn check_cpu_vulnerabilities() {
+    let vulnerabilities = System::cpu_vulnerabilities();
+    if !cfg!(target_os = "linux") {
+        assert!(vulnerabilities.is_empty());
+    }
+    for vulnerability in &vulnerabilities {
+        assert!(!vulnerability.name().is_empty());
+    }
+    assert!(
+        vulnerabilities
+            .windows(2)
+            .all(|pair| pair[0].name() < pair[1].name())
+    );
+}
+
+#[test]
+fn check_memory_hardware_info() {
+    let Some(info) = System::memory_hardware_info() else {
+        return;
+    };
+    assert!(info.slot_count() >= info.modules().len());
+    assert!(info.modules().iter().all(|module| module.size() > 0));
+}
+
+#[test]
+fn check_board_model_and_throttling_status() {
+    let model = System::board_model();
+    let status = System::throttling_status();
+    if !cfg!(target_os = "linux") {
+        assert!(model.is_none());
+        assert!(status.is_none());
+        return;
+    }
+    assert!(model.is_none_or(|model| !model.is_empty() && !model.ends_with('\0')));
+    if let Some(status) = status {
+        // The "currently" flags are also set in the "since boot" flags.
+        assert!(!status.is_under_voltage() || status.has_been_under_voltage());
+        assert!(!status.is_throttled() || status.has_been_throttled());
+    }
+}
+
+#[test]
+fn check_turbo_enabled() {
+    let turbo_enabled = System::turbo_enabled();
+    let mut s = System::new();
+    s.refresh_cpu_frequency();
+    if !cfg!(any(target_os = "linux", windows)) {
+        assert!(turbo_enabled.is_none());
+        assert!(s.cpus().iter().all(|cpu| cpu.boost_active().is_none()));
+    }
+}
+
+#[test]
+fn check_cpu_idle_states() {
+    let s = System::new_with_specifics(
+        RefreshKind::nothing().with_cpu(CpuRefreshKind::nothing().with_idle_states()),
+    );
+    for cpu in s.cpus() {
+        if !cfg!(target_os = "linux") {
+            assert!(cpu.idle_states().is_empty());
+        }
+        for state in cpu.idle_states() {
+            assert!(!state.name().is_empty());
+        }
+    }
+}
+
+#[test]
+fn check_socket_summary() {
+    let summary = System::socket_summary();
+    if !cfg!(any(target_os = "linux", target_os = "freebsd", windows)) {
+        assert!(summary.is_none());
+        return;
+    }
+    if cfg!(target_os = "linux")
+        && let Some(summary) = summary
+    {
+        assert!(summary.tcp_sockets().is_some());
+        assert!(summary.udp_sockets().is_some());
+    }
+}
+
+#[test]
+fn check_kernel_log() {
+    let log = System::kernel_log(None);
+    if !cfg!(any(target_os = "linux", target_os = "redox", windows)) {
+        assert_eq!(log.unwrap_err().kind(), std::io::ErrorKind::Unsupported);
+        return;
+    }
+    // Reading the kernel log usually requires privileges.
+    let Ok(entries) = log else {
+        return;
+    };
+    assert!(
+        entries
+            .windows(2)
+            .all(|entries| entries[0].sequence() < entries[1].sequence())
+    );
+    if cfg!(target_os = "linux") {
+        assert!(
+            entries
+                .iter()
+                .all(|entry| entry.time_since_boot().is_some())
+        );
+        let since = std::time::Instant::now();
+        let recent = System::kernel_log(Some(since)).unwrap();
+        assert!(recent.len() <= entries.len());
+    }
+}
+
+#[test]
+fn check_swap_devices() {
+    let mut s = System::new();
+    let devices = s.swap_devices();
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(target_vendor = "apple") {
+        assert!(devices.is_empty());
+    }
+    assert!(devices.iter().all(|device| device.used() <= device.total()));
+    if cfg!(target_os = "linux") && !devices.is_empty() {
+        s.refresh_memory();
+        let total = devices.iter().map(|device| device.total()).sum::<u64>();
+        assert_eq!(total, s.total_swap());
+    }
+}
+
+#[test]
+fn check_compressed_memory() {
+    let s = System::new();
+    let compressed = s.compressed_memory();
+    if !sysinfo::IS_SUPPORTED_SYSTEM || !cfg!(any(target_os = "linux", target_os = "macos")) {
+        assert!(compressed.is_none());
+    }
+    if let Some(compressed) = compressed
+        && compressed.compressed_size() > 0
+    {
+        assert!(compressed.compression_ratio() > 0.);
+    }
+}
+
+#[test]
+fn check_inotify_usage() {
+    let usage = System::new().inotify_usage();
+    if !sysinfo::IS_SUPPORTED_SYSTEM || !cfg!(target_os = "linux") {
+        assert!(usage.is_none());
+    }
+    if let Some(usage) = usage {
+        assert!(usage.max_instances() > 0);
+        assert!(usage.max_watches() > 0);
+        assert!(usage.instances() <= usage.max_instances());
+    }
+}
+
+#[test]
+fn check_facts() {
+    let facts = System::facts();
+    assert_eq!(facts.cpu_arch(), System::cpu_arch());
+    assert_eq!(facts.host_name(), System::host_name().as_deref());
+    if sysinfo::IS_SUPPORTED_SYSTEM {
+        assert!(facts.cpu_count() > 0);
+        assert!(facts.total_memory() > 0);
+        assert_ne!(facts.boot_time(), 0);
+    }
+}
+
+#[test]
+fn check_core_dump_config() {
+    let config = System::core_dump_config();
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(any(windows, target_os = "ios")) {
+        assert!(config.is_none());
+    } else if cfg!(target_os = "linux") {
+        let config = config.unwrap();
+        assert!(!config.pattern().is_empty());
+        assert_eq!(
+            config.handler().is_some(),
+            config.pattern().starts_with('|')
+        );
+    }
+}
+
+#[test]
+fn check_last_refreshed_at() {
+    let mut s = System::new();
+    assert!(s.last_refreshed_at().is_none());
+    s.refresh_memory();
+    let first = s.last_refreshed_at().unwrap();
+    s.refresh_cpu_usage();
+    let second = s.last_refreshed_at().unwrap();
+    assert!(second.instant() >= first.instant());
+
+    if !sysinfo::IS_SUPPORTED_SYSTEM {
+        return;
+    }
+    let pid = sysinfo::get_current_pid().unwrap();
+    s.refresh_processes(sysinfo::ProcessesToUpdate::Some(&[pid]), false);
+    let refreshed_at = s.process(pid).unwrap().last_refreshed_at().unwrap();
+    assert_eq!(Some(refreshed_at), s.last_refreshed_at());
+    assert!(refreshed_at.instant() >= second.instant());
+}
+
+#[test]
+fn check_cpu_measurement_interval() {
+    let mut s = System::new();
+    assert!(!s.refresh_cpu_usage());
+    if !sysinfo::MINIMUM_CPU_UPDATE_INTERVAL.is_zero() {
+        // Too soon, the CPU usage isn't updated.
+        assert!(!s.refresh_cpu_usage());
+        assert!(
+            s.cpus()
+                .iter()
+                .all(|cpu| cpu.measurement_interval().is_none())
+        );
+    }
+
+    std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+    if !sysinfo::IS_SUPPORTED_SYSTEM {
+        return;
+    }
+    assert!(s.refresh_cpu_usage());
+    for cpu in s.cpus() {
+        assert!(cpu.measurement_interval().unwrap() >= sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+    }
+
+    if cfg!(target_os = "linux") {
+        // Computing the processes CPU usage only updates the global CPU times, so it must not
+        // shorten the interval of the per-CPU usage.
+        std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+        s.refresh_processes_specifics(
+            sysinfo::ProcessesToUpdate::All,
+            false,
+            sysinfo::ProcessRefreshKind::nothing().with_cpu(),
+        );
+        std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+        assert!(s.refresh_cpu_usage());
+        for cpu in s.cpus() {
+            assert!(
+                cpu.measurement_interval().unwrap() >= sysinfo::MINIMUM_CPU_UPDATE_INTERVAL * 2
+            );
+        }
+    }
+}
+
+#[test]
+fn check_firewall_status() {
+    let firewalls = System::firewall_status();
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(any(target_os = "ios", feature = "apple-sandbox")) {
+        assert!(firewalls.is_empty());
+    }
+    for firewall in &firewalls {
+        assert!(!firewall.name().is_empty());
+    }
+}
+
 // This test is used to ensure that the CPU usage computation isn't completely going off
 // when refreshing it too frequently (ie, multiple times in a row in a very small interval).
 #[test]
@@ -247,3 +683,27 @@ fn test_refresh_memory() {
     assert_ne!(s.total_memory(), 0);
     assert_ne!(s.free_memory(), 0);
 }
+
+#[cfg(feature = "apple-silicon")]
+#[test]
+fn check_apple_silicon_metrics() {
+    let metrics = sysinfo::AppleSiliconMetrics::start();
+    if !cfg!(target_os = "macos") || cfg!(feature = "apple-sandbox") {
+        assert_eq!(
+            metrics.err().map(|error| error.kind()),
+            Some(std::io::ErrorKind::Unsupported)
+        );
+        return;
+    }
+    // Intel Macs don't have the `IOReport` channels of Apple Silicon chips.
+    let Ok(mut metrics) = metrics else {
+        return;
+    };
+    metrics.refresh();
+    std::thread::sleep(std::time::Duration::from_millis(100));
+    metrics.refresh();
+    assert!(!metrics.clusters().is_empty());
+    for cluster in metrics.clusters() {
+        assert!((0. ..=100.).contains(&cluster.usage()));
+    }
+}